@@ -0,0 +1,990 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+// A small HTTP framework: routing, middleware, body parsing, validation,
+// caching and server-sent events.
+
+export type Method =
+  | "GET"
+  | "HEAD"
+  | "POST"
+  | "PUT"
+  | "PATCH"
+  | "DELETE"
+  | "OPTIONS";
+
+export type Params = Record<string, string>;
+
+export interface Context<State = Record<string, unknown>> {
+  request: Request;
+  url: URL;
+  params: Params;
+  state: State;
+  remoteAddr?: Deno.NetAddr;
+}
+
+export type Handler<State = Record<string, unknown>> = (
+  ctx: Context<State>,
+) => Response | Promise<Response>;
+
+export type Middleware<State = Record<string, unknown>> = (
+  ctx: Context<State>,
+  next: () => Promise<Response>,
+) => Response | Promise<Response>;
+
+export class HttpError extends Error {
+  readonly status: number;
+  readonly expose: boolean;
+  readonly headers: Headers;
+
+  constructor(
+    status: number,
+    message?: string,
+    init: { expose?: boolean; headers?: HeadersInit } = {},
+  ) {
+    super(message ?? STATUS_TEXT[status] ?? "Unknown error");
+    this.name = "HttpError";
+    this.status = status;
+    this.expose = init.expose ?? status < 500;
+    this.headers = new Headers(init.headers);
+  }
+
+  toResponse(): Response {
+    const body = this.expose ? this.message : STATUS_TEXT[this.status];
+    return new Response(body, { status: this.status, headers: this.headers });
+  }
+}
+
+export const STATUS_TEXT: Record<number, string> = {
+  200: "OK",
+  201: "Created",
+  204: "No Content",
+  301: "Moved Permanently",
+  302: "Found",
+  304: "Not Modified",
+  400: "Bad Request",
+  401: "Unauthorized",
+  403: "Forbidden",
+  404: "Not Found",
+  405: "Method Not Allowed",
+  409: "Conflict",
+  413: "Content Too Large",
+  415: "Unsupported Media Type",
+  422: "Unprocessable Content",
+  429: "Too Many Requests",
+  500: "Internal Server Error",
+  502: "Bad Gateway",
+  503: "Service Unavailable",
+};
+
+// Routing
+
+interface Segment {
+  kind: "static" | "param" | "wildcard";
+  value: string;
+}
+
+interface Route<State> {
+  method: Method | "*";
+  pattern: string;
+  segments: Segment[];
+  handler: Handler<State>;
+}
+
+function parsePattern(pattern: string): Segment[] {
+  if (!pattern.startsWith("/")) {
+    throw new TypeError(`Route pattern must start with "/": ${pattern}`);
+  }
+  const segments: Segment[] = [];
+  const parts = pattern.split("/").slice(1);
+  for (let i = 0; i < parts.length; i++) {
+    const part = parts[i];
+    if (part === "*") {
+      if (i !== parts.length - 1) {
+        throw new TypeError(`Wildcard must be the last segment: ${pattern}`);
+      }
+      segments.push({ kind: "wildcard", value: "*" });
+    } else if (part.startsWith(":")) {
+      const name = part.slice(1);
+      if (!/^[A-Za-z_][A-Za-z0-9_]*$/.test(name)) {
+        throw new TypeError(`Invalid parameter name "${name}" in ${pattern}`);
+      }
+      segments.push({ kind: "param", value: name });
+    } else {
+      segments.push({ kind: "static", value: decodeURIComponent(part) });
+    }
+  }
+  return segments;
+}
+
+function matchSegments(segments: Segment[], path: string): Params | null {
+  const parts = path.split("/").slice(1);
+  const params: Params = {};
+  for (let i = 0; i < segments.length; i++) {
+    const segment = segments[i];
+    switch (segment.kind) {
+      case "wildcard":
+        params["*"] = parts.slice(i).map(decodeURIComponent).join("/");
+        return params;
+      case "param":
+        if (i >= parts.length || parts[i] === "") {
+          return null;
+        }
+        params[segment.value] = decodeURIComponent(parts[i]);
+        break;
+      case "static":
+        if (parts[i] !== segment.value) {
+          return null;
+        }
+        break;
+    }
+  }
+  return parts.length === segments.length ? params : null;
+}
+
+function specificity(segments: Segment[]): number[] {
+  return segments.map((segment) => {
+    switch (segment.kind) {
+      case "static":
+        return 3;
+      case "param":
+        return 2;
+      case "wildcard":
+        return 1;
+    }
+  });
+}
+
+function compareSpecificity(a: number[], b: number[]): number {
+  for (let i = 0; i < Math.min(a.length, b.length); i++) {
+    if (a[i] !== b[i]) {
+      return b[i] - a[i];
+    }
+  }
+  return b.length - a.length;
+}
+
+export class Router<State = Record<string, unknown>> {
+  #routes: Route<State>[] = [];
+  #middleware: Middleware<State>[] = [];
+  #notFound: Handler<State> = () => new HttpError(404).toResponse();
+  #sorted = true;
+
+  use(middleware: Middleware<State>): this {
+    this.#middleware.push(middleware);
+    return this;
+  }
+
+  on(method: Method | "*", pattern: string, handler: Handler<State>): this {
+    this.#routes.push({
+      method,
+      pattern,
+      segments: parsePattern(pattern),
+      handler,
+    });
+    this.#sorted = false;
+    return this;
+  }
+
+  get(pattern: string, handler: Handler<State>): this {
+    return this.on("GET", pattern, handler);
+  }
+
+  post(pattern: string, handler: Handler<State>): this {
+    return this.on("POST", pattern, handler);
+  }
+
+  put(pattern: string, handler: Handler<State>): this {
+    return this.on("PUT", pattern, handler);
+  }
+
+  patch(pattern: string, handler: Handler<State>): this {
+    return this.on("PATCH", pattern, handler);
+  }
+
+  delete(pattern: string, handler: Handler<State>): this {
+    return this.on("DELETE", pattern, handler);
+  }
+
+  notFound(handler: Handler<State>): this {
+    this.#notFound = handler;
+    return this;
+  }
+
+  #sort() {
+    if (this.#sorted) {
+      return;
+    }
+    this.#routes.sort((a, b) =>
+      compareSpecificity(specificity(a.segments), specificity(b.segments))
+    );
+    this.#sorted = true;
+  }
+
+  #match(method: string, path: string) {
+    this.#sort();
+    const allowed = new Set<string>();
+    for (const route of this.#routes) {
+      const params = matchSegments(route.segments, path);
+      if (params === null) {
+        continue;
+      }
+      if (
+        route.method === "*" || route.method === method ||
+        (method === "HEAD" && route.method === "GET")
+      ) {
+        return { route, params, allowed };
+      }
+      allowed.add(route.method);
+    }
+    return { route: undefined, params: {}, allowed };
+  }
+
+  handler(initialState: () => State): Deno.ServeHandler {
+    return async (request, info) => {
+      const url = new URL(request.url);
+      const { route, params, allowed } = this.#match(
+        request.method,
+        url.pathname,
+      );
+      const ctx: Context<State> = {
+        request,
+        url,
+        params,
+        state: initialState(),
+        remoteAddr: info.remoteAddr,
+      };
+      const endpoint: Handler<State> = route
+        ? route.handler
+        : allowed.size > 0
+        ? () =>
+          new HttpError(405, undefined, {
+            headers: { allow: [...allowed].join(", ") },
+          }).toResponse()
+        : this.#notFound;
+      try {
+        const response = await compose(this.#middleware, endpoint)(ctx);
+        if (request.method === "HEAD") {
+          return new Response(null, response);
+        }
+        return response;
+      } catch (error) {
+        if (error instanceof HttpError) {
+          return error.toResponse();
+        }
+        console.error(error);
+        return new HttpError(500).toResponse();
+      }
+    };
+  }
+}
+
+export function compose<State>(
+  middleware: Middleware<State>[],
+  endpoint: Handler<State>,
+): Handler<State> {
+  return (ctx) => {
+    let index = -1;
+    const dispatch = async (i: number): Promise<Response> => {
+      if (i <= index) {
+        throw new Error("next() called multiple times");
+      }
+      index = i;
+      const fn = middleware[i];
+      if (fn === undefined) {
+        return await endpoint(ctx);
+      }
+      return await fn(ctx, () => dispatch(i + 1));
+    };
+    return dispatch(0);
+  };
+}
+
+// Body parsing
+
+export interface BodyOptions {
+  /** The largest body to accept, in bytes. */
+  limit?: number;
+}
+
+async function readLimited(request: Request, limit: number) {
+  const length = request.headers.get("content-length");
+  if (length !== null && Number(length) > limit) {
+    throw new HttpError(413);
+  }
+  if (request.body === null) {
+    return new Uint8Array();
+  }
+  const chunks: Uint8Array[] = [];
+  let size = 0;
+  for await (const chunk of request.body) {
+    size += chunk.byteLength;
+    if (size > limit) {
+      throw new HttpError(413);
+    }
+    chunks.push(chunk);
+  }
+  const result = new Uint8Array(size);
+  let offset = 0;
+  for (const chunk of chunks) {
+    result.set(chunk, offset);
+    offset += chunk.byteLength;
+  }
+  return result;
+}
+
+export async function readJson<T = unknown>(
+  request: Request,
+  { limit = 1024 * 1024 }: BodyOptions = {},
+): Promise<T> {
+  const type = request.headers.get("content-type") ?? "";
+  if (!/^application\/([\w.+-]+\+)?json\b/.test(type)) {
+    throw new HttpError(415, `Expected JSON, got "${type}"`);
+  }
+  const bytes = await readLimited(request, limit);
+  try {
+    return JSON.parse(new TextDecoder().decode(bytes)) as T;
+  } catch (error) {
+    throw new HttpError(400, `Invalid JSON: ${(error as Error).message}`);
+  }
+}
+
+export async function readForm(
+  request: Request,
+  { limit = 64 * 1024 }: BodyOptions = {},
+): Promise<URLSearchParams> {
+  const type = request.headers.get("content-type") ?? "";
+  if (!type.startsWith("application/x-www-form-urlencoded")) {
+    throw new HttpError(415, `Expected a form, got "${type}"`);
+  }
+  const bytes = await readLimited(request, limit);
+  return new URLSearchParams(new TextDecoder().decode(bytes));
+}
+
+// Validation
+
+export type Issue = { path: (string | number)[]; message: string };
+
+export type Result<T> =
+  | { ok: true; value: T }
+  | { ok: false; issues: Issue[] };
+
+export interface Schema<T> {
+  parse(value: unknown, path?: (string | number)[]): Result<T>;
+  optional(): Schema<T | undefined>;
+}
+
+export type Infer<S> = S extends Schema<infer T> ? T : never;
+
+function schema<T>(
+  parse: (value: unknown, path: (string | number)[]) => Result<T>,
+): Schema<T> {
+  const self: Schema<T> = {
+    parse: (value, path = []) => parse(value, path),
+    optional: () =>
+      schema<T | undefined>((value, path) =>
+        value === undefined ? { ok: true, value } : self.parse(value, path)
+      ),
+  };
+  return self;
+}
+
+function fail(path: (string | number)[], message: string) {
+  return { ok: false as const, issues: [{ path, message }] };
+}
+
+export const v = {
+  string(
+    { min = 0, max = Infinity, pattern }: {
+      min?: number;
+      max?: number;
+      pattern?: RegExp;
+    } = {},
+  ): Schema<string> {
+    return schema((value, path) => {
+      if (typeof value !== "string") {
+        return fail(path, `Expected a string, got ${typeof value}`);
+      }
+      if (value.length < min) {
+        return fail(path, `Must be at least ${min} characters`);
+      }
+      if (value.length > max) {
+        return fail(path, `Must be at most ${max} characters`);
+      }
+      if (pattern && !pattern.test(value)) {
+        return fail(path, `Must match ${pattern}`);
+      }
+      return { ok: true, value };
+    });
+  },
+
+  number(
+    { min = -Infinity, max = Infinity, integer = false }: {
+      min?: number;
+      max?: number;
+      integer?: boolean;
+    } = {},
+  ): Schema<number> {
+    return schema((value, path) => {
+      if (typeof value !== "number" || Number.isNaN(value)) {
+        return fail(path, `Expected a number, got ${typeof value}`);
+      }
+      if (integer && !Number.isInteger(value)) {
+        return fail(path, "Must be an integer");
+      }
+      if (value < min || value > max) {
+        return fail(path, `Must be between ${min} and ${max}`);
+      }
+      return { ok: true, value };
+    });
+  },
+
+  boolean(): Schema<boolean> {
+    return schema((value, path) =>
+      typeof value === "boolean"
+        ? { ok: true, value }
+        : fail(path, `Expected a boolean, got ${typeof value}`)
+    );
+  },
+
+  literal<const T extends string | number | boolean>(
+    expected: T,
+  ): Schema<T> {
+    return schema((value, path) =>
+      value === expected
+        ? { ok: true, value: value as T }
+        : fail(path, `Expected ${JSON.stringify(expected)}`)
+    );
+  },
+
+  union<T extends Schema<unknown>[]>(...options: T): Schema<Infer<T[number]>> {
+    return schema((value, path) => {
+      const issues: Issue[] = [];
+      for (const option of options) {
+        const result = option.parse(value, path);
+        if (result.ok) {
+          return result as Result<Infer<T[number]>>;
+        }
+        issues.push(...result.issues);
+      }
+      return { ok: false, issues };
+    });
+  },
+
+  array<T>(
+    item: Schema<T>,
+    { max = Infinity }: { max?: number } = {},
+  ): Schema<T[]> {
+    return schema((value, path) => {
+      if (!Array.isArray(value)) {
+        return fail(path, "Expected an array");
+      }
+      if (value.length > max) {
+        return fail(path, `Must have at most ${max} items`);
+      }
+      const result: T[] = [];
+      const issues: Issue[] = [];
+      value.forEach((element, index) => {
+        const parsed = item.parse(element, [...path, index]);
+        if (parsed.ok) {
+          result.push(parsed.value);
+        } else {
+          issues.push(...parsed.issues);
+        }
+      });
+      return issues.length > 0
+        ? { ok: false, issues }
+        : { ok: true, value: result };
+    });
+  },
+
+  object<Shape extends Record<string, Schema<unknown>>>(
+    shape: Shape,
+    { strict = false }: { strict?: boolean } = {},
+  ): Schema<{ [K in keyof Shape]: Infer<Shape[K]> }> {
+    type Output = { [K in keyof Shape]: Infer<Shape[K]> };
+    return schema((value, path) => {
+      if (typeof value !== "object" || value === null || Array.isArray(value)) {
+        return fail(path, "Expected an object");
+      }
+      const input = value as Record<string, unknown>;
+      const output: Partial<Output> = {};
+      const issues: Issue[] = [];
+      for (const key of Object.keys(shape) as (keyof Shape & string)[]) {
+        const parsed = shape[key].parse(input[key], [...path, key]);
+        if (parsed.ok) {
+          if (parsed.value !== undefined) {
+            output[key] = parsed.value as Output[typeof key];
+          }
+        } else {
+          issues.push(...parsed.issues);
+        }
+      }
+      if (strict) {
+        for (const key of Object.keys(input)) {
+          if (!Object.hasOwn(shape, key)) {
+            issues.push({ path: [...path, key], message: "Unknown key" });
+          }
+        }
+      }
+      return issues.length > 0
+        ? { ok: false, issues }
+        : { ok: true, value: output as Output };
+    });
+  },
+};
+
+export function validate<T>(schema: Schema<T>, value: unknown): T {
+  const result = schema.parse(value);
+  if (!result.ok) {
+    const message = result.issues
+      .map(({ path, message }) =>
+        path.length > 0 ? `${path.join(".")}: ${message}` : message
+      )
+      .join("\n");
+    throw new HttpError(422, message);
+  }
+  return result.value;
+}
+
+// Caching
+
+interface Entry<V> {
+  value: V;
+  expires: number;
+}
+
+export class LruCache<K, V> {
+  readonly #max: number;
+  readonly #ttl: number;
+  readonly #entries = new Map<K, Entry<V>>();
+  #hits = 0;
+  #misses = 0;
+
+  constructor({ max = 1000, ttl = Infinity }: { max?: number; ttl?: number }) {
+    if (max < 1) {
+      throw new RangeError("max must be at least 1");
+    }
+    this.#max = max;
+    this.#ttl = ttl;
+  }
+
+  get size(): number {
+    return this.#entries.size;
+  }
+
+  get stats() {
+    const total = this.#hits + this.#misses;
+    return {
+      hits: this.#hits,
+      misses: this.#misses,
+      ratio: total === 0 ? 0 : this.#hits / total,
+    };
+  }
+
+  get(key: K): V | undefined {
+    const entry = this.#entries.get(key);
+    if (entry === undefined) {
+      this.#misses++;
+      return undefined;
+    }
+    if (entry.expires <= Date.now()) {
+      this.#entries.delete(key);
+      this.#misses++;
+      return undefined;
+    }
+    // Move the entry to the end so that it's evicted last.
+    this.#entries.delete(key);
+    this.#entries.set(key, entry);
+    this.#hits++;
+    return entry.value;
+  }
+
+  set(key: K, value: V, ttl = this.#ttl): this {
+    this.#entries.delete(key);
+    this.#entries.set(key, { value, expires: Date.now() + ttl });
+    while (this.#entries.size > this.#max) {
+      const oldest = this.#entries.keys().next().value as K;
+      this.#entries.delete(oldest);
+    }
+    return this;
+  }
+
+  delete(key: K): boolean {
+    return this.#entries.delete(key);
+  }
+
+  clear(): void {
+    this.#entries.clear();
+    this.#hits = 0;
+    this.#misses = 0;
+  }
+
+  async getOrSet(key: K, load: () => Promise<V>): Promise<V> {
+    const cached = this.get(key);
+    if (cached !== undefined) {
+      return cached;
+    }
+    const value = await load();
+    this.set(key, value);
+    return value;
+  }
+}
+
+export function etag(body: string | Uint8Array): Promise<string> {
+  const bytes = typeof body === "string" ? new TextEncoder().encode(body) : body;
+  return crypto.subtle.digest("SHA-1", bytes).then((digest) => {
+    const hex = Array.from(new Uint8Array(digest))
+      .slice(0, 10)
+      .map((byte) => byte.toString(16).padStart(2, "0"))
+      .join("");
+    return `W/"${bytes.byteLength.toString(16)}-${hex}"`;
+  });
+}
+
+export function cache<State>(
+  { maxAge = 60, store = new LruCache<string, Response>({ max: 500 }) }: {
+    maxAge?: number;
+    store?: LruCache<string, Response>;
+  } = {},
+): Middleware<State> {
+  return async (ctx, next) => {
+    if (ctx.request.method !== "GET") {
+      return await next();
+    }
+    const key = ctx.url.pathname + ctx.url.search;
+    const cached = store.get(key);
+    if (cached) {
+      const tag = cached.headers.get("etag");
+      if (tag !== null && ctx.request.headers.get("if-none-match") === tag) {
+        return new Response(null, { status: 304, headers: { etag: tag } });
+      }
+      return cached.clone();
+    }
+    const response = await next();
+    if (response.status !== 200 || response.headers.has("set-cookie")) {
+      return response;
+    }
+    const body = new Uint8Array(await response.arrayBuffer());
+    const headers = new Headers(response.headers);
+    headers.set("etag", await etag(body));
+    headers.set("cache-control", `public, max-age=${maxAge}`);
+    const stored = new Response(body, { status: 200, headers });
+    store.set(key, stored.clone(), maxAge * 1000);
+    return stored;
+  };
+}
+
+// Middleware
+
+export function logger<State>(
+  log: (line: string) => void = console.log,
+): Middleware<State> {
+  return async (ctx, next) => {
+    const start = performance.now();
+    let status = 500;
+    try {
+      const response = await next();
+      status = response.status;
+      return response;
+    } finally {
+      const duration = (performance.now() - start).toFixed(1);
+      const addr = ctx.remoteAddr?.hostname ?? "-";
+      log(
+        `${addr} ${ctx.request.method} ${ctx.url.pathname} ${status} ${duration}ms`,
+      );
+    }
+  };
+}
+
+export function cors<State>(
+  { origins = ["*"], methods = ["GET", "POST"], maxAge = 600 }: {
+    origins?: string[];
+    methods?: Method[];
+    maxAge?: number;
+  } = {},
+): Middleware<State> {
+  const allowAll = origins.includes("*");
+  return async (ctx, next) => {
+    const origin = ctx.request.headers.get("origin");
+    if (origin === null || (!allowAll && !origins.includes(origin))) {
+      return await next();
+    }
+    if (ctx.request.method === "OPTIONS") {
+      return new Response(null, {
+        status: 204,
+        headers: {
+          "access-control-allow-origin": allowAll ? "*" : origin,
+          "access-control-allow-methods": methods.join(", "),
+          "access-control-max-age": String(maxAge),
+          "vary": "origin",
+        },
+      });
+    }
+    const response = await next();
+    const headers = new Headers(response.headers);
+    headers.set("access-control-allow-origin", allowAll ? "*" : origin);
+    headers.append("vary", "origin");
+    return new Response(response.body, {
+      status: response.status,
+      statusText: response.statusText,
+      headers,
+    });
+  };
+}
+
+export function rateLimit<State>(
+  { limit = 100, window = 60_000 }: { limit?: number; window?: number } = {},
+): Middleware<State> {
+  const buckets = new Map<string, { count: number; reset: number }>();
+  return async (ctx, next) => {
+    const key = ctx.remoteAddr?.hostname ?? "unknown";
+    const now = Date.now();
+    let bucket = buckets.get(key);
+    if (bucket === undefined || bucket.reset <= now) {
+      bucket = { count: 0, reset: now + window };
+      buckets.set(key, bucket);
+    }
+    bucket.count++;
+    const remaining = Math.max(0, limit - bucket.count);
+    if (bucket.count > limit) {
+      const retryAfter = Math.ceil((bucket.reset - now) / 1000);
+      throw new HttpError(429, undefined, {
+        headers: { "retry-after": String(retryAfter) },
+      });
+    }
+    const response = await next();
+    response.headers.set("x-ratelimit-limit", String(limit));
+    response.headers.set("x-ratelimit-remaining", String(remaining));
+    return response;
+  };
+}
+
+// Server-sent events
+
+export interface ServerSentEvent {
+  id?: string;
+  event?: string;
+  data: unknown;
+  retry?: number;
+}
+
+function encodeEvent(event: ServerSentEvent): string {
+  let text = "";
+  if (event.id !== undefined) {
+    text += `id: ${event.id}\n`;
+  }
+  if (event.event !== undefined) {
+    text += `event: ${event.event}\n`;
+  }
+  if (event.retry !== undefined) {
+    text += `retry: ${event.retry}\n`;
+  }
+  const data = typeof event.data === "string"
+    ? event.data
+    : JSON.stringify(event.data);
+  for (const line of data.split("\n")) {
+    text += `data: ${line}\n`;
+  }
+  return text + "\n";
+}
+
+export class EventStream {
+  #controller!: ReadableStreamDefaultController<Uint8Array>;
+  #encoder = new TextEncoder();
+  #closed = false;
+  #keepAlive: number;
+  readonly stream: ReadableStream<Uint8Array>;
+
+  constructor({ keepAlive = 15_000 }: { keepAlive?: number } = {}) {
+    this.stream = new ReadableStream({
+      start: (controller) => {
+        this.#controller = controller;
+      },
+      cancel: () => this.close(),
+    });
+    this.#keepAlive = setInterval(() => {
+      this.#write(": keep-alive\n\n");
+    }, keepAlive);
+  }
+
+  get closed(): boolean {
+    return this.#closed;
+  }
+
+  #write(text: string) {
+    if (!this.#closed) {
+      this.#controller.enqueue(this.#encoder.encode(text));
+    }
+  }
+
+  send(event: ServerSentEvent): void {
+    this.#write(encodeEvent(event));
+  }
+
+  close(): void {
+    if (this.#closed) {
+      return;
+    }
+    this.#closed = true;
+    clearInterval(this.#keepAlive);
+    try {
+      this.#controller.close();
+    } catch {
+      // The stream was cancelled by the client.
+    }
+  }
+
+  toResponse(): Response {
+    return new Response(this.stream, {
+      headers: {
+        "content-type": "text/event-stream",
+        "cache-control": "no-cache",
+        "connection": "keep-alive",
+      },
+    });
+  }
+}
+
+export class Broadcaster<T> {
+  #streams = new Set<EventStream>();
+  #nextId = 0;
+
+  get listeners(): number {
+    return this.#streams.size;
+  }
+
+  subscribe(): Response {
+    const stream = new EventStream();
+    this.#streams.add(stream);
+    stream.stream.pipeTo(new WritableStream()).catch(() => {}).finally(() => {
+      this.#streams.delete(stream);
+    });
+    return stream.toResponse();
+  }
+
+  publish(event: string, data: T): void {
+    const id = String(this.#nextId++);
+    for (const stream of this.#streams) {
+      if (stream.closed) {
+        this.#streams.delete(stream);
+      } else {
+        stream.send({ id, event, data });
+      }
+    }
+  }
+}
+
+// An example application
+
+const Todo = v.object({
+  title: v.string({ min: 1, max: 200 }),
+  done: v.boolean().optional(),
+  tags: v.array(v.string({ max: 20 }), { max: 10 }).optional(),
+  priority: v.union(v.literal("low"), v.literal("high")).optional(),
+}, { strict: true });
+
+type Todo = Infer<typeof Todo> & { id: string; created: number };
+
+interface AppState {
+  user?: string;
+}
+
+const todos = new Map<string, Todo>();
+const events = new Broadcaster<Todo | { id: string }>();
+
+function json(body: unknown, init: ResponseInit = {}): Response {
+  const headers = new Headers(init.headers);
+  headers.set("content-type", "application/json; charset=utf-8");
+  return new Response(JSON.stringify(body), { ...init, headers });
+}
+
+function findTodo(id: string): Todo {
+  const todo = todos.get(id);
+  if (todo === undefined) {
+    throw new HttpError(404, `No todo with id ${id}`);
+  }
+  return todo;
+}
+
+const auth: Middleware<AppState> = async (ctx, next) => {
+  const header = ctx.request.headers.get("authorization");
+  if (header?.startsWith("Bearer ")) {
+    ctx.state.user = header.slice("Bearer ".length);
+  } else if (ctx.request.method !== "GET") {
+    throw new HttpError(401, undefined, {
+      headers: { "www-authenticate": "Bearer" },
+    });
+  }
+  return await next();
+};
+
+export const app = new Router<AppState>()
+  .use(logger())
+  .use(cors({ methods: ["GET", "POST", "PATCH", "DELETE"] }))
+  .use(rateLimit({ limit: 300 }))
+  .use(auth)
+  .get("/", () => new Response("ok"))
+  .get("/todos", ({ url }) => {
+    const tag = url.searchParams.get("tag");
+    const done = url.searchParams.get("done");
+    let result = [...todos.values()];
+    if (tag !== null) {
+      result = result.filter((todo) => todo.tags?.includes(tag));
+    }
+    if (done !== null) {
+      result = result.filter((todo) => String(todo.done ?? false) === done);
+    }
+    result.sort((a, b) => b.created - a.created);
+    return json(result);
+  })
+  .get("/todos/events", () => events.subscribe())
+  .get("/todos/:id", ({ params }) => json(findTodo(params.id)))
+  .post("/todos", async ({ request }) => {
+    const input = validate(Todo, await readJson(request));
+    const todo: Todo = {
+      ...input,
+      id: crypto.randomUUID(),
+      created: Date.now(),
+    };
+    todos.set(todo.id, todo);
+    events.publish("created", todo);
+    return json(todo, {
+      status: 201,
+      headers: { location: `/todos/${todo.id}` },
+    });
+  })
+  .patch("/todos/:id", async ({ request, params }) => {
+    const todo = findTodo(params.id);
+    const body = await readJson<Record<string, unknown>>(request);
+    const input = validate(Todo, { title: todo.title, ...body });
+    const updated = { ...todo, ...input };
+    todos.set(todo.id, updated);
+    events.publish("updated", updated);
+    return json(updated);
+  })
+  .delete("/todos/:id", ({ params }) => {
+    findTodo(params.id);
+    todos.delete(params.id);
+    events.publish("deleted", { id: params.id });
+    return new Response(null, { status: 204 });
+  })
+  .get("/static/*", async ({ params }) => {
+    const path = params["*"];
+    if (path.split("/").some((part) => part === ".." || part === "")) {
+      throw new HttpError(400, "Invalid path");
+    }
+    try {
+      const file = await Deno.open(`./static/${path}`, { read: true });
+      return new Response(file.readable);
+    } catch (error) {
+      if (error instanceof Deno.errors.NotFound) {
+        throw new HttpError(404);
+      }
+      throw error;
+    }
+  });
+
+if (import.meta.main) {
+  const port = Number(Deno.env.get("PORT") ?? 8000);
+  Deno.serve({ port }, app.handler(() => ({})));
+}
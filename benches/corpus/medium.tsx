@@ -0,0 +1,349 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+/** @jsxImportSource preact */
+
+import { useCallback, useEffect, useMemo, useReducer, useRef } from "preact/hooks";
+import type { ComponentChildren, JSX } from "preact";
+
+export type SortDirection = "asc" | "desc";
+
+export interface Column<Row> {
+  key: keyof Row & string;
+  title: string;
+  width?: number;
+  sortable?: boolean;
+  render?: (row: Row) => ComponentChildren;
+  compare?: (a: Row, b: Row) => number;
+}
+
+export interface DataTableProps<Row> {
+  rows: Row[];
+  columns: Column<Row>[];
+  rowKey: (row: Row) => string;
+  pageSize?: number;
+  selectable?: boolean;
+  emptyMessage?: string;
+  onSelectionChange?: (keys: Set<string>) => void;
+  onRowClick?: (row: Row) => void;
+}
+
+interface State {
+  sortKey: string | null;
+  direction: SortDirection;
+  page: number;
+  filter: string;
+  selected: Set<string>;
+}
+
+type Action =
+  | { type: "sort"; key: string }
+  | { type: "page"; page: number }
+  | { type: "filter"; filter: string }
+  | { type: "toggle"; key: string }
+  | { type: "toggleAll"; keys: string[] }
+  | { type: "clearSelection" };
+
+function reducer(state: State, action: Action): State {
+  switch (action.type) {
+    case "sort": {
+      if (state.sortKey === action.key) {
+        return {
+          ...state,
+          direction: state.direction === "asc" ? "desc" : "asc",
+        };
+      }
+      return { ...state, sortKey: action.key, direction: "asc", page: 0 };
+    }
+    case "page":
+      return { ...state, page: action.page };
+    case "filter":
+      return { ...state, filter: action.filter, page: 0 };
+    case "toggle": {
+      const selected = new Set(state.selected);
+      if (selected.has(action.key)) {
+        selected.delete(action.key);
+      } else {
+        selected.add(action.key);
+      }
+      return { ...state, selected };
+    }
+    case "toggleAll": {
+      const allSelected = action.keys.every((key) => state.selected.has(key));
+      const selected = new Set(state.selected);
+      for (const key of action.keys) {
+        if (allSelected) {
+          selected.delete(key);
+        } else {
+          selected.add(key);
+        }
+      }
+      return { ...state, selected };
+    }
+    case "clearSelection":
+      return { ...state, selected: new Set() };
+    default:
+      return state;
+  }
+}
+
+function defaultCompare(a: unknown, b: unknown): number {
+  if (typeof a === "number" && typeof b === "number") {
+    return a - b;
+  }
+  if (a instanceof Date && b instanceof Date) {
+    return a.getTime() - b.getTime();
+  }
+  return String(a ?? "").localeCompare(String(b ?? ""), undefined, {
+    numeric: true,
+    sensitivity: "base",
+  });
+}
+
+function matches<Row>(row: Row, columns: Column<Row>[], filter: string) {
+  if (filter === "") {
+    return true;
+  }
+  const needle = filter.toLowerCase();
+  return columns.some((column) => {
+    const value = row[column.key];
+    return value != null && String(value).toLowerCase().includes(needle);
+  });
+}
+
+function SortIndicator(
+  { active, direction }: { active: boolean; direction: SortDirection },
+) {
+  if (!active) {
+    return <span class="sort-indicator" aria-hidden="true">↕</span>;
+  }
+  return (
+    <span class="sort-indicator active" aria-hidden="true">
+      {direction === "asc" ? "↑" : "↓"}
+    </span>
+  );
+}
+
+function Pagination(
+  { page, pages, onChange }: {
+    page: number;
+    pages: number;
+    onChange: (page: number) => void;
+  },
+) {
+  if (pages <= 1) {
+    return null;
+  }
+  const numbers: number[] = [];
+  const first = Math.max(0, Math.min(page - 2, pages - 5));
+  for (let i = first; i < Math.min(pages, first + 5); i++) {
+    numbers.push(i);
+  }
+  return (
+    <nav class="pagination" aria-label="Pages">
+      <button
+        type="button"
+        disabled={page === 0}
+        onClick={() => onChange(page - 1)}
+      >
+        Previous
+      </button>
+      {numbers.map((number) => (
+        <button
+          key={number}
+          type="button"
+          class={number === page ? "current" : undefined}
+          aria-current={number === page ? "page" : undefined}
+          onClick={() => onChange(number)}
+        >
+          {number + 1}
+        </button>
+      ))}
+      <button
+        type="button"
+        disabled={page === pages - 1}
+        onClick={() => onChange(page + 1)}
+      >
+        Next
+      </button>
+    </nav>
+  );
+}
+
+export function DataTable<Row>(props: DataTableProps<Row>) {
+  const {
+    rows,
+    columns,
+    rowKey,
+    pageSize = 25,
+    selectable = false,
+    emptyMessage = "No results",
+    onSelectionChange,
+    onRowClick,
+  } = props;
+  const [state, dispatch] = useReducer(reducer, {
+    sortKey: null,
+    direction: "asc",
+    page: 0,
+    filter: "",
+    selected: new Set<string>(),
+  });
+  const filterInput = useRef<HTMLInputElement>(null);
+
+  const filtered = useMemo(
+    () => rows.filter((row) => matches(row, columns, state.filter)),
+    [rows, columns, state.filter],
+  );
+
+  const sorted = useMemo(() => {
+    const column = columns.find((column) => column.key === state.sortKey);
+    if (!column) {
+      return filtered;
+    }
+    const compare = column.compare ??
+      ((a: Row, b: Row) => defaultCompare(a[column.key], b[column.key]));
+    const result = [...filtered].sort(compare);
+    return state.direction === "asc" ? result : result.reverse();
+  }, [filtered, columns, state.sortKey, state.direction]);
+
+  const pages = Math.ceil(sorted.length / pageSize);
+  const visible = sorted.slice(
+    state.page * pageSize,
+    (state.page + 1) * pageSize,
+  );
+  const visibleKeys = visible.map(rowKey);
+  const allVisibleSelected = visibleKeys.length > 0 &&
+    visibleKeys.every((key) => state.selected.has(key));
+
+  useEffect(() => {
+    onSelectionChange?.(state.selected);
+  }, [state.selected, onSelectionChange]);
+
+  useEffect(() => {
+    function onKeyDown(event: KeyboardEvent) {
+      if (event.key === "/" && document.activeElement === document.body) {
+        event.preventDefault();
+        filterInput.current?.focus();
+      } else if (event.key === "Escape") {
+        dispatch({ type: "clearSelection" });
+      }
+    }
+    addEventListener("keydown", onKeyDown);
+    return () => removeEventListener("keydown", onKeyDown);
+  }, []);
+
+  const onFilterInput = useCallback(
+    (event: JSX.TargetedEvent<HTMLInputElement>) => {
+      dispatch({ type: "filter", filter: event.currentTarget.value });
+    },
+    [],
+  );
+
+  return (
+    <div class="data-table">
+      <div class="toolbar">
+        <input
+          ref={filterInput}
+          type="search"
+          placeholder="Filter…"
+          value={state.filter}
+          onInput={onFilterInput}
+        />
+        {selectable && state.selected.size > 0 && (
+          <span class="selection-count">
+            {state.selected.size} selected
+            <button
+              type="button"
+              onClick={() => dispatch({ type: "clearSelection" })}
+            >
+              Clear
+            </button>
+          </span>
+        )}
+      </div>
+      <table>
+        <thead>
+          <tr>
+            {selectable && (
+              <th class="select">
+                <input
+                  type="checkbox"
+                  checked={allVisibleSelected}
+                  aria-label="Select all"
+                  onChange={() =>
+                    dispatch({ type: "toggleAll", keys: visibleKeys })}
+                />
+              </th>
+            )}
+            {columns.map((column) => (
+              <th
+                key={column.key}
+                style={column.width ? { width: `${column.width}px` } : {}}
+                aria-sort={state.sortKey === column.key
+                  ? state.direction === "asc" ? "ascending" : "descending"
+                  : "none"}
+              >
+                {column.sortable
+                  ? (
+                    <button
+                      type="button"
+                      onClick={() => dispatch({ type: "sort", key: column.key })}
+                    >
+                      {column.title}
+                      <SortIndicator
+                        active={state.sortKey === column.key}
+                        direction={state.direction}
+                      />
+                    </button>
+                  )
+                  : column.title}
+              </th>
+            ))}
+          </tr>
+        </thead>
+        <tbody>
+          {visible.length === 0
+            ? (
+              <tr>
+                <td colSpan={columns.length + (selectable ? 1 : 0)}>
+                  {emptyMessage}
+                </td>
+              </tr>
+            )
+            : visible.map((row, index) => {
+              const key = visibleKeys[index];
+              return (
+                <tr
+                  key={key}
+                  class={state.selected.has(key) ? "selected" : undefined}
+                  onClick={() => onRowClick?.(row)}
+                >
+                  {selectable && (
+                    <td class="select">
+                      <input
+                        type="checkbox"
+                        checked={state.selected.has(key)}
+                        onClick={(event) => event.stopPropagation()}
+                        onChange={() => dispatch({ type: "toggle", key })}
+                      />
+                    </td>
+                  )}
+                  {columns.map((column) => (
+                    <td key={column.key}>
+                      {column.render
+                        ? column.render(row)
+                        : String(row[column.key] ?? "")}
+                    </td>
+                  ))}
+                </tr>
+              );
+            })}
+        </tbody>
+      </table>
+      <Pagination
+        page={state.page}
+        pages={pages}
+        onChange={(page) => dispatch({ type: "page", page })}
+      />
+    </div>
+  );
+}
@@ -0,0 +1,77 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+export interface RetryOptions {
+  /** How many times to try, including the first attempt. */
+  attempts?: number;
+  /** The delay before the first retry, in milliseconds. */
+  delay?: number;
+  /** How much the delay grows after every retry. */
+  multiplier?: number;
+  signal?: AbortSignal;
+}
+
+export class RetryError extends Error {
+  constructor(readonly attempts: number, readonly cause: unknown) {
+    super(`Failed after ${attempts} attempts`);
+    this.name = "RetryError";
+  }
+}
+
+function sleep(ms: number, signal?: AbortSignal): Promise<void> {
+  return new Promise((resolve, reject) => {
+    if (signal?.aborted) {
+      reject(signal.reason);
+      return;
+    }
+    const id = setTimeout(() => {
+      signal?.removeEventListener("abort", abort);
+      resolve();
+    }, ms);
+    function abort() {
+      clearTimeout(id);
+      reject(signal!.reason);
+    }
+    signal?.addEventListener("abort", abort, { once: true });
+  });
+}
+
+export async function retry<T>(
+  fn: (attempt: number) => Promise<T>,
+  options: RetryOptions = {},
+): Promise<T> {
+  const { attempts = 3, delay = 100, multiplier = 2, signal } = options;
+  let wait = delay;
+  let lastError: unknown;
+  for (let attempt = 1; attempt <= attempts; attempt++) {
+    try {
+      return await fn(attempt);
+    } catch (error) {
+      lastError = error;
+      if (attempt === attempts) {
+        break;
+      }
+      await sleep(wait, signal);
+      wait *= multiplier;
+    }
+  }
+  throw new RetryError(attempts, lastError);
+}
+
+export function debounce<A extends unknown[]>(
+  fn: (...args: A) => void,
+  ms: number,
+): ((...args: A) => void) & { clear(): void } {
+  let timeout: number | undefined;
+  const debounced = (...args: A) => {
+    clearTimeout(timeout);
+    timeout = setTimeout(() => {
+      timeout = undefined;
+      fn(...args);
+    }, ms);
+  };
+  debounced.clear = () => {
+    clearTimeout(timeout);
+    timeout = undefined;
+  };
+  return debounced;
+}
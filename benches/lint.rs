@@ -0,0 +1,105 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+//! Benchmarks of linting the files in `benches/corpus`.
+//!
+//! The `corpus` group lints each file from source with the recommended rules
+//! and with all rules, so it includes parsing. The `rules` group lints the
+//! already parsed large file with one rule at a time; the `(none)` benchmark
+//! in it lints with no rules at all, which is the cost of setting up the
+//! context of a file that every other benchmark in the group includes.
+//!
+//! Run with `cargo bench --bench lint`. To check a change, save a baseline
+//! before it with `-- --save-baseline main` and compare against it after the
+//! change with `-- --baseline main`.
+
+use criterion::{
+  criterion_group, criterion_main, BenchmarkId, Criterion, Throughput,
+};
+use deno_ast::{MediaType, ModuleSpecifier, ParsedSource};
+use deno_lint::linter::{LintConfig, LintFileOptions, Linter, LinterOptions};
+use deno_lint::rules::{get_all_rules, recommended_rules, LintRule};
+use std::time::Duration;
+
+const CORPUS: &[(&str, &str)] = &[
+  ("small.ts", include_str!("corpus/small.ts")),
+  ("medium.tsx", include_str!("corpus/medium.tsx")),
+  ("large.ts", include_str!("corpus/large.ts")),
+];
+
+fn linter(rules: Vec<Box<dyn LintRule>>) -> Linter {
+  Linter::new(LinterOptions {
+    all_rule_codes: get_all_rules().iter().map(|rule| rule.code()).collect(),
+    rules,
+    custom_ignore_diagnostic_directive: None,
+    custom_ignore_file_directive: None,
+    resolver: None,
+    type_info: None,
+  })
+}
+
+fn config() -> LintConfig {
+  LintConfig {
+    default_jsx_factory: Some("React.createElement".to_string()),
+    default_jsx_fragment_factory: Some("React.Fragment".to_string()),
+  }
+}
+
+fn lint_file(linter: &Linter, name: &str, source: &str) -> ParsedSource {
+  let specifier =
+    ModuleSpecifier::parse(&format!("file:///corpus/{}", name)).unwrap();
+  let (parsed_source, _) = linter
+    .lint_file(LintFileOptions {
+      media_type: MediaType::from_specifier(&specifier),
+      specifier,
+      source_code: source.to_string(),
+      config: config(),
+    })
+    .unwrap_or_else(|err| panic!("{} doesn't parse: {}", name, err));
+  parsed_source
+}
+
+fn corpus(c: &mut Criterion) {
+  let mut group = c.benchmark_group("corpus");
+  let linters = [
+    ("recommended", linter(recommended_rules(get_all_rules()))),
+    ("all", linter(get_all_rules())),
+  ];
+  for (name, source) in CORPUS {
+    group.throughput(Throughput::Bytes(source.len() as u64));
+    for (rules, linter) in &linters {
+      group.bench_with_input(BenchmarkId::new(*rules, name), source, |b, s| {
+        b.iter(|| lint_file(linter, name, s))
+      });
+    }
+  }
+  group.finish();
+}
+
+fn rules(c: &mut Criterion) {
+  let (name, source) = CORPUS[CORPUS.len() - 1];
+  let parsed_source = lint_file(&linter(vec![]), name, source);
+
+  // There are many rules, so each gets less time than the defaults.
+  let mut group = c.benchmark_group("rules");
+  group
+    .throughput(Throughput::Bytes(source.len() as u64))
+    .sample_size(20)
+    .warm_up_time(Duration::from_millis(200))
+    .measurement_time(Duration::from_secs(1));
+
+  let none = linter(vec![]);
+  group.bench_function("(none)", |b| {
+    b.iter(|| none.lint_with_ast(&parsed_source, config()))
+  });
+  for rule in get_all_rules() {
+    let code = rule.code();
+    let linter = linter(vec![rule]);
+    group.bench_function(code, |b| {
+      b.iter(|| linter.lint_with_ast(&parsed_source, config()))
+    });
+  }
+  group.finish();
+}
+
+criterion_group!(benches, corpus, rules);
+criterion_main!(benches);
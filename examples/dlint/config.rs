@@ -1,9 +1,9 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
-use anyhow::bail;
 use anyhow::Error as AnyError;
 use deno_lint::rules::get_all_rules;
-use deno_lint::rules::{filtered_rules, LintRule};
+use deno_lint::rules::{filtered_rules, rule_code_for_alias, LintRule};
+use deno_lint::walk::{walk, FileMatcher, WalkOptions};
 use serde::Deserialize;
 use std::path::Path;
 use std::path::PathBuf;
@@ -32,6 +32,14 @@ pub struct Config {
 
 impl Config {
   pub fn get_rules(&self) -> Vec<Box<dyn LintRule>> {
+    for code in self.rules.include.iter().chain(&self.rules.exclude) {
+      if let Some(rule_code) = rule_code_for_alias(code) {
+        eprintln!(
+          "Warning: \"{}\" is a deprecated alias of the \"{}\" rule, use \"{}\" instead",
+          code, rule_code, rule_code
+        );
+      }
+    }
     filtered_rules(
       get_all_rules(),
       Some(self.rules.tags.clone()),
@@ -41,106 +49,32 @@ impl Config {
   }
 
   pub fn get_files(&self) -> Result<Vec<PathBuf>, AnyError> {
-    resolve_file_paths(&self.files)
-  }
-}
-
-pub fn load_from_json(config_path: &Path) -> Result<Config, std::io::Error> {
-  let json_str = std::fs::read_to_string(config_path)?;
-  let config: Config = serde_json::from_str(&json_str)?;
-  Ok(config)
-}
-
-// Ported from dprint
-// https://github.com/dprint/dprint/blob/358c91fbf0a545a0c9736cc496dc1d998028ae65/crates/dprint/src/cli/run_cli.rs#L686-L756
-fn resolve_file_paths(config: &FilesConfig) -> Result<Vec<PathBuf>, AnyError> {
-  let mut file_patterns = get_file_patterns(config);
-  let absolute_paths = take_absolute_paths(&mut file_patterns);
-
-  let cwd = std::env::current_dir()?;
-  let mut file_paths = glob(&cwd, &file_patterns)?;
-  file_paths.extend(absolute_paths);
-  return Ok(file_paths);
-
-  fn get_file_patterns(config: &FilesConfig) -> Vec<String> {
-    let mut file_patterns = Vec::new();
-
-    file_patterns.extend(config.include.clone());
-
-    file_patterns.extend(config.exclude.clone().into_iter().map(|exclude| {
-      if exclude.starts_with('!') {
-        exclude
-      } else {
-        format!("!{}", exclude)
-      }
-    }));
-
-    // glob walker doesn't support having `./` at the front of paths, so just remove them when they appear
-    for file_pattern in file_patterns.iter_mut() {
-      if file_pattern.starts_with("./") {
-        *file_pattern = String::from(&file_pattern[2..]);
-      }
-      if file_pattern.starts_with("!./") {
-        *file_pattern = format!("!{}", &file_pattern[3..]);
-      }
+    // Only the files given on the command line are linted without includes.
+    if self.files.include.is_empty() {
+      return Ok(vec![]);
     }
-
-    file_patterns
+    walk(&std::env::current_dir()?, &self.walk_options())
   }
 
-  fn take_absolute_paths(file_patterns: &mut Vec<String>) -> Vec<PathBuf> {
-    let len = file_patterns.len();
-    let mut file_paths = Vec::new();
-    for i in (0..len).rev() {
-      if is_absolute_path(&file_patterns[i]) {
-        file_paths.push(PathBuf::from(file_patterns.swap_remove(i))); // faster
-      }
-    }
-    file_paths
+  /// Whether the files of the config leave out the path, for files that are
+  /// not on disk like the code from stdin.
+  pub fn excludes(&self, path: &Path, cwd: &Path) -> Result<bool, AnyError> {
+    Ok(!FileMatcher::new(cwd, &self.walk_options())?.matches(path))
   }
 
-  fn is_absolute_path(file_pattern: &str) -> bool {
-    return !has_glob_chars(file_pattern)
-      && PathBuf::from(file_pattern).is_absolute();
-
-    fn has_glob_chars(text: &str) -> bool {
-      for c in text.chars() {
-        match c {
-          '*' | '{' | '}' | '[' | ']' | '!' => return true,
-          _ => {}
-        }
-      }
-
-      false
+  fn walk_options(&self) -> WalkOptions {
+    WalkOptions {
+      include: self.files.include.clone(),
+      exclude: self.files.exclude.clone(),
+      gitignore: true,
     }
   }
 }
 
-// Ported from dprint
-// https://github.com/dprint/dprint/blob/358c91fbf0a545a0c9736cc496dc1d998028ae65/crates/dprint/src/environment/real_environment.rs#L99-L123
-fn glob(
-  base: &Path,
-  file_patterns: &[String],
-) -> Result<Vec<PathBuf>, AnyError> {
-  let base = base.canonicalize()?;
-  let walker = globwalk::GlobWalkerBuilder::from_patterns(base, file_patterns)
-    .follow_links(false)
-    .file_type(globwalk::FileType::FILE)
-    .build();
-  let walker = match walker {
-    Ok(walker) => walker,
-    Err(err) => bail!("Error parsing file patterns: {}", err),
-  };
-
-  let mut file_paths = Vec::new();
-  for result in walker {
-    match result {
-      Ok(result) => file_paths.push(result.into_path()),
-      Err(err) => bail!("Error walking files: {}", err),
-    }
-  }
-
-  Ok(file_paths)
+pub fn load_from_json(config_path: &Path) -> Result<Config, std::io::Error> {
+  let json_str = std::fs::read_to_string(config_path)?;
+  let config: Config = serde_json::from_str(&json_str)?;
+  Ok(config)
 }
 
 #[cfg(test)]
@@ -225,4 +159,25 @@ mod tests {
     };
     assert_eq!(into_codes(config.get_rules()), set![]);
   }
+
+  #[test]
+  fn test_excludes() {
+    let cwd = Path::new("/project");
+    let config = Config {
+      files: FilesConfig {
+        include: svec!["src/", "./main.ts"],
+        exclude: svec!["src/vendor", "**/*.generated.ts"],
+      },
+      ..Default::default()
+    };
+    let excludes = |path: &str| config.excludes(&cwd.join(path), cwd).unwrap();
+    assert!(!excludes("src/app.ts"));
+    assert!(!excludes("main.ts"));
+    assert!(excludes("src/vendor/lib.ts"));
+    assert!(excludes("src/types.generated.ts"));
+    assert!(excludes("other.ts"));
+
+    let config = Config::default();
+    assert!(!config.excludes(&cwd.join("a.ts"), cwd).unwrap());
+  }
 }
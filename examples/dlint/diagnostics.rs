@@ -1,7 +1,10 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
+use ansi_term::{Colour, Style};
 use deno_ast::diagnostics::Diagnostic;
-use deno_lint::diagnostic::LintDiagnostic;
+use deno_ast::{SourcePos, SourceRange, SourceTextInfo};
+use deno_lint::diagnostic::{LintDiagnostic, LintDiagnosticRange};
+use std::fmt;
 
 pub fn display_diagnostics(
   diagnostics: &[LintDiagnostic],
@@ -18,13 +21,12 @@ fn print_compact(diagnostics: &[LintDiagnostic]) {
   for diagnostic in diagnostics {
     match &diagnostic.range {
       Some(range) => {
-        let display_index =
-          range.text_info.line_and_column_display(range.range.start);
+        let start = range.start_position();
         eprintln!(
           "{}: line {}, col {}, Error - {} ({})",
           diagnostic.specifier,
-          display_index.line_number,
-          display_index.column_number,
+          start.line_number,
+          start.column_number,
           diagnostic.details.message,
           diagnostic.details.code
         )
@@ -43,6 +45,160 @@ fn print_compact(diagnostics: &[LintDiagnostic]) {
 
 fn print_pretty(diagnostics: &[LintDiagnostic]) {
   for diagnostic in diagnostics {
-    eprintln!("{}\n", diagnostic.display());
+    match &diagnostic.range {
+      Some(range)
+        if range.start_position().line_number
+          != range.end_position().line_number =>
+      {
+        eprintln!("{}\n", MultilineDisplay { diagnostic, range });
+      }
+      _ => eprintln!("{}\n", diagnostic.display()),
+    }
   }
 }
+
+/// Spans with more lines than this skip the lines before their last one.
+const MAX_SHOWN_LINES: usize = 4;
+
+/// Displays a diagnostic whose range spans multiple lines like `rustc` does,
+/// with a line in the gutter that connects the start and the end of the span,
+/// instead of underlining every line on its own.
+///
+/// ```text
+///   |
+/// 1 | / function* gen() {
+/// 2 | |   return 1;
+/// 3 | | }
+///   | |_^
+/// ```
+struct MultilineDisplay<'a> {
+  diagnostic: &'a LintDiagnostic,
+  range: &'a LintDiagnosticRange,
+}
+
+impl fmt::Display for MultilineDisplay<'_> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let diagnostic = self.diagnostic;
+    let text_info = &self.range.text_info;
+    let start = self.range.start_position();
+    let end = self.range.end_position();
+    let blue = Colour::Fixed(12);
+    let red = Colour::Red.bold();
+    let width = end.line_number.to_string().len();
+    let gutter = |f: &mut fmt::Formatter<'_>, line: Option<usize>| {
+      let number = line.map(|line| line.to_string()).unwrap_or_default();
+      write!(f, "{}", blue.paint(format!("{:>width$} | ", number)))
+    };
+
+    writeln!(
+      f,
+      "{}: {}",
+      red.paint(format!("error[{}]", diagnostic.details.code)),
+      Style::new().bold().paint(&diagnostic.details.message)
+    )?;
+    let path = diagnostic
+      .specifier
+      .to_file_path()
+      .map(|path| path.display().to_string())
+      .unwrap_or_else(|_| diagnostic.specifier.to_string());
+    writeln!(
+      f,
+      "{:width$}{} {}{}",
+      "",
+      blue.paint("-->"),
+      Colour::Cyan.paint(path),
+      Colour::Yellow
+        .paint(format!(":{}:{}", start.line_number, start.column_number)),
+    )?;
+    gutter(f, None)?;
+    writeln!(f)?;
+
+    let start_line = start.line_number - 1;
+    let end_line = end.line_number - 1;
+    let start_line_start = text_info.line_start(start_line);
+    let start_column =
+      display_width(text_info, start_line_start, self.range.range.start);
+    // The gutter line starts right at the span when only whitespace is before
+    // it, and is otherwise connected to the start with an underline.
+    let starts_line = text_info
+      .range_text(&SourceRange::new(start_line_start, self.range.range.start))
+      .trim()
+      .is_empty();
+    for line in start_line..=end_line {
+      let shown = end_line - start_line < MAX_SHOWN_LINES
+        || line < start_line + MAX_SHOWN_LINES - 1
+        || line == end_line;
+      if !shown {
+        if line == start_line + MAX_SHOWN_LINES - 1 {
+          writeln!(f, "{}", blue.paint(format!("{:>width$}", "...")))?;
+        }
+        continue;
+      }
+      gutter(f, Some(line + 1))?;
+      let marker = match (line == start_line, starts_line) {
+        (true, true) => "/ ",
+        (true, false) => "  ",
+        (false, _) => "| ",
+      };
+      writeln!(
+        f,
+        "{}{}",
+        red.paint(marker),
+        text_info.line_text(line).replace('\t', "  ")
+      )?;
+      if line == start_line && !starts_line {
+        gutter(f, None)?;
+        writeln!(
+          f,
+          "{}",
+          red.paint(format!(" {}^", "_".repeat(start_column + 1)))
+        )?;
+      }
+    }
+
+    let end_column = display_width(
+      text_info,
+      text_info.line_start(end_line),
+      self.range.range.end,
+    );
+    gutter(f, None)?;
+    writeln!(
+      f,
+      "{}",
+      red.paint(format!("|{}^", "_".repeat(end_column.max(1))))
+    )?;
+
+    if let Some(hint) = &diagnostic.details.hint {
+      writeln!(
+        f,
+        "{:width$} {} {}: {}",
+        "",
+        blue.paint("="),
+        Style::new().bold().paint("hint"),
+        hint
+      )?;
+    }
+    writeln!(f)?;
+    for info in &diagnostic.details.info {
+      writeln!(f, "  {}: {}", blue.paint("info"), info)?;
+    }
+    if let Some(docs_url) = diagnostic.docs_url() {
+      writeln!(f, "  {}: {}", blue.paint("docs"), docs_url)?;
+    }
+    Ok(())
+  }
+}
+
+/// The width of the text between the positions, with tabs as two spaces like
+/// the snippets of single-line diagnostics.
+fn display_width(
+  text_info: &SourceTextInfo,
+  start: SourcePos,
+  end: SourcePos,
+) -> usize {
+  text_info
+    .range_text(&SourceRange::new(start, end))
+    .chars()
+    .map(|c| if c == '\t' { 2 } else { 1 })
+    .sum()
+}
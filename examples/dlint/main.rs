@@ -8,17 +8,23 @@ use core::panic;
 use deno_ast::diagnostics::Diagnostic;
 use deno_ast::MediaType;
 use deno_ast::ModuleSpecifier;
+use deno_lint::diff::{parse_unified_diff, ChangedLines};
 use deno_lint::linter::LintConfig;
 use deno_lint::linter::LintFileOptions;
 use deno_lint::linter::Linter;
 use deno_lint::linter::LinterOptions;
+use deno_lint::migrate;
 use deno_lint::rules::get_all_rules;
-use deno_lint::rules::{filtered_rules, recommended_rules};
+use deno_lint::rules::get_all_rules_metadata;
+use deno_lint::rules::{filtered_rules, recommended_rules, resolve_rule_code};
+use deno_lint::summary::{LintPolicy, LintSummary};
 use log::debug;
 use rayon::prelude::*;
 use std::collections::BTreeMap;
+use std::collections::HashMap;
 use std::collections::HashSet;
-use std::path::PathBuf;
+use std::io::Read;
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
 
@@ -39,6 +45,15 @@ fn create_cli_app<'a>() -> Command<'a> {
         )
         .arg(Arg::new("json").long("json")),
     )
+    .subcommand(
+      Command::new("migrate")
+        .about("Convert the rules of an ESLint config to a config for dlint")
+        .arg(
+          Arg::new("ESLINTRC")
+            .help("The .eslintrc.json file to convert")
+            .required(true),
+        ),
+    )
     .subcommand(
       Command::new("run")
         .arg(
@@ -57,7 +72,45 @@ fn create_cli_app<'a>() -> Command<'a> {
             .long("config")
             .help("Load config from file")
             .takes_value(true),
-        ).arg(
+        )
+        .arg(
+          Arg::new("STDIN")
+            .long("stdin")
+            .help("Lint the code from stdin instead of files"),
+        )
+        .arg(
+          Arg::new("STDIN_FILENAME")
+            .long("stdin-filename")
+            .help("The path of the file that is read from stdin, which determines its media type and whether the config excludes it")
+            .takes_value(true)
+            .requires("STDIN"),
+        )
+        .arg(
+          Arg::new("WARN")
+            .long("warn")
+            .help("Report the diagnostics of a rule as warnings")
+            .takes_value(true)
+            .multiple_occurrences(true),
+        )
+        .arg(
+          Arg::new("MAX_WARNINGS")
+            .long("max-warnings")
+            .help("Fail if there are more warnings than this")
+            .takes_value(true)
+            .validator(|val: &str| val.parse::<usize>().map(|_| ())),
+        )
+        .arg(
+          Arg::new("DENY_WARNINGS")
+            .long("deny-warnings")
+            .help("Fail if there are any warnings"),
+        )
+        .arg(
+          Arg::new("DIFF_BASE")
+            .long("diff-base")
+            .help("Only report the diagnostics on lines that changed since a git revision")
+            .takes_value(true),
+        )
+        .arg(
           Arg::new("FORMAT")
             .long("format")
             .help("Configure output format")
@@ -72,21 +125,84 @@ fn create_cli_app<'a>() -> Command<'a> {
     )
 }
 
+/// Where the code to lint comes from.
+enum Input {
+  Files(Vec<String>),
+  /// The code from stdin, with the path of the file it's the contents of.
+  Stdin(PathBuf),
+}
+
+/// The lines that changed in each file since the git revision, by the
+/// canonical path of the file.
+fn changed_lines(
+  diff_base: &str,
+) -> Result<HashMap<PathBuf, ChangedLines>, AnyError> {
+  let git = |args: &[&str]| -> Result<String, AnyError> {
+    let output = std::process::Command::new("git").args(args).output()?;
+    if !output.status.success() {
+      bail!(
+        "git {} failed: {}",
+        args.join(" "),
+        String::from_utf8_lossy(&output.stderr).trim()
+      );
+    }
+    Ok(String::from_utf8(output.stdout)?)
+  };
+  let root = PathBuf::from(git(&["rev-parse", "--show-toplevel"])?.trim());
+  let diff = git(&[
+    "diff",
+    "--unified=0",
+    "--no-color",
+    "--no-ext-diff",
+    diff_base,
+  ])?;
+  Ok(
+    parse_unified_diff(&diff)
+      .into_iter()
+      .map(|(path, lines)| (canonicalize(&root.join(path)), lines))
+      .collect(),
+  )
+}
+
+fn canonicalize(path: &Path) -> PathBuf {
+  path.canonicalize().unwrap_or_else(|_| path.to_path_buf())
+}
+
 fn run_linter(
-  paths: Vec<String>,
+  input: Input,
   filter_rule_name: Option<&str>,
   maybe_config: Option<Arc<config::Config>>,
   format: Option<&str>,
+  policy: LintPolicy,
+  diff_base: Option<&str>,
 ) -> Result<(), AnyError> {
   let cwd = std::env::current_dir()?;
-  let mut paths: Vec<PathBuf> =
-    paths.iter().map(|path| cwd.join(path)).collect();
+  let (paths, stdin) = match input {
+    Input::Files(paths) => {
+      let mut paths: Vec<PathBuf> =
+        paths.iter().map(|path| cwd.join(path)).collect();
+      if let Some(config) = maybe_config.clone() {
+        paths.extend(config.get_files()?);
+      }
+      (paths, None)
+    }
+    Input::Stdin(path) => {
+      let path = cwd.join(path);
+      if let Some(config) = &maybe_config {
+        if config.excludes(&path, &cwd)? {
+          debug!("Config excludes {}", path.display());
+          return Ok(());
+        }
+      }
+      let mut source_code = String::new();
+      std::io::stdin().read_to_string(&mut source_code)?;
+      (vec![path], Some(source_code))
+    }
+  };
 
-  if let Some(config) = maybe_config.clone() {
-    paths.extend(config.get_files()?);
-  }
+  let changed_lines = diff_base.map(changed_lines).transpose()?;
 
-  let error_counts = Arc::new(AtomicUsize::new(0));
+  let parse_error_counts = Arc::new(AtomicUsize::new(0));
 
   let all_rules = get_all_rules();
   let all_rule_codes = all_rules
@@ -112,33 +228,39 @@ fn run_linter(
     all_rule_codes,
     custom_ignore_file_directive: None,
     custom_ignore_diagnostic_directive: None,
+    resolver: None,
+    type_info: None,
   });
 
   paths
     .par_iter()
     .try_for_each(|file_path| -> Result<(), AnyError> {
-      let source_code = std::fs::read_to_string(file_path)?;
-
-      let (parsed_source, diagnostics) = linter.lint_file(LintFileOptions {
-        specifier: ModuleSpecifier::from_file_path(file_path).unwrap_or_else(
-          |_| {
-            panic!(
-              "Failed to convert path to module specifier: {}",
-              file_path.display()
-            )
+      let source_code = match &stdin {
+        Some(source_code) => source_code.clone(),
+        None => std::fs::read_to_string(file_path)?,
+      };
+
+      let (parsed_source, mut diagnostics) =
+        linter.lint_file(LintFileOptions {
+          specifier: ModuleSpecifier::from_file_path(file_path).unwrap_or_else(
+            |_| {
+              panic!(
+                "Failed to convert path to module specifier: {}",
+                file_path.display()
+              )
+            },
+          ),
+          source_code,
+          media_type: MediaType::from_path(file_path),
+          config: LintConfig {
+            default_jsx_factory: Some("React.createElement".to_string()),
+            default_jsx_fragment_factory: Some("React.Fragment".to_string()),
           },
-        ),
-        source_code,
-        media_type: MediaType::from_path(file_path),
-        config: LintConfig {
-          default_jsx_factory: Some("React.createElement".to_string()),
-          default_jsx_fragment_factory: Some("React.Fragment".to_string()),
-        },
-      })?;
+        })?;
 
-      let mut number_of_errors = diagnostics.len();
+      let mut number_of_parse_errors = 0;
       if !parsed_source.diagnostics().is_empty() {
-        number_of_errors += parsed_source.diagnostics().to_vec().len();
+        number_of_parse_errors += parsed_source.diagnostics().to_vec().len();
         parsed_source.diagnostics().to_vec().iter().for_each(
           |parsing_diagnostic| {
             eprintln!("{}", parsing_diagnostic.display());
@@ -146,7 +268,14 @@ fn run_linter(
         );
       }
 
-      error_counts.fetch_add(number_of_errors, Ordering::Relaxed);
+      parse_error_counts.fetch_add(number_of_parse_errors, Ordering::Relaxed);
+
+      if let Some(changed_lines) = &changed_lines {
+        diagnostics = match changed_lines.get(&canonicalize(file_path)) {
+          Some(lines) => lines.filter(diagnostics),
+          None => vec![],
+        };
+      }
 
       let mut lock = file_diagnostics.lock().unwrap();
 
@@ -159,14 +288,31 @@ fn run_linter(
     diagnostics::display_diagnostics(d, format);
   }
 
-  let err_count = error_counts.load(Ordering::Relaxed);
-  if err_count > 0 {
-    eprintln!(
+  let mut summary = LintSummary::new(
+    file_diagnostics.lock().unwrap().values().flatten(),
+    &policy,
+  );
+  summary.total.errors += parse_error_counts.load(Ordering::Relaxed);
+  let problems = summary.total.problems();
+  if problems > 0 {
+    eprint!(
       "Found {} problem{}",
-      err_count,
-      if err_count == 1 { "" } else { "s" }
+      problems,
+      if problems == 1 { "" } else { "s" }
     );
-    std::process::exit(1);
+    if summary.total.warnings > 0 {
+      eprint!(
+        " ({} error{}, {} warning{})",
+        summary.total.errors,
+        if summary.total.errors == 1 { "" } else { "s" },
+        summary.total.warnings,
+        if summary.total.warnings == 1 { "" } else { "s" }
+      );
+    }
+    eprintln!();
+  }
+  if summary.fails(&policy) {
+    std::process::exit(summary.exit_code(&policy));
   }
 
   Ok(())
@@ -194,23 +340,43 @@ fn main() -> Result<(), AnyError> {
 
       debug!("Config: {:#?}", maybe_config);
 
-      let paths: Vec<String> = run_matches
-        .values_of("FILES")
-        .unwrap_or_default()
-        .map(|p| p.to_string())
-        .collect();
+      let input = if run_matches.is_present("STDIN") {
+        Input::Stdin(PathBuf::from(
+          run_matches.value_of("STDIN_FILENAME").unwrap_or("stdin.ts"),
+        ))
+      } else {
+        Input::Files(
+          run_matches
+            .values_of("FILES")
+            .unwrap_or_default()
+            .map(|p| p.to_string())
+            .collect(),
+        )
+      };
       run_linter(
-        paths,
+        input,
         run_matches.value_of("RULE_CODE"),
         maybe_config,
         run_matches.value_of("FORMAT"),
+        LintPolicy {
+          warning_rules: run_matches
+            .values_of("WARN")
+            .unwrap_or_default()
+            .map(|code| resolve_rule_code(code).to_string())
+            .collect(),
+          max_warnings: run_matches
+            .value_of("MAX_WARNINGS")
+            .map(|max| max.parse().unwrap()),
+          deny_warnings: run_matches.is_present("DENY_WARNINGS"),
+        },
+        run_matches.value_of("DIFF_BASE"),
       )?;
     }
     Some(("rules", rules_matches)) => {
       let rules = if let Some(rule_name) = rules_matches.value_of("RULE_NAME") {
         rules::get_specific_rule_metadata(rule_name)
       } else {
-        rules::get_all_rules_metadata()
+        get_all_rules_metadata()
       };
       if rules_matches.is_present("json") {
         rules::print_rules::<rules::JsonFormatter>(rules);
@@ -218,6 +384,15 @@ fn main() -> Result<(), AnyError> {
         rules::print_rules::<rules::PrettyFormatter>(rules);
       }
     }
+    Some(("migrate", migrate_matches)) => {
+      let path = migrate_matches.value_of("ESLINTRC").unwrap();
+      let migration = migrate::from_eslint(&std::fs::read_to_string(path)?)?;
+      for name in &migration.unmapped {
+        eprintln!("Warning: no equivalent for \"{}\"", name);
+      }
+      let config = serde_json::json!({ "rules": migration });
+      println!("{}", serde_json::to_string_pretty(&config)?);
+    }
     _ => unreachable!(),
   };
 
@@ -451,4 +626,42 @@ mod tests {
     output: "issue1145_no_trailing_newline.out",
     exit_code: 1,
   });
+
+  itest!(multiline_span {
+    args: "run multiline.ts",
+    output: "multiline.out",
+    exit_code: 1,
+  });
+
+  itest!(stdin {
+    args: "run --stdin --stdin-filename component.tsx",
+    input: Some("var a = <div />;\n"),
+    output: "stdin.out",
+    exit_code: 1,
+  });
+
+  itest!(stdin_excluded_by_config {
+    args: "run --stdin --stdin-filename vendor/a.ts --config stdin_config.json",
+    input: Some("var a = 1;\n"),
+    output_str: Some(""),
+    exit_code: 0,
+  });
+
+  itest!(warnings {
+    args: "run warnings.ts --warn no-var",
+    output: "warnings.out",
+    exit_code: 0,
+  });
+
+  itest!(max_warnings {
+    args: "run warnings.ts --warn no-var --max-warnings 0",
+    output: "warnings.out",
+    exit_code: 1,
+  });
+
+  itest!(migrate_eslintrc {
+    args: "migrate eslintrc.json",
+    output: "migrate.out",
+    exit_code: 0,
+  });
 }
@@ -1,35 +1,16 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use crate::color::colorize_markdown;
-use deno_lint::rules::get_all_rules;
-use serde::Serialize;
+use deno_lint::rules::{get_all_rules_metadata, RuleMetadata};
 
-#[derive(Clone, Copy, Serialize)]
-pub struct Rule {
-  code: &'static str,
-  docs: &'static str,
-  tags: &'static [&'static str],
-}
-
-pub fn get_all_rules_metadata() -> Vec<Rule> {
-  get_all_rules()
-    .iter()
-    .map(|rule| Rule {
-      code: rule.code(),
-      docs: rule.docs(),
-      tags: rule.tags(),
-    })
-    .collect()
-}
-
-pub fn get_specific_rule_metadata(rule_name: &str) -> Vec<Rule> {
+pub fn get_specific_rule_metadata(rule_name: &str) -> Vec<RuleMetadata> {
   get_all_rules_metadata()
     .into_iter()
     .filter(|r| r.code == rule_name)
     .collect()
 }
 
-pub fn print_rules<F: RuleFormatter>(mut rules: Vec<Rule>) {
+pub fn print_rules<F: RuleFormatter>(mut rules: Vec<RuleMetadata>) {
   #[cfg(windows)]
   ansi_term::enable_ansi_support().expect("Failed to enable ANSI support");
 
@@ -47,11 +28,11 @@ pub enum JsonFormatter {}
 pub enum PrettyFormatter {}
 
 pub trait RuleFormatter {
-  fn format(rules: &mut [Rule]) -> Result<String, &'static str>;
+  fn format(rules: &mut [RuleMetadata]) -> Result<String, &'static str>;
 }
 
 impl RuleFormatter for JsonFormatter {
-  fn format(rules: &mut [Rule]) -> Result<String, &'static str> {
+  fn format(rules: &mut [RuleMetadata]) -> Result<String, &'static str> {
     if rules.is_empty() {
       return Err("Rule not found!");
     }
@@ -60,7 +41,7 @@ impl RuleFormatter for JsonFormatter {
 }
 
 impl RuleFormatter for PrettyFormatter {
-  fn format(rules: &mut [Rule]) -> Result<String, &'static str> {
+  fn format(rules: &mut [RuleMetadata]) -> Result<String, &'static str> {
     match rules {
       // Unknown rule name is specified.
       [] => Err("Rule not found!"),
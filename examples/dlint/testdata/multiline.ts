@@ -0,0 +1,12 @@
+function* gen() {
+  return 1;
+}
+
+const fn = function* () {
+  a();
+  b();
+  c();
+  d();
+};
+fn();
+gen();
@@ -0,0 +1 @@
+export var a = 1;
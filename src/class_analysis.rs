@@ -0,0 +1,630 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+//! Summarizes the members of a class, so rules about classes don't each have
+//! to match on every kind of class member.
+
+use deno_ast::swc::ast::{
+  Accessibility, BigInt, Bool, CallExpr, Callee, Class, ClassMember,
+  ComputedPropName, Decorator, Expr, ExprOrSpread, Function, IdentName, Key,
+  Lit, MemberExpr, MemberProp, MethodKind, Null, Number, ParamOrTsParamProp,
+  ParenExpr, Pat, PrivateName, PropName, Str, Super, ThisExpr, Tpl,
+  TsParamProp, TsParamPropParam,
+};
+use deno_ast::swc::visit::{noop_visit_type, Visit, VisitWith};
+use deno_ast::{SourceRange, SourceRangedForSpanned};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MemberKind {
+  Constructor,
+  Method,
+  Getter,
+  Setter,
+  Property,
+  /// A property declared with `accessor`, like `accessor foo = 1;`.
+  AutoAccessor,
+  StaticBlock,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum MemberKey<'a> {
+  Public(&'a PropName),
+  Private(&'a PrivateName),
+}
+
+impl<'a> MemberKey<'a> {
+  fn new(key: &'a Key) -> Self {
+    match key {
+      Key::Public(key) => MemberKey::Public(key),
+      Key::Private(key) => MemberKey::Private(key),
+    }
+  }
+
+  /// The name of the key, with the `#` of private names, or `None` if it's
+  /// computed from an expression that isn't a literal.
+  pub fn name(&self) -> Option<String> {
+    match self {
+      MemberKey::Public(key) => prop_name(key),
+      MemberKey::Private(key) => Some(format!("#{}", key.name)),
+    }
+  }
+
+  pub fn range(&self) -> SourceRange {
+    match self {
+      MemberKey::Public(key) => key.range(),
+      MemberKey::Private(key) => key.range(),
+    }
+  }
+}
+
+/// A member of a class, except for index signatures.
+#[derive(Debug, Clone)]
+pub struct Member<'a> {
+  /// The name of the member, see `MemberKey::name`.
+  pub name: Option<String>,
+  /// The key of the member, which static blocks don't have.
+  pub key: Option<MemberKey<'a>>,
+  pub kind: MemberKind,
+  pub is_static: bool,
+  pub accessibility: Option<Accessibility>,
+  pub is_abstract: bool,
+  pub is_override: bool,
+  pub readonly: bool,
+  pub declare: bool,
+  /// Whether the member has an implementation, which overload signatures
+  /// and abstract methods don't.
+  pub has_body: bool,
+  pub decorators: Vec<DecoratorInfo<'a>>,
+  /// The decorators of each parameter of methods and constructors.
+  pub param_decorators: Vec<Vec<DecoratorInfo<'a>>>,
+  /// The function of methods and accessors.
+  pub function: Option<&'a Function>,
+  /// Whether `this` or `super` is used directly in the member, which
+  /// excludes nested functions and classes that have their own `this`.
+  pub uses_this: bool,
+  pub range: SourceRange,
+}
+
+/// A parameter of the constructor that declares a property, like
+/// `private foo` in `constructor(private foo: string) {}`.
+#[derive(Debug, Clone)]
+pub struct ParamProp<'a> {
+  pub name: String,
+  /// The range of the name.
+  pub range: SourceRange,
+  pub accessibility: Option<Accessibility>,
+  pub readonly: bool,
+  pub decorators: Vec<DecoratorInfo<'a>>,
+  pub param: &'a TsParamProp,
+}
+
+/// A decorator, like `@foo.bar(1)`.
+#[derive(Debug, Clone)]
+pub struct DecoratorInfo<'a> {
+  /// The name of the decorator without its arguments, like `foo.bar`, or
+  /// `None` if it's another kind of expression.
+  pub name: Option<String>,
+  /// The arguments if the decorator is called, which is `None` for `@foo`
+  /// and empty for `@foo()`.
+  pub args: Option<&'a [ExprOrSpread]>,
+  pub decorator: &'a Decorator,
+}
+
+impl<'a> DecoratorInfo<'a> {
+  pub fn new(decorator: &'a Decorator) -> Self {
+    let (callee, args) = match &*decorator.expr {
+      Expr::Call(CallExpr {
+        callee: Callee::Expr(callee),
+        args,
+        ..
+      }) => (&**callee, Some(args.as_slice())),
+      expr => (expr, None),
+    };
+    Self {
+      name: decorator_name(callee),
+      args,
+      decorator,
+    }
+  }
+
+  pub fn range(&self) -> SourceRange {
+    self.decorator.range()
+  }
+}
+
+/// The decorators in the order they're written in.
+pub fn decorators(decorators: &[Decorator]) -> Vec<DecoratorInfo> {
+  decorators.iter().map(DecoratorInfo::new).collect()
+}
+
+fn decorator_name(expr: &Expr) -> Option<String> {
+  match expr {
+    Expr::Ident(ident) => Some(ident.sym.to_string()),
+    Expr::Member(MemberExpr {
+      obj,
+      prop: MemberProp::Ident(prop),
+      ..
+    }) => Some(format!("{}.{}", decorator_name(obj)?, prop.sym)),
+    Expr::Paren(ParenExpr { expr, .. }) => decorator_name(expr),
+    _ => None,
+  }
+}
+
+/// The getter and the setter of an accessor with the same name.
+#[derive(Debug, Clone)]
+pub struct AccessorPair<'a, 'b> {
+  pub name: String,
+  pub is_static: bool,
+  pub getter: Option<&'b Member<'a>>,
+  pub setter: Option<&'b Member<'a>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ClassAnalysis<'a> {
+  /// The decorators of the class itself.
+  pub decorators: Vec<DecoratorInfo<'a>>,
+  /// The members in the order they're declared in.
+  pub members: Vec<Member<'a>>,
+  pub param_props: Vec<ParamProp<'a>>,
+}
+
+impl<'a> ClassAnalysis<'a> {
+  pub fn new(class: &'a Class) -> Self {
+    let mut members = Vec::new();
+    let mut param_props = Vec::new();
+    for member in &class.body {
+      let member = match member {
+        ClassMember::Constructor(constructor) => {
+          param_props.extend(constructor.params.iter().filter_map(param_prop));
+          Member {
+            name: prop_name(&constructor.key),
+            key: Some(MemberKey::Public(&constructor.key)),
+            kind: MemberKind::Constructor,
+            is_static: false,
+            accessibility: constructor.accessibility,
+            is_abstract: false,
+            is_override: false,
+            readonly: false,
+            declare: false,
+            has_body: constructor.body.is_some(),
+            decorators: vec![],
+            param_decorators: constructor
+              .params
+              .iter()
+              .map(|param| match param {
+                ParamOrTsParamProp::Param(param) => {
+                  decorators(&param.decorators)
+                }
+                ParamOrTsParamProp::TsParamProp(param) => {
+                  decorators(&param.decorators)
+                }
+              })
+              .collect(),
+            function: None,
+            uses_this: uses_this(&constructor.body),
+            range: constructor.range(),
+          }
+        }
+        ClassMember::Method(method) => Member {
+          name: prop_name(&method.key),
+          key: Some(MemberKey::Public(&method.key)),
+          kind: method_kind(method.kind),
+          is_static: method.is_static,
+          accessibility: method.accessibility,
+          is_abstract: method.is_abstract,
+          is_override: method.is_override,
+          readonly: false,
+          declare: false,
+          has_body: method.function.body.is_some(),
+          decorators: decorators(&method.function.decorators),
+          param_decorators: method
+            .function
+            .params
+            .iter()
+            .map(|param| decorators(&param.decorators))
+            .collect(),
+          function: Some(&method.function),
+          uses_this: function_uses_this(&method.function),
+          range: method.range(),
+        },
+        ClassMember::PrivateMethod(method) => Member {
+          name: MemberKey::Private(&method.key).name(),
+          key: Some(MemberKey::Private(&method.key)),
+          kind: method_kind(method.kind),
+          is_static: method.is_static,
+          accessibility: method.accessibility,
+          is_abstract: method.is_abstract,
+          is_override: method.is_override,
+          readonly: false,
+          declare: false,
+          has_body: method.function.body.is_some(),
+          decorators: decorators(&method.function.decorators),
+          param_decorators: method
+            .function
+            .params
+            .iter()
+            .map(|param| decorators(&param.decorators))
+            .collect(),
+          function: Some(&method.function),
+          uses_this: function_uses_this(&method.function),
+          range: method.range(),
+        },
+        ClassMember::ClassProp(prop) => Member {
+          name: prop_name(&prop.key),
+          key: Some(MemberKey::Public(&prop.key)),
+          kind: MemberKind::Property,
+          is_static: prop.is_static,
+          accessibility: prop.accessibility,
+          is_abstract: prop.is_abstract,
+          is_override: prop.is_override,
+          readonly: prop.readonly,
+          declare: prop.declare,
+          has_body: prop.value.is_some(),
+          decorators: decorators(&prop.decorators),
+          param_decorators: vec![],
+          function: None,
+          uses_this: uses_this(&prop.value),
+          range: prop.range(),
+        },
+        ClassMember::PrivateProp(prop) => Member {
+          name: MemberKey::Private(&prop.key).name(),
+          key: Some(MemberKey::Private(&prop.key)),
+          kind: MemberKind::Property,
+          is_static: prop.is_static,
+          accessibility: prop.accessibility,
+          is_abstract: false,
+          is_override: prop.is_override,
+          readonly: prop.readonly,
+          declare: false,
+          has_body: prop.value.is_some(),
+          decorators: decorators(&prop.decorators),
+          param_decorators: vec![],
+          function: None,
+          uses_this: uses_this(&prop.value),
+          range: prop.range(),
+        },
+        ClassMember::AutoAccessor(accessor) => {
+          let key = MemberKey::new(&accessor.key);
+          Member {
+            name: key.name(),
+            key: Some(key),
+            kind: MemberKind::AutoAccessor,
+            is_static: accessor.is_static,
+            accessibility: accessor.accessibility,
+            is_abstract: accessor.is_abstract,
+            is_override: accessor.is_override,
+            readonly: false,
+            declare: false,
+            has_body: accessor.value.is_some(),
+            decorators: decorators(&accessor.decorators),
+            param_decorators: vec![],
+            function: None,
+            uses_this: uses_this(&accessor.value),
+            range: accessor.range(),
+          }
+        }
+        ClassMember::StaticBlock(block) => Member {
+          name: None,
+          key: None,
+          kind: MemberKind::StaticBlock,
+          is_static: true,
+          accessibility: None,
+          is_abstract: false,
+          is_override: false,
+          readonly: false,
+          declare: false,
+          has_body: true,
+          decorators: vec![],
+          param_decorators: vec![],
+          function: None,
+          uses_this: uses_this(&block.body),
+          range: block.range(),
+        },
+        ClassMember::TsIndexSignature(_) | ClassMember::Empty(_) => continue,
+      };
+      members.push(member);
+    }
+    Self {
+      decorators: decorators(&class.decorators),
+      members,
+      param_props,
+    }
+  }
+
+  /// The getters and setters grouped by their name and whether they're
+  /// static, in the order of their first accessor.
+  pub fn accessor_pairs(&self) -> Vec<AccessorPair<'a, '_>> {
+    let mut pairs: Vec<AccessorPair> = Vec::new();
+    for member in &self.members {
+      let is_getter = match member.kind {
+        MemberKind::Getter => true,
+        MemberKind::Setter => false,
+        _ => continue,
+      };
+      let Some(name) = &member.name else {
+        continue;
+      };
+      let index = match pairs
+        .iter()
+        .position(|p| &p.name == name && p.is_static == member.is_static)
+      {
+        Some(index) => index,
+        None => {
+          pairs.push(AccessorPair {
+            name: name.clone(),
+            is_static: member.is_static,
+            getter: None,
+            setter: None,
+          });
+          pairs.len() - 1
+        }
+      };
+      let pair = &mut pairs[index];
+      if is_getter {
+        pair.getter.get_or_insert(member);
+      } else {
+        pair.setter.get_or_insert(member);
+      }
+    }
+    pairs
+  }
+}
+
+fn method_kind(kind: MethodKind) -> MemberKind {
+  match kind {
+    MethodKind::Method => MemberKind::Method,
+    MethodKind::Getter => MemberKind::Getter,
+    MethodKind::Setter => MemberKind::Setter,
+  }
+}
+
+fn param_prop(param: &ParamOrTsParamProp) -> Option<ParamProp> {
+  let ParamOrTsParamProp::TsParamProp(param_prop) = param else {
+    return None;
+  };
+  let ident = match &param_prop.param {
+    TsParamPropParam::Ident(binding) => &binding.id,
+    TsParamPropParam::Assign(assign) => match &*assign.left {
+      Pat::Ident(binding) => &binding.id,
+      _ => return None,
+    },
+  };
+  Some(ParamProp {
+    name: ident.sym.to_string(),
+    range: ident.range(),
+    accessibility: param_prop.accessibility,
+    readonly: param_prop.readonly,
+    decorators: decorators(&param_prop.decorators),
+    param: param_prop,
+  })
+}
+
+/// The name of a property key, where keys that are literals are normalized
+/// like `["foo"]` to `foo`, or `None` if it's computed from another
+/// expression.
+pub fn prop_name(name: &PropName) -> Option<String> {
+  let normalized = match *name {
+    PropName::Ident(IdentName { ref sym, .. }) => sym.to_string(),
+    PropName::Str(Str { ref value, .. }) => value.to_string(),
+    PropName::Num(Number { ref value, .. }) => value.to_string(),
+    PropName::BigInt(BigInt { ref value, .. }) => value.to_string(),
+    PropName::Computed(ComputedPropName { ref expr, .. }) => match &**expr {
+      Expr::Lit(Lit::Str(Str { ref value, .. })) => value.to_string(),
+      Expr::Lit(Lit::Bool(Bool { ref value, .. })) => value.to_string(),
+      Expr::Lit(Lit::Null(Null { .. })) => "null".to_string(),
+      Expr::Lit(Lit::Num(Number { ref value, .. })) => value.to_string(),
+      Expr::Lit(Lit::BigInt(BigInt { ref value, .. })) => value.to_string(),
+      Expr::Tpl(Tpl {
+        ref quasis,
+        ref exprs,
+        ..
+      }) if exprs.is_empty() => {
+        quasis.iter().next().map(|q| q.raw.to_string())?
+      }
+      _ => return None,
+    },
+  };
+
+  Some(normalized)
+}
+
+fn function_uses_this(function: &Function) -> bool {
+  uses_this(&function.params) || uses_this(&function.body)
+}
+
+/// Whether `this` or `super` is used in the node, outside of nested
+/// functions and classes.
+pub fn uses_this<N: VisitWith<ThisFinder>>(node: &N) -> bool {
+  let mut finder = ThisFinder(false);
+  node.visit_with(&mut finder);
+  finder.0
+}
+
+pub struct ThisFinder(bool);
+
+impl Visit for ThisFinder {
+  noop_visit_type!();
+
+  fn visit_this_expr(&mut self, _: &ThisExpr) {
+    self.0 = true;
+  }
+
+  fn visit_super(&mut self, _: &Super) {
+    self.0 = true;
+  }
+
+  // Functions have their own `this`, unlike arrow functions.
+  fn visit_function(&mut self, _: &Function) {}
+
+  fn visit_class(&mut self, class: &Class) {
+    // Only the heritage of a nested class is evaluated with the outer
+    // `this`.
+    class.super_class.visit_with(self);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::parse;
+  use deno_ast::swc::ast::{Decl, Program, Stmt};
+
+  fn with_class(source: &str, f: impl FnOnce(ClassAnalysis)) {
+    let parsed_source = parse(source);
+    let stmt = match parsed_source.program_ref() {
+      Program::Module(module) => module.body[0].as_stmt().unwrap(),
+      Program::Script(script) => &script.body[0],
+    };
+    let Stmt::Decl(Decl::Class(decl)) = stmt else {
+      panic!("expected a class declaration");
+    };
+    let class = &decl.class;
+    f(ClassAnalysis::new(class))
+  }
+
+  #[test]
+  fn class_members() {
+    with_class(
+      r#"class Foo extends Bar {
+  static #count = 0;
+  ["name"] = "foo";
+  constructor(private readonly a: string, public b = 1, c: number) {
+    super();
+  }
+  get value() { return this.a; }
+  set value(v) {}
+  static get value() { return 1; }
+  helper() { return function () { return this; }; }
+  arrow() { return () => this.b; }
+  [Symbol.iterator]() {}
+  overload(): void;
+  overload() {}
+  static { Foo.#count++; }
+}"#,
+      |analysis| {
+        let members: Vec<_> = analysis
+          .members
+          .iter()
+          .map(|m| (m.name.as_deref(), m.kind, m.is_static, m.uses_this))
+          .collect();
+        assert_eq!(
+          members,
+          vec![
+            (Some("#count"), MemberKind::Property, true, false),
+            (Some("name"), MemberKind::Property, false, false),
+            (Some("constructor"), MemberKind::Constructor, false, true),
+            (Some("value"), MemberKind::Getter, false, true),
+            (Some("value"), MemberKind::Setter, false, false),
+            (Some("value"), MemberKind::Getter, true, false),
+            (Some("helper"), MemberKind::Method, false, false),
+            (Some("arrow"), MemberKind::Method, false, true),
+            (None, MemberKind::Method, false, false),
+            (Some("overload"), MemberKind::Method, false, false),
+            (Some("overload"), MemberKind::Method, false, false),
+            (None, MemberKind::StaticBlock, true, false),
+          ]
+        );
+        assert!(!analysis.members[9].has_body);
+        assert!(analysis.members[10].has_body);
+
+        let param_props: Vec<_> = analysis
+          .param_props
+          .iter()
+          .map(|p| (p.name.as_str(), p.accessibility, p.readonly))
+          .collect();
+        assert_eq!(
+          param_props,
+          vec![
+            ("a", Some(Accessibility::Private), true),
+            ("b", Some(Accessibility::Public), false),
+          ]
+        );
+      },
+    );
+  }
+
+  #[test]
+  fn class_decorators() {
+    with_class(
+      r#"@Component({ selector: "app" })
+class Foo {
+  @Input() @(decorators.track) name = "";
+  @HostListener("click", ["$event"])
+  onClick(@Inject(TOKEN) event: Event, other: number) {}
+  constructor(@Optional private readonly a: string) {}
+  plain() {}
+}"#,
+      |analysis| {
+        let names = |decorators: &[DecoratorInfo]| -> Vec<_> {
+          decorators
+            .iter()
+            .map(|d| (d.name.clone(), d.args.map(|args| args.len())))
+            .collect()
+        };
+        assert_eq!(
+          names(&analysis.decorators),
+          vec![(Some("Component".to_string()), Some(1))]
+        );
+        assert_eq!(
+          names(&analysis.members[0].decorators),
+          vec![
+            (Some("Input".to_string()), Some(0)),
+            (Some("decorators.track".to_string()), None),
+          ]
+        );
+        assert_eq!(
+          names(&analysis.members[1].decorators),
+          vec![(Some("HostListener".to_string()), Some(2))]
+        );
+        assert_eq!(analysis.members[1].param_decorators.len(), 2);
+        assert_eq!(
+          names(&analysis.members[1].param_decorators[0]),
+          vec![(Some("Inject".to_string()), Some(1))]
+        );
+        assert!(analysis.members[1].param_decorators[1].is_empty());
+        assert_eq!(
+          names(&analysis.members[2].param_decorators[0]),
+          vec![(Some("Optional".to_string()), None)]
+        );
+        assert_eq!(
+          names(&analysis.param_props[0].decorators),
+          vec![(Some("Optional".to_string()), None)]
+        );
+        assert!(analysis.members[3].decorators.is_empty());
+      },
+    );
+  }
+
+  #[test]
+  fn accessor_pairs() {
+    with_class(
+      "class Foo {
+  get a() { return 1; }
+  set b(v) {}
+  set a(v) {}
+  static set a(v) {}
+}",
+      |analysis| {
+        let pairs: Vec<_> = analysis
+          .accessor_pairs()
+          .iter()
+          .map(|p| {
+            (
+              p.name.clone(),
+              p.is_static,
+              p.getter.is_some(),
+              p.setter.is_some(),
+            )
+          })
+          .collect();
+        assert_eq!(
+          pairs,
+          vec![
+            ("a".to_string(), false, true, true),
+            ("b".to_string(), false, false, true),
+            ("a".to_string(), true, false, true),
+          ]
+        );
+      },
+    );
+  }
+}
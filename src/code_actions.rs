@@ -0,0 +1,224 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+//! Turns the fixes of diagnostics into the shape of LSP code actions, so
+//! editors can serialize them as they are.
+
+use crate::diagnostic::{LintDiagnostic, LintFix};
+use crate::fixes::overlaps;
+use deno_ast::{SourcePos, SourceRange, SourceTextInfo};
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum CodeActionKind {
+  #[serde(rename = "quickfix")]
+  QuickFix,
+  /// Applies all the fixes of a file.
+  #[serde(rename = "source.fixAll.deno-lint")]
+  FixAll,
+}
+
+/// An LSP `CodeAction`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CodeAction {
+  pub title: String,
+  pub kind: CodeActionKind,
+  #[serde(skip_serializing_if = "std::ops::Not::not")]
+  pub is_preferred: bool,
+  pub edit: WorkspaceEdit,
+}
+
+/// An LSP `WorkspaceEdit`, with the edits of each file by its URL.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct WorkspaceEdit {
+  pub changes: BTreeMap<String, Vec<TextEdit>>,
+}
+
+/// An LSP `TextEdit`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TextEdit {
+  pub range: Range,
+  pub new_text: String,
+}
+
+/// An LSP `Range`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Range {
+  pub start: Position,
+  pub end: Position,
+}
+
+/// An LSP `Position`, with the 0-indexed line and the 0-indexed column in
+/// UTF-16 code units.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Position {
+  pub line: usize,
+  pub character: usize,
+}
+
+impl Position {
+  fn new(text_info: &SourceTextInfo, pos: SourcePos) -> Self {
+    let line = text_info.line_index(pos);
+    let line_start = text_info.line_start(line);
+    let character = text_info
+      .range_text(&SourceRange::new(line_start, pos))
+      .encode_utf16()
+      .count();
+    Self { line, character }
+  }
+}
+
+impl LintDiagnostic {
+  /// A quick fix for each fix of the diagnostic, where the first one is
+  /// preferred.
+  pub fn code_actions(&self) -> Vec<CodeAction> {
+    let Some(range) = &self.range else {
+      return vec![];
+    };
+    self
+      .details
+      .fixes
+      .iter()
+      .enumerate()
+      .map(|(i, fix)| {
+        let mut edit = WorkspaceEdit::default();
+        edit.changes.insert(
+          self.specifier.to_string(),
+          text_edits(&range.text_info, fix),
+        );
+        CodeAction {
+          title: fix.description.to_string(),
+          kind: CodeActionKind::QuickFix,
+          is_preferred: i == 0,
+          edit,
+        }
+      })
+      .collect()
+  }
+}
+
+fn text_edits(text_info: &SourceTextInfo, fix: &LintFix) -> Vec<TextEdit> {
+  fix
+    .changes
+    .iter()
+    .map(|change| TextEdit {
+      range: Range {
+        start: Position::new(text_info, change.range.start),
+        end: Position::new(text_info, change.range.end),
+      },
+      new_text: change.new_text.to_string(),
+    })
+    .collect()
+}
+
+/// A code action that applies the first fix of each diagnostic, or `None`
+/// if none of them have fixes. A fix is left out if it overlaps with a fix
+/// of an earlier diagnostic, since the edits of a `WorkspaceEdit` must not
+/// overlap.
+pub fn fix_all_action(diagnostics: &[LintDiagnostic]) -> Option<CodeAction> {
+  let mut applied: BTreeMap<String, Vec<SourceRange>> = BTreeMap::new();
+  let mut edit = WorkspaceEdit::default();
+  for diagnostic in diagnostics {
+    let (Some(range), Some(fix)) =
+      (&diagnostic.range, diagnostic.details.fixes.first())
+    else {
+      continue;
+    };
+    let specifier = diagnostic.specifier.to_string();
+    let ranges = applied.entry(specifier.clone()).or_default();
+    let overlaps = fix
+      .changes
+      .iter()
+      .any(|change| ranges.iter().any(|range| overlaps(&change.range, range)));
+    if overlaps {
+      continue;
+    }
+    ranges.extend(fix.changes.iter().map(|change| change.range));
+    edit
+      .changes
+      .entry(specifier)
+      .or_default()
+      .extend(text_edits(&range.text_info, fix));
+  }
+  if edit.changes.is_empty() {
+    return None;
+  }
+  for edits in edit.changes.values_mut() {
+    edits
+      .sort_by_key(|edit| (edit.range.start.line, edit.range.start.character));
+  }
+  Some(CodeAction {
+    title: "Fix all auto-fixable lint problems".to_string(),
+    kind: CodeActionKind::FixAll,
+    is_preferred: false,
+    edit,
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::rules::no_window::NoWindow;
+  use crate::test_util;
+
+  #[test]
+  fn quick_fix_actions() {
+    let (_, diagnostics) = test_util::lint(
+      Box::new(NoWindow),
+      "/* 😀 */ window.foo();",
+      "file:///a.ts",
+    );
+    let actions = diagnostics[0].code_actions();
+    assert_eq!(
+      serde_json::to_value(&actions).unwrap(),
+      serde_json::json!([{
+        "title": "Rename window to globalThis",
+        "kind": "quickfix",
+        "isPreferred": true,
+        "edit": {
+          "changes": {
+            "file:///a.ts": [{
+              "range": {
+                "start": { "line": 0, "character": 9 },
+                "end": { "line": 0, "character": 15 },
+              },
+              "newText": "globalThis",
+            }],
+          },
+        },
+      }])
+    );
+  }
+
+  #[test]
+  fn fix_all() {
+    let (_, diagnostics) = test_util::lint(
+      Box::new(NoWindow),
+      "window.foo();\nwindow.bar();",
+      "file:///a.ts",
+    );
+    let action = fix_all_action(&diagnostics).unwrap();
+    assert_eq!(action.kind, CodeActionKind::FixAll);
+    let json = serde_json::to_value(&action).unwrap();
+    assert_eq!(json["kind"], "source.fixAll.deno-lint");
+    assert!(json.get("isPreferred").is_none());
+    let edits = &action.edit.changes["file:///a.ts"];
+    assert_eq!(edits.len(), 2);
+    assert_eq!(
+      edits[1].range.start,
+      Position {
+        line: 1,
+        character: 0
+      }
+    );
+
+    // The same fix twice overlaps with itself.
+    let duplicated = [diagnostics[0].clone(), diagnostics[0].clone()];
+    let action = fix_all_action(&duplicated).unwrap();
+    assert_eq!(action.edit.changes["file:///a.ts"].len(), 1);
+
+    assert!(fix_all_action(&[]).is_none());
+  }
+}
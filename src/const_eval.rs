@@ -0,0 +1,463 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+//! Evaluates expressions whose values are known without running the code,
+//! like `"a" + "b"` or `1 < 2`, so rules can reason about constant values
+//! instead of only about literals.
+
+use deno_ast::swc::ast::{
+  BinExpr, BinaryOp, Expr, Id, Lit, Pat, Tpl, UnaryOp, VarDeclKind,
+};
+use deno_ast::swc::common::SyntaxContext;
+use deno_ast::view::{self as ast_view, NodeTrait};
+use std::collections::HashMap;
+
+/// How many `const` variables are followed from one expression, which stops
+/// cycles like `const a = b, b = a;`.
+const MAX_DEPTH: usize = 32;
+
+/// A primitive value that an expression evaluates to.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConstValue {
+  Undefined,
+  Null,
+  Bool(bool),
+  Number(f64),
+  String(String),
+}
+
+impl ConstValue {
+  /// Whether the value is truthy, like in `if (value)`.
+  pub fn is_truthy(&self) -> bool {
+    match self {
+      ConstValue::Undefined | ConstValue::Null => false,
+      ConstValue::Bool(value) => *value,
+      ConstValue::Number(value) => *value != 0.0 && !value.is_nan(),
+      ConstValue::String(value) => !value.is_empty(),
+    }
+  }
+
+  /// The value converted to a number, like with `Number(value)`.
+  pub fn to_number(&self) -> f64 {
+    match self {
+      ConstValue::Undefined => f64::NAN,
+      ConstValue::Null => 0.0,
+      ConstValue::Bool(value) => f64::from(u8::from(*value)),
+      ConstValue::Number(value) => *value,
+      ConstValue::String(value) => string_to_number(value),
+    }
+  }
+
+  /// The value converted to a string, like with `String(value)`.
+  pub fn to_js_string(&self) -> String {
+    match self {
+      ConstValue::Undefined => "undefined".to_string(),
+      ConstValue::Null => "null".to_string(),
+      ConstValue::Bool(value) => value.to_string(),
+      ConstValue::Number(value) => number_to_string(*value),
+      ConstValue::String(value) => value.clone(),
+    }
+  }
+
+  /// The result of `typeof value`.
+  pub fn type_of(&self) -> &'static str {
+    match self {
+      ConstValue::Undefined => "undefined",
+      ConstValue::Null => "object",
+      ConstValue::Bool(_) => "boolean",
+      ConstValue::Number(_) => "number",
+      ConstValue::String(_) => "string",
+    }
+  }
+
+  /// The result of `a === b`.
+  pub fn strict_equals(&self, other: &ConstValue) -> bool {
+    self == other
+  }
+
+  /// The result of `a == b`.
+  pub fn loose_equals(&self, other: &ConstValue) -> bool {
+    use ConstValue::*;
+    match (self, other) {
+      (Undefined | Null, Undefined | Null) => true,
+      (Undefined | Null, _) | (_, Undefined | Null) => false,
+      (String(a), String(b)) => a == b,
+      (a, b) => a.to_number() == b.to_number(),
+    }
+  }
+}
+
+/// Evaluates expressions to constant values. Only primitive values are
+/// evaluated, and anything that depends on the runtime, like calls or
+/// property reads, makes the expression unknown.
+pub struct ConstEvaluator<'a> {
+  /// The initializers of `const` variables.
+  consts: HashMap<Id, &'a Expr>,
+  unresolved_ctxt: SyntaxContext,
+}
+
+impl<'a> ConstEvaluator<'a> {
+  /// An evaluator that knows the values of literals and of the globals
+  /// `undefined`, `NaN` and `Infinity`, but not of variables.
+  pub fn new(unresolved_ctxt: SyntaxContext) -> Self {
+    Self {
+      consts: HashMap::new(),
+      unresolved_ctxt,
+    }
+  }
+
+  /// An evaluator that also knows the values of the `const` variables of the
+  /// program that are initialized with constant expressions.
+  pub fn with_consts(
+    program: ast_view::Program<'a>,
+    unresolved_ctxt: SyntaxContext,
+  ) -> Self {
+    let mut evaluator = Self::new(unresolved_ctxt);
+    evaluator.collect_consts(program.as_node());
+    evaluator
+  }
+
+  fn collect_consts(&mut self, node: ast_view::Node<'a>) {
+    if let ast_view::Node::VarDecl(var_decl) = node {
+      if var_decl.inner.kind == VarDeclKind::Const {
+        for decl in &var_decl.inner.decls {
+          if let (Pat::Ident(binding), Some(init)) = (&decl.name, &decl.init) {
+            self.consts.insert(binding.id.to_id(), init);
+          }
+        }
+      }
+    }
+    for child in node.children() {
+      self.collect_consts(child);
+    }
+  }
+
+  /// The value of the expression, if it's known.
+  pub fn eval(&self, expr: &Expr) -> Option<ConstValue> {
+    self.eval_with_depth(expr, 0)
+  }
+
+  fn eval_with_depth(&self, expr: &Expr, depth: usize) -> Option<ConstValue> {
+    let eval = |expr: &Expr| self.eval_with_depth(expr, depth);
+    match expr {
+      Expr::Lit(Lit::Str(str)) => {
+        Some(ConstValue::String(str.value.to_string()))
+      }
+      Expr::Lit(Lit::Num(num)) => Some(ConstValue::Number(num.value)),
+      Expr::Lit(Lit::Bool(bool)) => Some(ConstValue::Bool(bool.value)),
+      Expr::Lit(Lit::Null(_)) => Some(ConstValue::Null),
+      Expr::Ident(ident) => {
+        if let Some(init) = self.consts.get(&ident.to_id()) {
+          return if depth < MAX_DEPTH {
+            self.eval_with_depth(init, depth + 1)
+          } else {
+            None
+          };
+        }
+        if ident.ctxt != self.unresolved_ctxt {
+          return None;
+        }
+        match ident.sym.as_ref() {
+          "undefined" => Some(ConstValue::Undefined),
+          "NaN" => Some(ConstValue::Number(f64::NAN)),
+          "Infinity" => Some(ConstValue::Number(f64::INFINITY)),
+          _ => None,
+        }
+      }
+      Expr::Tpl(tpl) => self.eval_tpl(tpl, depth),
+      Expr::Paren(paren) => eval(&paren.expr),
+      Expr::TsAs(as_expr) => eval(&as_expr.expr),
+      Expr::TsSatisfies(satisfies) => eval(&satisfies.expr),
+      Expr::TsNonNull(non_null) => eval(&non_null.expr),
+      Expr::TsConstAssertion(assertion) => eval(&assertion.expr),
+      Expr::TsTypeAssertion(assertion) => eval(&assertion.expr),
+      Expr::Unary(unary) => {
+        if unary.op == UnaryOp::Void {
+          return Some(ConstValue::Undefined);
+        }
+        let arg = eval(&unary.arg)?;
+        match unary.op {
+          UnaryOp::Bang => Some(ConstValue::Bool(!arg.is_truthy())),
+          UnaryOp::Minus => Some(ConstValue::Number(-arg.to_number())),
+          UnaryOp::Plus => Some(ConstValue::Number(arg.to_number())),
+          UnaryOp::Tilde => {
+            Some(ConstValue::Number(f64::from(!to_int32(arg.to_number()))))
+          }
+          UnaryOp::TypeOf => {
+            Some(ConstValue::String(arg.type_of().to_string()))
+          }
+          UnaryOp::Void | UnaryOp::Delete => None,
+        }
+      }
+      Expr::Bin(bin) => self.eval_bin(bin, depth),
+      Expr::Cond(cond) => {
+        if eval(&cond.test)?.is_truthy() {
+          eval(&cond.cons)
+        } else {
+          eval(&cond.alt)
+        }
+      }
+      Expr::Seq(seq) => eval(seq.exprs.last()?),
+      _ => None,
+    }
+  }
+
+  fn eval_tpl(&self, tpl: &Tpl, depth: usize) -> Option<ConstValue> {
+    let mut value = String::new();
+    for (i, quasi) in tpl.quasis.iter().enumerate() {
+      value.push_str(quasi.cooked.as_ref()?);
+      if let Some(expr) = tpl.exprs.get(i) {
+        value.push_str(&self.eval_with_depth(expr, depth)?.to_js_string());
+      }
+    }
+    Some(ConstValue::String(value))
+  }
+
+  fn eval_bin(&self, bin: &BinExpr, depth: usize) -> Option<ConstValue> {
+    let left = self.eval_with_depth(&bin.left, depth)?;
+    // The right side isn't evaluated when the left side short-circuits.
+    match bin.op {
+      BinaryOp::LogicalAnd if !left.is_truthy() => return Some(left),
+      BinaryOp::LogicalOr if left.is_truthy() => return Some(left),
+      BinaryOp::NullishCoalescing
+        if !matches!(left, ConstValue::Undefined | ConstValue::Null) =>
+      {
+        return Some(left)
+      }
+      BinaryOp::LogicalAnd
+      | BinaryOp::LogicalOr
+      | BinaryOp::NullishCoalescing => {
+        return self.eval_with_depth(&bin.right, depth)
+      }
+      _ => {}
+    }
+    let right = self.eval_with_depth(&bin.right, depth)?;
+    let number = |value: f64| Some(ConstValue::Number(value));
+    let bool = |value: bool| Some(ConstValue::Bool(value));
+    let (a, b) = (left.to_number(), right.to_number());
+    match bin.op {
+      BinaryOp::Add => match (&left, &right) {
+        (ConstValue::String(_), _) | (_, ConstValue::String(_)) => {
+          let (left, right) = (left.to_js_string(), right.to_js_string());
+          Some(ConstValue::String(format!("{left}{right}")))
+        }
+        _ => number(a + b),
+      },
+      BinaryOp::Sub => number(a - b),
+      BinaryOp::Mul => number(a * b),
+      BinaryOp::Div => number(a / b),
+      BinaryOp::Mod => number(a % b),
+      BinaryOp::Exp => number(a.powf(b)),
+      BinaryOp::BitAnd => number(f64::from(to_int32(a) & to_int32(b))),
+      BinaryOp::BitOr => number(f64::from(to_int32(a) | to_int32(b))),
+      BinaryOp::BitXor => number(f64::from(to_int32(a) ^ to_int32(b))),
+      BinaryOp::LShift => {
+        number(f64::from(to_int32(a).wrapping_shl(to_uint32(b) & 31)))
+      }
+      BinaryOp::RShift => {
+        number(f64::from(to_int32(a).wrapping_shr(to_uint32(b) & 31)))
+      }
+      BinaryOp::ZeroFillRShift => {
+        number(f64::from(to_uint32(a).wrapping_shr(to_uint32(b) & 31)))
+      }
+      BinaryOp::EqEqEq => bool(left.strict_equals(&right)),
+      BinaryOp::NotEqEq => bool(!left.strict_equals(&right)),
+      BinaryOp::EqEq => bool(left.loose_equals(&right)),
+      BinaryOp::NotEq => bool(!left.loose_equals(&right)),
+      BinaryOp::Lt | BinaryOp::Gt | BinaryOp::LtEq | BinaryOp::GtEq => {
+        let ordering = match (&left, &right) {
+          // Strings are compared by their UTF-16 code units.
+          (ConstValue::String(a), ConstValue::String(b)) => {
+            Some(a.encode_utf16().cmp(b.encode_utf16()))
+          }
+          _ => a.partial_cmp(&b),
+        };
+        // Comparisons with `NaN` are always false.
+        let Some(ordering) = ordering else {
+          return bool(false);
+        };
+        bool(match bin.op {
+          BinaryOp::Lt => ordering.is_lt(),
+          BinaryOp::Gt => ordering.is_gt(),
+          BinaryOp::LtEq => ordering.is_le(),
+          _ => ordering.is_ge(),
+        })
+      }
+      _ => None,
+    }
+  }
+}
+
+fn to_uint32(value: f64) -> u32 {
+  if !value.is_finite() {
+    return 0;
+  }
+  value.trunc().rem_euclid(4294967296.0) as u32
+}
+
+fn to_int32(value: f64) -> i32 {
+  to_uint32(value) as i32
+}
+
+/// Converts a string to a number like `Number(value)` does.
+fn string_to_number(value: &str) -> f64 {
+  let value = value.trim();
+  if value.is_empty() {
+    return 0.0;
+  }
+  let radix = match value.get(..2) {
+    Some("0x" | "0X") => Some(16),
+    Some("0o" | "0O") => Some(8),
+    Some("0b" | "0B") => Some(2),
+    _ => None,
+  };
+  if let Some(radix) = radix {
+    return u64::from_str_radix(&value[2..], radix)
+      .map_or(f64::NAN, |value| value as f64);
+  }
+  match value.trim_start_matches(['+', '-']) {
+    "Infinity" => {
+      if value.starts_with('-') {
+        f64::NEG_INFINITY
+      } else {
+        f64::INFINITY
+      }
+    }
+    // Rust also parses words like `inf` and `nan`, which are `NaN` in JS.
+    digits
+      if digits.chars().all(|c| {
+        c.is_ascii_digit() || matches!(c, '.' | 'e' | 'E' | '+' | '-')
+      }) =>
+    {
+      value.parse().unwrap_or(f64::NAN)
+    }
+    _ => f64::NAN,
+  }
+}
+
+/// Converts a number to a string like `String(value)` does.
+fn number_to_string(value: f64) -> String {
+  if value.is_nan() {
+    return "NaN".to_string();
+  }
+  if value.is_infinite() {
+    return if value > 0.0 { "Infinity" } else { "-Infinity" }.to_string();
+  }
+  if value == 0.0 {
+    // Including `-0`.
+    return "0".to_string();
+  }
+  let abs = value.abs();
+  if (1e-6..1e21).contains(&abs) {
+    return value.to_string();
+  }
+  // Rust writes `1e21` and `1e-7`, where JS writes `1e+21` and `1e-7`.
+  let exponential = format!("{value:e}");
+  match exponential.split_once('e') {
+    Some((mantissa, exponent)) if !exponent.starts_with('-') => {
+      format!("{mantissa}e+{exponent}")
+    }
+    _ => exponential,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::parse;
+  use deno_ast::swc::ast::{ModuleItem, Program, Stmt};
+
+  /// Evaluates the expression of the last statement of the source.
+  fn eval(source: &str) -> Option<ConstValue> {
+    let parsed_source = parse(source);
+    let unresolved_ctxt = parsed_source.unresolved_context();
+    parsed_source.with_view(|program| {
+      let evaluator = ConstEvaluator::with_consts(program, unresolved_ctxt);
+      let stmt = match parsed_source.program_ref() {
+        Program::Module(module) => match module.body.last() {
+          Some(ModuleItem::Stmt(stmt)) => stmt,
+          _ => panic!("expected a statement"),
+        },
+        Program::Script(script) => script.body.last().unwrap(),
+      };
+      let Stmt::Expr(expr_stmt) = stmt else {
+        panic!("expected an expression statement");
+      };
+      evaluator.eval(&expr_stmt.expr)
+    })
+  }
+
+  fn number(value: f64) -> Option<ConstValue> {
+    Some(ConstValue::Number(value))
+  }
+
+  fn string(value: &str) -> Option<ConstValue> {
+    Some(ConstValue::String(value.to_string()))
+  }
+
+  fn bool(value: bool) -> Option<ConstValue> {
+    Some(ConstValue::Bool(value))
+  }
+
+  #[test]
+  fn literals() {
+    assert_eq!(eval("1;"), number(1.0));
+    assert_eq!(eval("'a';"), string("a"));
+    assert_eq!(eval("true;"), bool(true));
+    assert_eq!(eval("null;"), Some(ConstValue::Null));
+    assert_eq!(eval("undefined;"), Some(ConstValue::Undefined));
+    assert_eq!(eval("void foo();"), Some(ConstValue::Undefined));
+    assert_eq!(eval("`a${1}b${'c'}`;"), string("a1bc"));
+    assert_eq!(eval("(1 as number);"), number(1.0));
+    assert_eq!(eval("1n;"), None);
+    assert_eq!(eval("/a/;"), None);
+    assert_eq!(eval("[];"), None);
+    assert_eq!(eval("`a${b}`;"), None);
+  }
+
+  #[test]
+  fn operators() {
+    assert_eq!(eval("1 + 2 * 3;"), number(7.0));
+    assert_eq!(eval("'a' + 1;"), string("a1"));
+    assert_eq!(eval("1 + null;"), number(1.0));
+    assert_eq!(eval("'' + 0.1;"), string("0.1"));
+    assert_eq!(eval("'' + 1e21;"), string("1e+21"));
+    assert_eq!(eval("'' + -0;"), string("0"));
+    assert_eq!(eval("2 ** 10;"), number(1024.0));
+    assert_eq!(eval("-1 >>> 28;"), number(15.0));
+    assert_eq!(eval("~5;"), number(-6.0));
+    assert_eq!(eval("!'';"), bool(true));
+    assert_eq!(eval("typeof null;"), string("object"));
+    assert_eq!(eval("1 < 2;"), bool(true));
+    assert_eq!(eval("'b' > 'a';"), bool(true));
+    assert_eq!(eval("'10' < '9';"), bool(true));
+    assert_eq!(eval("NaN < 1;"), bool(false));
+    assert_eq!(eval("1 === 1;"), bool(true));
+    assert_eq!(eval("NaN === NaN;"), bool(false));
+    assert_eq!(eval("'1' == 1;"), bool(true));
+    assert_eq!(eval("' 0x10 ' == 16;"), bool(true));
+    assert_eq!(eval("null == undefined;"), bool(true));
+    assert_eq!(eval("null == 0;"), bool(false));
+    assert_eq!(eval("'inf' == Infinity;"), bool(false));
+    assert_eq!(eval("true ? 1 : foo;"), number(1.0));
+    assert_eq!(eval("0 && foo;"), number(0.0));
+    assert_eq!(eval("null ?? 'a';"), string("a"));
+    assert_eq!(eval("(foo(), 1);"), number(1.0));
+    assert_eq!(eval("foo || 1;"), None);
+    assert_eq!(eval("1 + foo;"), None);
+    assert_eq!(eval("'a' in foo;"), None);
+  }
+
+  #[test]
+  fn variables() {
+    assert_eq!(eval("const a = 1; const b = a + 1; b;"), number(2.0));
+    assert_eq!(eval("const KIND = 'str'; `${KIND}ing`;"), string("string"));
+    assert_eq!(eval("let a = 1; a;"), None);
+    assert_eq!(eval("const a = foo(); a;"), None);
+    assert_eq!(eval("const { a } = { a: 1 }; a;"), None);
+    assert_eq!(eval("const a = b, b = a; a;"), None);
+    assert_eq!(
+      eval("const a = 1; function f(a) { return a; } a;"),
+      number(1.0)
+    );
+  }
+}
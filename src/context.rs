@@ -1,5 +1,6 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
+use crate::const_eval::ConstEvaluator;
 use crate::control_flow::ControlFlow;
 use crate::diagnostic::{
   LintDiagnostic, LintDiagnosticDetails, LintDiagnosticRange, LintFix,
@@ -8,19 +9,27 @@ use crate::ignore_directives::{
   parse_line_ignore_directives, CodeStatus, FileIgnoreDirective,
   LineIgnoreDirective,
 };
+use crate::jsdoc::JsDoc;
 use crate::linter::LinterContext;
+use crate::module_graph::ModuleGraph;
+use crate::node_id::{NodeId, NodeIds};
+use crate::resolver::SpecifierResolver;
 use crate::rules::{self, LintRule};
+use crate::type_info::TypeInfoProvider;
 use deno_ast::swc::ast::Expr;
 use deno_ast::swc::common::comments::Comment;
 use deno_ast::swc::common::util::take::Take;
 use deno_ast::swc::common::{SourceMap, SyntaxContext};
-use deno_ast::SourceTextInfo;
+use deno_ast::swc::parser::token::TokenAndSpan;
 use deno_ast::{
-  view as ast_view, ParsedSource, RootNode, SourcePos, SourceRange,
+  view as ast_view, LineAndColumnIndex, ParsedSource, RootNode, SourcePos,
+  SourceRange, SourceRanged, SourceRangedForSpanned, SourceTextInfo,
 };
 use deno_ast::{MediaType, ModuleSpecifier};
 use deno_ast::{MultiThreadedComments, Scope};
+use std::cell::OnceCell;
 use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
 use std::sync::Arc;
 
 /// `Context` stores all data needed to perform linting of a particular file.
@@ -33,8 +42,14 @@ pub struct Context<'a> {
   scope: Scope,
   control_flow: ControlFlow,
   traverse_flow: TraverseFlow,
+  node_ids: OnceCell<NodeIds<'a>>,
+  module_graph: OnceCell<ModuleGraph<'a>>,
+  const_evaluator: OnceCell<Rc<ConstEvaluator<'a>>>,
+  has_bom: bool,
   all_rule_codes: &'a HashSet<&'static str>,
   check_unknown_rules: bool,
+  resolver: &'a dyn SpecifierResolver,
+  type_info: Option<&'a dyn TypeInfoProvider>,
   #[allow(clippy::redundant_allocation)] // This type comes from SWC.
   jsx_factory: Option<Arc<Box<Expr>>>,
   #[allow(clippy::redundant_allocation)] // This type comes from SWC.
@@ -47,6 +62,7 @@ impl<'a> Context<'a> {
     parsed_source: ParsedSource,
     program: ast_view::Program<'a>,
     file_ignore_directive: Option<FileIgnoreDirective>,
+    has_bom: bool,
     default_jsx_factory: Option<String>,
     default_jsx_fragment_factory: Option<String>,
   ) -> Self {
@@ -111,8 +127,14 @@ impl<'a> Context<'a> {
       parsed_source,
       diagnostics: Vec::new(),
       traverse_flow: TraverseFlow::default(),
+      node_ids: OnceCell::new(),
+      module_graph: OnceCell::new(),
+      const_evaluator: OnceCell::new(),
+      has_bom,
       check_unknown_rules: linter_ctx.check_unknown_rules,
       all_rule_codes: &linter_ctx.all_rule_codes,
+      resolver: &*linter_ctx.resolver,
+      type_info: linter_ctx.type_info.as_deref(),
       jsx_factory,
       jsx_fragment_factory,
     }
@@ -149,6 +171,46 @@ impl<'a> Context<'a> {
     self.parsed_source.text_info_lazy()
   }
 
+  /// The text of the file, without a BOM.
+  pub fn source_text(&self) -> &str {
+    self.text_info().text_str()
+  }
+
+  /// The text of the node or range.
+  pub fn text_of(&self, range: impl SourceRanged) -> &str {
+    self.text_info().range_text(&range.range())
+  }
+
+  /// The whitespace at the start of the line that the node or range starts
+  /// on, like the indentation of a statement.
+  pub fn leading_whitespace(&self, range: impl SourceRanged) -> &str {
+    let line = self.line_text(self.line_index(range.start()));
+    let indent_len = line.len() - line.trim_start().len();
+    &line[..indent_len]
+  }
+
+  /// The zero-based index of the line that the position is on.
+  pub fn line_index(&self, pos: SourcePos) -> usize {
+    self.text_info().line_index(pos)
+  }
+
+  /// The zero-based line and column indexes of the position.
+  pub fn line_and_column(&self, pos: SourcePos) -> LineAndColumnIndex {
+    self.text_info().line_and_column_index(pos)
+  }
+
+  /// The text of the line, without the line break.
+  pub fn line_text(&self, line_index: usize) -> &str {
+    self.text_info().line_text(line_index)
+  }
+
+  /// Whether the file started with a byte order mark. The BOM is stripped
+  /// before parsing, so it isn't part of `text_info`. Files linted with
+  /// `Linter::lint_with_ast` were already parsed, so they never have one.
+  pub fn has_bom(&self) -> bool {
+    self.has_bom
+  }
+
   /// The AST view of the program, which for example can be used for getting
   /// comments
   pub fn program(&self) -> ast_view::Program<'a> {
@@ -176,6 +238,93 @@ impl<'a> Context<'a> {
     &self.control_flow
   }
 
+  /// An evaluator of constant expressions that knows the values of the
+  /// file's `const` variables, which are collected the first time a rule needs
+  /// them and then shared by all rules
+  pub fn const_evaluator(&self) -> Rc<ConstEvaluator<'a>> {
+    self
+      .const_evaluator
+      .get_or_init(|| {
+        Rc::new(ConstEvaluator::with_consts(
+          self.program,
+          self.unresolved_ctxt(),
+        ))
+      })
+      .clone()
+  }
+
+  /// The imports and exports of the file, which are collected the first time
+  /// a rule needs them and then shared by all rules
+  pub fn module_graph(&self) -> &ModuleGraph<'a> {
+    self
+      .module_graph
+      .get_or_init(|| ModuleGraph::new(self.program))
+  }
+
+  /// The ids of the nodes of the file and their parents, which are computed
+  /// the first time a rule needs them and then shared by all rules
+  pub fn node_ids(&self) -> &NodeIds<'a> {
+    self.node_ids.get_or_init(|| NodeIds::new(self.program))
+  }
+
+  /// The parent of a node, or `None` for the program
+  pub fn parent_of(&self, id: NodeId) -> Option<NodeId> {
+    self.node_ids().parent(id)
+  }
+
+  /// The ancestors of a node, from its parent up to the program
+  pub fn ancestors(&self, id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+    self.node_ids().ancestors(id)
+  }
+
+  /// Resolves a specifier imported by the file, with the resolver the linter
+  /// was created with
+  pub fn resolve(&self, specifier: &str) -> Option<ModuleSpecifier> {
+    self.resolver.resolve(specifier, self.specifier())
+  }
+
+  /// Whether the linter was created with types from a type checker, which
+  /// the rules that need them require
+  pub fn has_type_info(&self) -> bool {
+    self.type_info.is_some()
+  }
+
+  /// Whether the expression at the range has the type `any`, according to the
+  /// types the linter was created with. `None` without types, or if the type
+  /// isn't known
+  pub fn is_any_type(&self, range: SourceRange) -> Option<bool> {
+    let byte_range = range.as_byte_range(self.text_info().range().start);
+    self.type_info?.is_any(self.specifier(), byte_range)
+  }
+
+  /// Whether the expressions at the two ranges have the same type, according
+  /// to the types the linter was created with. `None` without types, or if
+  /// either type isn't known
+  pub fn is_same_type(&self, a: SourceRange, b: SourceRange) -> Option<bool> {
+    let start = self.text_info().range().start;
+    self.type_info?.is_same_type(
+      self.specifier(),
+      a.as_byte_range(start),
+      b.as_byte_range(start),
+    )
+  }
+
+  /// Whether the type of the expression at the range includes `null` or
+  /// `undefined`, according to the types the linter was created with. `None`
+  /// without types, or if the type isn't known
+  pub fn is_nullable_type(&self, range: SourceRange) -> Option<bool> {
+    let byte_range = range.as_byte_range(self.text_info().range().start);
+    self.type_info?.is_nullable(self.specifier(), byte_range)
+  }
+
+  /// The members of the union type of the expression at the range, according
+  /// to the types the linter was created with. `None` without types, or if the
+  /// type isn't known or isn't a union of literal values
+  pub fn union_members(&self, range: SourceRange) -> Option<Vec<String>> {
+    let byte_range = range.as_byte_range(self.text_info().range().start);
+    self.type_info?.union_members(self.specifier(), byte_range)
+  }
+
   /// Get the JSX factory expression for this file, if one is specified (via
   /// pragma or using a default). If this file is not JSX, uses the automatic
   /// transform, or the default factory is not specified, this will return
@@ -227,6 +376,68 @@ impl<'a> Context<'a> {
     self.program.comment_container().trailing_comments(end)
   }
 
+  /// The comments before and after the range, like the leading and trailing
+  /// comments of a node.
+  pub fn comments_around(
+    &self,
+    range: SourceRange,
+  ) -> impl Iterator<Item = &'a Comment> {
+    let comments = self.program.comment_container();
+    comments
+      .leading_comments(range.start)
+      .chain(comments.trailing_comments(range.end))
+  }
+
+  /// The comments inside of the range, like the comments in a block.
+  pub fn comments_within(
+    &self,
+    range: SourceRange,
+  ) -> impl Iterator<Item = &'a Comment> {
+    self
+      .all_comments()
+      .filter(move |comment| range.contains(&comment.range()))
+  }
+
+  /// The JSDoc comment right before the position, like the documentation of
+  /// the declaration that starts there.
+  pub fn jsdoc_at(&self, start: SourcePos) -> Option<JsDoc> {
+    JsDoc::from_comment(self.leading_comments_at(start).last()?)
+  }
+
+  /// All the tokens of the file in order, including the ones the AST doesn't
+  /// keep, like parentheses, semicolons and commas. Comments aren't tokens.
+  pub fn tokens(&self) -> &'a [TokenAndSpan] {
+    self.program.token_container().tokens
+  }
+
+  /// The tokens inside of the range, like the tokens of a node.
+  pub fn tokens_within(&self, range: SourceRange) -> &'a [TokenAndSpan] {
+    let tokens = self.tokens();
+    let start = tokens.partition_point(|token| token.start() < range.start);
+    let end = tokens.partition_point(|token| token.end() <= range.end);
+    &tokens[start..end.max(start)]
+  }
+
+  /// The last token that ends at or before the position, like the token
+  /// before a node.
+  pub fn token_before(&self, pos: SourcePos) -> Option<&'a TokenAndSpan> {
+    let tokens = self.tokens();
+    tokens[..tokens.partition_point(|token| token.end() <= pos)].last()
+  }
+
+  /// The first token that starts at or after the position, like the token
+  /// after a node.
+  pub fn token_after(&self, pos: SourcePos) -> Option<&'a TokenAndSpan> {
+    let tokens = self.tokens();
+    tokens[tokens.partition_point(|token| token.start() < pos)..].first()
+  }
+
+  /// Whether the file has any ignore directives.
+  pub(crate) fn has_ignore_directives(&self) -> bool {
+    self.file_ignore_directive.is_some()
+      || !self.line_ignore_directives.is_empty()
+  }
+
   /// Mark ignore directives as used if that directive actually suppresses some
   /// diagnostic, and return a list of diagnostics that are not ignored.
   /// Make sure that this method is called after all lint rules have been
@@ -441,6 +652,35 @@ impl<'a> Context<'a> {
     );
   }
 
+  /// Adds a diagnostic that also highlights other ranges, each with a
+  /// description of how it relates to the diagnostic.
+  pub fn add_diagnostic_with_related(
+    &mut self,
+    range: SourceRange,
+    code: impl ToString,
+    message: impl ToString,
+    hint: impl ToString,
+    related: Vec<(SourceRange, String)>,
+  ) {
+    let mut diagnostic = self.create_diagnostic(
+      Some(self.create_diagnostic_range(range)),
+      self.create_diagnostic_details(
+        code,
+        message,
+        Some(hint.to_string()),
+        Vec::new(),
+      ),
+    );
+    diagnostic.related = related
+      .into_iter()
+      .map(|(range, description)| LintDiagnosticRange {
+        description: Some(description),
+        ..self.create_diagnostic_range(range)
+      })
+      .collect();
+    self.diagnostics.push(diagnostic);
+  }
+
   pub fn add_diagnostic_details(
     &mut self,
     maybe_range: Option<LintDiagnosticRange>,
@@ -460,6 +700,7 @@ impl<'a> Context<'a> {
       specifier: self.specifier().clone(),
       range: maybe_range,
       details,
+      related: Vec::new(),
     }
   }
 
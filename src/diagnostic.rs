@@ -11,6 +11,7 @@ use deno_ast::diagnostics::DiagnosticSnippetHighlightStyle;
 use deno_ast::diagnostics::DiagnosticSourcePos;
 use deno_ast::diagnostics::DiagnosticSourceRange;
 use deno_ast::ModuleSpecifier;
+use deno_ast::SourcePos;
 use deno_ast::SourceRange;
 use deno_ast::SourceTextInfo;
 
@@ -34,6 +35,42 @@ pub struct LintDiagnosticRange {
   pub description: Option<String>,
 }
 
+impl LintDiagnosticRange {
+  /// The position of the first character of the range.
+  pub fn start_position(&self) -> LintDiagnosticPosition {
+    LintDiagnosticPosition::new(&self.text_info, self.range.start)
+  }
+
+  /// The position right after the last character of the range.
+  pub fn end_position(&self) -> LintDiagnosticPosition {
+    LintDiagnosticPosition::new(&self.text_info, self.range.end)
+  }
+}
+
+/// A position in a file, for consumers that can't use `SourcePos` directly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LintDiagnosticPosition {
+  /// The 1-indexed line number.
+  pub line_number: usize,
+  /// The 1-indexed column number, in characters.
+  pub column_number: usize,
+  /// The 0-indexed offset in bytes from the start of the file.
+  pub byte_index: usize,
+}
+
+impl LintDiagnosticPosition {
+  fn new(text_info: &SourceTextInfo, pos: SourcePos) -> Self {
+    // The column index of `line_and_column_index` is in characters already,
+    // unlike the display column, which expands tabs.
+    let index = text_info.line_and_column_index(pos);
+    Self {
+      line_number: index.line_index + 1,
+      column_number: index.column_index + 1,
+      byte_index: pos - text_info.range().start,
+    }
+  }
+}
+
 #[derive(Clone)]
 pub struct LintDiagnosticDetails {
   pub message: String,
@@ -62,6 +99,9 @@ pub struct LintDiagnostic {
   /// the whole file.
   pub range: Option<LintDiagnosticRange>,
   pub details: LintDiagnosticDetails,
+  /// Other ranges of the file that explain the diagnostic, like an earlier
+  /// declaration of a duplicate. They're only shown when there's a `range`.
+  pub related: Vec<LintDiagnosticRange>,
 }
 
 impl Diagnostic for LintDiagnostic {
@@ -101,7 +141,22 @@ impl Diagnostic for LintDiagnostic {
         },
         style: DiagnosticSnippetHighlightStyle::Error,
         description: range.description.as_deref().map(Cow::Borrowed),
-      }],
+      }]
+      .into_iter()
+      .chain(
+        self
+          .related
+          .iter()
+          .map(|related| DiagnosticSnippetHighlight {
+            range: DiagnosticSourceRange {
+              start: DiagnosticSourcePos::SourcePos(related.range.start),
+              end: DiagnosticSourcePos::SourcePos(related.range.end),
+            },
+            style: DiagnosticSnippetHighlightStyle::Hint,
+            description: related.description.as_deref().map(Cow::Borrowed),
+          }),
+      )
+      .collect(),
     })
   }
 
@@ -125,10 +180,7 @@ impl Diagnostic for LintDiagnostic {
     if let Some(custom_docs_url) = &self.details.custom_docs_url {
       Some(Cow::Borrowed(custom_docs_url))
     } else {
-      Some(Cow::Owned(format!(
-        "https://lint.deno.land/rules/{}",
-        &self.details.code
-      )))
+      Some(Cow::Owned(crate::rules::docs_url(&self.details.code)))
     }
   }
 }
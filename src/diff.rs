@@ -0,0 +1,255 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+//! Filters diagnostics to the lines that a change touched, so rules can be
+//! enforced on modified code only.
+
+use crate::diagnostic::LintDiagnostic;
+use std::collections::BTreeMap;
+use std::ops::RangeInclusive;
+
+/// The lines that were added or modified in a file, 1-indexed like line
+/// numbers of diagnostics.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ChangedLines {
+  ranges: Vec<RangeInclusive<usize>>,
+}
+
+impl ChangedLines {
+  pub fn new(ranges: impl IntoIterator<Item = RangeInclusive<usize>>) -> Self {
+    let mut lines = Self::default();
+    for range in ranges {
+      lines.add(range);
+    }
+    lines
+  }
+
+  /// Adds the lines of the range, merging it with the last range if it
+  /// continues it.
+  pub fn add(&mut self, range: RangeInclusive<usize>) {
+    if range.is_empty() {
+      return;
+    }
+    match self.ranges.last_mut() {
+      Some(last) if *last.end() + 1 == *range.start() => {
+        *last = *last.start()..=*range.end();
+      }
+      _ => self.ranges.push(range),
+    }
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.ranges.is_empty()
+  }
+
+  /// Whether any of the lines from `start` to `end` changed.
+  pub fn intersects(&self, start: usize, end: usize) -> bool {
+    self
+      .ranges
+      .iter()
+      .any(|range| *range.start() <= end && start <= *range.end())
+  }
+
+  /// Whether the diagnostic is on a changed line. A diagnostic about the
+  /// whole file is kept if anything in the file changed.
+  pub fn contains(&self, diagnostic: &LintDiagnostic) -> bool {
+    match &diagnostic.range {
+      Some(range) => self.intersects(
+        range.start_position().line_number,
+        range.end_position().line_number,
+      ),
+      None => !self.is_empty(),
+    }
+  }
+
+  /// Keeps only the diagnostics on changed lines.
+  pub fn filter(
+    &self,
+    diagnostics: Vec<LintDiagnostic>,
+  ) -> Vec<LintDiagnostic> {
+    diagnostics
+      .into_iter()
+      .filter(|diagnostic| self.contains(diagnostic))
+      .collect()
+  }
+}
+
+/// Reads the added lines of each file from a unified diff, like the output
+/// of `git diff`, by the paths after `+++ b/`. Context lines and removed
+/// lines are left out, so the amount of context doesn't matter. Deleted files
+/// are left out too, since there's nothing left to lint in them.
+pub fn parse_unified_diff(diff: &str) -> BTreeMap<String, ChangedLines> {
+  let mut files = BTreeMap::new();
+  let mut current: Option<&mut ChangedLines> = None;
+  let mut hunk: Option<Hunk> = None;
+  let mut previous = "";
+  for line in diff.lines() {
+    // The lines of a hunk are counted, since a removed or added line can
+    // look like a header, like `--- a` for a removed `-- a`.
+    if let Some(h) = hunk.as_mut().filter(|h| !h.is_done()) {
+      match line.as_bytes().first() {
+        Some(b'+') => {
+          if let Some(lines) = current.as_mut() {
+            lines.add(h.new_line..=h.new_line);
+          }
+          h.new_line += 1;
+          h.new_remaining = h.new_remaining.saturating_sub(1);
+        }
+        Some(b'-') => h.old_remaining = h.old_remaining.saturating_sub(1),
+        // `\ No newline at end of file`
+        Some(b'\\') => {}
+        _ => {
+          h.new_line += 1;
+          h.new_remaining = h.new_remaining.saturating_sub(1);
+          h.old_remaining = h.old_remaining.saturating_sub(1);
+        }
+      }
+    } else if let Some(path) = line
+      .strip_prefix("+++ ")
+      .filter(|_| previous.starts_with("--- "))
+    {
+      hunk = None;
+      current = match path.trim_end() {
+        "/dev/null" => None,
+        path => {
+          let path = path.strip_prefix("b/").unwrap_or(path);
+          Some(files.entry(path.to_string()).or_default())
+        }
+      };
+    } else if let Some(header) = line.strip_prefix("@@ ") {
+      hunk = Hunk::parse(header);
+    }
+    previous = line;
+  }
+  files
+}
+
+/// The position in a hunk while its lines are read.
+struct Hunk {
+  /// The line number in the new file of the next line.
+  new_line: usize,
+  old_remaining: usize,
+  new_remaining: usize,
+}
+
+impl Hunk {
+  /// Parses a hunk header like `-1,2 +3,4 @@`.
+  fn parse(header: &str) -> Option<Self> {
+    let mut ranges = header.split_whitespace();
+    let (_, old_count) = parse_range(ranges.next()?.strip_prefix('-')?)?;
+    let (new_start, new_count) =
+      parse_range(ranges.next()?.strip_prefix('+')?)?;
+    Some(Self {
+      new_line: new_start,
+      old_remaining: old_count,
+      new_remaining: new_count,
+    })
+  }
+
+  fn is_done(&self) -> bool {
+    self.old_remaining == 0 && self.new_remaining == 0
+  }
+}
+
+/// Parses the start and the line count of a hunk range, like `3,4`, where
+/// the count defaults to one.
+fn parse_range(range: &str) -> Option<(usize, usize)> {
+  match range.split_once(',') {
+    Some((start, count)) => Some((start.parse().ok()?, count.parse().ok()?)),
+    None => Some((range.parse().ok()?, 1)),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::rules::no_var::NoVar;
+  use crate::test_util;
+
+  #[test]
+  fn parse_diff() {
+    let diff = "diff --git a/src/a.ts b/src/a.ts
+index 1111111..2222222 100644
+--- a/src/a.ts
++++ b/src/a.ts
+@@ -1,0 +2,3 @@ function foo() {
++const a = 1;
++const b = 2;
++const c = 3;
+@@ -10 +12 @@
+-old
++new
+@@ -20,2 +21,0 @@
+-deleted
+-deleted
+diff --git a/b.ts b/b.ts
+deleted file mode 100644
+--- a/b.ts
++++ /dev/null
+@@ -1 +0,0 @@
+-gone
+";
+    let files = parse_unified_diff(diff);
+    assert_eq!(files.len(), 1);
+    assert_eq!(files["src/a.ts"], ChangedLines::new([2..=4, 12..=12]));
+  }
+
+  #[test]
+  fn parse_diff_with_context() {
+    let diff = "--- a/a.ts
++++ b/a.ts
+@@ -1,5 +1,6 @@
+ const a = 1;
+-const b = 2;
++const b = 3;
++const c = 4;
+ const d = 5;
+ const e = 6;
+ const f = 7;
+\\ No newline at end of file
+";
+    let files = parse_unified_diff(diff);
+    assert_eq!(files["a.ts"], ChangedLines::new([2..=3]));
+  }
+
+  #[test]
+  fn parse_diff_with_lines_like_headers() {
+    // A removed `-- a` and an added `++ b` look like file headers.
+    let diff = "--- a/a.ts
++++ b/a.ts
+@@ -1,2 +1,2 @@
+--- a
++++ b
+ c
+--- a/b.ts
++++ b/b.ts
+@@ -0,0 +1 @@
++d
+";
+    let files = parse_unified_diff(diff);
+    assert_eq!(files.len(), 2);
+    assert_eq!(files["a.ts"], ChangedLines::new([1..=1]));
+    assert_eq!(files["b.ts"], ChangedLines::new([1..=1]));
+  }
+
+  #[test]
+  fn filter_diagnostics() {
+    let (_, diagnostics) = test_util::lint(
+      Box::new(NoVar),
+      "var a = 1;\nvar b = 2;\nvar c = 3;",
+      "file:///a.ts",
+    );
+    let lines = ChangedLines::new([2..=2]);
+    let filtered = lines.filter(diagnostics.clone());
+    assert_eq!(filtered.len(), 1);
+    assert_eq!(
+      filtered[0]
+        .range
+        .as_ref()
+        .unwrap()
+        .start_position()
+        .line_number,
+      2
+    );
+    assert!(ChangedLines::default().filter(diagnostics).is_empty());
+  }
+}
@@ -0,0 +1,189 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+//! Tracks values that are typed as `any`.
+//!
+//! The `no-unsafe-*` rules need a `TypeInfoProvider` and only check
+//! TypeScript files. The provider tells which expressions are `any` as far as
+//! it knows; for the others, values are `any` judging from the syntax alone:
+//! expressions asserted to `any`, bindings and functions annotated with
+//! `any`, and `JSON.parse()` calls. Member accesses and calls on such values,
+//! and variables initialized with them, are `any` as well.
+
+use crate::context::Context;
+use crate::handler::{Handler, Traverse};
+use crate::Program;
+
+use deno_ast::swc::ast::Id;
+use deno_ast::view::{
+  BindingIdent, Callee, Expr, FnDecl, MemberProp, Pat, TsKeywordTypeKind,
+  TsType, TsTypeAnn, VarDeclarator,
+};
+use deno_ast::{MediaType, SourceRanged};
+use std::collections::HashSet;
+
+#[derive(Default)]
+pub(crate) struct ExplicitAny {
+  /// Bindings annotated with `any`.
+  bindings: HashSet<Id>,
+  /// Bindings annotated with `unknown`.
+  unknown_bindings: HashSet<Id>,
+  /// Functions declared to return `any`.
+  functions: HashSet<Id>,
+}
+
+/// Whether the `no-unsafe-*` rules check the file, which they only do with a
+/// `TypeInfoProvider`, and in TypeScript since JavaScript has no annotations
+/// to go by where the provider doesn't know the type.
+pub(crate) fn checks_file(ctx: &Context) -> bool {
+  ctx.has_type_info()
+    && matches!(
+      ctx.media_type(),
+      MediaType::TypeScript
+        | MediaType::Tsx
+        | MediaType::Mts
+        | MediaType::Cts
+        | MediaType::Dts
+        | MediaType::Dmts
+        | MediaType::Dcts
+    )
+}
+
+fn keyword_kind(type_ann: &TsTypeAnn) -> Option<TsKeywordTypeKind> {
+  match type_ann.type_ann {
+    TsType::TsKeywordType(keyword) => Some(keyword.keyword_kind()),
+    _ => None,
+  }
+}
+
+fn is_any_type(ty: &TsType) -> bool {
+  matches!(
+    ty,
+    TsType::TsKeywordType(keyword)
+      if keyword.keyword_kind() == TsKeywordTypeKind::TsAnyKeyword
+  )
+}
+
+/// Whether a value of this type can safely hold an `any` value.
+pub(crate) fn is_any_or_unknown_type(ty: &TsType) -> bool {
+  matches!(
+    ty,
+    TsType::TsKeywordType(keyword)
+      if matches!(
+        keyword.keyword_kind(),
+        TsKeywordTypeKind::TsAnyKeyword | TsKeywordTypeKind::TsUnknownKeyword
+      )
+  )
+}
+
+impl ExplicitAny {
+  pub fn collect(program: Program, ctx: &mut Context) -> Self {
+    let mut explicit_any = Self::default();
+    explicit_any.traverse(program, ctx);
+    explicit_any
+  }
+
+  /// Whether the expression evaluates to a value of type `any`.
+  pub fn is_any(&self, expr: &Expr, ctx: &Context) -> bool {
+    if let Some(is_any) = ctx.is_any_type(expr.range()) {
+      return is_any;
+    }
+    match expr {
+      Expr::TsAs(as_expr) => is_any_type(&as_expr.type_ann),
+      Expr::TsTypeAssertion(assertion) => is_any_type(&assertion.type_ann),
+      Expr::Paren(paren) => self.is_any(&paren.expr, ctx),
+      Expr::TsNonNull(non_null) => self.is_any(&non_null.expr, ctx),
+      Expr::Ident(ident) => self.bindings.contains(&ident.inner.to_id()),
+      Expr::Member(member) => self.is_any(&member.obj, ctx),
+      Expr::Call(call) => {
+        let Callee::Expr(callee) = &call.callee else {
+          return false;
+        };
+        match callee {
+          Expr::Ident(ident)
+            if self.functions.contains(&ident.inner.to_id()) =>
+          {
+            true
+          }
+          Expr::Member(member) if is_json_parse(member, ctx) => true,
+          callee => self.is_any(callee, ctx),
+        }
+      }
+      _ => false,
+    }
+  }
+
+  /// Whether the expression is `any` because it is derived from another
+  /// `any` value, e.g. `foo.bar` where `foo` is `any`.
+  pub fn is_derived_any(&self, expr: &Expr, ctx: &Context) -> bool {
+    match expr {
+      Expr::Paren(paren) => self.is_derived_any(&paren.expr, ctx),
+      Expr::TsNonNull(non_null) => self.is_derived_any(&non_null.expr, ctx),
+      Expr::Member(member) => self.is_any(&member.obj, ctx),
+      Expr::Call(call) => match &call.callee {
+        Callee::Expr(callee) => self.is_any(callee, ctx),
+        _ => false,
+      },
+      _ => false,
+    }
+  }
+
+  /// Whether the identifier is annotated with `any` or `unknown`, which
+  /// means that it can safely hold an `any` value.
+  pub fn accepts_any(&self, id: &Id) -> bool {
+    self.bindings.contains(id) || self.unknown_bindings.contains(id)
+  }
+}
+
+fn is_json_parse(member: &deno_ast::view::MemberExpr, ctx: &Context) -> bool {
+  matches!(
+    (&member.obj, &member.prop),
+    (Expr::Ident(obj), MemberProp::Ident(prop))
+      if obj.sym() == "JSON"
+        && prop.sym() == "parse"
+        && ctx.scope().is_global(&obj.inner.to_id())
+  )
+}
+
+impl Handler for ExplicitAny {
+  fn binding_ident(&mut self, binding: &BindingIdent, _ctx: &mut Context) {
+    match binding.type_ann.and_then(keyword_kind) {
+      Some(TsKeywordTypeKind::TsAnyKeyword) => {
+        self.bindings.insert(binding.id.inner.to_id());
+      }
+      Some(TsKeywordTypeKind::TsUnknownKeyword) => {
+        self.unknown_bindings.insert(binding.id.inner.to_id());
+      }
+      _ => {}
+    }
+  }
+
+  fn fn_decl(&mut self, fn_decl: &FnDecl, _ctx: &mut Context) {
+    if fn_decl.function.return_type.and_then(keyword_kind)
+      == Some(TsKeywordTypeKind::TsAnyKeyword)
+    {
+      self.functions.insert(fn_decl.ident.inner.to_id());
+    }
+  }
+
+  fn var_declarator(&mut self, declarator: &VarDeclarator, ctx: &mut Context) {
+    let (Pat::Ident(binding), Some(init)) =
+      (&declarator.name, &declarator.init)
+    else {
+      return;
+    };
+    if binding.type_ann.is_none() && self.is_any(init, ctx) {
+      self.bindings.insert(binding.id.inner.to_id());
+      return;
+    }
+    let return_type = match init {
+      Expr::Arrow(arrow) => arrow.return_type,
+      Expr::Fn(fn_expr) => fn_expr.function.return_type,
+      _ => return,
+    };
+    if return_type.and_then(keyword_kind)
+      == Some(TsKeywordTypeKind::TsAnyKeyword)
+    {
+      self.functions.insert(binding.id.inner.to_id());
+    }
+  }
+}
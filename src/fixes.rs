@@ -0,0 +1,219 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+//! Applies the fixes of many diagnostics at once, like `deno lint --fix`.
+
+use crate::diagnostic::{LintDiagnostic, LintFix};
+use crate::linter::{LintFileOptions, Linter};
+use deno_ast::{apply_text_changes, ParseDiagnostic, ParsedSource, TextChange};
+use deno_ast::{SourceRange, SourceTextInfo};
+
+/// The text after applying fixes, with how many of them were applied.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AppliedFixes {
+  pub text: String,
+  pub applied: usize,
+  /// The fixes that were left out because they overlap with another fix.
+  pub skipped: usize,
+}
+
+/// Applies the first fix of each diagnostic to the source in one pass.
+///
+/// Fixes are applied in the order of their first change, and a fix is left
+/// out as a whole if any of its changes overlap with a fix that was already
+/// taken, since applying both would mangle the text. The left out fixes can
+/// be applied by linting the result again, which `apply_all_until_stable`
+/// does.
+pub fn apply_all(source: &str, diagnostics: &[LintDiagnostic]) -> AppliedFixes {
+  let mut fixes: Vec<(&SourceTextInfo, &LintFix)> = diagnostics
+    .iter()
+    .filter_map(|d| {
+      Some((&d.range.as_ref()?.text_info, d.details.fixes.first()?))
+    })
+    .filter(|(_, fix)| !fix.changes.is_empty())
+    .collect();
+  fixes.sort_by_key(|(_, fix)| {
+    fix
+      .changes
+      .iter()
+      .map(|change| (change.range.start, change.range.end))
+      .min()
+  });
+
+  let mut taken: Vec<SourceRange> = Vec::new();
+  let mut changes = Vec::new();
+  let mut skipped = 0;
+  for (text_info, fix) in &fixes {
+    let conflicts = fix
+      .changes
+      .iter()
+      .any(|change| taken.iter().any(|range| overlaps(&change.range, range)));
+    if conflicts {
+      skipped += 1;
+      continue;
+    }
+    let start = text_info.range().start;
+    for change in &fix.changes {
+      taken.push(change.range);
+      changes.push(TextChange::new(
+        change.range.start - start,
+        change.range.end - start,
+        change.new_text.to_string(),
+      ));
+    }
+  }
+
+  AppliedFixes {
+    text: apply_text_changes(source, changes),
+    applied: fixes.len() - skipped,
+    skipped,
+  }
+}
+
+/// Whether two changes can't both be applied. Changes that only touch don't
+/// overlap, except for two insertions at the same position, since the order
+/// of the inserted texts would be ambiguous.
+pub(crate) fn overlaps(a: &SourceRange, b: &SourceRange) -> bool {
+  (a.start < b.end && b.start < a.end) || a == b
+}
+
+/// The result of fixing a file until no more fixes apply.
+pub struct FixedFile {
+  pub parsed_source: ParsedSource,
+  /// The diagnostics that are left in the fixed file.
+  pub diagnostics: Vec<LintDiagnostic>,
+  /// How many fixes were applied over all rounds.
+  pub applied: usize,
+  pub rounds: usize,
+}
+
+/// Lints the file and applies all the fixes, then lints the fixed text
+/// again to apply the fixes that overlapped or were enabled by earlier
+/// fixes, until no fixes are left or after `max_rounds` rounds.
+///
+/// Returns an error if the file doesn't parse, which includes a fix that
+/// breaks the syntax of the file.
+pub fn apply_all_until_stable(
+  linter: &Linter,
+  options: LintFileOptions,
+  max_rounds: usize,
+) -> Result<FixedFile, ParseDiagnostic> {
+  let LintFileOptions {
+    specifier,
+    media_type,
+    config,
+    ..
+  } = &options;
+  let (specifier, media_type, config) =
+    (specifier.clone(), *media_type, config.clone());
+  let (mut parsed_source, mut diagnostics) = linter.lint_file(options)?;
+  let mut applied = 0;
+  let mut rounds = 0;
+  while rounds < max_rounds {
+    let fixes = apply_all(parsed_source.text(), &diagnostics);
+    if fixes.applied == 0 {
+      break;
+    }
+    applied += fixes.applied;
+    rounds += 1;
+    (parsed_source, diagnostics) = linter.lint_file(LintFileOptions {
+      specifier: specifier.clone(),
+      source_code: fixes.text,
+      media_type,
+      config: config.clone(),
+    })?;
+  }
+  Ok(FixedFile {
+    parsed_source,
+    diagnostics,
+    applied,
+    rounds,
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::linter::{LintConfig, LinterOptions};
+  use crate::rules::no_window::NoWindow;
+  use crate::rules::no_window_prefix::NoWindowPrefix;
+  use crate::rules::LintRule;
+  use crate::test_util;
+  use deno_ast::{MediaType, ModuleSpecifier};
+
+  #[test]
+  fn apply_all_fixes() {
+    let source = "window.foo();\nwindow.bar();";
+    let (_, diagnostics) =
+      test_util::lint(Box::new(NoWindow), source, "file:///a.ts");
+    assert_eq!(
+      apply_all(source, &diagnostics),
+      AppliedFixes {
+        text: "globalThis.foo();\nglobalThis.bar();".to_string(),
+        applied: 2,
+        skipped: 0,
+      }
+    );
+  }
+
+  #[test]
+  fn apply_all_skips_overlapping_fixes() {
+    let source = "window.foo();";
+    let (_, diagnostics) =
+      test_util::lint(Box::new(NoWindow), source, "file:///a.ts");
+    let duplicated = [diagnostics[0].clone(), diagnostics[0].clone()];
+    assert_eq!(
+      apply_all(source, &duplicated),
+      AppliedFixes {
+        text: "globalThis.foo();".to_string(),
+        applied: 1,
+        skipped: 1,
+      }
+    );
+    assert_eq!(apply_all(source, &[]).text, source);
+  }
+
+  fn options(source: &str) -> LintFileOptions {
+    LintFileOptions {
+      specifier: ModuleSpecifier::parse("file:///a.ts").unwrap(),
+      source_code: source.to_string(),
+      media_type: MediaType::TypeScript,
+      config: LintConfig {
+        default_jsx_factory: None,
+        default_jsx_fragment_factory: None,
+      },
+    }
+  }
+
+  #[test]
+  fn apply_all_until_no_fixes_are_left() {
+    let rules: Vec<Box<dyn LintRule>> =
+      vec![Box::new(NoWindow), Box::new(NoWindowPrefix)];
+    let linter = Linter::new(LinterOptions {
+      all_rule_codes: rules.iter().map(|rule| rule.code()).collect(),
+      rules,
+      custom_ignore_diagnostic_directive: None,
+      custom_ignore_file_directive: None,
+      resolver: None,
+      type_info: None,
+    });
+
+    // Both rules fix the same `window`, so the second fix is skipped and not
+    // needed anymore after the first round.
+    let fixed =
+      apply_all_until_stable(&linter, options("window.fetch(window.foo);"), 5)
+        .unwrap();
+    assert_eq!(
+      fixed.parsed_source.text().as_ref(),
+      "globalThis.fetch(globalThis.foo);"
+    );
+    assert_eq!(fixed.applied, 2);
+    assert_eq!(fixed.rounds, 1);
+    assert!(fixed.diagnostics.is_empty());
+
+    let fixed =
+      apply_all_until_stable(&linter, options("window.foo;"), 0).unwrap();
+    assert_eq!(fixed.parsed_source.text().as_ref(), "window.foo;");
+    assert_eq!(fixed.rounds, 0);
+    assert_eq!(fixed.diagnostics.len(), 1);
+  }
+}
@@ -1,11 +1,20 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
+//! The ambient globals of the environments that code runs in, which rules
+//! that need to know if an unresolved name exists share.
+
+use serde::Deserialize;
+use std::collections::HashMap;
+
+/// A global with whether code may assign to it.
+pub type Global = (&'static str, bool);
+
 /// List of globals available in Deno environment.
 ///
 /// Boolean tells if global can be overwritten
 ///
 /// Adapted from https://www.npmjs.com/package/globals
-pub static GLOBALS: &[(&str, bool)] = &[
+pub static GLOBALS: &[Global] = &[
   ("AbortController", false),
   ("AbortSignal", false),
   ("addEventListener", false),
@@ -218,3 +227,205 @@ pub static GLOBALS: &[(&str, bool)] = &[
   ("WritableStreamDefaultController", false),
   ("WritableStreamDefaultWriter", false),
 ];
+
+/// Globals of the DOM, which browsers have and Deno doesn't.
+pub static DOM_GLOBALS: &[Global] = &[
+  ("cancelAnimationFrame", false),
+  ("customElements", false),
+  ("document", false),
+  ("Document", false),
+  ("DocumentFragment", false),
+  ("Element", false),
+  ("frames", false),
+  ("history", false),
+  ("HTMLElement", false),
+  ("HTMLInputElement", false),
+  ("IntersectionObserver", false),
+  ("KeyboardEvent", false),
+  ("MouseEvent", false),
+  ("MutationObserver", false),
+  ("Node", false),
+  ("NodeList", false),
+  ("onclick", true),
+  ("onkeydown", true),
+  ("onresize", true),
+  ("parent", false),
+  ("requestAnimationFrame", false),
+  ("ResizeObserver", false),
+  ("screen", false),
+  ("ShadowRoot", false),
+  ("top", false),
+];
+
+/// Globals of web workers that Deno doesn't have.
+pub static WORKER_GLOBALS: &[Global] = &[("importScripts", false)];
+
+/// Globals of Node.js, which Deno provides to npm packages and CommonJS
+/// modules.
+pub static NODE_GLOBALS: &[Global] = &[
+  ("__dirname", false),
+  ("__filename", false),
+  ("Buffer", false),
+  ("clearImmediate", false),
+  ("exports", true),
+  ("global", false),
+  ("module", false),
+  ("process", false),
+  ("require", false),
+  ("setImmediate", false),
+];
+
+/// Globals of Deno that are specific to it, so browsers don't have them.
+static DENO_ONLY_GLOBALS: &[&str] = &["Deno"];
+
+/// Globals of Deno that only exist on the main thread of a browser, so
+/// workers don't have them.
+static WINDOW_ONLY_GLOBALS: &[&str] = &[
+  "alert",
+  "confirm",
+  "localStorage",
+  "onbeforeunload",
+  "onload",
+  "onunload",
+  "prompt",
+  "sessionStorage",
+  "window",
+  "Window",
+];
+
+/// An environment that code runs in, which determines the globals it can
+/// use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Environment {
+  Deno,
+  /// The main thread of a browser, with the DOM.
+  Browser,
+  /// A web worker.
+  Worker,
+  /// The globals of Node.js that Deno provides for compatibility.
+  Node,
+}
+
+impl Environment {
+  /// The global of the environment with the name, if there is one.
+  pub fn global(self, name: &str) -> Option<Global> {
+    let find = |globals: &[Global]| {
+      globals.iter().find(|(global, _)| *global == name).copied()
+    };
+    let web_global = || {
+      if DENO_ONLY_GLOBALS.contains(&name) {
+        None
+      } else {
+        find(GLOBALS)
+      }
+    };
+    match self {
+      Environment::Deno => find(GLOBALS),
+      Environment::Browser => web_global().or_else(|| find(DOM_GLOBALS)),
+      Environment::Worker => web_global()
+        .filter(|(name, _)| !WINDOW_ONLY_GLOBALS.contains(name))
+        .or_else(|| find(WORKER_GLOBALS)),
+      Environment::Node => find(NODE_GLOBALS),
+    }
+  }
+
+  /// Whether the name is a global in the environment.
+  pub fn has_global(self, name: &str) -> bool {
+    self.global(name).is_some()
+  }
+}
+
+/// The globals that code can use, which are the globals of its environments
+/// and any that embedders add, e.g. for the globals of a custom runtime.
+#[derive(Debug, Clone)]
+pub struct Globals {
+  environments: Vec<Environment>,
+  /// Added globals by their name, with whether code may assign to them.
+  custom: HashMap<String, bool>,
+}
+
+impl Default for Globals {
+  /// The globals of Deno.
+  fn default() -> Self {
+    Self::new([Environment::Deno])
+  }
+}
+
+impl Globals {
+  pub fn new(environments: impl IntoIterator<Item = Environment>) -> Self {
+    Self {
+      environments: environments.into_iter().collect(),
+      custom: HashMap::new(),
+    }
+  }
+
+  /// Adds a global, which replaces whether code may assign to it if it's
+  /// already a global of an environment.
+  pub fn add(&mut self, name: impl Into<String>, writable: bool) {
+    self.custom.insert(name.into(), writable);
+  }
+
+  pub fn with_global(
+    mut self,
+    name: impl Into<String>,
+    writable: bool,
+  ) -> Self {
+    self.add(name, writable);
+    self
+  }
+
+  pub fn is_global(&self, name: &str) -> bool {
+    self.is_writable(name).is_some()
+  }
+
+  /// Whether code may assign to the global, or `None` if the name isn't a
+  /// global. A global is writable if any of the environments lets code
+  /// assign to it.
+  pub fn is_writable(&self, name: &str) -> Option<bool> {
+    if let Some(writable) = self.custom.get(name) {
+      return Some(*writable);
+    }
+    self
+      .environments
+      .iter()
+      .filter_map(|env| env.global(name))
+      .map(|(_, writable)| writable)
+      .reduce(|a, b| a || b)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn environment_globals() {
+    assert!(Environment::Deno.has_global("Deno"));
+    assert!(!Environment::Deno.has_global("document"));
+    assert!(Environment::Browser.has_global("document"));
+    assert!(Environment::Browser.has_global("fetch"));
+    assert!(!Environment::Browser.has_global("Deno"));
+    assert!(Environment::Worker.has_global("importScripts"));
+    assert!(Environment::Worker.has_global("self"));
+    assert!(!Environment::Worker.has_global("window"));
+    assert!(Environment::Node.has_global("Buffer"));
+    assert!(!Environment::Node.has_global("fetch"));
+  }
+
+  #[test]
+  fn globals() {
+    let globals = Globals::default();
+    assert_eq!(globals.is_writable("Object"), Some(false));
+    assert_eq!(globals.is_writable("onmessage"), Some(true));
+    assert_eq!(globals.is_writable("process"), None);
+
+    let globals = Globals::new([Environment::Deno, Environment::Node])
+      .with_global("Bun", false)
+      .with_global("Object", true);
+    assert!(globals.is_global("process"));
+    assert!(globals.is_global("Bun"));
+    assert_eq!(globals.is_writable("Object"), Some(true));
+    assert!(!globals.is_global("document"));
+  }
+}
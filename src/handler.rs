@@ -2,7 +2,7 @@
 
 use crate::context::Context;
 use deno_ast::view as ast_view;
-use deno_ast::view::NodeTrait;
+use deno_ast::view::{NodeKind, NodeTrait};
 
 pub trait Handler {
   fn on_enter_node(&mut self, _n: ast_view::Node, _ctx: &mut Context) {}
@@ -540,180 +540,7 @@ pub trait Traverse: Handler {
     self.on_enter_node(node, ctx);
 
     // Next, invoke a handler that is specific to the type of node.
-    use deno_ast::view::Node::*;
-    match node {
-      ArrayLit(n) => self.array_lit(n, ctx),
-      ArrayPat(n) => self.array_pat(n, ctx),
-      ArrowExpr(n) => self.arrow_expr(n, ctx),
-      AssignExpr(n) => self.assign_expr(n, ctx),
-      AssignPat(n) => self.assign_pat(n, ctx),
-      AssignPatProp(n) => self.assign_pat_prop(n, ctx),
-      AssignProp(n) => self.assign_prop(n, ctx),
-      AutoAccessor(n) => self.auto_accessor(n, ctx),
-      AwaitExpr(n) => self.await_expr(n, ctx),
-      BigInt(n) => self.big_int(n, ctx),
-      BinExpr(n) => self.bin_expr(n, ctx),
-      BindingIdent(n) => self.binding_ident(n, ctx),
-      BlockStmt(n) => self.block_stmt(n, ctx),
-      Bool(n) => self.bool(n, ctx),
-      BreakStmt(n) => self.break_stmt(n, ctx),
-      CallExpr(n) => self.call_expr(n, ctx),
-      CatchClause(n) => self.catch_clause(n, ctx),
-      Class(n) => self.class(n, ctx),
-      ClassDecl(n) => self.class_decl(n, ctx),
-      ClassExpr(n) => self.class_expr(n, ctx),
-      ClassMethod(n) => self.class_method(n, ctx),
-      ClassProp(n) => self.class_prop(n, ctx),
-      ComputedPropName(n) => self.computed_prop_name(n, ctx),
-      CondExpr(n) => self.cond_expr(n, ctx),
-      Constructor(n) => self.constructor(n, ctx),
-      ContinueStmt(n) => self.continue_stmt(n, ctx),
-      DebuggerStmt(n) => self.debugger_stmt(n, ctx),
-      Decorator(n) => self.decorator(n, ctx),
-      DoWhileStmt(n) => self.do_while_stmt(n, ctx),
-      EmptyStmt(n) => self.empty_stmt(n, ctx),
-      ExportAll(n) => self.export_all(n, ctx),
-      ExportDecl(n) => self.export_decl(n, ctx),
-      ExportDefaultDecl(n) => self.export_default_decl(n, ctx),
-      ExportDefaultExpr(n) => self.export_default_expr(n, ctx),
-      ExportDefaultSpecifier(n) => self.export_default_specifier(n, ctx),
-      ExportNamedSpecifier(n) => self.export_named_specifier(n, ctx),
-      ExportNamespaceSpecifier(n) => self.export_namespace_specifier(n, ctx),
-      ExprOrSpread(n) => self.expr_or_spread(n, ctx),
-      ExprStmt(n) => self.expr_stmt(n, ctx),
-      FnDecl(n) => self.fn_decl(n, ctx),
-      FnExpr(n) => self.fn_expr(n, ctx),
-      ForInStmt(n) => self.for_in_stmt(n, ctx),
-      ForOfStmt(n) => self.for_of_stmt(n, ctx),
-      ForStmt(n) => self.for_stmt(n, ctx),
-      Function(n) => self.function(n, ctx),
-      GetterProp(n) => self.getter_prop(n, ctx),
-      Ident(n) => self.ident(n, ctx),
-      IdentName(n) => self.ident_name(n, ctx),
-      IfStmt(n) => self.if_stmt(n, ctx),
-      Import(n) => self.import(n, ctx),
-      ImportDecl(n) => self.import_decl(n, ctx),
-      ImportDefaultSpecifier(n) => self.import_default_specifier(n, ctx),
-      ImportNamedSpecifier(n) => self.import_named_specifier(n, ctx),
-      ImportStarAsSpecifier(n) => self.import_star_as_specifier(n, ctx),
-      Invalid(n) => self.invalid(n, ctx),
-      JSXAttr(n) => self.jsx_attr(n, ctx),
-      JSXClosingElement(n) => self.jsx_closing_element(n, ctx),
-      JSXClosingFragment(n) => self.jsx_closing_fragment(n, ctx),
-      JSXElement(n) => self.jsx_element(n, ctx),
-      JSXEmptyExpr(n) => self.jsx_empty_expr(n, ctx),
-      JSXExprContainer(n) => self.jsx_expr_container(n, ctx),
-      JSXFragment(n) => self.jsx_fragment(n, ctx),
-      JSXMemberExpr(n) => self.jsx_member_expr(n, ctx),
-      JSXNamespacedName(n) => self.jsx_namespaced_name(n, ctx),
-      JSXOpeningElement(n) => self.jsx_opening_element(n, ctx),
-      JSXOpeningFragment(n) => self.jsx_opening_fragment(n, ctx),
-      JSXSpreadChild(n) => self.jsx_spread_child(n, ctx),
-      JSXText(n) => self.jsx_text(n, ctx),
-      KeyValuePatProp(n) => self.key_value_pat_prop(n, ctx),
-      KeyValueProp(n) => self.key_value_prop(n, ctx),
-      LabeledStmt(n) => self.labeled_stmt(n, ctx),
-      MemberExpr(n) => self.member_expr(n, ctx),
-      MetaPropExpr(n) => self.meta_prop_expr(n, ctx),
-      MethodProp(n) => self.method_prop(n, ctx),
-      Module(n) => self.module(n, ctx),
-      NamedExport(n) => self.named_export(n, ctx),
-      NewExpr(n) => self.new_expr(n, ctx),
-      Null(n) => self.null(n, ctx),
-      Number(n) => self.number(n, ctx),
-      ObjectLit(n) => self.object_lit(n, ctx),
-      ObjectPat(n) => self.object_pat(n, ctx),
-      OptChainExpr(n) => self.opt_chain_expr(n, ctx),
-      OptCall(n) => self.opt_call(n, ctx),
-      Param(n) => self.param(n, ctx),
-      ParenExpr(n) => self.paren_expr(n, ctx),
-      PrivateMethod(n) => self.private_method(n, ctx),
-      PrivateName(n) => self.private_name(n, ctx),
-      PrivateProp(n) => self.private_prop(n, ctx),
-      Regex(n) => self.regex(n, ctx),
-      RestPat(n) => self.rest_pat(n, ctx),
-      ReturnStmt(n) => self.return_stmt(n, ctx),
-      Script(n) => self.script(n, ctx),
-      SeqExpr(n) => self.seq_expr(n, ctx),
-      SetterProp(n) => self.setter_prop(n, ctx),
-      SpreadElement(n) => self.spread_element(n, ctx),
-      StaticBlock(n) => self.static_block(n, ctx),
-      Str(n) => self.str(n, ctx),
-      Super(n) => self.super_(n, ctx),
-      SuperPropExpr(n) => self.super_prop_expr(n, ctx),
-      SwitchCase(n) => self.switch_case(n, ctx),
-      SwitchStmt(n) => self.switch_stmt(n, ctx),
-      TaggedTpl(n) => self.tagged_tpl(n, ctx),
-      ThisExpr(n) => self.this_expr(n, ctx),
-      ThrowStmt(n) => self.throw_stmt(n, ctx),
-      Tpl(n) => self.tpl(n, ctx),
-      TplElement(n) => self.tpl_element(n, ctx),
-      TryStmt(n) => self.try_stmt(n, ctx),
-      TsArrayType(n) => self.ts_array_type(n, ctx),
-      TsAsExpr(n) => self.ts_as_expr(n, ctx),
-      TsCallSignatureDecl(n) => self.ts_call_signature_decl(n, ctx),
-      TsConditionalType(n) => self.ts_conditional_type(n, ctx),
-      TsConstAssertion(n) => self.ts_const_assertion(n, ctx),
-      TsConstructSignatureDecl(n) => self.ts_construct_signature_decl(n, ctx),
-      TsConstructorType(n) => self.ts_constructor_type(n, ctx),
-      TsEnumDecl(n) => self.ts_enum_decl(n, ctx),
-      TsEnumMember(n) => self.ts_enum_member(n, ctx),
-      TsExportAssignment(n) => self.ts_export_assignment(n, ctx),
-      TsExprWithTypeArgs(n) => self.ts_expr_with_type_args(n, ctx),
-      TsExternalModuleRef(n) => self.ts_external_module_ref(n, ctx),
-      TsFnType(n) => self.ts_fn_type(n, ctx),
-      TsGetterSignature(n) => self.ts_getter_signature(n, ctx),
-      TsImportEqualsDecl(n) => self.ts_import_equal_decl(n, ctx),
-      TsImportType(n) => self.ts_import_type(n, ctx),
-      TsIndexSignature(n) => self.ts_index_signature(n, ctx),
-      TsIndexedAccessType(n) => self.ts_indexed_access_type(n, ctx),
-      TsInferType(n) => self.ts_infer_type(n, ctx),
-      TsInstantiation(n) => self.ts_instantiation(n, ctx),
-      TsInterfaceBody(n) => self.ts_interface_body(n, ctx),
-      TsInterfaceDecl(n) => self.ts_interface_decl(n, ctx),
-      TsIntersectionType(n) => self.ts_intersection_type(n, ctx),
-      TsKeywordType(n) => self.ts_keyword_type(n, ctx),
-      TsLitType(n) => self.ts_lit_type(n, ctx),
-      TsMappedType(n) => self.ts_mapped_type(n, ctx),
-      TsMethodSignature(n) => self.ts_method_signature(n, ctx),
-      TsModuleBlock(n) => self.ts_module_block(n, ctx),
-      TsModuleDecl(n) => self.ts_module_decl(n, ctx),
-      TsNamespaceDecl(n) => self.ts_namespace_decl(n, ctx),
-      TsNamespaceExportDecl(n) => self.ts_namespace_export_decl(n, ctx),
-      TsNonNullExpr(n) => self.ts_non_null_expr(n, ctx),
-      TsOptionalType(n) => self.ts_optional_type(n, ctx),
-      TsParamProp(n) => self.ts_param_prop(n, ctx),
-      TsParenthesizedType(n) => self.ts_parenthesized_type(n, ctx),
-      TsPropertySignature(n) => self.ts_property_signature(n, ctx),
-      TsQualifiedName(n) => self.ts_qualified_name(n, ctx),
-      TsRestType(n) => self.ts_rest_type(n, ctx),
-      TsSatisfiesExpr(n) => self.ts_satisfies_expr(n, ctx),
-      TsSetterSignature(n) => self.ts_setter_signature(n, ctx),
-      TsThisType(n) => self.ts_this_type(n, ctx),
-      TsTplLitType(n) => self.ts_tpl_lit_type(n, ctx),
-      TsTupleElement(n) => self.ts_tuple_element(n, ctx),
-      TsTupleType(n) => self.ts_tuple_type(n, ctx),
-      TsTypeAliasDecl(n) => self.ts_type_alias_decl(n, ctx),
-      TsTypeAnn(n) => self.ts_type_ann(n, ctx),
-      TsTypeAssertion(n) => self.ts_type_assertion(n, ctx),
-      TsTypeLit(n) => self.ts_type_lit(n, ctx),
-      TsTypeOperator(n) => self.ts_type_operator(n, ctx),
-      TsTypeParam(n) => self.ts_type_param(n, ctx),
-      TsTypeParamDecl(n) => self.ts_type_param_decl(n, ctx),
-      TsTypeParamInstantiation(n) => self.ts_type_param_instantiation(n, ctx),
-      TsTypePredicate(n) => self.ts_type_predicate(n, ctx),
-      TsTypeQuery(n) => self.ts_type_query(n, ctx),
-      TsTypeRef(n) => self.ts_type_ref(n, ctx),
-      TsUnionType(n) => self.ts_union_type(n, ctx),
-      UnaryExpr(n) => self.unary_expr(n, ctx),
-      UpdateExpr(n) => self.update_expr(n, ctx),
-      UsingDecl(n) => self.using_decl(n, ctx),
-      VarDecl(n) => self.var_decl(n, ctx),
-      VarDeclarator(n) => self.var_declarator(n, ctx),
-      WhileStmt(n) => self.while_stmt(n, ctx),
-      WithStmt(n) => self.with_stmt(n, ctx),
-      YieldExpr(n) => self.yield_expr(n, ctx),
-    };
+    handle_node(self, node, ctx);
 
     // Walk the child nodes recursively.
     if !ctx.should_stop_traverse() {
@@ -727,4 +554,301 @@ pub trait Traverse: Handler {
   }
 }
 
-impl<H: Handler> Traverse for H {}
+impl<H: Handler + ?Sized> Traverse for H {}
+
+/// Calls the method of the handler that is specific to the type of node.
+fn handle_node<H: Handler + ?Sized>(
+  handler: &mut H,
+  node: ast_view::Node,
+  ctx: &mut Context,
+) {
+  use deno_ast::view::Node::*;
+  match node {
+    ArrayLit(n) => handler.array_lit(n, ctx),
+    ArrayPat(n) => handler.array_pat(n, ctx),
+    ArrowExpr(n) => handler.arrow_expr(n, ctx),
+    AssignExpr(n) => handler.assign_expr(n, ctx),
+    AssignPat(n) => handler.assign_pat(n, ctx),
+    AssignPatProp(n) => handler.assign_pat_prop(n, ctx),
+    AssignProp(n) => handler.assign_prop(n, ctx),
+    AutoAccessor(n) => handler.auto_accessor(n, ctx),
+    AwaitExpr(n) => handler.await_expr(n, ctx),
+    BigInt(n) => handler.big_int(n, ctx),
+    BinExpr(n) => handler.bin_expr(n, ctx),
+    BindingIdent(n) => handler.binding_ident(n, ctx),
+    BlockStmt(n) => handler.block_stmt(n, ctx),
+    Bool(n) => handler.bool(n, ctx),
+    BreakStmt(n) => handler.break_stmt(n, ctx),
+    CallExpr(n) => handler.call_expr(n, ctx),
+    CatchClause(n) => handler.catch_clause(n, ctx),
+    Class(n) => handler.class(n, ctx),
+    ClassDecl(n) => handler.class_decl(n, ctx),
+    ClassExpr(n) => handler.class_expr(n, ctx),
+    ClassMethod(n) => handler.class_method(n, ctx),
+    ClassProp(n) => handler.class_prop(n, ctx),
+    ComputedPropName(n) => handler.computed_prop_name(n, ctx),
+    CondExpr(n) => handler.cond_expr(n, ctx),
+    Constructor(n) => handler.constructor(n, ctx),
+    ContinueStmt(n) => handler.continue_stmt(n, ctx),
+    DebuggerStmt(n) => handler.debugger_stmt(n, ctx),
+    Decorator(n) => handler.decorator(n, ctx),
+    DoWhileStmt(n) => handler.do_while_stmt(n, ctx),
+    EmptyStmt(n) => handler.empty_stmt(n, ctx),
+    ExportAll(n) => handler.export_all(n, ctx),
+    ExportDecl(n) => handler.export_decl(n, ctx),
+    ExportDefaultDecl(n) => handler.export_default_decl(n, ctx),
+    ExportDefaultExpr(n) => handler.export_default_expr(n, ctx),
+    ExportDefaultSpecifier(n) => handler.export_default_specifier(n, ctx),
+    ExportNamedSpecifier(n) => handler.export_named_specifier(n, ctx),
+    ExportNamespaceSpecifier(n) => handler.export_namespace_specifier(n, ctx),
+    ExprOrSpread(n) => handler.expr_or_spread(n, ctx),
+    ExprStmt(n) => handler.expr_stmt(n, ctx),
+    FnDecl(n) => handler.fn_decl(n, ctx),
+    FnExpr(n) => handler.fn_expr(n, ctx),
+    ForInStmt(n) => handler.for_in_stmt(n, ctx),
+    ForOfStmt(n) => handler.for_of_stmt(n, ctx),
+    ForStmt(n) => handler.for_stmt(n, ctx),
+    Function(n) => handler.function(n, ctx),
+    GetterProp(n) => handler.getter_prop(n, ctx),
+    Ident(n) => handler.ident(n, ctx),
+    IdentName(n) => handler.ident_name(n, ctx),
+    IfStmt(n) => handler.if_stmt(n, ctx),
+    Import(n) => handler.import(n, ctx),
+    ImportDecl(n) => handler.import_decl(n, ctx),
+    ImportDefaultSpecifier(n) => handler.import_default_specifier(n, ctx),
+    ImportNamedSpecifier(n) => handler.import_named_specifier(n, ctx),
+    ImportStarAsSpecifier(n) => handler.import_star_as_specifier(n, ctx),
+    Invalid(n) => handler.invalid(n, ctx),
+    JSXAttr(n) => handler.jsx_attr(n, ctx),
+    JSXClosingElement(n) => handler.jsx_closing_element(n, ctx),
+    JSXClosingFragment(n) => handler.jsx_closing_fragment(n, ctx),
+    JSXElement(n) => handler.jsx_element(n, ctx),
+    JSXEmptyExpr(n) => handler.jsx_empty_expr(n, ctx),
+    JSXExprContainer(n) => handler.jsx_expr_container(n, ctx),
+    JSXFragment(n) => handler.jsx_fragment(n, ctx),
+    JSXMemberExpr(n) => handler.jsx_member_expr(n, ctx),
+    JSXNamespacedName(n) => handler.jsx_namespaced_name(n, ctx),
+    JSXOpeningElement(n) => handler.jsx_opening_element(n, ctx),
+    JSXOpeningFragment(n) => handler.jsx_opening_fragment(n, ctx),
+    JSXSpreadChild(n) => handler.jsx_spread_child(n, ctx),
+    JSXText(n) => handler.jsx_text(n, ctx),
+    KeyValuePatProp(n) => handler.key_value_pat_prop(n, ctx),
+    KeyValueProp(n) => handler.key_value_prop(n, ctx),
+    LabeledStmt(n) => handler.labeled_stmt(n, ctx),
+    MemberExpr(n) => handler.member_expr(n, ctx),
+    MetaPropExpr(n) => handler.meta_prop_expr(n, ctx),
+    MethodProp(n) => handler.method_prop(n, ctx),
+    Module(n) => handler.module(n, ctx),
+    NamedExport(n) => handler.named_export(n, ctx),
+    NewExpr(n) => handler.new_expr(n, ctx),
+    Null(n) => handler.null(n, ctx),
+    Number(n) => handler.number(n, ctx),
+    ObjectLit(n) => handler.object_lit(n, ctx),
+    ObjectPat(n) => handler.object_pat(n, ctx),
+    OptChainExpr(n) => handler.opt_chain_expr(n, ctx),
+    OptCall(n) => handler.opt_call(n, ctx),
+    Param(n) => handler.param(n, ctx),
+    ParenExpr(n) => handler.paren_expr(n, ctx),
+    PrivateMethod(n) => handler.private_method(n, ctx),
+    PrivateName(n) => handler.private_name(n, ctx),
+    PrivateProp(n) => handler.private_prop(n, ctx),
+    Regex(n) => handler.regex(n, ctx),
+    RestPat(n) => handler.rest_pat(n, ctx),
+    ReturnStmt(n) => handler.return_stmt(n, ctx),
+    Script(n) => handler.script(n, ctx),
+    SeqExpr(n) => handler.seq_expr(n, ctx),
+    SetterProp(n) => handler.setter_prop(n, ctx),
+    SpreadElement(n) => handler.spread_element(n, ctx),
+    StaticBlock(n) => handler.static_block(n, ctx),
+    Str(n) => handler.str(n, ctx),
+    Super(n) => handler.super_(n, ctx),
+    SuperPropExpr(n) => handler.super_prop_expr(n, ctx),
+    SwitchCase(n) => handler.switch_case(n, ctx),
+    SwitchStmt(n) => handler.switch_stmt(n, ctx),
+    TaggedTpl(n) => handler.tagged_tpl(n, ctx),
+    ThisExpr(n) => handler.this_expr(n, ctx),
+    ThrowStmt(n) => handler.throw_stmt(n, ctx),
+    Tpl(n) => handler.tpl(n, ctx),
+    TplElement(n) => handler.tpl_element(n, ctx),
+    TryStmt(n) => handler.try_stmt(n, ctx),
+    TsArrayType(n) => handler.ts_array_type(n, ctx),
+    TsAsExpr(n) => handler.ts_as_expr(n, ctx),
+    TsCallSignatureDecl(n) => handler.ts_call_signature_decl(n, ctx),
+    TsConditionalType(n) => handler.ts_conditional_type(n, ctx),
+    TsConstAssertion(n) => handler.ts_const_assertion(n, ctx),
+    TsConstructSignatureDecl(n) => handler.ts_construct_signature_decl(n, ctx),
+    TsConstructorType(n) => handler.ts_constructor_type(n, ctx),
+    TsEnumDecl(n) => handler.ts_enum_decl(n, ctx),
+    TsEnumMember(n) => handler.ts_enum_member(n, ctx),
+    TsExportAssignment(n) => handler.ts_export_assignment(n, ctx),
+    TsExprWithTypeArgs(n) => handler.ts_expr_with_type_args(n, ctx),
+    TsExternalModuleRef(n) => handler.ts_external_module_ref(n, ctx),
+    TsFnType(n) => handler.ts_fn_type(n, ctx),
+    TsGetterSignature(n) => handler.ts_getter_signature(n, ctx),
+    TsImportEqualsDecl(n) => handler.ts_import_equal_decl(n, ctx),
+    TsImportType(n) => handler.ts_import_type(n, ctx),
+    TsIndexSignature(n) => handler.ts_index_signature(n, ctx),
+    TsIndexedAccessType(n) => handler.ts_indexed_access_type(n, ctx),
+    TsInferType(n) => handler.ts_infer_type(n, ctx),
+    TsInstantiation(n) => handler.ts_instantiation(n, ctx),
+    TsInterfaceBody(n) => handler.ts_interface_body(n, ctx),
+    TsInterfaceDecl(n) => handler.ts_interface_decl(n, ctx),
+    TsIntersectionType(n) => handler.ts_intersection_type(n, ctx),
+    TsKeywordType(n) => handler.ts_keyword_type(n, ctx),
+    TsLitType(n) => handler.ts_lit_type(n, ctx),
+    TsMappedType(n) => handler.ts_mapped_type(n, ctx),
+    TsMethodSignature(n) => handler.ts_method_signature(n, ctx),
+    TsModuleBlock(n) => handler.ts_module_block(n, ctx),
+    TsModuleDecl(n) => handler.ts_module_decl(n, ctx),
+    TsNamespaceDecl(n) => handler.ts_namespace_decl(n, ctx),
+    TsNamespaceExportDecl(n) => handler.ts_namespace_export_decl(n, ctx),
+    TsNonNullExpr(n) => handler.ts_non_null_expr(n, ctx),
+    TsOptionalType(n) => handler.ts_optional_type(n, ctx),
+    TsParamProp(n) => handler.ts_param_prop(n, ctx),
+    TsParenthesizedType(n) => handler.ts_parenthesized_type(n, ctx),
+    TsPropertySignature(n) => handler.ts_property_signature(n, ctx),
+    TsQualifiedName(n) => handler.ts_qualified_name(n, ctx),
+    TsRestType(n) => handler.ts_rest_type(n, ctx),
+    TsSatisfiesExpr(n) => handler.ts_satisfies_expr(n, ctx),
+    TsSetterSignature(n) => handler.ts_setter_signature(n, ctx),
+    TsThisType(n) => handler.ts_this_type(n, ctx),
+    TsTplLitType(n) => handler.ts_tpl_lit_type(n, ctx),
+    TsTupleElement(n) => handler.ts_tuple_element(n, ctx),
+    TsTupleType(n) => handler.ts_tuple_type(n, ctx),
+    TsTypeAliasDecl(n) => handler.ts_type_alias_decl(n, ctx),
+    TsTypeAnn(n) => handler.ts_type_ann(n, ctx),
+    TsTypeAssertion(n) => handler.ts_type_assertion(n, ctx),
+    TsTypeLit(n) => handler.ts_type_lit(n, ctx),
+    TsTypeOperator(n) => handler.ts_type_operator(n, ctx),
+    TsTypeParam(n) => handler.ts_type_param(n, ctx),
+    TsTypeParamDecl(n) => handler.ts_type_param_decl(n, ctx),
+    TsTypeParamInstantiation(n) => handler.ts_type_param_instantiation(n, ctx),
+    TsTypePredicate(n) => handler.ts_type_predicate(n, ctx),
+    TsTypeQuery(n) => handler.ts_type_query(n, ctx),
+    TsTypeRef(n) => handler.ts_type_ref(n, ctx),
+    TsUnionType(n) => handler.ts_union_type(n, ctx),
+    UnaryExpr(n) => handler.unary_expr(n, ctx),
+    UpdateExpr(n) => handler.update_expr(n, ctx),
+    UsingDecl(n) => handler.using_decl(n, ctx),
+    VarDecl(n) => handler.var_decl(n, ctx),
+    VarDeclarator(n) => handler.var_declarator(n, ctx),
+    WhileStmt(n) => handler.while_stmt(n, ctx),
+    WithStmt(n) => handler.with_stmt(n, ctx),
+    YieldExpr(n) => handler.yield_expr(n, ctx),
+  };
+}
+
+/// Runs the handlers of many rules in one traversal of the AST, calling each
+/// handler only for the kinds of nodes it's interested in.
+///
+/// A handler that calls `ctx.stop_traverse()` skips the children of the node
+/// like it would in its own traversal, while the other handlers still visit
+/// them.
+pub(crate) struct Dispatcher<'a> {
+  handlers: Vec<Box<dyn Handler + 'a>>,
+  /// The indexes of the handlers to call for each kind of node, indexed by
+  /// `NodeKind as usize`. Kinds past the end only have the handlers of
+  /// `all_kinds`.
+  by_kind: Vec<Vec<usize>>,
+  /// The indexes of the handlers that are called for all nodes.
+  all_kinds: Vec<usize>,
+  /// The depth of the node whose children each handler skips, if any.
+  stopped_at: Vec<Option<usize>>,
+}
+
+impl<'a> Dispatcher<'a> {
+  pub fn new() -> Self {
+    Self {
+      handlers: Vec::new(),
+      by_kind: Vec::new(),
+      all_kinds: Vec::new(),
+      stopped_at: Vec::new(),
+    }
+  }
+
+  /// Adds a handler for the given kinds of nodes, or for all nodes if
+  /// `kinds` is `None`.
+  pub fn add(
+    &mut self,
+    handler: Box<dyn Handler + 'a>,
+    kinds: Option<&[NodeKind]>,
+  ) {
+    let index = self.handlers.len();
+    self.handlers.push(handler);
+    self.stopped_at.push(None);
+    match kinds {
+      Some(kinds) => {
+        for kind in kinds {
+          let kind = *kind as usize;
+          if self.by_kind.len() <= kind {
+            self.by_kind.resize(kind + 1, self.all_kinds.clone());
+          }
+          if self.by_kind[kind].last() != Some(&index) {
+            self.by_kind[kind].push(index);
+          }
+        }
+      }
+      None => {
+        self.all_kinds.push(index);
+        for indexes in self.by_kind.iter_mut() {
+          indexes.push(index);
+        }
+      }
+    }
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.handlers.is_empty()
+  }
+
+  pub fn traverse<'view, N>(&mut self, node: N, ctx: &mut Context)
+  where
+    N: NodeTrait<'view>,
+  {
+    self.traverse_node(node.as_node(), 0, ctx);
+  }
+
+  fn traverse_node(
+    &mut self,
+    node: ast_view::Node,
+    depth: usize,
+    ctx: &mut Context,
+  ) {
+    let indexes = self
+      .by_kind
+      .get(node.kind() as usize)
+      .unwrap_or(&self.all_kinds);
+
+    for &index in indexes {
+      if self.stopped_at[index].is_some() {
+        continue;
+      }
+      ctx.assert_traverse_init();
+      let handler = self.handlers[index].as_mut();
+      handler.on_enter_node(node, ctx);
+      handle_node(handler, node, ctx);
+      if ctx.should_stop_traverse() {
+        self.stopped_at[index] = Some(depth);
+      }
+    }
+
+    for child in node.children() {
+      self.traverse_node(child, depth + 1, ctx);
+    }
+
+    let indexes = self
+      .by_kind
+      .get(node.kind() as usize)
+      .unwrap_or(&self.all_kinds);
+    for &index in indexes {
+      match self.stopped_at[index] {
+        None => {}
+        Some(stopped_at) if stopped_at == depth => {
+          self.stopped_at[index] = None;
+        }
+        Some(_) => continue,
+      }
+      self.handlers[index].on_exit_node(node, ctx);
+    }
+  }
+}
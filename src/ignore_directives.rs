@@ -4,6 +4,7 @@ use deno_ast::SourceRangedForSpanned;
 use deno_ast::SourceTextInfoProvider;
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
+use crate::rules::resolve_rule_code;
 use deno_ast::swc::common::comments::Comment;
 use deno_ast::swc::common::comments::CommentKind;
 use deno_ast::view as ast_view;
@@ -156,7 +157,7 @@ fn parse_ignore_comment<T: DirectiveKind>(
           if code.is_empty() {
             None
           } else {
-            let code = code.trim().to_string();
+            let code = resolve_rule_code(code.trim()).to_string();
             Some((code, CodeStatus::default()))
           }
         })
@@ -354,4 +355,22 @@ const x = 42;
       },
     );
   }
+
+  #[test]
+  fn test_parse_ignore_directives_with_aliases() {
+    test_util::parse_and_then(
+      "// deno-lint-ignore-file @typescript-eslint/no-explicit-any\n// deno-lint-ignore react-hooks/exhaustive-deps foo\nuseEffect(() => {}, []);",
+      |program| {
+        let file_directive =
+          parse_file_ignore_directives("deno-lint-ignore-file", program)
+            .unwrap();
+        assert_eq!(file_directive.codes, code_map(["no-explicit-any"]));
+
+        let line_directives =
+          parse_line_ignore_directives("deno-lint-ignore", program);
+        let d = line_directives.get(&1).unwrap();
+        assert_eq!(d.codes, code_map(["exhaustive-deps", "foo"]));
+      },
+    );
+  }
 }
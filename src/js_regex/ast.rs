@@ -0,0 +1,241 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+//! The syntax tree of a regular expression pattern, for rules that need to
+//! look at the structure of a pattern instead of only validating it.
+
+/// A range of byte offsets in the pattern. `RegexSource::range_of` maps it to
+/// the source code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+  pub start: usize,
+  pub end: usize,
+}
+
+impl Span {
+  pub fn new(start: usize, end: usize) -> Self {
+    Self { start, end }
+  }
+}
+
+/// A whole pattern, e.g. `a|b` in `/a|b/`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pattern {
+  pub alternatives: Vec<Alternative>,
+}
+
+/// One of the `|` separated alternatives of a pattern or group.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Alternative {
+  pub terms: Vec<Term>,
+  pub span: Span,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Term {
+  pub atom: Atom,
+  pub quantifier: Option<Quantifier>,
+  /// The span of the atom and its quantifier.
+  pub span: Span,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Atom {
+  Assertion(Assertion),
+  Char(Char),
+  /// `.`
+  Dot,
+  ClassEscape(ClassEscape),
+  Class(CharacterClass),
+  Group(Group),
+  Backreference(Backreference),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Assertion {
+  /// `^`
+  Start,
+  /// `$`
+  End,
+  /// `\b` or `\B`
+  WordBoundary { negated: bool },
+  /// `(?=...)` or `(?!...)`
+  Lookahead {
+    negated: bool,
+    alternatives: Vec<Alternative>,
+  },
+  /// `(?<=...)` or `(?<!...)`
+  Lookbehind {
+    negated: bool,
+    alternatives: Vec<Alternative>,
+  },
+}
+
+/// A single character, written either literally or as an escape.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Char {
+  /// The code point, which may be a lone surrogate.
+  pub value: u32,
+  pub kind: CharKind,
+  pub span: Span,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CharKind {
+  /// `a`
+  Literal,
+  /// `\n`, `\t`, `\r`, `\v`, `\f`, or `\b` inside a class
+  ControlEscape,
+  /// `\cJ`
+  ControlLetter,
+  /// `\0`
+  Null,
+  /// `\012`, only allowed without the `u` flag
+  Octal,
+  /// `\x0a`
+  Hex,
+  /// `\u000a` or `\u{a}`
+  Unicode,
+  /// `\.`, an escaped character that stands for itself
+  Identity,
+}
+
+/// `\d`, `\w`, `\s`, `\p{...}` and their negations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClassEscape {
+  pub kind: ClassEscapeKind,
+  pub negated: bool,
+  pub span: Span,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClassEscapeKind {
+  Digit,
+  Word,
+  Space,
+  Property,
+}
+
+/// `[...]`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CharacterClass {
+  pub negated: bool,
+  pub items: Vec<ClassItem>,
+  pub span: Span,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClassItem {
+  Char(Char),
+  /// `a-z`
+  Range(Char, Char),
+  Escape(ClassEscape),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Group {
+  pub kind: GroupKind,
+  pub alternatives: Vec<Alternative>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GroupKind {
+  /// `(...)` or `(?<name>...)`
+  Capturing { name: Option<String> },
+  /// `(?:...)`
+  NonCapturing,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Backreference {
+  /// `\1`
+  Number(u32),
+  /// `\k<name>`
+  Name(String),
+}
+
+/// `*`, `+`, `?` or `{min,max}`, with `max` being `None` when unbounded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Quantifier {
+  pub min: u32,
+  pub max: Option<u32>,
+  pub greedy: bool,
+}
+
+impl Quantifier {
+  pub fn is_unbounded(&self) -> bool {
+    self.max.is_none()
+  }
+}
+
+/// Walks a pattern. Each method visits the children of its node by default,
+/// so an implementation only overrides the nodes it's interested in, calling
+/// the matching `walk_*` function to still visit the children.
+pub trait Visit {
+  fn visit_alternative(&mut self, alternative: &Alternative) {
+    walk_alternative(self, alternative);
+  }
+
+  fn visit_term(&mut self, term: &Term) {
+    self.visit_atom(&term.atom);
+  }
+
+  fn visit_atom(&mut self, atom: &Atom) {
+    walk_atom(self, atom);
+  }
+
+  fn visit_class(&mut self, class: &CharacterClass) {
+    walk_class(self, class);
+  }
+
+  fn visit_char(&mut self, _c: &Char) {}
+}
+
+pub fn walk_alternative<V: Visit + ?Sized>(
+  visitor: &mut V,
+  alternative: &Alternative,
+) {
+  for term in &alternative.terms {
+    visitor.visit_term(term);
+  }
+}
+
+pub fn walk_atom<V: Visit + ?Sized>(visitor: &mut V, atom: &Atom) {
+  match atom {
+    Atom::Assertion(
+      Assertion::Lookahead { alternatives, .. }
+      | Assertion::Lookbehind { alternatives, .. },
+    )
+    | Atom::Group(Group { alternatives, .. }) => {
+      for alternative in alternatives {
+        visitor.visit_alternative(alternative);
+      }
+    }
+    Atom::Char(c) => visitor.visit_char(c),
+    Atom::Class(class) => visitor.visit_class(class),
+    Atom::Assertion(_)
+    | Atom::Dot
+    | Atom::ClassEscape(_)
+    | Atom::Backreference(_) => {}
+  }
+}
+
+pub fn walk_class<V: Visit + ?Sized>(visitor: &mut V, class: &CharacterClass) {
+  for item in &class.items {
+    match item {
+      ClassItem::Char(c) => visitor.visit_char(c),
+      ClassItem::Range(from, to) => {
+        visitor.visit_char(from);
+        visitor.visit_char(to);
+      }
+      ClassItem::Escape(_) => {}
+    }
+  }
+}
+
+impl Pattern {
+  pub fn visit_with<V: Visit>(&self, visitor: &mut V) {
+    for alternative in &self.alternatives {
+      visitor.visit_alternative(alternative);
+    }
+  }
+}
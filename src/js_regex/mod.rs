@@ -1,9 +1,14 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
+pub mod ast;
+mod parser;
 mod reader;
+mod source;
 mod unicode;
 mod validator;
 
+pub use parser::parse_pattern;
+pub use source::{LiteralPattern, RegexSource};
 pub use validator::{EcmaRegexValidator, EcmaVersion};
 
 #[cfg(test)]
@@ -0,0 +1,720 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::ast::*;
+
+/// Parses a regular expression pattern, i.e. the part between the slashes of
+/// a regex literal, into its syntax tree.
+///
+/// The parser accepts the web compatibility syntax of Annex B without the `u`
+/// flag, such as `\8` or a lone `{`, and returns `None` for patterns it can't
+/// make sense of. It doesn't report early errors like duplicate group names,
+/// which is what `EcmaRegexValidator` is for. `\u{...}` is read as an escape
+/// even without the `u` flag, since it's almost always meant as one.
+/// Patterns using the class set syntax of the `v` flag aren't supported.
+pub fn parse_pattern(source: &str, unicode: bool) -> Option<Pattern> {
+  let mut parser = Parser {
+    src: source,
+    pos: 0,
+    unicode,
+  };
+  let alternatives = parser.disjunction()?;
+  // An unmatched `)` stops the parser early.
+  if parser.pos != source.len() {
+    return None;
+  }
+  Some(Pattern { alternatives })
+}
+
+struct Parser<'a> {
+  src: &'a str,
+  pos: usize,
+  unicode: bool,
+}
+
+impl Parser<'_> {
+  fn peek(&self) -> Option<char> {
+    self.src[self.pos..].chars().next()
+  }
+
+  fn peek_nth(&self, n: usize) -> Option<char> {
+    self.src[self.pos..].chars().nth(n)
+  }
+
+  fn next(&mut self) -> Option<char> {
+    let c = self.peek()?;
+    self.pos += c.len_utf8();
+    Some(c)
+  }
+
+  fn eat(&mut self, c: char) -> bool {
+    self.eat_str(c.encode_utf8(&mut [0; 4]))
+  }
+
+  fn eat_str(&mut self, s: &str) -> bool {
+    if self.src[self.pos..].starts_with(s) {
+      self.pos += s.len();
+      true
+    } else {
+      false
+    }
+  }
+
+  fn disjunction(&mut self) -> Option<Vec<Alternative>> {
+    let mut alternatives = vec![self.alternative()?];
+    while self.eat('|') {
+      alternatives.push(self.alternative()?);
+    }
+    Some(alternatives)
+  }
+
+  fn span_from(&self, start: usize) -> Span {
+    Span::new(start, self.pos)
+  }
+
+  fn alternative(&mut self) -> Option<Alternative> {
+    let start = self.pos;
+    let mut terms = Vec::new();
+    while !matches!(self.peek(), None | Some('|') | Some(')')) {
+      terms.push(self.term()?);
+    }
+    Some(Alternative {
+      terms,
+      span: self.span_from(start),
+    })
+  }
+
+  fn term(&mut self) -> Option<Term> {
+    let start = self.pos;
+    let atom = self.atom()?;
+    let quantifier = self.quantifier();
+    if let Some(quantifier) = quantifier {
+      if !self.is_quantifiable(&atom)
+        || quantifier.max.is_some_and(|max| max < quantifier.min)
+      {
+        return None;
+      }
+    }
+    Some(Term {
+      atom,
+      quantifier,
+      span: self.span_from(start),
+    })
+  }
+
+  fn is_quantifiable(&self, atom: &Atom) -> bool {
+    match atom {
+      Atom::Assertion(Assertion::Lookahead { .. }) => !self.unicode,
+      Atom::Assertion(_) => false,
+      _ => true,
+    }
+  }
+
+  fn quantifier(&mut self) -> Option<Quantifier> {
+    let (min, max) = match self.peek()? {
+      '*' => {
+        self.next();
+        (0, None)
+      }
+      '+' => {
+        self.next();
+        (1, None)
+      }
+      '?' => {
+        self.next();
+        (0, Some(1))
+      }
+      '{' => {
+        let start = self.pos;
+        match self.braced_quantifier() {
+          Some(bounds) => bounds,
+          None => {
+            self.pos = start;
+            return None;
+          }
+        }
+      }
+      _ => return None,
+    };
+    let greedy = !self.eat('?');
+    Some(Quantifier { min, max, greedy })
+  }
+
+  /// `{n}`, `{n,}` or `{n,m}`
+  fn braced_quantifier(&mut self) -> Option<(u32, Option<u32>)> {
+    self.next();
+    let min = self.decimal()?;
+    let max = if self.eat(',') {
+      if self.peek() == Some('}') {
+        None
+      } else {
+        Some(self.decimal()?)
+      }
+    } else {
+      Some(min)
+    };
+    if !self.eat('}') {
+      return None;
+    }
+    Some((min, max))
+  }
+
+  fn decimal(&mut self) -> Option<u32> {
+    let start = self.pos;
+    while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+      self.next();
+    }
+    if start == self.pos {
+      return None;
+    }
+    Some(self.src[start..self.pos].parse().unwrap_or(u32::MAX))
+  }
+
+  fn atom(&mut self) -> Option<Atom> {
+    let start = self.pos;
+    let atom = match self.next()? {
+      '^' => Atom::Assertion(Assertion::Start),
+      '$' => Atom::Assertion(Assertion::End),
+      '.' => Atom::Dot,
+      '(' => self.group()?,
+      '[' => Atom::Class(self.class(start)?),
+      '\\' => self.atom_escape(start)?,
+      // Nothing to repeat.
+      '*' | '+' | '?' => return None,
+      '{' => {
+        let after = self.pos;
+        self.pos -= 1;
+        if self.unicode || self.braced_quantifier().is_some() {
+          return None;
+        }
+        self.pos = after;
+        Atom::Char(self.literal('{', start))
+      }
+      ']' | '}' if self.unicode => return None,
+      c => Atom::Char(self.literal(c, start)),
+    };
+    Some(atom)
+  }
+
+  fn group(&mut self) -> Option<Atom> {
+    let atom = if self.eat_str("?:") {
+      Atom::Group(Group {
+        kind: GroupKind::NonCapturing,
+        alternatives: self.disjunction()?,
+      })
+    } else if self.eat_str("?=") || self.eat_str("?!") {
+      Atom::Assertion(Assertion::Lookahead {
+        negated: self.src[..self.pos].ends_with('!'),
+        alternatives: self.disjunction()?,
+      })
+    } else if self.eat_str("?<=") || self.eat_str("?<!") {
+      Atom::Assertion(Assertion::Lookbehind {
+        negated: self.src[..self.pos].ends_with('!'),
+        alternatives: self.disjunction()?,
+      })
+    } else if self.eat_str("?<") {
+      let name = self.group_name()?;
+      Atom::Group(Group {
+        kind: GroupKind::Capturing { name: Some(name) },
+        alternatives: self.disjunction()?,
+      })
+    } else if self.peek() == Some('?') {
+      return None;
+    } else {
+      Atom::Group(Group {
+        kind: GroupKind::Capturing { name: None },
+        alternatives: self.disjunction()?,
+      })
+    };
+    self.eat(')').then_some(atom)
+  }
+
+  /// Reads a group name up to and including the closing `>`.
+  fn group_name(&mut self) -> Option<String> {
+    let start = self.pos;
+    let end = start + self.src[start..].find('>')?;
+    let name = &self.src[start..end];
+    if name.is_empty() {
+      return None;
+    }
+    self.pos = end + 1;
+    Some(name.to_string())
+  }
+
+  /// Parses the escape after a `\` outside of a class. `start` is where the
+  /// `\` is.
+  fn atom_escape(&mut self, start: usize) -> Option<Atom> {
+    let c = self.next()?;
+    let atom = match c {
+      'b' | 'B' => {
+        Atom::Assertion(Assertion::WordBoundary { negated: c == 'B' })
+      }
+      '1'..='9' => {
+        self.pos -= 1;
+        Atom::Backreference(Backreference::Number(self.decimal()?))
+      }
+      'k' if self.peek() == Some('<') => {
+        self.next();
+        Atom::Backreference(Backreference::Name(self.group_name()?))
+      }
+      _ => match self.class_escape(c, start)? {
+        Some(escape) => Atom::ClassEscape(escape),
+        None => Atom::Char(self.character_escape(c, start)?),
+      },
+    };
+    Some(atom)
+  }
+
+  /// Parses `\d`, `\p{...}` and the like, returning `Some(None)` if `c` doesn't
+  /// start one of them.
+  fn class_escape(
+    &mut self,
+    c: char,
+    start: usize,
+  ) -> Option<Option<ClassEscape>> {
+    let (kind, negated) = match c {
+      'd' | 'D' => (ClassEscapeKind::Digit, c == 'D'),
+      'w' | 'W' => (ClassEscapeKind::Word, c == 'W'),
+      's' | 'S' => (ClassEscapeKind::Space, c == 'S'),
+      'p' | 'P' if self.unicode => {
+        if !self.eat('{') {
+          return None;
+        }
+        let end = self.pos + self.src[self.pos..].find('}')?;
+        self.pos = end + 1;
+        (ClassEscapeKind::Property, c == 'P')
+      }
+      _ => return Some(None),
+    };
+    Some(Some(ClassEscape {
+      kind,
+      negated,
+      span: self.span_from(start),
+    }))
+  }
+
+  /// Parses the character escape after the `\` at `start`, whose first
+  /// character `c` has already been consumed.
+  fn character_escape(&mut self, c: char, start: usize) -> Option<Char> {
+    let (value, kind) = match c {
+      't' => (0x09, CharKind::ControlEscape),
+      'n' => (0x0a, CharKind::ControlEscape),
+      'v' => (0x0b, CharKind::ControlEscape),
+      'f' => (0x0c, CharKind::ControlEscape),
+      'r' => (0x0d, CharKind::ControlEscape),
+      'c' => match self.peek() {
+        Some(letter) if letter.is_ascii_alphabetic() => {
+          self.next();
+          (letter as u32 % 32, CharKind::ControlLetter)
+        }
+        // `\c` without a letter is a literal backslash followed by `c`.
+        _ if !self.unicode => {
+          self.pos -= 1;
+          ('\\' as u32, CharKind::Literal)
+        }
+        _ => return None,
+      },
+      '0' if !self.peek().is_some_and(|c| c.is_ascii_digit()) => {
+        (0, CharKind::Null)
+      }
+      '0'..='7' if !self.unicode => {
+        let mut value = c.to_digit(8)?;
+        // Octal escapes stop before they would go over \377.
+        while let Some(digit) = self.peek().and_then(|c| c.to_digit(8)) {
+          if value * 8 + digit > 0o377 {
+            break;
+          }
+          self.next();
+          value = value * 8 + digit;
+        }
+        (value, CharKind::Octal)
+      }
+      'x' => match self.hex_digits(2) {
+        Some(value) => (value, CharKind::Hex),
+        None if !self.unicode => ('x' as u32, CharKind::Identity),
+        None => return None,
+      },
+      'u' => match self.unicode_escape() {
+        Some(value) => (value, CharKind::Unicode),
+        None if !self.unicode => ('u' as u32, CharKind::Identity),
+        None => return None,
+      },
+      c if self.unicode
+        && !matches!(
+          c,
+          '^'
+            | '$'
+            | '\\'
+            | '.'
+            | '*'
+            | '+'
+            | '?'
+            | '('
+            | ')'
+            | '['
+            | ']'
+            | '{'
+            | '}'
+            | '|'
+            | '/'
+            | '-'
+        ) =>
+      {
+        return None
+      }
+      c => (c as u32, CharKind::Identity),
+    };
+    Some(Char {
+      value,
+      kind,
+      span: self.span_from(start),
+    })
+  }
+
+  /// Reads exactly `n` hex digits, leaving the parser where it was if there
+  /// aren't enough of them.
+  fn hex_digits(&mut self, n: usize) -> Option<u32> {
+    let digits = self.src[self.pos..].get(..n)?;
+    if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+      return None;
+    }
+    self.pos += n;
+    u32::from_str_radix(digits, 16).ok()
+  }
+
+  /// Reads the rest of `\u{...}`, `\uXXXX` or, with the `u` flag, a surrogate
+  /// pair like `\ud83d\ude00`.
+  fn unicode_escape(&mut self) -> Option<u32> {
+    let start = self.pos;
+    if self.eat('{') {
+      let end = self.pos + self.src[self.pos..].find('}')?;
+      let digits = &self.src[self.pos..end];
+      let value = (!digits.is_empty()
+        && digits.chars().all(|c| c.is_ascii_hexdigit()))
+      .then(|| u32::from_str_radix(digits, 16).ok())
+      .flatten()
+      .filter(|value| *value <= 0x10ffff);
+      if value.is_none() {
+        self.pos = start;
+        return None;
+      }
+      self.pos = end + 1;
+      return value;
+    }
+    let lead = self.hex_digits(4)?;
+    if self.unicode && (0xd800..0xdc00).contains(&lead) {
+      let before_trail = self.pos;
+      if self.eat_str("\\u") {
+        match self.hex_digits(4) {
+          Some(trail) if (0xdc00..0xe000).contains(&trail) => {
+            return Some(0x10000 + ((lead - 0xd800) << 10) + (trail - 0xdc00));
+          }
+          _ => self.pos = before_trail,
+        }
+      }
+    }
+    Some(lead)
+  }
+
+  /// Parses a class whose `[` is at `start`.
+  fn class(&mut self, start: usize) -> Option<CharacterClass> {
+    let negated = self.eat('^');
+    let mut items = Vec::new();
+    loop {
+      if self.eat(']') {
+        break;
+      }
+      let from = self.class_atom()?;
+      let is_range = self.peek() == Some('-')
+        && !matches!(self.peek_nth(1), None | Some(']'));
+      if !is_range {
+        items.push(from);
+        continue;
+      }
+      let dash = self.pos;
+      self.next();
+      let to = self.class_atom()?;
+      match (from, to) {
+        (ClassItem::Char(from), ClassItem::Char(to)) => {
+          if to.value < from.value {
+            return None;
+          }
+          items.push(ClassItem::Range(from, to));
+        }
+        // Without the `u` flag, a `-` next to an escape like `\d` is literal.
+        (from, to) if !self.unicode => {
+          items.push(from);
+          items.push(ClassItem::Char(Char {
+            value: '-' as u32,
+            kind: CharKind::Literal,
+            span: Span::new(dash, dash + 1),
+          }));
+          items.push(to);
+        }
+        _ => return None,
+      }
+    }
+    Some(CharacterClass {
+      negated,
+      items,
+      span: self.span_from(start),
+    })
+  }
+
+  fn class_atom(&mut self) -> Option<ClassItem> {
+    let start = self.pos;
+    let c = self.next()?;
+    if c != '\\' {
+      return Some(ClassItem::Char(self.literal(c, start)));
+    }
+    let c = self.next()?;
+    if let Some(escape) = self.class_escape(c, start)? {
+      return Some(ClassItem::Escape(escape));
+    }
+    let c = match c {
+      'b' => Char {
+        value: 0x08,
+        kind: CharKind::ControlEscape,
+        span: self.span_from(start),
+      },
+      '-' if self.unicode => Char {
+        value: '-' as u32,
+        kind: CharKind::Identity,
+        span: self.span_from(start),
+      },
+      c => self.character_escape(c, start)?,
+    };
+    Some(ClassItem::Char(c))
+  }
+
+  /// Makes a literal character that starts at `start` and ends at the current
+  /// position.
+  fn literal(&self, c: char, start: usize) -> Char {
+    Char {
+      value: c as u32,
+      kind: CharKind::Literal,
+      span: self.span_from(start),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn literal(value: char, start: usize) -> Char {
+    Char {
+      value: value as u32,
+      kind: CharKind::Literal,
+      span: Span::new(start, start + value.len_utf8()),
+    }
+  }
+
+  fn single_atom(source: &str, unicode: bool) -> Atom {
+    let mut pattern = parse_pattern(source, unicode).unwrap();
+    assert_eq!(pattern.alternatives.len(), 1);
+    let mut terms = pattern.alternatives.remove(0).terms;
+    assert_eq!(terms.len(), 1, "{source}");
+    terms.remove(0).atom
+  }
+
+  #[test]
+  fn parses_alternatives_and_quantifiers() {
+    assert_eq!(
+      parse_pattern("a+|b{2,}?c", false),
+      Some(Pattern {
+        alternatives: vec![
+          Alternative {
+            terms: vec![Term {
+              atom: Atom::Char(literal('a', 0)),
+              quantifier: Some(Quantifier {
+                min: 1,
+                max: None,
+                greedy: true
+              }),
+              span: Span::new(0, 2),
+            }],
+            span: Span::new(0, 2),
+          },
+          Alternative {
+            terms: vec![
+              Term {
+                atom: Atom::Char(literal('b', 3)),
+                quantifier: Some(Quantifier {
+                  min: 2,
+                  max: None,
+                  greedy: false
+                }),
+                span: Span::new(3, 9),
+              },
+              Term {
+                atom: Atom::Char(literal('c', 9)),
+                quantifier: None,
+                span: Span::new(9, 10),
+              },
+            ],
+            span: Span::new(3, 10),
+          },
+        ],
+      })
+    );
+    assert_eq!(
+      parse_pattern("", false),
+      Some(Pattern {
+        alternatives: vec![Alternative {
+          terms: vec![],
+          span: Span::new(0, 0)
+        }],
+      })
+    );
+  }
+
+  #[test]
+  fn parses_escapes() {
+    let tests = [
+      (r"\x1f", 0x1f, CharKind::Hex),
+      (r"\u001f", 0x1f, CharKind::Unicode),
+      (r"\u{1f}", 0x1f, CharKind::Unicode),
+      (r"\u{0001f}", 0x1f, CharKind::Unicode),
+      (r"\u{1ff}", 0x1ff, CharKind::Unicode),
+      (r"\uabcd", 0xabcd, CharKind::Unicode),
+      (r"\n", 0x0a, CharKind::ControlEscape),
+      (r"\cJ", 0x0a, CharKind::ControlLetter),
+      (r"\0", 0, CharKind::Null),
+      (r"\012", 0o12, CharKind::Octal),
+      (r"\.", '.' as u32, CharKind::Identity),
+    ];
+    for (source, value, kind) in tests {
+      assert_eq!(
+        single_atom(source, false),
+        Atom::Char(Char {
+          value,
+          kind,
+          span: Span::new(0, source.len())
+        }),
+        "{source}"
+      );
+    }
+
+    assert_eq!(
+      parse_pattern(r"\x1g", false).unwrap().alternatives[0].terms[0].atom,
+      Atom::Char(Char {
+        value: 'x' as u32,
+        kind: CharKind::Identity,
+        span: Span::new(0, 2)
+      })
+    );
+    assert_eq!(
+      single_atom(r"\ud83d\ude00", true),
+      Atom::Char(Char {
+        value: 0x1f600,
+        kind: CharKind::Unicode,
+        span: Span::new(0, 12)
+      })
+    );
+    assert_eq!(
+      single_atom(r"\d", false),
+      Atom::ClassEscape(ClassEscape {
+        kind: ClassEscapeKind::Digit,
+        negated: false,
+        span: Span::new(0, 2)
+      })
+    );
+    assert_eq!(
+      single_atom(r"\P{Letter}", true),
+      Atom::ClassEscape(ClassEscape {
+        kind: ClassEscapeKind::Property,
+        negated: true,
+        span: Span::new(0, 10)
+      })
+    );
+    assert_eq!(
+      single_atom(r"\2", false),
+      Atom::Backreference(Backreference::Number(2))
+    );
+  }
+
+  #[test]
+  fn parses_classes() {
+    assert_eq!(
+      single_atom(r"[^a-z\d-]", false),
+      Atom::Class(CharacterClass {
+        negated: true,
+        items: vec![
+          ClassItem::Range(literal('a', 2), literal('z', 4)),
+          ClassItem::Escape(ClassEscape {
+            kind: ClassEscapeKind::Digit,
+            negated: false,
+            span: Span::new(5, 7)
+          }),
+          ClassItem::Char(literal('-', 7)),
+        ],
+        span: Span::new(0, 9),
+      })
+    );
+    assert_eq!(
+      single_atom(r"[\b]", false),
+      Atom::Class(CharacterClass {
+        negated: false,
+        items: vec![ClassItem::Char(Char {
+          value: 8,
+          kind: CharKind::ControlEscape,
+          span: Span::new(1, 3)
+        })],
+        span: Span::new(0, 4),
+      })
+    );
+  }
+
+  #[test]
+  fn parses_groups() {
+    assert_eq!(
+      single_atom("(?<year>a)", false),
+      Atom::Group(Group {
+        kind: GroupKind::Capturing {
+          name: Some("year".to_string())
+        },
+        alternatives: vec![Alternative {
+          terms: vec![Term {
+            atom: Atom::Char(literal('a', 8)),
+            quantifier: None,
+            span: Span::new(8, 9),
+          }],
+          span: Span::new(8, 9),
+        }],
+      })
+    );
+    assert!(matches!(
+      single_atom("(?<!a)", false),
+      Atom::Assertion(Assertion::Lookbehind { negated: true, .. })
+    ));
+    assert!(matches!(
+      single_atom("(?=a)", false),
+      Atom::Assertion(Assertion::Lookahead { negated: false, .. })
+    ));
+  }
+
+  #[test]
+  fn annex_b_syntax() {
+    assert_eq!(single_atom("{", false), Atom::Char(literal('{', 0)));
+    assert_eq!(single_atom("]", false), Atom::Char(literal(']', 0)));
+    assert_eq!(
+      single_atom(r"\a", false),
+      Atom::Char(Char {
+        value: 'a' as u32,
+        kind: CharKind::Identity,
+        span: Span::new(0, 2)
+      })
+    );
+    assert!(parse_pattern("a{1,", false).is_some());
+    assert!(parse_pattern("{", true).is_none());
+    assert!(parse_pattern(r"\a", true).is_none());
+  }
+
+  #[test]
+  fn invalid_patterns() {
+    for source in ["(", "a)", "[", "*", "a**", "^*", "[z-a]", "a{2,1}", "(?a)"]
+    {
+      assert_eq!(parse_pattern(source, false), None, "{source}");
+    }
+  }
+}
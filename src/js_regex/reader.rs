@@ -8,7 +8,9 @@ pub struct Reader {
   src: String,
   index: usize,
   end: usize,
-  cps: VecDeque<char>,
+  /// Code points, or UTF-16 code units without the `u` flag, which can be
+  /// lone surrogates and so aren't always valid `char`s.
+  cps: VecDeque<u32>,
 }
 
 impl Reader {
@@ -31,8 +33,16 @@ impl Reader {
     self.index
   }
 
+  /// Returns the code point at the offset as a `char`, with surrogates
+  /// replaced by U+FFFD. Use `value_with_offset` where surrogates matter.
   pub fn code_point_with_offset(&self, offset: usize) -> Option<char> {
-    self.cps.get(offset).cloned()
+    self
+      .value_with_offset(offset)
+      .map(|cp| char::from_u32(cp).unwrap_or(char::REPLACEMENT_CHARACTER))
+  }
+
+  pub fn value_with_offset(&self, offset: usize) -> Option<u32> {
+    self.cps.get(offset).copied()
   }
 
   pub fn reset(
@@ -73,7 +83,7 @@ impl Reader {
 
   pub fn eat(&mut self, cp: char) -> bool {
     let opt = self.cps.front();
-    if opt.is_some() && *opt.unwrap() == cp {
+    if opt.is_some() && *opt.unwrap() == cp as u32 {
       self.advance();
       true
     } else {
@@ -85,8 +95,8 @@ impl Reader {
     let (opt1, opt2) = (self.cps.front(), self.cps.get(1));
     if opt1.is_some()
       && opt2.is_some()
-      && *opt1.unwrap() == cp1
-      && *opt2.unwrap() == cp2
+      && *opt1.unwrap() == cp1 as u32
+      && *opt2.unwrap() == cp2 as u32
     {
       self.advance();
       self.advance();
@@ -102,9 +112,9 @@ impl Reader {
     if opt1.is_some()
       && opt2.is_some()
       && opt3.is_some()
-      && *opt1.unwrap() == cp1
-      && *opt2.unwrap() == cp2
-      && *opt3.unwrap() == cp3
+      && *opt1.unwrap() == cp1 as u32
+      && *opt2.unwrap() == cp2 as u32
+      && *opt3.unwrap() == cp3 as u32
     {
       self.advance();
       self.advance();
@@ -115,20 +125,13 @@ impl Reader {
     }
   }
 
-  fn at(&self, i: usize) -> Option<char> {
+  fn at(&self, i: usize) -> Option<u32> {
     if i >= self.end {
       None
     } else if self.unicode {
-      let c = self.src.chars().nth(i).unwrap();
-      Some(c)
+      self.src.chars().nth(i).map(|c| c as u32)
     } else {
-      // TODO: move the conversion out of this method and make it safe
-      unsafe {
-        let c: char = std::char::from_u32_unchecked(
-          self.src.encode_utf16().nth(i).unwrap() as u32,
-        );
-        Some(c)
-      }
+      self.src.encode_utf16().nth(i).map(u32::from)
     }
   }
 }
@@ -169,19 +172,19 @@ mod tests {
     let mut reader = Reader::new();
     // without unicode flag
     reader.reset("Hello", 0, 5, false);
-    assert_eq!(reader.at(1).unwrap() as u32, 101);
+    assert_eq!(reader.at(1).unwrap(), 101);
     reader.reset("􀃃a🩢☃★♲", 0, 6, false);
-    assert_eq!(reader.at(0).unwrap() as u32, 56256);
+    assert_eq!(reader.at(0).unwrap(), 56256);
     reader.reset("􀃃ello", 0, 6, false);
-    assert_eq!(reader.at(0).unwrap() as u32, 56256);
+    assert_eq!(reader.at(0).unwrap(), 56256);
     reader.reset("􀃃ello", 0, 6, false);
-    assert_eq!(reader.at(1).unwrap() as u32, 56515);
+    assert_eq!(reader.at(1).unwrap(), 56515);
     // with unicode flag
     reader.reset("Hello", 0, 5, true);
-    assert_eq!(reader.at(1).unwrap() as u32, 101);
+    assert_eq!(reader.at(1).unwrap(), 101);
     reader.reset("􀃃a🩢☃★♲", 0, 6, true);
-    assert_eq!(reader.at(0).unwrap() as u32, 1048771);
+    assert_eq!(reader.at(0).unwrap(), 1048771);
     reader.reset("􀃃ello", 0, 6, true);
-    assert_eq!(reader.at(0).unwrap() as u32, 1048771);
+    assert_eq!(reader.at(0).unwrap(), 1048771);
   }
 }
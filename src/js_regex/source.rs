@@ -0,0 +1,289 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::ast::{Assertion, Atom, Pattern, Span};
+use super::parse_pattern;
+use crate::context::Context;
+
+use deno_ast::view::{Expr, ExprOrSpread, Lit, Regex, Str};
+use deno_ast::{SourcePos, SourceRange, SourceRanged};
+
+/// A regular expression in the source code, written either as a regex literal
+/// or as string literals passed to `RegExp`, with a way to map the spans of
+/// its parsed pattern back to the source.
+pub struct RegexSource {
+  pub pattern: String,
+  /// `None` if the flags are passed to `RegExp` as something other than a
+  /// string literal, so they're unknown.
+  pub flags: Option<String>,
+  /// The range of the regex literal or the `RegExp` call.
+  pub range: SourceRange,
+  positions: Positions,
+  /// Where the flags start, if they're written as is.
+  flags_start: Option<SourcePos>,
+}
+
+/// The text of a pattern that only has literal characters, and whether it's
+/// anchored with `^` before or `$` after the text.
+pub struct LiteralPattern {
+  pub text: String,
+  pub start: bool,
+  pub end: bool,
+}
+
+enum Positions {
+  /// The pattern is written as is, starting at this position.
+  Verbatim(SourcePos),
+  /// The pattern is the value of the string literal starting at `start`.
+  /// `offsets[i]` is the offset of the byte `i` of the value in the raw text
+  /// of the literal.
+  Str {
+    start: SourcePos,
+    offsets: Vec<usize>,
+  },
+  /// The string literal has escapes that can't be mapped, like lone
+  /// surrogates.
+  Unknown,
+}
+
+impl RegexSource {
+  pub fn from_regex(regex: &Regex) -> Self {
+    let flags = regex.inner.flags.to_string();
+    Self {
+      flags_start: Some(regex.end() - flags.len()),
+      pattern: regex.inner.exp.to_string(),
+      flags: Some(flags),
+      range: regex.range(),
+      // Skip the opening `/`.
+      positions: Positions::Verbatim(regex.start() + 1),
+    }
+  }
+
+  /// Reads `RegExp("...")` and `new RegExp("...", "...")`, where `RegExp` is
+  /// the global. Regex literals passed to `RegExp` aren't included, since
+  /// they're checked as literals on their own.
+  pub fn from_constructor(
+    callee: &Expr,
+    args: &[&ExprOrSpread],
+    range: SourceRange,
+    ctx: &Context,
+  ) -> Option<Self> {
+    let Expr::Ident(ident) = callee else {
+      return None;
+    };
+    if ident.sym() != "RegExp" || !ctx.scope().is_global(&ident.inner.to_id()) {
+      return None;
+    }
+    let Expr::Lit(Lit::Str(pattern)) = args.first()?.expr else {
+      return None;
+    };
+    let (flags, flags_start) = match args.get(1).map(|arg| arg.expr) {
+      Some(Expr::Lit(Lit::Str(flags))) => {
+        let value = flags.value().to_string();
+        // Flags never need escapes, so they're as is unless they have some.
+        let verbatim =
+          flags.text_fast(ctx.text_info()).len() == value.len() + 2;
+        (Some(value), verbatim.then(|| flags.start() + 1))
+      }
+      Some(_) => (None, None),
+      None => (Some(String::new()), None),
+    };
+    Some(Self {
+      pattern: pattern.value().to_string(),
+      flags,
+      range,
+      positions: string_positions(pattern, ctx),
+      flags_start,
+    })
+  }
+
+  /// Whether the flags are known and include `flag`.
+  pub fn has_flag(&self, flag: char) -> bool {
+    self
+      .flags
+      .as_ref()
+      .is_some_and(|flags| flags.contains(flag))
+  }
+
+  /// Parses the pattern, with the `u` flag if it's set. Patterns with the `v`
+  /// flag aren't supported and return `None`.
+  pub fn parse(&self) -> Option<Pattern> {
+    if self.has_flag('v') {
+      return None;
+    }
+    parse_pattern(&self.pattern, self.has_flag('u'))
+  }
+
+  /// Reads the pattern as literal text, if its flags are known and all in
+  /// `allowed_flags`, and it only matches literal text.
+  pub fn literal_pattern(&self, allowed_flags: &str) -> Option<LiteralPattern> {
+    if !self
+      .flags
+      .as_ref()?
+      .chars()
+      .all(|flag| allowed_flags.contains(flag))
+    {
+      return None;
+    }
+    let pattern = self.parse()?;
+    let [alternative] = pattern.alternatives.as_slice() else {
+      return None;
+    };
+    let mut literal = LiteralPattern {
+      text: String::new(),
+      start: false,
+      end: false,
+    };
+    let last = alternative.terms.len().checked_sub(1)?;
+    for (i, term) in alternative.terms.iter().enumerate() {
+      if term.quantifier.is_some() {
+        return None;
+      }
+      match &term.atom {
+        Atom::Char(c) => literal.text.push(char::from_u32(c.value)?),
+        Atom::Assertion(Assertion::Start) if i == 0 => literal.start = true,
+        Atom::Assertion(Assertion::End) if i == last => literal.end = true,
+        _ => return None,
+      }
+    }
+    (!literal.text.is_empty()).then_some(literal)
+  }
+
+  /// The range of the span in the source, if it can be mapped exactly.
+  pub fn exact_range_of(&self, span: Span) -> Option<SourceRange> {
+    match &self.positions {
+      Positions::Verbatim(start) => {
+        Some(SourceRange::new(*start + span.start, *start + span.end))
+      }
+      Positions::Str { start, offsets } => Some(SourceRange::new(
+        *start + *offsets.get(span.start)?,
+        *start + *offsets.get(span.end)?,
+      )),
+      Positions::Unknown => None,
+    }
+  }
+
+  /// The range of the flag at byte `index` of the flags, if the flags are
+  /// written as is.
+  pub fn flag_range(&self, index: usize) -> Option<SourceRange> {
+    let start = self.flags_start? + index;
+    Some(SourceRange::new(start, start + 1))
+  }
+
+  /// The range of the span in the source, or of the whole regex if it can't
+  /// be mapped exactly.
+  pub fn range_of(&self, span: Span) -> SourceRange {
+    self.exact_range_of(span).unwrap_or(self.range)
+  }
+}
+
+fn string_positions(pattern: &Str, ctx: &Context) -> Positions {
+  let raw = pattern.text_fast(ctx.text_info());
+  match decode_string(raw) {
+    Some((value, offsets)) if value == pattern.value().as_str() => {
+      Positions::Str {
+        start: pattern.start(),
+        offsets,
+      }
+    }
+    _ => Positions::Unknown,
+  }
+}
+
+/// Decodes the raw text of a string literal, quotes included, into its value
+/// and the offsets in the raw text of each byte of the value, plus one for the
+/// end of the value.
+fn decode_string(raw: &str) -> Option<(String, Vec<usize>)> {
+  let body = raw.get(1..raw.len().checked_sub(1)?)?;
+  let mut value = String::new();
+  let mut offsets = Vec::new();
+  let mut chars = body.char_indices().peekable();
+  while let Some((i, c)) = chars.next() {
+    let offset = i + 1;
+    let decoded = if c != '\\' {
+      Some(c)
+    } else {
+      let (_, escaped) = chars.next()?;
+      match escaped {
+        'n' => Some('\n'),
+        't' => Some('\t'),
+        'r' => Some('\r'),
+        'b' => Some('\u{8}'),
+        'f' => Some('\u{c}'),
+        'v' => Some('\u{b}'),
+        '0' if !chars.peek().is_some_and(|(_, c)| c.is_ascii_digit()) => {
+          Some('\0')
+        }
+        // Legacy octal escapes like `\012` are rare enough to not map.
+        '0'..='9' => return None,
+        'x' => {
+          let digits = take_chars(&mut chars, 2)?;
+          Some(char::from_u32(u32::from_str_radix(&digits, 16).ok()?)?)
+        }
+        'u' => {
+          let digits = if chars.peek().is_some_and(|(_, c)| *c == '{') {
+            chars.next();
+            let mut digits = String::new();
+            loop {
+              match chars.next()? {
+                (_, '}') => break,
+                (_, c) => digits.push(c),
+              }
+            }
+            digits
+          } else {
+            take_chars(&mut chars, 4)?
+          };
+          // Lone surrogates, including the halves of `\ud83d\ude00`, can't be
+          // mapped since they aren't `char`s.
+          Some(char::from_u32(u32::from_str_radix(&digits, 16).ok()?)?)
+        }
+        // Line continuations don't add anything to the value.
+        '\r' => {
+          if chars.peek().is_some_and(|(_, c)| *c == '\n') {
+            chars.next();
+          }
+          None
+        }
+        '\n' | '\u{2028}' | '\u{2029}' => None,
+        c => Some(c),
+      }
+    };
+    if let Some(decoded) = decoded {
+      offsets.extend(std::iter::repeat(offset).take(decoded.len_utf8()));
+      value.push(decoded);
+    }
+  }
+  offsets.push(raw.len() - 1);
+  Some((value, offsets))
+}
+
+fn take_chars(
+  chars: &mut impl Iterator<Item = (usize, char)>,
+  n: usize,
+) -> Option<String> {
+  let s: String = chars.take(n).map(|(_, c)| c).collect();
+  (s.chars().count() == n).then_some(s)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn decodes_strings() {
+    assert_eq!(
+      decode_string(r#""a\\d""#),
+      Some((r"a\d".to_string(), vec![1, 2, 4, 5]))
+    );
+    assert_eq!(
+      decode_string(r"'\x41B\u{43}'"),
+      Some(("ABC".to_string(), vec![1, 5, 6, 12]))
+    );
+    assert_eq!(
+      decode_string("'a\\\nb'"),
+      Some(("ab".to_string(), vec![1, 4, 5]))
+    );
+    assert_eq!(decode_string("'é'"), Some(("é".to_string(), vec![1, 1, 3])));
+    assert_eq!(decode_string(r"'\ud83d\ude00'"), None);
+  }
+}
@@ -858,8 +858,8 @@ impl EcmaRegexValidator {
   fn consume_class_atom(&mut self) -> Result<bool, String> {
     let start = self.index();
 
-    if let Some(cp) = self.code_point_with_offset(0) {
-      if cp != '\\' && cp != ']' {
+    if let Some(cp) = self.value_with_offset(0) {
+      if cp != '\\' as u32 && cp != ']' as u32 {
         self.advance();
         self.last_int_value = cp as i64;
         return Ok(true);
@@ -989,25 +989,22 @@ impl EcmaRegexValidator {
     let start = self.index();
     let force_u_flag = !self.u_flag && self.ecma_version >= EcmaVersion::Es2020;
 
-    if let Some(mut cp) = self.code_point_with_offset(0) {
+    if let Some(mut cp) = self.value_with_offset(0) {
       self.advance();
-      let cp1 = self.code_point_with_offset(0);
-      if cp == '\\' && self.eat_regexp_unicode_escape_sequence(force_u_flag)? {
-        cp = std::char::from_u32(self.last_int_value as u32).unwrap();
+      let cp1 = self.value_with_offset(0);
+      if cp == '\\' as u32
+        && self.eat_regexp_unicode_escape_sequence(force_u_flag)?
+      {
+        cp = self.last_int_value as u32;
       } else if force_u_flag
         && is_lead_surrogate(cp as i64)
-        && cp1.is_some()
-        && is_trail_surrogate(cp1.unwrap() as i64)
+        && cp1.is_some_and(|cp1| is_trail_surrogate(cp1 as i64))
       {
-        cp = std::char::from_u32(combine_surrogate_pair(
-          cp as i64,
-          cp1.unwrap() as i64,
-        ) as u32)
-        .unwrap();
+        cp = combine_surrogate_pair(cp as i64, cp1.unwrap() as i64) as u32;
         self.advance();
       }
 
-      if is_regexp_identifier_start(cp) {
+      if char::from_u32(cp).is_some_and(is_regexp_identifier_start) {
         self.last_int_value = cp as i64;
         return Ok(true);
       }
@@ -1036,27 +1033,22 @@ impl EcmaRegexValidator {
   fn eat_regexp_identifier_part(&mut self) -> Result<bool, String> {
     let start = self.index();
     let force_u_flag = !self.u_flag && self.ecma_version >= EcmaVersion::Es2020;
-    let mut cp = self.code_point_with_offset(0);
+    let mut cp = self.value_with_offset(0);
     self.advance();
-    let cp1 = self.code_point_with_offset(0);
+    let cp1 = self.value_with_offset(0);
 
-    if cp == Some('\\')
+    if cp == Some('\\' as u32)
       && self.eat_regexp_unicode_escape_sequence(force_u_flag)?
     {
-      // TODO: convert unicode code point to char
-      cp = std::char::from_u32(self.last_int_value as u32);
-    } else if force_u_flag
-      && is_lead_surrogate(cp.unwrap() as i64)
-      && is_trail_surrogate(cp1.unwrap() as i64)
-    {
-      cp = std::char::from_u32(combine_surrogate_pair(
-        cp.unwrap() as i64,
-        cp1.unwrap() as i64,
-      ) as u32);
-      self.advance();
+      cp = Some(self.last_int_value as u32);
+    } else if let (true, Some(lead), Some(trail)) = (force_u_flag, cp, cp1) {
+      if is_lead_surrogate(lead as i64) && is_trail_surrogate(trail as i64) {
+        cp = Some(combine_surrogate_pair(lead as i64, trail as i64) as u32);
+        self.advance();
+      }
     }
 
-    if let Some(c) = cp {
+    if let Some(c) = cp.and_then(char::from_u32) {
       if is_regexp_identifier_part(c) {
         self.last_int_value = c as i64;
         return Ok(true);
@@ -0,0 +1,251 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+//! Parses JSDoc comments into their description and block tags.
+
+use deno_ast::swc::common::comments::{Comment, CommentKind};
+
+/// A parsed `/** ... */` comment.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct JsDoc {
+  /// The text before the first tag.
+  pub description: String,
+  pub tags: Vec<JsDocTag>,
+}
+
+/// A block tag of a JSDoc comment, like `@param {string} name The name`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct JsDocTag {
+  /// The tag name without the `@`, like `param`.
+  pub kind: String,
+  /// The type in braces after the tag name, without the braces.
+  pub type_expr: Option<String>,
+  /// The name that tags like `@param`, `@property` and `@template` document.
+  pub name: Option<String>,
+  /// Whether the name is in brackets, like `@param [name]`.
+  pub optional: bool,
+  /// The default value in `@param [name=value]`.
+  pub default: Option<String>,
+  pub description: String,
+}
+
+/// Tags that are followed by the name of what they document.
+const NAMED_TAGS: &[&str] = &[
+  "param",
+  "arg",
+  "argument",
+  "property",
+  "prop",
+  "template",
+  "typeParam",
+];
+
+impl JsDoc {
+  /// Parses the comment if it's a JSDoc comment, which is a block comment
+  /// that starts with `/**`.
+  pub fn from_comment(comment: &Comment) -> Option<Self> {
+    if comment.kind != CommentKind::Block {
+      return None;
+    }
+    // `/**/` is an empty block comment, and `/*** ... */` is not JSDoc.
+    if !comment.text.starts_with('*') || comment.text.starts_with("**") {
+      return None;
+    }
+    Some(Self::parse(&comment.text))
+  }
+
+  /// Parses the text between `/*` and `*/` of a JSDoc comment.
+  pub fn parse(text: &str) -> Self {
+    let mut doc = JsDoc::default();
+    let mut current: Option<(String, String)> = None;
+    for line in text.lines() {
+      let line = line.trim_start();
+      let line = line.strip_prefix('*').unwrap_or(line);
+      let line = line.strip_prefix(' ').unwrap_or(line).trim_end();
+      let tag = line
+        .strip_prefix('@')
+        .filter(|rest| rest.starts_with(|c: char| c.is_ascii_alphabetic()));
+      match tag {
+        Some(rest) => {
+          if let Some((kind, body)) = current.take() {
+            doc.tags.push(JsDocTag::parse(kind, &body));
+          }
+          let (kind, body) =
+            rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+          current = Some((kind.to_string(), body.to_string()));
+        }
+        None => {
+          let text = match &mut current {
+            Some((_, body)) => body,
+            None => &mut doc.description,
+          };
+          if !text.is_empty() || !line.is_empty() {
+            text.push('\n');
+            text.push_str(line);
+          }
+        }
+      }
+    }
+    if let Some((kind, body)) = current {
+      doc.tags.push(JsDocTag::parse(kind, &body));
+    }
+    doc.description = doc.description.trim().to_string();
+    doc
+  }
+
+  /// The tags with the given kind, like `param`.
+  pub fn tags_of<'a>(
+    &'a self,
+    kind: &'a str,
+  ) -> impl Iterator<Item = &'a JsDocTag> {
+    self.tags.iter().filter(move |tag| tag.kind == kind)
+  }
+
+  /// The `@param` tags, including their `@arg` and `@argument` aliases.
+  pub fn params(&self) -> impl Iterator<Item = &JsDocTag> {
+    self
+      .tags
+      .iter()
+      .filter(|tag| matches!(tag.kind.as_str(), "param" | "arg" | "argument"))
+  }
+}
+
+impl JsDocTag {
+  fn parse(kind: String, body: &str) -> Self {
+    let mut tag = JsDocTag {
+      kind,
+      ..Default::default()
+    };
+    let mut rest = body.trim_start();
+    if rest.starts_with('{') {
+      if let Some(end) = type_end(rest) {
+        tag.type_expr = Some(rest[1..end].trim().to_string());
+        rest = rest[end + 1..].trim_start();
+      }
+    }
+    if NAMED_TAGS.contains(&tag.kind.as_str()) {
+      rest = tag.parse_name(rest);
+    }
+    // The description of a named tag can be separated with a hyphen, like
+    // `@param name - The name`.
+    if tag.name.is_some() {
+      if let Some(description) = rest.strip_prefix('-') {
+        rest = description;
+      }
+    }
+    tag.description = rest.trim().to_string();
+    tag
+  }
+
+  /// Reads `name`, `[name]` or `[name=value]`, and returns the text after it.
+  fn parse_name<'b>(&mut self, text: &'b str) -> &'b str {
+    if let Some(bracketed) = text.strip_prefix('[') {
+      if let Some(end) = bracketed.find(']') {
+        let (name, default) = match bracketed[..end].split_once('=') {
+          Some((name, default)) => (name, Some(default.trim().to_string())),
+          None => (&bracketed[..end], None),
+        };
+        self.name = Some(name.trim().to_string());
+        self.optional = true;
+        self.default = default;
+        return bracketed[end + 1..].trim_start();
+      }
+    }
+    let end = text.find(char::is_whitespace).unwrap_or(text.len());
+    if end > 0 {
+      self.name = Some(text[..end].to_string());
+    }
+    text[end..].trim_start()
+  }
+}
+
+/// The index of the brace that closes the type at the start of the text.
+fn type_end(text: &str) -> Option<usize> {
+  let mut depth = 0;
+  for (i, c) in text.char_indices() {
+    match c {
+      '{' => depth += 1,
+      '}' => {
+        depth -= 1;
+        if depth == 0 {
+          return Some(i);
+        }
+      }
+      _ => {}
+    }
+  }
+  None
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn parse_description_and_tags() {
+    let doc = JsDoc::parse(
+      "*
+   * Adds two numbers.
+   *
+   * Both numbers must be finite.
+   * @param {number} a The first number
+   * @param {number} [b=0] - The second
+   *   number
+   * @returns {number}
+   ",
+    );
+    assert_eq!(
+      doc.description,
+      "Adds two numbers.\n\nBoth numbers must be finite."
+    );
+    assert_eq!(
+      doc.tags,
+      vec![
+        JsDocTag {
+          kind: "param".to_string(),
+          type_expr: Some("number".to_string()),
+          name: Some("a".to_string()),
+          description: "The first number".to_string(),
+          ..Default::default()
+        },
+        JsDocTag {
+          kind: "param".to_string(),
+          type_expr: Some("number".to_string()),
+          name: Some("b".to_string()),
+          optional: true,
+          default: Some("0".to_string()),
+          description: "The second\n  number".to_string(),
+        },
+        JsDocTag {
+          kind: "returns".to_string(),
+          type_expr: Some("number".to_string()),
+          ..Default::default()
+        },
+      ]
+    );
+  }
+
+  #[test]
+  fn parse_single_line() {
+    let doc = JsDoc::parse("* @deprecated Use `bar` instead. ");
+    assert_eq!(doc.description, "");
+    assert_eq!(doc.tags.len(), 1);
+    assert_eq!(doc.tags[0].kind, "deprecated");
+    assert_eq!(doc.tags[0].name, None);
+    assert_eq!(doc.tags[0].description, "Use `bar` instead.");
+  }
+
+  #[test]
+  fn parse_nested_type() {
+    let doc = JsDoc::parse("* @param {{ a: { b: string } }} options");
+    let param = doc.params().next().unwrap();
+    assert_eq!(param.type_expr.as_deref(), Some("{ a: { b: string } }"));
+    assert_eq!(param.name.as_deref(), Some("options"));
+  }
+
+  #[test]
+  fn email_is_not_a_tag() {
+    let doc = JsDoc::parse("* Ask foo@example.com\n * @ not a tag");
+    assert_eq!(doc.description, "Ask foo@example.com\n@ not a tag");
+    assert!(doc.tags.is_empty());
+  }
+}
@@ -6,22 +6,38 @@
 #[macro_use]
 extern crate log;
 
-#[cfg(test)]
+// Declared first so that its test macros are available to the other modules.
 #[macro_use]
-mod test_util;
+pub mod test_util;
 
 pub mod ast_parser;
+pub mod class_analysis;
+pub mod code_actions;
+pub mod const_eval;
 pub mod context;
 mod control_flow;
 pub mod diagnostic;
-mod globals;
+pub mod diff;
+mod explicit_any;
+pub mod fixes;
+pub mod globals;
 mod handler;
 mod ignore_directives;
 mod js_regex;
+pub mod jsdoc;
 pub mod linter;
+pub mod migrate;
+pub mod module_graph;
+pub mod node_id;
 mod performance_mark;
+pub mod resolver;
 pub mod rules;
+pub mod summary;
 pub mod swc_util;
+pub mod syntax_features;
+mod testing;
+pub mod type_info;
+pub mod walk;
 
 pub use deno_ast::view::Program;
 pub use deno_ast::view::ProgramRef;
@@ -30,11 +46,12 @@ pub use deno_ast::view::ProgramRef;
 mod lint_tests {
   use std::collections::HashSet;
 
-  use crate::diagnostic::LintDiagnostic;
+  use crate::diagnostic::{LintDiagnostic, LintDiagnosticPosition};
   use crate::linter::*;
   use crate::rules::{get_all_rules, recommended_rules, LintRule};
   use crate::test_util::{assert_diagnostic, parse};
   use deno_ast::ParsedSource;
+  use deno_ast::TextChange;
   use deno_ast::{MediaType, ModuleSpecifier};
 
   fn lint(
@@ -47,6 +64,8 @@ mod lint_tests {
       all_rule_codes,
       custom_ignore_diagnostic_directive: None,
       custom_ignore_file_directive: None,
+      resolver: None,
+      type_info: None,
     });
 
     let (_, diagnostics) = linter
@@ -73,6 +92,8 @@ mod lint_tests {
       all_rule_codes,
       custom_ignore_diagnostic_directive: None,
       custom_ignore_file_directive: None,
+      resolver: None,
+      type_info: None,
     });
     linter.lint_with_ast(
       parsed_source,
@@ -298,4 +319,285 @@ const _foo = 42;
     let diagnostics = lint_recommended_rules_with_ast(&parsed_source);
     assert!(diagnostics.is_empty());
   }
+
+  #[test]
+  fn diagnostic_positions() {
+    let diagnostics = lint_specified_rule(
+      Box::new(crate::rules::require_yield::RequireYield),
+      "// ✓\nfunction* gen() {\n  return 1;\n}",
+    );
+    let range = diagnostics[0].range.as_ref().unwrap();
+    assert_eq!(
+      range.start_position(),
+      LintDiagnosticPosition {
+        line_number: 2,
+        column_number: 1,
+        byte_index: 7,
+      }
+    );
+    assert_eq!(
+      range.end_position(),
+      LintDiagnosticPosition {
+        line_number: 4,
+        column_number: 2,
+        byte_index: 38,
+      }
+    );
+  }
+
+  #[test]
+  fn diagnostic_positions_count_tabs_as_one_character() {
+    let diagnostics = lint_specified_rule(
+      Box::new(crate::rules::require_yield::RequireYield),
+      "\tfunction* gen() {\n  return 1;\n}",
+    );
+    let range = diagnostics[0].range.as_ref().unwrap();
+    assert_eq!(
+      range.start_position(),
+      LintDiagnosticPosition {
+        line_number: 1,
+        column_number: 2,
+        byte_index: 1,
+      }
+    );
+  }
+
+  #[test]
+  fn relint_matches_full_lint() {
+    let linter = Linter::new(LinterOptions {
+      rules: recommended_rules(get_all_rules()),
+      all_rule_codes: get_all_rules()
+        .into_iter()
+        .map(|rule| rule.code())
+        .collect(),
+      custom_ignore_diagnostic_directive: None,
+      custom_ignore_file_directive: None,
+      resolver: None,
+      type_info: None,
+    });
+    let config = LintConfig {
+      default_jsx_factory: None,
+      default_jsx_fragment_factory: None,
+    };
+    let lint = |source: &str| {
+      let (parsed_source, diagnostics) = linter
+        .lint_file(LintFileOptions {
+          specifier: ModuleSpecifier::parse("file:///lint_test.ts").unwrap(),
+          source_code: source.to_string(),
+          media_type: MediaType::TypeScript,
+          config: config.clone(),
+        })
+        .unwrap();
+      LintResult {
+        parsed_source,
+        diagnostics,
+        config: config.clone(),
+        has_bom: false,
+      }
+    };
+    let summary = |result: &LintResult| {
+      result
+        .diagnostics
+        .iter()
+        .map(|d| {
+          let range = d.range.as_ref().unwrap();
+          (
+            d.details.code.clone(),
+            d.details.message.clone(),
+            range.start_position(),
+            range.end_position(),
+          )
+        })
+        .collect::<Vec<_>>()
+    };
+
+    let source = "var  a = 1;\nlet b  = 2;\ndebugger;\nvar c = 3;";
+    let changes = [
+      // The spaces in a statement without diagnostics.
+      TextChange::new(17, 19, " ".to_string()),
+      // The spaces in a statement with a diagnostic.
+      TextChange::new(3, 5, "\t".to_string()),
+      // The spaces before a reported statement.
+      TextChange::new(12, 12, "  ".to_string()),
+      // A change to the tokens.
+      TextChange::new(24, 33, "b;".to_string()),
+      // A new line.
+      TextChange::new(3, 5, "\n".to_string()),
+    ];
+    let previous = lint(source);
+    for change in changes {
+      let relinted = linter.relint(&previous, change.clone()).unwrap();
+      let expected = lint(&deno_ast::apply_text_changes(source, vec![change]));
+      assert_eq!(
+        relinted.parsed_source.text().as_ref(),
+        expected.parsed_source.text().as_ref()
+      );
+      assert_eq!(summary(&relinted), summary(&expected));
+    }
+  }
+
+  #[test]
+  fn shared_traversal_matches_rule_traversals() {
+    use crate::handler::Handler;
+
+    /// Delegates to a rule, but has its handler called for all nodes.
+    #[derive(Debug)]
+    struct AllNodes(Box<dyn LintRule>);
+
+    impl LintRule for AllNodes {
+      fn lint_program_with_ast_view<'view>(
+        &self,
+        context: &mut crate::context::Context<'view>,
+        program: crate::Program<'view>,
+      ) {
+        self.0.lint_program_with_ast_view(context, program);
+      }
+
+      fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+        self.0.handler()
+      }
+
+      fn code(&self) -> &'static str {
+        self.0.code()
+      }
+
+      #[cfg(feature = "docs")]
+      fn docs(&self) -> &'static str {
+        self.0.docs()
+      }
+    }
+
+    let source = r#"
+var x = 1;
+async function outer() {
+  function inner() { await1(); }
+  const f = async () => { return await fetch("a"); };
+  debugger;
+  if (x == null) { label: for (const k in {}) { continue label; } }
+}
+class A extends B {
+  constructor() { this.a = 1; super(); }
+  get b() {}
+}
+const re = /a  b/;
+new Symbol();
+let y: any = window.foo as const;
+switch (y) { case 1: case 1: break; }
+"#;
+    let all_rule_codes: HashSet<_> =
+      get_all_rules().iter().map(|rule| rule.code()).collect();
+    let summary = |diagnostics: Vec<LintDiagnostic>| {
+      let mut summary = diagnostics
+        .into_iter()
+        .map(|d| {
+          let range = d.range.as_ref().unwrap();
+          let start = range.start_position().byte_index;
+          (start, d.details.code, d.details.message)
+        })
+        .collect::<Vec<_>>();
+      summary.sort();
+      summary
+    };
+
+    let shared = lint(source, get_all_rules(), all_rule_codes.clone());
+    let separate = get_all_rules()
+      .into_iter()
+      .flat_map(|rule| {
+        let rule: Box<dyn LintRule> = Box::new(AllNodes(rule));
+        lint(source, vec![rule], all_rule_codes.clone())
+      })
+      .collect();
+    assert!(!shared.is_empty());
+    assert_eq!(summary(shared), summary(separate));
+  }
+
+  #[test]
+  fn rules_are_skipped_without_required_syntax() {
+    use crate::syntax_features::SyntaxFeatures;
+    use deno_ast::SourceRanged;
+
+    /// Reports every file that it runs on.
+    #[derive(Debug)]
+    struct ClassesOnly;
+
+    impl LintRule for ClassesOnly {
+      fn lint_program_with_ast_view<'view>(
+        &self,
+        context: &mut crate::context::Context<'view>,
+        program: crate::Program<'view>,
+      ) {
+        context.add_diagnostic(program.range(), "classes-only", "ran");
+      }
+
+      fn code(&self) -> &'static str {
+        "classes-only"
+      }
+
+      fn required_syntax(&self) -> SyntaxFeatures {
+        SyntaxFeatures::CLASS | SyntaxFeatures::DECORATOR
+      }
+
+      #[cfg(feature = "docs")]
+      fn docs(&self) -> &'static str {
+        ""
+      }
+    }
+
+    let lint = |source: &str| {
+      lint(source, vec![Box::new(ClassesOnly)], HashSet::new()).len()
+    };
+    assert_eq!(lint("const a = 1;"), 0);
+    assert_eq!(lint("const a = class {};"), 1);
+  }
+
+  #[test]
+  fn specifier_resolver() {
+    use crate::resolver::SpecifierResolver;
+    use crate::rules::no_self_import::NoSelfImport;
+    use std::sync::Arc;
+
+    /// Maps `@/` to the root directory, like an import map would.
+    #[derive(Debug)]
+    struct RootAlias;
+
+    impl SpecifierResolver for RootAlias {
+      fn resolve(
+        &self,
+        specifier: &str,
+        _referrer: &ModuleSpecifier,
+      ) -> Option<ModuleSpecifier> {
+        let path = specifier.strip_prefix("@/")?;
+        ModuleSpecifier::parse(&format!("file:///{path}")).ok()
+      }
+    }
+
+    let source = r#"import { a } from "@/lint_test.ts";"#;
+    let lint_with_resolver = |resolver: Option<Arc<dyn SpecifierResolver>>| {
+      let linter = Linter::new(LinterOptions {
+        rules: vec![Box::new(NoSelfImport)],
+        all_rule_codes: HashSet::new(),
+        custom_ignore_diagnostic_directive: None,
+        custom_ignore_file_directive: None,
+        resolver,
+        type_info: None,
+      });
+      let (_, diagnostics) = linter
+        .lint_file(LintFileOptions {
+          specifier: ModuleSpecifier::parse("file:///lint_test.ts").unwrap(),
+          source_code: source.to_string(),
+          media_type: MediaType::TypeScript,
+          config: LintConfig {
+            default_jsx_factory: None,
+            default_jsx_fragment_factory: None,
+          },
+        })
+        .unwrap();
+      diagnostics
+    };
+
+    assert!(lint_with_resolver(None).is_empty());
+    let diagnostics = lint_with_resolver(Some(Arc::new(RootAlias)));
+    assert_eq!(diagnostics.len(), 1);
+    assert_diagnostic(&diagnostics[0], "no-self-import", 1, 18, source);
+  }
 }
@@ -2,15 +2,27 @@
 
 use crate::ast_parser::parse_program;
 use crate::context::Context;
-use crate::diagnostic::LintDiagnostic;
+use crate::diagnostic::{LintDiagnostic, LintDiagnosticRange};
+use crate::handler::Dispatcher;
 use crate::ignore_directives::parse_file_ignore_directives;
 use crate::performance_mark::PerformanceMark;
-use crate::rules::{ban_unknown_rule_code::BanUnknownRuleCode, LintRule};
+use crate::resolver::{DefaultSpecifierResolver, SpecifierResolver};
+use crate::rules::{
+  ban_unknown_rule_code::BanUnknownRuleCode, program_ref, LintRule,
+};
+use crate::syntax_features::SyntaxFeatures;
+use crate::type_info::TypeInfoProvider;
+use crate::Program;
 use deno_ast::diagnostics::Diagnostic;
+use deno_ast::swc::common::comments::CommentKind;
 use deno_ast::MediaType;
 use deno_ast::ParsedSource;
+use deno_ast::{apply_text_changes, TextChange};
 use deno_ast::{ModuleSpecifier, ParseDiagnostic};
+use deno_ast::{SourcePos, SourceRange, SourceRangedForSpanned};
 use std::collections::HashSet;
+use std::ops::Range;
+use std::sync::Arc;
 
 pub struct LinterOptions {
   /// Rules to lint with.
@@ -21,6 +33,13 @@ pub struct LinterOptions {
   pub custom_ignore_file_directive: Option<&'static str>,
   /// Defaults to "deno-lint-ignore"
   pub custom_ignore_diagnostic_directive: Option<&'static str>,
+  /// Resolves import specifiers for rules that check where imports point to.
+  /// Defaults to `DefaultSpecifierResolver`, which doesn't know about import
+  /// maps.
+  pub resolver: Option<Arc<dyn SpecifierResolver>>,
+  /// Types for the rules that need a type checker, which are skipped without
+  /// it.
+  pub type_info: Option<Arc<dyn TypeInfoProvider>>,
 }
 
 /// A linter instance.
@@ -41,6 +60,8 @@ pub(crate) struct LinterContext {
   /// Rules are sorted by priority
   pub rules: Vec<Box<dyn LintRule>>,
   pub all_rule_codes: HashSet<&'static str>,
+  pub resolver: Arc<dyn SpecifierResolver>,
+  pub type_info: Option<Arc<dyn TypeInfoProvider>>,
 }
 
 impl LinterContext {
@@ -61,6 +82,10 @@ impl LinterContext {
       check_unknown_rules,
       rules,
       all_rule_codes: options.all_rule_codes,
+      resolver: options
+        .resolver
+        .unwrap_or_else(|| Arc::new(DefaultSpecifierResolver)),
+      type_info: options.type_info,
     }
   }
 }
@@ -78,6 +103,18 @@ pub struct LintConfig {
   pub default_jsx_fragment_factory: Option<String>,
 }
 
+/// The diagnostics of a file along with what they were created from, so the
+/// file can be linted again with `Linter::relint` after it's edited.
+#[derive(Clone)]
+pub struct LintResult {
+  pub parsed_source: ParsedSource,
+  pub diagnostics: Vec<LintDiagnostic>,
+  pub config: LintConfig,
+  /// Whether the file started with a byte order mark, which isn't part of
+  /// the parsed source.
+  pub has_bom: bool,
+}
+
 impl Linter {
   pub fn new(options: LinterOptions) -> Self {
     let ctx = LinterContext::new(options);
@@ -97,14 +134,22 @@ impl Linter {
   ) -> Result<(ParsedSource, Vec<LintDiagnostic>), ParseDiagnostic> {
     let _mark = PerformanceMark::new("Linter::lint");
 
+    // The BOM is stripped before parsing, so rules can only tell whether the
+    // file had one through `Context::has_bom`.
+    let has_bom = options.source_code.starts_with('\u{FEFF}');
     let parse_result = {
       let _mark = PerformanceMark::new("ast_parser.parse_program");
-      parse_program(options.specifier, options.media_type, options.source_code)
+      parse_program(
+        options.specifier,
+        options.media_type,
+        deno_ast::strip_bom(options.source_code),
+      )
     };
 
     let parsed_source = parse_result?;
     let diagnostics = self.lint_inner(
       &parsed_source,
+      has_bom,
       options.config.default_jsx_factory,
       options.config.default_jsx_fragment_factory,
     );
@@ -122,13 +167,152 @@ impl Linter {
     config: LintConfig,
   ) -> Vec<LintDiagnostic> {
     let _mark = PerformanceMark::new("Linter::lint_with_ast");
+    // The BOM was already stripped when the file was parsed.
     self.lint_inner(
       parsed_source,
+      false,
       config.default_jsx_factory,
       config.default_jsx_fragment_factory,
     )
   }
 
+  /// Lint a file again after `change` is applied to it.
+  ///
+  /// When the change only edits spaces and tabs between the tokens of a line,
+  /// the diagnostics can't change other than moving, so only the rules that
+  /// reported diagnostics touching the change are run again and the other
+  /// diagnostics are moved to their new positions. Any other change, or a
+  /// file with ignore directives that would have to be checked again, falls
+  /// back to linting the whole file.
+  pub fn relint(
+    &self,
+    previous: &LintResult,
+    change: TextChange,
+  ) -> Result<LintResult, ParseDiagnostic> {
+    let _mark = PerformanceMark::new("Linter::relint");
+
+    let previous_source = &previous.parsed_source;
+    let source_code =
+      apply_text_changes(previous_source.text(), vec![change.clone()]);
+    let parsed_source = parse_program(
+      previous_source.specifier().clone(),
+      previous_source.media_type(),
+      source_code,
+    )?;
+    let config = previous.config.clone();
+
+    let diagnostics =
+      if is_layout_change(previous_source, &parsed_source, &change) {
+        self.relint_layout_change(previous, &parsed_source, &change)
+      } else {
+        None
+      };
+    let diagnostics = diagnostics.unwrap_or_else(|| {
+      self.lint_inner(
+        &parsed_source,
+        previous.has_bom,
+        config.default_jsx_factory.clone(),
+        config.default_jsx_fragment_factory.clone(),
+      )
+    });
+
+    Ok(LintResult {
+      parsed_source,
+      diagnostics,
+      config,
+      has_bom: previous.has_bom,
+    })
+  }
+
+  /// Moves the previous diagnostics after a change to the layout of a line,
+  /// and runs the rules whose diagnostics touch the change again. Returns
+  /// `None` if the whole file has to be linted again instead.
+  fn relint_layout_change(
+    &self,
+    previous: &LintResult,
+    parsed_source: &ParsedSource,
+    change: &TextChange,
+  ) -> Option<Vec<LintDiagnostic>> {
+    let touches_change = |range: &SourceRange, text_start: SourcePos| {
+      let start = range.start - text_start;
+      let end = range.end - text_start;
+      start <= change.range.end && change.range.start <= end
+    };
+    let mut dirty_codes = HashSet::new();
+    for diagnostic in &previous.diagnostics {
+      let Some(range) = &diagnostic.range else {
+        // The whole file is affected by diagnostics without a range.
+        return None;
+      };
+      let text_start = range.text_info.range().start.as_source_pos();
+      let dirty = touches_change(&range.range, text_start)
+        || diagnostic.details.fixes.iter().any(|fix| {
+          fix
+            .changes
+            .iter()
+            .any(|fix_change| touches_change(&fix_change.range, text_start))
+        });
+      if dirty {
+        dirty_codes.insert(diagnostic.details.code.as_str());
+      }
+    }
+
+    let mut diagnostics: Vec<LintDiagnostic> = previous
+      .diagnostics
+      .iter()
+      .filter(|d| !dirty_codes.contains(d.details.code.as_str()))
+      .map(|d| move_diagnostic(d, parsed_source, change))
+      .collect();
+    if !dirty_codes.is_empty() {
+      diagnostics.extend(self.lint_rules(
+        parsed_source,
+        &dirty_codes,
+        previous.config.clone(),
+        previous.has_bom,
+      )?);
+    }
+    sort_diagnostics(&mut diagnostics);
+    Some(diagnostics)
+  }
+
+  /// Runs only the rules with the given codes. Returns `None` if the file
+  /// has ignore directives, since whether they are used depends on the
+  /// diagnostics of all the rules.
+  fn lint_rules(
+    &self,
+    parsed_source: &ParsedSource,
+    codes: &HashSet<&str>,
+    config: LintConfig,
+    has_bom: bool,
+  ) -> Option<Vec<LintDiagnostic>> {
+    parsed_source.with_view(|pg| {
+      let file_ignore_directive =
+        parse_file_ignore_directives(self.ctx.ignore_file_directive, pg);
+      let mut context = Context::new(
+        &self.ctx,
+        parsed_source.clone(),
+        pg,
+        file_ignore_directive,
+        has_bom,
+        config.default_jsx_factory,
+        config.default_jsx_fragment_factory,
+      );
+      if context.has_ignore_directives() {
+        return None;
+      }
+      run_rules(
+        self
+          .ctx
+          .rules
+          .iter()
+          .filter(|rule| codes.contains(rule.code())),
+        &mut context,
+        pg,
+      );
+      Some(context.check_ignore_directive_usage())
+    })
+  }
+
   // TODO(bartlomieju): this struct does too much - not only it checks for ignored
   // lint rules, it also runs 2 additional rules. These rules should be rewritten
   // to use a regular way of writing a rule and not live on the `Context` struct.
@@ -142,14 +326,7 @@ impl Linter {
     diagnostics.extend(context.ban_unused_ignore(&self.ctx.rules));
 
     // Finally sort by position the diagnostics originates on then by code
-    diagnostics.sort_by(|a, b| {
-      let a_range = a.range.as_ref().map(|r| r.range.start);
-      let b_range = b.range.as_ref().map(|r| r.range.start);
-      match a_range.cmp(&b_range) {
-        std::cmp::Ordering::Equal => a.code().cmp(&b.code()),
-        cmp => cmp,
-      }
-    });
+    sort_diagnostics(&mut diagnostics);
 
     diagnostics
   }
@@ -157,6 +334,7 @@ impl Linter {
   fn lint_inner(
     &self,
     parsed_source: &ParsedSource,
+    has_bom: bool,
     default_jsx_factory: Option<String>,
     default_jsx_fragment_factory: Option<String>,
   ) -> Vec<LintDiagnostic> {
@@ -191,14 +369,13 @@ impl Linter {
         parsed_source.clone(),
         pg,
         file_ignore_directive,
+        has_bom,
         default_jsx_factory,
         default_jsx_fragment_factory,
       );
 
       // Run configured lint rules.
-      for rule in self.ctx.rules.iter() {
-        rule.lint_program_with_ast_view(&mut context, pg);
-      }
+      run_rules(self.ctx.rules.iter(), &mut context, pg);
 
       self.collect_diagnostics(context)
     });
@@ -206,3 +383,135 @@ impl Linter {
     diagnostics
   }
 }
+
+/// Runs the rules that traverse the AST themselves one after another, and
+/// then the handlers of the other rules all in the same traversal. Rules
+/// that check syntax the file doesn't use are skipped.
+fn run_rules<'a, 'view>(
+  rules: impl Iterator<Item = &'a Box<dyn LintRule>>,
+  context: &mut Context<'view>,
+  program: Program<'view>,
+) {
+  let mut features = None;
+  let mut dispatcher = Dispatcher::new();
+  for rule in rules {
+    let required = rule.required_syntax();
+    if !required.is_empty() {
+      let features = *features.get_or_insert_with(|| {
+        let _mark = PerformanceMark::new("Linter::scan_syntax_features");
+        SyntaxFeatures::scan(program_ref(program))
+      });
+      if !features.intersects(required) {
+        continue;
+      }
+    }
+    match rule.handler() {
+      Some(handler) => dispatcher.add(handler, rule.node_kinds()),
+      None => rule.lint_program_with_ast_view(context, program),
+    }
+  }
+  if !dispatcher.is_empty() {
+    let _mark = PerformanceMark::new("Linter::dispatch_handlers");
+    dispatcher.traverse(program, context);
+  }
+}
+
+fn sort_diagnostics(diagnostics: &mut [LintDiagnostic]) {
+  diagnostics.sort_by(|a, b| {
+    let a_range = a.range.as_ref().map(|r| r.range.start);
+    let b_range = b.range.as_ref().map(|r| r.range.start);
+    match a_range.cmp(&b_range) {
+      std::cmp::Ordering::Equal => a.code().cmp(&b.code()),
+      cmp => cmp,
+    }
+  });
+}
+
+/// Whether the change only edits the spaces and tabs between two tokens of a
+/// line, so that the tokens, the comments and the lines stay the same.
+fn is_layout_change(
+  previous: &ParsedSource,
+  parsed_source: &ParsedSource,
+  change: &TextChange,
+) -> bool {
+  let is_layout = |text: &str| text.chars().all(|c| c == ' ' || c == '\t');
+  let Some(replaced) = previous.text().get(change.range.clone()) else {
+    return false;
+  };
+  if !is_layout(replaced) || !is_layout(&change.new_text) {
+    return false;
+  }
+
+  tokens(previous) == tokens(parsed_source)
+    && comments(previous) == comments(parsed_source)
+}
+
+fn tokens(parsed_source: &ParsedSource) -> Vec<(bool, &str)> {
+  let text_info = parsed_source.text_info_lazy();
+  parsed_source
+    .tokens()
+    .iter()
+    .map(|token| {
+      (
+        token.had_line_break,
+        text_info.range_text(&token.span.range()),
+      )
+    })
+    .collect()
+}
+
+fn comments(parsed_source: &ParsedSource) -> Vec<(CommentKind, String)> {
+  parsed_source
+    .comments()
+    .get_vec()
+    .into_iter()
+    .map(|comment| (comment.kind, comment.text.to_string()))
+    .collect()
+}
+
+/// Moves a diagnostic that doesn't touch the change to the same text in the
+/// changed file.
+fn move_diagnostic(
+  diagnostic: &LintDiagnostic,
+  parsed_source: &ParsedSource,
+  change: &TextChange,
+) -> LintDiagnostic {
+  let text_info = parsed_source.text_info_lazy();
+  let move_range = |range: SourceRange, previous_start: SourcePos| {
+    let start = text_info.range().start;
+    let index = |pos: SourcePos| {
+      move_index(pos - previous_start, &change.range, change.new_text.len())
+    };
+    SourceRange::new(start + index(range.start), start + index(range.end))
+  };
+  let mut diagnostic = diagnostic.clone();
+  if let Some(range) = diagnostic.range.as_mut() {
+    let previous_start = range.text_info.range().start.as_source_pos();
+    for fix in diagnostic.details.fixes.iter_mut() {
+      for fix_change in fix.changes.iter_mut() {
+        fix_change.range = move_range(fix_change.range, previous_start);
+      }
+    }
+    *range = LintDiagnosticRange {
+      text_info: text_info.clone(),
+      range: move_range(range.range, previous_start),
+      description: range.description.take(),
+    };
+    for related in diagnostic.related.iter_mut() {
+      *related = LintDiagnosticRange {
+        text_info: text_info.clone(),
+        range: move_range(related.range, previous_start),
+        description: related.description.take(),
+      };
+    }
+  }
+  diagnostic
+}
+
+fn move_index(index: usize, changed: &Range<usize>, new_len: usize) -> usize {
+  if index >= changed.end {
+    index - changed.len() + new_len
+  } else {
+    index
+  }
+}
@@ -0,0 +1,164 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+//! Converts ESLint configs to the closest set of rules here, to bootstrap the
+//! config of a project that moves from ESLint.
+
+use crate::rules::{get_all_rules, rule_code_for_alias};
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::{BTreeSet, HashSet};
+
+/// The presets of ESLint configs that correspond to a tag, in `extends`.
+const PRESET_TAGS: &[(&str, &str)] = &[
+  ("eslint:recommended", "recommended"),
+  ("plugin:@typescript-eslint/recommended", "recommended"),
+  (
+    "plugin:@typescript-eslint/eslint-recommended",
+    "recommended",
+  ),
+  ("plugin:react/recommended", "react"),
+  ("plugin:react-hooks/recommended", "react"),
+  ("plugin:promise/recommended", "promise"),
+  ("plugin:jest/recommended", "test"),
+  ("plugin:security/recommended", "security"),
+];
+
+/// The rules of an ESLint config, as the `rules` of a deno_lint config.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct EslintMigration {
+  pub tags: Vec<String>,
+  pub include: Vec<String>,
+  pub exclude: Vec<String>,
+  /// The ESLint rules and presets that have no equivalent here.
+  #[serde(skip)]
+  pub unmapped: Vec<String>,
+}
+
+/// Maps the `extends` presets and the `rules` of an `.eslintrc` file in JSON
+/// to tags and rules. Enabled rules are included and disabled rules are
+/// excluded, while their options are dropped since they differ between the
+/// two.
+pub fn from_eslint(json: &str) -> Result<EslintMigration, serde_json::Error> {
+  let config: Value = serde_json::from_str(json)?;
+  let codes: HashSet<&'static str> =
+    get_all_rules().iter().map(|rule| rule.code()).collect();
+  let mut tags = BTreeSet::new();
+  let mut include = BTreeSet::new();
+  let mut exclude = BTreeSet::new();
+  let mut unmapped = Vec::new();
+
+  let extends = match &config["extends"] {
+    Value::String(preset) => vec![preset.as_str()],
+    Value::Array(presets) => presets.iter().filter_map(Value::as_str).collect(),
+    _ => vec![],
+  };
+  for preset in extends {
+    match PRESET_TAGS.iter().find(|(name, _)| *name == preset) {
+      Some((_, tag)) => {
+        tags.insert(tag.to_string());
+      }
+      None => unmapped.push(preset.to_string()),
+    }
+  }
+
+  if let Value::Object(rules) = &config["rules"] {
+    for (name, setting) in rules {
+      let code = match codes.get(name.as_str()) {
+        Some(code) => *code,
+        None => match rule_code_for_alias(name) {
+          Some(code) => code,
+          None => {
+            unmapped.push(name.to_string());
+            continue;
+          }
+        },
+      };
+      if is_enabled(setting) {
+        exclude.remove(code);
+        include.insert(code.to_string());
+      } else {
+        include.remove(code);
+        exclude.insert(code.to_string());
+      }
+    }
+  }
+
+  Ok(EslintMigration {
+    tags: tags.into_iter().collect(),
+    include: include.into_iter().collect(),
+    exclude: exclude.into_iter().collect(),
+    unmapped,
+  })
+}
+
+/// Whether the severity of a rule, like `"error"`, `2` or `["warn", {}]`,
+/// turns the rule on.
+fn is_enabled(setting: &Value) -> bool {
+  let severity = match setting {
+    Value::Array(setting) => setting.first().unwrap_or(&Value::Null),
+    severity => severity,
+  };
+  match severity {
+    Value::String(severity) => severity != "off",
+    Value::Number(severity) => severity.as_u64() != Some(0),
+    _ => false,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn migrate_rules() {
+    let migration = from_eslint(
+      r#"{
+        "extends": ["eslint:recommended", "plugin:react-hooks/recommended", "airbnb"],
+        "rules": {
+          "no-console": "error",
+          "eqeqeq": ["warn", "always"],
+          "no-debugger": 0,
+          "no-empty": "off",
+          "@typescript-eslint/no-explicit-any": 2,
+          "react-hooks/exhaustive-deps": ["error"],
+          "indent": ["error", 2],
+          "import/no-cycle": "error"
+        }
+      }"#,
+    )
+    .unwrap();
+    assert_eq!(
+      migration,
+      EslintMigration {
+        tags: vec!["react".to_string(), "recommended".to_string()],
+        include: vec![
+          "eqeqeq".to_string(),
+          "exhaustive-deps".to_string(),
+          "no-console".to_string(),
+          "no-explicit-any".to_string(),
+        ],
+        exclude: vec!["no-debugger".to_string(), "no-empty".to_string()],
+        unmapped: vec![
+          "airbnb".to_string(),
+          "import/no-cycle".to_string(),
+          "indent".to_string(),
+        ],
+      }
+    );
+  }
+
+  #[test]
+  fn migrate_extends_string() {
+    let migration =
+      from_eslint(r#"{ "extends": "plugin:@typescript-eslint/recommended" }"#)
+        .unwrap();
+    assert_eq!(migration.tags, vec!["recommended".to_string()]);
+    assert!(migration.include.is_empty());
+    assert!(migration.unmapped.is_empty());
+  }
+
+  #[test]
+  fn migrate_invalid_json() {
+    assert!(from_eslint("{ rules: }").is_err());
+  }
+}
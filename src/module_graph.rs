@@ -0,0 +1,451 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+//! Lists the imports and exports of a module, so rules about dependencies
+//! don't each have to match on every kind of module declaration.
+
+use deno_ast::swc::ast::{
+  Callee, Decl, ExportSpecifier, Expr, Id, ImportSpecifier, Lit,
+  ModuleExportName, Str, TsModuleName, TsModuleRef,
+};
+use deno_ast::swc::utils::find_pat_ids;
+use deno_ast::view::{self as ast_view, NodeTrait};
+use deno_ast::{SourceRange, SourceRanged, SourceRangedForSpanned};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportKind {
+  /// `import { a } from "x"` or `import "x"`.
+  Import,
+  /// `export { a } from "x"` or `export * as ns from "x"`.
+  ReExport,
+  /// `export * from "x"`.
+  ExportAll,
+  /// `import("x")`, when the specifier is a string literal.
+  DynamicImport,
+  /// `import a = require("x")`.
+  ImportEquals,
+}
+
+/// A module that the file depends on.
+#[derive(Debug, Clone)]
+pub struct ModuleImport<'a> {
+  pub kind: ImportKind,
+  /// The specifier as written, like `./mod.ts`.
+  pub specifier: &'a str,
+  /// The range of the specifier, including the quotes.
+  pub specifier_range: SourceRange,
+  /// The range of the whole declaration or call.
+  pub range: SourceRange,
+  /// Whether the whole declaration is type-only, like `import type { A }`.
+  /// Declarations with only some type-only bindings aren't.
+  pub type_only: bool,
+  /// The bindings that `import` declarations create.
+  pub bindings: Vec<ImportBinding>,
+}
+
+/// A binding created by an `import` declaration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportBinding {
+  /// The imported name, which is `default` for default imports and `None`
+  /// for namespace imports.
+  pub imported: Option<String>,
+  pub local: String,
+  pub range: SourceRange,
+  /// Whether the binding is type-only, either on its own or because the
+  /// whole declaration is.
+  pub type_only: bool,
+}
+
+/// A name that the module exports.
+#[derive(Debug, Clone)]
+pub struct ModuleExport<'a> {
+  /// The exported name, which is `default` for default exports.
+  pub name: String,
+  /// The range of the specifier or declaration that exports the name.
+  pub range: SourceRange,
+  /// Whether only the type is exported, like interfaces or `export type`.
+  pub type_only: bool,
+  /// The specifier of the module that the name is re-exported from.
+  pub from: Option<&'a str>,
+}
+
+/// The imports and exports of a module, in the order they're written.
+#[derive(Debug, Clone, Default)]
+pub struct ModuleGraph<'a> {
+  pub imports: Vec<ModuleImport<'a>>,
+  pub exports: Vec<ModuleExport<'a>>,
+}
+
+impl<'a> ModuleGraph<'a> {
+  pub fn new(program: ast_view::Program<'a>) -> Self {
+    let mut graph = Self::default();
+    graph.collect(program.as_node());
+    graph
+  }
+
+  /// The imports of the specifier, like to find duplicate imports.
+  pub fn imports_of<'s>(
+    &'s self,
+    specifier: &'s str,
+  ) -> impl Iterator<Item = &'s ModuleImport<'a>> {
+    self
+      .imports
+      .iter()
+      .filter(move |import| import.specifier == specifier)
+  }
+
+  /// The specifiers of the imports with their ranges. They borrow from the
+  /// program rather than the graph, so rules can report diagnostics while
+  /// going through them.
+  pub fn import_specifiers(&self) -> Vec<(&'a str, SourceRange)> {
+    self
+      .imports
+      .iter()
+      .map(|import| (import.specifier, import.specifier_range))
+      .collect()
+  }
+
+  fn add_import(
+    &mut self,
+    kind: ImportKind,
+    src: &'a Str,
+    range: SourceRange,
+    type_only: bool,
+    bindings: Vec<ImportBinding>,
+  ) {
+    self.imports.push(ModuleImport {
+      kind,
+      specifier: &src.value,
+      specifier_range: src.range(),
+      range,
+      type_only,
+      bindings,
+    });
+  }
+
+  fn add_export(
+    &mut self,
+    name: impl ToString,
+    range: SourceRange,
+    type_only: bool,
+    from: Option<&'a str>,
+  ) {
+    self.exports.push(ModuleExport {
+      name: name.to_string(),
+      range,
+      type_only,
+      from,
+    });
+  }
+
+  fn collect(&mut self, node: ast_view::Node<'a>) {
+    match node {
+      ast_view::Node::ImportDecl(decl) => {
+        let decl = decl.inner;
+        let bindings = decl
+          .specifiers
+          .iter()
+          .map(|specifier| import_binding(specifier, decl.type_only))
+          .collect();
+        self.add_import(
+          ImportKind::Import,
+          &decl.src,
+          decl.range(),
+          decl.type_only,
+          bindings,
+        );
+      }
+      ast_view::Node::NamedExport(export) => {
+        let export = export.inner;
+        let from = export.src.as_deref();
+        if let Some(src) = from {
+          self.add_import(
+            ImportKind::ReExport,
+            src,
+            export.range(),
+            export.type_only,
+            Vec::new(),
+          );
+        }
+        let from = from.map(|src| &*src.value);
+        for specifier in &export.specifiers {
+          match specifier {
+            ExportSpecifier::Named(named) => self.add_export(
+              export_name(named.exported.as_ref().unwrap_or(&named.orig)),
+              named.range(),
+              export.type_only || named.is_type_only,
+              from,
+            ),
+            ExportSpecifier::Namespace(namespace) => self.add_export(
+              export_name(&namespace.name),
+              namespace.range(),
+              export.type_only,
+              from,
+            ),
+            ExportSpecifier::Default(default) => self.add_export(
+              &default.exported.sym,
+              default.exported.range(),
+              export.type_only,
+              from,
+            ),
+          }
+        }
+      }
+      ast_view::Node::ExportAll(export) => {
+        let export = export.inner;
+        self.add_import(
+          ImportKind::ExportAll,
+          &export.src,
+          export.range(),
+          export.type_only,
+          Vec::new(),
+        );
+      }
+      ast_view::Node::ExportDecl(export) => {
+        let range = export.range();
+        match &export.inner.decl {
+          Decl::Class(class) => {
+            self.add_export(&class.ident.sym, range, false, None)
+          }
+          Decl::Fn(function) => {
+            self.add_export(&function.ident.sym, range, false, None)
+          }
+          Decl::Var(var) => {
+            for id in find_pat_ids::<_, Id>(&var.decls) {
+              self.add_export(&id.0, range, false, None);
+            }
+          }
+          Decl::TsInterface(interface) => {
+            self.add_export(&interface.id.sym, range, true, None)
+          }
+          Decl::TsTypeAlias(alias) => {
+            self.add_export(&alias.id.sym, range, true, None)
+          }
+          Decl::TsEnum(ts_enum) => {
+            self.add_export(&ts_enum.id.sym, range, false, None)
+          }
+          Decl::TsModule(module) => {
+            if let TsModuleName::Ident(id) = &module.id {
+              self.add_export(&id.sym, range, false, None);
+            }
+          }
+          Decl::Using(_) => {}
+        }
+      }
+      ast_view::Node::ExportDefaultDecl(export) => {
+        self.add_export("default", export.range(), false, None);
+      }
+      ast_view::Node::ExportDefaultExpr(export) => {
+        self.add_export("default", export.range(), false, None);
+      }
+      ast_view::Node::TsImportEqualsDecl(decl) => {
+        let decl = decl.inner;
+        if let TsModuleRef::TsExternalModuleRef(module_ref) = &decl.module_ref {
+          self.add_import(
+            ImportKind::ImportEquals,
+            &module_ref.expr,
+            decl.range(),
+            decl.is_type_only,
+            vec![ImportBinding {
+              imported: Some("default".to_string()),
+              local: decl.id.sym.to_string(),
+              range: decl.id.range(),
+              type_only: decl.is_type_only,
+            }],
+          );
+        }
+        if decl.is_export {
+          self.add_export(&decl.id.sym, decl.range(), false, None);
+        }
+      }
+      ast_view::Node::CallExpr(call) => {
+        let call = call.inner;
+        if let (Callee::Import(_), Some(arg)) =
+          (&call.callee, call.args.first())
+        {
+          if let Expr::Lit(Lit::Str(src)) = &*arg.expr {
+            self.add_import(
+              ImportKind::DynamicImport,
+              src,
+              call.range(),
+              false,
+              Vec::new(),
+            );
+          }
+        }
+      }
+      _ => {}
+    }
+    for child in node.children() {
+      self.collect(child);
+    }
+  }
+}
+
+fn import_binding(
+  specifier: &ImportSpecifier,
+  type_only: bool,
+) -> ImportBinding {
+  match specifier {
+    ImportSpecifier::Named(named) => ImportBinding {
+      imported: Some(
+        named
+          .imported
+          .as_ref()
+          .map_or_else(|| named.local.sym.to_string(), export_name),
+      ),
+      local: named.local.sym.to_string(),
+      range: named.range(),
+      type_only: type_only || named.is_type_only,
+    },
+    ImportSpecifier::Default(default) => ImportBinding {
+      imported: Some("default".to_string()),
+      local: default.local.sym.to_string(),
+      range: default.range(),
+      type_only,
+    },
+    ImportSpecifier::Namespace(namespace) => ImportBinding {
+      imported: None,
+      local: namespace.local.sym.to_string(),
+      range: namespace.range(),
+      type_only,
+    },
+  }
+}
+
+fn export_name(name: &ModuleExportName) -> String {
+  match name {
+    ModuleExportName::Ident(ident) => ident.sym.to_string(),
+    ModuleExportName::Str(str) => str.value.to_string(),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::parse;
+
+  fn with_graph(source: &str, f: impl FnOnce(ModuleGraph)) {
+    let parsed_source = parse(source);
+    parsed_source.with_view(|program| f(ModuleGraph::new(program)));
+  }
+
+  #[test]
+  fn imports() {
+    with_graph(
+      r#"import a, { b as c, type D } from "./a.ts";
+import type * as ns from "./types.ts";
+import "./polyfill.ts";
+export { e } from "./e.ts";
+export * from "./all.ts";
+import f = require("./f.js");
+async function load() {
+  await import("./lazy.ts");
+  await import(name);
+}"#,
+      |graph| {
+        let imports = graph
+          .imports
+          .iter()
+          .map(|import| (import.kind, import.specifier, import.type_only))
+          .collect::<Vec<_>>();
+        assert_eq!(
+          imports,
+          vec![
+            (ImportKind::Import, "./a.ts", false),
+            (ImportKind::Import, "./types.ts", true),
+            (ImportKind::Import, "./polyfill.ts", false),
+            (ImportKind::ReExport, "./e.ts", false),
+            (ImportKind::ExportAll, "./all.ts", false),
+            (ImportKind::ImportEquals, "./f.js", false),
+            (ImportKind::DynamicImport, "./lazy.ts", false),
+          ]
+        );
+
+        let bindings = graph.imports[0]
+          .bindings
+          .iter()
+          .map(|binding| {
+            (
+              binding.imported.as_deref(),
+              &*binding.local,
+              binding.type_only,
+            )
+          })
+          .collect::<Vec<_>>();
+        assert_eq!(
+          bindings,
+          vec![
+            (Some("default"), "a", false),
+            (Some("b"), "c", false),
+            (Some("D"), "D", true),
+          ]
+        );
+        assert_eq!(graph.imports[1].bindings[0].imported, None);
+        assert_eq!(graph.imports[1].bindings[0].local, "ns");
+        assert!(graph.imports[2].bindings.is_empty());
+
+        let specifiers = graph.import_specifiers();
+        assert_eq!(specifiers.len(), graph.imports.len());
+        assert_eq!(specifiers[3], ("./e.ts", graph.imports[3].specifier_range));
+      },
+    );
+  }
+
+  #[test]
+  fn exports() {
+    with_graph(
+      r#"export const a = 1, { b, c: [d] } = obj;
+export function f() {}
+export class C {}
+export interface I {}
+export type T = string;
+export enum E {}
+export { a as "quoted", type T as U };
+export * as ns from "./ns.ts";
+export default function () {}"#,
+      |graph| {
+        let exports = graph
+          .exports
+          .iter()
+          .map(|export| (&*export.name, export.type_only, export.from))
+          .collect::<Vec<_>>();
+        assert_eq!(
+          exports,
+          vec![
+            ("a", false, None),
+            ("b", false, None),
+            ("d", false, None),
+            ("f", false, None),
+            ("C", false, None),
+            ("I", true, None),
+            ("T", true, None),
+            ("E", false, None),
+            ("quoted", false, None),
+            ("U", true, None),
+            ("ns", false, Some("./ns.ts")),
+            ("default", false, None),
+          ]
+        );
+      },
+    );
+  }
+
+  #[test]
+  fn imports_of() {
+    with_graph(
+      r#"import { a } from "./mod.ts";
+import { b } from "./other.ts";
+import type { C } from "./mod.ts";"#,
+      |graph| {
+        let imports = graph.imports_of("./mod.ts").collect::<Vec<_>>();
+        assert_eq!(imports.len(), 2);
+        assert!(!imports[0].type_only);
+        assert!(imports[1].type_only);
+        assert_eq!(
+          imports[1].specifier_range.start,
+          imports[1].range.start + 23
+        );
+      },
+    );
+  }
+}
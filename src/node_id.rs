@@ -0,0 +1,376 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+//! Identifies the nodes of a file, so that rules can keep nodes in sets and
+//! maps without keying them by their range, which isn't unique: a node and
+//! its only child can have the same range, and so can zero-width nodes at the
+//! same position.
+
+use crate::Program;
+use deno_ast::view::{Node, NodeTrait};
+use std::any::TypeId;
+use std::collections::HashMap;
+
+/// The position of a node in a pre-order traversal of the program, which is
+/// the same for all the rules that lint a file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NodeId(u32);
+
+/// The `NodeId`s of all the nodes of a program, along with the parent of
+/// each node.
+pub struct NodeIds<'view> {
+  nodes: Vec<Node<'view>>,
+  parents: Vec<Option<NodeId>>,
+  /// The ids by the address and the type of the SWC node of a node.
+  ids: HashMap<(usize, TypeId), NodeId>,
+}
+
+impl<'view> NodeIds<'view> {
+  pub fn new(program: Program<'view>) -> Self {
+    let mut node_ids = Self {
+      nodes: Vec::new(),
+      parents: Vec::new(),
+      ids: HashMap::new(),
+    };
+    let mut stack = vec![(program.as_node(), None)];
+    while let Some((node, parent)) = stack.pop() {
+      let id = NodeId(node_ids.nodes.len() as u32);
+      node_ids.nodes.push(node);
+      node_ids.parents.push(parent);
+      node_ids.ids.insert(key(node), id);
+      let children = node.children();
+      stack.extend(children.into_iter().rev().map(|child| (child, Some(id))));
+    }
+    node_ids
+  }
+
+  /// The id of a node of the program.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the node isn't part of the program.
+  pub fn id(&self, node: Node) -> NodeId {
+    *self
+      .ids
+      .get(&key(node))
+      .expect("The node is not part of the program")
+  }
+
+  /// The id of the node of the program that wraps the SWC node, for rules
+  /// that visit the SWC AST. Returns `None` for nodes outside the program,
+  /// and for types that no node wraps, like `swc::ast::Expr`.
+  pub fn id_of<T: 'static>(&self, swc_node: &T) -> Option<NodeId> {
+    self.ids.get(&swc_key(swc_node)).copied()
+  }
+
+  pub fn node(&self, id: NodeId) -> Node<'view> {
+    self.nodes[id.0 as usize]
+  }
+
+  /// The id of the parent of the node, or `None` for the program.
+  pub fn parent(&self, id: NodeId) -> Option<NodeId> {
+    self.parents[id.0 as usize]
+  }
+
+  /// The ids of the ancestors of the node, from its parent up to the program.
+  pub fn ancestors(&self, id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+    let parents = &self.parents;
+    std::iter::successors(parents[id.0 as usize], move |id| {
+      parents[id.0 as usize]
+    })
+  }
+
+  pub fn len(&self) -> usize {
+    self.nodes.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.nodes.is_empty()
+  }
+}
+
+fn swc_key<T: 'static>(swc_node: &T) -> (usize, TypeId) {
+  // A node can start at the same address as its first field, so the type
+  // tells them apart.
+  (swc_node as *const T as usize, TypeId::of::<T>())
+}
+
+macro_rules! key {
+  ($($kind:ident),* $(,)?) => {
+    fn key(node: Node) -> (usize, TypeId) {
+      match node {
+        $(Node::$kind(n) => swc_key(n.inner),)*
+      }
+    }
+  };
+}
+
+key!(
+  ArrayLit,
+  ArrayPat,
+  ArrowExpr,
+  AssignExpr,
+  AssignPat,
+  AssignPatProp,
+  AssignProp,
+  AutoAccessor,
+  AwaitExpr,
+  BigInt,
+  BinExpr,
+  BindingIdent,
+  BlockStmt,
+  Bool,
+  BreakStmt,
+  CallExpr,
+  CatchClause,
+  Class,
+  ClassDecl,
+  ClassExpr,
+  ClassMethod,
+  ClassProp,
+  ComputedPropName,
+  CondExpr,
+  Constructor,
+  ContinueStmt,
+  DebuggerStmt,
+  Decorator,
+  DoWhileStmt,
+  EmptyStmt,
+  ExportAll,
+  ExportDecl,
+  ExportDefaultDecl,
+  ExportDefaultExpr,
+  ExportDefaultSpecifier,
+  ExportNamedSpecifier,
+  ExportNamespaceSpecifier,
+  ExprOrSpread,
+  ExprStmt,
+  FnDecl,
+  FnExpr,
+  ForInStmt,
+  ForOfStmt,
+  ForStmt,
+  Function,
+  GetterProp,
+  Ident,
+  IdentName,
+  IfStmt,
+  Import,
+  ImportDecl,
+  ImportDefaultSpecifier,
+  ImportNamedSpecifier,
+  ImportStarAsSpecifier,
+  Invalid,
+  JSXAttr,
+  JSXClosingElement,
+  JSXClosingFragment,
+  JSXElement,
+  JSXEmptyExpr,
+  JSXExprContainer,
+  JSXFragment,
+  JSXMemberExpr,
+  JSXNamespacedName,
+  JSXOpeningElement,
+  JSXOpeningFragment,
+  JSXSpreadChild,
+  JSXText,
+  KeyValuePatProp,
+  KeyValueProp,
+  LabeledStmt,
+  MemberExpr,
+  MetaPropExpr,
+  MethodProp,
+  Module,
+  NamedExport,
+  NewExpr,
+  Null,
+  Number,
+  ObjectLit,
+  ObjectPat,
+  OptChainExpr,
+  OptCall,
+  Param,
+  ParenExpr,
+  PrivateMethod,
+  PrivateName,
+  PrivateProp,
+  Regex,
+  RestPat,
+  ReturnStmt,
+  Script,
+  SeqExpr,
+  SetterProp,
+  SpreadElement,
+  StaticBlock,
+  Str,
+  Super,
+  SuperPropExpr,
+  SwitchCase,
+  SwitchStmt,
+  TaggedTpl,
+  ThisExpr,
+  ThrowStmt,
+  Tpl,
+  TplElement,
+  TryStmt,
+  TsArrayType,
+  TsAsExpr,
+  TsCallSignatureDecl,
+  TsConditionalType,
+  TsConstAssertion,
+  TsConstructSignatureDecl,
+  TsConstructorType,
+  TsEnumDecl,
+  TsEnumMember,
+  TsExportAssignment,
+  TsExprWithTypeArgs,
+  TsExternalModuleRef,
+  TsFnType,
+  TsGetterSignature,
+  TsImportEqualsDecl,
+  TsImportType,
+  TsIndexSignature,
+  TsIndexedAccessType,
+  TsInferType,
+  TsInstantiation,
+  TsInterfaceBody,
+  TsInterfaceDecl,
+  TsIntersectionType,
+  TsKeywordType,
+  TsLitType,
+  TsMappedType,
+  TsMethodSignature,
+  TsModuleBlock,
+  TsModuleDecl,
+  TsNamespaceDecl,
+  TsNamespaceExportDecl,
+  TsNonNullExpr,
+  TsOptionalType,
+  TsParamProp,
+  TsParenthesizedType,
+  TsPropertySignature,
+  TsQualifiedName,
+  TsRestType,
+  TsSatisfiesExpr,
+  TsSetterSignature,
+  TsThisType,
+  TsTplLitType,
+  TsTupleElement,
+  TsTupleType,
+  TsTypeAliasDecl,
+  TsTypeAnn,
+  TsTypeAssertion,
+  TsTypeLit,
+  TsTypeOperator,
+  TsTypeParam,
+  TsTypeParamDecl,
+  TsTypeParamInstantiation,
+  TsTypePredicate,
+  TsTypeQuery,
+  TsTypeRef,
+  TsUnionType,
+  UnaryExpr,
+  UpdateExpr,
+  UsingDecl,
+  VarDecl,
+  VarDeclarator,
+  WhileStmt,
+  WithStmt,
+  YieldExpr
+);
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::parse;
+  use deno_ast::swc::ast::{Decl, Stmt};
+  use deno_ast::view::NodeKind;
+  use deno_ast::SourceRanged;
+
+  #[test]
+  fn ids_are_unique_and_in_pre_order() {
+    let parsed_source = parse("let a = 1; if (a) { a; }");
+    parsed_source.with_view(|program| {
+      let node_ids = NodeIds::new(program);
+      let root = node_ids.id(program.as_node());
+      assert_eq!(root, NodeId(0));
+      assert_eq!(node_ids.parent(root), None);
+
+      let mut previous = root;
+      for i in 1..node_ids.len() {
+        let id = NodeId(i as u32);
+        let node = node_ids.node(id);
+        assert_eq!(node_ids.id(node), id);
+        assert!(previous < id);
+        previous = id;
+
+        let parent = node_ids.parent(id).unwrap();
+        assert!(parent < id);
+        assert_eq!(node_ids.id(node.parent().unwrap()), parent);
+      }
+    });
+  }
+
+  #[test]
+  fn ancestors_go_up_to_the_program() {
+    let parsed_source = parse("function f() { return a; }");
+    parsed_source.with_view(|program| {
+      let node_ids = NodeIds::new(program);
+      let ident = node_ids
+        .nodes
+        .iter()
+        .rposition(|node| node.kind() == NodeKind::Ident)
+        .unwrap();
+      let kinds = node_ids
+        .ancestors(NodeId(ident as u32))
+        .map(|id| node_ids.node(id).kind())
+        .collect::<Vec<_>>();
+      assert_eq!(
+        kinds,
+        vec![
+          NodeKind::ReturnStmt,
+          NodeKind::BlockStmt,
+          NodeKind::Function,
+          NodeKind::FnDecl,
+          NodeKind::Script,
+        ]
+      );
+      assert_eq!(node_ids.ancestors(NodeId(0)).count(), 0);
+    });
+  }
+
+  #[test]
+  fn nodes_with_the_same_range_have_different_ids() {
+    // The expression statement and the identifier have the same range.
+    let parsed_source = parse("a");
+    parsed_source.with_view(|program| {
+      let node_ids = NodeIds::new(program);
+      let ident = node_ids
+        .nodes
+        .iter()
+        .find(|node| node.kind() == NodeKind::Ident)
+        .unwrap();
+      let expr_stmt = ident.parent().unwrap();
+      assert_eq!(expr_stmt.kind(), NodeKind::ExprStmt);
+      assert_eq!(ident.range(), expr_stmt.range());
+      assert_ne!(node_ids.id(*ident), node_ids.id(expr_stmt));
+    });
+  }
+
+  #[test]
+  fn id_of_swc_node() {
+    let parsed_source = parse("function f() {}");
+    parsed_source.with_view(|program| {
+      let node_ids = NodeIds::new(program);
+      let script = parsed_source.program_ref().as_script().unwrap();
+      let Stmt::Decl(decl) = &script.body[0] else {
+        unreachable!();
+      };
+      let Decl::Fn(fn_decl) = decl else {
+        unreachable!();
+      };
+      let id = node_ids.id_of(fn_decl).unwrap();
+      assert_eq!(node_ids.node(id).kind(), NodeKind::FnDecl);
+      assert_eq!(node_ids.id_of(decl), None);
+    });
+  }
+}
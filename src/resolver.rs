@@ -0,0 +1,69 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+//! Resolution of import specifiers, so rules about where imports point to can
+//! compare URLs instead of the specifiers as written.
+
+use deno_ast::ModuleSpecifier;
+
+/// Resolves the specifiers of imports, which embedders implement to apply
+/// import maps, `deno.json` imports and the like.
+pub trait SpecifierResolver: std::fmt::Debug + Send + Sync {
+  /// Resolves `specifier` as imported by the module at `referrer`. Returns
+  /// `None` if it can't be resolved, like an unmapped bare specifier.
+  fn resolve(
+    &self,
+    specifier: &str,
+    referrer: &ModuleSpecifier,
+  ) -> Option<ModuleSpecifier>;
+}
+
+/// Resolves relative and absolute specifiers against the referrer and takes
+/// URLs like `https:`, `jsr:` and `npm:` specifiers as they are. Bare
+/// specifiers like `react` need an import map, so they aren't resolved.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DefaultSpecifierResolver;
+
+impl SpecifierResolver for DefaultSpecifierResolver {
+  fn resolve(
+    &self,
+    specifier: &str,
+    referrer: &ModuleSpecifier,
+  ) -> Option<ModuleSpecifier> {
+    if specifier.starts_with("./")
+      || specifier.starts_with("../")
+      || specifier.starts_with('/')
+    {
+      referrer.join(specifier).ok()
+    } else {
+      ModuleSpecifier::parse(specifier).ok()
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn default_resolver() {
+    let referrer = ModuleSpecifier::parse("file:///repo/src/mod.ts").unwrap();
+    let resolve = |specifier| {
+      DefaultSpecifierResolver
+        .resolve(specifier, &referrer)
+        .map(String::from)
+    };
+    assert_eq!(resolve("./a.ts").as_deref(), Some("file:///repo/src/a.ts"));
+    assert_eq!(resolve("../a.ts").as_deref(), Some("file:///repo/a.ts"));
+    assert_eq!(resolve("/lib/a.ts").as_deref(), Some("file:///lib/a.ts"));
+    assert_eq!(
+      resolve("https://example.com/a.ts").as_deref(),
+      Some("https://example.com/a.ts")
+    );
+    assert_eq!(
+      resolve("jsr:@std/path@1").as_deref(),
+      Some("jsr:@std/path@1")
+    );
+    assert_eq!(resolve("react"), None);
+    assert_eq!(resolve("@/a.ts"), None);
+  }
+}
@@ -1,12 +1,18 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use crate::context::Context;
+use crate::handler::{Handler, Traverse};
+use crate::syntax_features::SyntaxFeatures;
 use crate::Program;
 use crate::ProgramRef;
+use deno_ast::view::NodeKind;
+use once_cell::sync::Lazy;
+use serde::Serialize;
 use std::cmp::Ordering;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 pub mod adjacent_overload_signatures;
+pub mod arrow_body_style;
 pub mod ban_ts_comment;
 pub mod ban_types;
 pub mod ban_unknown_rule_code;
@@ -14,16 +20,29 @@ pub mod ban_untagged_ignore;
 pub mod ban_untagged_todo;
 pub mod ban_unused_ignore;
 pub mod camelcase;
+pub mod class_methods_use_this;
+pub mod consistent_generic_constructors;
 pub mod constructor_super;
 pub mod default_param_last;
 pub mod eqeqeq;
+pub mod exhaustive_deps;
 pub mod explicit_function_return_type;
 pub mod explicit_module_boundary_types;
 pub mod for_direction;
 pub mod fresh_handler_export;
+pub mod fresh_island_serializable_props;
+pub mod fresh_no_browser_globals_in_handlers;
 pub mod fresh_server_event_handlers;
 pub mod getter_return;
 pub mod guard_for_in;
+pub mod jsx_key;
+pub mod jsx_no_children_prop;
+pub mod jsx_no_comment_textnodes;
+pub mod jsx_no_duplicate_props;
+pub mod jsx_props_no_spread_multi;
+pub mod logical_assignment_operators;
+pub mod method_signature_style;
+pub mod naming_convention;
 pub mod no_array_constructor;
 pub mod no_async_promise_executor;
 pub mod no_await_in_loop;
@@ -33,30 +52,44 @@ pub mod no_case_declarations;
 pub mod no_class_assign;
 pub mod no_compare_neg_zero;
 pub mod no_cond_assign;
+pub mod no_confusing_arrow;
+pub mod no_confusing_non_null_assertion;
+pub mod no_confusing_void_expression;
 pub mod no_console;
+pub mod no_console_spread_secrets;
 pub mod no_const_assign;
 pub mod no_constant_condition;
 pub mod no_control_regex;
 pub mod no_debugger;
 pub mod no_delete_var;
 pub mod no_deprecated_deno_api;
+pub mod no_disabled_tests;
+pub mod no_div_regex;
 pub mod no_dupe_args;
 pub mod no_dupe_class_members;
 pub mod no_dupe_else_if;
 pub mod no_dupe_keys;
+pub mod no_dupe_regex_alternatives;
 pub mod no_duplicate_case;
+pub mod no_duplicate_decorators;
+pub mod no_duplicate_enum_values;
 pub mod no_empty;
 pub mod no_empty_character_class;
 pub mod no_empty_enum;
+pub mod no_empty_function;
 pub mod no_empty_interface;
 pub mod no_empty_pattern;
 pub mod no_eval;
+pub mod no_eval_like;
 pub mod no_ex_assign;
 pub mod no_explicit_any;
 pub mod no_external_imports;
 pub mod no_extra_boolean_cast;
 pub mod no_extra_non_null_assertion;
+pub mod no_extra_parens;
+pub mod no_extra_semi;
 pub mod no_fallthrough;
+pub mod no_focused_tests;
 pub mod no_func_assign;
 pub mod no_global_assign;
 pub mod no_implicit_declare_namespace_export;
@@ -64,61 +97,144 @@ pub mod no_import_assertions;
 pub mod no_import_assign;
 pub mod no_inferrable_types;
 pub mod no_inner_declarations;
+pub mod no_insecure_random;
 pub mod no_invalid_regexp;
 pub mod no_invalid_triple_slash_reference;
 pub mod no_irregular_whitespace;
 pub mod no_misused_new;
+pub mod no_mixed_enums;
 pub mod no_namespace;
+pub mod no_nested_promises;
 pub mod no_new_symbol;
+pub mod no_node_builtins_without_prefix;
 pub mod no_node_globals;
 pub mod no_non_null_asserted_optional_chain;
 pub mod no_non_null_assertion;
+pub mod no_nonoctal_decimal_escape;
 pub mod no_obj_calls;
 pub mod no_octal;
+pub mod no_octal_escape;
+pub mod no_process_global;
+pub mod no_promise_in_callback;
 pub mod no_prototype_builtins;
 pub mod no_redeclare;
+pub mod no_redundant_type_constituents;
 pub mod no_regex_spaces;
+pub mod no_regex_vulnerabilities;
+pub mod no_relative_parent_imports;
+pub mod no_return_await;
 pub mod no_self_assign;
 pub mod no_self_compare;
+pub mod no_self_import;
 pub mod no_setter_return;
 pub mod no_shadow_restricted_names;
 pub mod no_sparse_arrays;
+pub mod no_standalone_expect;
 pub mod no_sync_fn_in_async_fn;
 pub mod no_this_alias;
 pub mod no_this_before_super;
 pub mod no_throw_literal;
 pub mod no_top_level_await;
+pub mod no_top_level_side_effect_in_library;
 pub mod no_undef;
+pub mod no_unexpected_multiline;
+pub mod no_unnecessary_type_arguments;
+pub mod no_unnecessary_type_assertion;
+pub mod no_unnecessary_type_constraint;
 pub mod no_unreachable;
+pub mod no_unsafe_assignment;
+pub mod no_unsafe_call;
 pub mod no_unsafe_finally;
+pub mod no_unsafe_member_access;
 pub mod no_unsafe_negation;
 pub mod no_unused_labels;
 pub mod no_unused_vars;
+pub mod no_unversioned_remote_imports;
+pub mod no_useless_constructor;
+pub mod no_useless_empty_export;
+pub mod no_useless_generators;
+pub mod no_useless_path_segments;
+pub mod no_useless_regex_escape;
+pub mod no_useless_regex_flags;
 pub mod no_var;
 pub mod no_window;
 pub mod no_window_prefix;
 pub mod no_with;
+pub mod object_shorthand;
+pub mod operator_assignment;
+pub mod parameter_properties;
+pub mod prefer_array_flat;
+pub mod prefer_array_flat_map;
 pub mod prefer_as_const;
 pub mod prefer_ascii;
+pub mod prefer_at;
+pub mod prefer_await_to_then;
 pub mod prefer_const;
+pub mod prefer_destructuring;
+pub mod prefer_enum_initializers;
+pub mod prefer_includes;
+pub mod prefer_jsr_over_deno_land_x;
+pub mod prefer_literal_enum_member;
 pub mod prefer_namespace_keyword;
+pub mod prefer_numeric_literals;
+pub mod prefer_object_has_own;
 pub mod prefer_primordials;
+pub mod prefer_readonly;
+pub mod prefer_return_this_type;
+pub mod prefer_string_method;
+pub mod prefer_string_starts_ends_with;
 pub mod require_await;
+pub mod require_await_in_async_test;
+pub mod require_jsdoc;
+pub mod require_permission_comments;
+pub mod require_unicode_regexp;
 pub mod require_yield;
+pub mod rules_of_hooks;
 pub mod single_var_declarator;
+pub mod switch_exhaustiveness_check;
 pub mod triple_slash_reference;
+pub mod unicode_bom;
 pub mod use_isnan;
+pub mod valid_jsdoc;
 pub mod valid_typeof;
 pub mod verbatim_module_syntax;
 
 pub trait LintRule: std::fmt::Debug + Send + Sync {
   /// Executes lint using `dprint-swc-ecma-ast-view`.
-  /// Falls back to the `lint_program` method if not implemented.
+  ///
+  /// Rules with a `handler` don't need to implement this, since the linter
+  /// calls their handler in the traversal it shares between all rules.
   fn lint_program_with_ast_view<'view>(
     &self,
     context: &mut Context<'view>,
     program: Program<'view>,
-  );
+  ) {
+    if let Some(mut handler) = self.handler() {
+      handler.traverse(program, context);
+    }
+  }
+
+  /// Returns a handler for the nodes of the kinds in `node_kinds`, which the
+  /// linter calls in one traversal of the AST for all rules instead of each
+  /// rule traversing it on its own. Rules that return `None` traverse the AST
+  /// themselves in `lint_program_with_ast_view`.
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    None
+  }
+
+  /// Returns the kinds of nodes that the handler is called for, or `None` to
+  /// call it for all nodes, which handlers that implement `on_enter_node` or
+  /// `on_exit_node` need.
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    None
+  }
+
+  /// Returns the syntax the rule checks, so that the linter can skip the
+  /// rule on files that use none of it. Rules that return
+  /// `SyntaxFeatures::NONE` run on all files.
+  fn required_syntax(&self) -> SyntaxFeatures {
+    SyntaxFeatures::NONE
+  }
 
   /// Returns the unique code that identifies the rule
   fn code(&self) -> &'static str;
@@ -128,6 +244,15 @@ pub trait LintRule: std::fmt::Debug + Send + Sync {
     &[]
   }
 
+  /// Returns other codes that refer to this rule, like the name of the
+  /// equivalent ESLint rule when it differs from the code.
+  ///
+  /// Aliases are accepted in configs and ignore directives, but are
+  /// deprecated in favor of the code.
+  fn aliases(&self) -> &'static [&'static str] {
+    &[]
+  }
+
   /// Returns the documentation string for this rule, describing what this rule is for with several
   /// examples.
   #[cfg(feature = "docs")]
@@ -140,6 +265,104 @@ pub trait LintRule: std::fmt::Debug + Send + Sync {
   fn priority(&self) -> u32 {
     0
   }
+
+  /// Whether diagnostics of this rule can come with fixes.
+  fn fixable(&self) -> bool {
+    false
+  }
+
+  /// Returns the JSON schema of the options this rule can be configured
+  /// with, or `None` if the rule has no options.
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    None
+  }
+}
+
+/// Information about a rule for tools that list the available rules.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RuleMetadata {
+  pub code: &'static str,
+  #[cfg(feature = "docs")]
+  pub docs: &'static str,
+  pub tags: &'static [&'static str],
+  pub aliases: &'static [&'static str],
+  pub docs_url: String,
+  pub fixable: bool,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub options_schema: Option<serde_json::Value>,
+}
+
+impl RuleMetadata {
+  pub fn from_rule(rule: &dyn LintRule) -> Self {
+    Self {
+      code: rule.code(),
+      #[cfg(feature = "docs")]
+      docs: rule.docs(),
+      tags: rule.tags(),
+      aliases: rule.aliases(),
+      docs_url: docs_url(rule.code()),
+      fixable: rule.fixable(),
+      options_schema: rule.options_schema(),
+    }
+  }
+}
+
+/// Returns the metadata of all rules, sorted by their code.
+pub fn get_all_rules_metadata() -> Vec<RuleMetadata> {
+  get_all_rules()
+    .iter()
+    .map(|rule| RuleMetadata::from_rule(rule.as_ref()))
+    .collect()
+}
+
+/// The URL of the documentation of the rule with the given code.
+pub fn docs_url(code: &str) -> String {
+  format!("https://lint.deno.land/rules/{}", code)
+}
+
+/// The namespace of typescript-eslint, whose rules have the same names as
+/// the rules here, like `@typescript-eslint/no-explicit-any`.
+const TYPESCRIPT_ESLINT_NAMESPACE: &str = "@typescript-eslint/";
+
+static RULE_CODES: Lazy<HashSet<&'static str>> =
+  Lazy::new(|| get_all_rules().iter().map(|rule| rule.code()).collect());
+
+static RULE_ALIASES: Lazy<HashMap<&'static str, &'static str>> =
+  Lazy::new(|| {
+    let mut aliases = HashMap::new();
+    for rule in get_all_rules() {
+      for alias in rule.aliases() {
+        aliases.insert(*alias, rule.code());
+      }
+    }
+    aliases
+  });
+
+/// Returns the code of the rule that `alias` refers to, or `None` if it's
+/// not an alias of a rule.
+pub fn rule_code_for_alias(alias: &str) -> Option<&'static str> {
+  if let Some(code) = RULE_ALIASES.get(alias) {
+    return Some(code);
+  }
+  let code = alias.strip_prefix(TYPESCRIPT_ESLINT_NAMESPACE)?;
+  RULE_CODES.get(code).copied()
+}
+
+/// Resolves a rule code from a config or an ignore directive. Aliases are
+/// resolved to the code of their rule with a deprecation warning, and other
+/// codes are returned as they are.
+pub fn resolve_rule_code(code: &str) -> &str {
+  match rule_code_for_alias(code) {
+    Some(rule_code) => {
+      warn!(
+        "\"{}\" is a deprecated alias of the \"{}\" rule, use \"{}\" instead",
+        code, rule_code, rule_code
+      );
+      rule_code
+    }
+    None => code,
+  }
 }
 
 /// TODO(@magurotuna): remove this after all rules get to use ast_view
@@ -178,6 +401,9 @@ pub fn recommended_rules(
 /// - if `maybe_include` is `Some`, rules with matching codes will be added
 ///   to the return list
 ///
+/// Aliases in `maybe_exclude` and `maybe_include` are resolved to the codes
+/// of their rules.
+///
 /// Before returning the list will sorted alphabetically.
 pub fn filtered_rules(
   all_rules: Vec<Box<dyn LintRule>>,
@@ -187,6 +413,14 @@ pub fn filtered_rules(
 ) -> Vec<Box<dyn LintRule>> {
   let tags_set =
     maybe_tags.map(|tags| tags.into_iter().collect::<HashSet<_>>());
+  let resolve = |codes: Vec<String>| {
+    codes
+      .iter()
+      .map(|code| resolve_rule_code(code).to_owned())
+      .collect::<Vec<_>>()
+  };
+  let maybe_exclude = maybe_exclude.map(resolve);
+  let maybe_include = maybe_include.map(resolve);
 
   let mut rules = all_rules
     .into_iter()
@@ -237,23 +471,39 @@ pub(crate) fn sort_rules_by_priority(rules: &mut [Box<dyn LintRule>]) {
 fn get_all_rules_raw() -> Vec<Box<dyn LintRule>> {
   vec![
     Box::new(adjacent_overload_signatures::AdjacentOverloadSignatures),
-    Box::new(ban_ts_comment::BanTsComment),
+    Box::new(arrow_body_style::ArrowBodyStyle::default()),
+    Box::new(ban_ts_comment::BanTsComment::default()),
     Box::new(ban_types::BanTypes),
     Box::new(ban_unknown_rule_code::BanUnknownRuleCode),
     Box::new(ban_untagged_ignore::BanUntaggedIgnore),
-    Box::new(ban_untagged_todo::BanUntaggedTodo),
+    Box::new(ban_untagged_todo::BanUntaggedTodo::default()),
     Box::new(ban_unused_ignore::BanUnusedIgnore),
     Box::new(camelcase::Camelcase),
+    Box::new(class_methods_use_this::ClassMethodsUseThis::default()),
+    Box::new(
+      consistent_generic_constructors::ConsistentGenericConstructors::default(),
+    ),
     Box::new(constructor_super::ConstructorSuper),
     Box::new(default_param_last::DefaultParamLast),
     Box::new(eqeqeq::Eqeqeq),
+    Box::new(exhaustive_deps::ExhaustiveDeps),
     Box::new(explicit_function_return_type::ExplicitFunctionReturnType),
     Box::new(explicit_module_boundary_types::ExplicitModuleBoundaryTypes),
     Box::new(for_direction::ForDirection),
     Box::new(fresh_handler_export::FreshHandlerExport),
+    Box::new(fresh_island_serializable_props::FreshIslandSerializableProps),
+    Box::new(fresh_no_browser_globals_in_handlers::FreshNoBrowserGlobalsInHandlers),
     Box::new(fresh_server_event_handlers::FreshServerEventHandlers),
     Box::new(getter_return::GetterReturn),
     Box::new(guard_for_in::GuardForIn),
+    Box::new(jsx_key::JsxKey),
+    Box::new(jsx_no_children_prop::JsxNoChildrenProp),
+    Box::new(jsx_no_comment_textnodes::JsxNoCommentTextnodes),
+    Box::new(jsx_no_duplicate_props::JsxNoDuplicateProps),
+    Box::new(jsx_props_no_spread_multi::JsxPropsNoSpreadMulti),
+    Box::new(logical_assignment_operators::LogicalAssignmentOperators::default()),
+    Box::new(method_signature_style::MethodSignatureStyle::default()),
+    Box::new(naming_convention::NamingConvention::default()),
     Box::new(no_array_constructor::NoArrayConstructor),
     Box::new(no_async_promise_executor::NoAsyncPromiseExecutor),
     Box::new(no_await_in_loop::NoAwaitInLoop),
@@ -263,32 +513,48 @@ fn get_all_rules_raw() -> Vec<Box<dyn LintRule>> {
     Box::new(no_class_assign::NoClassAssign),
     Box::new(no_compare_neg_zero::NoCompareNegZero),
     Box::new(no_cond_assign::NoCondAssign),
+    Box::new(no_confusing_arrow::NoConfusingArrow::default()),
+    Box::new(no_confusing_non_null_assertion::NoConfusingNonNullAssertion),
+    Box::new(
+      no_confusing_void_expression::NoConfusingVoidExpression::default(),
+    ),
     Box::new(no_console::NoConsole),
+    Box::new(no_console_spread_secrets::NoConsoleSpreadSecrets::default()),
     Box::new(no_const_assign::NoConstAssign),
     Box::new(no_constant_condition::NoConstantCondition),
     Box::new(no_control_regex::NoControlRegex),
     Box::new(no_debugger::NoDebugger),
     Box::new(no_delete_var::NoDeleteVar),
     Box::new(no_deprecated_deno_api::NoDeprecatedDenoApi),
+    Box::new(no_disabled_tests::NoDisabledTests),
+    Box::new(no_div_regex::NoDivRegex),
     Box::new(no_dupe_args::NoDupeArgs),
     Box::new(no_dupe_class_members::NoDupeClassMembers),
     Box::new(no_dupe_else_if::NoDupeElseIf),
     Box::new(no_dupe_keys::NoDupeKeys),
+    Box::new(no_dupe_regex_alternatives::NoDupeRegexAlternatives),
     Box::new(no_duplicate_case::NoDuplicateCase),
+    Box::new(no_duplicate_decorators::NoDuplicateDecorators),
+    Box::new(no_duplicate_enum_values::NoDuplicateEnumValues),
     Box::new(no_empty::NoEmpty),
     Box::new(no_empty_character_class::NoEmptyCharacterClass),
     Box::new(no_empty_enum::NoEmptyEnum),
+    Box::new(no_empty_function::NoEmptyFunction::default()),
     Box::new(no_empty_interface::NoEmptyInterface),
     Box::new(no_empty_pattern::NoEmptyPattern),
     Box::new(no_eval::NoEval),
+    Box::new(no_eval_like::NoEvalLike::default()),
     Box::new(no_ex_assign::NoExAssign),
     Box::new(no_explicit_any::NoExplicitAny),
     Box::new(no_external_imports::NoExternalImport),
     Box::new(no_extra_boolean_cast::NoExtraBooleanCast),
     Box::new(no_extra_non_null_assertion::NoExtraNonNullAssertion),
+    Box::new(no_extra_parens::NoExtraParens::default()),
+    Box::new(no_extra_semi::NoExtraSemi),
     Box::new(no_fallthrough::NoFallthrough),
+    Box::new(no_focused_tests::NoFocusedTests),
     Box::new(no_func_assign::NoFuncAssign),
-    Box::new(no_global_assign::NoGlobalAssign),
+    Box::new(no_global_assign::NoGlobalAssign::default()),
     Box::new(
       no_implicit_declare_namespace_export::NoImplicitDeclareNamespaceExport,
     ),
@@ -296,52 +562,109 @@ fn get_all_rules_raw() -> Vec<Box<dyn LintRule>> {
     Box::new(no_import_assign::NoImportAssign),
     Box::new(no_inferrable_types::NoInferrableTypes),
     Box::new(no_inner_declarations::NoInnerDeclarations),
+    Box::new(no_insecure_random::NoInsecureRandom::default()),
     Box::new(no_invalid_regexp::NoInvalidRegexp),
     Box::new(no_invalid_triple_slash_reference::NoInvalidTripleSlashReference),
-    Box::new(no_irregular_whitespace::NoIrregularWhitespace),
+    Box::new(no_irregular_whitespace::NoIrregularWhitespace::default()),
     Box::new(no_misused_new::NoMisusedNew),
+    Box::new(no_mixed_enums::NoMixedEnums),
     Box::new(no_namespace::NoNamespace),
+    Box::new(no_nested_promises::NoNestedPromises),
     Box::new(no_new_symbol::NoNewSymbol),
+    Box::new(no_node_builtins_without_prefix::NoNodeBuiltinsWithoutPrefix),
     Box::new(no_node_globals::NoNodeGlobals),
     Box::new(
       no_non_null_asserted_optional_chain::NoNonNullAssertedOptionalChain,
     ),
     Box::new(no_non_null_assertion::NoNonNullAssertion),
+    Box::new(no_nonoctal_decimal_escape::NoNonoctalDecimalEscape),
     Box::new(no_obj_calls::NoObjCalls),
     Box::new(no_octal::NoOctal),
+    Box::new(no_octal_escape::NoOctalEscape),
+    Box::new(no_process_global::NoProcessGlobal::default()),
+    Box::new(no_promise_in_callback::NoPromiseInCallback),
     Box::new(no_prototype_builtins::NoPrototypeBuiltins),
     Box::new(no_redeclare::NoRedeclare),
+    Box::new(no_redundant_type_constituents::NoRedundantTypeConstituents),
     Box::new(no_regex_spaces::NoRegexSpaces),
+    Box::new(no_regex_vulnerabilities::NoRegexVulnerabilities),
+    Box::new(no_relative_parent_imports::NoRelativeParentImports::default()),
+    Box::new(no_return_await::NoReturnAwait),
     Box::new(no_self_assign::NoSelfAssign),
     Box::new(no_self_compare::NoSelfCompare),
+    Box::new(no_self_import::NoSelfImport),
     Box::new(no_setter_return::NoSetterReturn),
     Box::new(no_shadow_restricted_names::NoShadowRestrictedNames),
     Box::new(no_sparse_arrays::NoSparseArrays),
+    Box::new(no_standalone_expect::NoStandaloneExpect),
     Box::new(no_sync_fn_in_async_fn::NoSyncFnInAsyncFn),
     Box::new(no_this_alias::NoThisAlias),
     Box::new(no_this_before_super::NoThisBeforeSuper),
     Box::new(no_throw_literal::NoThrowLiteral),
     Box::new(no_top_level_await::NoTopLevelAwait),
-    Box::new(no_undef::NoUndef),
+    Box::new(no_top_level_side_effect_in_library::NoTopLevelSideEffectInLibrary::default()),
+    Box::new(no_undef::NoUndef::default()),
+    Box::new(no_unexpected_multiline::NoUnexpectedMultiline),
+    Box::new(no_unnecessary_type_arguments::NoUnnecessaryTypeArguments),
+    Box::new(no_unnecessary_type_assertion::NoUnnecessaryTypeAssertion),
+    Box::new(no_unnecessary_type_constraint::NoUnnecessaryTypeConstraint),
     Box::new(no_unreachable::NoUnreachable),
+    Box::new(no_unsafe_assignment::NoUnsafeAssignment),
+    Box::new(no_unsafe_call::NoUnsafeCall),
     Box::new(no_unsafe_finally::NoUnsafeFinally),
+    Box::new(no_unsafe_member_access::NoUnsafeMemberAccess),
     Box::new(no_unsafe_negation::NoUnsafeNegation),
     Box::new(no_unused_labels::NoUnusedLabels),
-    Box::new(no_unused_vars::NoUnusedVars),
+    Box::new(no_unused_vars::NoUnusedVars::default()),
+    Box::new(
+      no_unversioned_remote_imports::NoUnversionedRemoteImports::default(),
+    ),
+    Box::new(no_useless_constructor::NoUselessConstructor),
+    Box::new(no_useless_empty_export::NoUselessEmptyExport),
+    Box::new(no_useless_generators::NoUselessGenerators),
+    Box::new(no_useless_path_segments::NoUselessPathSegments::default()),
+    Box::new(no_useless_regex_escape::NoUselessRegexEscape),
+    Box::new(no_useless_regex_flags::NoUselessRegexFlags),
     Box::new(no_var::NoVar),
     Box::new(no_window::NoWindow),
     Box::new(no_window_prefix::NoWindowPrefix),
     Box::new(no_with::NoWith),
+    Box::new(object_shorthand::ObjectShorthand::default()),
+    Box::new(operator_assignment::OperatorAssignment::default()),
+    Box::new(parameter_properties::ParameterProperties::default()),
+    Box::new(prefer_array_flat::PreferArrayFlat),
+    Box::new(prefer_array_flat_map::PreferArrayFlatMap),
     Box::new(prefer_as_const::PreferAsConst),
     Box::new(prefer_ascii::PreferAscii),
+    Box::new(prefer_at::PreferAt),
+    Box::new(prefer_await_to_then::PreferAwaitToThen),
     Box::new(prefer_const::PreferConst),
+    Box::new(prefer_destructuring::PreferDestructuring::default()),
+    Box::new(prefer_enum_initializers::PreferEnumInitializers),
+    Box::new(prefer_includes::PreferIncludes),
+    Box::new(prefer_jsr_over_deno_land_x::PreferJsrOverDenoLandX),
+    Box::new(prefer_literal_enum_member::PreferLiteralEnumMember::default()),
     Box::new(prefer_namespace_keyword::PreferNamespaceKeyword),
+    Box::new(prefer_numeric_literals::PreferNumericLiterals),
+    Box::new(prefer_object_has_own::PreferObjectHasOwn),
     Box::new(prefer_primordials::PreferPrimordials),
+    Box::new(prefer_readonly::PreferReadonly),
+    Box::new(prefer_return_this_type::PreferReturnThisType),
+    Box::new(prefer_string_method::PreferStringMethod),
+    Box::new(prefer_string_starts_ends_with::PreferStringStartsEndsWith),
     Box::new(require_await::RequireAwait),
+    Box::new(require_await_in_async_test::RequireAwaitInAsyncTest),
+    Box::new(require_jsdoc::RequireJsdoc),
+    Box::new(require_permission_comments::RequirePermissionComments),
+    Box::new(require_unicode_regexp::RequireUnicodeRegexp),
     Box::new(require_yield::RequireYield),
+    Box::new(rules_of_hooks::RulesOfHooks),
     Box::new(single_var_declarator::SingleVarDeclarator),
+    Box::new(switch_exhaustiveness_check::SwitchExhaustivenessCheck),
     Box::new(triple_slash_reference::TripleSlashReference),
+    Box::new(unicode_bom::UnicodeBom::default()),
     Box::new(use_isnan::UseIsNaN),
+    Box::new(valid_jsdoc::ValidJsdoc),
     Box::new(valid_typeof::ValidTypeof),
     Box::new(verbatim_module_syntax::VerbatimModuleSyntax),
   ]
@@ -406,6 +729,16 @@ mod tests {
     );
     assert_eq!(rules.len(), recommended_rules(get_all_rules()).len());
 
+    // Should select the JSX rules by their tag.
+    let rules = filtered_rules(
+      get_all_rules(),
+      Some(vec!["react".to_string()]),
+      None,
+      None,
+    );
+    assert!(rules.iter().any(|rule| rule.code() == "jsx-key"));
+    assert!(rules.iter().all(|rule| rule.tags().contains(&"react")));
+
     // Should skip all rules if given empty tags vec.
     let rules = filtered_rules(get_all_rules(), Some(vec![]), None, None);
     assert!(rules.is_empty());
@@ -448,6 +781,76 @@ mod tests {
     assert_eq!(rules[1].code(), "no-throw-literal");
   }
 
+  #[test]
+  fn rule_aliases() {
+    assert_eq!(
+      rule_code_for_alias("react-hooks/exhaustive-deps"),
+      Some("exhaustive-deps")
+    );
+    assert_eq!(
+      rule_code_for_alias("@typescript-eslint/no-explicit-any"),
+      Some("no-explicit-any")
+    );
+    assert_eq!(rule_code_for_alias("@typescript-eslint/unknown-rule"), None);
+    assert_eq!(rule_code_for_alias("no-explicit-any"), None);
+    assert_eq!(
+      resolve_rule_code("promise/no-nesting"),
+      "no-nested-promises"
+    );
+    assert_eq!(resolve_rule_code("unknown-rule"), "unknown-rule");
+    assert_eq!(
+      resolve_rule_code("no-assertions-outside-test"),
+      "no-standalone-expect"
+    );
+
+    // Aliases must not shadow codes or other aliases.
+    let mut seen = HashSet::new();
+    for rule in get_all_rules() {
+      for alias in rule.aliases() {
+        assert!(!RULE_CODES.contains(alias), "{} is a rule code", alias);
+        assert!(seen.insert(*alias), "{} is a duplicate alias", alias);
+      }
+    }
+
+    let rules = filtered_rules(
+      get_all_rules(),
+      Some(vec![]),
+      Some(vec!["react/jsx-key".to_string()]),
+      Some(vec![
+        "jsx-key".to_string(),
+        "@typescript-eslint/no-explicit-any".to_string(),
+      ]),
+    );
+    assert_eq!(rules.len(), 1);
+    assert_eq!(rules[0].code(), "no-explicit-any");
+  }
+
+  #[test]
+  fn rules_metadata() {
+    let metadata = get_all_rules_metadata();
+    assert_eq!(metadata.len(), get_all_rules().len());
+
+    let ban_ts_comment = metadata
+      .iter()
+      .find(|rule| rule.code == "ban-ts-comment")
+      .unwrap();
+    assert!(ban_ts_comment.fixable);
+    assert!(ban_ts_comment.aliases.is_empty());
+    assert_eq!(
+      ban_ts_comment.docs_url,
+      "https://lint.deno.land/rules/ban-ts-comment"
+    );
+    let json = serde_json::to_value(ban_ts_comment).unwrap();
+    assert_eq!(json["tags"], serde_json::json!(["recommended"]));
+    assert_eq!(json["fixable"], true);
+    assert_eq!(json["optionsSchema"]["type"], "object");
+
+    let eqeqeq = metadata.iter().find(|rule| rule.code == "eqeqeq").unwrap();
+    assert!(!eqeqeq.fixable);
+    let json = serde_json::to_value(eqeqeq).unwrap();
+    assert!(json.get("optionsSchema").is_none());
+  }
+
   #[test]
   fn ensure_lint_rules_are_sharable_across_threads() {
     use std::thread::spawn;
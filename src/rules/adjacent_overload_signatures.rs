@@ -1,9 +1,9 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::handler::Handler;
 use crate::swc_util::StringRepr;
-use crate::Program;
+use deno_ast::view::NodeKind;
 use deno_ast::{view as ast_view, SourceRanged};
 use derive_more::Display;
 use std::collections::HashSet;
@@ -34,12 +34,19 @@ impl LintRule for AdjacentOverloadSignatures {
     CODE
   }
 
-  fn lint_program_with_ast_view<'view>(
-    &self,
-    context: &mut Context,
-    program: Program<'_>,
-  ) {
-    AdjacentOverloadSignaturesHandler.traverse(program, context);
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(AdjacentOverloadSignaturesHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[
+      NodeKind::Script,
+      NodeKind::Module,
+      NodeKind::TsModuleBlock,
+      NodeKind::Class,
+      NodeKind::TsTypeLit,
+      NodeKind::TsInterfaceBody,
+    ])
   }
 
   #[cfg(feature = "docs")]
@@ -0,0 +1,375 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::Handler;
+
+use deno_ast::swc::ast::{BlockStmtOrExpr, Expr, Stmt};
+use deno_ast::view::{ArrowExpr, NodeKind};
+use deno_ast::{SourceRange, SourceRangedForSpanned};
+use derive_more::Display;
+use serde::Deserialize;
+
+#[derive(Debug, Default)]
+pub struct ArrowBodyStyle {
+  options: ArrowBodyStyleOptions,
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ArrowBodyStyleOptions {
+  pub style: ArrowBodyStyleKind,
+  /// With the `as-needed` style, requires a block to return object literals,
+  /// like `() => { return {}; }` instead of `() => ({})`.
+  pub require_return_for_object_literal: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ArrowBodyStyleKind {
+  /// Concise bodies wherever a block only returns a value, like `() => x`.
+  #[default]
+  AsNeeded,
+  /// Block bodies for all arrow functions, like `() => { return x; }`.
+  Always,
+  /// Concise bodies for all arrow functions.
+  Never,
+}
+
+impl ArrowBodyStyle {
+  pub fn new(options: ArrowBodyStyleOptions) -> Self {
+    Self { options }
+  }
+}
+
+const CODE: &str = "arrow-body-style";
+
+#[derive(Display)]
+enum ArrowBodyStyleMessage {
+  #[display(fmt = "Unexpected block statement surrounding arrow body")]
+  UnexpectedBlock,
+  #[display(fmt = "Expected block statement surrounding arrow body")]
+  ExpectedBlock,
+}
+
+#[derive(Display)]
+enum ArrowBodyStyleHint {
+  #[display(fmt = "Return the value directly, e.g. `() => x`")]
+  UseConcise,
+  #[display(
+    fmt = "Use a block with a `return` statement, e.g. `() => {{ return x; }}`"
+  )]
+  UseBlock,
+}
+
+const CONCISE_FIX_DESC: &str = "Convert to a concise body";
+const BLOCK_FIX_DESC: &str = "Convert to a block body";
+
+impl LintRule for ArrowBodyStyle {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "style": {
+          "enum": ["as-needed", "always", "never"],
+          "default": "as-needed"
+        },
+        "requireReturnForObjectLiteral": { "type": "boolean" }
+      }
+    }))
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(ArrowBodyStyleHandler {
+      options: &self.options,
+    }))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::ArrowExpr])
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/arrow_body_style.md")
+  }
+}
+
+struct ArrowBodyStyleHandler<'a> {
+  options: &'a ArrowBodyStyleOptions,
+}
+
+impl Handler for ArrowBodyStyleHandler<'_> {
+  fn arrow_expr(&mut self, arrow_expr: &ArrowExpr, ctx: &mut Context) {
+    let style = self.options.style;
+    match &*arrow_expr.inner.body {
+      BlockStmtOrExpr::BlockStmt(block) => {
+        let returned = match block.stmts.as_slice() {
+          [Stmt::Return(return_stmt)] => return_stmt.arg.as_deref(),
+          _ => None,
+        };
+        let is_object = returned.is_some_and(is_object_lit);
+        let report = match style {
+          ArrowBodyStyleKind::Always => false,
+          ArrowBodyStyleKind::Never => true,
+          ArrowBodyStyleKind::AsNeeded => {
+            returned.is_some()
+              && !(is_object && self.options.require_return_for_object_literal)
+          }
+        };
+        if !report {
+          return;
+        }
+        // Blocks that do more than return a value can't be fixed, and the
+        // comments in the block would be lost.
+        let fixes = match returned {
+          Some(expr) if ctx.comments_within(block.range()).next().is_none() => {
+            let text = expr.text_fast(ctx.text_info());
+            // A body starting with `{` would be parsed as a block.
+            let new_text = if text.starts_with('{') || expr.is_seq() {
+              format!("({})", text)
+            } else {
+              text.to_string()
+            };
+            vec![fix(CONCISE_FIX_DESC, block.range(), new_text)]
+          }
+          _ => vec![],
+        };
+        ctx.add_diagnostic_with_fixes(
+          block.range(),
+          CODE,
+          ArrowBodyStyleMessage::UnexpectedBlock,
+          Some(ArrowBodyStyleHint::UseConcise.to_string()),
+          fixes,
+        );
+      }
+      BlockStmtOrExpr::Expr(expr) => {
+        let report = match style {
+          ArrowBodyStyleKind::Always => true,
+          ArrowBodyStyleKind::Never => false,
+          ArrowBodyStyleKind::AsNeeded => {
+            self.options.require_return_for_object_literal
+              && is_object_lit(expr)
+          }
+        };
+        if !report {
+          return;
+        }
+        // The parentheses around object literals aren't needed anymore.
+        let value = match &**expr {
+          Expr::Paren(paren) => &*paren.expr,
+          expr => expr,
+        };
+        let new_text =
+          format!("{{ return {}; }}", value.text_fast(ctx.text_info()));
+        ctx.add_diagnostic_with_fixes(
+          expr.range(),
+          CODE,
+          ArrowBodyStyleMessage::ExpectedBlock,
+          Some(ArrowBodyStyleHint::UseBlock.to_string()),
+          vec![fix(BLOCK_FIX_DESC, expr.range(), new_text)],
+        );
+      }
+    }
+  }
+}
+
+fn is_object_lit(expr: &Expr) -> bool {
+  match expr {
+    Expr::Object(_) => true,
+    Expr::Paren(paren) => is_object_lit(&paren.expr),
+    _ => false,
+  }
+}
+
+fn fix(
+  description: &'static str,
+  range: SourceRange,
+  new_text: String,
+) -> LintFix {
+  LintFix {
+    description: description.into(),
+    changes: vec![LintFixChange {
+      new_text: new_text.into(),
+      range,
+    }],
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn arrow_body_style_valid() {
+    assert_lint_ok! {
+      ArrowBodyStyle::default(),
+      "const foo = () => 0;",
+      "const foo = () => ({});",
+      "const foo = () => {};",
+      "const foo = () => { bar(); };",
+      "const foo = () => { return; };",
+      "const foo = () => { bar(); return 0; };",
+      "const foo = (a: number): number => a * 2;",
+    };
+
+    assert_lint_ok! {
+      ArrowBodyStyle::new(ArrowBodyStyleOptions {
+        style: ArrowBodyStyleKind::Always,
+        ..Default::default()
+      }),
+      "const foo = () => { return 0; };",
+      "const foo = () => {};",
+    };
+
+    assert_lint_ok! {
+      ArrowBodyStyle::new(ArrowBodyStyleOptions {
+        style: ArrowBodyStyleKind::Never,
+        ..Default::default()
+      }),
+      "const foo = () => 0;",
+      "const foo = () => ({ a: 1 });",
+    };
+
+    assert_lint_ok! {
+      ArrowBodyStyle::new(ArrowBodyStyleOptions {
+        require_return_for_object_literal: true,
+        ..Default::default()
+      }),
+      "const foo = () => { return {}; };",
+      "const foo = () => 0;",
+    };
+  }
+
+  #[test]
+  fn arrow_body_style_invalid() {
+    assert_lint_err! {
+      ArrowBodyStyle::default(),
+      "const foo = () => { return 0; };": [
+        {
+          col: 18,
+          message: ArrowBodyStyleMessage::UnexpectedBlock,
+          hint: ArrowBodyStyleHint::UseConcise,
+          fix: (CONCISE_FIX_DESC, "const foo = () => 0;"),
+        }
+      ],
+      "const foo = (a) => {\n  return a.b;\n};": [
+        {
+          col: 19,
+          message: ArrowBodyStyleMessage::UnexpectedBlock,
+          hint: ArrowBodyStyleHint::UseConcise,
+          fix: (CONCISE_FIX_DESC, "const foo = (a) => a.b;"),
+        }
+      ],
+      "const foo = () => { return { a: 1 }; };": [
+        {
+          col: 18,
+          message: ArrowBodyStyleMessage::UnexpectedBlock,
+          hint: ArrowBodyStyleHint::UseConcise,
+          fix: (CONCISE_FIX_DESC, "const foo = () => ({ a: 1 });"),
+        }
+      ],
+      "const foo = () => { return {}.toString(); };": [
+        {
+          col: 18,
+          message: ArrowBodyStyleMessage::UnexpectedBlock,
+          hint: ArrowBodyStyleHint::UseConcise,
+          fix: (CONCISE_FIX_DESC, "const foo = () => ({}.toString());"),
+        }
+      ],
+      "const foo = () => { return a, b; };": [
+        {
+          col: 18,
+          message: ArrowBodyStyleMessage::UnexpectedBlock,
+          hint: ArrowBodyStyleHint::UseConcise,
+          fix: (CONCISE_FIX_DESC, "const foo = () => (a, b);"),
+        }
+      ],
+      "const foo = () => { /* zero */ return 0; };": [
+        {
+          col: 18,
+          message: ArrowBodyStyleMessage::UnexpectedBlock,
+          hint: ArrowBodyStyleHint::UseConcise,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      ArrowBodyStyle::new(ArrowBodyStyleOptions {
+        style: ArrowBodyStyleKind::Always,
+        ..Default::default()
+      }),
+      "const foo = () => 0;": [
+        {
+          col: 18,
+          message: ArrowBodyStyleMessage::ExpectedBlock,
+          hint: ArrowBodyStyleHint::UseBlock,
+          fix: (BLOCK_FIX_DESC, "const foo = () => { return 0; };"),
+        }
+      ],
+      "const foo = () => ({ a: 1 });": [
+        {
+          col: 18,
+          message: ArrowBodyStyleMessage::ExpectedBlock,
+          hint: ArrowBodyStyleHint::UseBlock,
+          fix: (BLOCK_FIX_DESC, "const foo = () => { return { a: 1 }; };"),
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      ArrowBodyStyle::new(ArrowBodyStyleOptions {
+        style: ArrowBodyStyleKind::Never,
+        ..Default::default()
+      }),
+      "const foo = () => { return 0; };": [
+        {
+          col: 18,
+          message: ArrowBodyStyleMessage::UnexpectedBlock,
+          hint: ArrowBodyStyleHint::UseConcise,
+          fix: (CONCISE_FIX_DESC, "const foo = () => 0;"),
+        }
+      ],
+      "const foo = () => { bar(); };": [
+        {
+          col: 18,
+          message: ArrowBodyStyleMessage::UnexpectedBlock,
+          hint: ArrowBodyStyleHint::UseConcise,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      ArrowBodyStyle::new(ArrowBodyStyleOptions {
+        require_return_for_object_literal: true,
+        ..Default::default()
+      }),
+      "const foo = () => ({});": [
+        {
+          col: 18,
+          message: ArrowBodyStyleMessage::ExpectedBlock,
+          hint: ArrowBodyStyleHint::UseBlock,
+          fix: (BLOCK_FIX_DESC, "const foo = () => { return {}; };"),
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn arrow_body_style_options() {
+    let options: ArrowBodyStyleOptions = serde_json::from_str(
+      r#"{ "style": "as-needed", "requireReturnForObjectLiteral": true }"#,
+    )
+    .unwrap();
+    assert_eq!(options.style, ArrowBodyStyleKind::AsNeeded);
+    assert!(options.require_return_for_object_literal);
+  }
+}
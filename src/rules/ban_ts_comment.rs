@@ -1,72 +1,185 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
+use crate::diagnostic::{LintFix, LintFixChange};
 use crate::Program;
 use deno_ast::swc::common::comments::Comment;
 use deno_ast::swc::common::comments::CommentKind;
 use deno_ast::SourceRange;
 use deno_ast::SourceRangedForSpanned;
+use derive_more::Display;
 use once_cell::sync::Lazy;
 use regex::Regex;
+use serde::Deserialize;
 
 /// This rule differs from typescript-eslint. In typescript-eslint the following
 /// defaults apply:
 /// - ts-expect-error: allowed with comment
 /// - ts-ignore: not allowed
 /// - ts-nocheck: not allowed
+/// - ts-check: allowed
 ///
 /// This rules defaults:
 /// - ts-expect-error: allowed with comment
 /// - ts-ignore: allowed with comment
 /// - ts-nocheck: allowed with comment
-#[derive(Debug)]
-pub struct BanTsComment;
+/// - ts-check: allowed
+#[derive(Debug, Default)]
+pub struct BanTsComment {
+  options: BanTsCommentOptions,
+}
+
+impl BanTsComment {
+  pub fn new(options: BanTsCommentOptions) -> Self {
+    Self { options }
+  }
+}
+
+/// How a single directive is treated.
+///
+/// In the configuration, `true` bans the directive, `false` allows it and
+/// `"allow-with-description"` allows it only when followed by a comment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(from = "DirectiveConfigRepr")]
+pub enum DirectiveConfig {
+  Allow,
+  AllowWithDescription,
+  Ban,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum DirectiveConfigRepr {
+  Ban(bool),
+  Mode(DirectiveMode),
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum DirectiveMode {
+  AllowWithDescription,
+}
+
+impl From<DirectiveConfigRepr> for DirectiveConfig {
+  fn from(repr: DirectiveConfigRepr) -> Self {
+    match repr {
+      DirectiveConfigRepr::Ban(true) => DirectiveConfig::Ban,
+      DirectiveConfigRepr::Ban(false) => DirectiveConfig::Allow,
+      DirectiveConfigRepr::Mode(DirectiveMode::AllowWithDescription) => {
+        DirectiveConfig::AllowWithDescription
+      }
+    }
+  }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct BanTsCommentOptions {
+  #[serde(rename = "ts-expect-error")]
+  pub ts_expect_error: DirectiveConfig,
+  #[serde(rename = "ts-ignore")]
+  pub ts_ignore: DirectiveConfig,
+  #[serde(rename = "ts-nocheck")]
+  pub ts_nocheck: DirectiveConfig,
+  #[serde(rename = "ts-check")]
+  pub ts_check: DirectiveConfig,
+  /// The minimum number of characters a description needs to have for
+  /// directives that are allowed with a description.
+  pub minimum_description_length: usize,
+}
+
+impl Default for BanTsCommentOptions {
+  fn default() -> Self {
+    Self {
+      ts_expect_error: DirectiveConfig::AllowWithDescription,
+      ts_ignore: DirectiveConfig::AllowWithDescription,
+      ts_nocheck: DirectiveConfig::AllowWithDescription,
+      ts_check: DirectiveConfig::Allow,
+      minimum_description_length: 1,
+    }
+  }
+}
+
+impl BanTsCommentOptions {
+  fn config(&self, kind: DirectiveKind) -> DirectiveConfig {
+    match kind {
+      DirectiveKind::ExpectError => self.ts_expect_error,
+      DirectiveKind::Ignore => self.ts_ignore,
+      DirectiveKind::Nocheck => self.ts_nocheck,
+      DirectiveKind::Check => self.ts_check,
+    }
+  }
+}
 
 const CODE: &str = "ban-ts-comment";
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 enum DirectiveKind {
   ExpectError,
   Ignore,
   Nocheck,
+  Check,
 }
 
 impl DirectiveKind {
-  fn as_message(&self) -> &'static str {
-    use DirectiveKind::*;
-    match *self {
-      ExpectError => "`@ts-expect-error` is not allowed without comment",
-      Ignore => "`@ts-ignore` is not allowed without comment",
-      Nocheck => "`@ts-nocheck` is not allowed without comment",
+  fn from_name(name: &str) -> Option<Self> {
+    match name {
+      "expect-error" => Some(DirectiveKind::ExpectError),
+      "ignore" => Some(DirectiveKind::Ignore),
+      "nocheck" => Some(DirectiveKind::Nocheck),
+      "check" => Some(DirectiveKind::Check),
+      _ => None,
     }
   }
 
-  fn as_hint(&self) -> &'static str {
-    use DirectiveKind::*;
-    match *self {
-      ExpectError => "Add an in-line comment explaining the reason for using `@ts-expect-error`, like `// @ts-expect-error: <reason>`",
-      Ignore => "Add an in-line comment explaining the reason for using `@ts-ignore`, like `// @ts-ignore: <reason>`",
-      Nocheck => "Add an in-line comment explaining the reason for using `@ts-nocheck`, like `// @ts-nocheck: <reason>`",
+  fn as_str(&self) -> &'static str {
+    match self {
+      DirectiveKind::ExpectError => "@ts-expect-error",
+      DirectiveKind::Ignore => "@ts-ignore",
+      DirectiveKind::Nocheck => "@ts-nocheck",
+      DirectiveKind::Check => "@ts-check",
     }
   }
 }
 
-impl BanTsComment {
-  fn report(
-    &self,
-    context: &mut Context,
-    range: SourceRange,
-    kind: DirectiveKind,
-  ) {
-    context.add_diagnostic_with_hint(
-      range,
-      CODE,
-      kind.as_message(),
-      kind.as_hint(),
-    );
-  }
+#[derive(Display)]
+enum BanTsCommentMessage {
+  #[display(fmt = "`{}` is not allowed without comment", _0)]
+  WithoutComment(&'static str),
+  #[display(fmt = "`{}` is not allowed", _0)]
+  Banned(&'static str),
+  #[display(
+    fmt = "The comment for `{}` must be at least {} characters long",
+    _0,
+    _1
+  )]
+  CommentTooShort(&'static str, usize),
 }
 
+#[derive(Display)]
+enum BanTsCommentHint {
+  #[display(
+    fmt = "Add an in-line comment explaining the reason for using `{}`, like `// {}: <reason>`",
+    _0,
+    _0
+  )]
+  AddComment(&'static str),
+  #[display(fmt = "Remove the `{}` directive and fix the type errors", _0)]
+  Remove(&'static str),
+  #[display(
+    fmt = "Use `@ts-expect-error` instead, which reports an error when there is nothing to suppress"
+  )]
+  UseExpectError,
+  #[display(
+    fmt = "Explain the reason for using `{}` in more detail, like `// {}: <reason>`",
+    _0,
+    _0
+  )]
+  ExtendComment(&'static str),
+}
+
+const FIX_DESC: &str = "Replace `@ts-ignore` with `@ts-expect-error`";
+
 impl LintRule for BanTsComment {
   fn tags(&self) -> &'static [&'static str] {
     &["recommended"]
@@ -76,20 +189,45 @@ impl LintRule for BanTsComment {
     CODE
   }
 
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    let directive = serde_json::json!({
+      "oneOf": [
+        { "type": "boolean" },
+        { "const": "allow-with-description" }
+      ]
+    });
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "ts-expect-error": directive,
+        "ts-ignore": directive,
+        "ts-nocheck": directive,
+        "ts-check": directive,
+        "minimumDescriptionLength": { "type": "integer", "minimum": 0, "default": 1 }
+      }
+    }))
+  }
+
   fn lint_program_with_ast_view(
     &self,
     context: &mut Context,
     _program: Program,
   ) {
-    let mut violated_comment_ranges = Vec::new();
+    let mut violated_comments = Vec::new();
 
-    violated_comment_ranges.extend(context.all_comments().filter_map(|c| {
-      let kind = check_comment(c)?;
-      Some((c.range(), kind))
+    violated_comments.extend(context.all_comments().filter_map(|c| {
+      let directive = parse_directive(c)?;
+      let violation = self.check_directive(&directive)?;
+      let fix = self.expect_error_fix(c, &directive);
+      Some((c.range(), directive.kind, violation, fix))
     }));
 
-    for (range, kind) in violated_comment_ranges {
-      self.report(context, range, kind);
+    for (range, kind, violation, fix) in violated_comments {
+      self.report(context, range, kind, violation, fix);
     }
   }
 
@@ -99,40 +237,142 @@ impl LintRule for BanTsComment {
   }
 }
 
-/// Returns `None` if the comment includes no directives.
-fn check_comment(comment: &Comment) -> Option<DirectiveKind> {
-  if comment.kind != CommentKind::Line {
-    return None;
-  }
+struct Directive<'a> {
+  kind: DirectiveKind,
+  description: &'a str,
+}
 
-  static EXPECT_ERROR_REGEX: Lazy<Regex> =
-    Lazy::new(|| Regex::new(r"^/*\s*@ts-expect-error\s*$").unwrap());
-  static IGNORE_REGEX: Lazy<Regex> =
-    Lazy::new(|| Regex::new(r"^/*\s*@ts-ignore\s*$").unwrap());
-  static NOCHECK_REGEX: Lazy<Regex> =
-    Lazy::new(|| Regex::new(r"^/*\s*@ts-nocheck\s*$").unwrap());
+enum Violation {
+  Banned,
+  WithoutComment,
+  CommentTooShort,
+}
 
-  if EXPECT_ERROR_REGEX.is_match(&comment.text) {
-    return Some(DirectiveKind::ExpectError);
+impl BanTsComment {
+  fn check_directive(&self, directive: &Directive) -> Option<Violation> {
+    match self.options.config(directive.kind) {
+      DirectiveConfig::Allow => None,
+      DirectiveConfig::Ban => Some(Violation::Banned),
+      DirectiveConfig::AllowWithDescription => {
+        let length = directive.description.chars().count();
+        if length == 0 {
+          Some(Violation::WithoutComment)
+        } else if length < self.options.minimum_description_length {
+          Some(Violation::CommentTooShort)
+        } else {
+          None
+        }
+      }
+    }
   }
-  if IGNORE_REGEX.is_match(&comment.text) {
-    return Some(DirectiveKind::Ignore);
+
+  /// The fix that replaces a `@ts-ignore` directive with `@ts-expect-error`,
+  /// unless the result would be reported too.
+  fn expect_error_fix(
+    &self,
+    comment: &Comment,
+    directive: &Directive,
+  ) -> Option<LintFix> {
+    if directive.kind != DirectiveKind::Ignore {
+      return None;
+    }
+    let expect_error = Directive {
+      kind: DirectiveKind::ExpectError,
+      description: directive.description,
+    };
+    if self.check_directive(&expect_error).is_some() {
+      return None;
+    }
+    let new_text = format!(
+      "//{}",
+      comment.text.replacen("@ts-ignore", "@ts-expect-error", 1)
+    );
+    Some(LintFix {
+      description: FIX_DESC.into(),
+      changes: vec![LintFixChange {
+        new_text: new_text.into(),
+        range: comment.range(),
+      }],
+    })
   }
-  if NOCHECK_REGEX.is_match(&comment.text) {
-    return Some(DirectiveKind::Nocheck);
+
+  fn report(
+    &self,
+    context: &mut Context,
+    range: SourceRange,
+    kind: DirectiveKind,
+    violation: Violation,
+    fix: Option<LintFix>,
+  ) {
+    let name = kind.as_str();
+    let (message, hint) = match violation {
+      Violation::WithoutComment => (
+        BanTsCommentMessage::WithoutComment(name),
+        BanTsCommentHint::AddComment(name),
+      ),
+      Violation::CommentTooShort => (
+        BanTsCommentMessage::CommentTooShort(
+          name,
+          self.options.minimum_description_length,
+        ),
+        BanTsCommentHint::ExtendComment(name),
+      ),
+      Violation::Banned
+        if kind == DirectiveKind::Ignore
+          && self.options.ts_expect_error != DirectiveConfig::Ban =>
+      {
+        (
+          BanTsCommentMessage::Banned(name),
+          BanTsCommentHint::UseExpectError,
+        )
+      }
+      Violation::Banned => (
+        BanTsCommentMessage::Banned(name),
+        BanTsCommentHint::Remove(name),
+      ),
+    };
+    context.add_diagnostic_with_fixes(
+      range,
+      CODE,
+      message,
+      Some(hint.to_string()),
+      fix.into_iter().collect(),
+    );
+  }
+}
+
+/// Returns `None` if the comment is not a directive.
+fn parse_directive(comment: &Comment) -> Option<Directive> {
+  if comment.kind != CommentKind::Line {
+    return None;
   }
 
-  None
+  static DIRECTIVE_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+      r"^/*\s*@ts-(?P<name>expect-error|ignore|nocheck|check)(?P<description>[\s:].*)?$",
+    )
+    .unwrap()
+  });
+
+  let captures = DIRECTIVE_REGEX.captures(&comment.text)?;
+  let kind = DirectiveKind::from_name(captures.name("name")?.as_str())?;
+  let description = captures.name("description").map_or("", |description| {
+    let description = description.as_str().trim_start();
+    description.strip_prefix(':').unwrap_or(description).trim()
+  });
+
+  Some(Directive { kind, description })
 }
 
 #[cfg(test)]
 mod tests {
   use super::*;
+  use crate::test_util::{LintCase, RuleTester};
 
   #[test]
   fn ban_ts_comment_valid() {
     assert_lint_ok! {
-      BanTsComment,
+      BanTsComment::default(),
       r#"// just a comment containing @ts-expect-error somewhere"#,
       r#"// just a random @ts-expect-error     comment with too many spaces"#,
       r#"/* @ts-expect-error */"#,
@@ -164,6 +404,7 @@ mod tests {
 // @ts-check in a block
 */
 "#,
+      r#"// @ts-check"#,
       r#"if (false) {
 // @ts-ignore: Unreachable code error
 console.log('hello');
@@ -176,6 +417,18 @@ console.log('hello');
 // @ts-nocheck: Unreachable code error
 console.log('hello');
 }"#,
+      r#"// @ts-ignore because of a bug in the types"#,
+      r#"// @ts-ignored"#,
+    };
+
+    assert_lint_ok! {
+      BanTsComment::new(BanTsCommentOptions {
+        ts_ignore: DirectiveConfig::Allow,
+        minimum_description_length: 10,
+        ..Default::default()
+      }),
+      r#"// @ts-ignore"#,
+      r#"// @ts-expect-error: a long enough reason"#,
     };
   }
 
@@ -183,9 +436,9 @@ console.log('hello');
   fn ban_ts_comment_invalid() {
     //@ts-expect-error
     assert_lint_err! {
-      BanTsComment,
-      DirectiveKind::ExpectError.as_message(),
-      DirectiveKind::ExpectError.as_hint(),
+      BanTsComment::default(),
+      BanTsCommentMessage::WithoutComment("@ts-expect-error"),
+      BanTsCommentHint::AddComment("@ts-expect-error"),
       r#"// @ts-expect-error"# : [
         {
           col: 0
@@ -205,14 +458,19 @@ console.log('hello');
         {
           col: 0
         }
+      ],
+      r#"// @ts-expect-error: "# : [
+        {
+          col: 0
+        }
       ]
     }
 
     //@ts-ignore
     assert_lint_err! {
-      BanTsComment,
-      DirectiveKind::Ignore.as_message(),
-      DirectiveKind::Ignore.as_hint(),
+      BanTsComment::default(),
+      BanTsCommentMessage::WithoutComment("@ts-ignore"),
+      BanTsCommentHint::AddComment("@ts-ignore"),
       r#"// @ts-ignore"# : [
         {
           col: 0
@@ -237,9 +495,9 @@ console.log('hello');
 
     //@ts-nocheck
     assert_lint_err! {
-      BanTsComment,
-      DirectiveKind::Nocheck.as_message(),
-      DirectiveKind::Nocheck.as_hint(),
+      BanTsComment::default(),
+      BanTsCommentMessage::WithoutComment("@ts-nocheck"),
+      BanTsCommentHint::AddComment("@ts-nocheck"),
       r#"// @ts-nocheck"# : [
         {
           col: 0
@@ -262,4 +520,154 @@ console.log('hello');
       ]
     }
   }
+
+  #[test]
+  fn ban_ts_comment_invalid_with_options() {
+    assert_lint_err! {
+      BanTsComment::new(BanTsCommentOptions {
+        ts_ignore: DirectiveConfig::Ban,
+        ts_nocheck: DirectiveConfig::Ban,
+        ts_check: DirectiveConfig::Ban,
+        minimum_description_length: 5,
+        ..Default::default()
+      }),
+      r#"// @ts-ignore: Unreachable code error"#: [
+        {
+          col: 0,
+          message: BanTsCommentMessage::Banned("@ts-ignore"),
+          hint: BanTsCommentHint::UseExpectError,
+          fix: (FIX_DESC, "// @ts-expect-error: Unreachable code error"),
+        }
+      ],
+      // `@ts-expect-error` without a comment would be reported too.
+      r#"/// @ts-ignore"#: [
+        {
+          col: 0,
+          message: BanTsCommentMessage::Banned("@ts-ignore"),
+          hint: BanTsCommentHint::UseExpectError,
+        }
+      ],
+      r#"// @ts-nocheck: Generated code"#: [
+        {
+          col: 0,
+          message: BanTsCommentMessage::Banned("@ts-nocheck"),
+          hint: BanTsCommentHint::Remove("@ts-nocheck"),
+        }
+      ],
+      r#"// @ts-check"#: [
+        {
+          col: 0,
+          message: BanTsCommentMessage::Banned("@ts-check"),
+          hint: BanTsCommentHint::Remove("@ts-check"),
+        }
+      ],
+      r#"// @ts-expect-error: bug"#: [
+        {
+          col: 0,
+          message: BanTsCommentMessage::CommentTooShort("@ts-expect-error", 5),
+          hint: BanTsCommentHint::ExtendComment("@ts-expect-error"),
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      BanTsComment::new(BanTsCommentOptions {
+        ts_expect_error: DirectiveConfig::Ban,
+        ts_ignore: DirectiveConfig::Ban,
+        ..Default::default()
+      }),
+      r#"// @ts-ignore: Unreachable code error"#: [
+        {
+          col: 0,
+          message: BanTsCommentMessage::Banned("@ts-ignore"),
+          hint: BanTsCommentHint::Remove("@ts-ignore"),
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      BanTsComment::new(BanTsCommentOptions {
+        ts_expect_error: DirectiveConfig::Allow,
+        ..Default::default()
+      }),
+      r#"// @ts-ignore"#: [
+        {
+          col: 0,
+          message: BanTsCommentMessage::WithoutComment("@ts-ignore"),
+          hint: BanTsCommentHint::AddComment("@ts-ignore"),
+          fix: (FIX_DESC, "// @ts-expect-error"),
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      BanTsComment::new(BanTsCommentOptions {
+        minimum_description_length: 5,
+        ..Default::default()
+      }),
+      // The description is too short for `@ts-expect-error` as well.
+      r#"// @ts-ignore: bug"#: [
+        {
+          col: 0,
+          message: BanTsCommentMessage::CommentTooShort("@ts-ignore", 5),
+          hint: BanTsCommentHint::ExtendComment("@ts-ignore"),
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn ban_ts_comment_fixes_are_not_reported_again() {
+    let configs = [
+      BanTsCommentOptions {
+        ts_ignore: DirectiveConfig::Ban,
+        ..Default::default()
+      },
+      BanTsCommentOptions {
+        ts_expect_error: DirectiveConfig::Allow,
+        ..Default::default()
+      },
+    ];
+    let sources = [
+      "// @ts-ignore",
+      "// @ts-ignore: Unreachable code error",
+      "/// @ts-ignore: bug",
+    ];
+    for options in configs {
+      let tester = RuleTester::new(Box::new(BanTsComment::new(options)));
+      for source in sources {
+        let (_, diagnostics) = tester.lint(&LintCase::new(source));
+        let fixed = crate::fixes::apply_all(source, &diagnostics);
+        if fixed.applied == 0 {
+          continue;
+        }
+        let (_, diagnostics) = tester.lint(&LintCase::new(&fixed.text));
+        assert!(
+          diagnostics.is_empty(),
+          "{source:?} was fixed to {:?}, which is reported again",
+          fixed.text
+        );
+      }
+    }
+  }
+
+  #[test]
+  fn ban_ts_comment_deserialize_options() {
+    let options: BanTsCommentOptions =
+      serde_json::from_value(serde_json::json!({
+        "ts-ignore": true,
+        "ts-nocheck": false,
+        "ts-check": "allow-with-description",
+        "minimumDescriptionLength": 3,
+      }))
+      .unwrap();
+    assert_eq!(
+      options.ts_expect_error,
+      DirectiveConfig::AllowWithDescription
+    );
+    assert_eq!(options.ts_ignore, DirectiveConfig::Ban);
+    assert_eq!(options.ts_nocheck, DirectiveConfig::Allow);
+    assert_eq!(options.ts_check, DirectiveConfig::AllowWithDescription);
+    assert_eq!(options.minimum_description_length, 3);
+  }
 }
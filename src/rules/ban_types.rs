@@ -1,9 +1,8 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
-use crate::Program;
-use deno_ast::view::TsEntityName;
+use crate::handler::Handler;
+use deno_ast::view::{NodeKind, TsEntityName};
 use deno_ast::{view as ast_view, SourceRanged};
 use if_chain::if_chain;
 use std::convert::TryFrom;
@@ -82,12 +81,12 @@ impl LintRule for BanTypes {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    BanTypesHandler.traverse(program, context);
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(BanTypesHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::TsTypeRef, NodeKind::TsTypeLit])
   }
 
   #[cfg(feature = "docs")]
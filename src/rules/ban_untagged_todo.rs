@@ -5,38 +5,132 @@ use crate::Program;
 use deno_ast::swc::common::comments::Comment;
 use deno_ast::swc::common::comments::CommentKind;
 use deno_ast::SourceRangedForSpanned;
+use derive_more::Display;
 use once_cell::sync::Lazy;
 use regex::Regex;
+use serde::Deserialize;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 #[derive(Debug)]
-pub struct BanUntaggedTodo;
+pub struct BanUntaggedTodo {
+  keywords: Vec<String>,
+  tag_pattern: Option<Regex>,
+  /// The date that expiry dates are compared to, as `YYYY-MM-DD`. Defaults
+  /// to the current date.
+  today: Option<String>,
+}
+
+impl Default for BanUntaggedTodo {
+  fn default() -> Self {
+    Self {
+      keywords: default_keywords(),
+      tag_pattern: None,
+      today: None,
+    }
+  }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct BanUntaggedTodoOptions {
+  /// The words that start a comment which has to be tagged, matched without
+  /// regard to case. Defaults to `["TODO"]`.
+  pub keywords: Vec<String>,
+  /// A regular expression that the tag has to match, e.g. `"^#\\d+$"` to
+  /// require issue numbers. By default any tag without spaces is allowed.
+  pub tag_pattern: Option<String>,
+}
+
+impl Default for BanUntaggedTodoOptions {
+  fn default() -> Self {
+    Self {
+      keywords: default_keywords(),
+      tag_pattern: None,
+    }
+  }
+}
+
+fn default_keywords() -> Vec<String> {
+  vec!["TODO".to_string()]
+}
+
+impl BanUntaggedTodo {
+  /// Returns an error if the tag pattern isn't a valid regular expression.
+  pub fn new(options: BanUntaggedTodoOptions) -> Result<Self, regex::Error> {
+    Ok(Self {
+      keywords: options.keywords,
+      tag_pattern: options
+        .tag_pattern
+        .as_deref()
+        .map(Regex::new)
+        .transpose()?,
+      today: None,
+    })
+  }
+}
 
 const CODE: &str = "ban-untagged-todo";
-const MESSAGE: &str = "TODO should be tagged with (@username) or (#issue)";
-const HINT: &str = "Add a user tag or issue reference to the TODO comment, e.g. TODO(@djones), TODO(djones), TODO(#123)";
+
+#[derive(Display)]
+enum BanUntaggedTodoMessage {
+  #[display(fmt = "{} should be tagged with (@username) or (#issue)", _0)]
+  Untagged(String),
+  #[display(fmt = "{} should be tagged with an issue matching `{}`", _0, _1)]
+  MissingIssue(String, String),
+  #[display(fmt = "{} expired on {}", _0, _1)]
+  Expired(String, String),
+}
+
+#[derive(Display)]
+enum BanUntaggedTodoHint {
+  #[display(
+    fmt = "Add a user tag or issue reference to the {0} comment, e.g. {0}(@djones), {0}(djones), {0}(#123)",
+    _0
+  )]
+  AddTag(String),
+  #[display(
+    fmt = "Add the issue that tracks the {0} to its tag, e.g. {0}(#123)",
+    _0
+  )]
+  AddIssue(String),
+  #[display(
+    fmt = "Resolve the {0}, or move its date if it has to wait longer",
+    _0
+  )]
+  Resolve(String),
+}
 
 impl LintRule for BanUntaggedTodo {
   fn code(&self) -> &'static str {
     CODE
   }
 
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "keywords": { "type": "array", "items": { "type": "string" } },
+        "tagPattern": { "type": "string" }
+      }
+    }))
+  }
+
   fn lint_program_with_ast_view(
     &self,
     context: &mut Context,
     _program: Program,
   ) {
-    let mut violated_comment_ranges = Vec::new();
+    let today = self.today.clone().unwrap_or_else(current_date);
+    let mut violations = Vec::new();
 
-    violated_comment_ranges.extend(context.all_comments().filter_map(|c| {
-      if check_comment(c) {
-        Some(c.range())
-      } else {
-        None
+    for comment in context.all_comments() {
+      if let Some((message, hint)) = self.check_comment(comment, &today) {
+        violations.push((comment.range(), message, hint));
       }
-    }));
+    }
 
-    for range in violated_comment_ranges {
-      context.add_diagnostic_with_hint(range, CODE, MESSAGE, HINT);
+    for (range, message, hint) in violations {
+      context.add_diagnostic_with_hint(range, CODE, message, hint);
     }
   }
 
@@ -46,37 +140,143 @@ impl LintRule for BanUntaggedTodo {
   }
 }
 
-/// Returns `true` if the comment should be reported.
-fn check_comment(comment: &Comment) -> bool {
-  if comment.kind != CommentKind::Line {
-    return false;
-  }
+impl BanUntaggedTodo {
+  /// Returns the message and hint if the comment should be reported.
+  fn check_comment(
+    &self,
+    comment: &Comment,
+    today: &str,
+  ) -> Option<(BanUntaggedTodoMessage, BanUntaggedTodoHint)> {
+    if comment.kind != CommentKind::Line {
+      return None;
+    }
 
-  let text = comment.text.to_lowercase();
-  let text = text.trim_start();
+    let text = comment.text.trim_start();
+    let (keyword, rest) = self.keywords.iter().find_map(|keyword| {
+      let rest = strip_prefix_ignore_case(text, keyword)?;
+      let is_word_end = !rest
+        .starts_with(|c: char| c.is_alphanumeric() || c == '_' || c == '-');
+      is_word_end.then_some((keyword.clone(), rest))
+    })?;
+    let untagged = || match &self.tag_pattern {
+      Some(pattern) => Some((
+        BanUntaggedTodoMessage::MissingIssue(
+          keyword.clone(),
+          pattern.as_str().to_string(),
+        ),
+        BanUntaggedTodoHint::AddIssue(keyword.clone()),
+      )),
+      None => Some((
+        BanUntaggedTodoMessage::Untagged(keyword.clone()),
+        BanUntaggedTodoHint::AddTag(keyword.clone()),
+      )),
+    };
+
+    let Some(tag) = rest
+      .strip_prefix('(')
+      .and_then(|rest| rest.split_once(')'))
+      .map(|(tag, _)| tag)
+    else {
+      return untagged();
+    };
 
-  if !text.starts_with("todo") {
-    return false;
+    // A tag can list several parts, like `TODO(#123, 2025-01-01)`.
+    let (dates, others): (Vec<&str>, Vec<&str>) = tag
+      .split(',')
+      .map(str::trim)
+      .partition(|part| is_date(part));
+    if let Some(expired) = dates.iter().filter(|date| **date < today).min() {
+      return Some((
+        BanUntaggedTodoMessage::Expired(keyword.clone(), expired.to_string()),
+        BanUntaggedTodoHint::Resolve(keyword),
+      ));
+    }
+    static DEFAULT_TAG_RE: Lazy<Regex> =
+      Lazy::new(|| Regex::new(r"^[#@]?\S+$").unwrap());
+    let pattern = self.tag_pattern.as_ref().unwrap_or(&DEFAULT_TAG_RE);
+    let is_tagged = if others.is_empty() {
+      // A date on its own is enough, since the TODO can't be forgotten.
+      true
+    } else {
+      others.iter().any(|part| pattern.is_match(part))
+    };
+    if is_tagged {
+      None
+    } else {
+      untagged()
+    }
   }
+}
+
+fn strip_prefix_ignore_case<'t>(
+  text: &'t str,
+  prefix: &str,
+) -> Option<&'t str> {
+  let head = text.get(..prefix.len())?;
+  head
+    .eq_ignore_ascii_case(prefix)
+    .then(|| &text[prefix.len()..])
+}
 
-  static TODO_RE: Lazy<Regex> =
-    Lazy::new(|| Regex::new(r"todo\((#|@)?\S+\)").unwrap());
+/// Whether the text is a date in the `YYYY-MM-DD` format.
+fn is_date(text: &str) -> bool {
+  static DATE_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^\d{4}-(0[1-9]|1[0-2])-(0[1-9]|[12]\d|3[01])$").unwrap()
+  });
+  DATE_RE.is_match(text)
+}
 
-  if TODO_RE.is_match(text) {
-    return false;
-  }
+/// The current date in UTC as `YYYY-MM-DD`.
+fn current_date() -> String {
+  let seconds = SystemTime::now()
+    .duration_since(UNIX_EPOCH)
+    .map_or(0, |duration| duration.as_secs());
+  let (year, month, day) = civil_from_days((seconds / 86_400) as i64);
+  format!("{year:04}-{month:02}-{day:02}")
+}
 
-  true
+/// Converts days since 1970-01-01 to a date of the Gregorian calendar, see
+/// http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+  let z = days + 719_468;
+  let era = z.div_euclid(146_097);
+  let day_of_era = z.rem_euclid(146_097);
+  let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524
+    - day_of_era / 146_096)
+    / 365;
+  let day_of_year =
+    day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+  let mp = (5 * day_of_year + 2) / 153;
+  let day = day_of_year - (153 * mp + 2) / 5 + 1;
+  let month = if mp < 10 { mp + 3 } else { mp - 9 };
+  let year = year_of_era + era * 400 + i64::from(month <= 2);
+  (year, month, day)
 }
 
 #[cfg(test)]
 mod tests {
   use super::*;
 
+  fn on(today: &str, rule: BanUntaggedTodo) -> BanUntaggedTodo {
+    BanUntaggedTodo {
+      today: Some(today.to_string()),
+      ..rule
+    }
+  }
+
+  #[test]
+  fn civil_dates() {
+    assert_eq!(civil_from_days(0), (1970, 1, 1));
+    assert_eq!(civil_from_days(59), (1970, 3, 1));
+    assert_eq!(civil_from_days(11_016), (2000, 2, 29));
+    assert_eq!(civil_from_days(20_089), (2025, 1, 1));
+    assert_eq!(civil_from_days(-1), (1969, 12, 31));
+  }
+
   #[test]
   fn ban_ts_ignore_valid() {
     assert_lint_ok! {
-      BanUntaggedTodo,
+      BanUntaggedTodo::default(),
       r#"
 // TODO(@someusername)
 const c = "c";
@@ -101,31 +301,148 @@ const b = "b";
 // TODO(#1234) this should be fixed in next release
 const b = "b";
       "#,
+      r#"
+// Todoist integration
+const d = "d";
+      "#,
+      r#"
+// FIXME the keywords can be configured
+const e = "e";
+      "#,
     };
   }
 
   #[test]
   fn ban_ts_ignore_invalid() {
+    let message =
+      BanUntaggedTodoMessage::Untagged("TODO".to_string()).to_string();
+    let hint = BanUntaggedTodoHint::AddTag("TODO".to_string()).to_string();
     assert_lint_err! {
-      BanUntaggedTodo,
+      BanUntaggedTodo::default(),
       r#"
 // TODO
 function foo() {
   // pass
 }
-      "#: [{ col: 0, line: 2, message: MESSAGE, hint: HINT }],
+      "#: [{ col: 0, line: 2, message: &message, hint: &hint }],
     r#"
 // TODO this should be fixed in next release (username)
 const a = "a";
-      "#: [{ col: 0, line: 2, message: MESSAGE, hint: HINT }],
+      "#: [{ col: 0, line: 2, message: &message, hint: &hint }],
     r#"
 // TODO this should be fixed in next release (#1234)
 const b = "b";
-      "#: [{ col: 0, line: 2, message: MESSAGE, hint: HINT }],
+      "#: [{ col: 0, line: 2, message: &message, hint: &hint }],
     r#"
 // TODO this should be fixed in next release (@someusername)
 const c = "c";
-      "#: [{ col: 0, line: 2, message: MESSAGE, hint: HINT }],
+      "#: [{ col: 0, line: 2, message: &message, hint: &hint }],
+    r#"
+// todo() fix this
+const d = "d";
+      "#: [{ col: 0, line: 2, message: &message, hint: &hint }],
     }
   }
+
+  #[test]
+  fn ban_untagged_todo_keywords() {
+    let rule = || {
+      BanUntaggedTodo::new(BanUntaggedTodoOptions {
+        keywords: vec!["TODO".to_string(), "FIXME".to_string()],
+        ..Default::default()
+      })
+      .unwrap()
+    };
+
+    assert_lint_ok! {
+      rule(),
+      "// FIXME(#12) handle errors",
+      "// fixme(@djones)",
+    };
+
+    assert_lint_err! {
+      rule(),
+      "// FIXME handle errors": [
+        {
+          col: 0,
+          message: BanUntaggedTodoMessage::Untagged("FIXME".to_string()),
+          hint: BanUntaggedTodoHint::AddTag("FIXME".to_string()),
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn ban_untagged_todo_tag_pattern() {
+    let rule = || {
+      BanUntaggedTodo::new(BanUntaggedTodoOptions {
+        tag_pattern: Some(r"^(#\d+|PROJ-\d+|https://\S+/issues/\d+)$".into()),
+        ..Default::default()
+      })
+      .unwrap()
+    };
+
+    assert_lint_ok! {
+      on("2025-01-01", rule()),
+      "// TODO(#123)",
+      "// TODO(PROJ-42) migrate",
+      "// TODO(https://github.com/denoland/deno_lint/issues/1)",
+      "// TODO(@djones, #123)",
+      "// TODO(2025-06-01)",
+    };
+
+    assert_lint_err! {
+      on("2025-01-01", rule()),
+      "// TODO(@djones) migrate": [
+        {
+          col: 0,
+          message: BanUntaggedTodoMessage::MissingIssue(
+            "TODO".to_string(),
+            r"^(#\d+|PROJ-\d+|https://\S+/issues/\d+)$".to_string(),
+          ),
+          hint: BanUntaggedTodoHint::AddIssue("TODO".to_string()),
+        }
+      ],
+    };
+
+    assert!(BanUntaggedTodo::new(BanUntaggedTodoOptions {
+      tag_pattern: Some("(".to_string()),
+      ..Default::default()
+    })
+    .is_err());
+  }
+
+  #[test]
+  fn ban_untagged_todo_expiry_dates() {
+    assert_lint_ok! {
+      on("2025-01-01", BanUntaggedTodo::default()),
+      "// TODO(2025-01-01) remove the polyfill",
+      "// TODO(2025-02-01) remove the polyfill",
+      "// TODO(@djones, 2026-01-01)",
+    };
+
+    assert_lint_err! {
+      on("2025-01-02", BanUntaggedTodo::default()),
+      "// TODO(2025-01-01) remove the polyfill": [
+        {
+          col: 0,
+          message: BanUntaggedTodoMessage::Expired(
+            "TODO".to_string(),
+            "2025-01-01".to_string(),
+          ),
+          hint: BanUntaggedTodoHint::Resolve("TODO".to_string()),
+        }
+      ],
+      "// TODO(#123, 2024-06-30)": [
+        {
+          col: 0,
+          message: BanUntaggedTodoMessage::Expired(
+            "TODO".to_string(),
+            "2024-06-30".to_string(),
+          ),
+          hint: BanUntaggedTodoHint::Resolve("TODO".to_string()),
+        }
+      ],
+    };
+  }
 }
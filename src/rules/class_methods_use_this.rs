@@ -0,0 +1,236 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::class_analysis::{ClassAnalysis, MemberKind};
+use crate::handler::Handler;
+use crate::syntax_features::SyntaxFeatures;
+
+use deno_ast::view::{Class, NodeKind};
+use derive_more::Display;
+use serde::Deserialize;
+
+#[derive(Debug, Default)]
+pub struct ClassMethodsUseThis {
+  options: ClassMethodsUseThisOptions,
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ClassMethodsUseThisOptions {
+  /// Methods that may leave `this` unused, including the `#` of private
+  /// methods, e.g. `"render"` or `"#format"`.
+  pub except_methods: Vec<String>,
+}
+
+impl ClassMethodsUseThis {
+  pub fn new(options: ClassMethodsUseThisOptions) -> Self {
+    Self { options }
+  }
+}
+
+const CODE: &str = "class-methods-use-this";
+
+#[derive(Display)]
+enum ClassMethodsUseThisMessage {
+  #[display(fmt = "Expected `this` to be used by class method `{}`", _0)]
+  Named(String),
+  #[display(fmt = "Expected `this` to be used by class method")]
+  Unnamed,
+}
+
+#[derive(Display)]
+enum ClassMethodsUseThisHint {
+  #[display(
+    fmt = "Make the method `static`, or move it out of the class into a function"
+  )]
+  MakeStatic,
+}
+
+impl LintRule for ClassMethodsUseThis {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn required_syntax(&self) -> SyntaxFeatures {
+    SyntaxFeatures::CLASS
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "exceptMethods": { "type": "array", "items": { "type": "string" } }
+      }
+    }))
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(ClassMethodsUseThisHandler {
+      options: &self.options,
+    }))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::Class])
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/class_methods_use_this.md")
+  }
+}
+
+struct ClassMethodsUseThisHandler<'a> {
+  options: &'a ClassMethodsUseThisOptions,
+}
+
+impl Handler for ClassMethodsUseThisHandler<'_> {
+  fn class(&mut self, class: &Class, ctx: &mut Context) {
+    let analysis = ClassAnalysis::new(class.inner);
+    for member in &analysis.members {
+      if !matches!(
+        member.kind,
+        MemberKind::Method | MemberKind::Getter | MemberKind::Setter
+      ) || member.is_static
+        || !member.has_body
+        || member.uses_this
+      {
+        continue;
+      }
+      // Overrides have to be instance methods to override the method of the
+      // super class, and decorators may rely on the method not being static.
+      if member.is_override || !member.decorators.is_empty() {
+        continue;
+      }
+      let message = match &member.name {
+        Some(name) if self.options.except_methods.contains(name) => continue,
+        Some(name) => ClassMethodsUseThisMessage::Named(name.clone()),
+        None => ClassMethodsUseThisMessage::Unnamed,
+      };
+      let range = member.key.map_or(member.range, |key| key.range());
+      ctx.add_diagnostic_with_hint(
+        range,
+        CODE,
+        message,
+        ClassMethodsUseThisHint::MakeStatic,
+      );
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn class_methods_use_this_valid() {
+    assert_lint_ok! {
+      ClassMethodsUseThis::default(),
+      "class Foo { bar() { return this.baz; } }",
+      "class Foo { bar() { this.baz = 1; } }",
+      "class Foo { bar() { return super.baz(); } }",
+      "class Foo { bar() { return () => this.baz; } }",
+      "class Foo { static bar() { return 1; } }",
+      "class Foo { constructor() {} }",
+      "class Foo { get bar() { return this.baz; } set bar(value) { this.baz = value; } }",
+      "class Foo { #bar() { return this.baz; } }",
+      "class Foo { bar = 1; }",
+      "class Foo { static { console.log(1); } }",
+      "class Foo extends Bar { override bar() { return 1; } }",
+      "class Foo { @decorator bar() { return 1; } }",
+      "abstract class Foo { abstract bar(): number; }",
+      "class Foo { bar(): number; bar(value?: number) { return this.baz; } }",
+      "const foo = { bar() { return 1; } };",
+      "function foo() { return 1; }",
+    };
+
+    assert_lint_ok! {
+      ClassMethodsUseThis::new(ClassMethodsUseThisOptions {
+        except_methods: vec!["bar".to_string(), "#baz".to_string()],
+      }),
+      "class Foo { bar() { return 1; } }",
+      "class Foo { #baz() { return 1; } }",
+    };
+  }
+
+  #[test]
+  fn class_methods_use_this_invalid() {
+    assert_lint_err! {
+      ClassMethodsUseThis::default(),
+      "class Foo { bar() { return 1; } }": [
+        {
+          col: 12,
+          message: ClassMethodsUseThisMessage::Named("bar".to_string()),
+          hint: ClassMethodsUseThisHint::MakeStatic,
+        }
+      ],
+      "class Foo { #bar() { return 1; } }": [
+        {
+          col: 12,
+          message: ClassMethodsUseThisMessage::Named("#bar".to_string()),
+          hint: ClassMethodsUseThisHint::MakeStatic,
+        }
+      ],
+      "class Foo { get bar() { return 1; } }": [
+        {
+          col: 16,
+          message: ClassMethodsUseThisMessage::Named("bar".to_string()),
+          hint: ClassMethodsUseThisHint::MakeStatic,
+        }
+      ],
+      "class Foo { [bar]() { return 1; } }": [
+        {
+          col: 12,
+          message: ClassMethodsUseThisMessage::Unnamed,
+          hint: ClassMethodsUseThisHint::MakeStatic,
+        }
+      ],
+      "class Foo { bar() { return function () { return this; }; } }": [
+        {
+          col: 12,
+          message: ClassMethodsUseThisMessage::Named("bar".to_string()),
+          hint: ClassMethodsUseThisHint::MakeStatic,
+        }
+      ],
+      "class Foo { bar() { return class { baz = this; }; } }": [
+        {
+          col: 12,
+          message: ClassMethodsUseThisMessage::Named("bar".to_string()),
+          hint: ClassMethodsUseThisHint::MakeStatic,
+        }
+      ],
+      "class Foo { bar() { class Baz { qux() { return 1; } } } }": [
+        {
+          col: 12,
+          message: ClassMethodsUseThisMessage::Named("bar".to_string()),
+          hint: ClassMethodsUseThisHint::MakeStatic,
+        },
+        {
+          col: 32,
+          message: ClassMethodsUseThisMessage::Named("qux".to_string()),
+          hint: ClassMethodsUseThisHint::MakeStatic,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      ClassMethodsUseThis::new(ClassMethodsUseThisOptions {
+        except_methods: vec!["bar".to_string()],
+      }),
+      "class Foo { bar() {} baz() {} }": [
+        {
+          col: 21,
+          message: ClassMethodsUseThisMessage::Named("baz".to_string()),
+          hint: ClassMethodsUseThisHint::MakeStatic,
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn class_methods_use_this_options() {
+    let options: ClassMethodsUseThisOptions =
+      serde_json::from_str(r#"{ "exceptMethods": ["render"] }"#).unwrap();
+    assert_eq!(options.except_methods, vec!["render".to_string()]);
+  }
+}
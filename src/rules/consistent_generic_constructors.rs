@@ -0,0 +1,418 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::Handler;
+
+use deno_ast::view::{
+  ArrowExpr, AssignPat, ClassProp, Expr, NodeKind, NodeTrait, Param, Pat,
+  TsEntityName, TsType, TsTypeAnn, VarDeclarator,
+};
+use deno_ast::{SourcePos, SourceRange, SourceRanged};
+use derive_more::Display;
+use serde::Deserialize;
+
+#[derive(Debug, Default)]
+pub struct ConsistentGenericConstructors {
+  options: ConsistentGenericConstructorsOptions,
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ConsistentGenericConstructorsOptions {
+  pub style: GenericConstructorStyle,
+}
+
+/// Where the type arguments of a generic constructor call should be written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum GenericConstructorStyle {
+  /// `const map = new Map<string, number>();`
+  #[default]
+  Constructor,
+  /// `const map: Map<string, number> = new Map();`
+  TypeAnnotation,
+}
+
+impl ConsistentGenericConstructors {
+  pub fn new(options: ConsistentGenericConstructorsOptions) -> Self {
+    Self { options }
+  }
+}
+
+const CODE: &str = "consistent-generic-constructors";
+
+#[derive(Display)]
+enum ConsistentGenericConstructorsMessage {
+  #[display(
+    fmt = "The generic type arguments should be specified as part of the constructor call"
+  )]
+  PreferConstructor,
+  #[display(
+    fmt = "The generic type arguments should be specified as part of the type annotation"
+  )]
+  PreferTypeAnnotation,
+}
+
+#[derive(Display)]
+enum ConsistentGenericConstructorsHint {
+  #[display(fmt = "Move the type arguments to the constructor call")]
+  MoveToConstructor,
+  #[display(fmt = "Move the type arguments to a type annotation")]
+  MoveToTypeAnnotation,
+}
+
+#[derive(Display)]
+enum ConsistentGenericConstructorsFix {
+  #[display(fmt = "Move the type arguments to the constructor call")]
+  MoveToConstructor,
+  #[display(fmt = "Move the type arguments to a type annotation")]
+  MoveToTypeAnnotation,
+}
+
+impl LintRule for ConsistentGenericConstructors {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "style": {
+          "enum": ["constructor", "type-annotation"],
+          "default": "constructor"
+        }
+      }
+    }))
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(ConsistentGenericConstructorsHandler {
+      style: self.options.style,
+    }))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[
+      NodeKind::VarDeclarator,
+      NodeKind::AssignPat,
+      NodeKind::ClassProp,
+    ])
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/consistent_generic_constructors.md")
+  }
+}
+
+struct ConsistentGenericConstructorsHandler {
+  style: GenericConstructorStyle,
+}
+
+impl ConsistentGenericConstructorsHandler {
+  /// Checks a binding or property of the form `x: T = new C()`. `name_end` is
+  /// the end of the binding or property name, which is where a missing type
+  /// annotation is added.
+  fn check(
+    &self,
+    range: SourceRange,
+    name_end: SourcePos,
+    type_ann: Option<&TsTypeAnn>,
+    value: &Expr,
+    ctx: &mut Context,
+  ) {
+    let Expr::New(new_expr) = value else {
+      return;
+    };
+    let Expr::Ident(callee) = &new_expr.callee else {
+      return;
+    };
+
+    let type_ref = match type_ann.map(|type_ann| &type_ann.type_ann) {
+      Some(TsType::TsTypeRef(type_ref)) => match &type_ref.type_name {
+        TsEntityName::Ident(ident) if ident.sym() == callee.sym() => {
+          Some(type_ref)
+        }
+        _ => return,
+      },
+      Some(_) => return,
+      None => None,
+    };
+
+    match self.style {
+      GenericConstructorStyle::Constructor => {
+        let (Some(type_ann), Some(type_ref)) = (type_ann, type_ref) else {
+          return;
+        };
+        let Some(type_args) = type_ref.type_params else {
+          return;
+        };
+        if new_expr.type_args.is_some() {
+          return;
+        }
+        let mut new_text = type_args.text().to_string();
+        if new_expr.args.is_none() {
+          new_text.push_str("()");
+        }
+        ctx.add_diagnostic_with_fixes(
+          range,
+          CODE,
+          ConsistentGenericConstructorsMessage::PreferConstructor,
+          Some(
+            ConsistentGenericConstructorsHint::MoveToConstructor.to_string(),
+          ),
+          vec![LintFix {
+            description: ConsistentGenericConstructorsFix::MoveToConstructor
+              .to_string()
+              .into(),
+            changes: vec![
+              LintFixChange {
+                new_text: "".into(),
+                range: type_ann.range(),
+              },
+              LintFixChange {
+                new_text: new_text.into(),
+                range: SourceRange::new(callee.end(), callee.end()),
+              },
+            ],
+          }],
+        );
+      }
+      GenericConstructorStyle::TypeAnnotation => {
+        if type_ann.is_some() {
+          return;
+        }
+        let Some(type_args) = new_expr.type_args else {
+          return;
+        };
+        let new_text = format!(": {}{}", callee.sym(), type_args.text());
+        ctx.add_diagnostic_with_fixes(
+          range,
+          CODE,
+          ConsistentGenericConstructorsMessage::PreferTypeAnnotation,
+          Some(
+            ConsistentGenericConstructorsHint::MoveToTypeAnnotation.to_string(),
+          ),
+          vec![LintFix {
+            description: ConsistentGenericConstructorsFix::MoveToTypeAnnotation
+              .to_string()
+              .into(),
+            changes: vec![
+              LintFixChange {
+                new_text: new_text.into(),
+                range: SourceRange::new(name_end, name_end),
+              },
+              LintFixChange {
+                new_text: "".into(),
+                range: type_args.range(),
+              },
+            ],
+          }],
+        );
+      }
+    }
+  }
+
+  fn check_binding(
+    &self,
+    range: SourceRange,
+    pat: &Pat,
+    value: &Expr,
+    ctx: &mut Context,
+  ) {
+    if let Pat::Ident(binding) = pat {
+      self.check(range, binding.id.end(), binding.type_ann, value, ctx);
+    }
+  }
+}
+
+impl Handler for ConsistentGenericConstructorsHandler {
+  fn var_declarator(&mut self, declarator: &VarDeclarator, ctx: &mut Context) {
+    if let Some(init) = &declarator.init {
+      self.check_binding(declarator.range(), &declarator.name, init, ctx);
+    }
+  }
+
+  fn assign_pat(&mut self, assign_pat: &AssignPat, ctx: &mut Context) {
+    // Only parameter defaults can have a type annotation, not defaults in
+    // destructuring patterns.
+    let parent = assign_pat.parent();
+    if !parent.is::<Param>() && !parent.is::<ArrowExpr>() {
+      return;
+    }
+    self.check_binding(
+      assign_pat.range(),
+      &assign_pat.left,
+      &assign_pat.right,
+      ctx,
+    );
+  }
+
+  fn class_prop(&mut self, class_prop: &ClassProp, ctx: &mut Context) {
+    // A missing annotation would have to go after the `?` or `!` of the
+    // property, so those are left alone.
+    if (class_prop.is_optional() || class_prop.definite())
+      && class_prop.type_ann.is_none()
+    {
+      return;
+    }
+    if let Some(value) = &class_prop.value {
+      self.check(
+        class_prop.range(),
+        class_prop.key.end(),
+        class_prop.type_ann,
+        value,
+        ctx,
+      );
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn type_annotation_style() -> ConsistentGenericConstructors {
+    ConsistentGenericConstructors::new(ConsistentGenericConstructorsOptions {
+      style: GenericConstructorStyle::TypeAnnotation,
+    })
+  }
+
+  #[test]
+  fn consistent_generic_constructors_valid() {
+    assert_lint_ok! {
+      ConsistentGenericConstructors::default(),
+      "const a = new Map<string, number>();",
+      "const a: Map<string, number> = new Map<string, number>();",
+      "const a: Map = new Map();",
+      "const a: Foo<string> = new Bar();",
+      "const a: Foo.Bar<string> = new Foo.Bar();",
+      "const a = new Foo();",
+      "class C { a = new Map<string, number>(); }",
+      "function f(a = new Map<string, number>()) {}",
+    };
+
+    assert_lint_ok! {
+      type_annotation_style(),
+      "const a: Map<string, number> = new Map();",
+      "const a: Map<string, number> = new Map<string, number>();",
+      "const a = new Map();",
+      "class C { a: Map<string, number> = new Map(); }",
+      "function f(a: Map<string, number> = new Map()) {}",
+      "const a: Foo = new Bar<string>();",
+      "const [a = new Set<string>()] = [];",
+    };
+  }
+
+  #[test]
+  fn consistent_generic_constructors_invalid() {
+    assert_lint_err! {
+      ConsistentGenericConstructors::default(),
+      "const a: Map<string, number> = new Map();": [
+        {
+          col: 6,
+          message: ConsistentGenericConstructorsMessage::PreferConstructor,
+          hint: ConsistentGenericConstructorsHint::MoveToConstructor,
+          fix: (
+            "Move the type arguments to the constructor call",
+            "const a = new Map<string, number>();"
+          ),
+        }
+      ],
+      "const a: Set<string> = new Set;": [
+        {
+          col: 6,
+          message: ConsistentGenericConstructorsMessage::PreferConstructor,
+          hint: ConsistentGenericConstructorsHint::MoveToConstructor,
+          fix: (
+            "Move the type arguments to the constructor call",
+            "const a = new Set<string>();"
+          ),
+        }
+      ],
+      "class C { a?: Set<string> = new Set(); }": [
+        {
+          col: 10,
+          message: ConsistentGenericConstructorsMessage::PreferConstructor,
+          hint: ConsistentGenericConstructorsHint::MoveToConstructor,
+          fix: (
+            "Move the type arguments to the constructor call",
+            "class C { a? = new Set<string>(); }"
+          ),
+        }
+      ],
+      "function f(a: Set<string> = new Set()) {}": [
+        {
+          col: 11,
+          message: ConsistentGenericConstructorsMessage::PreferConstructor,
+          hint: ConsistentGenericConstructorsHint::MoveToConstructor,
+          fix: (
+            "Move the type arguments to the constructor call",
+            "function f(a = new Set<string>()) {}"
+          ),
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      type_annotation_style(),
+      "const a = new Map<string, number>();": [
+        {
+          col: 6,
+          message: ConsistentGenericConstructorsMessage::PreferTypeAnnotation,
+          hint: ConsistentGenericConstructorsHint::MoveToTypeAnnotation,
+          fix: (
+            "Move the type arguments to a type annotation",
+            "const a: Map<string, number> = new Map();"
+          ),
+        }
+      ],
+      "class C { a = new Set<string>(); }": [
+        {
+          col: 10,
+          message: ConsistentGenericConstructorsMessage::PreferTypeAnnotation,
+          hint: ConsistentGenericConstructorsHint::MoveToTypeAnnotation,
+          fix: (
+            "Move the type arguments to a type annotation",
+            "class C { a: Set<string> = new Set(); }"
+          ),
+        }
+      ],
+      "function f(a = new Set<string>()) {}": [
+        {
+          col: 11,
+          message: ConsistentGenericConstructorsMessage::PreferTypeAnnotation,
+          hint: ConsistentGenericConstructorsHint::MoveToTypeAnnotation,
+          fix: (
+            "Move the type arguments to a type annotation",
+            "function f(a: Set<string> = new Set()) {}"
+          ),
+        }
+      ],
+      "const f = (a = new Set<string>()) => {};": [
+        {
+          col: 11,
+          message: ConsistentGenericConstructorsMessage::PreferTypeAnnotation,
+          hint: ConsistentGenericConstructorsHint::MoveToTypeAnnotation,
+          fix: (
+            "Move the type arguments to a type annotation",
+            "const f = (a: Set<string> = new Set()) => {};"
+          ),
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn consistent_generic_constructors_options() {
+    let options: ConsistentGenericConstructorsOptions =
+      serde_json::from_str(r#"{ "style": "type-annotation" }"#).unwrap();
+    assert_eq!(options.style, GenericConstructorStyle::TypeAnnotation);
+  }
+}
@@ -1,8 +1,9 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
-use crate::Program;
+use crate::handler::Handler;
+use crate::syntax_features::SyntaxFeatures;
+use deno_ast::view::NodeKind;
 use deno_ast::{view as ast_view, SourceRange, SourceRanged};
 use if_chain::if_chain;
 
@@ -22,12 +23,16 @@ impl LintRule for ConstructorSuper {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    ConstructorSuperHandler.traverse(program, context);
+  fn required_syntax(&self) -> SyntaxFeatures {
+    SyntaxFeatures::CLASS
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(ConstructorSuperHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::Class])
   }
 
   #[cfg(feature = "docs")]
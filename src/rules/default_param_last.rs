@@ -1,8 +1,8 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
-use crate::Program;
+use crate::handler::Handler;
+use deno_ast::view::NodeKind;
 use deno_ast::{view as ast_view, SourceRanged};
 use derive_more::Display;
 
@@ -30,12 +30,16 @@ impl LintRule for DefaultParamLast {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    DefaultParamLastHandler.traverse(program, context);
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(DefaultParamLastHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[
+      NodeKind::Function,
+      NodeKind::Constructor,
+      NodeKind::ArrowExpr,
+    ])
   }
 
   #[cfg(feature = "docs")]
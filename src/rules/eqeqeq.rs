@@ -1,9 +1,9 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
-use crate::Program;
+use crate::handler::Handler;
 use deno_ast::swc::ast::BinaryOp;
+use deno_ast::view::NodeKind;
 use deno_ast::{view as ast_view, SourceRanged};
 use derive_more::Display;
 
@@ -33,12 +33,12 @@ impl LintRule for Eqeqeq {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    EqeqeqHandler.traverse(program, context);
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(EqeqeqHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::BinExpr])
   }
 
   #[cfg(feature = "docs")]
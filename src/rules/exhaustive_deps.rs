@@ -0,0 +1,761 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::Handler;
+
+use deno_ast::swc::ast::{
+  ArrowExpr as SwcArrowExpr, CallExpr as SwcCallExpr, Callee as SwcCallee,
+  CatchClause, ClassDecl, Expr as SwcExpr, FnDecl, Id, JSXElementName,
+  JSXObject, MemberProp as SwcMemberProp, Param, Pat as SwcPat, Prop,
+  SimpleAssignTarget, VarDeclarator,
+};
+use deno_ast::swc::utils::find_pat_ids;
+use deno_ast::swc::visit::{noop_visit_type, Visit, VisitWith};
+use deno_ast::view::{
+  ArrowExpr, CallExpr, Callee, Expr, Function, MemberProp, NodeKind, NodeTrait,
+};
+use deno_ast::{SourceRanged, SourceRangedForSpanned};
+use derive_more::Display;
+use std::collections::{BTreeSet, HashSet};
+use std::fmt;
+
+#[derive(Debug)]
+pub struct ExhaustiveDeps;
+
+const CODE: &str = "exhaustive-deps";
+
+#[derive(Display)]
+enum ExhaustiveDepsMessage {
+  #[display(fmt = "React Hook `{}` has {}", _0, _1)]
+  Missing(String, DependencyList),
+  #[display(fmt = "React Hook `{}` has {}", _0, _1)]
+  Unnecessary(String, DependencyList),
+  #[display(fmt = "React Hook `{}` has {} and {}", _0, _1, _2)]
+  MissingAndUnnecessary(String, DependencyList, DependencyList),
+  #[display(
+    fmt = "React Hook `{}` was passed a dependency list that is not an array literal",
+    _0
+  )]
+  NotArrayLiteral(String),
+  #[display(
+    fmt = "React Hook `{}` has a complex expression in the dependency array",
+    _0
+  )]
+  ComplexDependency(String),
+  #[display(
+    fmt = "React Hook `{}` does nothing when called without a dependency array",
+    _0
+  )]
+  NoDependencyArray(String),
+}
+
+#[derive(Display)]
+enum ExhaustiveDepsHint {
+  #[display(
+    fmt = "List every value from the component that the callback uses, and only those, in the dependency array"
+  )]
+  UpdateDependencies,
+  #[display(
+    fmt = "Pass the dependencies as an array literal so they can be checked"
+  )]
+  UseArrayLiteral,
+  #[display(
+    fmt = "Extract the expression to a variable and list that variable instead"
+  )]
+  ExtractToVariable,
+  #[display(
+    fmt = "Pass an array of the values the callback uses as the last argument"
+  )]
+  PassDependencyArray,
+}
+
+const FIX_DESC: &str = "Update the dependency array";
+
+/// A list of dependencies in a message, e.g. "a missing dependency: `a`" or
+/// "missing dependencies: `a` and `b`".
+struct DependencyList {
+  article: &'static str,
+  adjective: &'static str,
+  dependencies: Vec<String>,
+}
+
+impl DependencyList {
+  fn missing(dependencies: Vec<String>) -> Self {
+    Self {
+      article: "a",
+      adjective: "missing",
+      dependencies,
+    }
+  }
+
+  fn unnecessary(dependencies: Vec<String>) -> Self {
+    Self {
+      article: "an",
+      adjective: "unnecessary",
+      dependencies,
+    }
+  }
+}
+
+impl fmt::Display for DependencyList {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let len = self.dependencies.len();
+    if len == 1 {
+      write!(f, "{} {} dependency: ", self.article, self.adjective)?;
+    } else {
+      write!(f, "{} dependencies: ", self.adjective)?;
+    }
+    for (i, dependency) in self.dependencies.iter().enumerate() {
+      let separator = match i {
+        0 => "",
+        i if i == len - 1 => " and ",
+        _ => ", ",
+      };
+      write!(f, "{separator}`{dependency}`")?;
+    }
+    Ok(())
+  }
+}
+
+/// The hooks that take a callback and a dependency array, with the index of
+/// the dependency array in their arguments. The callback comes right before.
+static HOOKS: phf::Map<&'static str, usize> = phf::phf_map! {
+  "useEffect" => 1,
+  "useLayoutEffect" => 1,
+  "useInsertionEffect" => 1,
+  "useMemo" => 1,
+  "useCallback" => 1,
+  "useImperativeHandle" => 2,
+};
+
+/// Hooks whose result is only recomputed when a dependency changes, so that
+/// dependencies the callback doesn't use are unnecessary. Effects may list
+/// extra dependencies to re-run when they change.
+fn is_memo_hook(name: &str) -> bool {
+  name == "useMemo" || name == "useCallback"
+}
+
+/// Hooks that return an array whose element at the given index never changes
+/// between renders, like the setter returned by `useState`.
+static STABLE_ELEMENTS: phf::Map<&'static str, usize> = phf::phf_map! {
+  "useState" => 1,
+  "useReducer" => 1,
+  "useTransition" => 1,
+};
+
+impl LintRule for ExhaustiveDeps {
+  fn tags(&self) -> &'static [&'static str] {
+    &["react", "fresh"]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn aliases(&self) -> &'static [&'static str] {
+    &["react-hooks/exhaustive-deps"]
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(ExhaustiveDepsHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::CallExpr])
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/exhaustive_deps.md")
+  }
+}
+
+/// The name of the called hook, for calls like `useEffect()` and
+/// `React.useEffect()`.
+fn callee_name<'a>(callee: &'a Callee) -> Option<&'a str> {
+  match callee {
+    Callee::Expr(Expr::Ident(ident)) => Some(ident.sym()),
+    Callee::Expr(Expr::Member(member)) => match (&member.obj, &member.prop) {
+      (Expr::Ident(_), MemberProp::Ident(prop)) => Some(prop.sym()),
+      _ => None,
+    },
+    _ => None,
+  }
+}
+
+fn swc_callee_name(callee: &SwcCallee) -> Option<&str> {
+  let SwcCallee::Expr(expr) = callee else {
+    return None;
+  };
+  match &**expr {
+    SwcExpr::Ident(ident) => Some(&ident.sym),
+    SwcExpr::Member(member) => match (&*member.obj, &member.prop) {
+      (SwcExpr::Ident(_), SwcMemberProp::Ident(prop)) => Some(&prop.sym),
+      _ => None,
+    },
+    _ => None,
+  }
+}
+
+/// The binding and path of expressions like `props.user.name`, or `None` for
+/// any other expression.
+fn dependency_path(expr: &SwcExpr) -> Option<(Id, String)> {
+  match expr {
+    SwcExpr::Ident(ident) => Some((ident.to_id(), ident.sym.to_string())),
+    SwcExpr::Member(member) => {
+      let SwcMemberProp::Ident(prop) = &member.prop else {
+        return None;
+      };
+      let (id, path) = dependency_path(&member.obj)?;
+      Some((id, format!("{path}.{}", prop.sym)))
+    }
+    SwcExpr::Paren(paren) => dependency_path(&paren.expr),
+    _ => None,
+  }
+}
+
+/// The object whose property is called or assigned, e.g. `props` for
+/// `props.onChange()`, since the call depends on the whole object.
+fn object_path(expr: &SwcExpr) -> Option<(Id, String)> {
+  let (id, path) = dependency_path(expr)?;
+  let (object, _) = path.rsplit_once('.')?;
+  Some((id, object.to_string()))
+}
+
+/// Whether a dependency on `dependency` is a dependency on `path`, i.e. both
+/// are the same or `path` is a property of `dependency`.
+fn covers(dependency: &str, path: &str) -> bool {
+  path
+    .strip_prefix(dependency)
+    .is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+}
+
+/// Collects the bindings declared in a function, including nested scopes.
+#[derive(Default)]
+struct DeclarationCollector {
+  declared: HashSet<Id>,
+  /// Bindings whose value never changes between renders.
+  stable: HashSet<Id>,
+}
+
+impl Visit for DeclarationCollector {
+  noop_visit_type!();
+
+  fn visit_var_declarator(&mut self, declarator: &VarDeclarator) {
+    self
+      .declared
+      .extend(find_pat_ids::<_, Id>(&declarator.name));
+    if let Some(SwcExpr::Call(call)) = declarator.init.as_deref() {
+      self.collect_stable(&declarator.name, call);
+    }
+    declarator.visit_children_with(self);
+  }
+
+  fn visit_param(&mut self, param: &Param) {
+    self.declared.extend(find_pat_ids::<_, Id>(&param.pat));
+    param.visit_children_with(self);
+  }
+
+  fn visit_arrow_expr(&mut self, arrow: &SwcArrowExpr) {
+    for param in &arrow.params {
+      self.declared.extend(find_pat_ids::<_, Id>(param));
+    }
+    arrow.visit_children_with(self);
+  }
+
+  fn visit_fn_decl(&mut self, fn_decl: &FnDecl) {
+    self.declared.insert(fn_decl.ident.to_id());
+    fn_decl.visit_children_with(self);
+  }
+
+  fn visit_class_decl(&mut self, class_decl: &ClassDecl) {
+    self.declared.insert(class_decl.ident.to_id());
+    class_decl.visit_children_with(self);
+  }
+
+  fn visit_catch_clause(&mut self, catch_clause: &CatchClause) {
+    if let Some(param) = &catch_clause.param {
+      self.declared.extend(find_pat_ids::<_, Id>(param));
+    }
+    catch_clause.visit_children_with(self);
+  }
+}
+
+impl DeclarationCollector {
+  fn collect_stable(&mut self, name: &SwcPat, call: &SwcCallExpr) {
+    let Some(hook) = swc_callee_name(&call.callee) else {
+      return;
+    };
+    if hook == "useRef" {
+      if let SwcPat::Ident(ident) = name {
+        self.stable.insert(ident.id.to_id());
+      }
+    } else if let Some(&index) = STABLE_ELEMENTS.get(hook) {
+      if let SwcPat::Array(array) = name {
+        if let Some(Some(SwcPat::Ident(ident))) = array.elems.get(index) {
+          self.stable.insert(ident.id.to_id());
+        }
+      }
+    }
+  }
+}
+
+/// Collects the paths of the values referenced by a callback.
+#[derive(Default)]
+struct ReferenceCollector {
+  references: Vec<(Id, String)>,
+}
+
+impl Visit for ReferenceCollector {
+  noop_visit_type!();
+
+  fn visit_expr(&mut self, expr: &SwcExpr) {
+    match dependency_path(expr) {
+      Some(reference) => self.references.push(reference),
+      None => expr.visit_children_with(self),
+    }
+  }
+
+  fn visit_call_expr(&mut self, call: &SwcCallExpr) {
+    if let SwcCallee::Expr(callee) = &call.callee {
+      if let Some(reference) = object_path(callee) {
+        self.references.push(reference);
+        call.args.visit_with(self);
+        return;
+      }
+    }
+    call.visit_children_with(self);
+  }
+
+  fn visit_simple_assign_target(&mut self, target: &SimpleAssignTarget) {
+    match target {
+      SimpleAssignTarget::Ident(ident) => {
+        self
+          .references
+          .push((ident.id.to_id(), ident.id.sym.to_string()));
+      }
+      SimpleAssignTarget::Member(member) => {
+        match dependency_path(&member.obj) {
+          Some(reference) => self.references.push(reference),
+          None => member.obj.visit_with(self),
+        }
+        member.prop.visit_with(self);
+      }
+      _ => target.visit_children_with(self),
+    }
+  }
+
+  fn visit_prop(&mut self, prop: &Prop) {
+    match prop {
+      Prop::Shorthand(ident) => {
+        self.references.push((ident.to_id(), ident.sym.to_string()));
+      }
+      _ => prop.visit_children_with(self),
+    }
+  }
+
+  fn visit_jsx_element_name(&mut self, name: &JSXElementName) {
+    match name {
+      JSXElementName::Ident(ident) => {
+        self.references.push((ident.to_id(), ident.sym.to_string()));
+      }
+      JSXElementName::JSXMemberExpr(member) => {
+        let mut obj = &member.obj;
+        while let JSXObject::JSXMemberExpr(inner) = obj {
+          obj = &inner.obj;
+        }
+        if let JSXObject::Ident(ident) = obj {
+          self.references.push((ident.to_id(), ident.sym.to_string()));
+        }
+      }
+      JSXElementName::JSXNamespacedName(_) => {}
+    }
+  }
+}
+
+/// The bindings declared in the function that calls the hook.
+fn component_declarations(call_expr: &CallExpr) -> DeclarationCollector {
+  let mut collector = DeclarationCollector::default();
+  for ancestor in call_expr.ancestors() {
+    if let Some(function) = ancestor.to::<Function>() {
+      function.inner.visit_with(&mut collector);
+      break;
+    }
+    if let Some(arrow) = ancestor.to::<ArrowExpr>() {
+      arrow.inner.visit_with(&mut collector);
+      break;
+    }
+  }
+  collector
+}
+
+/// Removes the paths that are covered by another path of the set, e.g.
+/// `props.user` when `props` is there too.
+fn without_covered(paths: BTreeSet<String>) -> Vec<String> {
+  paths
+    .iter()
+    .filter(|path| {
+      !paths
+        .iter()
+        .any(|other| other != *path && covers(other, path))
+    })
+    .cloned()
+    .collect()
+}
+
+struct ExhaustiveDepsHandler;
+
+impl Handler for ExhaustiveDepsHandler {
+  fn call_expr(&mut self, call_expr: &CallExpr, ctx: &mut Context) {
+    let Some((&name, &deps_index)) =
+      callee_name(&call_expr.callee).and_then(|name| HOOKS.get_entry(name))
+    else {
+      return;
+    };
+    let Some(callback) = call_expr.args.get(deps_index - 1) else {
+      return;
+    };
+    if callback.spread().is_some()
+      || !matches!(&*callback.inner.expr, SwcExpr::Arrow(_) | SwcExpr::Fn(_))
+    {
+      return;
+    }
+
+    let Some(deps) = call_expr.args.get(deps_index) else {
+      if is_memo_hook(name) {
+        ctx.add_diagnostic_with_hint(
+          call_expr.range(),
+          CODE,
+          ExhaustiveDepsMessage::NoDependencyArray(name.to_string()),
+          ExhaustiveDepsHint::PassDependencyArray,
+        );
+      }
+      return;
+    };
+    let array = match &*deps.inner.expr {
+      SwcExpr::Array(array) if deps.spread().is_none() => array,
+      _ => {
+        ctx.add_diagnostic_with_hint(
+          deps.range(),
+          CODE,
+          ExhaustiveDepsMessage::NotArrayLiteral(name.to_string()),
+          ExhaustiveDepsHint::UseArrayLiteral,
+        );
+        return;
+      }
+    };
+
+    let component = component_declarations(call_expr);
+    let mut local = DeclarationCollector::default();
+    callback.inner.expr.visit_with(&mut local);
+    let is_component_value =
+      |id: &Id| component.declared.contains(id) && !local.declared.contains(id);
+
+    let mut references = ReferenceCollector::default();
+    callback.inner.expr.visit_with(&mut references);
+    let mut used = BTreeSet::new();
+    let mut required = BTreeSet::new();
+    for (id, path) in references.references {
+      if !is_component_value(&id) {
+        continue;
+      }
+      if !component.stable.contains(&id) {
+        required.insert(path.clone());
+      }
+      used.insert(path);
+    }
+
+    let mut has_complex = false;
+    let mut kept = Vec::new();
+    let mut unnecessary = Vec::new();
+    for elem in array.elems.iter().flatten() {
+      let path = match dependency_path(&elem.expr) {
+        Some((id, path)) if elem.spread.is_none() => {
+          is_component_value(&id).then_some(path)
+        }
+        _ => {
+          has_complex = true;
+          ctx.add_diagnostic_with_hint(
+            elem.expr.range(),
+            CODE,
+            ExhaustiveDepsMessage::ComplexDependency(name.to_string()),
+            ExhaustiveDepsHint::ExtractToVariable,
+          );
+          continue;
+        }
+      };
+      let is_used = path.as_ref().is_some_and(|path| {
+        !is_memo_hook(name)
+          || used
+            .iter()
+            .any(|used| covers(path, used) || covers(used, path))
+      });
+      match path {
+        Some(path) if is_used => {
+          if !kept.contains(&path) {
+            kept.push(path);
+          }
+        }
+        _ => unnecessary.push(elem.expr.text_fast(ctx.text_info()).to_string()),
+      }
+    }
+    if has_complex {
+      return;
+    }
+
+    let missing = without_covered(
+      required
+        .into_iter()
+        .filter(|path| !kept.iter().any(|dependency| covers(dependency, path)))
+        .collect(),
+    );
+    let message = match (missing.is_empty(), unnecessary.is_empty()) {
+      (true, true) => return,
+      (false, true) => ExhaustiveDepsMessage::Missing(
+        name.to_string(),
+        DependencyList::missing(missing.clone()),
+      ),
+      (true, false) => ExhaustiveDepsMessage::Unnecessary(
+        name.to_string(),
+        DependencyList::unnecessary(unnecessary),
+      ),
+      (false, false) => ExhaustiveDepsMessage::MissingAndUnnecessary(
+        name.to_string(),
+        DependencyList::missing(missing.clone()),
+        DependencyList::unnecessary(unnecessary),
+      ),
+    };
+
+    kept.extend(missing);
+    ctx.add_diagnostic_with_fixes(
+      deps.range(),
+      CODE,
+      message,
+      Some(ExhaustiveDepsHint::UpdateDependencies.to_string()),
+      vec![LintFix {
+        description: FIX_DESC.into(),
+        changes: vec![LintFixChange {
+          new_text: format!("[{}]", kept.join(", ")).into(),
+          range: deps.range(),
+        }],
+      }],
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn exhaustive_deps_valid() {
+    assert_lint_ok! {
+      ExhaustiveDeps,
+      "function App({ id }) { useEffect(() => { fetchUser(id); }, [id]); }",
+      "function App(props) { useEffect(() => { log(props.user.name); }, [props.user]); }",
+      "function App(props) { useEffect(() => { log(props.user.name); }, [props.user.name]); }",
+      "function App() { const [count, setCount] = useState(0); useEffect(() => { setCount(1); }, []); }",
+      "function App() { const [state, dispatch] = useReducer(reducer, 0); useEffect(() => { dispatch({}); }, []); }",
+      "function App() { const ref = useRef(null); useEffect(() => { ref.current.focus(); }, []); }",
+      "function App() { useEffect(() => { document.title = TITLE; }, []); }",
+      "function App() { useEffect(() => { const id = setInterval(tick, 1000); return () => clearInterval(id); }, []); }",
+      "function App({ a, b }) { const sum = useMemo(() => a + b, [a, b]); }",
+      "function App({ onChange }) { const handle = useCallback((event) => onChange(event.target.value), [onChange]); }",
+      "function App(props) { useEffect(() => { props.onLoad(); }, [props]); }",
+      "function App({ id }) { useEffect(() => {}, [id]); }",
+      "function App({ id }) { useEffect(() => { fetchUser(id); }); }",
+      "function App({ a }) { const value = React.useMemo(() => ({ a }), [a]); }",
+      "function App({ id }) { useEffect(effect, []); }",
+      "function App({ store }) { useEffect(() => { store.count = 1; }, [store]); }",
+      "function App({ value }) { const ref = useRef(null); useEffect(() => { ref.current = value; }, [value]); }",
+      "const value = useMemo(() => compute(), []);",
+    };
+
+    assert_lint_ok! {
+      ExhaustiveDeps,
+      filename: "file:///foo.tsx",
+      "function App({ Item }) { const el = useMemo(() => <Item />, [Item]); }",
+      "function App({ ui }) { const el = useMemo(() => <ui.Button />, [ui]); }",
+    };
+  }
+
+  #[test]
+  fn exhaustive_deps_invalid() {
+    assert_lint_err! {
+      ExhaustiveDeps,
+      "function App({ id }) { useEffect(() => { fetchUser(id); }, []); }": [
+        {
+          col: 59,
+          message: ExhaustiveDepsMessage::Missing(
+            "useEffect".to_string(),
+            DependencyList::missing(vec!["id".to_string()])
+          ),
+          hint: ExhaustiveDepsHint::UpdateDependencies,
+          fix: (
+            FIX_DESC,
+            "function App({ id }) { useEffect(() => { fetchUser(id); }, [id]); }"
+          ),
+        }
+      ],
+      "function App({ a, b, c }) { const sum = useMemo(() => a + b + c, [a]); }": [
+        {
+          col: 65,
+          message: ExhaustiveDepsMessage::Missing(
+            "useMemo".to_string(),
+            DependencyList::missing(vec!["b".to_string(), "c".to_string()])
+          ),
+          hint: ExhaustiveDepsHint::UpdateDependencies,
+          fix: (
+            FIX_DESC,
+            "function App({ a, b, c }) { const sum = useMemo(() => a + b + c, [a, b, c]); }"
+          ),
+        }
+      ],
+      "function App(props) { useEffect(() => { log(props.user.name, props.user.id); }, []); }": [
+        {
+          col: 80,
+          message: ExhaustiveDepsMessage::Missing(
+            "useEffect".to_string(),
+            DependencyList::missing(vec![
+              "props.user.id".to_string(),
+              "props.user.name".to_string()
+            ])
+          ),
+          hint: ExhaustiveDepsHint::UpdateDependencies,
+          fix: (
+            FIX_DESC,
+            "function App(props) { useEffect(() => { log(props.user.name, props.user.id); }, [props.user.id, props.user.name]); }"
+          ),
+        }
+      ],
+      "function App(props) { useEffect(() => { props.onLoad(); }, []); }": [
+        {
+          col: 59,
+          message: ExhaustiveDepsMessage::Missing(
+            "useEffect".to_string(),
+            DependencyList::missing(vec!["props".to_string()])
+          ),
+          hint: ExhaustiveDepsHint::UpdateDependencies,
+          fix: (
+            FIX_DESC,
+            "function App(props) { useEffect(() => { props.onLoad(); }, [props]); }"
+          ),
+        }
+      ],
+      "function App() { const [count, setCount] = useState(0); const next = useCallback(() => setCount(count + 1), []); }": [
+        {
+          col: 108,
+          message: ExhaustiveDepsMessage::Missing(
+            "useCallback".to_string(),
+            DependencyList::missing(vec!["count".to_string()])
+          ),
+          hint: ExhaustiveDepsHint::UpdateDependencies,
+          fix: (
+            FIX_DESC,
+            "function App() { const [count, setCount] = useState(0); const next = useCallback(() => setCount(count + 1), [count]); }"
+          ),
+        }
+      ],
+      "function App({ a, b }) { const value = useMemo(() => a * 2, [a, b]); }": [
+        {
+          col: 60,
+          message: ExhaustiveDepsMessage::Unnecessary(
+            "useMemo".to_string(),
+            DependencyList::unnecessary(vec!["b".to_string()])
+          ),
+          hint: ExhaustiveDepsHint::UpdateDependencies,
+          fix: (
+            FIX_DESC,
+            "function App({ a, b }) { const value = useMemo(() => a * 2, [a]); }"
+          ),
+        }
+      ],
+      "function App({ id }) { useEffect(() => { load(id, window.location); }, [window.location]); }": [
+        {
+          col: 71,
+          message: ExhaustiveDepsMessage::MissingAndUnnecessary(
+            "useEffect".to_string(),
+            DependencyList::missing(vec!["id".to_string()]),
+            DependencyList::unnecessary(vec!["window.location".to_string()])
+          ),
+          hint: ExhaustiveDepsHint::UpdateDependencies,
+          fix: (
+            FIX_DESC,
+            "function App({ id }) { useEffect(() => { load(id, window.location); }, [id]); }"
+          ),
+        }
+      ],
+      "function App({ ref, value }) { useImperativeHandle(ref, () => ({ value }), []); }": [
+        {
+          col: 75,
+          message: ExhaustiveDepsMessage::Missing(
+            "useImperativeHandle".to_string(),
+            DependencyList::missing(vec!["value".to_string()])
+          ),
+          hint: ExhaustiveDepsHint::UpdateDependencies,
+          fix: (
+            FIX_DESC,
+            "function App({ ref, value }) { useImperativeHandle(ref, () => ({ value }), [value]); }"
+          ),
+        }
+      ],
+      "function App({ id }) { useEffect(() => { load(id); }, [id, load]); }": [
+        {
+          col: 54,
+          message: ExhaustiveDepsMessage::Unnecessary(
+            "useEffect".to_string(),
+            DependencyList::unnecessary(vec!["load".to_string()])
+          ),
+          hint: ExhaustiveDepsHint::UpdateDependencies,
+          fix: (
+            FIX_DESC,
+            "function App({ id }) { useEffect(() => { load(id); }, [id]); }"
+          ),
+        }
+      ],
+      "function App({ deps }) { useEffect(() => {}, deps); }": [
+        {
+          col: 45,
+          message: ExhaustiveDepsMessage::NotArrayLiteral("useEffect".to_string()),
+          hint: ExhaustiveDepsHint::UseArrayLiteral,
+        }
+      ],
+      "function App({ items }) { useEffect(() => { log(items); }, [items.length > 0]); }": [
+        {
+          col: 60,
+          message: ExhaustiveDepsMessage::ComplexDependency("useEffect".to_string()),
+          hint: ExhaustiveDepsHint::ExtractToVariable,
+        }
+      ],
+      "function App({ a }) { const value = useMemo(() => a * 2); }": [
+        {
+          col: 36,
+          message: ExhaustiveDepsMessage::NoDependencyArray("useMemo".to_string()),
+          hint: ExhaustiveDepsHint::PassDependencyArray,
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn dependency_list_display() {
+    assert_eq!(
+      DependencyList::missing(vec!["a".to_string()]).to_string(),
+      "a missing dependency: `a`"
+    );
+    assert_eq!(
+      DependencyList::unnecessary(vec![
+        "a".to_string(),
+        "b".to_string(),
+        "c".to_string()
+      ])
+      .to_string(),
+      "unnecessary dependencies: `a`, `b` and `c`"
+    );
+  }
+}
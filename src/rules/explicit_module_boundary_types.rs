@@ -5,6 +5,7 @@ use crate::handler::{Handler, Traverse};
 
 use deno_ast::{view as ast_view, MediaType, SourceRange, SourceRanged};
 use derive_more::Display;
+use std::collections::HashSet;
 
 #[derive(Debug)]
 pub struct ExplicitModuleBoundaryTypes;
@@ -43,7 +44,9 @@ impl LintRule for ExplicitModuleBoundaryTypes {
     if matches!(context.media_type(), MediaType::JavaScript | MediaType::Jsx) {
       return;
     }
-    ExplicitModuleBoundaryTypesHandler.traverse(program, context);
+    let mut handler = ExplicitModuleBoundaryTypesHandler::default();
+    handler.traverse(program, context);
+    handler.check_exported_locals(program, context);
   }
 
   #[cfg(feature = "docs")]
@@ -52,7 +55,55 @@ impl LintRule for ExplicitModuleBoundaryTypes {
   }
 }
 
-struct ExplicitModuleBoundaryTypesHandler;
+#[derive(Default)]
+struct ExplicitModuleBoundaryTypesHandler {
+  /// Names of local declarations that are exported separately from their
+  /// declaration, e.g. `export { foo }` or `export default foo`.
+  exported_locals: HashSet<String>,
+}
+
+impl ExplicitModuleBoundaryTypesHandler {
+  fn check_exported_locals(
+    &self,
+    program: ast_view::Program,
+    ctx: &mut Context,
+  ) {
+    if self.exported_locals.is_empty() {
+      return;
+    }
+    let ast_view::Program::Module(module) = program else {
+      return;
+    };
+
+    for item in module.body {
+      let ast_view::ModuleItem::Stmt(ast_view::Stmt::Decl(decl)) = item else {
+        continue;
+      };
+      match decl {
+        ast_view::Decl::Class(decl)
+          if self.exported_locals.contains(decl.ident.sym().as_ref()) =>
+        {
+          check_class(decl.class, ctx)
+        }
+        ast_view::Decl::Fn(decl)
+          if self.exported_locals.contains(decl.ident.sym().as_ref()) =>
+        {
+          check_fn(decl.function, ctx, false)
+        }
+        ast_view::Decl::Var(var) => {
+          for declarator in var.decls {
+            if let ast_view::Pat::Ident(ident) = &declarator.name {
+              if self.exported_locals.contains(ident.id.sym().as_ref()) {
+                check_var_declarator(declarator, ctx);
+              }
+            }
+          }
+        }
+        _ => {}
+      }
+    }
+  }
+}
 
 impl Handler for ExplicitModuleBoundaryTypesHandler {
   fn export_decl(
@@ -87,15 +138,71 @@ impl Handler for ExplicitModuleBoundaryTypesHandler {
     export_default_expr: &ast_view::ExportDefaultExpr,
     ctx: &mut Context,
   ) {
+    if let ast_view::Expr::Ident(ident) = &export_default_expr.expr {
+      self.exported_locals.insert(ident.sym().to_string());
+    }
     check_expr(&export_default_expr.expr, ctx);
   }
+
+  fn named_export(
+    &mut self,
+    named_export: &ast_view::NamedExport,
+    _ctx: &mut Context,
+  ) {
+    // Re-exports from other modules are checked in their own module.
+    if named_export.src.is_some() || named_export.type_only() {
+      return;
+    }
+
+    for specifier in named_export.specifiers {
+      if let ast_view::ExportSpecifier::Named(named) = specifier {
+        if let ast_view::ModuleExportName::Ident(ident) = &named.orig {
+          if !named.is_type_only() {
+            self.exported_locals.insert(ident.sym().to_string());
+          }
+        }
+      }
+    }
+  }
 }
 
 fn check_class(class: &ast_view::Class, ctx: &mut Context) {
+  use ast_view::ClassMember;
+
+  // Private members are not part of the module boundary.
+  let is_private =
+    |accessibility| accessibility == Some(ast_view::Accessibility::Private);
+
   for member in class.body {
-    if let ast_view::ClassMember::Method(method) = member {
-      let is_setter = method.inner.kind == ast_view::MethodKind::Setter;
-      check_fn(method.function, ctx, is_setter);
+    match member {
+      ClassMember::Method(method) if !is_private(method.accessibility()) => {
+        let is_setter = method.inner.kind == ast_view::MethodKind::Setter;
+        check_fn(method.function, ctx, is_setter);
+      }
+      ClassMember::Constructor(constructor)
+        if !is_private(constructor.accessibility()) =>
+      {
+        for param in constructor.params {
+          match param {
+            ast_view::ParamOrTsParamProp::Param(param) => {
+              check_pat(&param.pat, ctx)
+            }
+            ast_view::ParamOrTsParamProp::TsParamProp(prop) => {
+              if let ast_view::TsParamPropParam::Ident(ident) = &prop.param {
+                check_ann(ident.type_ann, ident.id.range(), ctx);
+              }
+            }
+          }
+        }
+      }
+      ClassMember::ClassProp(prop)
+        if prop.type_ann.is_none() && !is_private(prop.accessibility()) =>
+      {
+        if let Some(ast_view::Expr::Arrow(arrow)) = &prop.value {
+          check_arrow(arrow, ctx);
+        }
+      }
+      _ => {}
     }
   }
 }
@@ -183,10 +290,24 @@ fn check_expr(expr: &ast_view::Expr, ctx: &mut Context) {
 
 fn check_var_decl(var: &ast_view::VarDecl, ctx: &mut Context) {
   for declarator in var.decls {
-    if let Some(expr) = &declarator.init {
-      check_expr(expr, ctx)
+    check_var_declarator(declarator, ctx);
+  }
+}
+
+fn check_var_declarator(
+  declarator: &ast_view::VarDeclarator,
+  ctx: &mut Context,
+) {
+  // The variable's own annotation, e.g. `export const f: Handler = (req) => ..`,
+  // already types the function.
+  if let ast_view::Pat::Ident(ident) = &declarator.name {
+    if ident.type_ann.is_some() {
+      return;
     }
   }
+  if let Some(expr) = &declarator.init {
+    check_expr(expr, ctx)
+  }
 }
 
 #[cfg(test)]
@@ -206,6 +327,16 @@ mod tests {
       "export function test(arg = 1) : number { return arg;}",
       "export function test(arg :number = 1) : number { return arg;}",
       "export class Test { set method() { return true; } }",
+      "export class Test { private method() { return; } }",
+      "export class Test { #method() { return; } }",
+      "export class Test { constructor(arg: string, private readonly other: number) {} }",
+      "export class Test { private constructor(arg) {} }",
+      "export class Test { handler = (arg: string): void => {} }",
+      "export class Test { private handler = (arg) => {} }",
+      "export const handler: Handler = (req) => new Response();",
+      "function notExported(arg) { return arg; } export { other } from './mod.ts';",
+      "function typed(arg: string): string { return arg; } export { typed };",
+      "export type { Foo } from './mod.ts';",
     };
 
     assert_lint_ok! {
@@ -297,6 +428,41 @@ mod tests {
         message: ExplicitModuleBoundaryTypesMessage::MissingRetType,
         hint: ExplicitModuleBoundaryTypesHint::AddRetType,
       }],
+      r#"export class Test { constructor(arg, public other) {} }"#: [
+      {
+        col: 32,
+        message: ExplicitModuleBoundaryTypesMessage::MissingArgType,
+        hint: ExplicitModuleBoundaryTypesHint::AddArgTypes,
+      },
+      {
+        col: 44,
+        message: ExplicitModuleBoundaryTypesMessage::MissingArgType,
+        hint: ExplicitModuleBoundaryTypesHint::AddArgTypes,
+      }],
+      r#"export class Test { handler = (arg: string) => {} }"#: [
+      {
+        col: 30,
+        message: ExplicitModuleBoundaryTypesMessage::MissingRetType,
+        hint: ExplicitModuleBoundaryTypesHint::AddRetType,
+      }],
+      r#"function foo(arg: string) { return arg; } export { foo as bar };"#: [
+      {
+        col: 0,
+        message: ExplicitModuleBoundaryTypesMessage::MissingRetType,
+        hint: ExplicitModuleBoundaryTypesHint::AddRetType,
+      }],
+      r#"const foo = (): void => {}, bar = (arg): void => {}; export { bar };"#: [
+      {
+        col: 35,
+        message: ExplicitModuleBoundaryTypesMessage::MissingArgType,
+        hint: ExplicitModuleBoundaryTypesHint::AddArgTypes,
+      }],
+      r#"class Foo { method() {} } export default Foo;"#: [
+      {
+        col: 12,
+        message: ExplicitModuleBoundaryTypesMessage::MissingRetType,
+        hint: ExplicitModuleBoundaryTypesHint::AddRetType,
+      }],
     }
   }
 }
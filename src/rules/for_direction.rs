@@ -1,13 +1,13 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
-use crate::Program;
+use crate::handler::Handler;
 use deno_ast::swc::ast::AssignOp;
 use deno_ast::swc::ast::BinaryOp;
 use deno_ast::swc::ast::UpdateOp;
 use deno_ast::view::{
-  AssignExpr, AssignTarget, Expr, SimpleAssignTarget, UnaryOp, UpdateExpr,
+  AssignExpr, AssignTarget, Expr, NodeKind, SimpleAssignTarget, UnaryOp,
+  UpdateExpr,
 };
 use deno_ast::{view as ast_view, SourceRanged};
 
@@ -23,12 +23,12 @@ impl LintRule for ForDirection {
     "for-direction"
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    ForDirectionHandler.traverse(program, context);
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(ForDirectionHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::ForStmt])
   }
 
   #[cfg(feature = "docs")]
@@ -1,9 +1,9 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::handler::Handler;
 
-use deno_ast::view::{Decl, Pat, Program};
+use deno_ast::view::{Decl, NodeKind, Pat};
 use deno_ast::SourceRanged;
 
 #[derive(Debug)]
@@ -23,12 +23,12 @@ impl LintRule for FreshHandlerExport {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    Visitor.traverse(program, context);
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(Visitor))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::ExportDecl])
   }
 
   #[cfg(feature = "docs")]
@@ -0,0 +1,422 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::handler::Handler;
+
+use deno_ast::swc::ast::{
+  Decl, DefaultDecl, Expr, Function, Lit, ModuleDecl, ModuleItem, Pat,
+  TsEntityName, TsKeywordTypeKind, TsType, TsTypeElement,
+  TsUnionOrIntersectionType,
+};
+use deno_ast::view::{Module, NodeKind};
+use deno_ast::{SourceRange, SourceRangedForSpanned};
+use derive_more::Display;
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug)]
+pub struct FreshIslandSerializableProps;
+
+const CODE: &str = "fresh-island-serializable-props";
+
+#[derive(Display)]
+enum FreshIslandSerializablePropsMessage {
+  #[display(fmt = "The `{}` prop of an island is not serializable", _0)]
+  NotSerializable(String),
+}
+
+#[derive(Display)]
+enum FreshIslandSerializablePropsHint {
+  #[display(
+    fmt = "Islands are rendered on the server and their props are sent to the browser, so pass data instead and create functions inside the island"
+  )]
+  PassData,
+}
+
+/// Types that the Fresh serializer can't send to the browser.
+static NOT_SERIALIZABLE: phf::Set<&'static str> = phf::phf_set! {
+  "Function",
+  "Promise",
+  "WeakMap",
+  "WeakSet",
+  "WeakRef",
+};
+
+impl LintRule for FreshIslandSerializableProps {
+  fn tags(&self) -> &'static [&'static str] {
+    &["fresh"]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(Visitor))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::Module])
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/fresh_island_serializable_props.md")
+  }
+}
+
+/// A type declared in the module, which props can refer to by name.
+#[derive(Clone, Copy)]
+enum LocalType<'a> {
+  Interface(&'a [TsTypeElement]),
+  Alias(&'a TsType),
+}
+
+/// Looks for props types that can't be serialized, following the interfaces
+/// and type aliases declared in the same module.
+struct PropsChecker<'a> {
+  types: HashMap<&'a str, LocalType<'a>>,
+  visiting: HashSet<&'a str>,
+  found: Vec<(SourceRange, String)>,
+}
+
+impl<'a> PropsChecker<'a> {
+  fn new(items: &'a [ModuleItem]) -> Self {
+    let mut types = HashMap::new();
+    for item in items {
+      let decl = match item {
+        ModuleItem::Stmt(stmt) => stmt.as_decl(),
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export)) => {
+          Some(&export.decl)
+        }
+        _ => None,
+      };
+      match decl {
+        Some(Decl::TsInterface(interface)) => {
+          types.insert(
+            &*interface.id.sym,
+            LocalType::Interface(&interface.body.body),
+          );
+        }
+        Some(Decl::TsTypeAlias(alias)) => {
+          types.insert(&*alias.id.sym, LocalType::Alias(&alias.type_ann));
+        }
+        _ => {}
+      }
+    }
+    Self {
+      types,
+      visiting: HashSet::new(),
+      found: Vec::new(),
+    }
+  }
+
+  fn check_function(&mut self, function: &'a Function) {
+    if let Some(param) = function.params.first() {
+      self.check_param(&param.pat);
+    }
+  }
+
+  fn check_param(&mut self, pat: &'a Pat) {
+    let type_ann = match pat {
+      Pat::Ident(ident) => &ident.type_ann,
+      Pat::Object(object) => &object.type_ann,
+      Pat::Assign(assign) => return self.check_param(&assign.left),
+      _ => return,
+    };
+    if let Some(type_ann) = type_ann {
+      self.check_type(&type_ann.type_ann, None);
+    }
+  }
+
+  /// Checks the type of the prop at `path`, or of the whole props if `path`
+  /// is `None`.
+  fn check_type(&mut self, ty: &'a TsType, path: Option<&str>) {
+    match ty {
+      TsType::TsFnOrConstructorType(_) => self.report(ty.range(), path),
+      TsType::TsKeywordType(keyword)
+        if keyword.kind == TsKeywordTypeKind::TsSymbolKeyword =>
+      {
+        self.report(ty.range(), path)
+      }
+      TsType::TsTypeLit(lit) => self.check_members(&lit.members, path),
+      TsType::TsUnionOrIntersectionType(union_or_intersection) => {
+        let types = match union_or_intersection {
+          TsUnionOrIntersectionType::TsUnionType(union) => &union.types,
+          TsUnionOrIntersectionType::TsIntersectionType(intersection) => {
+            &intersection.types
+          }
+        };
+        for ty in types {
+          self.check_type(ty, path);
+        }
+      }
+      TsType::TsArrayType(array) => self.check_type(&array.elem_type, path),
+      TsType::TsParenthesizedType(paren) => {
+        self.check_type(&paren.type_ann, path)
+      }
+      TsType::TsTypeRef(type_ref) => {
+        let TsEntityName::Ident(name) = &type_ref.type_name else {
+          return;
+        };
+        let name = &*name.sym;
+        if NOT_SERIALIZABLE.contains(name) {
+          self.report(ty.range(), path);
+          return;
+        }
+        if !self.visiting.insert(name) {
+          return;
+        }
+        match self.types.get(name).copied() {
+          Some(LocalType::Interface(members)) => {
+            self.check_members(members, path)
+          }
+          Some(LocalType::Alias(ty)) => self.check_type(ty, path),
+          None => {}
+        }
+        self.visiting.remove(name);
+      }
+      _ => {}
+    }
+  }
+
+  fn check_members(
+    &mut self,
+    members: &'a [TsTypeElement],
+    path: Option<&str>,
+  ) {
+    for member in members {
+      let (key, computed) = match member {
+        TsTypeElement::TsPropertySignature(prop) => (&prop.key, prop.computed),
+        TsTypeElement::TsMethodSignature(method) => {
+          (&method.key, method.computed)
+        }
+        _ => continue,
+      };
+      let name = match &**key {
+        Expr::Ident(ident) if !computed => ident.sym.to_string(),
+        Expr::Lit(Lit::Str(s)) => s.value.to_string(),
+        _ => continue,
+      };
+      let member_path = match path {
+        Some(path) => format!("{path}.{name}"),
+        None => name,
+      };
+      match member {
+        TsTypeElement::TsPropertySignature(prop) => {
+          if let Some(type_ann) = &prop.type_ann {
+            self.check_type(&type_ann.type_ann, Some(&member_path));
+          }
+        }
+        _ => self.report(member.range(), Some(&member_path)),
+      }
+    }
+  }
+
+  fn report(&mut self, range: SourceRange, path: Option<&str>) {
+    // A props parameter that is itself a function isn't a component.
+    if let Some(path) = path {
+      self.found.push((range, path.to_string()));
+    }
+  }
+}
+
+/// The function of the component exported by the module item, if any.
+fn exported_component<'a>(
+  item: &'a ModuleItem,
+  items: &'a [ModuleItem],
+) -> Option<ComponentFn<'a>> {
+  match item {
+    ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export)) => {
+      decl_component(&export.decl)
+    }
+    ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(export)) => {
+      match &export.decl {
+        DefaultDecl::Fn(fn_expr) => {
+          Some(ComponentFn::Function(&fn_expr.function))
+        }
+        _ => None,
+      }
+    }
+    ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(export)) => {
+      match &*export.expr {
+        // `export default Counter;`
+        Expr::Ident(ident) => items.iter().find_map(|item| {
+          let ModuleItem::Stmt(stmt) = item else {
+            return None;
+          };
+          let decl = stmt.as_decl()?;
+          let declares = match decl {
+            Decl::Fn(fn_decl) => fn_decl.ident.sym == ident.sym,
+            Decl::Var(var_decl) => var_decl.decls.iter().any(|declarator| {
+              matches!(&declarator.name, Pat::Ident(name) if name.id.sym == ident.sym)
+            }),
+            _ => false,
+          };
+          if declares {
+            decl_component(decl)
+          } else {
+            None
+          }
+        }),
+        expr => expr_component(expr),
+      }
+    }
+    _ => None,
+  }
+}
+
+enum ComponentFn<'a> {
+  Function(&'a Function),
+  Arrow(&'a [Pat]),
+}
+
+fn decl_component(decl: &Decl) -> Option<ComponentFn> {
+  match decl {
+    Decl::Fn(fn_decl) => Some(ComponentFn::Function(&fn_decl.function)),
+    Decl::Var(var_decl) => var_decl
+      .decls
+      .iter()
+      .find_map(|declarator| expr_component(declarator.init.as_deref()?)),
+    _ => None,
+  }
+}
+
+fn expr_component(expr: &Expr) -> Option<ComponentFn> {
+  match expr {
+    Expr::Fn(fn_expr) => Some(ComponentFn::Function(&fn_expr.function)),
+    Expr::Arrow(arrow) => Some(ComponentFn::Arrow(&arrow.params)),
+    Expr::Paren(paren) => expr_component(&paren.expr),
+    _ => None,
+  }
+}
+
+struct Visitor;
+
+impl Handler for Visitor {
+  fn module(&mut self, module: &Module, ctx: &mut Context) {
+    // Islands live in the `islands/` folder, or in an `(_islands)` folder
+    // inside of `routes/`.
+    let Some(mut path_segments) = ctx.specifier().path_segments() else {
+      return;
+    };
+    if !path_segments.any(|part| part == "islands" || part == "(_islands)") {
+      return;
+    }
+
+    let items = &module.inner.body;
+    let mut checker = PropsChecker::new(items);
+    for item in items {
+      match exported_component(item, items) {
+        Some(ComponentFn::Function(function)) => {
+          checker.check_function(function)
+        }
+        Some(ComponentFn::Arrow(params)) => {
+          if let Some(param) = params.first() {
+            checker.check_param(param);
+          }
+        }
+        None => {}
+      }
+    }
+
+    for (range, prop) in checker.found {
+      ctx.add_diagnostic_with_hint(
+        range,
+        CODE,
+        FreshIslandSerializablePropsMessage::NotSerializable(prop),
+        FreshIslandSerializablePropsHint::PassData,
+      );
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn fresh_island_serializable_props_valid() {
+    assert_lint_ok! {
+      FreshIslandSerializableProps,
+      filename: "file:///islands/Counter.tsx",
+      "export default function Counter(props: { start: number; label?: string }) {}",
+      "interface Props { items: string[]; user: { name: string } } export default function List({ items }: Props) {}",
+      "type Props = { count: Signal<number>; children: ComponentChildren }; export const Counter = (props: Props) => null;",
+      "function helper(callback: () => void) {} export default function Counter() {}",
+      "export function Counter(props: { data: Uint8Array | null }) {}",
+    };
+
+    assert_lint_ok! {
+      FreshIslandSerializableProps,
+      filename: "file:///components/Button.tsx",
+      "export default function Button(props: { onClick: () => void }) {}",
+    };
+  }
+
+  #[test]
+  fn fresh_island_serializable_props_invalid() {
+    assert_lint_err! {
+      FreshIslandSerializableProps,
+      filename: "file:///islands/Counter.tsx",
+      "export default function Counter(props: { onChange: (n: number) => void }) {}": [
+        {
+          col: 51,
+          message: FreshIslandSerializablePropsMessage::NotSerializable(
+            "onChange".to_string()
+          ),
+          hint: FreshIslandSerializablePropsHint::PassData,
+        }
+      ],
+      "interface Props { start: number; format(n: number): string } export default function Counter({ start }: Props) {}": [
+        {
+          col: 33,
+          message: FreshIslandSerializablePropsMessage::NotSerializable(
+            "format".to_string()
+          ),
+          hint: FreshIslandSerializablePropsHint::PassData,
+        }
+      ],
+      "type Options = { parse: Function }; type Props = { options: Options }; export const Form = (props: Props) => null;": [
+        {
+          col: 24,
+          message: FreshIslandSerializablePropsMessage::NotSerializable(
+            "options.parse".to_string()
+          ),
+          hint: FreshIslandSerializablePropsHint::PassData,
+        }
+      ],
+      "function Counter(props: { id: symbol }) {} export default Counter;": [
+        {
+          col: 30,
+          message: FreshIslandSerializablePropsMessage::NotSerializable(
+            "id".to_string()
+          ),
+          hint: FreshIslandSerializablePropsHint::PassData,
+        }
+      ],
+      "export function Loader(props: { data: Promise<string> | null }) {}": [
+        {
+          col: 38,
+          message: FreshIslandSerializablePropsMessage::NotSerializable(
+            "data".to_string()
+          ),
+          hint: FreshIslandSerializablePropsHint::PassData,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      FreshIslandSerializableProps,
+      filename: "file:///routes/shop/(_islands)/Cart.tsx",
+      "export default function Cart(props: { onBuy: () => void }) {}": [
+        {
+          col: 45,
+          message: FreshIslandSerializablePropsMessage::NotSerializable(
+            "onBuy".to_string()
+          ),
+          hint: FreshIslandSerializablePropsHint::PassData,
+        }
+      ],
+    };
+  }
+}
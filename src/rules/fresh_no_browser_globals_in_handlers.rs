@@ -0,0 +1,221 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::handler::Handler;
+
+use deno_ast::swc::ast::{Expr, Ident, UnaryExpr, UnaryOp};
+use deno_ast::swc::visit::{noop_visit_type, Visit, VisitWith};
+use deno_ast::view::{Decl, ExportDecl, NodeKind, Pat};
+use deno_ast::SourceRangedForSpanned;
+use derive_more::Display;
+
+#[derive(Debug)]
+pub struct FreshNoBrowserGlobalsInHandlers;
+
+const CODE: &str = "fresh-no-browser-globals-in-handlers";
+
+#[derive(Display)]
+enum FreshNoBrowserGlobalsInHandlersMessage {
+  #[display(
+    fmt = "`{}` is only available in the browser, but route handlers run on the server",
+    _0
+  )]
+  BrowserGlobal(String),
+}
+
+#[derive(Display)]
+enum FreshNoBrowserGlobalsInHandlersHint {
+  #[display(
+    fmt = "Use the request passed to the handler, or move this code into an island"
+  )]
+  UseRequest,
+}
+
+/// Globals that only exist in browsers, or that Deno shares between all the
+/// requests instead of keeping them per user.
+static BROWSER_GLOBALS: phf::Set<&'static str> = phf::phf_set! {
+  "window",
+  "document",
+  "history",
+  "screen",
+  "localStorage",
+  "sessionStorage",
+  "alert",
+  "confirm",
+  "prompt",
+  "requestAnimationFrame",
+  "cancelAnimationFrame",
+  "matchMedia",
+  "getComputedStyle",
+  "innerWidth",
+  "innerHeight",
+  "HTMLElement",
+};
+
+impl LintRule for FreshNoBrowserGlobalsInHandlers {
+  fn tags(&self) -> &'static [&'static str] {
+    &["fresh"]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(Visitor))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::ExportDecl])
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/fresh_no_browser_globals_in_handlers.md")
+  }
+}
+
+/// Finds the browser globals used in a handler. `typeof window` checks are
+/// allowed, since they are how code detects where it runs.
+struct GlobalFinder<'c, 'view> {
+  ctx: &'c mut Context<'view>,
+}
+
+impl Visit for GlobalFinder<'_, '_> {
+  noop_visit_type!();
+
+  fn visit_expr(&mut self, expr: &Expr) {
+    let Expr::Ident(ident) = expr else {
+      expr.visit_children_with(self);
+      return;
+    };
+    self.check_ident(ident);
+  }
+
+  fn visit_unary_expr(&mut self, unary: &UnaryExpr) {
+    if unary.op == UnaryOp::TypeOf && unary.arg.is_ident() {
+      return;
+    }
+    unary.visit_children_with(self);
+  }
+}
+
+impl GlobalFinder<'_, '_> {
+  fn check_ident(&mut self, ident: &Ident) {
+    if BROWSER_GLOBALS.contains(&*ident.sym)
+      && self.ctx.scope().is_global(&ident.to_id())
+    {
+      self.ctx.add_diagnostic_with_hint(
+        ident.range(),
+        CODE,
+        FreshNoBrowserGlobalsInHandlersMessage::BrowserGlobal(
+          ident.sym.to_string(),
+        ),
+        FreshNoBrowserGlobalsInHandlersHint::UseRequest,
+      );
+    }
+  }
+}
+
+struct Visitor;
+
+impl Handler for Visitor {
+  fn export_decl(&mut self, export_decl: &ExportDecl, ctx: &mut Context) {
+    // Fresh only runs the handlers of files in the routes/ folder.
+    let Some(mut path_segments) = ctx.specifier().path_segments() else {
+      return;
+    };
+    if !path_segments.any(|part| part == "routes") {
+      return;
+    }
+
+    let mut finder = GlobalFinder { ctx };
+    match export_decl.decl {
+      Decl::Fn(fn_decl) if fn_decl.ident.sym() == "handler" => {
+        fn_decl.inner.function.visit_with(&mut finder);
+      }
+      Decl::Var(var_decl) => {
+        for declarator in var_decl.decls {
+          if !matches!(declarator.name, Pat::Ident(name) if name.id.sym() == "handler")
+          {
+            continue;
+          }
+          if let Some(init) = &declarator.inner.init {
+            init.visit_with(&mut finder);
+          }
+        }
+      }
+      _ => {}
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn fresh_no_browser_globals_in_handlers_valid() {
+    assert_lint_ok! {
+      FreshNoBrowserGlobalsInHandlers,
+      filename: "file:///routes/index.tsx",
+      "export const handler = { GET(req) { return new Response(new URL(req.url).pathname); } };",
+      "export function handler(req) { const url = new URL(req.url); return Response.redirect(url); }",
+      "export const handler = { GET() { if (typeof window !== 'undefined') {} } };",
+      "export const handler = { GET() { const document = getDocument(); return document.body; } };",
+      "export default function Page() { return <button onClick={() => window.alert('hi')} />; }",
+      "export const config = { value: window };",
+      "export function handler(ctx) { return ctx.render({ title: ctx.state.title }); }",
+    };
+
+    assert_lint_ok! {
+      FreshNoBrowserGlobalsInHandlers,
+      filename: "file:///islands/Counter.tsx",
+      "export const handler = { GET() { return localStorage.getItem('count'); } };",
+    };
+  }
+
+  #[test]
+  fn fresh_no_browser_globals_in_handlers_invalid() {
+    assert_lint_err! {
+      FreshNoBrowserGlobalsInHandlers,
+      filename: "file:///routes/index.tsx",
+      "export const handler = { GET() { return new Response(window.location.href); } };": [
+        {
+          col: 53,
+          message: FreshNoBrowserGlobalsInHandlersMessage::BrowserGlobal(
+            "window".to_string()
+          ),
+          hint: FreshNoBrowserGlobalsInHandlersHint::UseRequest,
+        }
+      ],
+      "export async function handler(req, ctx) { const token = localStorage.getItem('token'); return ctx.render(); }": [
+        {
+          col: 56,
+          message: FreshNoBrowserGlobalsInHandlersMessage::BrowserGlobal(
+            "localStorage".to_string()
+          ),
+          hint: FreshNoBrowserGlobalsInHandlersHint::UseRequest,
+        }
+      ],
+      "export const handler = define.handlers({ POST() { document.title = 'Saved'; } });": [
+        {
+          col: 50,
+          message: FreshNoBrowserGlobalsInHandlersMessage::BrowserGlobal(
+            "document".to_string()
+          ),
+          hint: FreshNoBrowserGlobalsInHandlersHint::UseRequest,
+        }
+      ],
+      "export const handler = { GET() { if (!confirm('Sure?')) { return new Response(null); } } };": [
+        {
+          col: 38,
+          message: FreshNoBrowserGlobalsInHandlersMessage::BrowserGlobal(
+            "confirm".to_string()
+          ),
+          hint: FreshNoBrowserGlobalsInHandlersHint::UseRequest,
+        }
+      ],
+    };
+  }
+}
@@ -1,10 +1,11 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::handler::Handler;
+use crate::syntax_features::SyntaxFeatures;
 
 use deno_ast::view::{
-  Expr, JSXAttrName, JSXAttrValue, JSXElementName, JSXExpr, Program,
+  Expr, JSXAttrName, JSXAttrValue, JSXElementName, JSXExpr, NodeKind,
 };
 use deno_ast::SourceRanged;
 
@@ -26,12 +27,16 @@ impl LintRule for FreshServerEventHandlers {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    Visitor.traverse(program, context);
+  fn required_syntax(&self) -> SyntaxFeatures {
+    SyntaxFeatures::JSX
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(Visitor))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::JSXAttr])
   }
 
   #[cfg(feature = "docs")]
@@ -0,0 +1,310 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::syntax_features::SyntaxFeatures;
+use crate::Program;
+
+use deno_ast::swc::ast::{
+  BinaryOp, BlockStmt, BlockStmtOrExpr, Expr as SwcExpr, JSXAttrName,
+  JSXAttrOrSpread, JSXElement, ReturnStmt, Stmt,
+};
+use deno_ast::view::{ArrayLit, CallExpr, Callee, Expr, MemberProp};
+use deno_ast::{MediaType, SourceRangedForSpanned};
+use derive_more::Display;
+
+#[derive(Debug)]
+pub struct JsxKey;
+
+const CODE: &str = "jsx-key";
+
+#[derive(Display, Clone, Copy)]
+enum JsxKeyMessage {
+  #[display(fmt = "Missing `key` prop for element in iterator")]
+  InIterator,
+  #[display(fmt = "Missing `key` prop for element in array")]
+  InArray,
+}
+
+#[derive(Display)]
+enum JsxKeyHint {
+  #[display(
+    fmt = "Add a `key` prop with a value that is unique among its siblings"
+  )]
+  AddKey,
+  #[display(
+    fmt = "Shorthand fragments can't have a `key`, use `<Fragment key={{...}}>` instead"
+  )]
+  UseFragment,
+}
+
+impl LintRule for JsxKey {
+  fn tags(&self) -> &'static [&'static str] {
+    &["react", "jsx"]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn required_syntax(&self) -> SyntaxFeatures {
+    SyntaxFeatures::JSX
+  }
+
+  fn aliases(&self) -> &'static [&'static str] {
+    &["react/jsx-key"]
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    if !matches!(context.media_type(), MediaType::Jsx | MediaType::Tsx) {
+      return;
+    }
+    JsxKeyHandler.traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/jsx_key.md")
+  }
+}
+
+fn has_key(element: &JSXElement) -> bool {
+  element.opening.attrs.iter().any(|attr| match attr {
+    JSXAttrOrSpread::JSXAttr(attr) => {
+      matches!(&attr.name, JSXAttrName::Ident(name) if &*name.sym == "key")
+    }
+    JSXAttrOrSpread::SpreadElement(_) => false,
+  })
+}
+
+/// Reports the elements that `expr` can evaluate to if they don't have a key.
+fn check_expr(expr: &SwcExpr, message: JsxKeyMessage, ctx: &mut Context) {
+  match expr {
+    SwcExpr::Paren(paren) => check_expr(&paren.expr, message, ctx),
+    SwcExpr::Cond(cond) => {
+      check_expr(&cond.cons, message, ctx);
+      check_expr(&cond.alt, message, ctx);
+    }
+    SwcExpr::Bin(bin)
+      if matches!(
+        bin.op,
+        BinaryOp::LogicalAnd
+          | BinaryOp::LogicalOr
+          | BinaryOp::NullishCoalescing
+      ) =>
+    {
+      check_expr(&bin.right, message, ctx);
+    }
+    SwcExpr::JSXElement(element) if !has_key(element) => {
+      ctx.add_diagnostic_with_hint(
+        element.range(),
+        CODE,
+        message,
+        JsxKeyHint::AddKey,
+      );
+    }
+    SwcExpr::JSXFragment(fragment) => {
+      ctx.add_diagnostic_with_hint(
+        fragment.range(),
+        CODE,
+        message,
+        JsxKeyHint::UseFragment,
+      );
+    }
+    _ => {}
+  }
+}
+
+/// Collects the values returned by a function body, without entering nested
+/// functions.
+#[derive(Default)]
+struct ReturnCollector<'a> {
+  returned: Vec<&'a SwcExpr>,
+}
+
+impl<'a> ReturnCollector<'a> {
+  fn collect(&mut self, block: &'a BlockStmt) {
+    for stmt in &block.stmts {
+      self.collect_stmt(stmt);
+    }
+  }
+
+  fn collect_stmt(&mut self, stmt: &'a Stmt) {
+    match stmt {
+      Stmt::Return(ReturnStmt { arg: Some(arg), .. }) => {
+        self.returned.push(arg);
+      }
+      Stmt::Block(block) => self.collect(block),
+      Stmt::If(if_stmt) => {
+        self.collect_stmt(&if_stmt.cons);
+        if let Some(alt) = &if_stmt.alt {
+          self.collect_stmt(alt);
+        }
+      }
+      Stmt::Switch(switch) => {
+        for case in &switch.cases {
+          for stmt in &case.cons {
+            self.collect_stmt(stmt);
+          }
+        }
+      }
+      Stmt::Try(try_stmt) => {
+        self.collect(&try_stmt.block);
+        if let Some(handler) = &try_stmt.handler {
+          self.collect(&handler.body);
+        }
+        if let Some(finalizer) = &try_stmt.finalizer {
+          self.collect(finalizer);
+        }
+      }
+      Stmt::Labeled(labeled) => self.collect_stmt(&labeled.body),
+      Stmt::For(for_stmt) => self.collect_stmt(&for_stmt.body),
+      Stmt::ForIn(for_in) => self.collect_stmt(&for_in.body),
+      Stmt::ForOf(for_of) => self.collect_stmt(&for_of.body),
+      Stmt::While(while_stmt) => self.collect_stmt(&while_stmt.body),
+      Stmt::DoWhile(do_while) => self.collect_stmt(&do_while.body),
+      _ => {}
+    }
+  }
+}
+
+fn check_callback(callback: &Expr, ctx: &mut Context) {
+  let message = JsxKeyMessage::InIterator;
+  let body = match callback {
+    Expr::Arrow(arrow) => match &*arrow.inner.body {
+      BlockStmtOrExpr::Expr(expr) => {
+        check_expr(expr, message, ctx);
+        return;
+      }
+      BlockStmtOrExpr::BlockStmt(block) => block,
+    },
+    Expr::Fn(fn_expr) => match &fn_expr.inner.function.body {
+      Some(block) => block,
+      None => return,
+    },
+    _ => return,
+  };
+
+  let mut collector = ReturnCollector::default();
+  collector.collect(body);
+  for returned in collector.returned {
+    check_expr(returned, message, ctx);
+  }
+}
+
+struct JsxKeyHandler;
+
+impl Handler for JsxKeyHandler {
+  fn call_expr(&mut self, call_expr: &CallExpr, ctx: &mut Context) {
+    let Callee::Expr(Expr::Member(callee)) = &call_expr.callee else {
+      return;
+    };
+    let MemberProp::Ident(method) = &callee.prop else {
+      return;
+    };
+    // `Array.from(items, callback)`
+    let is_array_from = method.sym() == "from"
+      && matches!(&callee.obj, Expr::Ident(obj) if obj.sym() == "Array");
+    let callback_index = match method.sym().as_ref() {
+      "map" | "flatMap" => 0,
+      "from" if is_array_from => 1,
+      _ => return,
+    };
+    if let Some(callback) = call_expr.args.get(callback_index) {
+      check_callback(&callback.expr, ctx);
+    }
+  }
+
+  fn array_lit(&mut self, array_lit: &ArrayLit, ctx: &mut Context) {
+    for elem in array_lit.elems.iter().flatten() {
+      if elem.spread().is_none() {
+        check_expr(&elem.inner.expr, JsxKeyMessage::InArray, ctx);
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn jsx_key_valid() {
+    assert_lint_ok! {
+      JsxKey,
+      filename: "file:///foo.tsx",
+      "const list = items.map((item) => <li key={item.id}>{item.name}</li>);",
+      "const list = items.map(function (item) { return <li key={item.id} />; });",
+      "const list = items.map((item) => (item.visible ? <li key={item.id} /> : null));",
+      "const list = Array.from(items, (item) => <li key={item} />);",
+      "const list = items.map((item) => <Fragment key={item.id}><dt /><dd /></Fragment>);",
+      "const list = [<li key=\"a\" />, <li key=\"b\" />];",
+      "const list = items.map((item) => item.name);",
+      "const list = items.map((item) => { const render = () => <li />; return render; });",
+      "const list = items.forEach((item) => <li />);",
+      "const el = <div>{item}</div>;",
+      "const list = [...items];",
+    };
+  }
+
+  #[test]
+  fn jsx_key_invalid() {
+    assert_lint_err! {
+      JsxKey,
+      filename: "file:///foo.tsx",
+      "const list = items.map((item) => <li>{item.name}</li>);": [
+        {
+          col: 33,
+          message: JsxKeyMessage::InIterator,
+          hint: JsxKeyHint::AddKey,
+        }
+      ],
+      "const list = items.map(function (item) { if (item.a) { return <li />; } return <li key={item.id} />; });": [
+        {
+          col: 62,
+          message: JsxKeyMessage::InIterator,
+          hint: JsxKeyHint::AddKey,
+        }
+      ],
+      "const list = items.map((item) => (item.visible ? <li /> : <span key={item.id} />));": [
+        {
+          col: 49,
+          message: JsxKeyMessage::InIterator,
+          hint: JsxKeyHint::AddKey,
+        }
+      ],
+      "const list = items.flatMap((item) => item.visible && <li {...item} />);": [
+        {
+          col: 53,
+          message: JsxKeyMessage::InIterator,
+          hint: JsxKeyHint::AddKey,
+        }
+      ],
+      "const list = Array.from(items, (item) => <li />);": [
+        {
+          col: 41,
+          message: JsxKeyMessage::InIterator,
+          hint: JsxKeyHint::AddKey,
+        }
+      ],
+      "const list = items.map((item) => <><dt /><dd /></>);": [
+        {
+          col: 33,
+          message: JsxKeyMessage::InIterator,
+          hint: JsxKeyHint::UseFragment,
+        }
+      ],
+      "const list = [<li key=\"a\" />, <li />];": [
+        {
+          col: 30,
+          message: JsxKeyMessage::InArray,
+          hint: JsxKeyHint::AddKey,
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,107 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::syntax_features::SyntaxFeatures;
+use crate::Program;
+
+use deno_ast::view::{JSXAttr, JSXAttrName};
+use deno_ast::{MediaType, SourceRanged};
+use derive_more::Display;
+
+#[derive(Debug)]
+pub struct JsxNoChildrenProp;
+
+const CODE: &str = "jsx-no-children-prop";
+
+#[derive(Display)]
+enum JsxNoChildrenPropMessage {
+  #[display(fmt = "Avoid passing children as a prop")]
+  ChildrenProp,
+}
+
+#[derive(Display)]
+enum JsxNoChildrenPropHint {
+  #[display(fmt = "Put the children between the opening and closing tags")]
+  NestChildren,
+}
+
+impl LintRule for JsxNoChildrenProp {
+  fn tags(&self) -> &'static [&'static str] {
+    &["react", "jsx"]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn required_syntax(&self) -> SyntaxFeatures {
+    SyntaxFeatures::JSX
+  }
+
+  fn aliases(&self) -> &'static [&'static str] {
+    &["react/no-children-prop"]
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    if !matches!(context.media_type(), MediaType::Jsx | MediaType::Tsx) {
+      return;
+    }
+    JsxNoChildrenPropHandler.traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/jsx_no_children_prop.md")
+  }
+}
+
+struct JsxNoChildrenPropHandler;
+
+impl Handler for JsxNoChildrenPropHandler {
+  fn jsx_attr(&mut self, attr: &JSXAttr, ctx: &mut Context) {
+    if matches!(attr.name, JSXAttrName::Ident(name) if name.sym() == "children")
+    {
+      ctx.add_diagnostic_with_hint(
+        attr.range(),
+        CODE,
+        JsxNoChildrenPropMessage::ChildrenProp,
+        JsxNoChildrenPropHint::NestChildren,
+      );
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn jsx_no_children_prop_valid() {
+    assert_lint_ok! {
+      JsxNoChildrenProp,
+      filename: "file:///foo.tsx",
+      "<div>foo</div>",
+      "<Foo>{children}</Foo>",
+      "<Foo {...props} />",
+      "<Foo child={a} />",
+    };
+  }
+
+  #[test]
+  fn jsx_no_children_prop_invalid() {
+    assert_lint_err! {
+      JsxNoChildrenProp,
+      JsxNoChildrenPropMessage::ChildrenProp,
+      JsxNoChildrenPropHint::NestChildren,
+      filename: "file:///foo.tsx",
+      r#"<div children="foo" />"#: [{ col: 5 }],
+      "<Foo children={<Bar />} />": [{ col: 5 }],
+      "<Foo id={a} children={[a, b]}>c</Foo>": [{ col: 12 }],
+    };
+  }
+}
@@ -0,0 +1,149 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::syntax_features::SyntaxFeatures;
+use crate::Program;
+
+use deno_ast::view::JSXText;
+use deno_ast::{MediaType, SourceRange, SourceRanged};
+use std::ops::Range;
+use derive_more::Display;
+
+#[derive(Debug)]
+pub struct JsxNoCommentTextnodes;
+
+const CODE: &str = "jsx-no-comment-textnodes";
+
+#[derive(Display)]
+enum JsxNoCommentTextnodesMessage {
+  #[display(fmt = "This comment is rendered as text")]
+  Comment,
+}
+
+#[derive(Display)]
+enum JsxNoCommentTextnodesHint {
+  #[display(fmt = "Wrap the comment in braces, like `{{/* comment */}}`")]
+  WrapInBraces,
+}
+
+impl LintRule for JsxNoCommentTextnodes {
+  fn tags(&self) -> &'static [&'static str] {
+    &["react", "jsx"]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn required_syntax(&self) -> SyntaxFeatures {
+    SyntaxFeatures::JSX
+  }
+
+  fn aliases(&self) -> &'static [&'static str] {
+    &["react/jsx-no-comment-textnodes"]
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    if !matches!(context.media_type(), MediaType::Jsx | MediaType::Tsx) {
+      return;
+    }
+    JsxNoCommentTextnodesHandler.traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/jsx_no_comment_textnodes.md")
+  }
+}
+
+struct JsxNoCommentTextnodesHandler;
+
+impl Handler for JsxNoCommentTextnodesHandler {
+  fn jsx_text(&mut self, jsx_text: &JSXText, ctx: &mut Context) {
+    if let Some(comment) = find_comment_line(jsx_text.raw()) {
+      let start = jsx_text.start();
+      ctx.add_diagnostic_with_hint(
+        SourceRange::new(start + comment.start, start + comment.end),
+        CODE,
+        JsxNoCommentTextnodesMessage::Comment,
+        JsxNoCommentTextnodesHint::WrapInBraces,
+      );
+    }
+  }
+}
+
+/// Returns the byte range of the first line of `text` that starts with `//`
+/// or `/*`, without its indentation and trailing whitespace.
+fn find_comment_line(text: &str) -> Option<Range<usize>> {
+  let mut offset = 0;
+  for line in text.split_inclusive('\n') {
+    let trimmed = line.trim_start();
+    if trimmed.starts_with("//") || trimmed.starts_with("/*") {
+      let start = offset + line.len() - trimmed.len();
+      return Some(start..start + trimmed.trim_end().len());
+    }
+    offset += line.len();
+  }
+  None
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn jsx_no_comment_textnodes_valid() {
+    assert_lint_ok! {
+      JsxNoCommentTextnodes,
+      filename: "file:///foo.tsx",
+      "<div>{/* comment */}</div>",
+      "<div>{// comment\n}</div>",
+      "<a href=\"https://deno.land\">https://deno.land</a>",
+      "<div>a // b</div>",
+      "<div attr=\"// not a comment\" />",
+    };
+  }
+
+  #[test]
+  fn jsx_no_comment_textnodes_invalid() {
+    assert_lint_err! {
+      JsxNoCommentTextnodes,
+      filename: "file:///foo.tsx",
+      "<div>// comment</div>": [
+        {
+          col: 5,
+          message: JsxNoCommentTextnodesMessage::Comment,
+          hint: JsxNoCommentTextnodesHint::WrapInBraces,
+        }
+      ],
+      "<div>/* comment */</div>": [
+        {
+          col: 5,
+          message: JsxNoCommentTextnodesMessage::Comment,
+          hint: JsxNoCommentTextnodesHint::WrapInBraces,
+        }
+      ],
+      "<div>\n  // note\n</div>": [
+        {
+          line: 2,
+          col: 2,
+          message: JsxNoCommentTextnodesMessage::Comment,
+          hint: JsxNoCommentTextnodesHint::WrapInBraces,
+        }
+      ],
+      "<div>\n  text\n  // comment\n</div>": [
+        {
+          line: 3,
+          col: 2,
+          message: JsxNoCommentTextnodesMessage::Comment,
+          hint: JsxNoCommentTextnodesHint::WrapInBraces,
+        }
+      ],
+    };
+  }
+}
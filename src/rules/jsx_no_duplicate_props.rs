@@ -0,0 +1,148 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::syntax_features::SyntaxFeatures;
+use crate::Program;
+
+use deno_ast::view::{JSXAttrName, JSXAttrOrSpread, JSXOpeningElement};
+use deno_ast::{MediaType, SourceRanged};
+use derive_more::Display;
+use std::collections::HashSet;
+
+#[derive(Debug)]
+pub struct JsxNoDuplicateProps;
+
+const CODE: &str = "jsx-no-duplicate-props";
+
+#[derive(Display)]
+enum JsxNoDuplicatePropsMessage {
+  #[display(fmt = "Duplicate prop `{}`", _0)]
+  Duplicate(String),
+}
+
+#[derive(Display)]
+enum JsxNoDuplicatePropsHint {
+  #[display(fmt = "Remove one of the props, only the last one is used")]
+  Remove,
+}
+
+impl LintRule for JsxNoDuplicateProps {
+  fn tags(&self) -> &'static [&'static str] {
+    &["react", "jsx"]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn required_syntax(&self) -> SyntaxFeatures {
+    SyntaxFeatures::JSX
+  }
+
+  fn aliases(&self) -> &'static [&'static str] {
+    &["react/jsx-no-duplicate-props"]
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    if !matches!(context.media_type(), MediaType::Jsx | MediaType::Tsx) {
+      return;
+    }
+    JsxNoDuplicatePropsHandler.traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/jsx_no_duplicate_props.md")
+  }
+}
+
+struct JsxNoDuplicatePropsHandler;
+
+impl Handler for JsxNoDuplicatePropsHandler {
+  fn jsx_opening_element(
+    &mut self,
+    opening: &JSXOpeningElement,
+    ctx: &mut Context,
+  ) {
+    let mut seen = HashSet::new();
+    for attr in opening.attrs {
+      let JSXAttrOrSpread::JSXAttr(attr) = attr else {
+        continue;
+      };
+      let name = match attr.name {
+        JSXAttrName::Ident(ident) => ident.sym().to_string(),
+        JSXAttrName::JSXNamespacedName(name) => {
+          format!("{}:{}", name.ns.sym(), name.name.sym())
+        }
+      };
+      if !seen.insert(name.clone()) {
+        ctx.add_diagnostic_with_hint(
+          attr.range(),
+          CODE,
+          JsxNoDuplicatePropsMessage::Duplicate(name),
+          JsxNoDuplicatePropsHint::Remove,
+        );
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn jsx_no_duplicate_props_valid() {
+    assert_lint_ok! {
+      JsxNoDuplicateProps,
+      filename: "file:///foo.tsx",
+      r#"<div id="a" class="b" />"#,
+      r#"<div id="a" {...props} />"#,
+      r#"<div {...a} {...a} />"#,
+      r#"<div name="a" Name="b" />"#,
+      r#"<svg xlink:href="a" href="b" />"#,
+      r#"<div id="a"><span id="a" /></div>"#,
+    };
+  }
+
+  #[test]
+  fn jsx_no_duplicate_props_invalid() {
+    assert_lint_err! {
+      JsxNoDuplicateProps,
+      filename: "file:///foo.tsx",
+      r#"<div id="a" id="b" />"#: [
+        {
+          col: 12,
+          message: JsxNoDuplicatePropsMessage::Duplicate("id".to_string()),
+          hint: JsxNoDuplicatePropsHint::Remove,
+        }
+      ],
+      r#"<Foo onClick={a} {...props} onClick={b} onClick />"#: [
+        {
+          col: 28,
+          message: JsxNoDuplicatePropsMessage::Duplicate("onClick".to_string()),
+          hint: JsxNoDuplicatePropsHint::Remove,
+        },
+        {
+          col: 40,
+          message: JsxNoDuplicatePropsMessage::Duplicate("onClick".to_string()),
+          hint: JsxNoDuplicatePropsHint::Remove,
+        }
+      ],
+      r#"<svg xlink:href="a" xlink:href="b" />"#: [
+        {
+          col: 20,
+          message: JsxNoDuplicatePropsMessage::Duplicate(
+            "xlink:href".to_string()
+          ),
+          hint: JsxNoDuplicatePropsHint::Remove,
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,150 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::syntax_features::SyntaxFeatures;
+use crate::Program;
+
+use deno_ast::view::{
+  Expr, JSXAttrOrSpread, JSXOpeningElement, MemberProp, NodeTrait,
+};
+use deno_ast::{MediaType, SourceRanged};
+use derive_more::Display;
+use std::collections::HashSet;
+
+#[derive(Debug)]
+pub struct JsxPropsNoSpreadMulti;
+
+const CODE: &str = "jsx-props-no-spread-multi";
+
+#[derive(Display)]
+enum JsxPropsNoSpreadMultiMessage {
+  #[display(fmt = "`{}` is spread more than once", _0)]
+  Duplicate(String),
+}
+
+#[derive(Display)]
+enum JsxPropsNoSpreadMultiHint {
+  #[display(fmt = "Remove the duplicate spread")]
+  Remove,
+}
+
+impl LintRule for JsxPropsNoSpreadMulti {
+  fn tags(&self) -> &'static [&'static str] {
+    &["react", "jsx"]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn required_syntax(&self) -> SyntaxFeatures {
+    SyntaxFeatures::JSX
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    if !matches!(context.media_type(), MediaType::Jsx | MediaType::Tsx) {
+      return;
+    }
+    JsxPropsNoSpreadMultiHandler.traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/jsx_props_no_spread_multi.md")
+  }
+}
+
+/// Whether spreading the expression twice always spreads the same object.
+/// Calls and other expressions can return a different object every time.
+fn is_stable(expr: &Expr) -> bool {
+  match expr {
+    Expr::Ident(_) | Expr::This(_) => true,
+    Expr::Member(member) => {
+      !matches!(member.prop, MemberProp::Computed(_)) && is_stable(&member.obj)
+    }
+    _ => false,
+  }
+}
+
+struct JsxPropsNoSpreadMultiHandler;
+
+impl Handler for JsxPropsNoSpreadMultiHandler {
+  fn jsx_opening_element(
+    &mut self,
+    opening: &JSXOpeningElement,
+    ctx: &mut Context,
+  ) {
+    let mut seen = HashSet::new();
+    for attr in opening.attrs {
+      let JSXAttrOrSpread::SpreadElement(spread) = attr else {
+        continue;
+      };
+      if !is_stable(&spread.expr) {
+        continue;
+      }
+      let text = spread.expr.text();
+      if !seen.insert(text) {
+        ctx.add_diagnostic_with_hint(
+          spread.range(),
+          CODE,
+          JsxPropsNoSpreadMultiMessage::Duplicate(text.to_string()),
+          JsxPropsNoSpreadMultiHint::Remove,
+        );
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn jsx_props_no_spread_multi_valid() {
+    assert_lint_ok! {
+      JsxPropsNoSpreadMulti,
+      filename: "file:///foo.tsx",
+      "<div {...a} {...b} />",
+      "<div {...props} id={props.id} />",
+      "<div {...getProps()} {...getProps()} />",
+      "<div {...a[i]} {...a[i]} />",
+      "<div {...a}><span {...a} /></div>",
+    };
+  }
+
+  #[test]
+  fn jsx_props_no_spread_multi_invalid() {
+    assert_lint_err! {
+      JsxPropsNoSpreadMulti,
+      filename: "file:///foo.tsx",
+      "<div {...props} id=\"a\" {...props} />": [
+        {
+          col: 24,
+          message: JsxPropsNoSpreadMultiMessage::Duplicate("props".to_string()),
+          hint: JsxPropsNoSpreadMultiHint::Remove,
+        }
+      ],
+      "<Foo {...this.props} {...this.props} {...this.props} />": [
+        {
+          col: 22,
+          message: JsxPropsNoSpreadMultiMessage::Duplicate(
+            "this.props".to_string()
+          ),
+          hint: JsxPropsNoSpreadMultiHint::Remove,
+        },
+        {
+          col: 38,
+          message: JsxPropsNoSpreadMultiMessage::Duplicate(
+            "this.props".to_string()
+          ),
+          hint: JsxPropsNoSpreadMultiHint::Remove,
+        }
+      ],
+    };
+  }
+}
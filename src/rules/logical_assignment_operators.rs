@@ -0,0 +1,537 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::Handler;
+use crate::swc_util::{is_same_reference, is_simple_reference};
+
+use deno_ast::swc::ast::{
+  AssignOp, AssignTarget, BinExpr, BinaryOp, Expr, Lit, SimpleAssignTarget,
+  Stmt, UnaryOp,
+};
+use deno_ast::view::{AssignExpr, IfStmt, Node, NodeKind};
+use deno_ast::{SourceRange, SourceRangedForSpanned};
+use derive_more::Display;
+use serde::Deserialize;
+
+#[derive(Debug, Default)]
+pub struct LogicalAssignmentOperators {
+  options: LogicalAssignmentOperatorsOptions,
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct LogicalAssignmentOperatorsOptions {
+  pub style: LogicalAssignmentStyle,
+  /// With the `always` style, also reports `if` statements that only assign
+  /// a value to the variable they check, like `if (!a) a = b;`.
+  pub enforce_for_if_statements: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum LogicalAssignmentStyle {
+  /// Logical assignment operators wherever they can be used, like `a ||= b`.
+  #[default]
+  Always,
+  /// No logical assignment operators, like `a || (a = b)`.
+  Never,
+}
+
+impl LogicalAssignmentOperators {
+  pub fn new(options: LogicalAssignmentOperatorsOptions) -> Self {
+    Self { options }
+  }
+}
+
+const CODE: &str = "logical-assignment-operators";
+
+#[derive(Display)]
+enum LogicalAssignmentOperatorsMessage {
+  #[display(
+    fmt = "Assignment (`=`) can be replaced with operator assignment (`{}`)",
+    _0
+  )]
+  Assignment(&'static str),
+  #[display(
+    fmt = "`if` statement can be replaced with operator assignment (`{}`)",
+    _0
+  )]
+  If(&'static str),
+  #[display(fmt = "Unexpected logical operator assignment (`{}`)", _0)]
+  Unexpected(&'static str),
+}
+
+#[derive(Display)]
+enum LogicalAssignmentOperatorsHint {
+  #[display(fmt = "Use the `{}` operator instead", _0)]
+  UseOperator(&'static str),
+  #[display(fmt = "Use a logical expression instead, e.g. `a || (a = b)`")]
+  UseLogical,
+}
+
+const FIX_DESC: &str = "Use operator assignment";
+const LOGICAL_FIX_DESC: &str = "Use a logical expression";
+
+impl LintRule for LogicalAssignmentOperators {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "style": { "enum": ["always", "never"], "default": "always" },
+        "enforceForIfStatements": { "type": "boolean" }
+      }
+    }))
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(LogicalAssignmentOperatorsHandler {
+      options: &self.options,
+    }))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::AssignExpr, NodeKind::IfStmt])
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/logical_assignment_operators.md")
+  }
+}
+
+struct LogicalAssignmentOperatorsHandler<'a> {
+  options: &'a LogicalAssignmentOperatorsOptions,
+}
+
+impl Handler for LogicalAssignmentOperatorsHandler<'_> {
+  fn assign_expr(&mut self, assign_expr: &AssignExpr, ctx: &mut Context) {
+    let is_expr_stmt = matches!(assign_expr.parent(), Node::ExprStmt(_));
+    let assign_expr = assign_expr.inner;
+    let AssignTarget::Simple(target) = &assign_expr.left else {
+      return;
+    };
+
+    match self.options.style {
+      LogicalAssignmentStyle::Always => {
+        if assign_expr.op != AssignOp::Assign {
+          return;
+        }
+        let Expr::Bin(bin) = &*assign_expr.right else {
+          return;
+        };
+        let Some(operator) = logical_assign_operator(bin.op) else {
+          return;
+        };
+        if !is_same_reference(target, &bin.left) {
+          return;
+        }
+        let new_text = format!(
+          "{} {} {}",
+          target.text_fast(ctx.text_info()),
+          operator,
+          bin.right.text_fast(ctx.text_info())
+        );
+        add_diagnostic(
+          ctx,
+          assign_expr.range(),
+          LogicalAssignmentOperatorsMessage::Assignment(operator),
+          LogicalAssignmentOperatorsHint::UseOperator(operator),
+          fix(ctx, FIX_DESC, assign_expr.range(), new_text),
+        );
+      }
+      LogicalAssignmentStyle::Never => {
+        let (operator, logical) = match assign_expr.op {
+          AssignOp::OrAssign => ("||=", "||"),
+          AssignOp::AndAssign => ("&&=", "&&"),
+          AssignOp::NullishAssign => ("??=", "??"),
+          _ => return,
+        };
+        // The target is read twice in the logical expression.
+        let fixes = if is_simple_reference(target) {
+          let target = target.text_fast(ctx.text_info());
+          let logical_expr = format!(
+            "{} {} ({} = {})",
+            target,
+            logical,
+            target,
+            assign_expr.right.text_fast(ctx.text_info())
+          );
+          let new_text = if is_expr_stmt {
+            logical_expr
+          } else {
+            format!("({})", logical_expr)
+          };
+          fix(ctx, LOGICAL_FIX_DESC, assign_expr.range(), new_text)
+        } else {
+          vec![]
+        };
+        add_diagnostic(
+          ctx,
+          assign_expr.range(),
+          LogicalAssignmentOperatorsMessage::Unexpected(operator),
+          LogicalAssignmentOperatorsHint::UseLogical,
+          fixes,
+        );
+      }
+    }
+  }
+
+  fn if_stmt(&mut self, if_stmt: &IfStmt, ctx: &mut Context) {
+    if self.options.style != LogicalAssignmentStyle::Always
+      || !self.options.enforce_for_if_statements
+    {
+      return;
+    }
+    let if_stmt = if_stmt.inner;
+    if if_stmt.alt.is_some() {
+      return;
+    }
+    let stmt = match &*if_stmt.cons {
+      Stmt::Block(block) => match block.stmts.as_slice() {
+        [stmt] => stmt,
+        _ => return,
+      },
+      stmt => stmt,
+    };
+    let Stmt::Expr(expr_stmt) = stmt else {
+      return;
+    };
+    let Expr::Assign(assign_expr) = &*expr_stmt.expr else {
+      return;
+    };
+    if assign_expr.op != AssignOp::Assign {
+      return;
+    }
+    let AssignTarget::Simple(target) = &assign_expr.left else {
+      return;
+    };
+    let Some(operator) = if_test_operator(&if_stmt.test, target) else {
+      return;
+    };
+
+    let new_text = format!(
+      "{} {} {};",
+      target.text_fast(ctx.text_info()),
+      operator,
+      assign_expr.right.text_fast(ctx.text_info())
+    );
+    add_diagnostic(
+      ctx,
+      if_stmt.range(),
+      LogicalAssignmentOperatorsMessage::If(operator),
+      LogicalAssignmentOperatorsHint::UseOperator(operator),
+      fix(ctx, FIX_DESC, if_stmt.range(), new_text),
+    );
+  }
+}
+
+fn logical_assign_operator(op: BinaryOp) -> Option<&'static str> {
+  match op {
+    BinaryOp::LogicalOr => Some("||="),
+    BinaryOp::LogicalAnd => Some("&&="),
+    BinaryOp::NullishCoalescing => Some("??="),
+    _ => None,
+  }
+}
+
+/// The operator that an `if` statement with the test, which only assigns to
+/// the target, can be replaced with.
+fn if_test_operator(
+  test: &Expr,
+  target: &SimpleAssignTarget,
+) -> Option<&'static str> {
+  match test {
+    Expr::Paren(paren) => if_test_operator(&paren.expr, target),
+    Expr::Unary(unary)
+      if unary.op == UnaryOp::Bang && is_same_reference(target, &unary.arg) =>
+    {
+      Some("||=")
+    }
+    // `a == null` or `a == undefined`.
+    Expr::Bin(bin)
+      if bin.op == BinaryOp::EqEq
+        && comparison_operand(&bin.left, &bin.right, target).is_some() =>
+    {
+      Some("??=")
+    }
+    // `a === null || a === undefined`, in either order.
+    Expr::Bin(bin) if bin.op == BinaryOp::LogicalOr => {
+      let (Expr::Bin(left), Expr::Bin(right)) = (&*bin.left, &*bin.right)
+      else {
+        return None;
+      };
+      let is_strict_check = |bin: &BinExpr, null: bool| {
+        bin.op == BinaryOp::EqEqEq
+          && comparison_operand(&bin.left, &bin.right, target)
+            .is_some_and(|operand| is_null(operand) == null)
+      };
+      ((is_strict_check(left, true) && is_strict_check(right, false))
+        || (is_strict_check(left, false) && is_strict_check(right, true)))
+      .then_some("??=")
+    }
+    test if is_same_reference(target, test) => Some("&&="),
+    _ => None,
+  }
+}
+
+/// The `null` or `undefined` operand that the target is compared with.
+fn comparison_operand<'a>(
+  left: &'a Expr,
+  right: &'a Expr,
+  target: &SimpleAssignTarget,
+) -> Option<&'a Expr> {
+  let operand = if is_same_reference(target, left) {
+    right
+  } else if is_same_reference(target, right) {
+    left
+  } else {
+    return None;
+  };
+  (is_null(operand) || is_undefined(operand)).then_some(operand)
+}
+
+fn is_null(expr: &Expr) -> bool {
+  matches!(expr, Expr::Lit(Lit::Null(_)))
+}
+
+fn is_undefined(expr: &Expr) -> bool {
+  matches!(expr, Expr::Ident(ident) if ident.sym == *"undefined")
+}
+
+/// Comments in the range would be lost, so it isn't fixed if there are any.
+fn fix(
+  ctx: &Context,
+  description: &'static str,
+  range: SourceRange,
+  new_text: String,
+) -> Vec<LintFix> {
+  if ctx.comments_within(range).next().is_some() {
+    return vec![];
+  }
+  vec![LintFix {
+    description: description.into(),
+    changes: vec![LintFixChange {
+      new_text: new_text.into(),
+      range,
+    }],
+  }]
+}
+
+fn add_diagnostic(
+  ctx: &mut Context,
+  range: SourceRange,
+  message: LogicalAssignmentOperatorsMessage,
+  hint: LogicalAssignmentOperatorsHint,
+  fixes: Vec<LintFix>,
+) {
+  ctx.add_diagnostic_with_fixes(
+    range,
+    CODE,
+    message,
+    Some(hint.to_string()),
+    fixes,
+  );
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn logical_assignment_operators_valid() {
+    assert_lint_ok! {
+      LogicalAssignmentOperators::default(),
+      "a ||= b;",
+      "a = b || c;",
+      "a = b || a;",
+      "a = a + b;",
+      "a = a || b || c;",
+      "a.b = a.c || d;",
+      "a[b()] = a[b()] || c;",
+      "foo().a = foo().a || b;",
+      "a += a || b;",
+      "if (!a) a = b;",
+    };
+
+    let options = LogicalAssignmentOperatorsOptions {
+      enforce_for_if_statements: true,
+      ..Default::default()
+    };
+    assert_lint_ok! {
+      LogicalAssignmentOperators::new(options.clone()),
+      "if (!a) { a = b; } else { c(); }",
+      "if (!a) { a = b; c(); }",
+      "if (!a) b = c;",
+      "if (a === null) a = b;",
+      "if (a === null || b === undefined) a = b;",
+      "if (a) a += b;",
+    };
+
+    assert_lint_ok! {
+      LogicalAssignmentOperators::new(LogicalAssignmentOperatorsOptions {
+        style: LogicalAssignmentStyle::Never,
+        ..Default::default()
+      }),
+      "a = a || b;",
+      "a || (a = b);",
+      "a += b;",
+    };
+  }
+
+  #[test]
+  fn logical_assignment_operators_invalid() {
+    assert_lint_err! {
+      LogicalAssignmentOperators::default(),
+      "a = a || b;": [
+        {
+          col: 0,
+          message: LogicalAssignmentOperatorsMessage::Assignment("||="),
+          hint: LogicalAssignmentOperatorsHint::UseOperator("||="),
+          fix: (FIX_DESC, "a ||= b;"),
+        }
+      ],
+      "a = a && b;": [
+        {
+          col: 0,
+          message: LogicalAssignmentOperatorsMessage::Assignment("&&="),
+          hint: LogicalAssignmentOperatorsHint::UseOperator("&&="),
+          fix: (FIX_DESC, "a &&= b;"),
+        }
+      ],
+      "this.a.b = this.a.b ?? (c, d);": [
+        {
+          col: 0,
+          message: LogicalAssignmentOperatorsMessage::Assignment("??="),
+          hint: LogicalAssignmentOperatorsHint::UseOperator("??="),
+          fix: (FIX_DESC, "this.a.b ??= (c, d);"),
+        }
+      ],
+      "a['b'] = a['b'] || c;": [
+        {
+          col: 0,
+          message: LogicalAssignmentOperatorsMessage::Assignment("||="),
+          hint: LogicalAssignmentOperatorsHint::UseOperator("||="),
+          fix: (FIX_DESC, "a['b'] ||= c;"),
+        }
+      ],
+      "const x = (a = (a) || b);": [
+        {
+          col: 11,
+          message: LogicalAssignmentOperatorsMessage::Assignment("||="),
+          hint: LogicalAssignmentOperatorsHint::UseOperator("||="),
+          fix: (FIX_DESC, "const x = (a ||= b);"),
+        }
+      ],
+      "a = a /* default */ || b;": [
+        {
+          col: 0,
+          message: LogicalAssignmentOperatorsMessage::Assignment("||="),
+          hint: LogicalAssignmentOperatorsHint::UseOperator("||="),
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      LogicalAssignmentOperators::new(LogicalAssignmentOperatorsOptions {
+        enforce_for_if_statements: true,
+        ..Default::default()
+      }),
+      "if (!a) a = b;": [
+        {
+          col: 0,
+          message: LogicalAssignmentOperatorsMessage::If("||="),
+          hint: LogicalAssignmentOperatorsHint::UseOperator("||="),
+          fix: (FIX_DESC, "a ||= b;"),
+        }
+      ],
+      "if (a.b) {\n  a.b = c;\n}": [
+        {
+          col: 0,
+          message: LogicalAssignmentOperatorsMessage::If("&&="),
+          hint: LogicalAssignmentOperatorsHint::UseOperator("&&="),
+          fix: (FIX_DESC, "a.b &&= c;"),
+        }
+      ],
+      "if (a == null) a = b;": [
+        {
+          col: 0,
+          message: LogicalAssignmentOperatorsMessage::If("??="),
+          hint: LogicalAssignmentOperatorsHint::UseOperator("??="),
+          fix: (FIX_DESC, "a ??= b;"),
+        }
+      ],
+      "if (undefined == a) a = b;": [
+        {
+          col: 0,
+          message: LogicalAssignmentOperatorsMessage::If("??="),
+          hint: LogicalAssignmentOperatorsHint::UseOperator("??="),
+          fix: (FIX_DESC, "a ??= b;"),
+        }
+      ],
+      "if (a === undefined || a === null) a = b;": [
+        {
+          col: 0,
+          message: LogicalAssignmentOperatorsMessage::If("??="),
+          hint: LogicalAssignmentOperatorsHint::UseOperator("??="),
+          fix: (FIX_DESC, "a ??= b;"),
+        }
+      ],
+      "if (!a) {\n  // default\n  a = b;\n}": [
+        {
+          col: 0,
+          message: LogicalAssignmentOperatorsMessage::If("||="),
+          hint: LogicalAssignmentOperatorsHint::UseOperator("||="),
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      LogicalAssignmentOperators::new(LogicalAssignmentOperatorsOptions {
+        style: LogicalAssignmentStyle::Never,
+        ..Default::default()
+      }),
+      "a ||= b;": [
+        {
+          col: 0,
+          message: LogicalAssignmentOperatorsMessage::Unexpected("||="),
+          hint: LogicalAssignmentOperatorsHint::UseLogical,
+          fix: (LOGICAL_FIX_DESC, "a || (a = b);"),
+        }
+      ],
+      "const x = a.b ??= c;": [
+        {
+          col: 10,
+          message: LogicalAssignmentOperatorsMessage::Unexpected("??="),
+          hint: LogicalAssignmentOperatorsHint::UseLogical,
+          fix: (LOGICAL_FIX_DESC, "const x = (a.b ?? (a.b = c));"),
+        }
+      ],
+      "foo().a &&= b;": [
+        {
+          col: 0,
+          message: LogicalAssignmentOperatorsMessage::Unexpected("&&="),
+          hint: LogicalAssignmentOperatorsHint::UseLogical,
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn logical_assignment_operators_options() {
+    let options: LogicalAssignmentOperatorsOptions = serde_json::from_str(
+      r#"{ "style": "never", "enforceForIfStatements": true }"#,
+    )
+    .unwrap();
+    assert_eq!(options.style, LogicalAssignmentStyle::Never);
+    assert!(options.enforce_for_if_statements);
+  }
+}
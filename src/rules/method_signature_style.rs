@@ -0,0 +1,378 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::Handler;
+
+use deno_ast::view::{
+  Expr, NodeKind, NodeTrait, TsFnOrConstructorType, TsFnParam, TsInterfaceBody,
+  TsMethodSignature, TsPropertySignature, TsType, TsTypeElement, TsTypeLit,
+  TsTypeParamDecl,
+};
+use deno_ast::{SourceRange, SourceRanged};
+use derive_more::Display;
+use serde::Deserialize;
+
+#[derive(Debug, Default)]
+pub struct MethodSignatureStyle {
+  options: MethodSignatureStyleOptions,
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct MethodSignatureStyleOptions {
+  pub style: SignatureStyle,
+}
+
+/// How function members of interfaces and type literals should be written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SignatureStyle {
+  /// `f: () => void`, whose parameters are checked contravariantly under
+  /// `strictFunctionTypes`.
+  #[default]
+  Property,
+  /// `f(): void`
+  Method,
+}
+
+impl MethodSignatureStyle {
+  pub fn new(options: MethodSignatureStyleOptions) -> Self {
+    Self { options }
+  }
+}
+
+const CODE: &str = "method-signature-style";
+
+#[derive(Display)]
+enum MethodSignatureStyleMessage {
+  #[display(fmt = "Shorthand method signatures are not allowed")]
+  Method,
+  #[display(fmt = "Function property signatures are not allowed")]
+  Property,
+}
+
+#[derive(Display)]
+enum MethodSignatureStyleHint {
+  #[display(
+    fmt = "Use a function property instead, e.g. `f: () => void`, so that its parameters are checked strictly"
+  )]
+  UseProperty,
+  #[display(
+    fmt = "Use a shorthand method signature instead, e.g. `f(): void`"
+  )]
+  UseMethod,
+}
+
+const PROPERTY_FIX_DESC: &str = "Convert to a function property";
+const METHOD_FIX_DESC: &str = "Convert to a shorthand method signature";
+
+impl LintRule for MethodSignatureStyle {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "style": { "enum": ["property", "method"], "default": "property" }
+      }
+    }))
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(MethodSignatureStyleHandler {
+      style: self.options.style,
+    }))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::TsInterfaceBody, NodeKind::TsTypeLit])
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/method_signature_style.md")
+  }
+}
+
+/// The key of a member as written, including the brackets of a computed key
+/// and the `?` of an optional member.
+fn key_text(key: &Expr, computed: bool, optional: bool) -> String {
+  let mut text = if computed {
+    format!("[{}]", key.text())
+  } else {
+    key.text().to_string()
+  };
+  if optional {
+    text.push('?');
+  }
+  text
+}
+
+fn type_params_text<'a>(
+  type_params: Option<&'a TsTypeParamDecl<'a>>,
+) -> &'a str {
+  type_params.map_or("", |type_params| type_params.text())
+}
+
+fn params_text(params: &[TsFnParam], ctx: &Context) -> String {
+  match (params.first(), params.last()) {
+    (Some(first), Some(last)) => ctx
+      .text_of(SourceRange::new(first.start(), last.end()))
+      .to_string(),
+    _ => String::new(),
+  }
+}
+
+/// The `;` or `,` that is part of the member's range, if any.
+fn separator(member_text: &str) -> &str {
+  match member_text.chars().last() {
+    Some(';') => ";",
+    Some(',') => ",",
+    _ => "",
+  }
+}
+
+struct MethodSignatureStyleHandler {
+  style: SignatureStyle,
+}
+
+impl MethodSignatureStyleHandler {
+  fn check_members(&self, members: &[TsTypeElement], ctx: &mut Context) {
+    match self.style {
+      SignatureStyle::Property => {
+        for member in members {
+          if let TsTypeElement::TsMethodSignature(method) = member {
+            // Overloads would have to be merged into an intersection of
+            // function types, so they are only reported.
+            let is_overloaded = members.iter().any(|other| match other {
+              TsTypeElement::TsMethodSignature(other) => {
+                other.range() != method.range()
+                  && other.key.text() == method.key.text()
+              }
+              _ => false,
+            });
+            self.check_method(method, is_overloaded, ctx);
+          }
+        }
+      }
+      SignatureStyle::Method => {
+        for member in members {
+          if let TsTypeElement::TsPropertySignature(prop) = member {
+            self.check_property(prop, ctx);
+          }
+        }
+      }
+    }
+  }
+
+  fn check_method(
+    &self,
+    method: &TsMethodSignature,
+    is_overloaded: bool,
+    ctx: &mut Context,
+  ) {
+    let range = method.range();
+    if is_overloaded {
+      ctx.add_diagnostic_with_hint(
+        range,
+        CODE,
+        MethodSignatureStyleMessage::Method,
+        MethodSignatureStyleHint::UseProperty,
+      );
+      return;
+    }
+
+    let return_type = method
+      .type_ann
+      .map_or("any", |type_ann| type_ann.type_ann.text());
+    let new_text = format!(
+      "{}: {}({}) => {}{}",
+      key_text(&method.key, method.computed(), method.optional()),
+      type_params_text(method.type_params),
+      params_text(method.params, ctx),
+      return_type,
+      separator(method.text()),
+    );
+    ctx.add_diagnostic_with_fixes(
+      range,
+      CODE,
+      MethodSignatureStyleMessage::Method,
+      Some(MethodSignatureStyleHint::UseProperty.to_string()),
+      vec![LintFix {
+        description: PROPERTY_FIX_DESC.into(),
+        changes: vec![LintFixChange {
+          new_text: new_text.into(),
+          range,
+        }],
+      }],
+    );
+  }
+
+  fn check_property(&self, prop: &TsPropertySignature, ctx: &mut Context) {
+    // A method signature can't be `readonly`.
+    if prop.readonly() {
+      return;
+    }
+    let Some(TsType::TsFnOrConstructorType(TsFnOrConstructorType::TsFnType(
+      fn_type,
+    ))) = prop.type_ann.map(|type_ann| &type_ann.type_ann)
+    else {
+      return;
+    };
+
+    let range = prop.range();
+    let new_text = format!(
+      "{}{}({}): {}{}",
+      key_text(&prop.key, prop.computed(), prop.optional()),
+      type_params_text(fn_type.type_params),
+      params_text(fn_type.params, ctx),
+      fn_type.type_ann.type_ann.text(),
+      separator(prop.text()),
+    );
+    ctx.add_diagnostic_with_fixes(
+      range,
+      CODE,
+      MethodSignatureStyleMessage::Property,
+      Some(MethodSignatureStyleHint::UseMethod.to_string()),
+      vec![LintFix {
+        description: METHOD_FIX_DESC.into(),
+        changes: vec![LintFixChange {
+          new_text: new_text.into(),
+          range,
+        }],
+      }],
+    );
+  }
+}
+
+impl Handler for MethodSignatureStyleHandler {
+  fn ts_interface_body(&mut self, body: &TsInterfaceBody, ctx: &mut Context) {
+    self.check_members(body.body, ctx);
+  }
+
+  fn ts_type_lit(&mut self, type_lit: &TsTypeLit, ctx: &mut Context) {
+    self.check_members(type_lit.members, ctx);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn method_style() -> MethodSignatureStyle {
+    MethodSignatureStyle::new(MethodSignatureStyleOptions {
+      style: SignatureStyle::Method,
+    })
+  }
+
+  #[test]
+  fn method_signature_style_valid() {
+    assert_lint_ok! {
+      MethodSignatureStyle::default(),
+      "interface Foo { f: () => void; }",
+      "interface Foo { f: (a: string) => number; g?: () => void }",
+      "type Foo = { f: () => void };",
+      "interface Foo { get f(): number; set f(v: number); }",
+      "interface Foo { (): void; new (): Foo; }",
+      "const foo = { f() {} };",
+    };
+
+    assert_lint_ok! {
+      method_style(),
+      "interface Foo { f(): void; }",
+      "type Foo = { f(a: string): number };",
+      "interface Foo { readonly f: () => void; }",
+      "interface Foo { f: new () => Foo; }",
+      "interface Foo { f: string; }",
+    };
+  }
+
+  #[test]
+  fn method_signature_style_invalid() {
+    assert_lint_err! {
+      MethodSignatureStyle::default(),
+      "interface Foo { f(): void; }": [
+        {
+          col: 16,
+          message: MethodSignatureStyleMessage::Method,
+          hint: MethodSignatureStyleHint::UseProperty,
+          fix: (PROPERTY_FIX_DESC, "interface Foo { f: () => void; }"),
+        }
+      ],
+      "interface Foo { f?<T>(a: T, b?: string): T[]; }": [
+        {
+          col: 16,
+          message: MethodSignatureStyleMessage::Method,
+          hint: MethodSignatureStyleHint::UseProperty,
+          fix: (
+            PROPERTY_FIX_DESC,
+            "interface Foo { f?: <T>(a: T, b?: string) => T[]; }"
+          ),
+        }
+      ],
+      "type Foo = { [key](): void, g() };": [
+        {
+          col: 13,
+          message: MethodSignatureStyleMessage::Method,
+          hint: MethodSignatureStyleHint::UseProperty,
+          fix: (PROPERTY_FIX_DESC, "type Foo = { [key]: () => void, g() };"),
+        },
+        {
+          col: 28,
+          message: MethodSignatureStyleMessage::Method,
+          hint: MethodSignatureStyleHint::UseProperty,
+          fix: (PROPERTY_FIX_DESC, "type Foo = { [key](): void, g: () => any };"),
+        }
+      ],
+      "interface Foo { f(a: string): void; f(a: number): void; }": [
+        {
+          col: 16,
+          message: MethodSignatureStyleMessage::Method,
+          hint: MethodSignatureStyleHint::UseProperty,
+        },
+        {
+          col: 36,
+          message: MethodSignatureStyleMessage::Method,
+          hint: MethodSignatureStyleHint::UseProperty,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      method_style(),
+      "interface Foo { f: () => void; }": [
+        {
+          col: 16,
+          message: MethodSignatureStyleMessage::Property,
+          hint: MethodSignatureStyleHint::UseMethod,
+          fix: (METHOD_FIX_DESC, "interface Foo { f(): void; }"),
+        }
+      ],
+      "type Foo = { f?: <T>(a: T) => T[] };": [
+        {
+          col: 13,
+          message: MethodSignatureStyleMessage::Property,
+          hint: MethodSignatureStyleHint::UseMethod,
+          fix: (METHOD_FIX_DESC, "type Foo = { f?<T>(a: T): T[] };"),
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn method_signature_style_options() {
+    let options: MethodSignatureStyleOptions =
+      serde_json::from_str(r#"{ "style": "method" }"#).unwrap();
+    assert_eq!(options.style, SignatureStyle::Method);
+    let options: MethodSignatureStyleOptions =
+      serde_json::from_str("{}").unwrap();
+    assert_eq!(options.style, SignatureStyle::Property);
+  }
+}
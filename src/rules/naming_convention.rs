@@ -0,0 +1,925 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::handler::Handler;
+use deno_ast::view::NodeKind;
+
+use deno_ast::{view as ast_view, SourceRange, SourceRanged};
+use derive_more::Display;
+use serde::Deserialize;
+
+/// Enforces configurable naming conventions for identifiers.
+///
+/// Each entry of the options applies to one kind of identifier (a
+/// "selector"). Identifiers whose selector is not configured are not
+/// checked. If the same selector is configured more than once, the last
+/// entry wins.
+#[derive(Debug)]
+pub struct NamingConvention {
+  options: Vec<NamingConventionOption>,
+}
+
+const CODE: &str = "naming-convention";
+
+/// The kind of identifier an option applies to.
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum NamingSelector {
+  #[display(fmt = "Variable")]
+  Variable,
+  #[display(fmt = "Function")]
+  Function,
+  #[display(fmt = "Parameter")]
+  Parameter,
+  /// Class properties, methods, accessors and parameter properties.
+  #[display(fmt = "Class member")]
+  ClassMember,
+  #[display(fmt = "Enum member")]
+  EnumMember,
+  #[display(fmt = "Type parameter")]
+  TypeParameter,
+  #[display(fmt = "Interface")]
+  Interface,
+  #[display(fmt = "Class")]
+  Class,
+  #[display(fmt = "Type alias")]
+  TypeAlias,
+  #[display(fmt = "Enum")]
+  Enum,
+}
+
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum NamingFormat {
+  /// `fooBar`
+  #[display(fmt = "camelCase")]
+  #[serde(rename = "camelCase")]
+  CamelCase,
+  /// `FooBar`
+  #[display(fmt = "PascalCase")]
+  #[serde(rename = "PascalCase")]
+  PascalCase,
+  /// `FOO_BAR`
+  #[display(fmt = "UPPER_CASE")]
+  #[serde(rename = "UPPER_CASE")]
+  UpperCase,
+}
+
+/// How leading or trailing underscores are treated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum UnderscoreOption {
+  /// The name must not start (or end) with an underscore.
+  Forbid,
+  /// Underscores are allowed and stripped before checking the format.
+  #[default]
+  Allow,
+  /// The name must start (or end) with an underscore.
+  Require,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NamingConventionOption {
+  pub selector: NamingSelector,
+  /// Allowed formats. If empty, any format is accepted.
+  #[serde(default)]
+  pub format: Vec<NamingFormat>,
+  /// If not empty, the name must start with one of these prefixes.
+  #[serde(default)]
+  pub prefix: Vec<String>,
+  /// If not empty, the name must end with one of these suffixes.
+  #[serde(default)]
+  pub suffix: Vec<String>,
+  #[serde(default)]
+  pub leading_underscore: UnderscoreOption,
+  #[serde(default)]
+  pub trailing_underscore: UnderscoreOption,
+}
+
+impl NamingConventionOption {
+  pub fn new(selector: NamingSelector, format: Vec<NamingFormat>) -> Self {
+    Self {
+      selector,
+      format,
+      prefix: vec![],
+      suffix: vec![],
+      leading_underscore: UnderscoreOption::default(),
+      trailing_underscore: UnderscoreOption::default(),
+    }
+  }
+}
+
+impl NamingConvention {
+  pub fn new(options: Vec<NamingConventionOption>) -> Self {
+    Self { options }
+  }
+}
+
+impl Default for NamingConvention {
+  fn default() -> Self {
+    use NamingFormat::*;
+    use NamingSelector::*;
+    Self::new(vec![
+      NamingConventionOption::new(Variable, vec![CamelCase, UpperCase]),
+      NamingConventionOption::new(Function, vec![CamelCase, PascalCase]),
+      NamingConventionOption::new(Parameter, vec![CamelCase]),
+      NamingConventionOption::new(ClassMember, vec![CamelCase]),
+      NamingConventionOption::new(EnumMember, vec![PascalCase, UpperCase]),
+      NamingConventionOption::new(TypeParameter, vec![PascalCase]),
+      NamingConventionOption::new(Interface, vec![PascalCase]),
+      NamingConventionOption::new(Class, vec![PascalCase]),
+      NamingConventionOption::new(TypeAlias, vec![PascalCase]),
+      NamingConventionOption::new(Enum, vec![PascalCase]),
+    ])
+  }
+}
+
+impl LintRule for NamingConvention {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "array",
+      "items": {
+        "type": "object",
+        "properties": {
+          "selector": {
+            "enum": [
+              "variable",
+              "function",
+              "parameter",
+              "classMember",
+              "enumMember",
+              "typeParameter",
+              "interface",
+              "class",
+              "typeAlias",
+              "enum"
+            ]
+          },
+          "format": {
+            "type": "array",
+            "items": { "enum": ["camelCase", "PascalCase", "UPPER_CASE"] }
+          },
+          "prefix": { "type": "array", "items": { "type": "string" } },
+          "suffix": { "type": "array", "items": { "type": "string" } },
+          "leadingUnderscore": {
+            "enum": ["forbid", "allow", "require"],
+            "default": "allow"
+          },
+          "trailingUnderscore": {
+            "enum": ["forbid", "allow", "require"],
+            "default": "allow"
+          }
+        },
+        "required": ["selector"]
+      }
+    }))
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NamingConventionHandler {
+      options: &self.options,
+    }))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[
+      NodeKind::VarDecl,
+      NodeKind::FnDecl,
+      NodeKind::Param,
+      NodeKind::ArrowExpr,
+      NodeKind::TsParamProp,
+      NodeKind::ClassDecl,
+      NodeKind::Class,
+      NodeKind::TsEnumDecl,
+      NodeKind::TsTypeParam,
+      NodeKind::TsInterfaceDecl,
+      NodeKind::TsTypeAliasDecl,
+    ])
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/naming_convention.md")
+  }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Violation {
+  Format(String),
+  LeadingUnderscoreForbidden,
+  LeadingUnderscoreRequired,
+  TrailingUnderscoreForbidden,
+  TrailingUnderscoreRequired,
+  MissingPrefix,
+  MissingSuffix,
+}
+
+/// Checks `name` against `option`, returning the first violation found.
+fn check_name(
+  name: &str,
+  option: &NamingConventionOption,
+) -> Option<Violation> {
+  let mut rest = name;
+
+  match option.leading_underscore {
+    UnderscoreOption::Forbid if rest.starts_with('_') => {
+      return Some(Violation::LeadingUnderscoreForbidden);
+    }
+    UnderscoreOption::Require if !rest.starts_with('_') => {
+      return Some(Violation::LeadingUnderscoreRequired);
+    }
+    _ => rest = rest.trim_start_matches('_'),
+  }
+
+  match option.trailing_underscore {
+    UnderscoreOption::Forbid if rest.ends_with('_') => {
+      return Some(Violation::TrailingUnderscoreForbidden);
+    }
+    UnderscoreOption::Require if !rest.ends_with('_') => {
+      return Some(Violation::TrailingUnderscoreRequired);
+    }
+    _ => rest = rest.trim_end_matches('_'),
+  }
+
+  if !option.prefix.is_empty() {
+    match option.prefix.iter().find(|p| rest.starts_with(p.as_str())) {
+      Some(prefix) => rest = &rest[prefix.len()..],
+      None => return Some(Violation::MissingPrefix),
+    }
+  }
+
+  if !option.suffix.is_empty() {
+    match option.suffix.iter().find(|s| rest.ends_with(s.as_str())) {
+      Some(suffix) => rest = &rest[..rest.len() - suffix.len()],
+      None => return Some(Violation::MissingSuffix),
+    }
+  }
+
+  if option.format.is_empty()
+    || option.format.iter().any(|f| matches_format(rest, *f))
+  {
+    None
+  } else {
+    Some(Violation::Format(rest.to_string()))
+  }
+}
+
+fn matches_format(name: &str, format: NamingFormat) -> bool {
+  let Some(first) = name.chars().next() else {
+    return true;
+  };
+  match format {
+    NamingFormat::CamelCase => !first.is_uppercase() && !name.contains('_'),
+    NamingFormat::PascalCase => !first.is_lowercase() && !name.contains('_'),
+    NamingFormat::UpperCase => !name.chars().any(char::is_lowercase),
+  }
+}
+
+/// Splits an identifier into words on underscores and case boundaries, e.g.
+/// `fooBar_baz` into `["foo", "Bar", "baz"]` and `HTTPServer` into
+/// `["HTTP", "Server"]`.
+fn split_words(name: &str) -> Vec<String> {
+  let chars = name.chars().collect::<Vec<_>>();
+  let mut words = Vec::new();
+  let mut current = String::new();
+
+  for (i, &c) in chars.iter().enumerate() {
+    if c == '_' {
+      if !current.is_empty() {
+        words.push(std::mem::take(&mut current));
+      }
+      continue;
+    }
+
+    if c.is_uppercase() && !current.is_empty() {
+      let prev = chars[i - 1];
+      let next_is_lower = chars.get(i + 1).map_or(false, |n| n.is_lowercase());
+      if !prev.is_uppercase() || next_is_lower {
+        words.push(std::mem::take(&mut current));
+      }
+    }
+    current.push(c);
+  }
+
+  if !current.is_empty() {
+    words.push(current);
+  }
+  words
+}
+
+fn capitalize(word: &str) -> String {
+  let mut chars = word.chars();
+  match chars.next() {
+    Some(first) => {
+      let rest = chars.as_str().to_lowercase();
+      first.to_uppercase().collect::<String>() + rest.as_str()
+    }
+    None => String::new(),
+  }
+}
+
+fn convert_to_format(name: &str, format: NamingFormat) -> String {
+  let words = split_words(name);
+  match format {
+    NamingFormat::CamelCase => words
+      .iter()
+      .enumerate()
+      .map(|(i, w)| {
+        if i == 0 {
+          w.to_lowercase()
+        } else {
+          capitalize(w)
+        }
+      })
+      .collect(),
+    NamingFormat::PascalCase => words.iter().map(|w| capitalize(w)).collect(),
+    NamingFormat::UpperCase => words
+      .iter()
+      .map(|w| w.to_uppercase())
+      .collect::<Vec<_>>()
+      .join("_"),
+  }
+}
+
+fn format_list<T: ToString>(items: &[T]) -> String {
+  items
+    .iter()
+    .map(|i| i.to_string())
+    .collect::<Vec<_>>()
+    .join(", ")
+}
+
+struct NamingConventionHandler<'a> {
+  options: &'a [NamingConventionOption],
+}
+
+impl<'a> NamingConventionHandler<'a> {
+  fn check(
+    &self,
+    ctx: &mut Context,
+    selector: NamingSelector,
+    name: &str,
+    range: SourceRange,
+  ) {
+    let Some(option) =
+      self.options.iter().rev().find(|o| o.selector == selector)
+    else {
+      return;
+    };
+    let Some(violation) = check_name(name, option) else {
+      return;
+    };
+
+    let (message, hint) = match violation {
+      Violation::Format(core) => {
+        let message = format!(
+          "{} name `{}` must match one of the following formats: {}",
+          selector,
+          name,
+          format_list(&option.format)
+        );
+        let converted = convert_to_format(&core, option.format[0]);
+        let hint = if converted.is_empty() || converted == core {
+          format!("Rename `{}` to use {}", name, option.format[0])
+        } else {
+          format!(
+            "Consider renaming `{}` to `{}`",
+            name,
+            name.replacen(&core, &converted, 1)
+          )
+        };
+        (message, hint)
+      }
+      Violation::LeadingUnderscoreForbidden => (
+        format!(
+          "{} name `{}` must not have a leading underscore",
+          selector, name
+        ),
+        format!("Remove the leading underscore from `{}`", name),
+      ),
+      Violation::LeadingUnderscoreRequired => (
+        format!(
+          "{} name `{}` must have a leading underscore",
+          selector, name
+        ),
+        format!("Consider renaming `{}` to `_{}`", name, name),
+      ),
+      Violation::TrailingUnderscoreForbidden => (
+        format!(
+          "{} name `{}` must not have a trailing underscore",
+          selector, name
+        ),
+        format!("Remove the trailing underscore from `{}`", name),
+      ),
+      Violation::TrailingUnderscoreRequired => (
+        format!(
+          "{} name `{}` must have a trailing underscore",
+          selector, name
+        ),
+        format!("Consider renaming `{}` to `{}_`", name, name),
+      ),
+      Violation::MissingPrefix => (
+        format!(
+          "{} name `{}` must have one of the following prefixes: {}",
+          selector,
+          name,
+          format_list(&option.prefix)
+        ),
+        format!("Add one of the configured prefixes to `{}`", name),
+      ),
+      Violation::MissingSuffix => (
+        format!(
+          "{} name `{}` must have one of the following suffixes: {}",
+          selector,
+          name,
+          format_list(&option.suffix)
+        ),
+        format!("Add one of the configured suffixes to `{}`", name),
+      ),
+    };
+
+    ctx.add_diagnostic_with_hint(range, CODE, message, hint);
+  }
+
+  fn check_ident(
+    &self,
+    ctx: &mut Context,
+    selector: NamingSelector,
+    ident: &ast_view::Ident,
+  ) {
+    self.check(ctx, selector, ident.sym(), ident.range());
+  }
+
+  fn check_pat(
+    &self,
+    ctx: &mut Context,
+    selector: NamingSelector,
+    pat: &ast_view::Pat,
+  ) {
+    match pat {
+      ast_view::Pat::Ident(ident) => {
+        // `this` parameters only annotate the type of `this`.
+        if ident.id.sym() != "this" {
+          self.check_ident(ctx, selector, ident.id);
+        }
+      }
+      ast_view::Pat::Array(array) => {
+        for elem in array.elems.iter().flatten() {
+          self.check_pat(ctx, selector, elem);
+        }
+      }
+      ast_view::Pat::Rest(rest) => self.check_pat(ctx, selector, &rest.arg),
+      ast_view::Pat::Object(object) => {
+        for prop in object.props {
+          match prop {
+            ast_view::ObjectPatProp::KeyValue(kv) => {
+              self.check_pat(ctx, selector, &kv.value)
+            }
+            // Shorthand bindings like `{ foo_bar }` are dictated by the name
+            // of the destructured property, so they are not checked.
+            ast_view::ObjectPatProp::Assign(_) => {}
+            ast_view::ObjectPatProp::Rest(rest) => {
+              self.check_pat(ctx, selector, &rest.arg)
+            }
+          }
+        }
+      }
+      ast_view::Pat::Assign(assign) => {
+        self.check_pat(ctx, selector, &assign.left)
+      }
+      ast_view::Pat::Expr(_) | ast_view::Pat::Invalid(_) => {}
+    }
+  }
+
+  fn check_prop_name(&self, ctx: &mut Context, key: &ast_view::PropName) {
+    // Quoted, numeric and computed keys are not identifiers and can't be
+    // renamed freely, so only plain identifiers are checked.
+    if let ast_view::PropName::Ident(ident) = key {
+      self.check(ctx, NamingSelector::ClassMember, ident.sym(), ident.range());
+    }
+  }
+
+  fn check_private_name(&self, ctx: &mut Context, key: &ast_view::PrivateName) {
+    self.check(ctx, NamingSelector::ClassMember, key.name(), key.range());
+  }
+}
+
+impl<'a> Handler for NamingConventionHandler<'a> {
+  fn var_decl(&mut self, var_decl: &ast_view::VarDecl, ctx: &mut Context) {
+    if var_decl.declare() {
+      ctx.stop_traverse();
+      return;
+    }
+
+    for decl in var_decl.decls {
+      self.check_pat(ctx, NamingSelector::Variable, &decl.name);
+    }
+  }
+
+  fn fn_decl(&mut self, fn_decl: &ast_view::FnDecl, ctx: &mut Context) {
+    if fn_decl.declare() {
+      ctx.stop_traverse();
+      return;
+    }
+
+    self.check_ident(ctx, NamingSelector::Function, fn_decl.ident);
+  }
+
+  fn param(&mut self, param: &ast_view::Param, ctx: &mut Context) {
+    self.check_pat(ctx, NamingSelector::Parameter, &param.pat);
+  }
+
+  fn arrow_expr(&mut self, arrow: &ast_view::ArrowExpr, ctx: &mut Context) {
+    for param in arrow.params {
+      self.check_pat(ctx, NamingSelector::Parameter, param);
+    }
+  }
+
+  fn ts_param_prop(
+    &mut self,
+    param_prop: &ast_view::TsParamProp,
+    ctx: &mut Context,
+  ) {
+    if param_prop.inner.is_override {
+      return;
+    }
+
+    let ident = match &param_prop.param {
+      ast_view::TsParamPropParam::Ident(ident) => Some(ident.id),
+      ast_view::TsParamPropParam::Assign(assign) => match &assign.left {
+        ast_view::Pat::Ident(ident) => Some(ident.id),
+        _ => None,
+      },
+    };
+    if let Some(ident) = ident {
+      self.check_ident(ctx, NamingSelector::ClassMember, ident);
+    }
+  }
+
+  fn class_decl(
+    &mut self,
+    class_decl: &ast_view::ClassDecl,
+    ctx: &mut Context,
+  ) {
+    if class_decl.declare() {
+      ctx.stop_traverse();
+      return;
+    }
+
+    self.check_ident(ctx, NamingSelector::Class, class_decl.ident);
+  }
+
+  fn class(&mut self, class: &ast_view::Class, ctx: &mut Context) {
+    use ast_view::ClassMember;
+
+    for member in class.body {
+      match member {
+        // Overridden members get their names from the base class.
+        ClassMember::Method(method) if !method.inner.is_override => {
+          self.check_prop_name(ctx, &method.key);
+        }
+        ClassMember::ClassProp(prop) if !prop.inner.is_override => {
+          self.check_prop_name(ctx, &prop.key);
+        }
+        ClassMember::PrivateMethod(method) => {
+          self.check_private_name(ctx, method.key);
+        }
+        ClassMember::PrivateProp(prop) => {
+          self.check_private_name(ctx, prop.key);
+        }
+        ClassMember::AutoAccessor(accessor) => match &accessor.key {
+          ast_view::Key::Private(name) => self.check_private_name(ctx, name),
+          ast_view::Key::Public(name) => self.check_prop_name(ctx, name),
+        },
+        _ => {}
+      }
+    }
+  }
+
+  fn ts_enum_decl(
+    &mut self,
+    enum_decl: &ast_view::TsEnumDecl,
+    ctx: &mut Context,
+  ) {
+    if enum_decl.declare() {
+      ctx.stop_traverse();
+      return;
+    }
+
+    self.check_ident(ctx, NamingSelector::Enum, enum_decl.id);
+    for member in enum_decl.members {
+      if let ast_view::TsEnumMemberId::Ident(ident) = &member.id {
+        self.check_ident(ctx, NamingSelector::EnumMember, ident);
+      }
+    }
+  }
+
+  fn ts_type_param(
+    &mut self,
+    type_param: &ast_view::TsTypeParam,
+    ctx: &mut Context,
+  ) {
+    self.check_ident(ctx, NamingSelector::TypeParameter, type_param.name);
+  }
+
+  fn ts_interface_decl(
+    &mut self,
+    interface_decl: &ast_view::TsInterfaceDecl,
+    ctx: &mut Context,
+  ) {
+    if interface_decl.declare() {
+      ctx.stop_traverse();
+      return;
+    }
+
+    self.check_ident(ctx, NamingSelector::Interface, interface_decl.id);
+  }
+
+  fn ts_type_alias_decl(
+    &mut self,
+    type_alias: &ast_view::TsTypeAliasDecl,
+    ctx: &mut Context,
+  ) {
+    if type_alias.declare() {
+      ctx.stop_traverse();
+      return;
+    }
+
+    self.check_ident(ctx, NamingSelector::TypeAlias, type_alias.id);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_split_words() {
+    let tests = [
+      ("fooBar", vec!["foo", "Bar"]),
+      ("foo_bar", vec!["foo", "bar"]),
+      ("FOO_BAR", vec!["FOO", "BAR"]),
+      ("HTTPServer", vec!["HTTP", "Server"]),
+      ("parseHTTP2Response", vec!["parse", "HTTP2", "Response"]),
+    ];
+
+    for (input, expected) in tests {
+      assert_eq!(split_words(input), expected);
+    }
+  }
+
+  #[test]
+  fn test_convert_to_format() {
+    use NamingFormat::*;
+    let tests = [
+      ("foo_bar", CamelCase, "fooBar"),
+      ("FooBar", CamelCase, "fooBar"),
+      ("foo_bar", PascalCase, "FooBar"),
+      ("fooBar", UpperCase, "FOO_BAR"),
+      ("HTTPServer", UpperCase, "HTTP_SERVER"),
+    ];
+
+    for (input, format, expected) in tests {
+      assert_eq!(convert_to_format(input, format), expected);
+    }
+  }
+
+  #[test]
+  fn naming_convention_valid() {
+    assert_lint_ok! {
+      NamingConvention::default(),
+      "const fooBar = 1;",
+      "const FOO_BAR = 1;",
+      "let _private = 1; let trailing_ = 2;",
+      "const [firstItem, ...otherItems] = list;",
+      "const { foo_bar } = obj;",
+      "const { foo_bar: fooBar } = obj;",
+      "function doSomething(someParam: string) {}",
+      "function MyComponent() {}",
+      "const f = (_: number, secondArg: number) => {};",
+      "function f(this: Window) {}",
+      "class MyClass { someProp = 1; #secret = 2; someMethod() {} get value() { return 1; } }",
+      "class A { constructor(private readonly someValue: string) {} }",
+      "class A extends B { override some_method() {} }",
+      "class A { 'quoted_key' = 1; [computed_key] = 2; }",
+      "enum Direction { Up, DOWN_LEFT }",
+      "function identity<T, TValue>(value: TValue): T {}",
+      "interface SomeInterface { some_property: string }",
+      "type SomeType = { some_property: string };",
+      "declare const some_global: string;",
+      "declare function some_function(some_param: string): void;",
+      "declare class some_class { some_method(): void }",
+      "import { some_import } from './mod.ts';",
+      "try {} catch (some_error) {}",
+    };
+  }
+
+  #[test]
+  fn naming_convention_invalid() {
+    assert_lint_err! {
+      NamingConvention::default(),
+      "const foo_bar = 1;": [
+        {
+          col: 6,
+          message: "Variable name `foo_bar` must match one of the following formats: camelCase, UPPER_CASE",
+          hint: "Consider renaming `foo_bar` to `fooBar`",
+        }
+      ],
+      "let _foo_bar = 1;": [
+        {
+          col: 4,
+          message: "Variable name `_foo_bar` must match one of the following formats: camelCase, UPPER_CASE",
+          hint: "Consider renaming `_foo_bar` to `_fooBar`",
+        }
+      ],
+      "const [first_item] = list;": [
+        {
+          col: 7,
+          message: "Variable name `first_item` must match one of the following formats: camelCase, UPPER_CASE",
+          hint: "Consider renaming `first_item` to `firstItem`",
+        }
+      ],
+      "function do_something(SomeParam) {}": [
+        {
+          col: 9,
+          message: "Function name `do_something` must match one of the following formats: camelCase, PascalCase",
+          hint: "Consider renaming `do_something` to `doSomething`",
+        },
+        {
+          col: 22,
+          message: "Parameter name `SomeParam` must match one of the following formats: camelCase",
+          hint: "Consider renaming `SomeParam` to `someParam`",
+        }
+      ],
+      "const f = (some_arg) => {};": [
+        {
+          col: 11,
+          message: "Parameter name `some_arg` must match one of the following formats: camelCase",
+          hint: "Consider renaming `some_arg` to `someArg`",
+        }
+      ],
+      "class my_class { some_prop = 1; #some_secret = 2; SomeMethod() {} }": [
+        {
+          col: 6,
+          message: "Class name `my_class` must match one of the following formats: PascalCase",
+          hint: "Consider renaming `my_class` to `MyClass`",
+        },
+        {
+          col: 17,
+          message: "Class member name `some_prop` must match one of the following formats: camelCase",
+          hint: "Consider renaming `some_prop` to `someProp`",
+        },
+        {
+          col: 32,
+          message: "Class member name `some_secret` must match one of the following formats: camelCase",
+          hint: "Consider renaming `some_secret` to `someSecret`",
+        },
+        {
+          col: 50,
+          message: "Class member name `SomeMethod` must match one of the following formats: camelCase",
+          hint: "Consider renaming `SomeMethod` to `someMethod`",
+        }
+      ],
+      "class A { constructor(private some_value: string) {} }": [
+        {
+          col: 30,
+          message: "Class member name `some_value` must match one of the following formats: camelCase",
+          hint: "Consider renaming `some_value` to `someValue`",
+        }
+      ],
+      "enum direction { up }": [
+        {
+          col: 5,
+          message: "Enum name `direction` must match one of the following formats: PascalCase",
+          hint: "Consider renaming `direction` to `Direction`",
+        },
+        {
+          col: 17,
+          message: "Enum member name `up` must match one of the following formats: PascalCase, UPPER_CASE",
+          hint: "Consider renaming `up` to `Up`",
+        }
+      ],
+      "function f<t_value>() {}": [
+        {
+          col: 11,
+          message: "Type parameter name `t_value` must match one of the following formats: PascalCase",
+          hint: "Consider renaming `t_value` to `TValue`",
+        }
+      ],
+      "interface some_interface {}": [
+        {
+          col: 10,
+          message: "Interface name `some_interface` must match one of the following formats: PascalCase",
+          hint: "Consider renaming `some_interface` to `SomeInterface`",
+        }
+      ],
+      "type some_type = string;": [
+        {
+          col: 5,
+          message: "Type alias name `some_type` must match one of the following formats: PascalCase",
+          hint: "Consider renaming `some_type` to `SomeType`",
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn naming_convention_custom_options() {
+    let interface_prefix = || {
+      NamingConvention::new(vec![NamingConventionOption {
+        prefix: vec!["I".to_string()],
+        ..NamingConventionOption::new(
+          NamingSelector::Interface,
+          vec![NamingFormat::PascalCase],
+        )
+      }])
+    };
+    assert_lint_ok! {
+      interface_prefix(),
+      "interface IFoo {}",
+      // unconfigured selectors are not checked
+      "const foo_bar = 1;",
+    };
+    assert_lint_err! {
+      interface_prefix(),
+      "interface Foo {}": [
+        {
+          col: 10,
+          message: "Interface name `Foo` must have one of the following prefixes: I",
+          hint: "Add one of the configured prefixes to `Foo`",
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NamingConvention::new(vec![NamingConventionOption {
+        leading_underscore: UnderscoreOption::Forbid,
+        ..NamingConventionOption::new(
+          NamingSelector::Variable,
+          vec![NamingFormat::CamelCase],
+        )
+      }]),
+      "const _foo = 1;": [
+        {
+          col: 6,
+          message: "Variable name `_foo` must not have a leading underscore",
+          hint: "Remove the leading underscore from `_foo`",
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NamingConvention::new(vec![NamingConventionOption {
+        leading_underscore: UnderscoreOption::Require,
+        ..NamingConventionOption::new(NamingSelector::ClassMember, vec![])
+      }]),
+      "class A { foo = 1; _bar = 2; }": [
+        {
+          col: 10,
+          message: "Class member name `foo` must have a leading underscore",
+          hint: "Consider renaming `foo` to `_foo`",
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NamingConvention::new(vec![NamingConventionOption {
+        suffix: vec!["Error".to_string()],
+        ..NamingConventionOption::new(
+          NamingSelector::Class,
+          vec![NamingFormat::PascalCase],
+        )
+      }]),
+      "class NotFoundError {} class NotFound {}": [
+        {
+          col: 29,
+          message: "Class name `NotFound` must have one of the following suffixes: Error",
+          hint: "Add one of the configured suffixes to `NotFound`",
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn naming_convention_deserialize_options() {
+    let options: Vec<NamingConventionOption> = serde_json::from_str(
+      r#"[
+        { "selector": "typeParameter", "format": ["PascalCase"], "prefix": ["T"] },
+        { "selector": "variable", "format": ["camelCase", "UPPER_CASE"], "leadingUnderscore": "forbid" }
+      ]"#,
+    )
+    .unwrap();
+    assert_eq!(options[0].selector, NamingSelector::TypeParameter);
+    assert_eq!(options[0].prefix, vec!["T".to_string()]);
+    assert_eq!(
+      options[1].format,
+      vec![NamingFormat::CamelCase, NamingFormat::UpperCase]
+    );
+    assert_eq!(options[1].leading_underscore, UnderscoreOption::Forbid);
+    assert_eq!(options[1].trailing_underscore, UnderscoreOption::Allow);
+  }
+}
@@ -1,9 +1,8 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
-use crate::Program;
-use deno_ast::view::{CallExpr, Callee, Expr, ExprOrSpread, NewExpr};
+use crate::handler::Handler;
+use deno_ast::view::{CallExpr, Callee, Expr, ExprOrSpread, NewExpr, NodeKind};
 use deno_ast::{SourceRange, SourceRanged};
 
 #[derive(Debug)]
@@ -22,12 +21,12 @@ impl LintRule for NoArrayConstructor {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    NoArrayConstructorHandler.traverse(program, context);
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoArrayConstructorHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::NewExpr, NodeKind::CallExpr])
   }
 
   #[cfg(feature = "docs")]
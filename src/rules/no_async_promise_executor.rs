@@ -1,9 +1,9 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
-use crate::Program;
-use deno_ast::view::{Expr, NewExpr, ParenExpr};
+use crate::handler::Handler;
+use crate::syntax_features::SyntaxFeatures;
+use deno_ast::view::{Expr, NewExpr, NodeKind, ParenExpr};
 use deno_ast::SourceRanged;
 
 #[derive(Debug)]
@@ -23,12 +23,16 @@ impl LintRule for NoAsyncPromiseExecutor {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    NoAsyncPromiseExecutorHandler.traverse(program, context);
+  fn required_syntax(&self) -> SyntaxFeatures {
+    SyntaxFeatures::ASYNC
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoAsyncPromiseExecutorHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::NewExpr])
   }
 
   #[cfg(feature = "docs")]
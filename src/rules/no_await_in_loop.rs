@@ -1,9 +1,9 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
-use crate::Program;
-use deno_ast::view::NodeTrait;
+use crate::handler::Handler;
+use crate::syntax_features::SyntaxFeatures;
+use deno_ast::view::{NodeKind, NodeTrait};
 use deno_ast::{view as ast_view, SourceRanged};
 
 #[derive(Debug)]
@@ -18,12 +18,16 @@ impl LintRule for NoAwaitInLoop {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program<'_>,
-  ) {
-    NoAwaitInLoopHandler.traverse(program, context);
+  fn required_syntax(&self) -> SyntaxFeatures {
+    SyntaxFeatures::ASYNC
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoAwaitInLoopHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::AwaitExpr])
   }
 
   #[cfg(feature = "docs")]
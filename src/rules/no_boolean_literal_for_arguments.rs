@@ -1,7 +1,6 @@
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
-use crate::Program;
-use deno_ast::view::{CallExpr, NodeTrait};
+use crate::handler::Handler;
+use deno_ast::view::{CallExpr, NodeKind, NodeTrait};
 use deno_ast::SourceRanged;
 
 #[derive(Debug)]
@@ -14,12 +13,12 @@ const HINT: &str =
   "const ARG_ONE = true, ARG_TWO = false;\nyourFunction(ARG_ONE, ARG_TWO)";
 
 impl LintRule for NoBooleanLiteralForArguments {
-  fn lint_program_with_ast_view<'view>(
-    &self,
-    context: &mut Context<'view>,
-    program: Program<'view>,
-  ) {
-    NoBooleanLiteralForArgumentsVisitor.traverse(program, context);
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoBooleanLiteralForArgumentsVisitor))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::CallExpr])
   }
 
   fn code(&self) -> &'static str {
@@ -1,9 +1,9 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
-use crate::Program;
-use deno_ast::view::{Decl, Stmt, SwitchCase, VarDeclKind};
+use crate::handler::Handler;
+use crate::syntax_features::SyntaxFeatures;
+use deno_ast::view::{Decl, NodeKind, Stmt, SwitchCase, VarDeclKind};
 use deno_ast::SourceRanged;
 
 #[derive(Debug)]
@@ -22,12 +22,16 @@ impl LintRule for NoCaseDeclarations {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    NoCaseDeclarationsHandler.traverse(program, context);
+  fn required_syntax(&self) -> SyntaxFeatures {
+    SyntaxFeatures::SWITCH
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoCaseDeclarationsHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::SwitchCase])
   }
 
   #[cfg(feature = "docs")]
@@ -1,10 +1,10 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::handler::Handler;
 use crate::swc_util::find_lhs_ids;
-use crate::Program;
-use deno_ast::view::AssignExpr;
+use crate::syntax_features::SyntaxFeatures;
+use deno_ast::view::{AssignExpr, NodeKind};
 use deno_ast::{BindingKind, SourceRanged};
 
 #[derive(Debug)]
@@ -23,12 +23,16 @@ impl LintRule for NoClassAssign {
     CODE
   }
 
-  fn lint_program_with_ast_view<'view>(
-    &self,
-    context: &mut Context<'view>,
-    program: Program<'view>,
-  ) {
-    NoClassAssignVisitor.traverse(program, context);
+  fn required_syntax(&self) -> SyntaxFeatures {
+    SyntaxFeatures::CLASS
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoClassAssignVisitor))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::AssignExpr])
   }
 
   #[cfg(feature = "docs")]
@@ -1,14 +1,13 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
-use crate::Program;
+use crate::handler::Handler;
 use deno_ast::swc::ast::BinaryOp::*;
 use deno_ast::swc::ast::Expr::Lit;
 use deno_ast::swc::ast::Lit::Num;
 use deno_ast::swc::ast::UnaryExpr;
 use deno_ast::swc::ast::UnaryOp::Minus;
-use deno_ast::view::{BinExpr, BinaryOp, Expr};
+use deno_ast::view::{BinExpr, BinaryOp, Expr, NodeKind};
 use deno_ast::SourceRanged;
 use derive_more::Display;
 
@@ -38,12 +37,12 @@ impl LintRule for NoCompareNegZero {
     CODE
   }
 
-  fn lint_program_with_ast_view<'view>(
-    &self,
-    context: &mut Context,
-    program: Program<'_>,
-  ) {
-    NoCompareNegZeroHandler.traverse(program, context);
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoCompareNegZeroHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::BinExpr])
   }
 
   #[cfg(feature = "docs")]
@@ -1,9 +1,10 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
-use crate::Program;
-use deno_ast::view::{CondExpr, DoWhileStmt, Expr, ForStmt, IfStmt, WhileStmt};
+use crate::handler::Handler;
+use deno_ast::view::{
+  CondExpr, DoWhileStmt, Expr, ForStmt, IfStmt, NodeKind, WhileStmt,
+};
 use deno_ast::{SourceRange, SourceRanged};
 use derive_more::Display;
 
@@ -37,12 +38,18 @@ impl LintRule for NoCondAssign {
     CODE
   }
 
-  fn lint_program_with_ast_view<'view>(
-    &self,
-    context: &mut Context,
-    program: Program<'_>,
-  ) {
-    NoCondAssignHandler.traverse(program, context);
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoCondAssignHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[
+      NodeKind::IfStmt,
+      NodeKind::WhileStmt,
+      NodeKind::DoWhileStmt,
+      NodeKind::ForStmt,
+      NodeKind::CondExpr,
+    ])
   }
 
   #[cfg(feature = "docs")]
@@ -0,0 +1,242 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::Handler;
+
+use deno_ast::swc::ast::{BlockStmtOrExpr, Expr, Pat};
+use deno_ast::view::{ArrowExpr, NodeKind};
+use deno_ast::SourceRangedForSpanned;
+use derive_more::Display;
+use serde::Deserialize;
+
+#[derive(Debug, Default)]
+pub struct NoConfusingArrow {
+  options: NoConfusingArrowOptions,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct NoConfusingArrowOptions {
+  /// Whether conditional bodies wrapped in parentheses are allowed, which is
+  /// the default.
+  pub allow_parens: bool,
+  /// Whether arrow functions with a single parameter that's a plain
+  /// identifier are allowed, like `a => a ? b : c`.
+  pub only_one_simple_param: bool,
+}
+
+impl Default for NoConfusingArrowOptions {
+  fn default() -> Self {
+    Self {
+      allow_parens: true,
+      only_one_simple_param: false,
+    }
+  }
+}
+
+impl NoConfusingArrow {
+  pub fn new(options: NoConfusingArrowOptions) -> Self {
+    Self { options }
+  }
+}
+
+const CODE: &str = "no-confusing-arrow";
+
+#[derive(Display)]
+enum NoConfusingArrowMessage {
+  #[display(
+    fmt = "Arrow function used ambiguously with a conditional expression"
+  )]
+  Confusing,
+}
+
+#[derive(Display)]
+enum NoConfusingArrowHint {
+  #[display(
+    fmt = "Wrap the conditional expression in parentheses, so `=>` can't be mistaken for `>=`"
+  )]
+  WrapInParens,
+  #[display(fmt = "Use a block body with a `return` statement instead")]
+  UseBlock,
+}
+
+const FIX_DESC: &str = "Wrap the body in parentheses";
+
+impl LintRule for NoConfusingArrow {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "allowParens": { "type": "boolean", "default": true },
+        "onlyOneSimpleParam": { "type": "boolean", "default": false }
+      }
+    }))
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoConfusingArrowHandler {
+      options: &self.options,
+    }))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::ArrowExpr])
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_confusing_arrow.md")
+  }
+}
+
+struct NoConfusingArrowHandler<'a> {
+  options: &'a NoConfusingArrowOptions,
+}
+
+impl Handler for NoConfusingArrowHandler<'_> {
+  fn arrow_expr(&mut self, arrow_expr: &ArrowExpr, ctx: &mut Context) {
+    let arrow_expr = arrow_expr.inner;
+    let BlockStmtOrExpr::Expr(body) = &*arrow_expr.body else {
+      return;
+    };
+    let is_parenthesized = match &**body {
+      Expr::Cond(_) => false,
+      Expr::Paren(paren) if paren.expr.is_cond() => true,
+      _ => return,
+    };
+    if is_parenthesized && self.options.allow_parens {
+      return;
+    }
+    if self.options.only_one_simple_param
+      && matches!(arrow_expr.params.as_slice(), [Pat::Ident(_)])
+    {
+      return;
+    }
+
+    if is_parenthesized {
+      ctx.add_diagnostic_with_hint(
+        body.range(),
+        CODE,
+        NoConfusingArrowMessage::Confusing,
+        NoConfusingArrowHint::UseBlock,
+      );
+    } else {
+      ctx.add_diagnostic_with_fixes(
+        body.range(),
+        CODE,
+        NoConfusingArrowMessage::Confusing,
+        Some(NoConfusingArrowHint::WrapInParens.to_string()),
+        vec![LintFix {
+          description: FIX_DESC.into(),
+          changes: vec![LintFixChange {
+            new_text: format!("({})", body.text_fast(ctx.text_info())).into(),
+            range: body.range(),
+          }],
+        }],
+      );
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_confusing_arrow_valid() {
+    assert_lint_ok! {
+      NoConfusingArrow::default(),
+      "const x = (a) => (1 ? 2 : 3);",
+      "const x = (a) => { return 1 ? 2 : 3; };",
+      "const x = (a) => a >= 1;",
+      "const x = a >= 1 ? () => 2 : () => 3;",
+      "const x = (a) => foo(a ? 1 : 2);",
+    };
+
+    assert_lint_ok! {
+      NoConfusingArrow::new(NoConfusingArrowOptions {
+        only_one_simple_param: true,
+        ..Default::default()
+      }),
+      "const x = a => a ? 1 : 2;",
+      "const x = (a) => a ? 1 : 2;",
+    };
+  }
+
+  #[test]
+  fn no_confusing_arrow_invalid() {
+    assert_lint_err! {
+      NoConfusingArrow::default(),
+      "const x = a => 1 ? 2 : 3;": [
+        {
+          col: 15,
+          message: NoConfusingArrowMessage::Confusing,
+          hint: NoConfusingArrowHint::WrapInParens,
+          fix: (FIX_DESC, "const x = a => (1 ? 2 : 3);"),
+        }
+      ],
+      "const x = (a) => a >= 1 ? 2 : 3;": [
+        {
+          col: 17,
+          message: NoConfusingArrowMessage::Confusing,
+          hint: NoConfusingArrowHint::WrapInParens,
+          fix: (FIX_DESC, "const x = (a) => (a >= 1 ? 2 : 3);"),
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoConfusingArrow::new(NoConfusingArrowOptions {
+        allow_parens: false,
+        ..Default::default()
+      }),
+      "const x = (a) => (1 ? 2 : 3);": [
+        {
+          col: 17,
+          message: NoConfusingArrowMessage::Confusing,
+          hint: NoConfusingArrowHint::UseBlock,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoConfusingArrow::new(NoConfusingArrowOptions {
+        only_one_simple_param: true,
+        ..Default::default()
+      }),
+      "const x = (a, b) => a ? b : 1;": [
+        {
+          col: 20,
+          message: NoConfusingArrowMessage::Confusing,
+          hint: NoConfusingArrowHint::WrapInParens,
+          fix: (FIX_DESC, "const x = (a, b) => (a ? b : 1);"),
+        }
+      ],
+      "const x = ({ a }) => a ? 1 : 2;": [
+        {
+          col: 21,
+          message: NoConfusingArrowMessage::Confusing,
+          hint: NoConfusingArrowHint::WrapInParens,
+          fix: (FIX_DESC, "const x = ({ a }) => (a ? 1 : 2);"),
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn no_confusing_arrow_options() {
+    let options: NoConfusingArrowOptions =
+      serde_json::from_str(r#"{ "onlyOneSimpleParam": true }"#).unwrap();
+    assert!(options.allow_parens);
+    assert!(options.only_one_simple_param);
+  }
+}
@@ -0,0 +1,214 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::Handler;
+
+use deno_ast::view::{
+  AssignExpr, AssignOp, AssignTarget, BinExpr, BinaryOp, Expr, NodeKind,
+  SimpleAssignTarget, TsNonNullExpr,
+};
+use deno_ast::{SourceRange, SourceRanged};
+use derive_more::Display;
+
+#[derive(Debug)]
+pub struct NoConfusingNonNullAssertion;
+
+const CODE: &str = "no-confusing-non-null-assertion";
+
+#[derive(Display)]
+enum NoConfusingNonNullAssertionMessage {
+  #[display(
+    fmt = "Confusing combination of non-null assertion and equality test like `a! == b`, which looks very similar to `a !== b`"
+  )]
+  Equality,
+  #[display(
+    fmt = "Confusing combination of non-null assertion and assignment like `a! = b`, which looks very similar to `a != b`"
+  )]
+  Assignment,
+  #[display(
+    fmt = "Confusing combination of non-null assertion and `{}` operator like `a! {} b`, which might be misread as `!(a {} b)`",
+    _0,
+    _0,
+    _0
+  )]
+  Operator(&'static str),
+}
+
+#[derive(Display)]
+enum NoConfusingNonNullAssertionHint {
+  #[display(
+    fmt = "Remove the unnecessary non-null assertion, or wrap the left-hand side in parentheses"
+  )]
+  RemoveOrWrap,
+  #[display(fmt = "Remove the unnecessary non-null assertion")]
+  Remove,
+}
+
+const REMOVE_FIX_DESC: &str = "Remove the `!`";
+const WRAP_FIX_DESC: &str = "Wrap the left-hand side in parentheses";
+
+impl LintRule for NoConfusingNonNullAssertion {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoConfusingNonNullAssertionHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::BinExpr, NodeKind::AssignExpr])
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_confusing_non_null_assertion.md")
+  }
+}
+
+fn remove_fix(non_null: &TsNonNullExpr) -> LintFix {
+  LintFix {
+    description: REMOVE_FIX_DESC.into(),
+    changes: vec![LintFixChange {
+      new_text: "".into(),
+      range: SourceRange::new(non_null.end() - 1, non_null.end()),
+    }],
+  }
+}
+
+fn wrap_fix(non_null: &TsNonNullExpr) -> LintFix {
+  LintFix {
+    description: WRAP_FIX_DESC.into(),
+    changes: vec![
+      LintFixChange {
+        new_text: "(".into(),
+        range: SourceRange::new(non_null.start(), non_null.start()),
+      },
+      LintFixChange {
+        new_text: ")".into(),
+        range: SourceRange::new(non_null.end(), non_null.end()),
+      },
+    ],
+  }
+}
+
+struct NoConfusingNonNullAssertionHandler;
+
+impl Handler for NoConfusingNonNullAssertionHandler {
+  fn bin_expr(&mut self, bin_expr: &BinExpr, ctx: &mut Context) {
+    let Expr::TsNonNull(non_null) = bin_expr.left else {
+      return;
+    };
+    let message = match bin_expr.op() {
+      BinaryOp::EqEq | BinaryOp::EqEqEq => {
+        NoConfusingNonNullAssertionMessage::Equality
+      }
+      BinaryOp::In => NoConfusingNonNullAssertionMessage::Operator("in"),
+      BinaryOp::InstanceOf => {
+        NoConfusingNonNullAssertionMessage::Operator("instanceof")
+      }
+      _ => return,
+    };
+    ctx.add_diagnostic_with_fixes(
+      bin_expr.range(),
+      CODE,
+      message,
+      Some(NoConfusingNonNullAssertionHint::RemoveOrWrap.to_string()),
+      vec![remove_fix(non_null), wrap_fix(non_null)],
+    );
+  }
+
+  fn assign_expr(&mut self, assign_expr: &AssignExpr, ctx: &mut Context) {
+    if assign_expr.op() != AssignOp::Assign {
+      return;
+    }
+    let AssignTarget::Simple(SimpleAssignTarget::TsNonNull(non_null)) =
+      &assign_expr.left
+    else {
+      return;
+    };
+    ctx.add_diagnostic_with_fixes(
+      assign_expr.range(),
+      CODE,
+      NoConfusingNonNullAssertionMessage::Assignment,
+      Some(NoConfusingNonNullAssertionHint::Remove.to_string()),
+      vec![remove_fix(non_null)],
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_confusing_non_null_assertion_valid() {
+    assert_lint_ok! {
+      NoConfusingNonNullAssertion,
+      "a == b!;",
+      "a! !== b;",
+      "(a!) == b;",
+      "a.b! + c;",
+      "(a!) in b;",
+      "a! < b;",
+      "(a!) = b;",
+      "a! += b;",
+    };
+  }
+
+  #[test]
+  fn no_confusing_non_null_assertion_invalid() {
+    assert_lint_err! {
+      NoConfusingNonNullAssertion,
+      "a! == b;": [
+        {
+          col: 0,
+          message: NoConfusingNonNullAssertionMessage::Equality,
+          hint: NoConfusingNonNullAssertionHint::RemoveOrWrap,
+          fix: (REMOVE_FIX_DESC, "a == b;"),
+          fix: (WRAP_FIX_DESC, "(a!) == b;"),
+        }
+      ],
+      "a.b! === c;": [
+        {
+          col: 0,
+          message: NoConfusingNonNullAssertionMessage::Equality,
+          hint: NoConfusingNonNullAssertionHint::RemoveOrWrap,
+          fix: (REMOVE_FIX_DESC, "a.b === c;"),
+          fix: (WRAP_FIX_DESC, "(a.b!) === c;"),
+        }
+      ],
+      "if (a! in b) {}": [
+        {
+          col: 4,
+          message: NoConfusingNonNullAssertionMessage::Operator("in"),
+          hint: NoConfusingNonNullAssertionHint::RemoveOrWrap,
+          fix: (REMOVE_FIX_DESC, "if (a in b) {}"),
+          fix: (WRAP_FIX_DESC, "if ((a!) in b) {}"),
+        }
+      ],
+      "a! instanceof B;": [
+        {
+          col: 0,
+          message: NoConfusingNonNullAssertionMessage::Operator("instanceof"),
+          hint: NoConfusingNonNullAssertionHint::RemoveOrWrap,
+          fix: (REMOVE_FIX_DESC, "a instanceof B;"),
+          fix: (WRAP_FIX_DESC, "(a!) instanceof B;"),
+        }
+      ],
+      "a.b! = c;": [
+        {
+          col: 0,
+          message: NoConfusingNonNullAssertionMessage::Assignment,
+          hint: NoConfusingNonNullAssertionHint::Remove,
+          fix: (REMOVE_FIX_DESC, "a.b = c;"),
+        }
+      ],
+    };
+  }
+}
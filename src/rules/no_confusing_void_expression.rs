@@ -0,0 +1,518 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::{Handler, Traverse};
+use crate::Program;
+
+use deno_ast::swc::ast::{
+  ArrowExpr as SwcArrowExpr, Class as SwcClass, Function as SwcFunction, Id,
+  ReturnStmt as SwcReturnStmt,
+};
+use deno_ast::swc::visit::{noop_visit_type, Visit, VisitWith};
+use deno_ast::view::{
+  BinaryOp, BlockStmt, BlockStmtOrExpr, CallExpr, Callee, Expr, Node,
+  NodeTrait, Pat, TsKeywordTypeKind, TsType, TsTypeAnn, UnaryOp, VarDeclKind,
+};
+use deno_ast::{SourceRange, SourceRanged};
+use derive_more::Display;
+use serde::Deserialize;
+use std::collections::HashSet;
+
+/// Disallows using the result of a call that returns `void` as a value.
+///
+/// Without type information, a call is considered to return `void` if it is a
+/// method call on the global `console`, or if it calls a function declared in
+/// the same file that is annotated to return `void` or that never returns a
+/// value.
+#[derive(Debug, Default)]
+pub struct NoConfusingVoidExpression {
+  options: NoConfusingVoidExpressionOptions,
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct NoConfusingVoidExpressionOptions {
+  /// Allow void expressions as the body of an arrow function shorthand, e.g.
+  /// `() => console.log("foo")`.
+  pub ignore_arrow_shorthand: bool,
+}
+
+impl NoConfusingVoidExpression {
+  pub fn new(options: NoConfusingVoidExpressionOptions) -> Self {
+    Self { options }
+  }
+}
+
+const CODE: &str = "no-confusing-void-expression";
+
+#[derive(Display)]
+enum NoConfusingVoidExpressionMessage {
+  #[display(
+    fmt = "Placing a void expression inside another expression is forbidden"
+  )]
+  Expression,
+  #[display(fmt = "Returning a void expression from a function is forbidden")]
+  Return,
+  #[display(
+    fmt = "Returning a void expression from an arrow function shorthand is forbidden"
+  )]
+  ArrowShorthand,
+}
+
+#[derive(Display)]
+enum NoConfusingVoidExpressionHint {
+  #[display(fmt = "Move the expression to its own statement")]
+  Expression,
+  #[display(fmt = "Move the expression before the `return` statement")]
+  Return,
+  #[display(fmt = "Add braces around the arrow function body")]
+  ArrowShorthand,
+}
+
+const RETURN_FIX_DESC: &str = "Move the expression before the return";
+const ARROW_FIX_DESC: &str = "Add braces to the arrow function";
+
+impl LintRule for NoConfusingVoidExpression {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "ignoreArrowShorthand": { "type": "boolean", "default": false }
+      }
+    }))
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    // Functions can be called before they are declared, so all of them are
+    // collected before any call is checked.
+    let mut collector = VoidFunctionCollector::default();
+    collector.traverse(program, context);
+    NoConfusingVoidExpressionHandler {
+      options: &self.options,
+      void_functions: collector.void_functions,
+    }
+    .traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_confusing_void_expression.md")
+  }
+}
+
+fn is_void_type_ann(type_ann: &TsTypeAnn) -> bool {
+  matches!(
+    type_ann.type_ann,
+    TsType::TsKeywordType(keyword)
+      if keyword.keyword_kind() == TsKeywordTypeKind::TsVoidKeyword
+  )
+}
+
+/// Looks for `return` statements with a value, skipping nested functions and
+/// classes.
+#[derive(Default)]
+struct ReturnValueFinder {
+  found: bool,
+}
+
+impl Visit for ReturnValueFinder {
+  noop_visit_type!();
+
+  fn visit_return_stmt(&mut self, return_stmt: &SwcReturnStmt) {
+    if return_stmt.arg.is_some() {
+      self.found = true;
+    }
+  }
+
+  fn visit_function(&mut self, _function: &SwcFunction) {}
+
+  fn visit_arrow_expr(&mut self, _arrow_expr: &SwcArrowExpr) {}
+
+  fn visit_class(&mut self, _class: &SwcClass) {}
+}
+
+fn returns_no_value(body: &BlockStmt) -> bool {
+  let mut finder = ReturnValueFinder::default();
+  body.inner.visit_with(&mut finder);
+  !finder.found
+}
+
+fn is_void_function(
+  return_type: Option<&TsTypeAnn>,
+  body: &BlockStmtOrExpr,
+  is_async: bool,
+  is_generator: bool,
+) -> bool {
+  if let Some(return_type) = return_type {
+    return is_void_type_ann(return_type);
+  }
+  if is_async || is_generator {
+    return false;
+  }
+  match body {
+    BlockStmtOrExpr::BlockStmt(block) => returns_no_value(block),
+    BlockStmtOrExpr::Expr(_) => false,
+  }
+}
+
+/// Collects the bindings of functions that return `void`.
+#[derive(Default)]
+struct VoidFunctionCollector {
+  void_functions: HashSet<Id>,
+}
+
+impl Handler for VoidFunctionCollector {
+  fn fn_decl(&mut self, fn_decl: &deno_ast::view::FnDecl, _ctx: &mut Context) {
+    let function = fn_decl.function;
+    let Some(body) = function.body else {
+      return;
+    };
+    if is_void_function(
+      function.return_type,
+      &BlockStmtOrExpr::BlockStmt(body),
+      function.is_async(),
+      function.is_generator(),
+    ) {
+      self.void_functions.insert(fn_decl.ident.inner.to_id());
+    }
+  }
+
+  fn var_declarator(
+    &mut self,
+    declarator: &deno_ast::view::VarDeclarator,
+    _ctx: &mut Context,
+  ) {
+    // Only `const` bindings are considered, since others can be reassigned.
+    let is_const = declarator
+      .parent()
+      .to::<deno_ast::view::VarDecl>()
+      .map_or(false, |decl| decl.decl_kind() == VarDeclKind::Const);
+    let (true, Pat::Ident(binding), Some(init)) =
+      (is_const, &declarator.name, &declarator.init)
+    else {
+      return;
+    };
+    let is_void = match init {
+      Expr::Arrow(arrow) => is_void_function(
+        arrow.return_type,
+        &arrow.body,
+        arrow.is_async(),
+        arrow.is_generator(),
+      ),
+      Expr::Fn(fn_expr) => {
+        let function = fn_expr.function;
+        function.body.map_or(false, |body| {
+          is_void_function(
+            function.return_type,
+            &BlockStmtOrExpr::BlockStmt(body),
+            function.is_async(),
+            function.is_generator(),
+          )
+        })
+      }
+      _ => false,
+    };
+    if is_void {
+      self.void_functions.insert(binding.id.inner.to_id());
+    }
+  }
+}
+
+struct NoConfusingVoidExpressionHandler<'a> {
+  options: &'a NoConfusingVoidExpressionOptions,
+  void_functions: HashSet<Id>,
+}
+
+/// Where a void expression ends up being used.
+enum Position<'a> {
+  /// The value is discarded.
+  Allowed,
+  Return(&'a deno_ast::view::ReturnStmt<'a>),
+  ArrowShorthand(&'a deno_ast::view::ArrowExpr<'a>),
+  Expression,
+}
+
+impl<'a> NoConfusingVoidExpressionHandler<'a> {
+  fn is_void_call(&self, call_expr: &CallExpr, ctx: &Context) -> bool {
+    let Callee::Expr(callee) = &call_expr.callee else {
+      return false;
+    };
+    match callee {
+      Expr::Ident(ident) => self.void_functions.contains(&ident.inner.to_id()),
+      Expr::Member(member) => matches!(
+        &member.obj,
+        Expr::Ident(obj)
+          if obj.sym() == "console"
+            && ctx.scope().is_global(&obj.inner.to_id())
+      ),
+      _ => false,
+    }
+  }
+
+  fn position<'b>(&self, node: Node<'b>) -> Position<'b> {
+    let Some(parent) = node.parent() else {
+      return Position::Allowed;
+    };
+    let range = node.range();
+    match parent {
+      Node::ExprStmt(_) => Position::Allowed,
+      Node::ForStmt(for_stmt)
+        if for_stmt.update.map(|update| update.range()) == Some(range) =>
+      {
+        Position::Allowed
+      }
+      Node::UnaryExpr(unary) if unary.op() == UnaryOp::Void => {
+        Position::Allowed
+      }
+      Node::ParenExpr(_) => self.position(parent),
+      // `foo && console.log(foo)` only evaluates to the void expression when
+      // it is on the right hand side.
+      Node::BinExpr(bin)
+        if matches!(
+          bin.op(),
+          BinaryOp::LogicalAnd
+            | BinaryOp::LogicalOr
+            | BinaryOp::NullishCoalescing
+        ) && bin.right.range() == range =>
+      {
+        self.position(parent)
+      }
+      Node::CondExpr(cond) if cond.test.range() != range => {
+        self.position(parent)
+      }
+      Node::SeqExpr(seq) => {
+        if seq.exprs.last().map(|expr| expr.range()) == Some(range) {
+          self.position(parent)
+        } else {
+          Position::Allowed
+        }
+      }
+      Node::ReturnStmt(return_stmt) => Position::Return(return_stmt),
+      Node::ArrowExpr(arrow) => {
+        let is_void_arrow = arrow.return_type.map_or(false, is_void_type_ann);
+        if self.options.ignore_arrow_shorthand || is_void_arrow {
+          Position::Allowed
+        } else {
+          Position::ArrowShorthand(arrow)
+        }
+      }
+      _ => Position::Expression,
+    }
+  }
+}
+
+fn add_diagnostic(
+  ctx: &mut Context,
+  range: SourceRange,
+  message: NoConfusingVoidExpressionMessage,
+  hint: NoConfusingVoidExpressionHint,
+  fixes: Vec<LintFix>,
+) {
+  ctx.add_diagnostic_with_fixes(
+    range,
+    CODE,
+    message,
+    Some(hint.to_string()),
+    fixes,
+  );
+}
+
+impl<'a> Handler for NoConfusingVoidExpressionHandler<'a> {
+  fn call_expr(&mut self, call_expr: &CallExpr, ctx: &mut Context) {
+    if !self.is_void_call(call_expr, ctx) {
+      return;
+    }
+
+    match self.position(call_expr.as_node()) {
+      Position::Allowed => {}
+      Position::Return(return_stmt) => {
+        // The fix adds a statement, which is only possible inside a block.
+        let fixes = match (&return_stmt.arg, return_stmt.parent()) {
+          (Some(arg), Node::BlockStmt(_)) => vec![LintFix {
+            description: RETURN_FIX_DESC.into(),
+            changes: vec![LintFixChange {
+              new_text: format!("{}; return;", arg.text()).into(),
+              range: return_stmt.range(),
+            }],
+          }],
+          _ => vec![],
+        };
+        add_diagnostic(
+          ctx,
+          call_expr.range(),
+          NoConfusingVoidExpressionMessage::Return,
+          NoConfusingVoidExpressionHint::Return,
+          fixes,
+        );
+      }
+      Position::ArrowShorthand(arrow) => {
+        let fixes = match &arrow.body {
+          BlockStmtOrExpr::Expr(body) => vec![LintFix {
+            description: ARROW_FIX_DESC.into(),
+            changes: vec![LintFixChange {
+              new_text: format!("{{ {}; }}", body.text()).into(),
+              range: body.range(),
+            }],
+          }],
+          BlockStmtOrExpr::BlockStmt(_) => vec![],
+        };
+        add_diagnostic(
+          ctx,
+          call_expr.range(),
+          NoConfusingVoidExpressionMessage::ArrowShorthand,
+          NoConfusingVoidExpressionHint::ArrowShorthand,
+          fixes,
+        );
+      }
+      Position::Expression => {
+        add_diagnostic(
+          ctx,
+          call_expr.range(),
+          NoConfusingVoidExpressionMessage::Expression,
+          NoConfusingVoidExpressionHint::Expression,
+          vec![],
+        );
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_confusing_void_expression_valid() {
+    assert_lint_ok! {
+      NoConfusingVoidExpression::default(),
+      r#"console.log("foo");"#,
+      r#"void console.log("foo");"#,
+      r#"foo && console.log(foo);"#,
+      r#"foo ? console.log(foo) : console.error(bar);"#,
+      r#"(console.log("foo"), 1);"#,
+      r#"for (let i = 0; i < 3; i++, console.log(i)) {}"#,
+      r#"const cb = (): void => console.log("foo");"#,
+      "function foo() { return; } const x = bar();",
+      "function foo() { return 1; } const x = foo();",
+      "const foo = () => 1; const x = foo();",
+      "async function foo() {} const x = foo();",
+      "function* foo() {} const x = foo();",
+      "function foo(): number | undefined {} const x = foo();",
+      "function foo() { function bar() { return 1; } } foo();",
+      // `let` bindings can be reassigned
+      "let foo = () => {}; foo = () => 1; const x = foo();",
+      r#"const console = { log: (s: string) => s }; const x = console.log("a");"#,
+      "function foo() { const inner = () => { return 1; }; } foo();",
+    };
+
+    assert_lint_ok! {
+      NoConfusingVoidExpression::new(NoConfusingVoidExpressionOptions {
+        ignore_arrow_shorthand: true,
+      }),
+      r#"const cb = () => console.log("foo");"#,
+      "arr.forEach((x) => console.log(x));",
+    };
+  }
+
+  #[test]
+  fn no_confusing_void_expression_invalid() {
+    assert_lint_err! {
+      NoConfusingVoidExpression::default(),
+      r#"const x = console.log("foo");"#: [
+        {
+          col: 10,
+          message: NoConfusingVoidExpressionMessage::Expression,
+          hint: NoConfusingVoidExpressionHint::Expression,
+        }
+      ],
+      "function foo() {} const x = foo();": [
+        {
+          col: 28,
+          message: NoConfusingVoidExpressionMessage::Expression,
+          hint: NoConfusingVoidExpressionHint::Expression,
+        }
+      ],
+      "const x = foo(); function foo(): void {}": [
+        {
+          col: 10,
+          message: NoConfusingVoidExpressionMessage::Expression,
+          hint: NoConfusingVoidExpressionHint::Expression,
+        }
+      ],
+      "const foo = function () {}; bar(foo());": [
+        {
+          col: 32,
+          message: NoConfusingVoidExpressionMessage::Expression,
+          hint: NoConfusingVoidExpressionHint::Expression,
+        }
+      ],
+      r#"const x = foo || console.log("foo");"#: [
+        {
+          col: 17,
+          message: NoConfusingVoidExpressionMessage::Expression,
+          hint: NoConfusingVoidExpressionHint::Expression,
+        }
+      ],
+      r#"if (console.log("foo")) {}"#: [
+        {
+          col: 4,
+          message: NoConfusingVoidExpressionMessage::Expression,
+          hint: NoConfusingVoidExpressionHint::Expression,
+        }
+      ],
+      "const log = () => {}; function foo() { return log(); }": [
+        {
+          col: 46,
+          message: NoConfusingVoidExpressionMessage::Return,
+          hint: NoConfusingVoidExpressionHint::Return,
+          fix: (
+            RETURN_FIX_DESC,
+            "const log = () => {}; function foo() { log(); return; }"
+          ),
+        }
+      ],
+      r#"function foo() { if (bar) return console.log("foo"); }"#: [
+        {
+          col: 33,
+          message: NoConfusingVoidExpressionMessage::Return,
+          hint: NoConfusingVoidExpressionHint::Return,
+        }
+      ],
+      r#"const cb = () => console.log("foo");"#: [
+        {
+          col: 17,
+          message: NoConfusingVoidExpressionMessage::ArrowShorthand,
+          hint: NoConfusingVoidExpressionHint::ArrowShorthand,
+          fix: (
+            ARROW_FIX_DESC,
+            r#"const cb = () => { console.log("foo"); };"#
+          ),
+        }
+      ],
+      "arr.forEach((x) => x && console.log(x));": [
+        {
+          col: 24,
+          message: NoConfusingVoidExpressionMessage::ArrowShorthand,
+          hint: NoConfusingVoidExpressionHint::ArrowShorthand,
+          fix: (
+            ARROW_FIX_DESC,
+            "arr.forEach((x) => { x && console.log(x); });"
+          ),
+        }
+      ],
+    };
+  }
+}
@@ -1,6 +1,6 @@
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
-use crate::Program;
+use crate::handler::Handler;
+use deno_ast::view::NodeKind;
 
 use deno_ast::view as ast_view;
 use deno_ast::SourceRanged;
@@ -21,12 +21,12 @@ impl LintRule for NoConsole {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    NoConsoleHandler.traverse(program, context);
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoConsoleHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::MemberExpr, NodeKind::ExprStmt])
   }
 
   #[cfg(feature = "docs")]
@@ -0,0 +1,320 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::handler::Handler;
+
+use deno_ast::swc::ast::{
+  ArrowExpr, Class, Function, Ident, MemberProp as SwcMemberProp,
+};
+use deno_ast::swc::visit::{noop_visit_type, Visit, VisitWith};
+use deno_ast::view::{CallExpr, Callee, Expr, MemberProp, NodeKind};
+use deno_ast::{SourceRange, SourceRangedForSpanned};
+use derive_more::Display;
+use serde::Deserialize;
+
+#[derive(Debug, Default)]
+pub struct NoConsoleSpreadSecrets {
+  options: NoConsoleSpreadSecretsOptions,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct NoConsoleSpreadSecretsOptions {
+  /// Names that look like secrets. A name matches if it contains one of the
+  /// patterns, ignoring case, `_` and `-`, so `apiKey` matches `API_KEY`.
+  pub patterns: Vec<String>,
+}
+
+impl Default for NoConsoleSpreadSecretsOptions {
+  fn default() -> Self {
+    Self {
+      patterns: [
+        "token",
+        "password",
+        "passwd",
+        "secret",
+        "apiKey",
+        "privateKey",
+        "credential",
+      ]
+      .into_iter()
+      .map(String::from)
+      .collect(),
+    }
+  }
+}
+
+impl NoConsoleSpreadSecrets {
+  pub fn new(options: NoConsoleSpreadSecretsOptions) -> Self {
+    Self { options }
+  }
+}
+
+const CODE: &str = "no-console-spread-secrets";
+
+#[derive(Display)]
+enum NoConsoleSpreadSecretsMessage {
+  #[display(fmt = "`{}` looks like a secret and is logged", _0)]
+  Logged(String),
+}
+
+#[derive(Display)]
+enum NoConsoleSpreadSecretsHint {
+  #[display(
+    fmt = "Remove it from the output, or log a masked value or whether it is set instead"
+  )]
+  RemoveOrMask,
+}
+
+impl LintRule for NoConsoleSpreadSecrets {
+  fn tags(&self) -> &'static [&'static str] {
+    &["security"]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "patterns": { "type": "array", "items": { "type": "string" } }
+      }
+    }))
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoConsoleSpreadSecretsHandler {
+      patterns: self
+        .options
+        .patterns
+        .iter()
+        .map(|pattern| normalize(pattern))
+        .collect(),
+    }))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::CallExpr])
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_console_spread_secrets.md")
+  }
+}
+
+/// Lowercases the name and removes separators, so that `API_KEY`, `api-key`
+/// and `apiKey` are the same.
+fn normalize(name: &str) -> String {
+  name
+    .chars()
+    .filter(|c| c.is_alphanumeric())
+    .map(|c| c.to_ascii_lowercase())
+    .collect()
+}
+
+/// Whether the call writes its arguments to the console, like `console.log()`
+/// or `Deno.stdout.write()`.
+fn is_output_call(call_expr: &CallExpr, ctx: &Context) -> bool {
+  let Callee::Expr(Expr::Member(callee)) = &call_expr.callee else {
+    return false;
+  };
+  match &callee.obj {
+    Expr::Ident(obj) => {
+      obj.sym() == "console" && ctx.scope().is_global(&obj.inner.to_id())
+    }
+    Expr::Member(stream) => {
+      let (Expr::Ident(deno), MemberProp::Ident(stream_name)) =
+        (&stream.obj, &stream.prop)
+      else {
+        return false;
+      };
+      let MemberProp::Ident(method) = &callee.prop else {
+        return false;
+      };
+      deno.sym() == "Deno"
+        && ctx.scope().is_global(&deno.inner.to_id())
+        && matches!(stream_name.sym().as_ref(), "stdout" | "stderr")
+        && matches!(method.sym().as_ref(), "write" | "writeSync")
+    }
+    _ => false,
+  }
+}
+
+/// Collects the names in a logged value that look like secrets, without
+/// entering functions, which aren't called by logging them.
+struct SecretFinder<'a> {
+  patterns: &'a [String],
+  found: Vec<(SourceRange, String)>,
+}
+
+impl SecretFinder<'_> {
+  fn check(&mut self, name: &str, range: SourceRange) {
+    let normalized = normalize(name);
+    if self
+      .patterns
+      .iter()
+      .any(|pattern| normalized.contains(pattern.as_str()))
+    {
+      self.found.push((range, name.to_string()));
+    }
+  }
+}
+
+impl Visit for SecretFinder<'_> {
+  noop_visit_type!();
+
+  fn visit_ident(&mut self, ident: &Ident) {
+    self.check(&ident.sym, ident.range());
+  }
+
+  fn visit_member_prop(&mut self, prop: &SwcMemberProp) {
+    match prop {
+      SwcMemberProp::Ident(ident) => self.check(&ident.sym, ident.range()),
+      _ => prop.visit_children_with(self),
+    }
+  }
+
+  fn visit_function(&mut self, _function: &Function) {}
+
+  fn visit_arrow_expr(&mut self, _arrow_expr: &ArrowExpr) {}
+
+  fn visit_class(&mut self, _class: &Class) {}
+}
+
+struct NoConsoleSpreadSecretsHandler {
+  patterns: Vec<String>,
+}
+
+impl Handler for NoConsoleSpreadSecretsHandler {
+  fn call_expr(&mut self, call_expr: &CallExpr, ctx: &mut Context) {
+    if !is_output_call(call_expr, ctx) {
+      return;
+    }
+    let mut finder = SecretFinder {
+      patterns: &self.patterns,
+      found: Vec::new(),
+    };
+    for arg in &call_expr.inner.args {
+      arg.visit_with(&mut finder);
+    }
+    for (range, name) in finder.found {
+      ctx.add_diagnostic_with_hint(
+        range,
+        CODE,
+        NoConsoleSpreadSecretsMessage::Logged(name),
+        NoConsoleSpreadSecretsHint::RemoveOrMask,
+      );
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_console_spread_secrets_valid() {
+    assert_lint_ok! {
+      NoConsoleSpreadSecrets::default(),
+      "console.log(user.name);",
+      "console.log('token refreshed');",
+      "console.log({ hasToken: Boolean(user) });",
+      "console.log(items.map((token) => token.kind));",
+      "const token = getToken(); send(token);",
+      "logger.info(password);",
+      "Deno.stdout.write(encoder.encode(message));",
+      "const console = { log() {} }; console.log(password);",
+    };
+
+    assert_lint_ok! {
+      NoConsoleSpreadSecrets::new(NoConsoleSpreadSecretsOptions {
+        patterns: vec!["ssn".to_string()],
+      }),
+      "console.log(token);",
+    };
+  }
+
+  #[test]
+  fn no_console_spread_secrets_invalid() {
+    assert_lint_err! {
+      NoConsoleSpreadSecrets::default(),
+      "console.log(token);": [
+        {
+          col: 12,
+          message: NoConsoleSpreadSecretsMessage::Logged("token".to_string()),
+          hint: NoConsoleSpreadSecretsHint::RemoveOrMask,
+        }
+      ],
+      "console.log('config', { ...env, apiKey: config.API_KEY });": [
+        {
+          col: 47,
+          message: NoConsoleSpreadSecretsMessage::Logged("API_KEY".to_string()),
+          hint: NoConsoleSpreadSecretsHint::RemoveOrMask,
+        }
+      ],
+      "console.error(`login failed for ${user} with ${userPassword}`);": [
+        {
+          col: 47,
+          message: NoConsoleSpreadSecretsMessage::Logged(
+            "userPassword".to_string()
+          ),
+          hint: NoConsoleSpreadSecretsHint::RemoveOrMask,
+        }
+      ],
+      "console.log({ clientSecret });": [
+        {
+          col: 14,
+          message: NoConsoleSpreadSecretsMessage::Logged(
+            "clientSecret".to_string()
+          ),
+          hint: NoConsoleSpreadSecretsHint::RemoveOrMask,
+        }
+      ],
+      "console.log(...credentials);": [
+        {
+          col: 15,
+          message: NoConsoleSpreadSecretsMessage::Logged(
+            "credentials".to_string()
+          ),
+          hint: NoConsoleSpreadSecretsHint::RemoveOrMask,
+        }
+      ],
+      "Deno.stdout.writeSync(new TextEncoder().encode(session.access_token));": [
+        {
+          col: 55,
+          message: NoConsoleSpreadSecretsMessage::Logged(
+            "access_token".to_string()
+          ),
+          hint: NoConsoleSpreadSecretsHint::RemoveOrMask,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoConsoleSpreadSecrets::new(NoConsoleSpreadSecretsOptions {
+        patterns: vec!["ssn".to_string()],
+      }),
+      "console.log(user.SSN);": [
+        {
+          col: 17,
+          message: NoConsoleSpreadSecretsMessage::Logged("SSN".to_string()),
+          hint: NoConsoleSpreadSecretsHint::RemoveOrMask,
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn no_console_spread_secrets_options() {
+    let options: NoConsoleSpreadSecretsOptions =
+      serde_json::from_str(r#"{ "patterns": ["ssn", "pin"] }"#).unwrap();
+    assert_eq!(options.patterns, vec!["ssn".to_string(), "pin".to_string()]);
+
+    let options: NoConsoleSpreadSecretsOptions =
+      serde_json::from_str("{}").unwrap();
+    assert!(options.patterns.contains(&"apiKey".to_string()));
+  }
+}
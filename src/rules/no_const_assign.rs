@@ -1,11 +1,10 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
-use crate::Program;
+use crate::handler::Handler;
 use deno_ast::view::{
-  ArrayPat, AssignExpr, AssignTarget, AssignTargetPat, Expr, Ident, ObjectPat,
-  ObjectPatProp, Pat, SimpleAssignTarget, UpdateExpr,
+  ArrayPat, AssignExpr, AssignTarget, AssignTargetPat, Expr, Ident, NodeKind,
+  ObjectPat, ObjectPatProp, Pat, SimpleAssignTarget, UpdateExpr,
 };
 use deno_ast::{BindingKind, SourceRange, SourceRanged};
 use derive_more::Display;
@@ -33,12 +32,12 @@ impl LintRule for NoConstAssign {
     CODE
   }
 
-  fn lint_program_with_ast_view<'view>(
-    &self,
-    context: &mut Context,
-    program: Program<'_>,
-  ) {
-    NoConstAssignHandler.traverse(program, context);
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoConstAssignHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::AssignExpr, NodeKind::UpdateExpr])
   }
 
   #[cfg(feature = "docs")]
@@ -2,6 +2,7 @@
 
 use super::program_ref;
 use super::{Context, LintRule};
+use crate::const_eval::ConstEvaluator;
 use crate::Program;
 use crate::ProgramRef;
 use deno_ast::swc::ast::{BinaryOp, CondExpr, Expr, IfStmt, Lit, UnaryOp};
@@ -9,6 +10,7 @@ use deno_ast::swc::visit::{noop_visit_type, Visit, VisitWith};
 use deno_ast::SourceRange;
 use deno_ast::SourceRangedForSpanned;
 use derive_more::Display;
+use std::rc::Rc;
 
 #[derive(Debug)]
 pub struct NoConstantCondition;
@@ -59,11 +61,13 @@ impl LintRule for NoConstantCondition {
 
 struct NoConstantConditionVisitor<'c, 'view> {
   context: &'c mut Context<'view>,
+  evaluator: Rc<ConstEvaluator<'view>>,
 }
 
 impl<'c, 'view: 'c> NoConstantConditionVisitor<'c, 'view> {
   fn new(context: &'c mut Context<'view>) -> Self {
-    Self { context }
+    let evaluator = context.const_evaluator();
+    Self { context, evaluator }
   }
 
   fn add_diagnostic(&mut self, range: SourceRange) {
@@ -76,6 +80,7 @@ impl<'c, 'view: 'c> NoConstantConditionVisitor<'c, 'view> {
   }
 
   fn is_constant(
+    &self,
     node: &Expr,
     parent_node: Option<&Expr>,
     in_boolean_position: bool,
@@ -89,16 +94,16 @@ impl<'c, 'view: 'c> NoConstantConditionVisitor<'c, 'view> {
             None => false,
           }))
           || tpl.exprs.iter().all(|expr| {
-            Self::is_constant(expr, parent_node, in_boolean_position)
+            self.is_constant(expr, parent_node, in_boolean_position)
           })
       }
       // TODO(humancalico) confirm in_boolean_position here
-      Expr::Paren(paren) => Self::is_constant(&paren.expr, Some(node), false),
+      Expr::Paren(paren) => self.is_constant(&paren.expr, Some(node), false),
       Expr::Array(arr) => match parent_node {
         Some(Expr::Bin(bin)) => {
           if bin.op == BinaryOp::Add {
             arr.elems.iter().all(|element| {
-              Self::is_constant(
+              self.is_constant(
                 &element.as_ref().unwrap().expr,
                 parent_node,
                 false,
@@ -115,16 +120,16 @@ impl<'c, 'view: 'c> NoConstantConditionVisitor<'c, 'view> {
           true
         } else {
           (unary.op == UnaryOp::TypeOf && in_boolean_position)
-            || Self::is_constant(&unary.arg, Some(node), true)
+            || self.is_constant(&unary.arg, Some(node), true)
         }
       }
       Expr::Bin(bin) => {
         // This is for LogicalExpression
         if bin.op == BinaryOp::LogicalOr || bin.op == BinaryOp::LogicalAnd {
           let is_left_constant =
-            Self::is_constant(&bin.left, Some(node), in_boolean_position);
+            self.is_constant(&bin.left, Some(node), in_boolean_position);
           let is_right_constant =
-            Self::is_constant(&bin.right, Some(node), in_boolean_position);
+            self.is_constant(&bin.right, Some(node), in_boolean_position);
           let is_left_short_circuit =
             is_left_constant && check_short_circuit(&bin.left, bin.op);
           let is_right_short_circuit =
@@ -136,29 +141,39 @@ impl<'c, 'view: 'c> NoConstantConditionVisitor<'c, 'view> {
         }
         // These are fo regular BinaryExpression
         else if bin.op != BinaryOp::In {
-          Self::is_constant(&bin.left, Some(node), false)
-            && Self::is_constant(&bin.right, Some(node), false)
+          self.is_constant(&bin.left, Some(node), false)
+            && self.is_constant(&bin.right, Some(node), false)
         } else {
           false
         }
       }
       Expr::Assign(assign) => {
         assign.op == deno_ast::swc::ast::AssignOp::Assign
-          && Self::is_constant(&assign.right, Some(node), in_boolean_position)
+          && self.is_constant(&assign.right, Some(node), in_boolean_position)
       }
-      Expr::Seq(seq) => Self::is_constant(
+      Expr::Seq(seq) => self.is_constant(
         &seq.exprs[seq.exprs.len() - 1],
         Some(node),
         in_boolean_position,
       ),
+      Expr::Ident(_) => self.evaluator.eval(node).is_some(),
+      Expr::Cond(cond) => match self.evaluator.eval(&cond.test) {
+        Some(test) => {
+          let branch = if test.is_truthy() {
+            &cond.cons
+          } else {
+            &cond.alt
+          };
+          self.is_constant(branch, Some(node), in_boolean_position)
+        }
+        None => false,
+      },
       Expr::This(_)
       | Expr::Update(_)
       | Expr::Member(_)
       | Expr::SuperProp(_)
-      | Expr::Cond(_)
       | Expr::Call(_)
       | Expr::New(_)
-      | Expr::Ident(_)
       | Expr::TaggedTpl(_)
       | Expr::Class(_)
       | Expr::Yield(_)
@@ -182,7 +197,7 @@ impl<'c, 'view: 'c> NoConstantConditionVisitor<'c, 'view> {
   }
 
   fn report(&mut self, condition: &Expr) {
-    if Self::is_constant(condition, None, true) {
+    if self.is_constant(condition, None, true) {
       let range = condition.range();
       self.add_diagnostic(range);
     }
@@ -355,6 +370,11 @@ mod tests {
       // nested
       r#"if (foo) { if (bar) {} }"#,
       r#"foo ? bar ? 1 : 2 : 3"#,
+
+      // identifiers
+      "let debug = false; if (debug) {}",
+      "function f(undefined) { if (undefined) {} }",
+      "if (a ? 1 : 2) {}",
     };
   }
 
@@ -362,6 +382,20 @@ mod tests {
   fn no_constant_condition_invalid() {
     assert_lint_err! {
       NoConstantCondition,
+      "const x = 1; if (x) {}": [
+        {
+          col: 17,
+          message: NoConstantConditionMessage::Unexpected,
+          hint: NoConstantConditionHint::Remove,
+        }
+      ],
+      "const DEBUG = false; const verbose = !DEBUG; if (verbose) {}": [
+        {
+          col: 49,
+          message: NoConstantConditionMessage::Unexpected,
+          hint: NoConstantConditionHint::Remove,
+        }
+      ],
       r"true ? 1 : 2;": [
         {
           col: 0,
@@ -901,6 +935,32 @@ mod tests {
           message: NoConstantConditionMessage::Unexpected,
           hint: NoConstantConditionHint::Remove,
         }
+      ],
+      "if (undefined) {}": [
+        {
+          col: 4,
+          message: NoConstantConditionMessage::Unexpected,
+          hint: NoConstantConditionHint::Remove,
+        }
+      ],
+      "if (Infinity > 0) {}": [
+        {
+          col: 4,
+          message: NoConstantConditionMessage::Unexpected,
+          hint: NoConstantConditionHint::Remove,
+        }
+      ],
+      "if (false ? a : 1) {}": [
+        {
+          col: 4,
+          message: NoConstantConditionMessage::Unexpected,
+          hint: NoConstantConditionHint::Remove,
+        },
+        {
+          col: 4,
+          message: NoConstantConditionMessage::Unexpected,
+          hint: NoConstantConditionHint::Remove,
+        }
       ]
     };
   }
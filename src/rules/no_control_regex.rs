@@ -1,14 +1,13 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
-use crate::swc_util::extract_regex;
-use crate::Program;
-use deno_ast::view::{CallExpr, Callee, Expr, NewExpr, Regex};
+use crate::handler::Handler;
+use crate::js_regex::ast::{Char, CharKind, Visit};
+use crate::js_regex::RegexSource;
+use crate::syntax_features::SyntaxFeatures;
+use deno_ast::view::{CallExpr, Callee, NewExpr, NodeKind, Regex};
 use deno_ast::{SourceRange, SourceRanged};
 use derive_more::Display;
-use std::iter::Peekable;
-use std::str::Chars;
 
 #[derive(Debug)]
 pub struct NoControlRegex;
@@ -41,12 +40,16 @@ impl LintRule for NoControlRegex {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    NoControlRegexHandler.traverse(program, context);
+  fn required_syntax(&self) -> SyntaxFeatures {
+    SyntaxFeatures::REGEX
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoControlRegexHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::Regex, NodeKind::NewExpr, NodeKind::CallExpr])
   }
 
   #[cfg(feature = "docs")]
@@ -66,82 +69,60 @@ fn add_diagnostic(range: SourceRange, cp: u64, ctx: &mut Context) {
   );
 }
 
-fn check_regex(regex: &str, range: SourceRange, ctx: &mut Context) {
-  let mut iter = regex.chars().peekable();
-  while let Some(ch) = iter.next() {
-    if ch != '\\' {
-      continue;
-    }
-    match iter.next() {
-      Some('x') => {
-        if let Some(cp) = read_hex_n(&mut iter, 2) {
-          if cp <= 31 {
-            add_diagnostic(range, cp, ctx);
-            return;
-          }
-        }
-      }
-      Some('u') => {
-        let cp = match iter.peek() {
-          Some(&'{') => read_hex_until_brace(&mut iter),
-          Some(_) => read_hex_n(&mut iter, 4),
-          _ => None,
-        };
-        if let Some(cp) = cp {
-          if cp <= 31 {
-            add_diagnostic(range, cp, ctx);
-            return;
-          }
-        }
-      }
-      _ => continue,
-    }
-  }
+/// Finds the first control character written as a `\x` or `\u` escape.
+/// Other escapes like `\n` or `\t` are allowed.
+#[derive(Default)]
+struct ControlCharFinder {
+  found: Option<u32>,
 }
 
-/// Read the next n characters and try to parse it as hexadecimal.
-fn read_hex_n(iter: &mut Peekable<Chars>, n: usize) -> Option<u64> {
-  let mut s = String::new();
-  for _ in 0..n {
-    let ch = iter.next()?;
-    s.push(ch);
+impl Visit for ControlCharFinder {
+  fn visit_char(&mut self, c: &Char) {
+    if self.found.is_none()
+      && c.value <= 31
+      && matches!(c.kind, CharKind::Hex | CharKind::Unicode)
+    {
+      self.found = Some(c.value);
+    }
   }
-  u64::from_str_radix(s.as_str(), 16).ok()
 }
 
-/// Read characters until `}` and try to parse it as hexadecimal.
-fn read_hex_until_brace(iter: &mut Peekable<Chars>) -> Option<u64> {
-  iter.next(); // consume `{`
-  let mut s = String::new();
-  loop {
-    let ch = iter.next()?;
-    if ch == '}' {
-      break;
-    }
-    s.push(ch);
+fn check_regex(regex: &RegexSource, ctx: &mut Context) {
+  let Some(pattern) = regex.parse() else {
+    return;
+  };
+  let mut finder = ControlCharFinder::default();
+  pattern.visit_with(&mut finder);
+  if let Some(cp) = finder.found {
+    add_diagnostic(regex.range, cp.into(), ctx);
   }
-  u64::from_str_radix(s.as_str(), 16).ok()
 }
 
 impl Handler for NoControlRegexHandler {
   fn regex(&mut self, regex: &Regex, ctx: &mut Context) {
-    check_regex(regex.inner.exp.to_string().as_str(), regex.range(), ctx);
+    check_regex(&RegexSource::from_regex(regex), ctx);
   }
 
   fn new_expr(&mut self, new_expr: &NewExpr, ctx: &mut Context) {
-    if let Expr::Ident(ident) = new_expr.callee {
-      if let Some(args) = &new_expr.args {
-        if let Some(regex) = extract_regex(ctx.scope(), ident, args) {
-          check_regex(regex.as_str(), new_expr.range(), ctx);
-        }
-      }
+    if let Some(regex) = RegexSource::from_constructor(
+      &new_expr.callee,
+      new_expr.args.unwrap_or_default(),
+      new_expr.range(),
+      ctx,
+    ) {
+      check_regex(&regex, ctx);
     }
   }
 
   fn call_expr(&mut self, call_expr: &CallExpr, ctx: &mut Context) {
-    if let Callee::Expr(Expr::Ident(ident)) = &call_expr.callee {
-      if let Some(regex) = extract_regex(ctx.scope(), ident, call_expr.args) {
-        check_regex(regex.as_str(), call_expr.range(), ctx);
+    if let Callee::Expr(callee) = &call_expr.callee {
+      if let Some(regex) = RegexSource::from_constructor(
+        callee,
+        call_expr.args,
+        call_expr.range(),
+        ctx,
+      ) {
+        check_regex(&regex, ctx);
       }
     }
   }
@@ -151,44 +132,6 @@ impl Handler for NoControlRegexHandler {
 mod tests {
   use super::*;
 
-  #[test]
-  fn test_read_hex_n() {
-    let tests = [
-      (r#"1f"#, Some(0x1f)),
-      (r#"001f"#, Some(0x1f)),
-      (r#"1g"#, None),
-      (r#"001g"#, None),
-      (r#"1ff"#, Some(0x1ff)),
-      (r#"abcd"#, Some(0xabcd)),
-    ];
-
-    for &(input, expected) in tests.iter() {
-      assert_eq!(
-        read_hex_n(&mut input.chars().peekable(), input.len()),
-        expected
-      );
-    }
-  }
-
-  #[test]
-  fn test_read_hex_until_brace() {
-    let tests = [
-      (r#"{1f}"#, Some(0x1f)),
-      (r#"{001f}"#, Some(0x1f)),
-      (r#"{1g}"#, None),
-      (r#"{001g}"#, None),
-      (r#"{1ff}"#, Some(0x1ff)),
-      (r#"{abcd}"#, Some(0xabcd)),
-    ];
-
-    for &(input, expected) in tests.iter() {
-      assert_eq!(
-        read_hex_until_brace(&mut input.chars().peekable()),
-        expected,
-      );
-    }
-  }
-
   #[test]
   fn no_control_regex_valid() {
     assert_lint_ok! {
@@ -1,9 +1,8 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
-use crate::Program;
-use deno_ast::view::DebuggerStmt;
+use crate::handler::Handler;
+use deno_ast::view::{DebuggerStmt, NodeKind};
 use deno_ast::SourceRanged;
 use derive_more::Display;
 
@@ -33,12 +32,12 @@ impl LintRule for NoDebugger {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    NoDebuggerHandler.traverse(program, context);
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoDebuggerHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::DebuggerStmt])
   }
 
   #[cfg(feature = "docs")]
@@ -1,9 +1,8 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
-use crate::Program;
-use deno_ast::view::{Expr, UnaryExpr, UnaryOp};
+use crate::handler::Handler;
+use deno_ast::view::{Expr, NodeKind, UnaryExpr, UnaryOp};
 use deno_ast::SourceRanged;
 use derive_more::Display;
 
@@ -33,12 +32,12 @@ impl LintRule for NoDeleteVar {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    NoDeleteVarHandler.traverse(program, context);
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoDeleteVarHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::UnaryExpr])
   }
 
   #[cfg(feature = "docs")]
@@ -3,13 +3,11 @@
 use super::Context;
 use super::LintRule;
 use crate::handler::Handler;
-use crate::handler::Traverse;
-use crate::Program;
+use deno_ast::view::NodeKind;
 
 use deno_ast::view as ast_view;
 use deno_ast::SourceRanged;
 use if_chain::if_chain;
-use std::convert::TryFrom;
 
 #[derive(Debug)]
 pub struct NoDeprecatedDenoApi;
@@ -25,12 +23,12 @@ impl LintRule for NoDeprecatedDenoApi {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program<'_>,
-  ) {
-    NoDeprecatedDenoApiHandler.traverse(program, context);
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoDeprecatedDenoApiHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::MemberExpr, NodeKind::TsQualifiedName])
   }
 
   #[cfg(feature = "docs")]
@@ -61,253 +59,210 @@ fn extract_symbol<'a>(
   }
 }
 
-enum DeprecatedApi {
-  Buffer,
-  Copy,
-  CustomInspect,
-  File,
-  Iter,
-  IterSync,
-  ReadAll,
-  ReadAllSync,
-  Run,
-  WriteAll,
-  WriteAllSync,
-  Isatty,
-  Metrics,
-  Resources,
-  ServeHttp,
-  Seek,
-  SeekSync,
-  Read,
-  ReadSync,
-  Shutdown,
-  Ftruncate,
-  FtruncateSync,
-  Close,
-  Write,
-  WriteSync,
-  Futime,
-  FutimeSync,
-  Fstat,
-  FstatSync,
+/// A deprecated API in the `Deno` namespace.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeprecatedDenoApi {
+  /// The full name of the API, e.g. `Deno.run`.
+  pub name: &'static str,
+  /// The version of Deno that started warning about uses of the API.
+  pub deprecated_since: &'static str,
+  /// The version of Deno that removed the API, if it has been removed.
+  pub removed_in: Option<&'static str>,
+  /// The APIs to migrate to. If there are several, all of them are suggested.
+  pub replacements: &'static [DenoApiReplacement],
 }
 
-impl TryFrom<(&str, &str)> for DeprecatedApi {
-  type Error = ();
-
-  /// Converts the given member expression (made up of `obj_symbol` and `prop_symbol`) into
-  /// `DeprecatedApi` if it's one of deprecated APIs.
-  /// Note that this conversion does not take shadowing into account, so use this after calling
-  /// `is_global` method on the scope analyzer.
-  fn try_from(
-    (obj_symbol, prop_symbol): (&str, &str),
-  ) -> Result<Self, Self::Error> {
-    if obj_symbol != "Deno" {
-      return Err(());
-    }
-
-    match prop_symbol {
-      "Buffer" => Ok(DeprecatedApi::Buffer),
-      "copy" => Ok(DeprecatedApi::Copy),
-      "customInspect" => Ok(DeprecatedApi::CustomInspect),
-      "iter" => Ok(DeprecatedApi::Iter),
-      "iterSync" => Ok(DeprecatedApi::IterSync),
-      "File" => Ok(DeprecatedApi::File),
-      "readAll" => Ok(DeprecatedApi::ReadAll),
-      "readAllSync" => Ok(DeprecatedApi::ReadAllSync),
-      "run" => Ok(DeprecatedApi::Run),
-      "writeAll" => Ok(DeprecatedApi::WriteAll),
-      "writeAllSync" => Ok(DeprecatedApi::WriteAllSync),
-      "isatty" => Ok(DeprecatedApi::Isatty),
-      "metrics" => Ok(DeprecatedApi::Metrics),
-      "resources" => Ok(DeprecatedApi::Resources),
-      "serveHttp" => Ok(DeprecatedApi::ServeHttp),
-      "seek" => Ok(DeprecatedApi::Seek),
-      "seekSync" => Ok(DeprecatedApi::SeekSync),
-      "read" => Ok(DeprecatedApi::Read),
-      "readSync" => Ok(DeprecatedApi::ReadSync),
-      "shutdown" => Ok(DeprecatedApi::Shutdown),
-      "ftruncate" => Ok(DeprecatedApi::Ftruncate),
-      "ftruncateSync" => Ok(DeprecatedApi::FtruncateSync),
-      "close" => Ok(DeprecatedApi::Close),
-      "write" => Ok(DeprecatedApi::Write),
-      "writeSync" => Ok(DeprecatedApi::WriteSync),
-      "futime" => Ok(DeprecatedApi::Futime),
-      "futimeSync" => Ok(DeprecatedApi::FutimeSync),
-      "fstat" => Ok(DeprecatedApi::Fstat),
-      "fstatSync" => Ok(DeprecatedApi::FstatSync),
-      _ => Err(()),
-    }
-  }
+/// An API that replaces a deprecated one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DenoApiReplacement {
+  /// The name of the replacement, or a URL to it.
+  pub name: &'static str,
+  /// Where the replacement is documented.
+  pub url: Option<&'static str>,
 }
-enum Replacement {
-  NameAndUrl(&'static str, &'static str),
-  Name(&'static str),
-  #[allow(dead_code)]
-  NameAndUrls(Vec<(&'static str, &'static str)>),
-  None,
+
+const fn replacement(name: &'static str) -> DenoApiReplacement {
+  DenoApiReplacement { name, url: None }
 }
 
-impl DeprecatedApi {
-  fn message(&self) -> String {
-    let name = self.get_deprecated_api_name();
-    format!(
-      "`{}` is deprecated and scheduled for removal in Deno 2.0",
-      name,
-    )
+const fn replacement_at(
+  name: &'static str,
+  url: &'static str,
+) -> DenoApiReplacement {
+  DenoApiReplacement {
+    name,
+    url: Some(url),
   }
+}
 
-  fn hint(&self) -> Option<String> {
-    match self.get_replacement() {
-      Replacement::Name(name) => Some(format!("Use `{}` instead", name)),
-      Replacement::NameAndUrl(name, url) => {
-        Some(format!("Use `{}` from {} instead", name, url))
-      }
-      Replacement::NameAndUrls(name_and_urls) => {
-        let mut hint = String::from("Use ");
-        for (i, (name, url)) in name_and_urls.into_iter().enumerate() {
-          if i != 0 {
-            hint.push_str(" and ");
-          }
-          hint.push_str(&format!("`{}` from {}", name, url));
-        }
-        hint.push_str(" instead");
-        Some(hint)
-      }
-      Replacement::None => None,
-    }
+const fn removed_in_2_0(
+  name: &'static str,
+  replacements: &'static [DenoApiReplacement],
+) -> DeprecatedDenoApi {
+  DeprecatedDenoApi {
+    name,
+    deprecated_since: "1.40",
+    removed_in: Some("2.0"),
+    replacements,
   }
+}
+
+const DENO_COMMAND_API: &str = "https://deno.land/api?s=Deno.Command";
+const STD_BUFFER: &str = "https://deno.land/std/io/buffer.ts?s=Buffer";
+const STD_COPY: &str = "https://deno.land/std/io/copy.ts?s=copy";
+const STD_READ_ALL: &str = "https://deno.land/std/io/read_all.ts?s=readAll";
+const STD_READ_ALL_SYNC: &str =
+  "https://deno.land/std/io/read_all.ts?s=readAllSync";
+const STD_WRITE_ALL: &str = "https://deno.land/std/io/write_all.ts?s=writeAll";
+const STD_WRITE_ALL_SYNC: &str =
+  "https://deno.land/std/io/write_all.ts?s=writeAllSync";
+const STREAMS_READABLE_TS: &str = "https://deno.land/api?s=ReadableStream";
+
+const FS_FILE_SEEK: &[DenoApiReplacement] = &[
+  replacement_at(
+    "Deno.FsFile.seek",
+    "https://deno.land/apis?s=Deno.FsFile#method_seek_0",
+  ),
+  replacement_at(
+    "Deno.FsFile.seekSync",
+    "https://deno.land/apis?s=Deno.FsFile#method_seekSync_0",
+  ),
+];
+const FS_FILE_READ: &[DenoApiReplacement] = &[
+  replacement_at(
+    "Deno.FsFile.read",
+    "https://deno.land/apis?s=Deno.FsFile#method_read_0",
+  ),
+  replacement_at(
+    "Deno.FsFile.readSync",
+    "https://deno.land/apis?s=Deno.FsFile#method_readSync_0",
+  ),
+];
+const FS_FILE_FTRUNCATE: &[DenoApiReplacement] = &[
+  replacement_at(
+    "Deno.FsFile.ftruncate",
+    "https://deno.land/apis?s=Deno.FsFile#method_ftruncate_0",
+  ),
+  replacement_at(
+    "Deno.FsFile.ftruncateSync",
+    "https://deno.land/apis?s=Deno.FsFile#method_ftruncateSync_0",
+  ),
+];
+const FS_FILE_WRITE: &[DenoApiReplacement] = &[
+  replacement_at(
+    "Deno.FsFile.write",
+    "https://deno.land/apis?s=Deno.FsFile#method_write_0",
+  ),
+  replacement_at(
+    "Deno.FsFile.writeSync",
+    "https://deno.land/apis?s=Deno.FsFile#method_writeSync_0",
+  ),
+];
+const FS_FILE_FUTIME: &[DenoApiReplacement] = &[
+  replacement_at(
+    "Deno.FsFile.futime",
+    "https://deno.land/apis?s=Deno.FsFile#method_futime_0",
+  ),
+  replacement_at(
+    "Deno.FsFile.futimeSync",
+    "https://deno.land/apis?s=Deno.FsFile#method_futimeSync_0",
+  ),
+];
+const FS_FILE_FSTAT: &[DenoApiReplacement] = &[
+  replacement_at(
+    "Deno.FsFile.fstat",
+    "https://deno.land/apis?s=Deno.FsFile#method_fstat_0",
+  ),
+  replacement_at(
+    "Deno.FsFile.fstatSync",
+    "https://deno.land/apis?s=Deno.FsFile#method_fstatSync_0",
+  ),
+];
+
+/// The deprecated and removed APIs of the `Deno` namespace. This table is the
+/// single source of truth for this rule, and is public so that it can be kept
+/// in sync with the Deno CLI on every release.
+pub static DEPRECATED_DENO_APIS: &[DeprecatedDenoApi] = &[
+  removed_in_2_0("Deno.Buffer", &[replacement(STD_BUFFER)]),
+  removed_in_2_0("Deno.copy", &[replacement(STD_COPY)]),
+  removed_in_2_0(
+    "Deno.customInspect",
+    &[replacement("Symbol.for(\"Deno.customInspect\")")],
+  ),
+  removed_in_2_0("Deno.iter", &[replacement(STREAMS_READABLE_TS)]),
+  removed_in_2_0("Deno.iterSync", &[replacement(STREAMS_READABLE_TS)]),
+  removed_in_2_0("Deno.File", &[replacement("Deno.FsFile")]),
+  removed_in_2_0("Deno.readAll", &[replacement_at("readAll", STD_READ_ALL)]),
+  removed_in_2_0(
+    "Deno.readAllSync",
+    &[replacement_at("readAllSync", STD_READ_ALL_SYNC)],
+  ),
+  removed_in_2_0(
+    "Deno.run",
+    &[replacement_at("Deno.Command", DENO_COMMAND_API)],
+  ),
+  removed_in_2_0(
+    "Deno.writeAll",
+    &[replacement_at("writeAll", STD_WRITE_ALL)],
+  ),
+  removed_in_2_0(
+    "Deno.writeAllSync",
+    &[replacement_at("writeAllSync", STD_WRITE_ALL_SYNC)],
+  ),
+  removed_in_2_0("Deno.isatty", &[replacement("Deno.stdin.isTerminal")]),
+  removed_in_2_0("Deno.metrics", &[]),
+  removed_in_2_0("Deno.resources", &[]),
+  removed_in_2_0("Deno.serveHttp", &[replacement("Deno.serve")]),
+  removed_in_2_0("Deno.seek", FS_FILE_SEEK),
+  removed_in_2_0("Deno.seekSync", FS_FILE_SEEK),
+  removed_in_2_0("Deno.read", FS_FILE_READ),
+  removed_in_2_0("Deno.readSync", FS_FILE_READ),
+  removed_in_2_0("Deno.shutdown", &[replacement("Deno.Conn.closeWrite")]),
+  removed_in_2_0("Deno.ftruncate", FS_FILE_FTRUNCATE),
+  removed_in_2_0("Deno.ftruncateSync", FS_FILE_FTRUNCATE),
+  removed_in_2_0("Deno.close", &[replacement(".close()")]),
+  removed_in_2_0("Deno.write", FS_FILE_WRITE),
+  removed_in_2_0("Deno.writeSync", FS_FILE_WRITE),
+  removed_in_2_0("Deno.futime", FS_FILE_FUTIME),
+  removed_in_2_0("Deno.futimeSync", FS_FILE_FUTIME),
+  removed_in_2_0("Deno.fstat", FS_FILE_FSTAT),
+  removed_in_2_0("Deno.fstatSync", FS_FILE_FSTAT),
+];
+
+/// Looks up a deprecated API by its full name, e.g. `Deno.run`.
+pub fn find_deprecated_deno_api(
+  name: &str,
+) -> Option<&'static DeprecatedDenoApi> {
+  DEPRECATED_DENO_APIS.iter().find(|api| api.name == name)
+}
 
-  fn get_deprecated_api_name(&self) -> &'static str {
-    use DeprecatedApi::*;
-    match *self {
-      Buffer => "Deno.Buffer",
-      Copy => "Deno.copy",
-      CustomInspect => "Deno.customInspect",
-      Iter => "Deno.iter",
-      IterSync => "Deno.iterSync",
-      File => "Deno.File",
-      ReadAll => "Deno.readAll",
-      ReadAllSync => "Deno.readAllSync",
-      Run => "Deno.run",
-      WriteAll => "Deno.writeAll",
-      WriteAllSync => "Deno.writeAllSync",
-      Isatty => "Deno.isatty",
-      Metrics => "Deno.metrics",
-      Resources => "Deno.resources",
-      ServeHttp => "Deno.serveHttp",
-      Seek => "Deno.seek",
-      SeekSync => "Deno.seekSync",
-      Read => "Deno.read",
-      ReadSync => "Deno.readSync",
-      Shutdown => "Deno.shutdown",
-      Ftruncate => "Deno.ftruncate",
-      FtruncateSync => "Deno.ftruncateSync",
-      Close => "Deno.close",
-      Write => "Deno.write",
-      WriteSync => "Deno.writeSync",
-      Futime => "Deno.futime",
-      FutimeSync => "Deno.futimeSync",
-      Fstat => "Deno.fstat",
-      FstatSync => "Deno.fstatSync",
+impl DeprecatedDenoApi {
+  pub fn message(&self) -> String {
+    match self.removed_in {
+      Some(removed_in) => format!(
+        "`{}` was deprecated in Deno {} and removed in Deno {}",
+        self.name, self.deprecated_since, removed_in
+      ),
+      None => format!(
+        "`{}` is deprecated since Deno {}",
+        self.name, self.deprecated_since
+      ),
     }
   }
 
-  fn get_replacement(&self) -> Replacement {
-    const DENO_COMMAND_API: &str = "https://deno.land/api?s=Deno.Command";
-    const STD_BUFFER: &str = "https://deno.land/std/io/buffer.ts?s=Buffer";
-    const STD_COPY: &str = "https://deno.land/std/io/copy.ts?s=copy";
-    const STD_READ_ALL: &str = "https://deno.land/std/io/read_all.ts?s=readAll";
-    const STD_READ_ALL_SYNC: &str =
-      "https://deno.land/std/io/read_all.ts?s=readAllSync";
-    const STD_WRITE_ALL: &str =
-      "https://deno.land/std/io/write_all.ts?s=writeAll";
-    const STD_WRITE_ALL_SYNC: &str =
-      "https://deno.land/std/io/write_all.ts?s=writeAllSync";
-    const STREAMS_READABLE_TS: &str = "https://deno.land/api?s=ReadableStream";
-
-    use DeprecatedApi::*;
-    use Replacement::*;
-    match *self {
-      Buffer => Name(STD_BUFFER),
-      Copy => Name(STD_COPY),
-      CustomInspect => Name("Symbol.for(\"Deno.customInspect\")"),
-      Iter => Name(STREAMS_READABLE_TS),
-      IterSync => Name(STREAMS_READABLE_TS),
-      File => Name("Deno.FsFile"),
-      ReadAll => NameAndUrl("readAll", STD_READ_ALL),
-      ReadAllSync => NameAndUrl("readAllSync", STD_READ_ALL_SYNC),
-      Run => NameAndUrl("Deno.Command", DENO_COMMAND_API),
-      WriteAll => NameAndUrl("writeAll", STD_WRITE_ALL),
-      WriteAllSync => NameAndUrl("writeAllSync", STD_WRITE_ALL_SYNC),
-      Isatty => Name("Deno.stdin.isTerminal"),
-      Metrics => None,
-      Resources => None,
-      ServeHttp => Name("Deno.serve"),
-      Seek | SeekSync => NameAndUrls(vec![
-        (
-          "Deno.FsFile.seek",
-          "https://deno.land/apis?s=Deno.FsFile#method_seek_0",
-        ),
-        (
-          "Deno.FsFile.seekSync",
-          "https://deno.land/apis?s=Deno.FsFile#method_seekSync_0",
-        ),
-      ]),
-      Read | ReadSync => NameAndUrls(vec![
-        (
-          "Deno.FsFile.read",
-          "https://deno.land/apis?s=Deno.FsFile#method_read_0",
-        ),
-        (
-          "Deno.FsFile.readSync",
-          "https://deno.land/apis?s=Deno.FsFile#method_readSync_0",
-        ),
-      ]),
-      Ftruncate | FtruncateSync => NameAndUrls(vec![
-        (
-          "Deno.FsFile.ftruncate",
-          "https://deno.land/apis?s=Deno.FsFile#method_ftruncate_0",
-        ),
-        (
-          "Deno.FsFile.ftruncateSync",
-          "https://deno.land/apis?s=Deno.FsFile#method_ftruncateSync_0",
-        ),
-      ]),
-      Write | WriteSync => NameAndUrls(vec![
-        (
-          "Deno.FsFile.write",
-          "https://deno.land/apis?s=Deno.FsFile#method_write_0",
-        ),
-        (
-          "Deno.FsFile.writeSync",
-          "https://deno.land/apis?s=Deno.FsFile#method_writeSync_0",
-        ),
-      ]),
-      Futime | FutimeSync => NameAndUrls(vec![
-        (
-          "Deno.FsFile.futime",
-          "https://deno.land/apis?s=Deno.FsFile#method_futime_0",
-        ),
-        (
-          "Deno.FsFile.futimeSync",
-          "https://deno.land/apis?s=Deno.FsFile#method_futimeSync_0",
-        ),
-      ]),
-      Fstat | FstatSync => NameAndUrls(vec![
-        (
-          "Deno.FsFile.fstat",
-          "https://deno.land/apis?s=Deno.FsFile#method_fstat_0",
-        ),
-        (
-          "Deno.FsFile.fstatSync",
-          "https://deno.land/apis?s=Deno.FsFile#method_fstatSync_0",
-        ),
-      ]),
-      Shutdown => Name("Deno.Conn.closeWrite"),
-      Close => Name(".close()"),
+  pub fn hint(&self) -> Option<String> {
+    if self.replacements.is_empty() {
+      return None;
     }
+    let replacements = self
+      .replacements
+      .iter()
+      .map(|replacement| match replacement.url {
+        Some(url) => format!("`{}` from {}", replacement.name, url),
+        None => format!("`{}`", replacement.name),
+      })
+      .collect::<Vec<_>>();
+    Some(format!("Use {} instead", replacements.join(" and ")))
   }
 }
 
@@ -327,10 +282,11 @@ impl Handler for NoDeprecatedDenoApiHandler {
     use deno_ast::view::Expr;
     if_chain! {
       if let Expr::Ident(obj) = &member_expr.obj;
+      if obj.sym() == "Deno";
       if ctx.scope().is_global(&obj.inner.to_id());
-      let obj_symbol: &str = obj.sym();
       if let Some(prop_symbol) = extract_symbol(&member_expr.prop);
-      if let Ok(deprecated_api) = DeprecatedApi::try_from((obj_symbol, prop_symbol));
+      if let Some(deprecated_api) =
+        find_deprecated_deno_api(&format!("Deno.{}", prop_symbol));
       then {
         match deprecated_api.hint() {
           Some(hint) => {
@@ -360,7 +316,7 @@ impl Handler for NoDeprecatedDenoApiHandler {
       if qualified_name.right.sym() == "File";
       if ctx.scope().is_global(&ident.inner.to_id());
       then {
-        let deprecated_api = DeprecatedApi::File;
+        let deprecated_api = find_deprecated_deno_api("Deno.File").unwrap();
         ctx.add_diagnostic_with_hint(
           qualified_name.range(),
           CODE,
@@ -464,104 +420,104 @@ mod tests {
 
   #[test]
   fn no_deprecated_deno_api_invalid() {
-    use DeprecatedApi::*;
+    let api = |name| find_deprecated_deno_api(name).unwrap();
 
     assert_lint_err! {
       NoDeprecatedDenoApi,
       "new Deno.Buffer();": [
         {
           col: 4,
-          message: Buffer.message(),
-          hint: Buffer.hint().unwrap()
+          message: api("Deno.Buffer").message(),
+          hint: api("Deno.Buffer").hint().unwrap()
         }
       ],
       "Deno.readAll(reader);": [
         {
           col: 0,
-          message: ReadAll.message(),
-          hint: ReadAll.hint().unwrap()
+          message: api("Deno.readAll").message(),
+          hint: api("Deno.readAll").hint().unwrap()
         }
       ],
       "Deno.readAllSync(reader);": [
         {
           col: 0,
-          message: ReadAllSync.message(),
-          hint: ReadAllSync.hint().unwrap()
+          message: api("Deno.readAllSync").message(),
+          hint: api("Deno.readAllSync").hint().unwrap()
         }
       ],
       "Deno.writeAll(writer, data);": [
         {
           col: 0,
-          message: WriteAll.message(),
-          hint: WriteAll.hint().unwrap()
+          message: api("Deno.writeAll").message(),
+          hint: api("Deno.writeAll").hint().unwrap()
         }
       ],
       "Deno.writeAllSync(writer, data);": [
         {
           col: 0,
-          message: WriteAllSync.message(),
-          hint: WriteAllSync.hint().unwrap()
+          message: api("Deno.writeAllSync").message(),
+          hint: api("Deno.writeAllSync").hint().unwrap()
         }
       ],
       "Deno.iter(reader);": [
         {
           col: 0,
-          message: Iter.message(),
-          hint: Iter.hint().unwrap()
+          message: api("Deno.iter").message(),
+          hint: api("Deno.iter").hint().unwrap()
         }
       ],
       "Deno.iterSync(reader);": [
         {
           col: 0,
-          message: IterSync.message(),
-          hint: IterSync.hint().unwrap()
+          message: api("Deno.iterSync").message(),
+          hint: api("Deno.iterSync").hint().unwrap()
         }
       ],
       "Deno.copy(reader, writer);": [
         {
           col: 0,
-          message: Copy.message(),
-          hint: Copy.hint().unwrap()
+          message: api("Deno.copy").message(),
+          hint: api("Deno.copy").hint().unwrap()
         }
       ],
       "Deno.customInspect;": [
         {
           col: 0,
-          message: CustomInspect.message(),
-          hint: CustomInspect.hint().unwrap()
+          message: api("Deno.customInspect").message(),
+          hint: api("Deno.customInspect").hint().unwrap()
         }
       ],
       "Deno.File;": [
         {
           col: 0,
-          message: File.message(),
-          hint: File.hint().unwrap()
+          message: api("Deno.File").message(),
+          hint: api("Deno.File").hint().unwrap()
         }
       ],
       "let file: Deno.File;": [
         {
           col: 10,
-          message: File.message(),
-          hint: File.hint().unwrap()
+          message: api("Deno.File").message(),
+          hint: api("Deno.File").hint().unwrap()
         }
       ],
       "Deno.run(options);": [
         {
           col: 0,
-          message: Run.message(),
-          hint: Run.hint().unwrap()
+          message: api("Deno.run").message(),
+          hint: api("Deno.run").hint().unwrap()
         }
       ],
       "Deno.metrics();": [
         {
           col: 0,
-          message: Metrics.message(),
+          message: api("Deno.metrics").message(),
         }
       ],
       "Deno.resources();": [
         {
           col: 0,
-          message: Resources.message(),
+          message: api("Deno.resources").message(),
         }
       ],
 
@@ -569,64 +525,64 @@ mod tests {
       r#"new Deno["Buffer"]();"#: [
         {
           col: 4,
-          message: Buffer.message(),
-          hint: Buffer.hint().unwrap()
+          message: api("Deno.Buffer").message(),
+          hint: api("Deno.Buffer").hint().unwrap()
         }
       ],
       r#"Deno["readAll"](reader);"#: [
         {
           col: 0,
-          message: ReadAll.message(),
-          hint: ReadAll.hint().unwrap()
+          message: api("Deno.readAll").message(),
+          hint: api("Deno.readAll").hint().unwrap()
         }
       ],
       r#"Deno["readAllSync"](reader);"#: [
         {
           col: 0,
-          message: ReadAllSync.message(),
-          hint: ReadAllSync.hint().unwrap()
+          message: api("Deno.readAllSync").message(),
+          hint: api("Deno.readAllSync").hint().unwrap()
         }
       ],
       r#"Deno["writeAll"](writer, data);"#: [
         {
           col: 0,
-          message: WriteAll.message(),
-          hint: WriteAll.hint().unwrap()
+          message: api("Deno.writeAll").message(),
+          hint: api("Deno.writeAll").hint().unwrap()
         }
       ],
       r#"Deno["writeAllSync"](writer, data);"#: [
         {
           col: 0,
-          message: WriteAllSync.message(),
-          hint: WriteAllSync.hint().unwrap()
+          message: api("Deno.writeAllSync").message(),
+          hint: api("Deno.writeAllSync").hint().unwrap()
         }
       ],
       r#"Deno["iter"](reader);"#: [
         {
           col: 0,
-          message: Iter.message(),
-          hint: Iter.hint().unwrap()
+          message: api("Deno.iter").message(),
+          hint: api("Deno.iter").hint().unwrap()
         }
       ],
       r#"Deno["iterSync"](reader);"#: [
         {
           col: 0,
-          message: IterSync.message(),
-          hint: IterSync.hint().unwrap()
+          message: api("Deno.iterSync").message(),
+          hint: api("Deno.iterSync").hint().unwrap()
         }
       ],
       r#"Deno["copy"](reader, writer);"#: [
         {
           col: 0,
-          message: Copy.message(),
-          hint: Copy.hint().unwrap()
+          message: api("Deno.copy").message(),
+          hint: api("Deno.copy").hint().unwrap()
         }
       ],
       r#"Deno["customInspect"];"#: [
         {
           col: 0,
-          message: CustomInspect.message(),
-          hint: CustomInspect.hint().unwrap()
+          message: api("Deno.customInspect").message(),
+          hint: api("Deno.customInspect").hint().unwrap()
         }
       ],
 
@@ -634,71 +590,71 @@ mod tests {
       r#"new Deno[`Buffer`]();"#: [
         {
           col: 4,
-          message: Buffer.message(),
-          hint: Buffer.hint().unwrap()
+          message: api("Deno.Buffer").message(),
+          hint: api("Deno.Buffer").hint().unwrap()
         }
       ],
       r#"Deno[`readAll`](reader);"#: [
         {
           col: 0,
-          message: ReadAll.message(),
-          hint: ReadAll.hint().unwrap()
+          message: api("Deno.readAll").message(),
+          hint: api("Deno.readAll").hint().unwrap()
         }
       ],
       r#"Deno[`readAllSync`](reader);"#: [
         {
           col: 0,
-          message: ReadAllSync.message(),
-          hint: ReadAllSync.hint().unwrap()
+          message: api("Deno.readAllSync").message(),
+          hint: api("Deno.readAllSync").hint().unwrap()
         }
       ],
       r#"Deno[`writeAll`](writer, data);"#: [
         {
           col: 0,
-          message: WriteAll.message(),
-          hint: WriteAll.hint().unwrap()
+          message: api("Deno.writeAll").message(),
+          hint: api("Deno.writeAll").hint().unwrap()
         }
       ],
       r#"Deno[`writeAllSync`](writer, data);"#: [
         {
           col: 0,
-          message: WriteAllSync.message(),
-          hint: WriteAllSync.hint().unwrap()
+          message: api("Deno.writeAllSync").message(),
+          hint: api("Deno.writeAllSync").hint().unwrap()
         }
       ],
       r#"Deno[`iter`](reader);"#: [
         {
           col: 0,
-          message: Iter.message(),
-          hint: Iter.hint().unwrap()
+          message: api("Deno.iter").message(),
+          hint: api("Deno.iter").hint().unwrap()
         }
       ],
       r#"Deno[`iterSync`](reader);"#: [
         {
           col: 0,
-          message: IterSync.message(),
-          hint: IterSync.hint().unwrap()
+          message: api("Deno.iterSync").message(),
+          hint: api("Deno.iterSync").hint().unwrap()
         }
       ],
       r#"Deno[`copy`](reader);"#: [
         {
           col: 0,
-          message: Copy.message(),
-          hint: Copy.hint().unwrap()
+          message: api("Deno.copy").message(),
+          hint: api("Deno.copy").hint().unwrap()
         }
       ],
       r#"Deno[`customInspect`];"#: [
         {
           col: 0,
-          message: CustomInspect.message(),
-          hint: CustomInspect.hint().unwrap()
+          message: api("Deno.customInspect").message(),
+          hint: api("Deno.customInspect").hint().unwrap()
         }
       ],
       r#"Deno[`File`];"#: [
         {
           col: 0,
-          message: File.message(),
-          hint: File.hint().unwrap()
+          message: api("Deno.File").message(),
+          hint: api("Deno.File").hint().unwrap()
         }
       ],
 
@@ -712,8 +668,8 @@ Deno.readAll(reader);
         {
           line: 5,
           col: 0,
-          message: ReadAll.message(),
-          hint: ReadAll.hint().unwrap()
+          message: api("Deno.readAll").message(),
+          hint: api("Deno.readAll").hint().unwrap()
         }
       ],
     }
@@ -739,11 +695,43 @@ Deno.readAll(reader);
     ];
 
     for test in tests {
-      let hint = DeprecatedApi::try_from(("Deno", test.0))
+      let hint = find_deprecated_deno_api(&format!("Deno.{}", test.0))
         .unwrap()
         .hint()
         .unwrap();
       assert_eq!(hint, test.1);
     }
   }
+
+  #[test]
+  fn expect_deprecated_api_message() {
+    let api = find_deprecated_deno_api("Deno.run").unwrap();
+    assert_eq!(
+      api.message(),
+      "`Deno.run` was deprecated in Deno 1.40 and removed in Deno 2.0"
+    );
+    let api = DeprecatedDenoApi {
+      removed_in: None,
+      ..*api
+    };
+    assert_eq!(api.message(), "`Deno.run` is deprecated since Deno 1.40");
+    assert_eq!(
+      find_deprecated_deno_api("Deno.metrics").unwrap().hint(),
+      None
+    );
+    assert_eq!(
+      find_deprecated_deno_api("Deno.seek").unwrap().hint().unwrap(),
+      "Use `Deno.FsFile.seek` from https://deno.land/apis?s=Deno.FsFile#method_seek_0 and `Deno.FsFile.seekSync` from https://deno.land/apis?s=Deno.FsFile#method_seekSync_0 instead"
+    );
+  }
+
+  #[test]
+  fn deprecated_deno_apis_table() {
+    let mut names = std::collections::HashSet::new();
+    for api in DEPRECATED_DENO_APIS {
+      assert!(api.name.starts_with("Deno."), "{}", api.name);
+      assert!(names.insert(api.name), "duplicate entry for {}", api.name);
+    }
+    assert_eq!(find_deprecated_deno_api("Deno.readTextFile"), None);
+  }
 }
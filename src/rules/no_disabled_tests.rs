@@ -0,0 +1,132 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::handler::Handler;
+use crate::testing::TestCall;
+use deno_ast::view::{CallExpr, Expr, Lit, NodeKind};
+use deno_ast::SourceRanged;
+use derive_more::Display;
+
+#[derive(Debug)]
+pub struct NoDisabledTests;
+
+const CODE: &str = "no-disabled-tests";
+
+#[derive(Display)]
+enum NoDisabledTestsMessage {
+  #[display(fmt = "Disabled test with `.{}`", _0)]
+  Modifier(String),
+  #[display(fmt = "Disabled test with `ignore: true`")]
+  IgnoreOption,
+}
+
+#[derive(Display)]
+enum NoDisabledTestsHint {
+  #[display(
+    fmt = "Fix the test and enable it again, remove it, or only ignore it under a condition"
+  )]
+  Enable,
+}
+
+impl LintRule for NoDisabledTests {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn aliases(&self) -> &'static [&'static str] {
+    &["jest/no-disabled-tests"]
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoDisabledTestsHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::CallExpr])
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_disabled_tests.md")
+  }
+}
+
+struct NoDisabledTestsHandler;
+
+impl Handler for NoDisabledTestsHandler {
+  fn call_expr(&mut self, call_expr: &CallExpr, ctx: &mut Context) {
+    let Some(test) = TestCall::from_call(call_expr, ctx) else {
+      return;
+    };
+
+    if let (Some(name @ ("ignore" | "skip")), Some(modifier)) =
+      (test.modifier_name(), test.modifier)
+    {
+      ctx.add_diagnostic_with_hint(
+        modifier.range(),
+        CODE,
+        NoDisabledTestsMessage::Modifier(name.to_string()),
+        NoDisabledTestsHint::Enable,
+      );
+    }
+
+    // `ignore: Deno.build.os === "windows"` only disables the test sometimes,
+    // which is fine.
+    if let Some(ignore) = test.option("ignore") {
+      if matches!(ignore.value, Expr::Lit(Lit::Bool(value)) if value.value()) {
+        ctx.add_diagnostic_with_hint(
+          ignore.range(),
+          CODE,
+          NoDisabledTestsMessage::IgnoreOption,
+          NoDisabledTestsHint::Enable,
+        );
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_disabled_tests_valid() {
+    assert_lint_ok! {
+      NoDisabledTests,
+      r#"Deno.test("adds", () => {});"#,
+      r#"Deno.test({ name: "adds", ignore: Deno.build.os === "windows", fn() {} });"#,
+      r#"Deno.test({ name: "adds", ignore: false, fn() {} });"#,
+      r#"Deno.test.only("adds", () => {});"#,
+      r#"it("adds", () => {});"#,
+      r#"parser.skip(2);"#,
+    };
+  }
+
+  #[test]
+  fn no_disabled_tests_invalid() {
+    assert_lint_err! {
+      NoDisabledTests,
+      r#"Deno.test.ignore("adds", () => {});"#: [
+        {
+          col: 10,
+          message: NoDisabledTestsMessage::Modifier("ignore".to_string()),
+          hint: NoDisabledTestsHint::Enable,
+        }
+      ],
+      r#"it.skip("adds", () => {});"#: [
+        {
+          col: 3,
+          message: NoDisabledTestsMessage::Modifier("skip".to_string()),
+          hint: NoDisabledTestsHint::Enable,
+        }
+      ],
+      r#"Deno.test({ name: "adds", ignore: true, fn() {} });"#: [
+        {
+          col: 26,
+          message: NoDisabledTestsMessage::IgnoreOption,
+          hint: NoDisabledTestsHint::Enable,
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,130 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::Handler;
+use crate::syntax_features::SyntaxFeatures;
+use deno_ast::view::{NodeKind, Regex};
+use deno_ast::{SourceRange, SourceRanged};
+use derive_more::Display;
+
+#[derive(Debug)]
+pub struct NoDivRegex;
+
+const CODE: &str = "no-div-regex";
+const FIX_DESC: &str = "Wrap the `=` in a character class";
+
+#[derive(Display)]
+enum NoDivRegexMessage {
+  #[display(
+    fmt = "A regular expression literal can be confused with the `/=` operator"
+  )]
+  Confusing,
+}
+
+#[derive(Display)]
+enum NoDivRegexHint {
+  #[display(fmt = "Use `[=]` at the start of the pattern instead")]
+  UseClass,
+}
+
+impl LintRule for NoDivRegex {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn required_syntax(&self) -> SyntaxFeatures {
+    SyntaxFeatures::REGEX
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoDivRegexHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::Regex])
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_div_regex.md")
+  }
+}
+
+struct NoDivRegexHandler;
+
+impl Handler for NoDivRegexHandler {
+  fn regex(&mut self, regex: &Regex, ctx: &mut Context) {
+    if !regex.inner.exp.starts_with('=') {
+      return;
+    }
+    // Skip the opening `/`.
+    let start = regex.start() + 1;
+    ctx.add_diagnostic_with_fixes(
+      regex.range(),
+      CODE,
+      NoDivRegexMessage::Confusing,
+      Some(NoDivRegexHint::UseClass.to_string()),
+      vec![LintFix {
+        description: FIX_DESC.into(),
+        changes: vec![LintFixChange {
+          new_text: "[=]".into(),
+          range: SourceRange::new(start, start + 1),
+        }],
+      }],
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_div_regex_valid() {
+    assert_lint_ok! {
+      NoDivRegex,
+      "const re = /foo/;",
+      "const re = /[=]foo/;",
+      "const re = /\\=foo/;",
+      "const re = /foo=/;",
+      "const re = new RegExp('=foo');",
+      "let a = 1; a /= 2;",
+    };
+  }
+
+  #[test]
+  fn no_div_regex_invalid() {
+    assert_lint_err! {
+      NoDivRegex,
+      "const re = /=foo/;": [
+        {
+          col: 11,
+          message: NoDivRegexMessage::Confusing,
+          hint: NoDivRegexHint::UseClass,
+          fix: (FIX_DESC, "const re = /[=]foo/;"),
+        }
+      ],
+      "function f() { return /=foo/g.test(bar); }": [
+        {
+          col: 22,
+          message: NoDivRegexMessage::Confusing,
+          hint: NoDivRegexHint::UseClass,
+          fix: (FIX_DESC, "function f() { return /[=]foo/g.test(bar); }"),
+        }
+      ],
+      "const re = /==/;": [
+        {
+          col: 11,
+          message: NoDivRegexMessage::Confusing,
+          hint: NoDivRegexHint::UseClass,
+          fix: (FIX_DESC, "const re = /[=]=/;"),
+        }
+      ],
+    };
+  }
+}
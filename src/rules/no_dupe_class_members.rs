@@ -2,15 +2,13 @@
 
 use super::program_ref;
 use super::{Context, LintRule};
+use crate::class_analysis::{ClassAnalysis, MemberKey, MemberKind};
+use crate::syntax_features::SyntaxFeatures;
 use crate::Program;
 use crate::ProgramRef;
-use deno_ast::swc::ast::{
-  BigInt, Bool, Class, ClassMethod, ComputedPropName, Expr, IdentName, Lit,
-  MethodKind, Null, Number, PropName, Str, Tpl,
-};
+use deno_ast::swc::ast::Class;
 use deno_ast::swc::visit::{noop_visit_type, Visit, VisitWith};
 use deno_ast::SourceRange;
-use deno_ast::SourceRangedForSpanned;
 use derive_more::Display;
 use std::cmp::Ordering;
 use std::collections::BTreeMap;
@@ -41,6 +39,10 @@ impl LintRule for NoDupeClassMembers {
     CODE
   }
 
+  fn required_syntax(&self) -> SyntaxFeatures {
+    SyntaxFeatures::CLASS
+  }
+
   fn lint_program_with_ast_view<'view>(
     &self,
     context: &mut Context<'view>,
@@ -83,110 +85,52 @@ impl<'c, 'view> Visit for NoDupeClassMembersVisitor<'c, 'view> {
   noop_visit_type!();
 
   fn visit_class(&mut self, class: &Class) {
-    let mut visitor = ClassVisitor::new(self);
-    class.visit_children_with(&mut visitor);
-    visitor.aggregate_dupes();
-  }
-}
-
-struct ClassVisitor<'a, 'b, 'view> {
-  root_visitor: &'b mut NoDupeClassMembersVisitor<'a, 'view>,
-  appeared_methods: BTreeMap<MethodToCheck, Vec<(SourceRange, String)>>,
-}
-
-impl<'a, 'b, 'view> ClassVisitor<'a, 'b, 'view> {
-  fn new(root_visitor: &'b mut NoDupeClassMembersVisitor<'a, 'view>) -> Self {
-    Self {
-      root_visitor,
-      appeared_methods: BTreeMap::new(),
+    let analysis = ClassAnalysis::new(class);
+    let mut appeared_methods: BTreeMap<
+      MethodToCheck,
+      Vec<(SourceRange, String)>,
+    > = BTreeMap::new();
+    for member in &analysis.members {
+      if !matches!(
+        member.kind,
+        MemberKind::Method | MemberKind::Getter | MemberKind::Setter
+      ) || !member.has_body
+      {
+        continue;
+      }
+      let (Some(MemberKey::Public(_)), Some(name)) = (member.key, &member.name)
+      else {
+        continue;
+      };
+      let m = MethodToCheck {
+        normalized_name: name.clone(),
+        kind: member.kind,
+        is_static: member.is_static,
+      };
+      appeared_methods
+        .entry(m)
+        .or_default()
+        .push((member.range, name.clone()));
     }
-  }
-
-  fn aggregate_dupes(&mut self) {
-    let root_visitor = &mut self.root_visitor;
 
-    self
-      .appeared_methods
+    appeared_methods
       .values()
       .filter(|m| m.len() >= 2)
       .flatten()
       .for_each(|(range, name)| {
-        root_visitor.add_diagnostic(*range, name);
+        self.add_diagnostic(*range, name);
       });
-  }
-}
 
-impl<'a, 'b, 'view> Visit for ClassVisitor<'a, 'b, 'view> {
-  noop_visit_type!();
-
-  fn visit_class(&mut self, class: &Class) {
-    let mut visitor = ClassVisitor::new(self.root_visitor);
-    class.visit_children_with(&mut visitor);
-    visitor.aggregate_dupes();
-  }
-
-  fn visit_class_method(&mut self, class_method: &ClassMethod) {
-    if class_method.function.body.is_some() {
-      if let Some(m) = MethodToCheck::new(
-        &class_method.key,
-        class_method.kind,
-        class_method.is_static,
-      ) {
-        let name = m.normalized_name.clone();
-        self
-          .appeared_methods
-          .entry(m)
-          .or_default()
-          .push((class_method.range(), name));
-      }
-    }
-    class_method.visit_children_with(self);
+    class.visit_children_with(self);
   }
 }
 
-fn normalize_prop_name(name: &PropName) -> Option<String> {
-  let normalized = match *name {
-    PropName::Ident(IdentName { ref sym, .. }) => sym.to_string(),
-    PropName::Str(Str { ref value, .. }) => value.to_string(),
-    PropName::Num(Number { ref value, .. }) => value.to_string(),
-    PropName::BigInt(BigInt { ref value, .. }) => value.to_string(),
-    PropName::Computed(ComputedPropName { ref expr, .. }) => match &**expr {
-      Expr::Lit(Lit::Str(Str { ref value, .. })) => value.to_string(),
-      Expr::Lit(Lit::Bool(Bool { ref value, .. })) => value.to_string(),
-      Expr::Lit(Lit::Null(Null { .. })) => "null".to_string(),
-      Expr::Lit(Lit::Num(Number { ref value, .. })) => value.to_string(),
-      Expr::Lit(Lit::BigInt(BigInt { ref value, .. })) => value.to_string(),
-      Expr::Tpl(Tpl {
-        ref quasis,
-        ref exprs,
-        ..
-      }) if exprs.is_empty() => {
-        quasis.iter().next().map(|q| q.raw.to_string())?
-      }
-      _ => return None,
-    },
-  };
-
-  Some(normalized)
-}
-
 struct MethodToCheck {
   normalized_name: String,
-  kind: MethodKind,
+  kind: MemberKind,
   is_static: bool,
 }
 
-impl MethodToCheck {
-  fn new(name: &PropName, kind: MethodKind, is_static: bool) -> Option<Self> {
-    let normalized_name = normalize_prop_name(name)?;
-    Some(Self {
-      normalized_name,
-      kind,
-      is_static,
-    })
-  }
-}
-
 impl PartialEq for MethodToCheck {
   fn eq(&self, other: &Self) -> bool {
     if self.normalized_name != other.normalized_name {
@@ -199,8 +143,8 @@ impl PartialEq for MethodToCheck {
 
     !matches!(
       (self.kind, other.kind),
-      (MethodKind::Getter, MethodKind::Setter)
-        | (MethodKind::Setter, MethodKind::Getter)
+      (MemberKind::Getter, MemberKind::Setter)
+        | (MemberKind::Setter, MemberKind::Getter)
     )
   }
 }
@@ -220,8 +164,8 @@ impl Ord for MethodToCheck {
       .cmp(&other.normalized_name)
       .then(self.is_static.cmp(&other.is_static))
       .then_with(|| match (self.kind, other.kind) {
-        (MethodKind::Getter, MethodKind::Setter) => Ordering::Less,
-        (MethodKind::Setter, MethodKind::Getter) => Ordering::Greater,
+        (MemberKind::Getter, MemberKind::Setter) => Ordering::Less,
+        (MemberKind::Setter, MemberKind::Getter) => Ordering::Greater,
         _ => Ordering::Equal,
       })
   }
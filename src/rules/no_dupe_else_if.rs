@@ -2,10 +2,10 @@
 
 use super::program_ref;
 use super::{Context, LintRule};
-use crate::swc_util::span_and_ctx_drop;
+use crate::swc_util::normalize_expr;
 use crate::Program;
 use crate::ProgramRef;
-use deno_ast::swc::ast::{BinExpr, BinaryOp, Expr, IfStmt, ParenExpr, Stmt};
+use deno_ast::swc::ast::{BinExpr, BinaryOp, Expr, IfStmt, Stmt};
 use deno_ast::swc::visit::{noop_visit_type, Visit, VisitWith};
 use deno_ast::{SourceRange, SourceRangedForSpanned};
 use derive_more::Display;
@@ -32,6 +32,8 @@ enum NoDupeElseIfHint {
   RemoveOrRework,
 }
 
+const RELATED_DESC: &str = "covered by this earlier condition";
+
 impl LintRule for NoDupeElseIf {
   fn tags(&self) -> &'static [&'static str] {
     &["recommended"]
@@ -86,9 +88,12 @@ impl<'c, 'view> Visit for NoDupeElseIfVisitor<'c, 'view> {
     // This check is necessary to avoid outputting the same errors multiple times.
     if !self.checked_ranges.contains(&range) {
       self.checked_ranges.insert(range);
-      let span_dropped_test = span_and_ctx_drop(if_stmt.test.clone());
-      let mut appeared_conditions: Vec<Vec<Vec<Expr>>> = Vec::new();
-      append_test(&mut appeared_conditions, *span_dropped_test);
+      let mut appeared_conditions: Vec<AppearedCondition> = Vec::new();
+      append_test(
+        &mut appeared_conditions,
+        range,
+        normalize_expr(&if_stmt.test),
+      );
 
       let mut next = if_stmt.alt.as_ref();
       while let Some(cur) = next {
@@ -96,16 +101,16 @@ impl<'c, 'view> Visit for NoDupeElseIfVisitor<'c, 'view> {
           ref test, ref alt, ..
         }) = &**cur
         {
-          // preserve the range before dropping
+          // preserve the range before normalizing
           let range = test.range();
-          let span_dropped_test = span_and_ctx_drop(test.clone());
+          let normalized_test = normalize_expr(test);
           let mut current_condition_to_check: Vec<Vec<Vec<Expr>>> =
-            mk_condition_to_check(*span_dropped_test.clone())
+            mk_condition_to_check(normalized_test.clone())
               .into_iter()
               .map(split_by_or_then_and)
               .collect();
 
-          for ap_cond in &appeared_conditions {
+          for (ap_range, ap_cond) in &appeared_conditions {
             current_condition_to_check = current_condition_to_check
               .into_iter()
               .map(|current_or_operands| {
@@ -124,18 +129,21 @@ impl<'c, 'view> Visit for NoDupeElseIfVisitor<'c, 'view> {
               .iter()
               .any(|or_operands| or_operands.is_empty())
             {
-              self.context.add_diagnostic_with_hint(
+              // Points at the condition that covered the last remaining
+              // operands.
+              self.context.add_diagnostic_with_related(
                 range,
                 CODE,
                 NoDupeElseIfMessage::Unexpected,
                 NoDupeElseIfHint::RemoveOrRework,
+                vec![(*ap_range, RELATED_DESC.to_string())],
               );
               break;
             }
           }
 
           self.checked_ranges.insert(range);
-          append_test(&mut appeared_conditions, *span_dropped_test);
+          append_test(&mut appeared_conditions, range, normalized_test);
           next = alt.as_ref();
         } else {
           break;
@@ -157,7 +165,6 @@ fn mk_condition_to_check(cond: Expr) -> Vec<Expr> {
       c.append(&mut split_by_and(cond));
       c
     }
-    Expr::Paren(ParenExpr { expr, .. }) => mk_condition_to_check(*expr),
     _ => vec![cond],
   }
 }
@@ -171,7 +178,6 @@ fn split_by_bin_op(op_to_split: BinaryOp, expr: Expr) -> Vec<Expr> {
       ret.append(&mut split_by_bin_op(op_to_split, *right));
       ret
     }
-    Expr::Paren(ParenExpr { expr, .. }) => split_by_bin_op(op_to_split, *expr),
     _ => vec![expr],
   }
 }
@@ -195,11 +201,10 @@ fn is_subset(arr_a: &[Expr], arr_b: &[Expr]) -> bool {
 }
 
 /// Determines whether the two given `Expr`s are considered to be equal in if-else condition
-/// context. Note that `expr1` and `expr2` must be span-dropped to be compared properly.
+/// context. Note that `expr1` and `expr2` must be normalized to be compared properly.
 fn equal_in_if_else(expr1: &Expr, expr2: &Expr) -> bool {
-  use deno_ast::swc::ast::Expr::*;
   match (expr1, expr2) {
-    (Bin(ref bin1), Bin(ref bin2))
+    (Expr::Bin(bin1), Expr::Bin(bin2))
       if matches!(bin1.op, BinaryOp::LogicalOr | BinaryOp::LogicalAnd)
         && bin1.op == bin2.op =>
     {
@@ -208,51 +213,20 @@ fn equal_in_if_else(expr1: &Expr, expr2: &Expr) -> bool {
         || equal_in_if_else(&bin1.left, &bin2.right)
           && equal_in_if_else(&bin1.right, &bin2.left)
     }
-    (Paren(ParenExpr { ref expr, .. }), _) => equal_in_if_else(expr, expr2),
-    (_, Paren(ParenExpr { ref expr, .. })) => equal_in_if_else(expr1, expr),
-    (Fn(a), Fn(b)) => {
-      eprintln!("fn:\n{:?}\n{:?}", a, b);
-      a == b
-    }
-    (This(_), This(_))
-    | (Array(_), Array(_))
-    | (Object(_), Object(_))
-    | (Unary(_), Unary(_))
-    | (Update(_), Update(_))
-    | (Bin(_), Bin(_))
-    | (Assign(_), Assign(_))
-    | (Member(_), Member(_))
-    | (Cond(_), Cond(_))
-    | (Call(_), Call(_))
-    | (New(_), New(_))
-    | (Seq(_), Seq(_))
-    | (Ident(_), Ident(_))
-    | (Lit(_), Lit(_))
-    | (Tpl(_), Tpl(_))
-    | (TaggedTpl(_), TaggedTpl(_))
-    | (Arrow(_), Arrow(_))
-    | (Class(_), Class(_))
-    | (Yield(_), Yield(_))
-    | (MetaProp(_), MetaProp(_))
-    | (Await(_), Await(_))
-    | (JSXMember(_), JSXMember(_))
-    | (JSXNamespacedName(_), JSXNamespacedName(_))
-    | (JSXEmpty(_), JSXEmpty(_))
-    | (JSXElement(_), JSXElement(_))
-    | (JSXFragment(_), JSXFragment(_))
-    | (TsTypeAssertion(_), TsTypeAssertion(_))
-    | (TsConstAssertion(_), TsConstAssertion(_))
-    | (TsNonNull(_), TsNonNull(_))
-    | (TsAs(_), TsAs(_))
-    | (PrivateName(_), PrivateName(_))
-    | (OptChain(_), OptChain(_))
-    | (Invalid(_), Invalid(_)) => expr1 == expr2,
-    _ => false,
+    _ => expr1 == expr2,
   }
 }
 
-fn append_test(appeared_conditions: &mut Vec<Vec<Vec<Expr>>>, expr: Expr) {
-  appeared_conditions.push(split_by_or_then_and(expr));
+/// The range of an earlier condition in the chain, along with its `||`
+/// operands split by `&&`.
+type AppearedCondition = (SourceRange, Vec<Vec<Expr>>);
+
+fn append_test(
+  appeared_conditions: &mut Vec<AppearedCondition>,
+  range: SourceRange,
+  expr: Expr,
+) {
+  appeared_conditions.push((range, split_by_or_then_and(expr)));
 }
 
 #[cfg(test)]
@@ -301,7 +275,6 @@ if (a) {
 "#,
       "if (a) if (b); else if (a);",
       "if (a) {} else if (!!a) {}",
-      "if (a === 1) {} else if (a === (1)) {}",
       "if (a || b) {} else if (c || d) {}",
       "if (a || b) {} else if (a || c) {}",
       "if (a) {} else if (a || b) {}",
@@ -312,6 +285,7 @@ if (a) {
       "if (a) {} else if (b && (a || c)) {}",
       "if (a) {} else if (b && (c || d && a)) {}",
       "if (a && b && c) {} else if (a && b && (c || d)) {}",
+      "if (a['b-c']) {} else if (a.b) {}",
     };
   }
 
@@ -324,6 +298,7 @@ if (a) {
           col: 19,
           message: NoDupeElseIfMessage::Unexpected,
           hint: NoDupeElseIfHint::RemoveOrRework,
+          related: (1, 4),
         }
       ],
       "if (a); else if (a);": [
@@ -653,11 +628,13 @@ function* foo(a) {
           col: 24,
           message: NoDupeElseIfMessage::Unexpected,
           hint: NoDupeElseIfHint::RemoveOrRework,
+          related: (1, 4),
         },
         {
           col: 39,
           message: NoDupeElseIfMessage::Unexpected,
           hint: NoDupeElseIfHint::RemoveOrRework,
+          related: (1, 4),
         }
       ],
       "if (a || b) {} else if (b || a) {}": [
@@ -672,6 +649,7 @@ function* foo(a) {
           col: 34,
           message: NoDupeElseIfMessage::Unexpected,
           hint: NoDupeElseIfHint::RemoveOrRework,
+          related: (1, 19),
         }
       ],
       "if (a || b) {} else if (c || d) {} else if (a || d) {}": [
@@ -679,6 +657,7 @@ function* foo(a) {
           col: 44,
           message: NoDupeElseIfMessage::Unexpected,
           hint: NoDupeElseIfHint::RemoveOrRework,
+          related: (1, 24),
         }
       ],
       "if ((a === b && fn(c)) || d) {} else if (fn(c) && a === b) {}": [
@@ -897,6 +876,27 @@ if (foo) {
           message: NoDupeElseIfMessage::Unexpected,
           hint: NoDupeElseIfHint::RemoveOrRework,
         }
+      ],
+      "if (a['b'] === 'x') {} else if (a.b === \"x\") {}": [
+        {
+          col: 32,
+          message: NoDupeElseIfMessage::Unexpected,
+          hint: NoDupeElseIfHint::RemoveOrRework,
+        }
+      ],
+      "if (a === 1) {} else if (a === (1)) {}": [
+        {
+          col: 25,
+          message: NoDupeElseIfMessage::Unexpected,
+          hint: NoDupeElseIfHint::RemoveOrRework,
+        }
+      ],
+      "if (n === 0x10) {} else if (n === 16) {}": [
+        {
+          col: 28,
+          message: NoDupeElseIfMessage::Unexpected,
+          hint: NoDupeElseIfHint::RemoveOrRework,
+        }
       ]
     };
   }
@@ -1,17 +1,19 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
+use crate::const_eval::ConstEvaluator;
 use crate::handler::{Handler, Traverse};
 use crate::swc_util::StringRepr;
 use crate::Program;
 use deno_ast::view::{
-  GetterProp, KeyValueProp, MethodProp, ObjectLit, Prop, PropOrSpread,
-  SetterProp,
+  GetterProp, KeyValueProp, MethodProp, ObjectLit, Prop, PropName,
+  PropOrSpread, SetterProp,
 };
 use deno_ast::{SourceRange, SourceRanged};
 use derive_more::Display;
 use std::collections::hash_map::Entry;
 use std::collections::HashMap;
+use std::rc::Rc;
 
 #[derive(Debug)]
 pub struct NoDupeKeys;
@@ -39,12 +41,15 @@ impl LintRule for NoDupeKeys {
     CODE
   }
 
-  fn lint_program_with_ast_view(
+  fn lint_program_with_ast_view<'view>(
     &self,
-    context: &mut Context,
-    program: Program,
+    context: &mut Context<'view>,
+    program: Program<'view>,
   ) {
-    NoDupeKeysHandler.traverse(program, context);
+    let mut handler = NoDupeKeysHandler {
+      evaluator: context.const_evaluator(),
+    };
+    handler.traverse(program, context);
   }
 
   #[cfg(feature = "docs")]
@@ -53,9 +58,22 @@ impl LintRule for NoDupeKeys {
   }
 }
 
-struct NoDupeKeysHandler;
+struct NoDupeKeysHandler<'view> {
+  evaluator: Rc<ConstEvaluator<'view>>,
+}
+
+impl<'view> NoDupeKeysHandler<'view> {
+  /// The name of the key, where computed keys like `[KEY]` or `["a" + "b"]`
+  /// are evaluated when their value is known.
+  fn key_name(&self, key: &PropName) -> Option<String> {
+    if let PropName::Computed(computed) = key {
+      if let Some(value) = self.evaluator.eval(&computed.inner.expr) {
+        return Some(value.to_js_string());
+      }
+    }
+    key.string_repr()
+  }
 
-impl NoDupeKeysHandler {
   fn report(
     &mut self,
     range: SourceRange,
@@ -164,7 +182,7 @@ impl PropertyInfo {
   }
 }
 
-impl Handler for NoDupeKeysHandler {
+impl<'view> Handler for NoDupeKeysHandler<'view> {
   fn object_lit(&mut self, obj_lit: &ObjectLit, ctx: &mut Context) {
     let range = obj_lit.range();
     let mut keys: HashMap<String, PropertyInfo> = HashMap::new();
@@ -176,17 +194,17 @@ impl Handler for NoDupeKeysHandler {
             self.check_key(range, Some(ident.inner.as_ref()), &mut keys, ctx);
           }
           Prop::KeyValue(KeyValueProp { key, .. }) => {
-            self.check_key(range, key.string_repr(), &mut keys, ctx);
+            self.check_key(range, self.key_name(key), &mut keys, ctx);
           }
           Prop::Assign(_) => {}
           Prop::Getter(GetterProp { key, .. }) => {
-            self.check_getter(range, key.string_repr(), &mut keys, ctx);
+            self.check_getter(range, self.key_name(key), &mut keys, ctx);
           }
           Prop::Setter(SetterProp { key, .. }) => {
-            self.check_setter(range, key.string_repr(), &mut keys, ctx);
+            self.check_setter(range, self.key_name(key), &mut keys, ctx);
           }
           Prop::Method(MethodProp { key, .. }) => {
-            self.check_key(range, key.string_repr(), &mut keys, ctx);
+            self.check_key(range, self.key_name(key), &mut keys, ctx);
           }
         }
       }
@@ -222,6 +240,8 @@ mod tests {
       r#"var {a, a} = obj"#,
       r#"var x = { 012: 1, 12: 2 };"#,
       r#"var x = { 1_0: 1, 1: 2 };"#,
+      r#"const KEY = "a"; var x = { [KEY]: 1, b: 2 };"#,
+      r#"let key = "a"; var x = { [key]: 1, a: 2 };"#,
       // nested
       r#"
 let x = {
@@ -375,6 +395,27 @@ var foo = {
           hint: NoDupeKeysHint::RemoveOrRename,
         }
       ],
+      r#"const KEY = "a"; var x = { [KEY]: 1, a: 2 };"#: [
+        {
+          col: 25,
+          message: variant!(NoDupeKeysMessage, Duplicate, "a"),
+          hint: NoDupeKeysHint::RemoveOrRename,
+        }
+      ],
+      r#"var x = { ["a" + "b"]: 1, ab: 2 };"#: [
+        {
+          col: 8,
+          message: variant!(NoDupeKeysMessage, Duplicate, "ab"),
+          hint: NoDupeKeysHint::RemoveOrRename,
+        }
+      ],
+      r#"var x = { [`${1 + 1}`]: 1, 2: 2 };"#: [
+        {
+          col: 8,
+          message: variant!(NoDupeKeysMessage, Duplicate, "2"),
+          hint: NoDupeKeysHint::RemoveOrRename,
+        }
+      ],
 
       // nested
       r#"
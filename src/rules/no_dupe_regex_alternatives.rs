@@ -0,0 +1,236 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::Handler;
+use crate::js_regex::ast::{Alternative, Assertion, Atom, Group, Span};
+use crate::js_regex::RegexSource;
+use crate::syntax_features::SyntaxFeatures;
+use deno_ast::view::{CallExpr, Callee, NewExpr, NodeKind, Regex};
+use deno_ast::SourceRanged;
+use derive_more::Display;
+
+#[derive(Debug)]
+pub struct NoDupeRegexAlternatives;
+
+const CODE: &str = "no-dupe-regex-alternatives";
+const FIX_DESC: &str = "Remove the duplicate alternative";
+
+#[derive(Display)]
+enum NoDupeRegexAlternativesMessage {
+  #[display(fmt = "Duplicate alternative `{}` in regular expression", _0)]
+  Duplicate(String),
+}
+
+#[derive(Display)]
+enum NoDupeRegexAlternativesHint {
+  #[display(
+    fmt = "Remove it, since the same alternative before it always matches first"
+  )]
+  Remove,
+}
+
+impl LintRule for NoDupeRegexAlternatives {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn required_syntax(&self) -> SyntaxFeatures {
+    SyntaxFeatures::REGEX
+  }
+
+  fn aliases(&self) -> &'static [&'static str] {
+    &["regexp/no-dupe-disjunctions"]
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoDupeRegexAlternativesHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::Regex, NodeKind::NewExpr, NodeKind::CallExpr])
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_dupe_regex_alternatives.md")
+  }
+}
+
+/// Collects the alternatives that are written exactly like an earlier
+/// alternative of the same group.
+fn find_duplicates(
+  alternatives: &[Alternative],
+  pattern: &str,
+  ignore_case: bool,
+  duplicates: &mut Vec<Span>,
+) {
+  let mut seen = Vec::with_capacity(alternatives.len());
+  for alternative in alternatives {
+    let text = &pattern[alternative.span.start..alternative.span.end];
+    let text = if ignore_case {
+      text.to_lowercase()
+    } else {
+      text.to_string()
+    };
+    if seen.contains(&text) {
+      duplicates.push(alternative.span);
+    } else {
+      seen.push(text);
+    }
+
+    for term in &alternative.terms {
+      if let Atom::Group(Group { alternatives, .. })
+      | Atom::Assertion(
+        Assertion::Lookahead { alternatives, .. }
+        | Assertion::Lookbehind { alternatives, .. },
+      ) = &term.atom
+      {
+        find_duplicates(alternatives, pattern, ignore_case, duplicates);
+      }
+    }
+  }
+}
+
+fn check_regex(regex: &RegexSource, ctx: &mut Context) {
+  let Some(pattern) = regex.parse() else {
+    return;
+  };
+  let mut duplicates = Vec::new();
+  find_duplicates(
+    &pattern.alternatives,
+    &regex.pattern,
+    regex.has_flag('i'),
+    &mut duplicates,
+  );
+  for span in duplicates {
+    let message = NoDupeRegexAlternativesMessage::Duplicate(
+      regex.pattern[span.start..span.end].to_string(),
+    );
+    let range = regex.range_of(span);
+    // A duplicate is never the first alternative, so it follows a `|`.
+    match regex.exact_range_of(Span::new(span.start - 1, span.end)) {
+      Some(fix_range) => ctx.add_diagnostic_with_fixes(
+        range,
+        CODE,
+        message,
+        Some(NoDupeRegexAlternativesHint::Remove.to_string()),
+        vec![LintFix {
+          description: FIX_DESC.into(),
+          changes: vec![LintFixChange {
+            new_text: "".into(),
+            range: fix_range,
+          }],
+        }],
+      ),
+      None => ctx.add_diagnostic_with_hint(
+        range,
+        CODE,
+        message,
+        NoDupeRegexAlternativesHint::Remove,
+      ),
+    }
+  }
+}
+
+struct NoDupeRegexAlternativesHandler;
+
+impl Handler for NoDupeRegexAlternativesHandler {
+  fn regex(&mut self, regex: &Regex, ctx: &mut Context) {
+    check_regex(&RegexSource::from_regex(regex), ctx);
+  }
+
+  fn new_expr(&mut self, new_expr: &NewExpr, ctx: &mut Context) {
+    if let Some(regex) = RegexSource::from_constructor(
+      &new_expr.callee,
+      new_expr.args.unwrap_or_default(),
+      new_expr.range(),
+      ctx,
+    ) {
+      check_regex(&regex, ctx);
+    }
+  }
+
+  fn call_expr(&mut self, call_expr: &CallExpr, ctx: &mut Context) {
+    if let Callee::Expr(callee) = &call_expr.callee {
+      if let Some(regex) = RegexSource::from_constructor(
+        callee,
+        call_expr.args,
+        call_expr.range(),
+        ctx,
+      ) {
+        check_regex(&regex, ctx);
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_dupe_regex_alternatives_valid() {
+    assert_lint_ok! {
+      NoDupeRegexAlternatives,
+      r"/a|b/",
+      r"/(foo|bar)|foo/",
+      r"/(?:a|A)/",
+      r"/[a|a]/",
+      r"/a\|a/",
+      r"new RegExp('cat|dog')",
+      r"const RegExp = Foo; new RegExp('a|a');",
+    };
+  }
+
+  #[test]
+  fn no_dupe_regex_alternatives_invalid() {
+    assert_lint_err! {
+      NoDupeRegexAlternatives,
+      r"/a|a/": [
+        {
+          col: 3,
+          message: NoDupeRegexAlternativesMessage::Duplicate("a".to_string()),
+          hint: NoDupeRegexAlternativesHint::Remove,
+          fix: (FIX_DESC, r"/a/"),
+        }
+      ],
+      r"const re = /^(?:png|jpe?g|gif|jpe?g)$/;": [
+        {
+          col: 30,
+          message: NoDupeRegexAlternativesMessage::Duplicate("jpe?g".to_string()),
+          hint: NoDupeRegexAlternativesHint::Remove,
+          fix: (FIX_DESC, r"const re = /^(?:png|jpe?g|gif)$/;"),
+        }
+      ],
+      r"/(?=foo|foo)/": [
+        {
+          col: 8,
+          message: NoDupeRegexAlternativesMessage::Duplicate("foo".to_string()),
+          hint: NoDupeRegexAlternativesHint::Remove,
+          fix: (FIX_DESC, r"/(?=foo)/"),
+        }
+      ],
+      r"/(?:Yes|yes)/i": [
+        {
+          col: 8,
+          message: NoDupeRegexAlternativesMessage::Duplicate("yes".to_string()),
+          hint: NoDupeRegexAlternativesHint::Remove,
+          fix: (FIX_DESC, r"/(?:Yes)/i"),
+        }
+      ],
+      r"new RegExp('\\d+|x|\\d+');": [
+        {
+          col: 19,
+          message: NoDupeRegexAlternativesMessage::Duplicate(r"\d+".to_string()),
+          hint: NoDupeRegexAlternativesHint::Remove,
+          fix: (FIX_DESC, r"new RegExp('\\d+|x');"),
+        }
+      ],
+    };
+  }
+}
@@ -2,7 +2,8 @@
 
 use super::program_ref;
 use super::{Context, LintRule};
-use crate::swc_util::span_and_ctx_drop;
+use crate::swc_util::normalize_expr;
+use crate::syntax_features::SyntaxFeatures;
 use crate::Program;
 use crate::ProgramRef;
 use deno_ast::swc::ast::{Expr, SwitchStmt};
@@ -38,6 +39,10 @@ impl LintRule for NoDuplicateCase {
     CODE
   }
 
+  fn required_syntax(&self) -> SyntaxFeatures {
+    SyntaxFeatures::SWITCH
+  }
+
   fn lint_program_with_ast_view<'view>(
     &self,
     context: &mut Context<'view>,
@@ -71,13 +76,13 @@ impl<'c, 'view> Visit for NoDuplicateCaseVisitor<'c, 'view> {
   noop_visit_type!();
 
   fn visit_switch_stmt(&mut self, switch_stmt: &SwitchStmt) {
-    // Check if there are duplicates by comparing span dropped expressions
-    let mut seen: HashSet<Box<Expr>> = HashSet::new();
+    // Compares the structure of the tests, so `case 'a'` and `case "a"` are
+    // duplicates too.
+    let mut seen: HashSet<Expr> = HashSet::new();
 
     for case in &switch_stmt.cases {
       if let Some(test) = &case.test {
-        let span_dropped_test = span_and_ctx_drop(test.clone());
-        if !seen.insert(span_dropped_test) {
+        if !seen.insert(normalize_expr(test)) {
           self.context.add_diagnostic_with_hint(
             case.range(),
             CODE,
@@ -115,6 +120,8 @@ mod tests {
       "var a = [1,2]; switch(a.toString()){case ([1,2]).toString():break; case ([1]).toString():break; default:break;}",
       "switch(a) { case a: break; } switch(a) { case a: break; }",
       "switch(a) { case toString: break; }",
+      "switch (a) { case `a${b}`: break; case 'ab': break; }",
+      "switch (a) { case foo['bar-baz']: break; case foo.bar: break; }",
 
       // nested
       r#"
@@ -315,6 +322,34 @@ switch (a) {
           hint: NoDuplicateCaseHint::RemoveOrRename,
         }
       ],
+      "switch (a) { case 'x': break; case \"x\": break; }": [
+        {
+          col: 30,
+          message: NoDuplicateCaseMessage::Unexpected,
+          hint: NoDuplicateCaseHint::RemoveOrRename,
+        }
+      ],
+      "switch (a) { case 0x10: break; case 16: break; }": [
+        {
+          col: 31,
+          message: NoDuplicateCaseMessage::Unexpected,
+          hint: NoDuplicateCaseHint::RemoveOrRename,
+        }
+      ],
+      "switch (a) { case foo['bar']: break; case (foo).bar: break; }": [
+        {
+          col: 37,
+          message: NoDuplicateCaseMessage::Unexpected,
+          hint: NoDuplicateCaseHint::RemoveOrRename,
+        }
+      ],
+      "switch (a) { case `x`: break; case 'x': break; }": [
+        {
+          col: 30,
+          message: NoDuplicateCaseMessage::Unexpected,
+          hint: NoDuplicateCaseHint::RemoveOrRename,
+        }
+      ],
     };
   }
 }
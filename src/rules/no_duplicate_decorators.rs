@@ -0,0 +1,195 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::class_analysis::{ClassAnalysis, DecoratorInfo};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::Handler;
+use crate::syntax_features::SyntaxFeatures;
+
+use deno_ast::view::{Class, NodeKind};
+use deno_ast::{SourceRange, SourceRangedForSpanned};
+use derive_more::Display;
+
+#[derive(Debug)]
+pub struct NoDuplicateDecorators;
+
+const CODE: &str = "no-duplicate-decorators";
+
+#[derive(Display)]
+enum NoDuplicateDecoratorsMessage {
+  #[display(fmt = "Duplicate decorator `{}`", _0)]
+  Duplicate(String),
+}
+
+#[derive(Display)]
+enum NoDuplicateDecoratorsHint {
+  #[display(fmt = "Remove the duplicate decorator")]
+  Remove,
+}
+
+const FIX_DESC: &str = "Remove the duplicate decorator";
+
+impl LintRule for NoDuplicateDecorators {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn required_syntax(&self) -> SyntaxFeatures {
+    SyntaxFeatures::DECORATOR
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoDuplicateDecoratorsHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::Class])
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_duplicate_decorators.md")
+  }
+}
+
+struct NoDuplicateDecoratorsHandler;
+
+impl Handler for NoDuplicateDecoratorsHandler {
+  fn class(&mut self, class: &Class, ctx: &mut Context) {
+    let analysis = ClassAnalysis::new(class.inner);
+    check_decorators(&analysis.decorators, ctx);
+    for member in &analysis.members {
+      check_decorators(&member.decorators, ctx);
+      for decorators in &member.param_decorators {
+        check_decorators(decorators, ctx);
+      }
+    }
+  }
+}
+
+/// Reports the decorators that are written exactly like one before them in
+/// the same list, ignoring whitespace, so the same decorator with different
+/// arguments is allowed.
+fn check_decorators(decorators: &[DecoratorInfo], ctx: &mut Context) {
+  let texts: Vec<String> = decorators
+    .iter()
+    .map(|decorator| {
+      decorator
+        .decorator
+        .expr
+        .text_fast(ctx.text_info())
+        .split_whitespace()
+        .collect()
+    })
+    .collect();
+  for (i, decorator) in decorators.iter().enumerate().skip(1) {
+    if !texts[..i].contains(&texts[i]) {
+      continue;
+    }
+    let range = decorator.range();
+    // Removes the whitespace between the previous decorator and this one,
+    // so the remaining decorators stay formatted as they were.
+    let previous_end = decorators[i - 1].range().end;
+    ctx.add_diagnostic_with_fixes(
+      range,
+      CODE,
+      NoDuplicateDecoratorsMessage::Duplicate(
+        decorator.decorator.text_fast(ctx.text_info()).to_string(),
+      ),
+      Some(NoDuplicateDecoratorsHint::Remove.to_string()),
+      vec![LintFix {
+        description: FIX_DESC.into(),
+        changes: vec![LintFixChange {
+          new_text: "".into(),
+          range: SourceRange::new(previous_end, range.end),
+        }],
+      }],
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_duplicate_decorators_valid() {
+    assert_lint_ok! {
+      NoDuplicateDecorators,
+      "@foo class A {}",
+      "@foo @bar class A {}",
+      "class A { @foo @bar method() {} }",
+      r#"class A { @HostListener("click") @HostListener("keydown") onEvent() {} }"#,
+      "class A { @foo a = 1; @foo b = 1; }",
+      "class A { method(@foo a, @foo b) {} }",
+      "@foo class A { @foo method() {} }",
+      "class A { @foo.bar @foo.baz accessor a = 1; }",
+    };
+  }
+
+  #[test]
+  fn no_duplicate_decorators_invalid() {
+    assert_lint_err! {
+      NoDuplicateDecorators,
+      "@foo @foo class A {}": [
+        {
+          col: 5,
+          message: NoDuplicateDecoratorsMessage::Duplicate("@foo".to_string()),
+          hint: NoDuplicateDecoratorsHint::Remove,
+          fix: (FIX_DESC, "@foo class A {}"),
+        }
+      ],
+      "class A { @foo() @bar @foo( ) method() {} }": [
+        {
+          col: 22,
+          message: NoDuplicateDecoratorsMessage::Duplicate("@foo( )".to_string()),
+          hint: NoDuplicateDecoratorsHint::Remove,
+          fix: (FIX_DESC, "class A { @foo() @bar method() {} }"),
+        }
+      ],
+      "class A {\n  @Input()\n  @Input()\n  name = '';\n}": [
+        {
+          line: 3,
+          col: 2,
+          message: NoDuplicateDecoratorsMessage::Duplicate("@Input()".to_string()),
+          hint: NoDuplicateDecoratorsHint::Remove,
+          fix: (FIX_DESC, "class A {\n  @Input()\n  name = '';\n}"),
+        }
+      ],
+      "class A { constructor(@Inject(A) @Inject(A) private a) {} }": [
+        {
+          col: 33,
+          message: NoDuplicateDecoratorsMessage::Duplicate("@Inject(A)".to_string()),
+          hint: NoDuplicateDecoratorsHint::Remove,
+          fix: (FIX_DESC, "class A { constructor(@Inject(A) private a) {} }"),
+        }
+      ],
+      "class A { #method(@foo @bar @foo a) {} }": [
+        {
+          col: 28,
+          message: NoDuplicateDecoratorsMessage::Duplicate("@foo".to_string()),
+          hint: NoDuplicateDecoratorsHint::Remove,
+          fix: (FIX_DESC, "class A { #method(@foo @bar a) {} }"),
+        }
+      ],
+      "const A = class { @foo @foo @foo static a = 1; };": [
+        {
+          col: 23,
+          message: NoDuplicateDecoratorsMessage::Duplicate("@foo".to_string()),
+          hint: NoDuplicateDecoratorsHint::Remove,
+          fix: (FIX_DESC, "const A = class { @foo @foo static a = 1; };"),
+        },
+        {
+          col: 28,
+          message: NoDuplicateDecoratorsMessage::Duplicate("@foo".to_string()),
+          hint: NoDuplicateDecoratorsHint::Remove,
+          fix: (FIX_DESC, "const A = class { @foo @foo static a = 1; };"),
+        }
+      ],
+    };
+  }
+}
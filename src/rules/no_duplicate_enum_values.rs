@@ -0,0 +1,195 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::handler::Handler;
+use crate::syntax_features::SyntaxFeatures;
+
+use deno_ast::view::{
+  Expr, Lit, NodeKind, NodeTrait, TsEnumDecl, TsEnumMemberId, UnaryOp,
+};
+use deno_ast::SourceRanged;
+use derive_more::Display;
+use std::collections::HashMap;
+
+#[derive(Debug)]
+pub struct NoDuplicateEnumValues;
+
+const CODE: &str = "no-duplicate-enum-values";
+
+#[derive(Display)]
+enum NoDuplicateEnumValuesMessage {
+  #[display(fmt = "Duplicate enum member value {}", _0)]
+  Duplicate(String),
+}
+
+#[derive(Display)]
+enum NoDuplicateEnumValuesHint {
+  #[display(
+    fmt = "Give each member a unique value, or reference the other member (e.g. `{}`) if the duplicate is intended",
+    _0
+  )]
+  UniqueValue(String),
+}
+
+impl LintRule for NoDuplicateEnumValues {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn required_syntax(&self) -> SyntaxFeatures {
+    SyntaxFeatures::ENUM
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoDuplicateEnumValuesHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::TsEnumDecl])
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_duplicate_enum_values.md")
+  }
+}
+
+#[derive(PartialEq, Eq, Hash)]
+enum EnumValue {
+  Str(String),
+  /// The bits of the number, so that it can be hashed.
+  Num(u64),
+}
+
+fn literal_value(expr: &Expr) -> Option<EnumValue> {
+  match expr {
+    Expr::Lit(Lit::Str(str)) => Some(EnumValue::Str(str.value().to_string())),
+    Expr::Lit(Lit::Num(num)) => Some(EnumValue::Num(num.value().to_bits())),
+    Expr::Tpl(tpl) if tpl.exprs.is_empty() => {
+      let cooked = tpl.quasis.first()?.cooked().as_ref()?;
+      Some(EnumValue::Str(cooked.to_string()))
+    }
+    Expr::Unary(unary) if unary.op() == UnaryOp::Minus => match unary.arg {
+      Expr::Lit(Lit::Num(num)) => {
+        Some(EnumValue::Num((-num.value()).to_bits()))
+      }
+      _ => None,
+    },
+    Expr::Paren(paren) => literal_value(&paren.expr),
+    _ => None,
+  }
+}
+
+struct NoDuplicateEnumValuesHandler;
+
+impl Handler for NoDuplicateEnumValuesHandler {
+  fn ts_enum_decl(&mut self, enum_decl: &TsEnumDecl, ctx: &mut Context) {
+    // Maps each value to the first member that has it.
+    let mut seen = HashMap::<EnumValue, String>::new();
+    for member in enum_decl.members {
+      let Some(init) = &member.init else {
+        continue;
+      };
+      let Some(value) = literal_value(init) else {
+        continue;
+      };
+      if let Some(first_member) = seen.get(&value) {
+        ctx.add_diagnostic_with_hint(
+          member.range(),
+          CODE,
+          NoDuplicateEnumValuesMessage::Duplicate(init.text().to_string()),
+          NoDuplicateEnumValuesHint::UniqueValue(first_member.clone()),
+        );
+      } else {
+        let reference = match &member.id {
+          TsEnumMemberId::Ident(ident) => {
+            format!("{}.{}", enum_decl.id.sym(), ident.sym())
+          }
+          TsEnumMemberId::Str(str) => {
+            format!("{}[{}]", enum_decl.id.sym(), str.text())
+          }
+        };
+        seen.insert(value, reference);
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_duplicate_enum_values_valid() {
+    assert_lint_ok! {
+      NoDuplicateEnumValues,
+      "enum E { A, B }",
+      "enum E { A = 0, B }",
+      "enum E { A = 1, B = 2 }",
+      "enum E { A = 'a', B = 'b' }",
+      "enum E { A = 1, B = '1' }",
+      "enum E { A = 1, B = -1 }",
+      "enum E { A = 1, B = A }",
+      "enum E { A = x, B = x }",
+      "enum E { A = 1 } enum F { A = 1 }",
+    };
+  }
+
+  #[test]
+  fn no_duplicate_enum_values_invalid() {
+    assert_lint_err! {
+      NoDuplicateEnumValues,
+      "enum E { A = 1, B = 1 }": [
+        {
+          col: 16,
+          message: NoDuplicateEnumValuesMessage::Duplicate("1".to_string()),
+          hint: NoDuplicateEnumValuesHint::UniqueValue("E.A".to_string()),
+        }
+      ],
+      "enum E { A = 'a', B = 'b', C = \"a\" }": [
+        {
+          col: 27,
+          message: NoDuplicateEnumValuesMessage::Duplicate(
+            "\"a\"".to_string()
+          ),
+          hint: NoDuplicateEnumValuesHint::UniqueValue("E.A".to_string()),
+        }
+      ],
+      "enum E { A = 'a', B = `a` }": [
+        {
+          col: 18,
+          message: NoDuplicateEnumValuesMessage::Duplicate("`a`".to_string()),
+          hint: NoDuplicateEnumValuesHint::UniqueValue("E.A".to_string()),
+        }
+      ],
+      "enum E { A = 0x10, B = 16, C = 16 }": [
+        {
+          col: 19,
+          message: NoDuplicateEnumValuesMessage::Duplicate("16".to_string()),
+          hint: NoDuplicateEnumValuesHint::UniqueValue("E.A".to_string()),
+        },
+        {
+          col: 27,
+          message: NoDuplicateEnumValuesMessage::Duplicate("16".to_string()),
+          hint: NoDuplicateEnumValuesHint::UniqueValue("E.A".to_string()),
+        }
+      ],
+      "enum E { 'a b' = 1, B = 1 }": [
+        {
+          col: 20,
+          message: NoDuplicateEnumValuesMessage::Duplicate("1".to_string()),
+          hint: NoDuplicateEnumValuesHint::UniqueValue(
+            "E['a b']".to_string()
+          ),
+        }
+      ],
+      "enum E { A = -1, B = -1 }": [
+        {
+          col: 17,
+          message: NoDuplicateEnumValuesMessage::Duplicate("-1".to_string()),
+          hint: NoDuplicateEnumValuesHint::UniqueValue("E.A".to_string()),
+        }
+      ],
+    };
+  }
+}
@@ -1,10 +1,11 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
-use crate::Program;
-use deno_ast::view::{ArrowExpr, BlockStmt, Constructor, Function, SwitchStmt};
-use deno_ast::{SourceRanged, SourceRangedForSpanned};
+use crate::handler::Handler;
+use deno_ast::view::{
+  ArrowExpr, BlockStmt, Constructor, Function, NodeKind, SwitchStmt,
+};
+use deno_ast::SourceRanged;
 
 #[derive(Debug)]
 pub struct NoEmpty;
@@ -20,12 +21,12 @@ impl LintRule for NoEmpty {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    NoEmptyHandler.traverse(program, context);
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoEmptyHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::BlockStmt, NodeKind::SwitchStmt])
   }
 
   #[cfg(feature = "docs")]
@@ -75,9 +76,7 @@ trait ContainsComments {
 
 impl ContainsComments for BlockStmt<'_> {
   fn contains_comments(&self, context: &Context) -> bool {
-    context
-      .all_comments()
-      .any(|comment| self.range().contains(&comment.range()))
+    context.comments_within(self.range()).next().is_some()
   }
 }
 
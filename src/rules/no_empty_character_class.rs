@@ -1,11 +1,12 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
-use crate::Program;
-use deno_ast::view::Regex;
+use crate::handler::Handler;
+use crate::js_regex::ast::{CharacterClass, Visit};
+use crate::js_regex::RegexSource;
+use crate::syntax_features::SyntaxFeatures;
+use deno_ast::view::{NodeKind, Regex};
 use deno_ast::SourceRanged;
-use once_cell::sync::Lazy;
 
 #[derive(Debug)]
 pub struct NoEmptyCharacterClass;
@@ -24,12 +25,16 @@ impl LintRule for NoEmptyCharacterClass {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    NoEmptyCharacterClassVisitor.traverse(program, context);
+  fn required_syntax(&self) -> SyntaxFeatures {
+    SyntaxFeatures::REGEX
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoEmptyCharacterClassVisitor))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::Regex])
   }
 
   #[cfg(feature = "docs")]
@@ -40,28 +45,27 @@ impl LintRule for NoEmptyCharacterClass {
 
 struct NoEmptyCharacterClassVisitor;
 
+/// Finds `[]`, which can never match. `[^]` matches any character and is
+/// allowed.
+#[derive(Default)]
+struct EmptyClassFinder {
+  found: bool,
+}
+
+impl Visit for EmptyClassFinder {
+  fn visit_class(&mut self, class: &CharacterClass) {
+    self.found |= !class.negated && class.items.is_empty();
+  }
+}
+
 impl Handler for NoEmptyCharacterClassVisitor {
   fn regex(&mut self, regex: &Regex, ctx: &mut Context) {
-    let raw_regex = regex.text_fast(ctx.text_info());
-
-    static RULE_REGEX: Lazy<regex::Regex> = Lazy::new(|| {
-      /* reference : [eslint no-empty-character-class](https://github.com/eslint/eslint/blob/master/lib/rules/no-empty-character-class.js#L13)
-       * plain-English description of the following regexp:
-       * 0. `^` fix the match at the beginning of the string
-       * 1. `\/`: the `/` that begins the regexp
-       * 2. `([^\\[]|\\.|\[([^\\\]]|\\.)+\])*`: regexp contents; 0 or more of the following
-       * 2.0. `[^\\[]`: any character that's not a `\` or a `[` (anything but escape sequences and character classes)
-       * 2.1. `\\.`: an escape sequence
-       * 2.2. `\[([^\\\]]|\\.)+\]`: a character class that isn't empty
-       * 3. `\/` the `/` that ends the regexp
-       * 4. `[dgimsuvy]*`: optional regexp flags
-       * 5. `$`: fix the match at the end of the string
-       */
-      regex::Regex::new(r"(?u)^/([^\\\[]|\\.|\[([^\\\]]|\\.)+\])*/[dgimsuvy]*$")
-        .unwrap()
-    });
-
-    if !RULE_REGEX.is_match(raw_regex) {
+    let Some(pattern) = RegexSource::from_regex(regex).parse() else {
+      return;
+    };
+    let mut finder = EmptyClassFinder::default();
+    pattern.visit_with(&mut finder);
+    if finder.found {
       ctx.add_diagnostic_with_hint(regex.range(), CODE, MESSAGE, HINT);
     }
   }
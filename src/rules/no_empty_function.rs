@@ -0,0 +1,367 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::handler::Handler;
+
+use deno_ast::swc::ast::{Accessibility, BlockStmt, MethodKind};
+use deno_ast::view::{
+  ArrowExpr, BlockStmtOrExpr, Constructor, Function, GetterProp, Node,
+  NodeKind, SetterProp,
+};
+use deno_ast::SourceRangedForSpanned;
+use derive_more::Display;
+use serde::Deserialize;
+
+#[derive(Debug, Default)]
+pub struct NoEmptyFunction {
+  options: NoEmptyFunctionOptions,
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct NoEmptyFunctionOptions {
+  /// The kinds of functions that may be empty.
+  pub allow: Vec<EmptyFunctionKind>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum EmptyFunctionKind {
+  /// Function declarations and expressions.
+  Functions,
+  ArrowFunctions,
+  /// Methods of classes and objects.
+  Methods,
+  /// Methods with the `private` modifier or a `#private` name.
+  PrivateMethods,
+  Getters,
+  Setters,
+  Constructors,
+  PrivateConstructors,
+  ProtectedConstructors,
+}
+
+impl NoEmptyFunction {
+  pub fn new(options: NoEmptyFunctionOptions) -> Self {
+    Self { options }
+  }
+}
+
+const CODE: &str = "no-empty-function";
+
+#[derive(Display)]
+enum NoEmptyFunctionMessage {
+  #[display(fmt = "Unexpected empty {}", _0)]
+  Empty(&'static str),
+}
+
+#[derive(Display)]
+enum NoEmptyFunctionHint {
+  #[display(fmt = "Add code or a comment to the empty function body")]
+  AddCode,
+}
+
+impl LintRule for NoEmptyFunction {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "allow": {
+          "type": "array",
+          "items": {
+            "enum": [
+              "functions",
+              "arrowFunctions",
+              "methods",
+              "privateMethods",
+              "getters",
+              "setters",
+              "constructors",
+              "privateConstructors",
+              "protectedConstructors"
+            ]
+          }
+        }
+      }
+    }))
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoEmptyFunctionHandler {
+      options: &self.options,
+    }))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[
+      NodeKind::Function,
+      NodeKind::ArrowExpr,
+      NodeKind::Constructor,
+      NodeKind::GetterProp,
+      NodeKind::SetterProp,
+    ])
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_empty_function.md")
+  }
+}
+
+struct NoEmptyFunctionHandler<'a> {
+  options: &'a NoEmptyFunctionOptions,
+}
+
+impl NoEmptyFunctionHandler<'_> {
+  /// Reports the body if it's empty, unless one of the kinds is allowed.
+  fn check(
+    &self,
+    body: Option<&BlockStmt>,
+    description: &'static str,
+    kinds: &[EmptyFunctionKind],
+    ctx: &mut Context,
+  ) {
+    let Some(body) = body else {
+      return;
+    };
+    if !body.stmts.is_empty()
+      || ctx.comments_within(body.range()).next().is_some()
+      || kinds.iter().any(|kind| self.options.allow.contains(kind))
+    {
+      return;
+    }
+    ctx.add_diagnostic_with_hint(
+      body.range(),
+      CODE,
+      NoEmptyFunctionMessage::Empty(description),
+      NoEmptyFunctionHint::AddCode,
+    );
+  }
+}
+
+impl Handler for NoEmptyFunctionHandler<'_> {
+  fn function(&mut self, function: &Function, ctx: &mut Context) {
+    use EmptyFunctionKind::*;
+    let (method_kind, is_private) = match function.parent() {
+      Node::ClassMethod(method) => (
+        Some(method.inner.kind),
+        method.inner.accessibility == Some(Accessibility::Private),
+      ),
+      Node::PrivateMethod(method) => (Some(method.inner.kind), true),
+      Node::MethodProp(_) => (Some(MethodKind::Method), false),
+      _ => (None, false),
+    };
+    let body = function.inner.body.as_ref();
+    match method_kind {
+      None => self.check(body, "function", &[Functions], ctx),
+      Some(MethodKind::Getter) => self.check(body, "getter", &[Getters], ctx),
+      Some(MethodKind::Setter) => self.check(body, "setter", &[Setters], ctx),
+      Some(MethodKind::Method) if is_private => {
+        self.check(body, "method", &[Methods, PrivateMethods], ctx)
+      }
+      Some(MethodKind::Method) => self.check(body, "method", &[Methods], ctx),
+    }
+  }
+
+  fn arrow_expr(&mut self, arrow_expr: &ArrowExpr, ctx: &mut Context) {
+    if let BlockStmtOrExpr::BlockStmt(body) = &arrow_expr.body {
+      self.check(
+        Some(body.inner),
+        "arrow function",
+        &[EmptyFunctionKind::ArrowFunctions],
+        ctx,
+      );
+    }
+  }
+
+  fn constructor(&mut self, constructor: &Constructor, ctx: &mut Context) {
+    use EmptyFunctionKind::*;
+    let kinds: &[EmptyFunctionKind] = match constructor.inner.accessibility {
+      Some(Accessibility::Private) => &[Constructors, PrivateConstructors],
+      Some(Accessibility::Protected) => &[Constructors, ProtectedConstructors],
+      _ => &[Constructors],
+    };
+    self.check(constructor.inner.body.as_ref(), "constructor", kinds, ctx);
+  }
+
+  fn getter_prop(&mut self, getter_prop: &GetterProp, ctx: &mut Context) {
+    self.check(
+      getter_prop.inner.body.as_ref(),
+      "getter",
+      &[EmptyFunctionKind::Getters],
+      ctx,
+    );
+  }
+
+  fn setter_prop(&mut self, setter_prop: &SetterProp, ctx: &mut Context) {
+    self.check(
+      setter_prop.inner.body.as_ref(),
+      "setter",
+      &[EmptyFunctionKind::Setters],
+      ctx,
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_empty_function_valid() {
+    assert_lint_ok! {
+      NoEmptyFunction::default(),
+      "function foo() { bar(); }",
+      "function foo() { /* nothing to do */ }",
+      "function foo() {\n  // nothing to do\n}",
+      "const foo = () => bar;",
+      "const foo = () => ({});",
+      "class A { foo() { return 1; } }",
+      "class A { foo(): void; foo() { bar(); } }",
+      "abstract class A { abstract foo(): void; }",
+      "declare function foo(): void;",
+      "const foo = { get bar() { return 1; } };",
+      "class A { constructor(private a: string) { bar(); } }",
+    };
+
+    let options = NoEmptyFunctionOptions {
+      allow: vec![
+        EmptyFunctionKind::ArrowFunctions,
+        EmptyFunctionKind::PrivateMethods,
+        EmptyFunctionKind::PrivateConstructors,
+      ],
+    };
+    assert_lint_ok! {
+      NoEmptyFunction::new(options.clone()),
+      "const noop = () => {};",
+      "class A { #foo() {} }",
+      "class A { private foo() {} }",
+      "class A { private constructor() {} }",
+    };
+
+    assert_lint_ok! {
+      NoEmptyFunction::new(NoEmptyFunctionOptions {
+        allow: vec![
+          EmptyFunctionKind::Functions,
+          EmptyFunctionKind::Methods,
+          EmptyFunctionKind::Getters,
+          EmptyFunctionKind::Setters,
+          EmptyFunctionKind::Constructors,
+        ],
+      }),
+      "function foo() {}",
+      "const foo = function () {};",
+      "class A { foo() {} #bar() {} }",
+      "const foo = { bar() {} };",
+      "class A { get foo() {} set foo(value) {} }",
+      "const foo = { get bar() {}, set bar(value) {} };",
+      "class A { constructor() {} }",
+      "class A { protected constructor() {} }",
+    };
+  }
+
+  #[test]
+  fn no_empty_function_invalid() {
+    assert_lint_err! {
+      NoEmptyFunction::default(),
+      "function foo() {}": [
+        {
+          col: 15,
+          message: NoEmptyFunctionMessage::Empty("function"),
+          hint: NoEmptyFunctionHint::AddCode,
+        }
+      ],
+      "const foo = async function* () {};": [
+        {
+          col: 31,
+          message: NoEmptyFunctionMessage::Empty("function"),
+          hint: NoEmptyFunctionHint::AddCode,
+        }
+      ],
+      "const foo = () => {};": [
+        {
+          col: 18,
+          message: NoEmptyFunctionMessage::Empty("arrow function"),
+          hint: NoEmptyFunctionHint::AddCode,
+        }
+      ],
+      "class A { foo() {} }": [
+        {
+          col: 16,
+          message: NoEmptyFunctionMessage::Empty("method"),
+          hint: NoEmptyFunctionHint::AddCode,
+        }
+      ],
+      "const foo = { bar() {} };": [
+        {
+          col: 20,
+          message: NoEmptyFunctionMessage::Empty("method"),
+          hint: NoEmptyFunctionHint::AddCode,
+        }
+      ],
+      "class A { get foo() {} }": [
+        {
+          col: 20,
+          message: NoEmptyFunctionMessage::Empty("getter"),
+          hint: NoEmptyFunctionHint::AddCode,
+        }
+      ],
+      "const foo = { set bar(value) {} };": [
+        {
+          col: 29,
+          message: NoEmptyFunctionMessage::Empty("setter"),
+          hint: NoEmptyFunctionHint::AddCode,
+        }
+      ],
+      "class A { constructor() {} }": [
+        {
+          col: 24,
+          message: NoEmptyFunctionMessage::Empty("constructor"),
+          hint: NoEmptyFunctionHint::AddCode,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoEmptyFunction::new(NoEmptyFunctionOptions {
+        allow: vec![
+          EmptyFunctionKind::PrivateMethods,
+          EmptyFunctionKind::PrivateConstructors,
+        ],
+      }),
+      "class A { foo() {} #bar() {} }": [
+        {
+          col: 16,
+          message: NoEmptyFunctionMessage::Empty("method"),
+          hint: NoEmptyFunctionHint::AddCode,
+        }
+      ],
+      "class A { protected constructor() {} }": [
+        {
+          col: 34,
+          message: NoEmptyFunctionMessage::Empty("constructor"),
+          hint: NoEmptyFunctionHint::AddCode,
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn no_empty_function_options() {
+    let options: NoEmptyFunctionOptions = serde_json::from_str(
+      r#"{ "allow": ["arrowFunctions", "privateConstructors"] }"#,
+    )
+    .unwrap();
+    assert_eq!(
+      options.allow,
+      vec![
+        EmptyFunctionKind::ArrowFunctions,
+        EmptyFunctionKind::PrivateConstructors
+      ]
+    );
+  }
+}
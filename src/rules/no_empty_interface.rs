@@ -1,9 +1,8 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
-use crate::Program;
-use deno_ast::view::TsInterfaceDecl;
+use crate::handler::Handler;
+use deno_ast::view::{NodeKind, TsInterfaceDecl};
 use deno_ast::SourceRanged;
 use derive_more::Display;
 
@@ -33,12 +32,12 @@ impl LintRule for NoEmptyInterface {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    NoEmptyInterfaceHandler.traverse(program, context);
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoEmptyInterfaceHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::TsInterfaceDecl])
   }
 
   #[cfg(feature = "docs")]
@@ -1,10 +1,11 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::handler::Handler;
 use crate::swc_util::StringRepr;
-use crate::Program;
-use deno_ast::view::{CallExpr, Callee, Expr, ParenExpr, VarDeclarator};
+use deno_ast::view::{
+  CallExpr, Callee, Expr, NodeKind, ParenExpr, VarDeclarator,
+};
 use deno_ast::{SourceRange, SourceRanged};
 
 #[derive(Debug)]
@@ -15,16 +16,20 @@ const MESSAGE: &str = "`eval` call is not allowed";
 const HINT: &str = "Remove the use of `eval`";
 
 impl LintRule for NoEval {
+  fn tags(&self) -> &'static [&'static str] {
+    &["security"]
+  }
+
   fn code(&self) -> &'static str {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    NoEvalHandler.traverse(program, context);
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoEvalHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::VarDeclarator, NodeKind::CallExpr])
   }
 
   #[cfg(feature = "docs")]
@@ -0,0 +1,533 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::handler::Handler;
+
+use deno_ast::swc::ast::{
+  ArrowExpr, Expr as SwcExpr, ExprOrSpread as SwcExprOrSpread, Function,
+  KeyValueProp, Lit as SwcLit, Prop, PropName, PropOrSpread, Tpl,
+};
+use deno_ast::swc::visit::{noop_visit_type, Visit, VisitWith};
+use deno_ast::view::{
+  BinaryOp, CallExpr, Callee, Expr, ExprOrSpread, Lit, MemberProp, NewExpr,
+  NodeKind,
+};
+use deno_ast::{SourceRange, SourceRanged, SourceRangedForSpanned};
+use derive_more::Display;
+use serde::Deserialize;
+
+#[derive(Debug, Default)]
+pub struct NoEvalLike {
+  options: NoEvalLikeOptions,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct NoEvalLikeOptions {
+  /// Report `new Function()` with a body that isn't a string literal.
+  pub function_constructor: bool,
+  /// Report `setTimeout()` and `setInterval()` with a string instead of a
+  /// function.
+  pub string_timers: bool,
+  /// Report template literals with interpolations in the arguments of
+  /// `Deno.Command` and `Deno.run()`.
+  pub command_interpolation: bool,
+}
+
+impl Default for NoEvalLikeOptions {
+  fn default() -> Self {
+    Self {
+      function_constructor: true,
+      string_timers: true,
+      command_interpolation: true,
+    }
+  }
+}
+
+impl NoEvalLike {
+  pub fn new(options: NoEvalLikeOptions) -> Self {
+    Self { options }
+  }
+}
+
+const CODE: &str = "no-eval-like";
+
+#[derive(Display)]
+enum NoEvalLikeMessage {
+  #[display(fmt = "`Function` constructor with a dynamic body evaluates code")]
+  FunctionConstructor,
+  #[display(fmt = "`{}` with a string argument evaluates code", _0)]
+  StringTimer(String),
+  #[display(fmt = "Interpolated string is passed to `{}`", _0)]
+  CommandInterpolation(&'static str),
+}
+
+#[derive(Display)]
+enum NoEvalLikeHint {
+  #[display(fmt = "Define the function in the source code instead")]
+  DefineFunction,
+  #[display(fmt = "Pass a function instead of a string")]
+  PassFunction,
+  #[display(
+    fmt = "Pass every argument as a separate array element instead of building a command line, so that values can't inject arguments or shell syntax"
+  )]
+  SeparateArguments,
+}
+
+impl LintRule for NoEvalLike {
+  fn tags(&self) -> &'static [&'static str] {
+    &["security"]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "functionConstructor": { "type": "boolean", "default": true },
+        "stringTimers": { "type": "boolean", "default": true },
+        "commandInterpolation": { "type": "boolean", "default": true }
+      }
+    }))
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoEvalLikeHandler {
+      options: &self.options,
+    }))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::NewExpr, NodeKind::CallExpr])
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_eval_like.md")
+  }
+}
+
+fn is_global_ident(expr: &Expr, name: &str, ctx: &Context) -> bool {
+  matches!(expr, Expr::Ident(ident)
+    if ident.sym() == name && ctx.scope().is_global(&ident.inner.to_id()))
+}
+
+/// Whether the expression is `Deno.<name>`.
+fn is_deno_api(expr: &Expr, name: &str, ctx: &Context) -> bool {
+  let Expr::Member(member) = expr else {
+    return false;
+  };
+  is_global_ident(&member.obj, "Deno", ctx)
+    && matches!(&member.prop, MemberProp::Ident(prop) if prop.sym() == name)
+}
+
+/// The name of a timer function that is called, for `setTimeout()` and
+/// `globalThis.setTimeout()`.
+fn timer_name(expr: &Expr, ctx: &Context) -> Option<&'static str> {
+  let name = match expr {
+    Expr::Ident(ident) if ctx.scope().is_global(&ident.inner.to_id()) => {
+      ident.sym()
+    }
+    Expr::Member(member)
+      if is_global_ident(&member.obj, "globalThis", ctx)
+        || is_global_ident(&member.obj, "self", ctx) =>
+    {
+      match &member.prop {
+        MemberProp::Ident(prop) => prop.sym(),
+        _ => return None,
+      }
+    }
+    _ => return None,
+  };
+  match name.as_ref() {
+    "setTimeout" => Some("setTimeout"),
+    "setInterval" => Some("setInterval"),
+    _ => None,
+  }
+}
+
+fn is_string_literal(expr: &Expr) -> bool {
+  match expr {
+    Expr::Lit(Lit::Str(_)) => true,
+    Expr::Tpl(tpl) => tpl.exprs.is_empty(),
+    Expr::Paren(paren) => is_string_literal(&paren.expr),
+    _ => false,
+  }
+}
+
+/// Whether the expression evaluates to a string, e.g. a template literal or
+/// the concatenation of a string literal.
+fn is_string(expr: &Expr) -> bool {
+  match expr {
+    Expr::Lit(Lit::Str(_)) | Expr::Tpl(_) => true,
+    Expr::Paren(paren) => is_string(&paren.expr),
+    Expr::Bin(bin) => {
+      bin.op() == BinaryOp::Add
+        && (is_string(&bin.left) || is_string(&bin.right))
+    }
+    _ => false,
+  }
+}
+
+/// Finds the template literals with interpolations in the arguments of a
+/// command, without entering functions.
+#[derive(Default)]
+struct InterpolationFinder {
+  found: Vec<SourceRange>,
+}
+
+impl Visit for InterpolationFinder {
+  noop_visit_type!();
+
+  fn visit_tpl(&mut self, tpl: &Tpl) {
+    if !tpl.exprs.is_empty() {
+      self.found.push(tpl.range());
+    }
+    tpl.visit_children_with(self);
+  }
+
+  fn visit_lit(&mut self, _lit: &SwcLit) {}
+
+  fn visit_function(&mut self, _function: &Function) {}
+
+  fn visit_arrow_expr(&mut self, _arrow_expr: &ArrowExpr) {}
+}
+
+struct NoEvalLikeHandler<'a> {
+  options: &'a NoEvalLikeOptions,
+}
+
+impl NoEvalLikeHandler<'_> {
+  fn check_function_constructor(
+    &self,
+    range: SourceRange,
+    args: &[&ExprOrSpread],
+    ctx: &mut Context,
+  ) {
+    let Some(body) = args.last() else {
+      return;
+    };
+    if body.spread().is_none() && is_string_literal(&body.expr) {
+      return;
+    }
+    ctx.add_diagnostic_with_hint(
+      range,
+      CODE,
+      NoEvalLikeMessage::FunctionConstructor,
+      NoEvalLikeHint::DefineFunction,
+    );
+  }
+
+  /// Reports interpolations in the executable of a command, and in the
+  /// script that is passed to a shell with `-c`. The other arguments are
+  /// passed to the executable as they are, so interpolating them can't inject
+  /// a command.
+  fn check_command(
+    &self,
+    api: &'static str,
+    executable: Option<&SwcExpr>,
+    args: &[Option<SwcExprOrSpread>],
+    ctx: &mut Context,
+  ) {
+    let Some(executable) = executable else {
+      return;
+    };
+    let mut finder = InterpolationFinder::default();
+    executable.visit_with(&mut finder);
+    if static_string(executable).is_some_and(is_shell) {
+      let script = args
+        .iter()
+        .skip_while(|arg| !arg_str(arg).is_some_and(is_script_flag))
+        .nth(1);
+      if let Some(Some(SwcExprOrSpread { spread: None, expr })) = script {
+        expr.visit_with(&mut finder);
+      }
+    }
+    for range in finder.found {
+      ctx.add_diagnostic_with_hint(
+        range,
+        CODE,
+        NoEvalLikeMessage::CommandInterpolation(api),
+        NoEvalLikeHint::SeparateArguments,
+      );
+    }
+  }
+}
+
+/// The value of a string literal, or of a template literal without
+/// interpolations.
+fn static_string(expr: &SwcExpr) -> Option<&str> {
+  match expr {
+    SwcExpr::Lit(SwcLit::Str(str)) => Some(&str.value),
+    SwcExpr::Tpl(tpl) if tpl.exprs.is_empty() => {
+      tpl.quasis.first()?.cooked.as_deref()
+    }
+    SwcExpr::Paren(paren) => static_string(&paren.expr),
+    _ => None,
+  }
+}
+
+fn arg_str(arg: &Option<SwcExprOrSpread>) -> Option<&str> {
+  match arg {
+    Some(SwcExprOrSpread { spread: None, expr }) => static_string(expr),
+    _ => None,
+  }
+}
+
+/// Whether the executable is a shell that runs the script passed to it, like
+/// `sh` or `/bin/bash`.
+fn is_shell(executable: &str) -> bool {
+  let name = executable.rsplit(['/', '\\']).next().unwrap_or(executable);
+  let name = name.to_ascii_lowercase();
+  let name = name.strip_suffix(".exe").unwrap_or(&name);
+  matches!(
+    name,
+    "sh"
+      | "bash"
+      | "zsh"
+      | "dash"
+      | "ksh"
+      | "fish"
+      | "cmd"
+      | "powershell"
+      | "pwsh"
+  )
+}
+
+/// Whether the argument tells a shell to run the next argument as a script,
+/// like `-c`, `-lc` or `/c`.
+fn is_script_flag(flag: &str) -> bool {
+  match flag.strip_prefix('-') {
+    Some(letters) => {
+      letters == "Command"
+        || (letters.ends_with('c')
+          && letters.chars().all(|c| c.is_ascii_alphabetic()))
+    }
+    None => flag.eq_ignore_ascii_case("/c"),
+  }
+}
+
+/// The elements of the array that is the value of `property` in the options.
+fn option_array<'a>(
+  options: Option<&'a SwcExpr>,
+  property: &str,
+) -> &'a [Option<SwcExprOrSpread>] {
+  let Some(SwcExpr::Object(options)) = options else {
+    return &[];
+  };
+  for prop in &options.props {
+    let PropOrSpread::Prop(prop) = prop else {
+      continue;
+    };
+    let Prop::KeyValue(KeyValueProp { key, value }) = &**prop else {
+      continue;
+    };
+    if matches!(key, PropName::Ident(ident) if ident.sym == *property) {
+      return match &**value {
+        SwcExpr::Array(array) => &array.elems,
+        _ => &[],
+      };
+    }
+  }
+  &[]
+}
+
+/// The expression of the argument at `index`, unless it is spread.
+fn arg<'a>(args: &[&ExprOrSpread<'a>], index: usize) -> Option<&'a SwcExpr> {
+  args
+    .get(index)
+    .filter(|arg| arg.spread().is_none())
+    .map(|arg| &*arg.inner.expr)
+}
+
+impl Handler for NoEvalLikeHandler<'_> {
+  fn new_expr(&mut self, new_expr: &NewExpr, ctx: &mut Context) {
+    let args = new_expr.args.unwrap_or_default();
+    if self.options.function_constructor
+      && is_global_ident(&new_expr.callee, "Function", ctx)
+    {
+      self.check_function_constructor(new_expr.range(), args, ctx);
+    }
+    if self.options.command_interpolation
+      && is_deno_api(&new_expr.callee, "Command", ctx)
+    {
+      self.check_command(
+        "Deno.Command",
+        arg(args, 0),
+        option_array(arg(args, 1), "args"),
+        ctx,
+      );
+    }
+  }
+
+  fn call_expr(&mut self, call_expr: &CallExpr, ctx: &mut Context) {
+    let Callee::Expr(callee) = &call_expr.callee else {
+      return;
+    };
+    if self.options.function_constructor
+      && is_global_ident(callee, "Function", ctx)
+    {
+      self.check_function_constructor(call_expr.range(), call_expr.args, ctx);
+    }
+    if self.options.command_interpolation && is_deno_api(callee, "run", ctx) {
+      let cmd = option_array(arg(call_expr.args, 0), "cmd");
+      if let Some((Some(executable), args)) = cmd.split_first() {
+        if executable.spread.is_none() {
+          self.check_command("Deno.run", Some(&executable.expr), args, ctx);
+        }
+      }
+    }
+    if self.options.string_timers {
+      if let Some(name) = timer_name(callee, ctx) {
+        if let Some(handler) = call_expr.args.first() {
+          if handler.spread().is_none() && is_string(&handler.expr) {
+            ctx.add_diagnostic_with_hint(
+              call_expr.range(),
+              CODE,
+              NoEvalLikeMessage::StringTimer(name.to_string()),
+              NoEvalLikeHint::PassFunction,
+            );
+          }
+        }
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_eval_like_valid() {
+    assert_lint_ok! {
+      NoEvalLike::default(),
+      "new Function('a', 'b', 'return a + b');",
+      "new Function(`return 1`);",
+      "new Function();",
+      "const Function = Foo; new Function(code);",
+      "setTimeout(() => tick(), 100);",
+      "setInterval(tick, 100);",
+      "globalThis.setTimeout(function () {}, 0);",
+      "timers.setTimeout('tick()', 100);",
+      r#"new Deno.Command("git", { args: ["log", since] }).output();"#,
+      r#"new Deno.Command("git", { args: ["log", "--oneline"] });"#,
+      r#"new Deno.Command("git", { args: ["log"], env: { HOME: `${home}/user` } });"#,
+      r#"new Deno.Command("git", { args: ["log", ...items.map((item) => `--grep=${item}`)] });"#,
+      r#"Deno.run({ cmd: ["deno", "--version"] });"#,
+      // separate arguments can't inject a command
+      r#"new Deno.Command("git", { args: [`--author=${name}`] });"#,
+      r#"new Deno.Command("sh", { args: ["build.sh", `${dir}/out`] });"#,
+      r#"new Deno.Command("sh", { args: ["-c", "echo $0", `${name}`] });"#,
+      r#"Deno.run({ cmd: ["git", "log", `--author=${name}`] });"#,
+      r#"Deno.run({ cmd: [...tool, `${file}`] });"#,
+    };
+
+    assert_lint_ok! {
+      NoEvalLike::new(NoEvalLikeOptions {
+        function_constructor: false,
+        string_timers: false,
+        command_interpolation: false,
+      }),
+      "new Function(code);",
+      "setTimeout('tick()', 100);",
+      "new Deno.Command(`${cmd}`);",
+    };
+  }
+
+  #[test]
+  fn no_eval_like_invalid() {
+    assert_lint_err! {
+      NoEvalLike::default(),
+      "new Function('a', body);": [
+        {
+          col: 0,
+          message: NoEvalLikeMessage::FunctionConstructor,
+          hint: NoEvalLikeHint::DefineFunction,
+        }
+      ],
+      "const fn = Function(`return ${expr}`);": [
+        {
+          col: 11,
+          message: NoEvalLikeMessage::FunctionConstructor,
+          hint: NoEvalLikeHint::DefineFunction,
+        }
+      ],
+      "new Function(...parts);": [
+        {
+          col: 0,
+          message: NoEvalLikeMessage::FunctionConstructor,
+          hint: NoEvalLikeHint::DefineFunction,
+        }
+      ],
+      "setTimeout('tick()', 100);": [
+        {
+          col: 0,
+          message: NoEvalLikeMessage::StringTimer("setTimeout".to_string()),
+          hint: NoEvalLikeHint::PassFunction,
+        }
+      ],
+      "globalThis.setInterval('update(' + id + ')', 1000);": [
+        {
+          col: 0,
+          message: NoEvalLikeMessage::StringTimer("setInterval".to_string()),
+          hint: NoEvalLikeHint::PassFunction,
+        }
+      ],
+      "new Deno.Command(\"sh\", { args: [\"-c\", `git checkout ${branch}`] });": [
+        {
+          col: 38,
+          message: NoEvalLikeMessage::CommandInterpolation("Deno.Command"),
+          hint: NoEvalLikeHint::SeparateArguments,
+        }
+      ],
+      "Deno.run({ cmd: [\"sh\", \"-c\", `rm -rf ${dir}`] });": [
+        {
+          col: 29,
+          message: NoEvalLikeMessage::CommandInterpolation("Deno.run"),
+          hint: NoEvalLikeHint::SeparateArguments,
+        }
+      ],
+      "new Deno.Command(`${bin}/deno`, { args: [\"run\"] });": [
+        {
+          col: 17,
+          message: NoEvalLikeMessage::CommandInterpolation("Deno.Command"),
+          hint: NoEvalLikeHint::SeparateArguments,
+        }
+      ],
+      "new Deno.Command(\"/bin/bash\", { args: [\"-lc\", `ls ${dir}`] });": [
+        {
+          col: 46,
+          message: NoEvalLikeMessage::CommandInterpolation("Deno.Command"),
+          hint: NoEvalLikeHint::SeparateArguments,
+        }
+      ],
+      "Deno.run({ cmd: [\"cmd.exe\", \"/C\", `del ${file}`] });": [
+        {
+          col: 34,
+          message: NoEvalLikeMessage::CommandInterpolation("Deno.run"),
+          hint: NoEvalLikeHint::SeparateArguments,
+        }
+      ],
+      "Deno.run({ cmd: [`${dir}/tool`, \"run\"] });": [
+        {
+          col: 17,
+          message: NoEvalLikeMessage::CommandInterpolation("Deno.run"),
+          hint: NoEvalLikeHint::SeparateArguments,
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn no_eval_like_options() {
+    let options: NoEvalLikeOptions =
+      serde_json::from_str(r#"{ "stringTimers": false }"#).unwrap();
+    assert!(options.function_constructor);
+    assert!(!options.string_timers);
+    assert!(options.command_interpolation);
+  }
+}
@@ -1,11 +1,10 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
-use crate::Program;
+use crate::handler::Handler;
 use deno_ast::view::{
-  ArrayPat, AssignExpr, AssignTarget, AssignTargetPat, Ident, ObjectPat,
-  ObjectPatProp, Pat, SimpleAssignTarget,
+  ArrayPat, AssignExpr, AssignTarget, AssignTargetPat, Ident, NodeKind,
+  ObjectPat, ObjectPatProp, Pat, SimpleAssignTarget,
 };
 use deno_ast::{BindingKind, SourceRange, SourceRanged};
 use derive_more::Display;
@@ -36,12 +35,12 @@ impl LintRule for NoExAssign {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    NoExAssignHandler.traverse(program, context);
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoExAssignHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::AssignExpr])
   }
 
   #[cfg(feature = "docs")]
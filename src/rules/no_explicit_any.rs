@@ -1,10 +1,9 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
-use crate::Program;
+use crate::handler::Handler;
 use deno_ast::swc::ast::TsKeywordTypeKind::TsAnyKeyword;
-use deno_ast::view::TsKeywordType;
+use deno_ast::view::{NodeKind, TsKeywordType};
 use deno_ast::SourceRanged;
 
 #[derive(Debug)]
@@ -23,12 +22,12 @@ impl LintRule for NoExplicitAny {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    NoExplicitAnyHandler.traverse(program, context);
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoExplicitAnyHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::TsKeywordType])
   }
 
   #[cfg(feature = "docs")]
@@ -1,11 +1,10 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
-use crate::Program;
+use crate::handler::Handler;
 use deno_ast::view::{
   CallExpr, Callee, CondExpr, DoWhileStmt, Expr, ExprOrSpread, ForStmt, Ident,
-  IfStmt, NewExpr, ParenExpr, UnaryExpr, UnaryOp, WhileStmt,
+  IfStmt, NewExpr, NodeKind, ParenExpr, UnaryExpr, UnaryOp, WhileStmt,
 };
 use deno_ast::SourceRange;
 use deno_ast::SourceRanged;
@@ -42,12 +41,21 @@ impl LintRule for NoExtraBooleanCast {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    NoExtraBooleanCastHandler.traverse(program, context);
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoExtraBooleanCastHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[
+      NodeKind::CondExpr,
+      NodeKind::ForStmt,
+      NodeKind::IfStmt,
+      NodeKind::WhileStmt,
+      NodeKind::DoWhileStmt,
+      NodeKind::CallExpr,
+      NodeKind::NewExpr,
+      NodeKind::UnaryExpr,
+    ])
   }
 
   #[cfg(feature = "docs")]
@@ -3,13 +3,11 @@
 use super::Context;
 use super::LintRule;
 use crate::handler::Handler;
-use crate::handler::Traverse;
-use crate::Program;
 
-use deno_ast::view::Expr;
 use deno_ast::view::OptChainBase;
 use deno_ast::view::OptChainExpr;
 use deno_ast::view::TsNonNullExpr;
+use deno_ast::view::{Expr, NodeKind};
 use deno_ast::SourceRange;
 use deno_ast::SourceRanged;
 use derive_more::Display;
@@ -40,12 +38,12 @@ impl LintRule for NoExtraNonNullAssertion {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    NoExtraNonNullAssertionHandler.traverse(program, context);
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoExtraNonNullAssertionHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::TsNonNullExpr, NodeKind::OptChainExpr])
   }
 
   #[cfg(feature = "docs")]
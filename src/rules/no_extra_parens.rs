@@ -0,0 +1,714 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::Handler;
+
+use deno_ast::swc::ast::{self, BinaryOp, Callee, Expr, VarDeclOrExpr};
+use deno_ast::view::{Node, NodeKind, NodeTrait, ParenExpr};
+use deno_ast::{SourceRange, SourceRanged, SourceRangedForSpanned};
+use derive_more::Display;
+use serde::Deserialize;
+
+#[derive(Debug, Default)]
+pub struct NoExtraParens {
+  options: NoExtraParensOptions,
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct NoExtraParensOptions {
+  pub style: NoExtraParensStyle,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum NoExtraParensStyle {
+  /// Unnecessary parentheses around any expression.
+  #[default]
+  All,
+  /// Unnecessary parentheses around function expressions only.
+  Functions,
+}
+
+impl NoExtraParens {
+  pub fn new(options: NoExtraParensOptions) -> Self {
+    Self { options }
+  }
+}
+
+const CODE: &str = "no-extra-parens";
+
+#[derive(Display)]
+enum NoExtraParensMessage {
+  #[display(fmt = "Unnecessary parentheses around expression")]
+  Unnecessary,
+}
+
+#[derive(Display)]
+enum NoExtraParensHint {
+  #[display(fmt = "Remove the parentheses")]
+  Remove,
+}
+
+const FIX_DESC: &str = "Remove the parentheses";
+
+impl LintRule for NoExtraParens {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "style": { "enum": ["all", "functions"], "default": "all" }
+      }
+    }))
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoExtraParensHandler {
+      options: &self.options,
+    }))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::ParenExpr])
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_extra_parens.md")
+  }
+}
+
+struct NoExtraParensHandler<'a> {
+  options: &'a NoExtraParensOptions,
+}
+
+impl Handler for NoExtraParensHandler<'_> {
+  fn paren_expr(&mut self, paren: &ParenExpr, ctx: &mut Context) {
+    let expr = &*paren.inner.expr;
+    if self.options.style == NoExtraParensStyle::Functions && !expr.is_fn_expr()
+    {
+      return;
+    }
+    // Nested parentheses like `((a))` are reported at the outer ones.
+    if matches!(paren.parent(), Node::ParenExpr(_)) {
+      return;
+    }
+    match min_precedence(paren.parent(), paren.inner) {
+      Some(min) if precedence(expr) >= min => {}
+      _ => return,
+    }
+    if !is_removable(paren, ctx) {
+      return;
+    }
+
+    ctx.add_diagnostic_with_fixes(
+      paren.range(),
+      CODE,
+      NoExtraParensMessage::Unnecessary,
+      Some(NoExtraParensHint::Remove.to_string()),
+      fix(paren, ctx),
+    );
+  }
+}
+
+const ASSIGNMENT: u8 = 1;
+const CONDITIONAL: u8 = 2;
+const UNARY: u8 = 14;
+const UPDATE: u8 = 15;
+const NEW_WITHOUT_ARGS: u8 = 16;
+const CALL: u8 = 17;
+const PRIMARY: u8 = 18;
+
+/// How tightly the expression binds, from sequences at `0` to primary
+/// expressions like identifiers and literals.
+fn precedence(expr: &Expr) -> u8 {
+  match expr {
+    Expr::Seq(_) => 0,
+    Expr::Assign(_) | Expr::Arrow(_) | Expr::Yield(_) => ASSIGNMENT,
+    Expr::Cond(_) => CONDITIONAL,
+    Expr::Bin(bin) => bin_precedence(bin.op),
+    Expr::TsAs(_) | Expr::TsSatisfies(_) | Expr::TsConstAssertion(_) => {
+      bin_precedence(BinaryOp::Lt)
+    }
+    Expr::Unary(_) | Expr::Await(_) | Expr::TsTypeAssertion(_) => UNARY,
+    Expr::Update(_) => UPDATE,
+    Expr::New(new) if new.args.is_none() => NEW_WITHOUT_ARGS,
+    Expr::Call(_)
+    | Expr::Member(_)
+    | Expr::SuperProp(_)
+    | Expr::New(_)
+    | Expr::OptChain(_)
+    | Expr::TaggedTpl(_)
+    | Expr::MetaProp(_)
+    | Expr::TsNonNull(_)
+    | Expr::TsInstantiation(_) => CALL,
+    _ => PRIMARY,
+  }
+}
+
+fn bin_precedence(op: BinaryOp) -> u8 {
+  CONDITIONAL + op.precedence()
+}
+
+/// The lowest precedence that the expression in the parentheses can have
+/// without them, or `None` if the parentheses are needed regardless of the
+/// expression, or aren't checked in that position.
+fn min_precedence(parent: Node, paren: &ast::ParenExpr) -> Option<u8> {
+  let range = paren.range();
+  let expr = &*paren.expr;
+  let min = match parent {
+    Node::ExprStmt(_)
+    | Node::ReturnStmt(_)
+    | Node::ThrowStmt(_)
+    | Node::IfStmt(_)
+    | Node::WhileStmt(_)
+    | Node::DoWhileStmt(_)
+    | Node::SwitchStmt(_)
+    | Node::SwitchCase(_)
+    | Node::ForInStmt(_)
+    | Node::ComputedPropName(_)
+    | Node::Tpl(_)
+    | Node::JSXExprContainer(_) => 0,
+    Node::ForStmt(for_stmt) => match &for_stmt.inner.init {
+      Some(VarDeclOrExpr::Expr(init)) if init.range() == range => return None,
+      _ => 0,
+    },
+    Node::ExprOrSpread(_)
+    | Node::SpreadElement(_)
+    | Node::VarDeclarator(_)
+    | Node::AssignPat(_)
+    | Node::KeyValueProp(_)
+    | Node::ClassProp(_)
+    | Node::ArrowExpr(_)
+    | Node::YieldExpr(_)
+    | Node::SeqExpr(_)
+    | Node::ForOfStmt(_)
+    | Node::ExportDefaultExpr(_) => ASSIGNMENT,
+    Node::AssignExpr(assign) if assign.inner.right.range() == range => {
+      ASSIGNMENT
+    }
+    Node::CondExpr(cond) if cond.inner.test.range() == range => CONDITIONAL + 1,
+    Node::CondExpr(_) => ASSIGNMENT,
+    Node::BinExpr(bin) => {
+      let op = bin.inner.op;
+      let is_left = bin.inner.left.range() == range;
+      // `??` can't be mixed with `||` or `&&` without parentheses.
+      if let Expr::Bin(inner) = expr {
+        if is_logical(op)
+          && is_logical(inner.op)
+          && (op == BinaryOp::NullishCoalescing)
+            != (inner.op == BinaryOp::NullishCoalescing)
+        {
+          return None;
+        }
+      }
+      if op == BinaryOp::Exp {
+        // `-a ** b` is a syntax error, and `**` is right-associative.
+        if is_left && precedence(expr) == UNARY {
+          return None;
+        }
+        bin_precedence(op) + u8::from(is_left)
+      } else {
+        bin_precedence(op) + u8::from(!is_left)
+      }
+    }
+    Node::UnaryExpr(_) | Node::AwaitExpr(_) => UNARY,
+    Node::UpdateExpr(_) => UPDATE,
+    Node::MemberExpr(member) if member.inner.obj.range() == range => {
+      // `1.toString()` would be parsed as a number, and the parentheses in
+      // `(a?.b).c` stop the short-circuiting.
+      if is_integer_literal(expr) || expr.is_opt_chain() {
+        return None;
+      }
+      CALL
+    }
+    Node::CallExpr(call) => match &call.inner.callee {
+      Callee::Expr(callee) if callee.range() == range => {
+        // The parentheses around the function of an IIFE are idiomatic.
+        if expr.is_opt_chain() || expr.is_fn_expr() {
+          return None;
+        }
+        CALL
+      }
+      _ => return None,
+    },
+    Node::NewExpr(new) if new.inner.callee.range() == range => {
+      // `new (a())()` would call `a` with `new`.
+      if is_constructor_reference(expr) {
+        0
+      } else {
+        return None;
+      }
+    }
+    _ => return None,
+  };
+  Some(min)
+}
+
+fn is_logical(op: BinaryOp) -> bool {
+  matches!(
+    op,
+    BinaryOp::LogicalOr | BinaryOp::LogicalAnd | BinaryOp::NullishCoalescing
+  )
+}
+
+fn is_integer_literal(expr: &Expr) -> bool {
+  match expr {
+    Expr::Lit(ast::Lit::Num(num)) => num
+      .raw
+      .as_ref()
+      .is_some_and(|raw| raw.chars().all(|c| c.is_ascii_digit() || c == '_')),
+    _ => false,
+  }
+}
+
+fn is_constructor_reference(expr: &Expr) -> bool {
+  match expr {
+    Expr::Ident(_) | Expr::This(_) => true,
+    Expr::Member(member) => is_constructor_reference(&member.obj),
+    _ => false,
+  }
+}
+
+/// Whether the code still parses the same way without the parentheses, which
+/// depends on more than the precedence of the expression.
+fn is_removable(paren: &ParenExpr, ctx: &Context) -> bool {
+  let range = paren.range();
+  let expr = &*paren.inner.expr;
+  let tokens = ctx.tokens_within(expr.range());
+  let Some(first) = tokens.first() else {
+    return false;
+  };
+  let first_text = ctx.text_of(first.range());
+  // The token that would follow the first one, which is after the closing
+  // parenthesis for expressions like `(let)[0]`.
+  let second = match tokens.get(1) {
+    Some(token) => Some(token),
+    None => ctx.token_after(range.end),
+  };
+  let second_text = second.map(|token| ctx.text_of(token.range()));
+
+  // The formatter wraps JSX that spans multiple lines in parentheses.
+  if matches!(expr, Expr::JSXElement(_) | Expr::JSXFragment(_))
+    && ctx.line_index(range.start) != ctx.line_index(range.end)
+  {
+    return false;
+  }
+
+  // `for (let a = ("b" in c); ;)` would be parsed as a `for...in` loop.
+  if matches!(expr, Expr::Bin(bin) if bin.op == BinaryOp::In) {
+    let in_for_init = paren.ancestors().any(|node| match node {
+      Node::ForStmt(for_stmt) => for_stmt
+        .inner
+        .init
+        .as_ref()
+        .is_some_and(|init| init.range().contains(&range)),
+      _ => false,
+    });
+    if in_for_init {
+      return false;
+    }
+  }
+
+  // Find what the expression would start without the parentheses, like the
+  // statement in `(a).b;`.
+  let mut node = paren.as_node();
+  while let Some(parent) = node.parent() {
+    if parent.start() == range.start && !matches!(parent, Node::ExprStmt(_)) {
+      node = parent;
+      continue;
+    }
+    return match parent {
+      Node::ExprStmt(_) => !matches!(
+        (first_text, second_text),
+        ("{" | "function" | "class", _)
+          | ("let", Some("["))
+          | ("async", Some("function"))
+      ),
+      Node::ArrowExpr(_) => first_text != "{",
+      Node::ExportDefaultExpr(_) => !matches!(
+        (first_text, second_text),
+        ("function" | "class", _) | ("async", Some("function"))
+      ),
+      // No line break is allowed after `return`, `throw` and `yield`.
+      Node::ReturnStmt(_) | Node::ThrowStmt(_) | Node::YieldExpr(_) => {
+        ctx.line_index(range.start) == ctx.line_index(first.start())
+      }
+      _ => true,
+    };
+  }
+  true
+}
+
+/// Removes the parentheses, keeping the tokens next to them apart, like in
+/// `typeof(a)`.
+fn fix(paren: &ParenExpr, ctx: &Context) -> Vec<LintFix> {
+  let range = paren.range();
+  if ctx.comments_within(range).next().is_some() {
+    return vec![];
+  }
+  let expr_range = paren.inner.expr.range();
+  let expr_text = ctx.text_of(expr_range);
+
+  let open = match ctx.token_before(range.start) {
+    Some(token)
+      if token.end() == range.start
+        && would_merge(ctx.text_of(token.range()), expr_text) =>
+    {
+      " "
+    }
+    _ => "",
+  };
+  let close = match ctx.token_after(range.end) {
+    Some(token)
+      if token.start() == range.end
+        && would_merge(expr_text, ctx.text_of(token.range())) =>
+    {
+      " "
+    }
+    _ => "",
+  };
+
+  vec![LintFix {
+    description: FIX_DESC.into(),
+    changes: vec![
+      LintFixChange {
+        new_text: open.into(),
+        range: SourceRange::new(range.start, expr_range.start),
+      },
+      LintFixChange {
+        new_text: close.into(),
+        range: SourceRange::new(expr_range.end, range.end),
+      },
+    ],
+  }]
+}
+
+/// Whether the texts would be read as different tokens when written next to
+/// each other, like `typeof` and `a`, or `-` and `-a`.
+fn would_merge(before: &str, after: &str) -> bool {
+  let (Some(a), Some(b)) = (before.chars().next_back(), after.chars().next())
+  else {
+    return false;
+  };
+  let is_word = |c: char| c.is_alphanumeric() || matches!(c, '_' | '$');
+  (is_word(a) && is_word(b))
+    || (a == b && matches!(a, '+' | '-'))
+    || (a == '/' && matches!(b, '/' | '*'))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_extra_parens_valid() {
+    assert_lint_ok! {
+      NoExtraParens::default(),
+      "a = b;",
+      "a = (b, c);",
+      "foo((a, b));",
+      "a = (b + c) * d;",
+      "a = b - (c - d);",
+      "a = (b ** c) ** d;",
+      "a = (-b) ** c;",
+      "a = (b || c) ?? d;",
+      "a = (b ?? c) && d;",
+      "a = (b ? c : d) ? e : f;",
+      "a = (b = c) ? d : e;",
+      "a = !(b && c);",
+      "a = (await b).c;",
+      "a = (b as C).d;",
+      "a = (new Foo).bar;",
+      "a = (1).toString();",
+      "a = (b?.c).d;",
+      "(b?.c)();",
+      "new (foo())();",
+      "(function() {})();",
+      "(function() {}).call(this);",
+      "(class {}).name;",
+      "({}).toString();",
+      "({ a } = b);",
+      "(let)[0] = 1;",
+      "(async function() {})();",
+      "const a = () => ({});",
+      "const a = () => ({}).b;",
+      "const a = () => (b, c);",
+      "export default (function() {});",
+      "function foo() { return (\n  a\n); }",
+      "function foo() { throw (\n  a\n).b; }",
+      "for (let i = (\"a\" in b); ;) {}",
+      "for (let i = (\"a\" in b) ? 1 : 2; ;) {}",
+      "(a) = 1;",
+    };
+
+    assert_lint_ok! {
+      NoExtraParens::new(NoExtraParensOptions {
+        style: NoExtraParensStyle::Functions,
+      }),
+      "a = (b);",
+      "a = (b * c);",
+      "(function() {})();",
+    };
+
+    assert_lint_ok! {
+      NoExtraParens::default(),
+      filename: "file:///foo.tsx",
+      "const a = (\n  <div>\n    <span />\n  </div>\n);",
+    };
+  }
+
+  #[test]
+  fn no_extra_parens_invalid() {
+    assert_lint_err! {
+      NoExtraParens::default(),
+      "a = (b);": [
+        {
+          col: 4,
+          message: NoExtraParensMessage::Unnecessary,
+          hint: NoExtraParensHint::Remove,
+          fix: (FIX_DESC, "a = b;"),
+        }
+      ],
+      "(a);": [
+        {
+          col: 0,
+          message: NoExtraParensMessage::Unnecessary,
+          hint: NoExtraParensHint::Remove,
+          fix: (FIX_DESC, "a;"),
+        }
+      ],
+      "a = ((b));": [
+        {
+          col: 4,
+          message: NoExtraParensMessage::Unnecessary,
+          hint: NoExtraParensHint::Remove,
+          fix: (FIX_DESC, "a = (b);"),
+        }
+      ],
+      "a = ( b * c ) + d;": [
+        {
+          col: 4,
+          message: NoExtraParensMessage::Unnecessary,
+          hint: NoExtraParensHint::Remove,
+          fix: (FIX_DESC, "a = b * c + d;"),
+        }
+      ],
+      "a = (b - c) - d;": [
+        {
+          col: 4,
+          message: NoExtraParensMessage::Unnecessary,
+          hint: NoExtraParensHint::Remove,
+          fix: (FIX_DESC, "a = b - c - d;"),
+        }
+      ],
+      "a = b ** (c ** d);": [
+        {
+          col: 9,
+          message: NoExtraParensMessage::Unnecessary,
+          hint: NoExtraParensHint::Remove,
+          fix: (FIX_DESC, "a = b ** c ** d;"),
+        }
+      ],
+      "a = (b.c)();": [
+        {
+          col: 4,
+          message: NoExtraParensMessage::Unnecessary,
+          hint: NoExtraParensHint::Remove,
+          fix: (FIX_DESC, "a = b.c();"),
+        }
+      ],
+      "a = (b()).c;": [
+        {
+          col: 4,
+          message: NoExtraParensMessage::Unnecessary,
+          hint: NoExtraParensHint::Remove,
+          fix: (FIX_DESC, "a = b().c;"),
+        }
+      ],
+      "a = (new Foo()).bar;": [
+        {
+          col: 4,
+          message: NoExtraParensMessage::Unnecessary,
+          hint: NoExtraParensHint::Remove,
+          fix: (FIX_DESC, "a = new Foo().bar;"),
+        }
+      ],
+      "a = new (b.c)();": [
+        {
+          col: 8,
+          message: NoExtraParensMessage::Unnecessary,
+          hint: NoExtraParensHint::Remove,
+          fix: (FIX_DESC, "a = new b.c();"),
+        }
+      ],
+      "a = (1.5).toFixed();": [
+        {
+          col: 4,
+          message: NoExtraParensMessage::Unnecessary,
+          hint: NoExtraParensHint::Remove,
+          fix: (FIX_DESC, "a = 1.5.toFixed();"),
+        }
+      ],
+      "a = typeof(b);": [
+        {
+          col: 10,
+          message: NoExtraParensMessage::Unnecessary,
+          hint: NoExtraParensHint::Remove,
+          fix: (FIX_DESC, "a = typeof b;"),
+        }
+      ],
+      "a = -(-b);": [
+        {
+          col: 5,
+          message: NoExtraParensMessage::Unnecessary,
+          hint: NoExtraParensHint::Remove,
+          fix: (FIX_DESC, "a = - -b;"),
+        }
+      ],
+      "a = (b)in c;": [
+        {
+          col: 4,
+          message: NoExtraParensMessage::Unnecessary,
+          hint: NoExtraParensHint::Remove,
+          fix: (FIX_DESC, "a = b in c;"),
+        }
+      ],
+      "a = `${(b)}`;": [
+        {
+          col: 7,
+          message: NoExtraParensMessage::Unnecessary,
+          hint: NoExtraParensHint::Remove,
+          fix: (FIX_DESC, "a = `${b}`;"),
+        }
+      ],
+      "a = b[(c)];": [
+        {
+          col: 6,
+          message: NoExtraParensMessage::Unnecessary,
+          hint: NoExtraParensHint::Remove,
+          fix: (FIX_DESC, "a = b[c];"),
+        }
+      ],
+      "if ((a)) {}": [
+        {
+          col: 4,
+          message: NoExtraParensMessage::Unnecessary,
+          hint: NoExtraParensHint::Remove,
+          fix: (FIX_DESC, "if (a) {}"),
+        }
+      ],
+      "const a = () => (b);": [
+        {
+          col: 16,
+          message: NoExtraParensMessage::Unnecessary,
+          hint: NoExtraParensHint::Remove,
+          fix: (FIX_DESC, "const a = () => b;"),
+        }
+      ],
+      "function foo() { return (a); }": [
+        {
+          col: 24,
+          message: NoExtraParensMessage::Unnecessary,
+          hint: NoExtraParensHint::Remove,
+          fix: (FIX_DESC, "function foo() { return a; }"),
+        }
+      ],
+      "function foo() { return (a\n); }": [
+        {
+          col: 24,
+          message: NoExtraParensMessage::Unnecessary,
+          hint: NoExtraParensHint::Remove,
+          fix: (FIX_DESC, "function foo() { return a; }"),
+        }
+      ],
+      "a = (/* b */ b);": [
+        {
+          col: 4,
+          message: NoExtraParensMessage::Unnecessary,
+          hint: NoExtraParensHint::Remove,
+        }
+      ],
+      "foo((a), ...(b));": [
+        {
+          col: 4,
+          message: NoExtraParensMessage::Unnecessary,
+          hint: NoExtraParensHint::Remove,
+          fix: (FIX_DESC, "foo(a, ...(b));"),
+        },
+        {
+          col: 12,
+          message: NoExtraParensMessage::Unnecessary,
+          hint: NoExtraParensHint::Remove,
+          fix: (FIX_DESC, "foo((a), ...b);"),
+        },
+      ],
+      "a = (b) ? (c) : (d, e);": [
+        {
+          col: 4,
+          message: NoExtraParensMessage::Unnecessary,
+          hint: NoExtraParensHint::Remove,
+          fix: (FIX_DESC, "a = b ? (c) : (d, e);"),
+        },
+        {
+          col: 10,
+          message: NoExtraParensMessage::Unnecessary,
+          hint: NoExtraParensHint::Remove,
+          fix: (FIX_DESC, "a = (b) ? c : (d, e);"),
+        },
+      ],
+      "(function() {}).call(this);\na = (function() {}).call(this);": [
+        {
+          line: 2,
+          col: 4,
+          message: NoExtraParensMessage::Unnecessary,
+          hint: NoExtraParensHint::Remove,
+          fix: (FIX_DESC, "(function() {}).call(this);\na = function() {}.call(this);"),
+        },
+      ],
+    };
+
+    assert_lint_err! {
+      NoExtraParens::default(),
+      filename: "file:///foo.tsx",
+      "const a = (<div />);": [
+        {
+          col: 10,
+          message: NoExtraParensMessage::Unnecessary,
+          hint: NoExtraParensHint::Remove,
+          fix: (FIX_DESC, "const a = <div />;"),
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoExtraParens::new(NoExtraParensOptions {
+        style: NoExtraParensStyle::Functions,
+      }),
+      "a = (function() {});": [
+        {
+          col: 4,
+          message: NoExtraParensMessage::Unnecessary,
+          hint: NoExtraParensHint::Remove,
+          fix: (FIX_DESC, "a = function() {};"),
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn no_extra_parens_options() {
+    let options: NoExtraParensOptions =
+      serde_json::from_str(r#"{ "style": "functions" }"#).unwrap();
+    assert_eq!(options.style, NoExtraParensStyle::Functions);
+  }
+}
@@ -0,0 +1,150 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::Handler;
+
+use deno_ast::view::{EmptyStmt, Node, NodeKind};
+use deno_ast::SourceRanged;
+
+#[derive(Debug)]
+pub struct NoExtraSemi;
+
+const CODE: &str = "no-extra-semi";
+const MESSAGE: &str = "Unnecessary semicolon";
+const HINT: &str = "Remove the extra semicolon";
+const FIX_DESC: &str = HINT;
+
+impl LintRule for NoExtraSemi {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoExtraSemiHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::EmptyStmt])
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_extra_semi.md")
+  }
+}
+
+struct NoExtraSemiHandler;
+
+impl Handler for NoExtraSemiHandler {
+  fn empty_stmt(&mut self, empty_stmt: &EmptyStmt, ctx: &mut Context) {
+    // Empty statements are allowed as the body of loops, `if` statements and
+    // labels, like `while (next());`.
+    if !matches!(
+      empty_stmt.parent(),
+      Node::Module(_)
+        | Node::Script(_)
+        | Node::BlockStmt(_)
+        | Node::SwitchCase(_)
+        | Node::TsModuleBlock(_)
+        | Node::Class(_)
+    ) {
+      return;
+    }
+
+    ctx.add_diagnostic_with_fixes(
+      empty_stmt.range(),
+      CODE,
+      MESSAGE,
+      Some(HINT.to_string()),
+      vec![LintFix {
+        description: FIX_DESC.into(),
+        changes: vec![LintFixChange {
+          new_text: "".into(),
+          range: empty_stmt.range(),
+        }],
+      }],
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_extra_semi_valid() {
+    assert_lint_ok! {
+      NoExtraSemi,
+      "const a = 1;",
+      "function foo() {}",
+      "class A { foo() {} }",
+      "class A { a = 1; }",
+      "for (;;);",
+      "while (next());",
+      "if (a);",
+      "if (a) {} else;",
+      "label: ;",
+      "do ; while (a);",
+    };
+  }
+
+  #[test]
+  fn no_extra_semi_invalid() {
+    assert_lint_err! {
+      NoExtraSemi,
+      "const a = 1;;": [
+        {
+          col: 12,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "const a = 1;"),
+        }
+      ],
+      "function foo() {};": [
+        {
+          col: 17,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "function foo() {}"),
+        }
+      ],
+      "function foo() { return; ; }": [
+        {
+          col: 25,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "function foo() { return;  }"),
+        }
+      ],
+      "class A { foo() {}; }": [
+        {
+          col: 18,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "class A { foo() {} }"),
+        }
+      ],
+      "switch (a) { case 1: ; }": [
+        {
+          col: 21,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "switch (a) { case 1:  }"),
+        }
+      ],
+      "namespace A { ; }": [
+        {
+          col: 14,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "namespace A {  }"),
+        }
+      ],
+    };
+  }
+}
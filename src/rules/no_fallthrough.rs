@@ -2,6 +2,7 @@
 
 use super::program_ref;
 use super::{Context, LintRule};
+use crate::syntax_features::SyntaxFeatures;
 use crate::Program;
 use crate::ProgramRef;
 use deno_ast::swc::common::comments::Comment;
@@ -40,6 +41,10 @@ impl LintRule for NoFallthrough {
     CODE
   }
 
+  fn required_syntax(&self) -> SyntaxFeatures {
+    SyntaxFeatures::SWITCH
+  }
+
   fn lint_program_with_ast_view<'view>(
     &self,
     context: &mut Context<'view>,
@@ -0,0 +1,162 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::Handler;
+use crate::testing::TestCall;
+use deno_ast::view::{CallExpr, Callee, Expr, Lit, NodeKind};
+use deno_ast::{SourceRange, SourceRanged};
+use derive_more::Display;
+
+#[derive(Debug)]
+pub struct NoFocusedTests;
+
+const CODE: &str = "no-focused-tests";
+const FIX_DESC: &str = "Remove `.only`";
+
+#[derive(Display)]
+enum NoFocusedTestsMessage {
+  #[display(fmt = "Focused test with `.only`")]
+  OnlyModifier,
+  #[display(fmt = "Focused test with `only: true`")]
+  OnlyOption,
+}
+
+#[derive(Display)]
+enum NoFocusedTestsHint {
+  #[display(fmt = "Remove it, so that the other tests run too")]
+  Remove,
+}
+
+impl LintRule for NoFocusedTests {
+  fn tags(&self) -> &'static [&'static str] {
+    &["test"]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn aliases(&self) -> &'static [&'static str] {
+    &["jest/no-focused-tests"]
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoFocusedTestsHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::CallExpr])
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_focused_tests.md")
+  }
+}
+
+struct NoFocusedTestsHandler;
+
+impl Handler for NoFocusedTestsHandler {
+  fn call_expr(&mut self, call_expr: &CallExpr, ctx: &mut Context) {
+    let Some(test) = TestCall::from_call(call_expr, ctx) else {
+      return;
+    };
+
+    if let (Some("only"), Callee::Expr(Expr::Member(callee))) =
+      (test.modifier_name(), &call_expr.callee)
+    {
+      let only = SourceRange::new(callee.obj.end(), callee.prop.end());
+      ctx.add_diagnostic_with_fixes(
+        callee.prop.range(),
+        CODE,
+        NoFocusedTestsMessage::OnlyModifier,
+        Some(NoFocusedTestsHint::Remove.to_string()),
+        vec![LintFix {
+          description: FIX_DESC.into(),
+          changes: vec![LintFixChange {
+            new_text: "".into(),
+            range: only,
+          }],
+        }],
+      );
+    }
+
+    if let Some(only) = test.option("only") {
+      if matches!(only.value, Expr::Lit(Lit::Bool(value)) if value.value()) {
+        ctx.add_diagnostic_with_hint(
+          only.range(),
+          CODE,
+          NoFocusedTestsMessage::OnlyOption,
+          NoFocusedTestsHint::Remove,
+        );
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_focused_tests_valid() {
+    assert_lint_ok! {
+      NoFocusedTests,
+      r#"Deno.test("adds", () => {});"#,
+      r#"Deno.test({ name: "adds", only: false, fn() {} });"#,
+      r#"Deno.test.ignore("adds", () => {});"#,
+      r#"it("adds", () => {});"#,
+      r#"describe.skip("math", () => {});"#,
+      r#"list.only();"#,
+      r#"const Deno = {}; Deno.test.only("adds", () => {});"#,
+    };
+  }
+
+  #[test]
+  fn no_focused_tests_invalid() {
+    assert_lint_err! {
+      NoFocusedTests,
+      r#"Deno.test.only("adds", () => {});"#: [
+        {
+          col: 10,
+          message: NoFocusedTestsMessage::OnlyModifier,
+          hint: NoFocusedTestsHint::Remove,
+          fix: (FIX_DESC, r#"Deno.test("adds", () => {});"#),
+        }
+      ],
+      r#"describe.only("math", () => { it.only("adds", () => {}); });"#: [
+        {
+          col: 9,
+          message: NoFocusedTestsMessage::OnlyModifier,
+          hint: NoFocusedTestsHint::Remove,
+          fix: (FIX_DESC, r#"describe("math", () => { it.only("adds", () => {}); });"#),
+        },
+        {
+          col: 33,
+          message: NoFocusedTestsMessage::OnlyModifier,
+          hint: NoFocusedTestsHint::Remove,
+          fix: (FIX_DESC, r#"describe.only("math", () => { it("adds", () => {}); });"#),
+        }
+      ],
+      r#"Deno.test({ name: "adds", only: true, fn() {} });"#: [
+        {
+          col: 26,
+          message: NoFocusedTestsMessage::OnlyOption,
+          hint: NoFocusedTestsHint::Remove,
+        }
+      ],
+      r#"Deno.test("adds", { only: true }, () => {});"#: [
+        {
+          col: 20,
+          message: NoFocusedTestsMessage::OnlyOption,
+          hint: NoFocusedTestsHint::Remove,
+        }
+      ],
+    };
+  }
+}
@@ -1,10 +1,9 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::handler::Handler;
 use crate::swc_util::find_lhs_ids;
-use crate::Program;
-use deno_ast::view::AssignExpr;
+use deno_ast::view::{AssignExpr, NodeKind};
 use deno_ast::{BindingKind, SourceRanged};
 use derive_more::Display;
 
@@ -36,12 +35,12 @@ impl LintRule for NoFuncAssign {
     CODE
   }
 
-  fn lint_program_with_ast_view<'view>(
-    &self,
-    context: &mut Context<'view>,
-    program: Program<'view>,
-  ) {
-    NoFuncAssignVisitor.traverse(program, context);
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoFuncAssignVisitor))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::AssignExpr])
   }
 
   #[cfg(feature = "docs")]
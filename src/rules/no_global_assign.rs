@@ -1,17 +1,56 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
+use crate::globals::{Environment, Globals};
 use crate::handler::{Handler, Traverse};
+use crate::swc_util::find_lhs_ids;
 use crate::Program;
-use crate::{globals::GLOBALS, swc_util::find_lhs_ids};
 use deno_ast::swc::ast::Id;
 use deno_ast::SourceRange;
 use deno_ast::SourceRangedForSpanned;
 use deno_ast::{view::*, SourceRanged};
 use derive_more::Display;
+use serde::Deserialize;
 
-#[derive(Debug)]
-pub struct NoGlobalAssign;
+#[derive(Debug, Default)]
+pub struct NoGlobalAssign {
+  globals: Globals,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct NoGlobalAssignOptions {
+  /// The environments whose globals can't be assigned to, which is only Deno
+  /// by default.
+  pub env: Vec<Environment>,
+  /// Globals that may be assigned to anyway.
+  pub exceptions: Vec<String>,
+}
+
+impl Default for NoGlobalAssignOptions {
+  fn default() -> Self {
+    Self {
+      env: vec![Environment::Deno],
+      exceptions: vec![],
+    }
+  }
+}
+
+impl NoGlobalAssign {
+  pub fn new(options: NoGlobalAssignOptions) -> Self {
+    let mut globals = Globals::new(options.env);
+    for name in options.exceptions {
+      globals.add(name, true);
+    }
+    Self::with_globals(globals)
+  }
+
+  /// Uses the globals as they are, for embedders with read-only globals that
+  /// no environment has.
+  pub fn with_globals(globals: Globals) -> Self {
+    Self { globals }
+  }
+}
 
 const CODE: &str = "no-global-assign";
 
@@ -36,12 +75,28 @@ impl LintRule for NoGlobalAssign {
     CODE
   }
 
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "env": {
+          "type": "array",
+          "items": { "enum": ["deno", "browser", "worker", "node"] }
+        },
+        "exceptions": { "type": "array", "items": { "type": "string" } }
+      }
+    }))
+  }
+
   fn lint_program_with_ast_view<'view>(
     &self,
     context: &mut Context<'view>,
     program: Program<'view>,
   ) {
-    NoGlobalAssignVisitor.traverse(program, context)
+    NoGlobalAssignVisitor {
+      globals: &self.globals,
+    }
+    .traverse(program, context)
   }
 
   #[cfg(feature = "docs")]
@@ -50,9 +105,11 @@ impl LintRule for NoGlobalAssign {
   }
 }
 
-struct NoGlobalAssignVisitor;
+struct NoGlobalAssignVisitor<'a> {
+  globals: &'a Globals,
+}
 
-impl NoGlobalAssignVisitor {
+impl NoGlobalAssignVisitor<'_> {
   fn check(&mut self, range: SourceRange, id: Id, ctx: &mut Context) {
     if id.1 != ctx.unresolved_ctxt() {
       return;
@@ -62,24 +119,19 @@ impl NoGlobalAssignVisitor {
       return;
     }
 
-    // We only care about globals.
-    let maybe_global = GLOBALS.iter().find(|(name, _)| name == &&*id.0);
-
-    if let Some(global) = maybe_global {
-      // If global can be overwritten then don't need to report anything
-      if !global.1 {
-        ctx.add_diagnostic_with_hint(
-          range,
-          CODE,
-          NoGlobalAssignMessage::NotAllowed,
-          NoGlobalAssignHint::Remove,
-        );
-      }
+    // We only care about globals that can't be overwritten.
+    if self.globals.is_writable(&id.0) == Some(false) {
+      ctx.add_diagnostic_with_hint(
+        range,
+        CODE,
+        NoGlobalAssignMessage::NotAllowed,
+        NoGlobalAssignHint::Remove,
+      );
     }
   }
 }
 
-impl Handler for NoGlobalAssignVisitor {
+impl Handler for NoGlobalAssignVisitor<'_> {
   fn assign_expr(&mut self, e: &AssignExpr, ctx: &mut Context) {
     let idents: Vec<deno_ast::swc::ast::Ident> = find_lhs_ids(&e.left);
 
@@ -102,13 +154,16 @@ mod tests {
   #[test]
   fn no_global_assign_valid() {
     assert_lint_ok! {
-      NoGlobalAssign,
+      NoGlobalAssign::default(),
       "string = 'hello world';",
       "var string;",
       "top = 0;",
       "require = 0;",
       "onmessage = function () {};",
       "let Array = 0; Array = 42;",
+      "function f(Deno) { Deno = {}; }",
+      "const undefined = 0;",
+      "document = null;",
       r#"
 let Boolean = true;
 function foo() {
@@ -121,7 +176,7 @@ function foo() {
   #[test]
   fn no_global_assign_invalid() {
     assert_lint_err! {
-      NoGlobalAssign,
+      NoGlobalAssign::default(),
       "String = 'hello world';": [
         {
           col: 0,
@@ -155,6 +210,27 @@ function foo() {
           hint: NoGlobalAssignHint::Remove,
         }
       ],
+      "Object = null;": [
+        {
+          col: 0,
+          message: NoGlobalAssignMessage::NotAllowed,
+          hint: NoGlobalAssignHint::Remove,
+        }
+      ],
+      "undefined = 1;": [
+        {
+          col: 0,
+          message: NoGlobalAssignMessage::NotAllowed,
+          hint: NoGlobalAssignHint::Remove,
+        }
+      ],
+      "Deno = {};": [
+        {
+          col: 0,
+          message: NoGlobalAssignMessage::NotAllowed,
+          hint: NoGlobalAssignHint::Remove,
+        }
+      ],
       r#"
 function foo() {
   let Boolean = false;
@@ -171,4 +247,44 @@ Boolean = true;
       ],
     };
   }
+
+  #[test]
+  fn no_global_assign_options() {
+    let options: NoGlobalAssignOptions = serde_json::from_str(
+      r#"{ "env": ["browser"], "exceptions": ["Object"] }"#,
+    )
+    .unwrap();
+    assert_eq!(options.env, vec![Environment::Browser]);
+
+    assert_lint_ok! {
+      NoGlobalAssign::new(options.clone()),
+      "Object = null;",
+      "Deno = {};",
+    };
+
+    assert_lint_err! {
+      NoGlobalAssign::new(NoGlobalAssignOptions {
+        env: vec![Environment::Browser],
+        exceptions: vec![],
+      }),
+      "document = null;": [
+        {
+          col: 0,
+          message: NoGlobalAssignMessage::NotAllowed,
+          hint: NoGlobalAssignHint::Remove,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoGlobalAssign::with_globals(Globals::default().with_global("Bun", false)),
+      "Bun = {};": [
+        {
+          col: 0,
+          message: NoGlobalAssignMessage::NotAllowed,
+          hint: NoGlobalAssignHint::Remove,
+        }
+      ],
+    };
+  }
 }
@@ -1,11 +1,12 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
-use crate::Program;
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::Handler;
 use deno_ast::swc::parser::token::{IdentLike, KnownIdent, Token, Word};
 use deno_ast::view as ast_view;
-use deno_ast::{SourceRanged, SourceRangedForSpanned};
+use deno_ast::view::{NodeKind, NodeTrait};
+use deno_ast::{SourceRange, SourceRanged, SourceRangedForSpanned};
 use if_chain::if_chain;
 
 #[derive(Debug)]
@@ -15,6 +16,43 @@ const CODE: &str = "no-import-assertions";
 const MESSAGE: &str =
   "The `assert` keyword is deprecated for import attributes";
 const HINT: &str = "Instead use the `with` keyword";
+const FIX_DESC: &str = "Replace `assert` with `with`";
+
+fn add_diagnostic(
+  range: SourceRange,
+  new_text: &'static str,
+  ctx: &mut Context,
+) {
+  ctx.add_diagnostic_with_fixes(
+    range,
+    CODE,
+    MESSAGE,
+    Some(HINT.to_string()),
+    vec![LintFix {
+      description: FIX_DESC.into(),
+      changes: vec![LintFixChange {
+        new_text: new_text.into(),
+        range,
+      }],
+    }],
+  );
+}
+
+/// Reports the `assert` keyword in front of the attributes of a static import
+/// or re-export, e.g. `import foo from "./foo.json" assert { type: "json" }`.
+fn check_with_clause(with: Option<&ast_view::ObjectLit>, ctx: &mut Context) {
+  if_chain! {
+    if let Some(with) = with;
+    if let Some(prev_token_and_span) = ctx.token_before(with.start());
+    if let Token::Word(word) = &prev_token_and_span.token;
+    if let Word::Ident(ident_like) = word;
+    if let IdentLike::Known(known_ident) = ident_like;
+    if matches!(known_ident, KnownIdent::Assert);
+    then {
+      add_diagnostic(prev_token_and_span.span.range(), "with", ctx);
+    }
+  }
+}
 
 impl LintRule for NoImportAssertions {
   fn tags(&self) -> &'static [&'static str] {
@@ -25,12 +63,21 @@ impl LintRule for NoImportAssertions {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program<'_>,
-  ) {
-    NoImportAssertionsHandler.traverse(program, context);
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoImportAssertionsHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[
+      NodeKind::ImportDecl,
+      NodeKind::NamedExport,
+      NodeKind::ExportAll,
+      NodeKind::CallExpr,
+    ])
   }
 
   #[cfg(feature = "docs")]
@@ -47,22 +94,23 @@ impl Handler for NoImportAssertionsHandler {
     import_decl: &ast_view::ImportDecl,
     ctx: &mut Context,
   ) {
-    if_chain! {
-      if let Some(with) = import_decl.with;
-      if let Some(prev_token_and_span) = with.start().previous_token_fast(ctx.program());
-      if let Token::Word(word) = &prev_token_and_span.token;
-      if let Word::Ident(ident_like) = word;
-      if let IdentLike::Known(known_ident) = ident_like;
-      if matches!(known_ident, KnownIdent::Assert);
-      then {
-        ctx.add_diagnostic_with_hint(
-          prev_token_and_span.span.range(),
-          CODE,
-          MESSAGE,
-          HINT,
-        );
-      }
-    }
+    check_with_clause(import_decl.with, ctx);
+  }
+
+  fn named_export(
+    &mut self,
+    named_export: &ast_view::NamedExport,
+    ctx: &mut Context,
+  ) {
+    check_with_clause(named_export.with, ctx);
+  }
+
+  fn export_all(
+    &mut self,
+    export_all: &ast_view::ExportAll,
+    ctx: &mut Context,
+  ) {
+    check_with_clause(export_all.with, ctx);
   }
 
   fn call_expr(&mut self, call_expr: &ast_view::CallExpr, ctx: &mut Context) {
@@ -79,22 +127,14 @@ impl Handler for NoImportAssertionsHandler {
               match key_value_prop.key {
                 ast_view::PropName::Ident(ident) => {
                   if ident.sym().as_ref() == "assert" {
-                    ctx.add_diagnostic_with_hint(
-                      ident.range(),
-                      CODE,
-                      MESSAGE,
-                      HINT,
-                    );
+                    add_diagnostic(ident.range(), "with", ctx);
                   }
                 },
                 ast_view::PropName::Str(str) => {
                   if str.value().as_ref() == "assert" {
-                    ctx.add_diagnostic_with_hint(
-                      str.range(),
-                      CODE,
-                      MESSAGE,
-                      HINT,
-                    );
+                    let quote = &str.text()[..1];
+                    let new_text = if quote == "'" { "'with'" } else { "\"with\"" };
+                    add_diagnostic(str.range(), new_text, ctx);
                   }
                 }
                 _ => (),
@@ -120,6 +160,8 @@ mod tests {
       r#"import('./foo.js');"#,
       r#"import('./foo.js', { with: { bar: 'bar' } });"#,
       r#"import('./foo.js', { "with": { bar: 'bar' } });"#,
+      r#"export { foo } from './foo.js' with { bar: 'bar' };"#,
+      r#"export * from './foo.js' with { bar: 'bar' };"#,
     };
   }
 
@@ -133,18 +175,57 @@ mod tests {
         {
           line: 1,
           col: 27,
+          fix: (
+            FIX_DESC,
+            r#"import foo from './foo.js' with { bar: 'bar' };"#
+          ),
         },
       ],
       r#"import('./foo.js', { assert: { bar: 'bar' } });"#: [
         {
           line: 1,
           col: 21,
+          fix: (FIX_DESC, r#"import('./foo.js', { with: { bar: 'bar' } });"#),
         },
       ],
       r#"import('./foo.js', { "assert": { bar: 'bar' } });"#: [
         {
           line: 1,
           col: 21,
+          fix: (
+            FIX_DESC,
+            r#"import('./foo.js', { "with": { bar: 'bar' } });"#
+          ),
+        },
+      ],
+      r#"import('./foo.js', { 'assert': { bar: 'bar' } });"#: [
+        {
+          line: 1,
+          col: 21,
+          fix: (
+            FIX_DESC,
+            r#"import('./foo.js', { 'with': { bar: 'bar' } });"#
+          ),
+        },
+      ],
+      r#"export { foo } from './foo.js' assert { bar: 'bar' };"#: [
+        {
+          line: 1,
+          col: 31,
+          fix: (
+            FIX_DESC,
+            r#"export { foo } from './foo.js' with { bar: 'bar' };"#
+          ),
+        },
+      ],
+      r#"export * from './foo.js' assert { bar: 'bar' };"#: [
+        {
+          line: 1,
+          col: 25,
+          fix: (
+            FIX_DESC,
+            r#"export * from './foo.js' with { bar: 'bar' };"#
+          ),
         },
       ],
     };
@@ -332,13 +332,12 @@ impl<'c, 'view> Visit for NoInferrableTypesVisitor<'c, 'view> {
   }
 
   fn visit_class_prop(&mut self, prop: &ClassProp) {
-    if prop.readonly || prop.is_optional {
-      return;
-    }
-    if let Some(init) = &prop.value {
-      if let PropName::Ident(_) = &prop.key {
-        if let Some(ident_type_ann) = &prop.type_ann {
-          self.check_ts_type(init, ident_type_ann, prop.range());
+    if !prop.readonly && !prop.is_optional {
+      if let Some(init) = &prop.value {
+        if let PropName::Ident(_) = &prop.key {
+          if let Some(ident_type_ann) = &prop.type_ann {
+            self.check_ts_type(init, ident_type_ann, prop.range());
+          }
         }
       }
     }
@@ -346,12 +345,11 @@ impl<'c, 'view> Visit for NoInferrableTypesVisitor<'c, 'view> {
   }
 
   fn visit_private_prop(&mut self, prop: &PrivateProp) {
-    if prop.readonly || prop.is_optional {
-      return;
-    }
-    if let Some(init) = &prop.value {
-      if let Some(ident_type_ann) = &prop.type_ann {
-        self.check_ts_type(init, ident_type_ann, prop.range());
+    if !prop.readonly && !prop.is_optional {
+      if let Some(init) = &prop.value {
+        if let Some(ident_type_ann) = &prop.type_ann {
+          self.check_ts_type(init, ident_type_ann, prop.range());
+        }
       }
     }
     prop.visit_children_with(self);
@@ -826,6 +824,20 @@ mod tests {
           hint: NoInferrableTypesHint::Remove,
         }
       ],
+      "class A { readonly a = () => { let x: number = 42; }; }": [
+        {
+          col: 35,
+          message: NoInferrableTypesMessage::NotAllowed,
+          hint: NoInferrableTypesHint::Remove,
+        }
+      ],
+      "class A { @dec(() => { let x: number = 42; }) a?: number; }": [
+        {
+          col: 27,
+          message: NoInferrableTypesMessage::NotAllowed,
+          hint: NoInferrableTypesHint::Remove,
+        }
+      ],
     };
   }
 }
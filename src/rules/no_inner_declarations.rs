@@ -1,18 +1,14 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
-use super::program_ref;
 use super::{Context, LintRule};
-use crate::Program;
-use crate::ProgramRef;
-use deno_ast::swc::ast::{
-  ArrowExpr, BlockStmtOrExpr, Constructor, Decl, DefaultDecl, FnDecl, Function,
-  ModuleDecl, ModuleItem, Script, Stmt, VarDecl, VarDeclKind,
-};
-use deno_ast::swc::visit::{noop_visit_type, Visit, VisitWith};
+use crate::handler::Handler;
+use crate::node_id::NodeId;
+use deno_ast::swc::ast::VarDeclKind;
+use deno_ast::view as ast_view;
+use deno_ast::view::{Node, NodeKind, NodeTrait};
 use deno_ast::SourceRange;
-use deno_ast::SourceRangedForSpanned;
+use deno_ast::SourceRanged;
 use derive_more::Display;
-use std::collections::HashSet;
 
 #[derive(Debug)]
 pub struct NoInnerDeclarations;
@@ -40,24 +36,12 @@ impl LintRule for NoInnerDeclarations {
     CODE
   }
 
-  fn lint_program_with_ast_view<'view>(
-    &self,
-    context: &mut Context<'view>,
-    program: Program<'view>,
-  ) {
-    let program = program_ref(program);
-    let mut valid_visitor = ValidDeclsVisitor::new();
-    match program {
-      ProgramRef::Module(m) => m.visit_with(&mut valid_visitor),
-      ProgramRef::Script(s) => s.visit_with(&mut valid_visitor),
-    }
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoInnerDeclarationsHandler))
+  }
 
-    let mut visitor =
-      NoInnerDeclarationsVisitor::new(context, valid_visitor.valid_decls);
-    match program {
-      ProgramRef::Module(m) => m.visit_with(&mut visitor),
-      ProgramRef::Script(s) => s.visit_with(&mut visitor),
-    }
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::FnDecl, NodeKind::VarDecl])
   }
 
   #[cfg(feature = "docs")]
@@ -66,170 +50,74 @@ impl LintRule for NoInnerDeclarations {
   }
 }
 
-struct ValidDeclsVisitor {
-  valid_decls: HashSet<SourceRange>,
-}
+struct NoInnerDeclarationsHandler;
 
-impl ValidDeclsVisitor {
-  fn new() -> Self {
-    Self {
-      valid_decls: HashSet::new(),
-    }
+impl Handler for NoInnerDeclarationsHandler {
+  fn fn_decl(&mut self, fn_decl: &ast_view::FnDecl, ctx: &mut Context) {
+    check(ctx, fn_decl.as_node(), "function");
   }
-}
 
-impl ValidDeclsVisitor {
-  fn check_stmts(&mut self, stmts: &[Stmt]) {
-    for stmt in stmts {
-      if let Stmt::Decl(decl) = stmt {
-        self.check_decl(decl);
-      }
-    }
-  }
-
-  fn check_decl(&mut self, decl: &Decl) {
-    match decl {
-      Decl::Fn(fn_decl) => {
-        self.valid_decls.insert(fn_decl.range());
-      }
-      Decl::Var(var_decl) => {
-        if var_decl.kind == VarDeclKind::Var {
-          self.valid_decls.insert(var_decl.range());
-        }
-      }
-      _ => {}
+  fn var_decl(&mut self, var_decl: &ast_view::VarDecl, ctx: &mut Context) {
+    if var_decl.inner.kind == VarDeclKind::Var {
+      check(ctx, var_decl.as_node(), "variable");
     }
   }
 }
 
-impl Visit for ValidDeclsVisitor {
-  noop_visit_type!();
-
-  fn visit_script(&mut self, item: &Script) {
-    for stmt in &item.body {
-      if let Stmt::Decl(decl) = stmt {
-        self.check_decl(decl)
-      }
-    }
-    item.visit_children_with(self);
-  }
-
-  fn visit_module_item(&mut self, item: &ModuleItem) {
-    match item {
-      ModuleItem::ModuleDecl(module_decl) => match module_decl {
-        ModuleDecl::ExportDecl(decl_export) => {
-          self.check_decl(&decl_export.decl)
-        }
-        ModuleDecl::ExportDefaultDecl(default_export) => {
-          if let DefaultDecl::Fn(fn_expr) = &default_export.decl {
-            self.valid_decls.insert(fn_expr.range());
-          }
-        }
-        _ => {}
-      },
-      ModuleItem::Stmt(module_stmt) => {
-        if let Stmt::Decl(decl) = module_stmt {
-          self.check_decl(decl)
-        }
-      }
-    }
-    item.visit_children_with(self);
-  }
-
-  fn visit_function(&mut self, function: &Function) {
-    if let Some(block) = &function.body {
-      self.check_stmts(&block.stmts);
-    }
-    function.visit_children_with(self);
-  }
-
-  fn visit_constructor(&mut self, constructor: &Constructor) {
-    if let Some(block) = &constructor.body {
-      self.check_stmts(&block.stmts);
-    }
-    constructor.visit_children_with(self);
-  }
-
-  fn visit_arrow_expr(&mut self, arrow_expr: &ArrowExpr) {
-    if let BlockStmtOrExpr::BlockStmt(block) = &*arrow_expr.body {
-      self.check_stmts(&block.stmts);
-    }
-    arrow_expr.visit_children_with(self);
+fn check(ctx: &mut Context, decl: Node, kind: &str) {
+  let id = ctx.node_ids().id(decl);
+  if is_valid(ctx, id) {
+    return;
   }
-}
 
-struct NoInnerDeclarationsVisitor<'c, 'view> {
-  context: &'c mut Context<'view>,
-  valid_decls: HashSet<SourceRange>,
-  in_function: bool,
+  let in_function = ctx.ancestors(id).any(|ancestor| {
+    matches!(
+      ctx.node_ids().node(ancestor).kind(),
+      NodeKind::Function | NodeKind::ArrowExpr
+    )
+  });
+  add_diagnostic(ctx, decl.range(), kind, in_function);
 }
 
-impl<'c, 'view> NoInnerDeclarationsVisitor<'c, 'view> {
-  fn new(
-    context: &'c mut Context<'view>,
-    valid_decls: HashSet<SourceRange>,
-  ) -> Self {
-    Self {
-      context,
-      valid_decls,
-      in_function: false,
-    }
+/// Whether the declaration is at the root of the module, of a namespace or of
+/// the body of a function.
+fn is_valid(ctx: &Context, id: NodeId) -> bool {
+  let kind_of = |id| ctx.node_ids().node(id).kind();
+  let Some(parent) = ctx.parent_of(id) else {
+    return false;
+  };
+  match kind_of(parent) {
+    NodeKind::Script | NodeKind::Module | NodeKind::TsModuleBlock => true,
+    NodeKind::ExportDecl => ctx.parent_of(parent).is_some_and(|grandparent| {
+      matches!(
+        kind_of(grandparent),
+        NodeKind::Module | NodeKind::TsModuleBlock
+      )
+    }),
+    NodeKind::BlockStmt => ctx.parent_of(parent).is_some_and(|grandparent| {
+      matches!(
+        kind_of(grandparent),
+        NodeKind::Function | NodeKind::Constructor | NodeKind::ArrowExpr
+      )
+    }),
+    _ => false,
   }
 }
 
-impl<'c, 'view> NoInnerDeclarationsVisitor<'c, 'view> {
-  fn add_diagnostic(&mut self, range: SourceRange, kind: &str) {
-    let root = if self.in_function {
-      "function"
-    } else {
-      "module"
-    };
-
-    self.context.add_diagnostic_with_hint(
-      range,
-      CODE,
-      NoInnerDeclarationsMessage::Move(kind.to_string(), root.to_string()),
-      NoInnerDeclarationsHint::Move,
-    );
-  }
-}
-
-impl<'c, 'view> Visit for NoInnerDeclarationsVisitor<'c, 'view> {
-  noop_visit_type!();
-
-  fn visit_arrow_expr(&mut self, arrow_expr: &ArrowExpr) {
-    let old = self.in_function;
-    self.in_function = true;
-    arrow_expr.visit_children_with(self);
-    self.in_function = old;
-  }
-
-  fn visit_function(&mut self, function: &Function) {
-    let old = self.in_function;
-    self.in_function = true;
-    function.visit_children_with(self);
-    self.in_function = old;
-  }
-
-  fn visit_fn_decl(&mut self, decl: &FnDecl) {
-    let range = decl.range();
-
-    if !self.valid_decls.contains(&range) {
-      self.add_diagnostic(range, "function");
-    }
-
-    decl.visit_children_with(self);
-  }
-
-  fn visit_var_decl(&mut self, decl: &VarDecl) {
-    let range = decl.range();
-
-    if decl.kind == VarDeclKind::Var && !self.valid_decls.contains(&range) {
-      self.add_diagnostic(range, "variable");
-    }
-
-    decl.visit_children_with(self);
-  }
+fn add_diagnostic(
+  ctx: &mut Context,
+  range: SourceRange,
+  kind: &str,
+  in_function: bool,
+) {
+  let root = if in_function { "function" } else { "module" };
+
+  ctx.add_diagnostic_with_hint(
+    range,
+    CODE,
+    NoInnerDeclarationsMessage::Move(kind.to_string(), root.to_string()),
+    NoInnerDeclarationsHint::Move,
+  );
 }
 
 #[cfg(test)]
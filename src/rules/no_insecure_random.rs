@@ -0,0 +1,394 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::handler::Handler;
+
+use deno_ast::view::{
+  AssignExpr, AssignTarget, CallExpr, Callee, ClassMethod, ClassProp,
+  DoWhileStmt, Expr, ExprStmt, FnDecl, FnExpr, ForInStmt, ForOfStmt, ForStmt,
+  IfStmt, KeyValueProp, MemberProp, MethodProp, Node, NodeKind, NodeTrait, Pat,
+  PropName, SimpleAssignTarget, SwitchStmt, ThrowStmt, VarDeclarator,
+  WhileStmt,
+};
+use deno_ast::SourceRanged;
+use derive_more::Display;
+use serde::Deserialize;
+
+#[derive(Debug, Default)]
+pub struct NoInsecureRandom {
+  options: NoInsecureRandomOptions,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct NoInsecureRandomOptions {
+  /// Words in names that suggest that a random value must be unpredictable.
+  /// They are matched against the words of camelCase and snake_case names,
+  /// so `id` matches `userId` and `SESSION_ID` but not `width`.
+  pub patterns: Vec<String>,
+}
+
+impl Default for NoInsecureRandomOptions {
+  fn default() -> Self {
+    Self {
+      patterns: [
+        "token", "secret", "password", "apiKey", "nonce", "salt", "id", "uuid",
+        "guid", "session", "otp", "csrf",
+      ]
+      .into_iter()
+      .map(String::from)
+      .collect(),
+    }
+  }
+}
+
+impl NoInsecureRandom {
+  pub fn new(options: NoInsecureRandomOptions) -> Self {
+    Self { options }
+  }
+}
+
+const CODE: &str = "no-insecure-random";
+
+#[derive(Display)]
+enum NoInsecureRandomMessage {
+  #[display(
+    fmt = "`Math.random()` is not cryptographically secure, but is used for `{}`",
+    _0
+  )]
+  Insecure(String),
+}
+
+#[derive(Display)]
+enum NoInsecureRandomHint {
+  #[display(
+    fmt = "Use `crypto.getRandomValues()` or `crypto.randomUUID()` instead"
+  )]
+  UseCrypto,
+}
+
+impl LintRule for NoInsecureRandom {
+  fn tags(&self) -> &'static [&'static str] {
+    &["security"]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "patterns": { "type": "array", "items": { "type": "string" } }
+      }
+    }))
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoInsecureRandomHandler {
+      patterns: self
+        .options
+        .patterns
+        .iter()
+        .map(|pattern| words(pattern))
+        .collect(),
+    }))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::CallExpr])
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_insecure_random.md")
+  }
+}
+
+/// Splits a camelCase, PascalCase, snake_case or kebab-case name into its
+/// lowercase words, e.g. `APIKey` into `api` and `key`.
+fn words(name: &str) -> Vec<String> {
+  let chars: Vec<char> = name.chars().collect();
+  let mut words = Vec::new();
+  let mut current = String::new();
+  for (i, &c) in chars.iter().enumerate() {
+    if !c.is_alphanumeric() {
+      if !current.is_empty() {
+        words.push(std::mem::take(&mut current));
+      }
+      continue;
+    }
+    let prev = i.checked_sub(1).map(|i| chars[i]);
+    let next = chars.get(i + 1);
+    let starts_word = c.is_uppercase()
+      && (prev.is_some_and(|prev| prev.is_lowercase() || prev.is_numeric())
+        || (prev.is_some_and(char::is_uppercase)
+          && next.is_some_and(|next| next.is_lowercase())));
+    if starts_word && !current.is_empty() {
+      words.push(std::mem::take(&mut current));
+    }
+    current.extend(c.to_lowercase());
+  }
+  if !current.is_empty() {
+    words.push(current);
+  }
+  words
+}
+
+fn prop_name<'a>(key: &PropName<'a>) -> Option<&'a str> {
+  match key {
+    PropName::Ident(ident) => Some(ident.sym()),
+    PropName::Str(s) => Some(s.value()),
+    _ => None,
+  }
+}
+
+fn callee_name<'a>(callee: &Callee<'a>) -> Option<&'a str> {
+  match callee {
+    Callee::Expr(Expr::Ident(ident)) => Some(ident.sym()),
+    Callee::Expr(Expr::Member(member)) => match &member.prop {
+      MemberProp::Ident(ident) => Some(ident.sym()),
+      _ => None,
+    },
+    _ => None,
+  }
+}
+
+/// What a node says about the value that flows into it.
+enum Naming<'a> {
+  /// The value is stored under this name, so there's nothing more to learn.
+  Final(&'a str),
+  /// The value is passed to something with this name, e.g. `setToken(x)`,
+  /// and may still be stored under another name.
+  Intermediate(&'a str),
+  /// The value doesn't flow any further.
+  Stop,
+  Continue,
+}
+
+fn final_name(name: Option<&str>) -> Naming {
+  match name {
+    Some(name) => Naming::Final(name),
+    None => Naming::Stop,
+  }
+}
+
+fn naming(node: Node) -> Naming {
+  if let Some(declarator) = node.to::<VarDeclarator>() {
+    return final_name(match &declarator.name {
+      Pat::Ident(ident) => Some(ident.id.sym()),
+      _ => None,
+    });
+  }
+  if let Some(assign) = node.to::<AssignExpr>() {
+    return final_name(match &assign.left {
+      AssignTarget::Simple(SimpleAssignTarget::Ident(ident)) => {
+        Some(ident.id.sym())
+      }
+      AssignTarget::Simple(SimpleAssignTarget::Member(member)) => {
+        match &member.prop {
+          MemberProp::Ident(ident) => Some(ident.sym()),
+          _ => None,
+        }
+      }
+      _ => None,
+    });
+  }
+  if let Some(prop) = node.to::<KeyValueProp>() {
+    return final_name(prop_name(&prop.key));
+  }
+  if let Some(prop) = node.to::<ClassProp>() {
+    return final_name(prop_name(&prop.key));
+  }
+  // The value is returned by a function, e.g. `function generateId()`.
+  if let Some(fn_decl) = node.to::<FnDecl>() {
+    return Naming::Final(fn_decl.ident.sym());
+  }
+  if let Some(FnExpr {
+    ident: Some(ident), ..
+  }) = node.to::<FnExpr>()
+  {
+    return Naming::Final(ident.sym());
+  }
+  if let Some(method) = node.to::<MethodProp>() {
+    return final_name(prop_name(&method.key));
+  }
+  if let Some(method) = node.to::<ClassMethod>() {
+    return final_name(prop_name(&method.key));
+  }
+  if let Some(call) = node.to::<CallExpr>() {
+    return match callee_name(&call.callee) {
+      Some(name) => Naming::Intermediate(name),
+      None => Naming::Continue,
+    };
+  }
+  if node.is::<ExprStmt>()
+    || node.is::<IfStmt>()
+    || node.is::<SwitchStmt>()
+    || node.is::<ThrowStmt>()
+    || node.is::<ForStmt>()
+    || node.is::<ForInStmt>()
+    || node.is::<ForOfStmt>()
+    || node.is::<WhileStmt>()
+    || node.is::<DoWhileStmt>()
+  {
+    return Naming::Stop;
+  }
+  Naming::Continue
+}
+
+fn is_math_random(call_expr: &CallExpr, ctx: &Context) -> bool {
+  let Callee::Expr(Expr::Member(callee)) = &call_expr.callee else {
+    return false;
+  };
+  matches!(
+    (&callee.obj, &callee.prop),
+    (Expr::Ident(obj), MemberProp::Ident(prop))
+      if obj.sym() == "Math"
+        && prop.sym() == "random"
+        && ctx.scope().is_global(&obj.inner.to_id())
+  )
+}
+
+struct NoInsecureRandomHandler {
+  patterns: Vec<Vec<String>>,
+}
+
+impl NoInsecureRandomHandler {
+  fn is_sensitive(&self, name: &str) -> bool {
+    let words = words(name);
+    self.patterns.iter().any(|pattern| {
+      !pattern.is_empty()
+        && words
+          .windows(pattern.len())
+          .any(|window| window == pattern.as_slice())
+    })
+  }
+}
+
+impl Handler for NoInsecureRandomHandler {
+  fn call_expr(&mut self, call_expr: &CallExpr, ctx: &mut Context) {
+    if !is_math_random(call_expr, ctx) {
+      return;
+    }
+    for ancestor in call_expr.ancestors() {
+      let name = match naming(ancestor) {
+        Naming::Final(name) => {
+          if !self.is_sensitive(name) {
+            return;
+          }
+          name
+        }
+        Naming::Intermediate(name) if self.is_sensitive(name) => name,
+        Naming::Intermediate(_) | Naming::Continue => continue,
+        Naming::Stop => return,
+      };
+      ctx.add_diagnostic_with_hint(
+        call_expr.range(),
+        CODE,
+        NoInsecureRandomMessage::Insecure(name.to_string()),
+        NoInsecureRandomHint::UseCrypto,
+      );
+      return;
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_insecure_random_valid() {
+    assert_lint_ok! {
+      NoInsecureRandom::default(),
+      "const jitter = Math.random() * 100;",
+      "const width = Math.floor(Math.random() * 10);",
+      "const token = crypto.randomUUID();",
+      "if (Math.random() < 0.5) { sessionStart(); }",
+      "const tokenCount = 3; shuffle(items, () => Math.random() - 0.5);",
+      "function pickColor() { return colors[Math.floor(Math.random() * colors.length)]; }",
+      "const Math = { random: () => 4 }; const id = Math.random();",
+      "const valid = Math.random() > 0.1;",
+    };
+
+    assert_lint_ok! {
+      NoInsecureRandom::new(NoInsecureRandomOptions {
+        patterns: vec!["discount".to_string()],
+      }),
+      "const token = Math.random();",
+    };
+  }
+
+  #[test]
+  fn no_insecure_random_invalid() {
+    assert_lint_err! {
+      NoInsecureRandom::default(),
+      "const token = Math.random().toString(36).slice(2);": [
+        {
+          col: 14,
+          message: NoInsecureRandomMessage::Insecure("token".to_string()),
+          hint: NoInsecureRandomHint::UseCrypto,
+        }
+      ],
+      "const userId = Math.floor(Math.random() * 1e9);": [
+        {
+          col: 26,
+          message: NoInsecureRandomMessage::Insecure("userId".to_string()),
+          hint: NoInsecureRandomHint::UseCrypto,
+        }
+      ],
+      "const session = { SESSION_ID: `${Date.now()}-${Math.random()}` };": [
+        {
+          col: 47,
+          message: NoInsecureRandomMessage::Insecure("SESSION_ID".to_string()),
+          hint: NoInsecureRandomHint::UseCrypto,
+        }
+      ],
+      "user.resetPassword = String(Math.random());": [
+        {
+          col: 28,
+          message: NoInsecureRandomMessage::Insecure("resetPassword".to_string()),
+          hint: NoInsecureRandomHint::UseCrypto,
+        }
+      ],
+      "function generateApiKey() { return Math.random().toString(16); }": [
+        {
+          col: 35,
+          message: NoInsecureRandomMessage::Insecure("generateApiKey".to_string()),
+          hint: NoInsecureRandomHint::UseCrypto,
+        }
+      ],
+      "const createNonce = () => Math.random().toString(36);": [
+        {
+          col: 26,
+          message: NoInsecureRandomMessage::Insecure("createNonce".to_string()),
+          hint: NoInsecureRandomHint::UseCrypto,
+        }
+      ],
+      "setCsrfToken(Math.random());": [
+        {
+          col: 13,
+          message: NoInsecureRandomMessage::Insecure("setCsrfToken".to_string()),
+          hint: NoInsecureRandomHint::UseCrypto,
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn no_insecure_random_options() {
+    let options: NoInsecureRandomOptions =
+      serde_json::from_str(r#"{ "patterns": ["code"] }"#).unwrap();
+    assert_eq!(options.patterns, vec!["code".to_string()]);
+  }
+
+  #[test]
+  fn no_insecure_random_words() {
+    assert_eq!(words("APIKey"), vec!["api", "key"]);
+    assert_eq!(words("userId"), vec!["user", "id"]);
+    assert_eq!(words("SESSION_ID"), vec!["session", "id"]);
+    assert_eq!(words("csrf-token2"), vec!["csrf", "token2"]);
+  }
+}
@@ -1,16 +1,11 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
-use super::program_ref;
 use super::{Context, LintRule};
+use crate::handler::Handler;
 use crate::js_regex::*;
-use crate::Program;
-use crate::ProgramRef;
-use deno_ast::swc::ast::Expr;
-use deno_ast::swc::ast::ExprOrSpread;
-use deno_ast::swc::visit::noop_visit_type;
-use deno_ast::swc::visit::Visit;
-use deno_ast::SourceRange;
-use deno_ast::SourceRangedForSpanned;
+use crate::syntax_features::SyntaxFeatures;
+use deno_ast::view::{CallExpr, Callee, NewExpr, NodeKind, Regex};
+use deno_ast::SourceRanged;
 
 #[derive(Debug)]
 pub struct NoInvalidRegexp;
@@ -28,17 +23,16 @@ impl LintRule for NoInvalidRegexp {
     CODE
   }
 
-  fn lint_program_with_ast_view<'view>(
-    &self,
-    context: &mut Context<'view>,
-    program: Program<'view>,
-  ) {
-    let program = program_ref(program);
-    let mut visitor = NoInvalidRegexpVisitor::new(context);
-    match program {
-      ProgramRef::Module(m) => visitor.visit_module(m),
-      ProgramRef::Script(s) => visitor.visit_script(s),
-    }
+  fn required_syntax(&self) -> SyntaxFeatures {
+    SyntaxFeatures::REGEX
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoInvalidRegexpHandler::new()))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::Regex, NodeKind::NewExpr, NodeKind::CallExpr])
   }
 
   #[cfg(feature = "docs")]
@@ -47,59 +41,29 @@ impl LintRule for NoInvalidRegexp {
   }
 }
 
-fn check_expr_for_string_literal(expr: &Expr) -> Option<String> {
-  if let Expr::Lit(deno_ast::swc::ast::Lit::Str(pattern_string)) = expr {
-    let s: &str = &pattern_string.value;
-    return Some(s.to_owned());
-  }
-  None
-}
-
-struct NoInvalidRegexpVisitor<'c, 'view> {
-  context: &'c mut Context<'view>,
+struct NoInvalidRegexpHandler {
   validator: EcmaRegexValidator,
 }
 
-impl<'c, 'view> NoInvalidRegexpVisitor<'c, 'view> {
-  fn new(context: &'c mut Context<'view>) -> Self {
+impl NoInvalidRegexpHandler {
+  fn new() -> Self {
     Self {
-      context,
       validator: EcmaRegexValidator::new(EcmaVersion::Es2022),
     }
   }
 
-  fn handle_call_or_new_expr(
-    &mut self,
-    callee: &Expr,
-    args: &[ExprOrSpread],
-    range: SourceRange,
-  ) {
-    if let Expr::Ident(ident) = callee {
-      if ident.sym != *"RegExp" || args.is_empty() {
-        return;
-      }
-      if let Some(pattern) = &check_expr_for_string_literal(&args[0].expr) {
-        if args.len() > 1 {
-          if let Some(flags) = &check_expr_for_string_literal(&args[1].expr) {
-            self.check_regex(pattern, flags, range);
-            return;
-          }
-        }
-        self.check_regex(pattern, "", range);
-      }
-    }
-  }
-
-  fn check_regex(&mut self, pattern: &str, flags: &str, range: SourceRange) {
+  fn check_regex(&mut self, regex: &RegexSource, ctx: &mut Context) {
+    // Flags that aren't a string literal can't be checked, so the pattern is
+    // checked as if there were none.
+    let flags = regex.flags.as_deref().unwrap_or_default();
+    let pattern = &regex.pattern;
     if self.check_for_invalid_flags(flags)
       || (!flags.is_empty()
         && self.check_for_invalid_pattern(pattern, flags.contains('u')))
       || (self.check_for_invalid_pattern(pattern, true)
         && self.check_for_invalid_pattern(pattern, false))
     {
-      self
-        .context
-        .add_diagnostic_with_hint(range, CODE, MESSAGE, HINT);
+      ctx.add_diagnostic_with_hint(regex.range, CODE, MESSAGE, HINT);
     }
   }
 
@@ -112,26 +76,32 @@ impl<'c, 'view> NoInvalidRegexpVisitor<'c, 'view> {
   }
 }
 
-impl<'c, 'view> Visit for NoInvalidRegexpVisitor<'c, 'view> {
-  noop_visit_type!();
-
-  fn visit_regex(&mut self, regex: &deno_ast::swc::ast::Regex) {
-    self.check_regex(&regex.exp, &regex.flags, regex.range());
+impl Handler for NoInvalidRegexpHandler {
+  fn regex(&mut self, regex: &Regex, ctx: &mut Context) {
+    self.check_regex(&RegexSource::from_regex(regex), ctx);
   }
 
-  fn visit_call_expr(&mut self, call_expr: &deno_ast::swc::ast::CallExpr) {
-    if let deno_ast::swc::ast::Callee::Expr(expr) = &call_expr.callee {
-      self.handle_call_or_new_expr(expr, &call_expr.args, call_expr.range());
+  fn new_expr(&mut self, new_expr: &NewExpr, ctx: &mut Context) {
+    if let Some(regex) = RegexSource::from_constructor(
+      &new_expr.callee,
+      new_expr.args.unwrap_or_default(),
+      new_expr.range(),
+      ctx,
+    ) {
+      self.check_regex(&regex, ctx);
     }
   }
 
-  fn visit_new_expr(&mut self, new_expr: &deno_ast::swc::ast::NewExpr) {
-    if new_expr.args.is_some() {
-      self.handle_call_or_new_expr(
-        &new_expr.callee,
-        new_expr.args.as_ref().unwrap(),
-        new_expr.range(),
-      );
+  fn call_expr(&mut self, call_expr: &CallExpr, ctx: &mut Context) {
+    if let Callee::Expr(callee) = &call_expr.callee {
+      if let Some(regex) = RegexSource::from_constructor(
+        callee,
+        call_expr.args,
+        call_expr.range(),
+        ctx,
+      ) {
+        self.check_regex(&regex, ctx);
+      }
     }
   }
 }
@@ -2,14 +2,49 @@
 
 use super::{Context, LintRule};
 use crate::Program;
-use deno_ast::{RootNode, SourceRangedForSpanned};
-use deno_ast::{SourceRange, SourceRanged};
+use deno_ast::swc::parser::token::Token;
+use deno_ast::SourceRange;
+use deno_ast::SourceRangedForSpanned;
 use derive_more::Display;
 use once_cell::sync::Lazy;
 use regex::{Matches, Regex};
+use serde::Deserialize;
 
-#[derive(Debug)]
-pub struct NoIrregularWhitespace;
+#[derive(Debug, Default)]
+pub struct NoIrregularWhitespace {
+  options: NoIrregularWhitespaceOptions,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct NoIrregularWhitespaceOptions {
+  /// Whether whitespace in string literals is allowed, which is the default.
+  pub skip_strings: bool,
+  /// Whether whitespace in template literals is allowed, which is the
+  /// default.
+  pub skip_templates: bool,
+  /// Whether whitespace in regex literals is allowed, which is the default.
+  pub skip_reg_exps: bool,
+  /// Whether whitespace in comments is allowed.
+  pub skip_comments: bool,
+}
+
+impl Default for NoIrregularWhitespaceOptions {
+  fn default() -> Self {
+    Self {
+      skip_strings: true,
+      skip_templates: true,
+      skip_reg_exps: true,
+      skip_comments: false,
+    }
+  }
+}
+
+impl NoIrregularWhitespace {
+  pub fn new(options: NoIrregularWhitespaceOptions) -> Self {
+    Self { options }
+  }
+}
 
 const CODE: &str = "no-irregular-whitespace";
 const HINT: &str = "Change to a normal space or tab";
@@ -48,40 +83,67 @@ impl LintRule for NoIrregularWhitespace {
     CODE
   }
 
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "skipStrings": { "type": "boolean", "default": true },
+        "skipTemplates": { "type": "boolean", "default": true },
+        "skipRegExps": { "type": "boolean", "default": true },
+        "skipComments": { "type": "boolean", "default": false }
+      }
+    }))
+  }
+
   fn lint_program_with_ast_view(
     &self,
     context: &mut Context,
-    program: Program,
+    _program: Program,
   ) {
+    let options = &self.options;
     let file_range = context.text_info().range();
-    let mut check_range = |range: SourceRange| {
-      let whitespace_text = range.text_fast(context.text_info()).to_string();
-      for whitespace_matches in
-        test_for_whitespace(&whitespace_text).into_iter()
-      {
-        for whitespace_match in whitespace_matches {
-          let whitespace_range = whitespace_match.range();
-          let range = SourceRange::new(
-            range.start + whitespace_range.start,
-            range.start + whitespace_range.end,
-          );
-          context.add_diagnostic_with_hint(
-            range,
-            CODE,
-            NoIrregularWhitespaceMessage::NotAllowed,
-            HINT,
-          );
-        }
-      }
+    let mut comments = if options.skip_comments {
+      context
+        .all_comments()
+        .map(|comment| comment.range())
+        .collect()
+    } else {
+      Vec::new()
     };
-    let mut last_end = file_range.start.as_source_pos();
+    comments.sort_by_key(|range| range.start);
 
-    for token in program.token_container().tokens {
-      check_range(SourceRange::new(last_end, token.start()));
+    // The whitespace and comments between tokens are always checked, and the
+    // tokens themselves only if they're literals that aren't skipped.
+    let mut ranges = Vec::new();
+    let mut last_end = file_range.start.as_source_pos();
+    for token in context.tokens() {
+      ranges.push(SourceRange::new(last_end, token.start()));
+      let is_checked = match &token.token {
+        Token::Str { .. } => !options.skip_strings,
+        Token::Template { .. } => !options.skip_templates,
+        Token::Regex(..) => !options.skip_reg_exps,
+        _ => false,
+      };
+      if is_checked {
+        ranges.push(token.range());
+      }
       last_end = token.end();
     }
+    ranges.push(SourceRange::new(last_end, file_range.end));
 
-    check_range(SourceRange::new(last_end, file_range.end));
+    let mut comments = comments.into_iter().peekable();
+    for range in ranges {
+      let mut start = range.start;
+      while let Some(comment) =
+        comments.next_if(|comment| comment.start < range.end)
+      {
+        if comment.start >= range.start {
+          check_range(SourceRange::new(start, comment.start), context);
+          start = comment.end;
+        }
+      }
+      check_range(SourceRange::new(start, range.end), context);
+    }
   }
 
   #[cfg(feature = "docs")]
@@ -90,6 +152,28 @@ impl LintRule for NoIrregularWhitespace {
   }
 }
 
+fn check_range(range: SourceRange, context: &mut Context) {
+  let text = context.text_of(range);
+  let mut irregular = Vec::new();
+  for whitespace_matches in test_for_whitespace(text) {
+    for whitespace_match in whitespace_matches {
+      let whitespace_range = whitespace_match.range();
+      irregular.push(SourceRange::new(
+        range.start + whitespace_range.start,
+        range.start + whitespace_range.end,
+      ));
+    }
+  }
+  for range in irregular {
+    context.add_diagnostic_with_hint(
+      range,
+      CODE,
+      NoIrregularWhitespaceMessage::NotAllowed,
+      HINT,
+    );
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -97,7 +181,7 @@ mod tests {
   #[test]
   fn no_irregular_whitespace_valid() {
     assert_lint_ok! {
-      NoIrregularWhitespace,
+      NoIrregularWhitespace::default(),
       "'\\u{000B}';",
       "'\\u{000C}';",
       "'\\u{0085}';",
@@ -144,13 +228,26 @@ mod tests {
       "'\u{202F}';",
       "'\u{205f}';",
       "'\u{3000}';",
+      "`\u{00A0}`;",
+      "`${a}\u{2000}`;",
+      "/\u{00A0}/;",
+    };
+
+    assert_lint_ok! {
+      NoIrregularWhitespace::new(NoIrregularWhitespaceOptions {
+        skip_comments: true,
+        ..Default::default()
+      }),
+      "// \u{00A0}",
+      "/* \u{3000} */ const a = 1;",
+      "const a = 1; /* \u{200B} */",
     };
   }
 
   #[test]
   fn no_irregular_whitespace_invalid() {
     assert_lint_err! {
-      NoIrregularWhitespace,
+      NoIrregularWhitespace::default(),
       "var any \u{000B} = 'thing';": [
         {
           col: 8,
@@ -324,7 +421,62 @@ mod tests {
           message: NoIrregularWhitespaceMessage::NotAllowed,
           hint: HINT,
         }
-      ]
+      ],
+      "const a = 1; // \u{00A0}": [
+        {
+          col: 16,
+          message: NoIrregularWhitespaceMessage::NotAllowed,
+          hint: HINT,
+        }
+      ],
     };
+
+    assert_lint_err! {
+      NoIrregularWhitespace::new(NoIrregularWhitespaceOptions {
+        skip_strings: false,
+        skip_templates: false,
+        skip_reg_exps: false,
+        skip_comments: true,
+      }),
+      "const a = '\u{00A0}';": [
+        {
+          col: 11,
+          message: NoIrregularWhitespaceMessage::NotAllowed,
+          hint: HINT,
+        }
+      ],
+      "const a = `${b}\u{2000}`;": [
+        {
+          col: 15,
+          message: NoIrregularWhitespaceMessage::NotAllowed,
+          hint: HINT,
+        }
+      ],
+      "const a = /\u{feff}/;": [
+        {
+          col: 11,
+          message: NoIrregularWhitespaceMessage::NotAllowed,
+          hint: HINT,
+        }
+      ],
+      "/* \u{00A0} */ var any \u{3000} = 'thing';": [
+        {
+          col: 16,
+          message: NoIrregularWhitespaceMessage::NotAllowed,
+          hint: HINT,
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn no_irregular_whitespace_options() {
+    let options: NoIrregularWhitespaceOptions =
+      serde_json::from_str(r#"{ "skipStrings": false, "skipComments": true }"#)
+        .unwrap();
+    assert!(!options.skip_strings);
+    assert!(options.skip_templates);
+    assert!(options.skip_reg_exps);
+    assert!(options.skip_comments);
   }
 }
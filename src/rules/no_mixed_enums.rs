@@ -0,0 +1,180 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::handler::Handler;
+use crate::syntax_features::SyntaxFeatures;
+
+use deno_ast::view::{
+  Expr, Lit, MemberProp, NodeKind, TsEnumDecl, TsEnumMemberId,
+};
+use deno_ast::SourceRanged;
+use derive_more::Display;
+use std::collections::HashMap;
+
+#[derive(Debug)]
+pub struct NoMixedEnums;
+
+const CODE: &str = "no-mixed-enums";
+
+#[derive(Display)]
+enum NoMixedEnumsMessage {
+  #[display(fmt = "Mixing number and string enum members can be confusing")]
+  Mixed,
+}
+
+#[derive(Display)]
+enum NoMixedEnumsHint {
+  #[display(
+    fmt = "Make this member a {} like the first member, or split the enum into two",
+    _0
+  )]
+  UseSameKind(MemberKind),
+}
+
+#[derive(Display, Clone, Copy, PartialEq, Eq)]
+enum MemberKind {
+  #[display(fmt = "number")]
+  Number,
+  #[display(fmt = "string")]
+  String,
+}
+
+impl LintRule for NoMixedEnums {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn required_syntax(&self) -> SyntaxFeatures {
+    SyntaxFeatures::ENUM
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoMixedEnumsHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::TsEnumDecl])
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_mixed_enums.md")
+  }
+}
+
+/// Determines whether the initializer is a number or a string, without type
+/// information. Returns `None` if that can't be known.
+fn init_kind(
+  init: &Expr,
+  enum_name: &str,
+  members: &HashMap<String, MemberKind>,
+) -> Option<MemberKind> {
+  match init {
+    Expr::Lit(Lit::Str(_)) | Expr::Tpl(_) => Some(MemberKind::String),
+    Expr::Lit(Lit::Num(_)) | Expr::Unary(_) => Some(MemberKind::Number),
+    Expr::Ident(ident) => members.get(ident.sym().as_ref()).copied(),
+    Expr::Member(member) => match (&member.obj, &member.prop) {
+      (Expr::Ident(obj), MemberProp::Ident(prop)) if obj.sym() == enum_name => {
+        members.get(prop.sym().as_ref()).copied()
+      }
+      _ => None,
+    },
+    Expr::Paren(paren) => init_kind(&paren.expr, enum_name, members),
+    _ => None,
+  }
+}
+
+struct NoMixedEnumsHandler;
+
+impl Handler for NoMixedEnumsHandler {
+  fn ts_enum_decl(&mut self, enum_decl: &TsEnumDecl, ctx: &mut Context) {
+    let enum_name = enum_decl.id.sym().as_ref();
+    let mut members = HashMap::new();
+    let mut first_kind = None;
+    for member in enum_decl.members {
+      // Members without an initializer are always numbers.
+      let kind = match &member.init {
+        Some(init) => init_kind(init, enum_name, &members),
+        None => Some(MemberKind::Number),
+      };
+      let Some(kind) = kind else {
+        continue;
+      };
+      match first_kind {
+        None => first_kind = Some(kind),
+        Some(first_kind) if first_kind != kind => {
+          let range = member
+            .init
+            .as_ref()
+            .map_or_else(|| member.range(), |init| init.range());
+          ctx.add_diagnostic_with_hint(
+            range,
+            CODE,
+            NoMixedEnumsMessage::Mixed,
+            NoMixedEnumsHint::UseSameKind(first_kind),
+          );
+          return;
+        }
+        Some(_) => {}
+      }
+      if let TsEnumMemberId::Ident(ident) = &member.id {
+        members.insert(ident.sym().to_string(), kind);
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_mixed_enums_valid() {
+    assert_lint_ok! {
+      NoMixedEnums,
+      "enum E { A, B }",
+      "enum E { A = 1, B = 2, C }",
+      "enum E { A = -1, B = ~2 }",
+      "enum E { A = 'a', B = 'b' }",
+      "enum E { A = 'a', B = `b` }",
+      "enum E { A = 'a', B = A, C = E.A }",
+      "enum E { A = 1, B = 2, C = A | B }",
+      "enum E { A = 'a', B = foo() }",
+    };
+  }
+
+  #[test]
+  fn no_mixed_enums_invalid() {
+    assert_lint_err! {
+      NoMixedEnums,
+      "enum E { A = 1, B = 'b' }": [
+        {
+          col: 20,
+          message: NoMixedEnumsMessage::Mixed,
+          hint: NoMixedEnumsHint::UseSameKind(MemberKind::Number),
+        }
+      ],
+      "enum E { A, B = 'b' }": [
+        {
+          col: 16,
+          message: NoMixedEnumsMessage::Mixed,
+          hint: NoMixedEnumsHint::UseSameKind(MemberKind::Number),
+        }
+      ],
+      "enum E { A = 'a', B = 0, C = 1 }": [
+        {
+          col: 22,
+          message: NoMixedEnumsMessage::Mixed,
+          hint: NoMixedEnumsHint::UseSameKind(MemberKind::String),
+        }
+      ],
+      "enum E { A = 1, B = 'b', C = B }": [
+        {
+          col: 20,
+          message: NoMixedEnumsMessage::Mixed,
+          hint: NoMixedEnumsHint::UseSameKind(MemberKind::Number),
+        }
+      ],
+    };
+  }
+}
@@ -2,6 +2,7 @@
 
 use super::{Context, LintRule};
 use crate::handler::{Handler, Traverse};
+use crate::syntax_features::SyntaxFeatures;
 use crate::Program;
 use deno_ast::view::NodeTrait;
 use deno_ast::{view as ast_view, MediaType, SourceRanged};
@@ -24,6 +25,10 @@ impl LintRule for NoNamespace {
     CODE
   }
 
+  fn required_syntax(&self) -> SyntaxFeatures {
+    SyntaxFeatures::NAMESPACE
+  }
+
   fn lint_program_with_ast_view(
     &self,
     context: &mut Context,
@@ -0,0 +1,160 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::handler::Handler;
+use crate::swc_util::enclosing_function;
+use deno_ast::swc::ast::{Id, Ident, Pat};
+use deno_ast::swc::utils::find_pat_ids;
+use deno_ast::swc::visit::{noop_visit_type, Visit, VisitWith};
+use deno_ast::view::{
+  CallExpr, Callee, Expr, ExprOrSpread, MemberProp, Node, NodeKind, NodeTrait,
+};
+use deno_ast::SourceRanged;
+
+#[derive(Debug)]
+pub struct NoNestedPromises;
+
+const CODE: &str = "no-nested-promises";
+const MESSAGE: &str =
+  "Avoid nesting `.then()` or `.catch()` in the callback of another one";
+const HINT: &str =
+  "Return the promise from the callback and chain onto the outer promise instead";
+
+impl LintRule for NoNestedPromises {
+  fn tags(&self) -> &'static [&'static str] {
+    &["promise"]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn aliases(&self) -> &'static [&'static str] {
+    &["promise/no-nesting"]
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoNestedPromisesHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::CallExpr])
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_nested_promises.md")
+  }
+}
+
+/// Returns the `then` or `catch` property of `promise.then(...)` or
+/// `promise.catch(...)`.
+fn promise_method<'a>(call_expr: &CallExpr<'a>) -> Option<&'a MemberProp<'a>> {
+  let Callee::Expr(Expr::Member(callee)) = &call_expr.callee else {
+    return None;
+  };
+  match &callee.prop {
+    MemberProp::Ident(prop)
+      if matches!(prop.sym().as_ref(), "then" | "catch") =>
+    {
+      Some(&callee.prop)
+    }
+    _ => None,
+  }
+}
+
+/// The parameters of the callback around the node, if it's passed to
+/// `.then()` or `.catch()`.
+fn outer_callback_params(node: Node) -> Option<Vec<&Pat>> {
+  let (params, callback) = match enclosing_function(node)? {
+    Node::Function(function) => (
+      function
+        .inner
+        .params
+        .iter()
+        .map(|param| &param.pat)
+        .collect(),
+      function.parent(),
+    ),
+    Node::ArrowExpr(arrow) => {
+      (arrow.inner.params.iter().collect(), arrow.as_node())
+    }
+    _ => return None,
+  };
+  let arg = callback.parent()?.to::<ExprOrSpread>()?;
+  let outer = arg.parent().to::<CallExpr>()?;
+  promise_method(outer)?;
+  Some(params)
+}
+
+/// Looks for references to the parameters of the outer callback, which would
+/// make the nested promise impossible to move out of it.
+struct ParamFinder {
+  params: Vec<Id>,
+  found: bool,
+}
+
+impl Visit for ParamFinder {
+  noop_visit_type!();
+
+  fn visit_ident(&mut self, ident: &Ident) {
+    self.found |= self.params.contains(&ident.to_id());
+  }
+}
+
+struct NoNestedPromisesHandler;
+
+impl Handler for NoNestedPromisesHandler {
+  fn call_expr(&mut self, call_expr: &CallExpr, ctx: &mut Context) {
+    let Some(prop) = promise_method(call_expr) else {
+      return;
+    };
+    let Some(params) = outer_callback_params(call_expr.as_node()) else {
+      return;
+    };
+    let mut finder = ParamFinder {
+      params: params.into_iter().flat_map(find_pat_ids).collect(),
+      found: false,
+    };
+    for arg in call_expr.args {
+      arg.inner.visit_with(&mut finder);
+    }
+    if !finder.found {
+      ctx.add_diagnostic_with_hint(prop.range(), CODE, MESSAGE, HINT);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_nested_promises_valid() {
+    assert_lint_ok! {
+      NoNestedPromises,
+      "p.then((a) => b(a)).then((c) => d(c));",
+      "p.then((a) => { return b(a); });",
+      "p.then((a) => b().then((c) => combine(a, c)));",
+      "p.catch((err) => retry().catch(function () { throw err; }));",
+      "run(() => p.then(log));",
+      "p.then((a) => { q.forEach(() => r.then(log)); });",
+    };
+  }
+
+  #[test]
+  fn no_nested_promises_invalid() {
+    assert_lint_err! {
+      NoNestedPromises,
+      "p.then((a) => b(a).then((c) => d(c)));": [
+        { col: 19, message: MESSAGE, hint: HINT }
+      ],
+      "p.then(function (a) { return getB().then(log); });": [
+        { col: 36, message: MESSAGE, hint: HINT }
+      ],
+      "p.catch(() => fallback().catch(handle));": [
+        { col: 25, message: MESSAGE, hint: HINT }
+      ],
+    };
+  }
+}
@@ -1,9 +1,8 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
-use crate::Program;
-use deno_ast::view::{Expr, NewExpr};
+use crate::handler::Handler;
+use deno_ast::view::{Expr, NewExpr, NodeKind};
 use deno_ast::SourceRanged;
 use if_chain::if_chain;
 
@@ -22,12 +21,16 @@ impl LintRule for NoNewSymbol {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    NoNewSymbolHandler.traverse(program, context);
+  fn aliases(&self) -> &'static [&'static str] {
+    &["no-new-native-nonconstructor"]
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoNewSymbolHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::NewExpr])
   }
 
   #[cfg(feature = "docs")]
@@ -0,0 +1,280 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::Handler;
+
+use deno_ast::view::{
+  CallExpr, Callee, ExportAll, Expr, ImportDecl, Lit, NamedExport, NodeKind,
+  Str, TsExternalModuleRef,
+};
+use deno_ast::{SourceRange, SourceRanged};
+use derive_more::Display;
+
+#[derive(Debug)]
+pub struct NoNodeBuiltinsWithoutPrefix;
+
+const CODE: &str = "no-node-builtins-without-prefix";
+
+#[derive(Display)]
+enum NoNodeBuiltinsWithoutPrefixMessage {
+  #[display(
+    fmt = "Node built-in module `{}` is imported without the `node:` prefix",
+    _0
+  )]
+  MissingPrefix(String),
+}
+
+#[derive(Display)]
+enum NoNodeBuiltinsWithoutPrefixHint {
+  #[display(fmt = "Import it from `node:{}` instead", _0)]
+  AddPrefix(String),
+}
+
+const FIX_DESC: &str = "Add the `node:` prefix";
+
+/// The built-in modules of Node.js, as listed by `module.builtinModules`,
+/// without the internal ones starting with an underscore.
+const NODE_BUILTINS: &[&str] = &[
+  "assert",
+  "assert/strict",
+  "async_hooks",
+  "buffer",
+  "child_process",
+  "cluster",
+  "console",
+  "constants",
+  "crypto",
+  "dgram",
+  "diagnostics_channel",
+  "dns",
+  "dns/promises",
+  "domain",
+  "events",
+  "fs",
+  "fs/promises",
+  "http",
+  "http2",
+  "https",
+  "inspector",
+  "inspector/promises",
+  "module",
+  "net",
+  "os",
+  "path",
+  "path/posix",
+  "path/win32",
+  "perf_hooks",
+  "process",
+  "punycode",
+  "querystring",
+  "readline",
+  "readline/promises",
+  "repl",
+  "stream",
+  "stream/consumers",
+  "stream/promises",
+  "stream/web",
+  "string_decoder",
+  "sys",
+  "timers",
+  "timers/promises",
+  "tls",
+  "trace_events",
+  "tty",
+  "url",
+  "util",
+  "util/types",
+  "v8",
+  "vm",
+  "wasi",
+  "worker_threads",
+  "zlib",
+];
+
+impl LintRule for NoNodeBuiltinsWithoutPrefix {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn aliases(&self) -> &'static [&'static str] {
+    &["unicorn/prefer-node-protocol"]
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoNodeBuiltinsWithoutPrefixHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[
+      NodeKind::ImportDecl,
+      NodeKind::NamedExport,
+      NodeKind::ExportAll,
+      NodeKind::TsExternalModuleRef,
+      NodeKind::CallExpr,
+    ])
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_node_builtins_without_prefix.md")
+  }
+}
+
+fn check_specifier(src: &Str, ctx: &mut Context) {
+  let specifier = src.value().as_ref();
+  if !NODE_BUILTINS.contains(&specifier) {
+    return;
+  }
+  // Insert the prefix right after the opening quote.
+  let insert_pos = src.start() + 1;
+  ctx.add_diagnostic_with_fixes(
+    src.range(),
+    CODE,
+    NoNodeBuiltinsWithoutPrefixMessage::MissingPrefix(specifier.to_string()),
+    Some(
+      NoNodeBuiltinsWithoutPrefixHint::AddPrefix(specifier.to_string())
+        .to_string(),
+    ),
+    vec![LintFix {
+      description: FIX_DESC.into(),
+      changes: vec![LintFixChange {
+        new_text: "node:".into(),
+        range: SourceRange::new(insert_pos, insert_pos),
+      }],
+    }],
+  );
+}
+
+struct NoNodeBuiltinsWithoutPrefixHandler;
+
+impl Handler for NoNodeBuiltinsWithoutPrefixHandler {
+  fn import_decl(&mut self, import_decl: &ImportDecl, ctx: &mut Context) {
+    check_specifier(import_decl.src, ctx);
+  }
+
+  fn named_export(&mut self, named_export: &NamedExport, ctx: &mut Context) {
+    if let Some(src) = named_export.src {
+      check_specifier(src, ctx);
+    }
+  }
+
+  fn export_all(&mut self, export_all: &ExportAll, ctx: &mut Context) {
+    check_specifier(export_all.src, ctx);
+  }
+
+  fn ts_external_module_ref(
+    &mut self,
+    module_ref: &TsExternalModuleRef,
+    ctx: &mut Context,
+  ) {
+    check_specifier(module_ref.expr, ctx);
+  }
+
+  fn call_expr(&mut self, call_expr: &CallExpr, ctx: &mut Context) {
+    if !matches!(call_expr.callee, Callee::Import(_)) {
+      return;
+    }
+    if let Some(Expr::Lit(Lit::Str(src))) =
+      call_expr.args.first().map(|arg| &arg.expr)
+    {
+      check_specifier(src, ctx);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_node_builtins_without_prefix_valid() {
+    assert_lint_ok! {
+      NoNodeBuiltinsWithoutPrefix,
+      r#"import fs from "node:fs";"#,
+      r#"import { readFile } from "node:fs/promises";"#,
+      r#"import fs from "npm:fs";"#,
+      r#"import foo from "./fs";"#,
+      r#"import foo from "fsevents";"#,
+      r#"export * from "node:path";"#,
+      r#"const fs = await import("node:fs");"#,
+      r#"const fs = await import(name);"#,
+      r#"foo("fs");"#,
+    };
+  }
+
+  #[test]
+  fn no_node_builtins_without_prefix_invalid() {
+    assert_lint_err! {
+      NoNodeBuiltinsWithoutPrefix,
+      r#"import fs from "fs";"#: [
+        {
+          col: 15,
+          message: NoNodeBuiltinsWithoutPrefixMessage::MissingPrefix(
+            "fs".to_string()
+          ),
+          hint: NoNodeBuiltinsWithoutPrefixHint::AddPrefix("fs".to_string()),
+          fix: (FIX_DESC, r#"import fs from "node:fs";"#),
+        }
+      ],
+      "import { readFile } from 'fs/promises';": [
+        {
+          col: 25,
+          message: NoNodeBuiltinsWithoutPrefixMessage::MissingPrefix(
+            "fs/promises".to_string()
+          ),
+          hint: NoNodeBuiltinsWithoutPrefixHint::AddPrefix(
+            "fs/promises".to_string()
+          ),
+          fix: (FIX_DESC, "import { readFile } from 'node:fs/promises';"),
+        }
+      ],
+      r#"export { join } from "path";"#: [
+        {
+          col: 21,
+          message: NoNodeBuiltinsWithoutPrefixMessage::MissingPrefix(
+            "path".to_string()
+          ),
+          hint: NoNodeBuiltinsWithoutPrefixHint::AddPrefix("path".to_string()),
+          fix: (FIX_DESC, r#"export { join } from "node:path";"#),
+        }
+      ],
+      r#"export * from "events";"#: [
+        {
+          col: 14,
+          message: NoNodeBuiltinsWithoutPrefixMessage::MissingPrefix(
+            "events".to_string()
+          ),
+          hint: NoNodeBuiltinsWithoutPrefixHint::AddPrefix(
+            "events".to_string()
+          ),
+          fix: (FIX_DESC, r#"export * from "node:events";"#),
+        }
+      ],
+      r#"const os = await import("os");"#: [
+        {
+          col: 24,
+          message: NoNodeBuiltinsWithoutPrefixMessage::MissingPrefix(
+            "os".to_string()
+          ),
+          hint: NoNodeBuiltinsWithoutPrefixHint::AddPrefix("os".to_string()),
+          fix: (FIX_DESC, r#"const os = await import("node:os");"#),
+        }
+      ],
+      r#"import url = require("url");"#: [
+        {
+          col: 21,
+          message: NoNodeBuiltinsWithoutPrefixMessage::MissingPrefix(
+            "url".to_string()
+          ),
+          hint: NoNodeBuiltinsWithoutPrefixHint::AddPrefix("url".to_string()),
+          fix: (FIX_DESC, r#"import url = require("node:url");"#),
+        }
+      ],
+    };
+  }
+}
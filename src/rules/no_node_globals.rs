@@ -5,8 +5,8 @@ use super::LintRule;
 use crate::diagnostic::LintFix;
 use crate::diagnostic::LintFixChange;
 use crate::handler::Handler;
-use crate::handler::Traverse;
 use crate::Program;
+use deno_ast::view::NodeKind;
 use std::borrow::Cow;
 
 use deno_ast::view as ast_view;
@@ -34,21 +34,24 @@ static NODE_GLOBALS: phf::Map<&'static str, FixKind> = phf::phf_map! {
 };
 
 impl LintRule for NoNodeGlobals {
-  fn lint_program_with_ast_view<'view>(
-    &self,
-    context: &mut Context<'view>,
-    program: Program<'view>,
-  ) {
-    NoNodeGlobalsHandler {
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoNodeGlobalsHandler {
       most_recent_import_range: None,
-    }
-    .traverse(program, context);
+    }))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::Ident, NodeKind::ImportDecl])
   }
 
   fn code(&self) -> &'static str {
     CODE
   }
 
+  fn fixable(&self) -> bool {
+    true
+  }
+
   fn tags(&self) -> &'static [&'static str] {
     &["recommended"]
   }
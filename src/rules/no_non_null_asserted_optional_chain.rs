@@ -1,9 +1,8 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
-use crate::Program;
-use deno_ast::view::{Callee, Expr, TsNonNullExpr};
+use crate::handler::Handler;
+use deno_ast::view::{Callee, Expr, NodeKind, TsNonNullExpr};
 use deno_ast::{SourceRange, SourceRanged};
 use derive_more::Display;
 
@@ -25,12 +24,12 @@ impl LintRule for NoNonNullAssertedOptionalChain {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    NoNonNullAssertedOptionalChainHandler.traverse(program, context);
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoNonNullAssertedOptionalChainHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::TsNonNullExpr])
   }
 
   #[cfg(feature = "docs")]
@@ -1,9 +1,8 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
-use crate::Program;
-use deno_ast::view::TsNonNullExpr;
+use crate::handler::Handler;
+use deno_ast::view::{NodeKind, TsNonNullExpr};
 use deno_ast::SourceRanged;
 use derive_more::Display;
 
@@ -23,12 +22,12 @@ impl LintRule for NoNonNullAssertion {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    NoNonNullAssertionHandler.traverse(program, context);
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoNonNullAssertionHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::TsNonNullExpr])
   }
 
   #[cfg(feature = "docs")]
@@ -0,0 +1,180 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::Handler;
+
+use deno_ast::view::{Node, NodeKind, Str};
+use deno_ast::{SourceRange, SourceRanged};
+use derive_more::Display;
+
+#[derive(Debug)]
+pub struct NoNonoctalDecimalEscape;
+
+const CODE: &str = "no-nonoctal-decimal-escape";
+const FIX_DESC: &str = "Remove the backslash";
+
+#[derive(Display)]
+enum NoNonoctalDecimalEscapeMessage {
+  #[display(fmt = "Unnecessary escape sequence `\\{}`", _0)]
+  Unnecessary(char),
+}
+
+#[derive(Display)]
+enum NoNonoctalDecimalEscapeHint {
+  #[display(
+    fmt = "The escape means the same as the digit, so write `{}` instead, or `\\\\{}` for a backslash",
+    _0,
+    _0
+  )]
+  UseDigit(char),
+}
+
+impl LintRule for NoNonoctalDecimalEscape {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoNonoctalDecimalEscapeHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::Str])
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_nonoctal_decimal_escape.md")
+  }
+}
+
+struct NoNonoctalDecimalEscapeHandler;
+
+impl Handler for NoNonoctalDecimalEscapeHandler {
+  fn str(&mut self, str: &Str, ctx: &mut Context) {
+    // Backslashes in JSX attributes aren't escapes.
+    if matches!(str.parent(), Node::JSXAttr(_)) {
+      return;
+    }
+    let start = str.start();
+    let raw = ctx.text_of(str.range());
+    let mut escapes = Vec::new();
+    // The start of the previous escape if it was `\0`, which would become an
+    // octal escape when followed by a plain digit.
+    let mut null_escape_start = None;
+    let mut chars = raw.char_indices();
+    while let Some((index, c)) = chars.next() {
+      if c != '\\' {
+        null_escape_start = None;
+        continue;
+      }
+      let Some((_, escaped)) = chars.next() else {
+        break;
+      };
+      if matches!(escaped, '8' | '9') {
+        escapes.push((index, escaped, null_escape_start));
+      }
+      null_escape_start = (escaped == '0').then_some(index);
+    }
+
+    for (index, digit, null_escape_start) in escapes {
+      let range = SourceRange::new(start + index, start + index + 2);
+      // `\0\8` is replaced with `\x008` rather than the octal escape `\08`.
+      let change = match null_escape_start {
+        Some(null_start) => LintFixChange {
+          new_text: format!("\\x00{}", digit).into(),
+          range: SourceRange::new(start + null_start, range.end),
+        },
+        None => LintFixChange {
+          new_text: digit.to_string().into(),
+          range,
+        },
+      };
+      ctx.add_diagnostic_with_fixes(
+        range,
+        CODE,
+        NoNonoctalDecimalEscapeMessage::Unnecessary(digit),
+        Some(NoNonoctalDecimalEscapeHint::UseDigit(digit).to_string()),
+        vec![LintFix {
+          description: FIX_DESC.into(),
+          changes: vec![change],
+        }],
+      );
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_nonoctal_decimal_escape_valid() {
+    assert_lint_ok! {
+      NoNonoctalDecimalEscape,
+      r#"const a = "8";"#,
+      r#"const a = "\\8";"#,
+      r#"const a = "\\\\9";"#,
+      r#"const a = "\0";"#,
+      r#"const a = "\x38";"#,
+      r#"const a = "\u0039";"#,
+      r#"const a = `8`;"#,
+    };
+
+    assert_lint_ok! {
+      NoNonoctalDecimalEscape,
+      filename: "file:///foo.tsx",
+      r#"const a = <div title="\8" />;"#,
+    };
+  }
+
+  #[test]
+  fn no_nonoctal_decimal_escape_invalid() {
+    assert_lint_err! {
+      NoNonoctalDecimalEscape,
+      r#"const a = "\8";"#: [
+        {
+          col: 11,
+          message: NoNonoctalDecimalEscapeMessage::Unnecessary('8'),
+          hint: NoNonoctalDecimalEscapeHint::UseDigit('8'),
+          fix: (FIX_DESC, r#"const a = "8";"#),
+        }
+      ],
+      r#"const a = 'foo\9bar';"#: [
+        {
+          col: 14,
+          message: NoNonoctalDecimalEscapeMessage::Unnecessary('9'),
+          hint: NoNonoctalDecimalEscapeHint::UseDigit('9'),
+          fix: (FIX_DESC, r#"const a = 'foo9bar';"#),
+        }
+      ],
+      r#"const a = "\\\8\9";"#: [
+        {
+          col: 13,
+          message: NoNonoctalDecimalEscapeMessage::Unnecessary('8'),
+          hint: NoNonoctalDecimalEscapeHint::UseDigit('8'),
+          fix: (FIX_DESC, r#"const a = "\\8\9";"#),
+        },
+        {
+          col: 15,
+          message: NoNonoctalDecimalEscapeMessage::Unnecessary('9'),
+          hint: NoNonoctalDecimalEscapeHint::UseDigit('9'),
+          fix: (FIX_DESC, r#"const a = "\\\89";"#),
+        }
+      ],
+      r#"const a = "\0\8";"#: [
+        {
+          col: 13,
+          message: NoNonoctalDecimalEscapeMessage::Unnecessary('8'),
+          hint: NoNonoctalDecimalEscapeHint::UseDigit('8'),
+          fix: (FIX_DESC, r#"const a = "\x008";"#),
+        }
+      ],
+    };
+  }
+}
@@ -1,9 +1,8 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
-use crate::Program;
-use deno_ast::view::{CallExpr, Callee, Expr, Ident, NewExpr};
+use crate::handler::Handler;
+use deno_ast::view::{CallExpr, Callee, Expr, Ident, NewExpr, NodeKind};
 use deno_ast::{SourceRange, SourceRanged};
 
 #[derive(Debug)]
@@ -24,12 +23,12 @@ impl LintRule for NoObjCalls {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    NoObjCallsHandler.traverse(program, context);
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoObjCallsHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::CallExpr, NodeKind::NewExpr])
   }
 
   #[cfg(feature = "docs")]
@@ -1,9 +1,8 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
-use crate::Program;
-use deno_ast::view::Number;
+use crate::handler::Handler;
+use deno_ast::view::{NodeKind, Number};
 use deno_ast::SourceRanged;
 use once_cell::sync::Lazy;
 use regex::Regex;
@@ -24,12 +23,12 @@ impl LintRule for NoOctal {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    NoOctalHandler.traverse(program, context);
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoOctalHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::Number])
   }
 
   #[cfg(feature = "docs")]
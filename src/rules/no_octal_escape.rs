@@ -0,0 +1,202 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::Handler;
+
+use deno_ast::view::{Node, NodeKind, Str};
+use deno_ast::{SourceRange, SourceRanged};
+use derive_more::Display;
+
+#[derive(Debug)]
+pub struct NoOctalEscape;
+
+const CODE: &str = "no-octal-escape";
+const FIX_DESC: &str = "Replace with a hexadecimal escape";
+
+#[derive(Display)]
+enum NoOctalEscapeMessage {
+  #[display(fmt = "Unexpected octal escape sequence `{}`", _0)]
+  Unexpected(String),
+}
+
+#[derive(Display)]
+enum NoOctalEscapeHint {
+  #[display(fmt = "Use the hexadecimal escape `{}` instead", _0)]
+  UseHex(String),
+}
+
+impl LintRule for NoOctalEscape {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoOctalEscapeHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::Str])
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_octal_escape.md")
+  }
+}
+
+struct NoOctalEscapeHandler;
+
+impl Handler for NoOctalEscapeHandler {
+  fn str(&mut self, str: &Str, ctx: &mut Context) {
+    // Backslashes in JSX attributes aren't escapes.
+    if matches!(str.parent(), Node::JSXAttr(_)) {
+      return;
+    }
+    let start = str.start();
+    let raw = ctx.text_of(str.range());
+    let mut escapes = Vec::new();
+    let mut chars = raw.char_indices().peekable();
+    while let Some((index, c)) = chars.next() {
+      if c != '\\' {
+        continue;
+      }
+      let Some((_, first)) = chars.next() else {
+        break;
+      };
+      let Some(first_digit) = first.to_digit(8) else {
+        continue;
+      };
+      let mut value = first_digit;
+      let mut len = 2;
+      let max_len = if first_digit <= 3 { 4 } else { 3 };
+      while len < max_len {
+        match chars.peek().and_then(|(_, c)| c.to_digit(8)) {
+          Some(digit) => {
+            value = value * 8 + digit;
+            len += 1;
+            chars.next();
+          }
+          None => break,
+        }
+      }
+      // `\0` is the null character, unless a digit follows it.
+      let is_followed_by_digit =
+        chars.peek().is_some_and(|(_, c)| c.is_ascii_digit());
+      if len == 2 && first_digit == 0 && !is_followed_by_digit {
+        continue;
+      }
+      escapes.push((index, raw[index..index + len].to_string(), value));
+    }
+
+    for (index, escape, value) in escapes {
+      let range = SourceRange::new(start + index, start + index + escape.len());
+      let hex = format!("\\x{:02x}", value);
+      ctx.add_diagnostic_with_fixes(
+        range,
+        CODE,
+        NoOctalEscapeMessage::Unexpected(escape),
+        Some(NoOctalEscapeHint::UseHex(hex.clone()).to_string()),
+        vec![LintFix {
+          description: FIX_DESC.into(),
+          changes: vec![LintFixChange {
+            new_text: hex.into(),
+            range,
+          }],
+        }],
+      );
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_octal_escape_valid() {
+    assert_lint_ok! {
+      NoOctalEscape,
+      r#"const a = "foo";"#,
+      r#"const a = "\0";"#,
+      r#"const a = "\0a";"#,
+      r#"const a = "\x41";"#,
+      r#"const a = "A";"#,
+      r#"const a = "\\1";"#,
+      r#"const a = "\\\\1";"#,
+      r#"const a = "\8";"#,
+      r#"const a = `\0`;"#,
+    };
+
+    assert_lint_ok! {
+      NoOctalEscape,
+      filename: "file:///foo.tsx",
+      r#"const a = <div title="\1" />;"#,
+    };
+  }
+
+  #[test]
+  fn no_octal_escape_invalid() {
+    assert_lint_err! {
+      NoOctalEscape,
+      r#"const a = "\1";"#: [
+        {
+          col: 11,
+          message: NoOctalEscapeMessage::Unexpected(r"\1".to_string()),
+          hint: NoOctalEscapeHint::UseHex(r"\x01".to_string()),
+          fix: (FIX_DESC, r#"const a = "\x01";"#),
+        }
+      ],
+      r#"const a = "foo\251";"#: [
+        {
+          col: 14,
+          message: NoOctalEscapeMessage::Unexpected(r"\251".to_string()),
+          hint: NoOctalEscapeHint::UseHex(r"\xa9".to_string()),
+          fix: (FIX_DESC, r#"const a = "foo\xa9";"#),
+        }
+      ],
+      r#"const a = '\377\400';"#: [
+        {
+          col: 11,
+          message: NoOctalEscapeMessage::Unexpected(r"\377".to_string()),
+          hint: NoOctalEscapeHint::UseHex(r"\xff".to_string()),
+          fix: (FIX_DESC, r#"const a = '\xff\400';"#),
+        },
+        {
+          col: 15,
+          message: NoOctalEscapeMessage::Unexpected(r"\40".to_string()),
+          hint: NoOctalEscapeHint::UseHex(r"\x20".to_string()),
+          fix: (FIX_DESC, r#"const a = '\377\x200';"#),
+        }
+      ],
+      r#"const a = "\01";"#: [
+        {
+          col: 11,
+          message: NoOctalEscapeMessage::Unexpected(r"\01".to_string()),
+          hint: NoOctalEscapeHint::UseHex(r"\x01".to_string()),
+          fix: (FIX_DESC, r#"const a = "\x01";"#),
+        }
+      ],
+      r#"const a = "\08";"#: [
+        {
+          col: 11,
+          message: NoOctalEscapeMessage::Unexpected(r"\0".to_string()),
+          hint: NoOctalEscapeHint::UseHex(r"\x00".to_string()),
+          fix: (FIX_DESC, r#"const a = "\x008";"#),
+        }
+      ],
+      r#"const a = "\\\7";"#: [
+        {
+          col: 13,
+          message: NoOctalEscapeMessage::Unexpected(r"\7".to_string()),
+          hint: NoOctalEscapeHint::UseHex(r"\x07".to_string()),
+          fix: (FIX_DESC, r#"const a = "\\\x07";"#),
+        }
+      ],
+    };
+  }
+}
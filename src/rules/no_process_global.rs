@@ -0,0 +1,322 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::Handler;
+
+use deno_ast::view::{
+  AssignExpr, Expr, Lit, MemberExpr, MemberProp, NodeKind, UnaryExpr, UnaryOp,
+  UpdateExpr,
+};
+use deno_ast::SourceRanged;
+use derive_more::Display;
+use serde::Deserialize;
+
+#[derive(Debug, Default)]
+pub struct NoProcessGlobal {
+  options: NoProcessGlobalOptions,
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct NoProcessGlobalOptions {
+  /// Properties of `process` that are allowed, e.g. `"env"` or `"argv"`.
+  pub allow: Vec<String>,
+}
+
+impl NoProcessGlobal {
+  pub fn new(options: NoProcessGlobalOptions) -> Self {
+    Self { options }
+  }
+}
+
+const CODE: &str = "no-process-global";
+
+#[derive(Display)]
+enum NoProcessGlobalMessage {
+  #[display(fmt = "`process.{}` has an equivalent `Deno` API", _0)]
+  HasDenoEquivalent(&'static str),
+}
+
+#[derive(Display)]
+enum NoProcessGlobalHint {
+  #[display(fmt = "Use `{}` instead", _0)]
+  Use(&'static str),
+}
+
+const FIX_DESC: &str = "Replace with the `Deno` API";
+
+/// How a property of `process` maps to the `Deno` namespace.
+#[derive(Debug, Clone, Copy)]
+enum Equivalent {
+  /// `process.<name>` can be replaced by this expression.
+  Same(&'static str),
+  /// `process.env.NAME` can be replaced by `Deno.env.get("NAME")`.
+  Env,
+  /// The equivalent behaves differently, e.g. `process.argv` includes the
+  /// path of the executable and `Deno.args` doesn't, so there is no fix.
+  Similar(&'static str),
+}
+
+impl Equivalent {
+  fn name(self) -> &'static str {
+    match self {
+      Equivalent::Same(name) | Equivalent::Similar(name) => name,
+      Equivalent::Env => "Deno.env.get()",
+    }
+  }
+}
+
+static EQUIVALENTS: phf::Map<&'static str, Equivalent> = phf::phf_map! {
+  "env" => Equivalent::Env,
+  "exit" => Equivalent::Same("Deno.exit"),
+  "cwd" => Equivalent::Same("Deno.cwd"),
+  "chdir" => Equivalent::Same("Deno.chdir"),
+  "pid" => Equivalent::Same("Deno.pid"),
+  "ppid" => Equivalent::Same("Deno.ppid"),
+  "kill" => Equivalent::Same("Deno.kill"),
+  "memoryUsage" => Equivalent::Same("Deno.memoryUsage"),
+  "argv" => Equivalent::Similar("Deno.args"),
+  "execPath" => Equivalent::Similar("Deno.execPath()"),
+  "platform" => Equivalent::Similar("Deno.build.os"),
+  "arch" => Equivalent::Similar("Deno.build.arch"),
+  "stdin" => Equivalent::Similar("Deno.stdin"),
+  "stdout" => Equivalent::Similar("Deno.stdout"),
+  "stderr" => Equivalent::Similar("Deno.stderr"),
+};
+
+impl LintRule for NoProcessGlobal {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "allow": { "type": "array", "items": { "type": "string" } }
+      }
+    }))
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoProcessGlobalHandler {
+      options: &self.options,
+    }))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::MemberExpr])
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_process_global.md")
+  }
+}
+
+fn static_prop_name<'a>(prop: &'a MemberProp) -> Option<&'a str> {
+  match prop {
+    MemberProp::Ident(ident) => Some(ident.sym()),
+    MemberProp::Computed(computed) => match &computed.expr {
+      Expr::Lit(Lit::Str(s)) => Some(s.value()),
+      _ => None,
+    },
+    MemberProp::PrivateName(_) => None,
+  }
+}
+
+/// Whether the member expression is written to, e.g. `process.env.FOO = ""`,
+/// in which case `Deno.env.get()` can't replace it.
+fn is_written(member_expr: &MemberExpr) -> bool {
+  let parent = member_expr.parent();
+  if let Some(assign) = parent.to::<AssignExpr>() {
+    return assign.left.range() == member_expr.range();
+  }
+  if let Some(unary) = parent.to::<UnaryExpr>() {
+    return unary.op() == UnaryOp::Delete;
+  }
+  parent.is::<UpdateExpr>()
+}
+
+/// The fix for `process.env.NAME`, which replaces the whole expression.
+fn env_fix(process_env: &MemberExpr) -> Option<LintFixChange> {
+  let outer = process_env.parent().to::<MemberExpr>()?;
+  if outer.obj.range() != process_env.range() || is_written(outer) {
+    return None;
+  }
+  let name = static_prop_name(&outer.prop)?;
+  Some(LintFixChange {
+    new_text: format!("Deno.env.get({name:?})").into(),
+    range: outer.range(),
+  })
+}
+
+struct NoProcessGlobalHandler<'a> {
+  options: &'a NoProcessGlobalOptions,
+}
+
+impl<'a> Handler for NoProcessGlobalHandler<'a> {
+  fn member_expr(&mut self, member_expr: &MemberExpr, ctx: &mut Context) {
+    let Expr::Ident(obj) = &member_expr.obj else {
+      return;
+    };
+    if obj.sym() != "process" || !ctx.scope().is_global(&obj.inner.to_id()) {
+      return;
+    }
+    let Some(prop) = static_prop_name(&member_expr.prop) else {
+      return;
+    };
+    let Some((&prop, &equivalent)) = EQUIVALENTS.get_entry(prop) else {
+      return;
+    };
+    if self.options.allow.iter().any(|allowed| allowed == prop) {
+      return;
+    }
+
+    let message = NoProcessGlobalMessage::HasDenoEquivalent(prop);
+    let hint = NoProcessGlobalHint::Use(equivalent.name());
+    let change = match equivalent {
+      Equivalent::Same(name) => Some(LintFixChange {
+        new_text: name.into(),
+        range: member_expr.range(),
+      }),
+      Equivalent::Env => env_fix(member_expr),
+      Equivalent::Similar(_) => None,
+    };
+    match change {
+      Some(change) => ctx.add_diagnostic_with_fixes(
+        member_expr.range(),
+        CODE,
+        message,
+        Some(hint.to_string()),
+        vec![LintFix {
+          description: FIX_DESC.into(),
+          changes: vec![change],
+        }],
+      ),
+      None => {
+        ctx.add_diagnostic_with_hint(member_expr.range(), CODE, message, hint)
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_process_global_valid() {
+    assert_lint_ok! {
+      NoProcessGlobal::default(),
+      r#"const port = Deno.env.get("PORT");"#,
+      "Deno.exit(1);",
+      "process.nextTick(() => {});",
+      r#"import process from "node:process"; process.exit(1);"#,
+      "const process = { exit() {} }; process.exit();",
+      "foo.process.exit();",
+      "process[name];",
+    };
+
+    assert_lint_ok! {
+      NoProcessGlobal::new(NoProcessGlobalOptions {
+        allow: vec!["env".to_string(), "argv".to_string()],
+      }),
+      "const port = process.env.PORT;",
+      "const args = process.argv.slice(2);",
+    };
+  }
+
+  #[test]
+  fn no_process_global_invalid() {
+    assert_lint_err! {
+      NoProcessGlobal::default(),
+      "const port = process.env.PORT;": [
+        {
+          col: 13,
+          message: NoProcessGlobalMessage::HasDenoEquivalent("env"),
+          hint: NoProcessGlobalHint::Use("Deno.env.get()"),
+          fix: (FIX_DESC, r#"const port = Deno.env.get("PORT");"#),
+        }
+      ],
+      r#"const port = process.env["PORT"];"#: [
+        {
+          col: 13,
+          message: NoProcessGlobalMessage::HasDenoEquivalent("env"),
+          hint: NoProcessGlobalHint::Use("Deno.env.get()"),
+          fix: (FIX_DESC, r#"const port = Deno.env.get("PORT");"#),
+        }
+      ],
+      r#"process.env.PORT = "8000";"#: [
+        {
+          col: 0,
+          message: NoProcessGlobalMessage::HasDenoEquivalent("env"),
+          hint: NoProcessGlobalHint::Use("Deno.env.get()"),
+        }
+      ],
+      "const env = process.env;": [
+        {
+          col: 12,
+          message: NoProcessGlobalMessage::HasDenoEquivalent("env"),
+          hint: NoProcessGlobalHint::Use("Deno.env.get()"),
+        }
+      ],
+      "process.exit(1);": [
+        {
+          col: 0,
+          message: NoProcessGlobalMessage::HasDenoEquivalent("exit"),
+          hint: NoProcessGlobalHint::Use("Deno.exit"),
+          fix: (FIX_DESC, "Deno.exit(1);"),
+        }
+      ],
+      r#"const dir = process["cwd"]();"#: [
+        {
+          col: 12,
+          message: NoProcessGlobalMessage::HasDenoEquivalent("cwd"),
+          hint: NoProcessGlobalHint::Use("Deno.cwd"),
+          fix: (FIX_DESC, "const dir = Deno.cwd();"),
+        }
+      ],
+      "const args = process.argv.slice(2);": [
+        {
+          col: 13,
+          message: NoProcessGlobalMessage::HasDenoEquivalent("argv"),
+          hint: NoProcessGlobalHint::Use("Deno.args"),
+        }
+      ],
+      r#"if (process.platform === "win32") {}"#: [
+        {
+          col: 4,
+          message: NoProcessGlobalMessage::HasDenoEquivalent("platform"),
+          hint: NoProcessGlobalHint::Use("Deno.build.os"),
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoProcessGlobal::new(NoProcessGlobalOptions {
+        allow: vec!["env".to_string()],
+      }),
+      "process.exit(process.env.CODE);": [
+        {
+          col: 0,
+          message: NoProcessGlobalMessage::HasDenoEquivalent("exit"),
+          hint: NoProcessGlobalHint::Use("Deno.exit"),
+          fix: (FIX_DESC, "Deno.exit(process.env.CODE);"),
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn no_process_global_options() {
+    let options: NoProcessGlobalOptions =
+      serde_json::from_str(r#"{ "allow": ["env", "exit"] }"#).unwrap();
+    assert_eq!(options.allow, vec!["env".to_string(), "exit".to_string()]);
+  }
+}
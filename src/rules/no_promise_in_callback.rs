@@ -0,0 +1,125 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::handler::Handler;
+use crate::swc_util::enclosing_function;
+use deno_ast::swc::ast::Pat;
+use deno_ast::view::{
+  CallExpr, Callee, Expr, ExprOrSpread, MemberProp, NewExpr, Node, NodeKind,
+  NodeTrait,
+};
+use deno_ast::SourceRanged;
+
+#[derive(Debug)]
+pub struct NoPromiseInCallback;
+
+const CODE: &str = "no-promise-in-callback";
+const MESSAGE: &str = "Avoid using promises inside of node-style callbacks";
+const HINT: &str = "Promisify the callback-based API, e.g. with `util.promisify`, and use promises throughout";
+
+impl LintRule for NoPromiseInCallback {
+  fn tags(&self) -> &'static [&'static str] {
+    &["promise"]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn aliases(&self) -> &'static [&'static str] {
+    &["promise/no-promise-in-callback"]
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoPromiseInCallbackHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::NewExpr, NodeKind::CallExpr])
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_promise_in_callback.md")
+  }
+}
+
+/// Whether the node is inside a function that is passed as an argument and
+/// takes an error as its first parameter, like `(err, data) => {}`.
+fn is_in_node_style_callback(node: Node) -> bool {
+  let (first_param, callback) = match enclosing_function(node) {
+    Some(Node::Function(function)) => (
+      function.inner.params.first().map(|param| &param.pat),
+      function.parent(),
+    ),
+    Some(Node::ArrowExpr(arrow)) => {
+      (arrow.inner.params.first(), arrow.as_node())
+    }
+    _ => return false,
+  };
+  let Some(Pat::Ident(first_param)) = first_param else {
+    return false;
+  };
+  matches!(first_param.sym.as_ref(), "err" | "error")
+    && callback
+      .parent()
+      .is_some_and(|parent| parent.is::<ExprOrSpread>())
+}
+
+struct NoPromiseInCallbackHandler;
+
+impl Handler for NoPromiseInCallbackHandler {
+  fn new_expr(&mut self, new_expr: &NewExpr, ctx: &mut Context) {
+    let Expr::Ident(callee) = &new_expr.callee else {
+      return;
+    };
+    if callee.sym() == "Promise"
+      && ctx.scope().is_global(&callee.inner.to_id())
+      && is_in_node_style_callback(new_expr.as_node())
+    {
+      ctx.add_diagnostic_with_hint(new_expr.range(), CODE, MESSAGE, HINT);
+    }
+  }
+
+  fn call_expr(&mut self, call_expr: &CallExpr, ctx: &mut Context) {
+    let Callee::Expr(Expr::Member(callee)) = &call_expr.callee else {
+      return;
+    };
+    let MemberProp::Ident(prop) = &callee.prop else {
+      return;
+    };
+    if prop.sym() == "then" && is_in_node_style_callback(call_expr.as_node()) {
+      ctx.add_diagnostic_with_hint(prop.range(), CODE, MESSAGE, HINT);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_promise_in_callback_valid() {
+    assert_lint_ok! {
+      NoPromiseInCallback,
+      "readFile(path, (err, data) => { if (err) throw err; parse(data); });",
+      "p.then((value) => new Promise((resolve) => resolve(value)));",
+      "function onError(err) { return fetch(url).then(log); }",
+      "readFile(path, (err) => { items.forEach(() => p.then(log)); });",
+      "class Promise {} readFile(path, (err) => new Promise());",
+    };
+  }
+
+  #[test]
+  fn no_promise_in_callback_invalid() {
+    assert_lint_err! {
+      NoPromiseInCallback,
+      "readFile(path, (err, data) => { fetch(url).then(log); });": [
+        { col: 43, message: MESSAGE, hint: HINT }
+      ],
+      "readFile(path, function (error, data) { return new Promise(run); });": [
+        { col: 47, message: MESSAGE, hint: HINT }
+      ],
+    };
+  }
+}
@@ -1,9 +1,8 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
-use crate::Program;
-use deno_ast::view::{CallExpr, Callee, Expr, MemberProp};
+use crate::handler::Handler;
+use deno_ast::view::{CallExpr, Callee, Expr, MemberProp, NodeKind};
 use deno_ast::SourceRanged;
 
 const BANNED_PROPERTIES: &[&str] =
@@ -30,12 +29,12 @@ impl LintRule for NoPrototypeBuiltins {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    NoPrototypeBuiltinsHandler.traverse(program, context);
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoPrototypeBuiltinsHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::CallExpr])
   }
 
   #[cfg(feature = "docs")]
@@ -93,9 +93,13 @@ impl<'c, 'view> Visit for NoRedeclareVisitor<'c, 'view> {
     for id in ids {
       self.declare(&id);
     }
+
+    p.decorators.visit_with(self);
   }
 
   fn visit_class_prop(&mut self, p: &ClassProp) {
+    p.decorators.visit_with(self);
+
     if let PropName::Computed(_) = &p.key {
       p.key.visit_with(self);
     }
@@ -159,6 +163,8 @@ mod tests {
       "var a; var {a = 0, b: Object = 0} = {};": [{line: 1, col: 12, message: MESSAGE}],
       "var a; var {a = 0, b: globalThis = 0} = {};": [{line: 1, col: 12, message: MESSAGE}],
       "function f(foo: number, foo: string) {}": [{line: 1, col: 24, message: MESSAGE}],
+      "class A { @dec(() => { var a; var a; }) foo = 1; }": [{col: 34, message: MESSAGE}],
+      "class A { foo(@dec(() => { var a; var a; }) b) {} }": [{col: 38, message: MESSAGE}],
     }
   }
 }
@@ -0,0 +1,347 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::handler::Handler;
+
+use deno_ast::view::{
+  NodeKind, NodeTrait, TsIntersectionType, TsKeywordTypeKind, TsLit, TsType,
+  TsUnionType,
+};
+use deno_ast::SourceRanged;
+use derive_more::Display;
+
+#[derive(Debug)]
+pub struct NoRedundantTypeConstituents;
+
+const CODE: &str = "no-redundant-type-constituents";
+
+#[derive(Display)]
+enum NoRedundantTypeConstituentsMessage {
+  #[display(fmt = "`{}` overrides all other types in this {} type", _0, _1)]
+  Overrides(&'static str, Container),
+  #[display(fmt = "`{}` is overridden by other types in this {} type", _0, _1)]
+  Overridden(&'static str, Container),
+  #[display(fmt = "`{}` is overridden by `{}` in this union type", _0, _1)]
+  LiteralOverridden(String, &'static str),
+  #[display(
+    fmt = "`{}` is overridden by `{}` in this intersection type",
+    _0,
+    _1
+  )]
+  PrimitiveOverridden(&'static str, String),
+}
+
+#[derive(Display)]
+enum NoRedundantTypeConstituentsHint {
+  #[display(fmt = "Replace the whole {} type with `{}`", _0, _1)]
+  ReplaceWith(Container, &'static str),
+  #[display(fmt = "Remove the redundant type")]
+  Remove,
+}
+
+#[derive(Display, Clone, Copy)]
+enum Container {
+  #[display(fmt = "union")]
+  Union,
+  #[display(fmt = "intersection")]
+  Intersection,
+}
+
+impl LintRule for NoRedundantTypeConstituents {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoRedundantTypeConstituentsHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::TsUnionType, NodeKind::TsIntersectionType])
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_redundant_type_constituents.md")
+  }
+}
+
+fn keyword_name(ty: &TsType) -> Option<&'static str> {
+  let TsType::TsKeywordType(keyword) = ty else {
+    return None;
+  };
+  let name = match keyword.keyword_kind() {
+    TsKeywordTypeKind::TsAnyKeyword => "any",
+    TsKeywordTypeKind::TsUnknownKeyword => "unknown",
+    TsKeywordTypeKind::TsNeverKeyword => "never",
+    TsKeywordTypeKind::TsStringKeyword => "string",
+    TsKeywordTypeKind::TsNumberKeyword => "number",
+    TsKeywordTypeKind::TsBooleanKeyword => "boolean",
+    TsKeywordTypeKind::TsBigIntKeyword => "bigint",
+    _ => return None,
+  };
+  Some(name)
+}
+
+/// The primitive type a literal type belongs to, e.g. `string` for `"foo"`.
+fn literal_primitive(ty: &TsType) -> Option<&'static str> {
+  let TsType::TsLitType(lit_type) = ty else {
+    return None;
+  };
+  let primitive = match &lit_type.lit {
+    TsLit::Str(_) | TsLit::Tpl(_) => "string",
+    TsLit::Number(_) => "number",
+    TsLit::Bool(_) => "boolean",
+    TsLit::BigInt(_) => "bigint",
+  };
+  Some(primitive)
+}
+
+fn unparen<'a>(ty: &'a TsType<'a>) -> &'a TsType<'a> {
+  match ty {
+    TsType::TsParenthesizedType(paren) => unparen(&paren.type_ann),
+    ty => ty,
+  }
+}
+
+struct NoRedundantTypeConstituentsHandler;
+
+impl Handler for NoRedundantTypeConstituentsHandler {
+  fn ts_union_type(&mut self, union: &TsUnionType, ctx: &mut Context) {
+    let types = union.types.iter().map(unparen).collect::<Vec<_>>();
+    let keywords = types
+      .iter()
+      .filter_map(|ty| keyword_name(ty))
+      .collect::<Vec<_>>();
+
+    for ty in &types {
+      match keyword_name(ty) {
+        Some(name @ ("any" | "unknown")) => {
+          ctx.add_diagnostic_with_hint(
+            ty.range(),
+            CODE,
+            NoRedundantTypeConstituentsMessage::Overrides(
+              name,
+              Container::Union,
+            ),
+            NoRedundantTypeConstituentsHint::ReplaceWith(
+              Container::Union,
+              name,
+            ),
+          );
+        }
+        Some("never") => {
+          ctx.add_diagnostic_with_hint(
+            ty.range(),
+            CODE,
+            NoRedundantTypeConstituentsMessage::Overridden(
+              "never",
+              Container::Union,
+            ),
+            NoRedundantTypeConstituentsHint::Remove,
+          );
+        }
+        _ => {
+          if let Some(primitive) = literal_primitive(ty) {
+            if keywords.contains(&primitive) {
+              ctx.add_diagnostic_with_hint(
+                ty.range(),
+                CODE,
+                NoRedundantTypeConstituentsMessage::LiteralOverridden(
+                  ty.text().to_string(),
+                  primitive,
+                ),
+                NoRedundantTypeConstituentsHint::Remove,
+              );
+            }
+          }
+        }
+      }
+    }
+  }
+
+  fn ts_intersection_type(
+    &mut self,
+    intersection: &TsIntersectionType,
+    ctx: &mut Context,
+  ) {
+    let types = intersection.types.iter().map(unparen).collect::<Vec<_>>();
+
+    for ty in &types {
+      match keyword_name(ty) {
+        Some(name @ ("any" | "never")) => {
+          ctx.add_diagnostic_with_hint(
+            ty.range(),
+            CODE,
+            NoRedundantTypeConstituentsMessage::Overrides(
+              name,
+              Container::Intersection,
+            ),
+            NoRedundantTypeConstituentsHint::ReplaceWith(
+              Container::Intersection,
+              name,
+            ),
+          );
+        }
+        Some("unknown") => {
+          ctx.add_diagnostic_with_hint(
+            ty.range(),
+            CODE,
+            NoRedundantTypeConstituentsMessage::Overridden(
+              "unknown",
+              Container::Intersection,
+            ),
+            NoRedundantTypeConstituentsHint::Remove,
+          );
+        }
+        Some(primitive) => {
+          // `string & "foo"` is the same as `"foo"`.
+          let literal = types
+            .iter()
+            .find(|other| literal_primitive(other) == Some(primitive));
+          if let Some(literal) = literal {
+            ctx.add_diagnostic_with_hint(
+              ty.range(),
+              CODE,
+              NoRedundantTypeConstituentsMessage::PrimitiveOverridden(
+                primitive,
+                literal.text().to_string(),
+              ),
+              NoRedundantTypeConstituentsHint::Remove,
+            );
+          }
+        }
+        None => {}
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_redundant_type_constituents_valid() {
+    assert_lint_ok! {
+      NoRedundantTypeConstituents,
+      "type T = string | number;",
+      "type T = 'a' | 'b';",
+      "type T = 'a' | number;",
+      "type T = any;",
+      "type T = string & { brand: true };",
+      "type T = 1n | number;",
+      "type T = A & B;",
+      "type T = (string | number) & {};",
+    };
+  }
+
+  #[test]
+  fn no_redundant_type_constituents_invalid() {
+    assert_lint_err! {
+      NoRedundantTypeConstituents,
+      "type T = string | any;": [
+        {
+          col: 18,
+          message: NoRedundantTypeConstituentsMessage::Overrides(
+            "any",
+            Container::Union
+          ),
+          hint: NoRedundantTypeConstituentsHint::ReplaceWith(
+            Container::Union,
+            "any"
+          ),
+        }
+      ],
+      "let x: (unknown) | Foo;": [
+        {
+          col: 8,
+          message: NoRedundantTypeConstituentsMessage::Overrides(
+            "unknown",
+            Container::Union
+          ),
+          hint: NoRedundantTypeConstituentsHint::ReplaceWith(
+            Container::Union,
+            "unknown"
+          ),
+        }
+      ],
+      "type T = string | never;": [
+        {
+          col: 18,
+          message: NoRedundantTypeConstituentsMessage::Overridden(
+            "never",
+            Container::Union
+          ),
+          hint: NoRedundantTypeConstituentsHint::Remove,
+        }
+      ],
+      "type T = 'a' | string | 1;": [
+        {
+          col: 9,
+          message: NoRedundantTypeConstituentsMessage::LiteralOverridden(
+            "'a'".to_string(),
+            "string"
+          ),
+          hint: NoRedundantTypeConstituentsHint::Remove,
+        }
+      ],
+      "type T = number & unknown;": [
+        {
+          col: 18,
+          message: NoRedundantTypeConstituentsMessage::Overridden(
+            "unknown",
+            Container::Intersection
+          ),
+          hint: NoRedundantTypeConstituentsHint::Remove,
+        }
+      ],
+      "type T = Foo & any;": [
+        {
+          col: 15,
+          message: NoRedundantTypeConstituentsMessage::Overrides(
+            "any",
+            Container::Intersection
+          ),
+          hint: NoRedundantTypeConstituentsHint::ReplaceWith(
+            Container::Intersection,
+            "any"
+          ),
+        }
+      ],
+      "type T = Foo & never;": [
+        {
+          col: 15,
+          message: NoRedundantTypeConstituentsMessage::Overrides(
+            "never",
+            Container::Intersection
+          ),
+          hint: NoRedundantTypeConstituentsHint::ReplaceWith(
+            Container::Intersection,
+            "never"
+          ),
+        }
+      ],
+      "type T = string & 'a';": [
+        {
+          col: 9,
+          message: NoRedundantTypeConstituentsMessage::PrimitiveOverridden(
+            "string",
+            "'a'".to_string()
+          ),
+          hint: NoRedundantTypeConstituentsHint::Remove,
+        }
+      ],
+      "type T = boolean | true;": [
+        {
+          col: 19,
+          message: NoRedundantTypeConstituentsMessage::LiteralOverridden(
+            "true".to_string(),
+            "boolean"
+          ),
+          hint: NoRedundantTypeConstituentsHint::Remove,
+        }
+      ],
+    };
+  }
+}
@@ -1,12 +1,15 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
-use crate::swc_util::extract_regex;
-use crate::Program;
-use deno_ast::view::{CallExpr, Callee, Expr, NewExpr, Regex};
-use deno_ast::{SourceRange, SourceRanged};
-use once_cell::sync::Lazy;
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::Handler;
+use crate::js_regex::ast::{
+  walk_alternative, Alternative, Atom, Char, CharKind, Span, Term, Visit,
+};
+use crate::js_regex::RegexSource;
+use crate::syntax_features::SyntaxFeatures;
+use deno_ast::view::{CallExpr, Callee, NewExpr, NodeKind, Regex};
+use deno_ast::SourceRanged;
 
 #[derive(Debug)]
 pub struct NoRegexSpaces;
@@ -14,6 +17,9 @@ pub struct NoRegexSpaces;
 const CODE: &str = "no-regex-spaces";
 const MESSAGE: &str =
   "more than one consecutive spaces in RegExp is not allowed";
+const HINT: &str =
+  "Use a quantifier for the spaces instead, e.g. ` {2}`, so they can be counted";
+const FIX_DESC: &str = "Replace the spaces with a quantifier";
 
 impl LintRule for NoRegexSpaces {
   fn tags(&self) -> &'static [&'static str] {
@@ -24,12 +30,20 @@ impl LintRule for NoRegexSpaces {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    NoRegexSpacesHandler.traverse(program, context);
+  fn required_syntax(&self) -> SyntaxFeatures {
+    SyntaxFeatures::REGEX
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoRegexSpacesHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::Regex, NodeKind::NewExpr, NodeKind::CallExpr])
   }
 
   #[cfg(feature = "docs")]
@@ -38,56 +52,143 @@ impl LintRule for NoRegexSpaces {
   }
 }
 
-struct NoRegexSpacesHandler;
+/// A run of consecutive spaces outside of character classes, none of which
+/// are quantified.
+struct SpaceRun {
+  span: Span,
+  /// The first space, which may be escaped, like `\ `.
+  first: Span,
+  len: usize,
+}
 
-fn check_regex(regex: &str, range: SourceRange, ctx: &mut Context) {
-  static DOUBLE_SPACE: Lazy<regex::Regex> =
-    Lazy::new(|| regex::Regex::new(r"(?u) {2}").unwrap());
-  static BRACKETS: Lazy<regex::Regex> =
-    Lazy::new(|| regex::Regex::new(r"\[.*?[^\\]\]").unwrap());
-  static SPACES: Lazy<regex::Regex> = Lazy::new(|| {
-    regex::Regex::new(r#"(?u)( {2,})(?: [+*{?]|[^+*{?]|$)"#).unwrap()
-  });
+#[derive(Default)]
+struct SpaceRunFinder {
+  runs: Vec<SpaceRun>,
+}
 
-  if !DOUBLE_SPACE.is_match(regex) {
-    return;
+impl SpaceRunFinder {
+  fn end_run(&mut self, run: &mut Vec<&Char>) {
+    if let [first, .., last] = run.as_slice() {
+      self.runs.push(SpaceRun {
+        span: Span::new(first.span.start, last.span.end),
+        first: first.span,
+        len: run.len(),
+      });
+    }
+    run.clear();
   }
+}
 
-  let mut character_classes = vec![];
-  for mtch in BRACKETS.find_iter(regex) {
-    character_classes.push((mtch.start(), mtch.end()));
+impl Visit for SpaceRunFinder {
+  fn visit_alternative(&mut self, alternative: &Alternative) {
+    let mut run = vec![];
+    for term in &alternative.terms {
+      match term {
+        Term {
+          atom: Atom::Char(c),
+          quantifier: None,
+          ..
+        } if c.value == u32::from(' ') => match c.kind {
+          CharKind::Literal => run.push(c),
+          // An escaped space, like `\ `, is only followed by a literal one
+          // in the source, so it starts a new run.
+          CharKind::Identity => {
+            self.end_run(&mut run);
+            run.push(c);
+          }
+          _ => self.end_run(&mut run),
+        },
+        _ => self.end_run(&mut run),
+      }
+    }
+    self.end_run(&mut run);
+    walk_alternative(self, alternative);
   }
+}
+
+fn check_regex(regex: &RegexSource, ctx: &mut Context) {
+  if !regex.pattern.contains("  ") {
+    return;
+  }
+  let Some(pattern) = regex.parse() else {
+    return;
+  };
+  let mut finder = SpaceRunFinder::default();
+  pattern.visit_with(&mut finder);
 
-  for mtch in SPACES.find_iter(regex) {
-    let not_in_classes = &character_classes
-      .iter()
-      .all(|v| mtch.start() < v.0 || v.1 <= mtch.start());
-    if *not_in_classes {
-      ctx.add_diagnostic(range, CODE, MESSAGE);
-      return;
+  let mut changes = Some(vec![]);
+  let mut found = false;
+  for run in finder.runs {
+    let (Some(range), Some(first)) = (
+      regex.exact_range_of(run.span),
+      regex.exact_range_of(run.first),
+    ) else {
+      // The spaces are in a string with escapes that can't be mapped, so
+      // they're reported without a fix.
+      found = true;
+      changes = None;
+      continue;
+    };
+    // Only spaces that are next to each other in the source are hard to
+    // count, unlike `\x20\x20` in a string.
+    if !ctx.text_of(range).contains("  ") {
+      continue;
+    }
+    found = true;
+    if let Some(changes) = &mut changes {
+      changes.push(LintFixChange {
+        new_text: format!("{}{{{}}}", ctx.text_of(first), run.len).into(),
+        range,
+      });
     }
   }
+  if !found {
+    return;
+  }
+
+  let fixes = changes
+    .map(|changes| LintFix {
+      description: FIX_DESC.into(),
+      changes,
+    })
+    .into_iter()
+    .collect();
+  ctx.add_diagnostic_with_fixes(
+    regex.range,
+    CODE,
+    MESSAGE,
+    Some(HINT.to_string()),
+    fixes,
+  );
 }
 
+struct NoRegexSpacesHandler;
+
 impl Handler for NoRegexSpacesHandler {
   fn regex(&mut self, regex: &Regex, ctx: &mut Context) {
-    check_regex(regex.inner.exp.to_string().as_str(), regex.range(), ctx);
+    check_regex(&RegexSource::from_regex(regex), ctx);
   }
 
   fn new_expr(&mut self, new_expr: &NewExpr, ctx: &mut Context) {
-    if let Expr::Ident(ident) = new_expr.callee {
-      if let Some(args) = &new_expr.args {
-        if let Some(regex) = extract_regex(ctx.scope(), ident, args) {
-          check_regex(regex.as_str(), new_expr.range(), ctx);
-        }
-      }
+    if let Some(regex) = RegexSource::from_constructor(
+      &new_expr.callee,
+      new_expr.args.unwrap_or_default(),
+      new_expr.range(),
+      ctx,
+    ) {
+      check_regex(&regex, ctx);
     }
   }
 
   fn call_expr(&mut self, call_expr: &CallExpr, ctx: &mut Context) {
-    if let Callee::Expr(Expr::Ident(ident)) = &call_expr.callee {
-      if let Some(regex) = extract_regex(ctx.scope(), ident, call_expr.args) {
-        check_regex(regex.as_str(), call_expr.range(), ctx);
+    if let Callee::Expr(callee) = &call_expr.callee {
+      if let Some(regex) = RegexSource::from_constructor(
+        callee,
+        call_expr.args,
+        call_expr.range(),
+        ctx,
+      ) {
+        check_regex(&regex, ctx);
       }
     }
   }
@@ -132,6 +233,7 @@ mod tests {
       r"var foo = new RegExp('bar \\u0020 baz')",
       r"var foo = new RegExp('bar\\u0020\\u0020baz')",
       r"var foo = new RegExp('bar \\\\u0020 baz')",
+      r"var foo = new RegExp('bar\x20\x20baz')",
 
       // don't report spaces in character classes
       "var foo = /[  ]/;",
@@ -155,162 +257,176 @@ mod tests {
   fn no_regex_spaces_invalid() {
     assert_lint_err! {
       NoRegexSpaces,
+      MESSAGE,
+      HINT,
       "let foo = /bar  baz/;": [
         {
           col: 10,
-          message: MESSAGE,
+          fix: (FIX_DESC, "let foo = /bar {2}baz/;"),
         }
       ],
       "let foo = /bar    baz/;": [
         {
           col: 10,
-          message: MESSAGE,
+          fix: (FIX_DESC, "let foo = /bar {4}baz/;"),
         }
       ],
       "let foo = / a b  c d /;": [
         {
           col: 10,
-          message: MESSAGE,
+          fix: (FIX_DESC, "let foo = / a b {2}c d /;"),
         }
       ],
       "let foo = RegExp(' a b c d  ');": [
         {
           col: 10,
-          message: MESSAGE,
+          fix: (FIX_DESC, "let foo = RegExp(' a b c d {2}');"),
         }
       ],
       "let foo = RegExp('bar    baz');": [
         {
           col: 10,
-          message: MESSAGE,
+          fix: (FIX_DESC, "let foo = RegExp('bar {4}baz');"),
         }
       ],
       "let foo = new RegExp('bar    baz');": [
         {
           col: 10,
-          message: MESSAGE,
+          fix: (FIX_DESC, "let foo = new RegExp('bar {4}baz');"),
         }
       ],
       "{ let RegExp = function() {}; } var foo = RegExp('bar    baz');": [
         {
           col: 42,
-          message: MESSAGE,
+          fix: (FIX_DESC, "{ let RegExp = function() {}; } var foo = RegExp('bar {4}baz');"),
         }
       ],
       "let foo = /bar   {3}baz/;": [
         {
           col: 10,
-          message: MESSAGE,
+          fix: (FIX_DESC, "let foo = /bar {2} {3}baz/;"),
         }
       ],
       "let foo = /bar    ?baz/;": [
         {
           col: 10,
-          message: MESSAGE,
+          fix: (FIX_DESC, "let foo = /bar {3} ?baz/;"),
         }
       ],
       "let foo = RegExp('bar   +baz')": [
         {
           col: 10,
-          message: MESSAGE,
+          fix: (FIX_DESC, "let foo = RegExp('bar {2} +baz')"),
         }
       ],
       "let foo = new RegExp('bar    ');": [
         {
           col: 10,
-          message: MESSAGE,
+          fix: (FIX_DESC, "let foo = new RegExp('bar {4}');"),
         }
       ],
       "let foo = /bar\\  baz/;": [
         {
           col: 10,
-          message: MESSAGE,
+          fix: (FIX_DESC, "let foo = /bar\\ {2}baz/;"),
         }
       ],
       "let foo = /[   ]  /;": [
         {
           col: 10,
-          message: MESSAGE,
+          fix: (FIX_DESC, "let foo = /[   ] {2}/;"),
         }
       ],
       "let foo = /  [   ] /;": [
         {
           col: 10,
-          message: MESSAGE,
+          fix: (FIX_DESC, "let foo = / {2}[   ] /;"),
         }
       ],
       "let foo = new RegExp('[   ]  ');": [
         {
           col: 10,
-          message: MESSAGE,
+          fix: (FIX_DESC, "let foo = new RegExp('[   ] {2}');"),
         }
       ],
       "let foo = RegExp('  [ ]');": [
         {
           col: 10,
-          message: MESSAGE,
+          fix: (FIX_DESC, "let foo = RegExp(' {2}[ ]');"),
         }
       ],
       "let foo = /\\[  /;": [
         {
           col: 10,
-          message: MESSAGE,
+          fix: (FIX_DESC, "let foo = /\\[ {2}/;"),
         }
       ],
       "let foo = /\\[  \\]/;": [
         {
           col: 10,
-          message: MESSAGE,
+          fix: (FIX_DESC, "let foo = /\\[ {2}\\]/;"),
         }
       ],
       "let foo = /(?:  )/;": [
         {
           col: 10,
-          message: MESSAGE,
+          fix: (FIX_DESC, "let foo = /(?: {2})/;"),
         }
       ],
       "let foo = RegExp('^foo(?=   )');": [
         {
           col: 10,
-          message: MESSAGE,
+          fix: (FIX_DESC, "let foo = RegExp('^foo(?= {3})');"),
         }
       ],
       "let foo = /\\  /": [
         {
           col: 10,
-          message: MESSAGE,
+          fix: (FIX_DESC, "let foo = /\\ {2}/"),
         }
       ],
       "let foo = / \\  /": [
         {
           col: 10,
-          message: MESSAGE,
+          fix: (FIX_DESC, "let foo = / \\ {2}/"),
         }
       ],
       "let foo = /  foo   /;": [
         {
           col: 10,
-          message: MESSAGE,
+          fix: (FIX_DESC, "let foo = / {2}foo {3}/;"),
         }
       ],
       "let foo = new RegExp('\\\\d  ')": [
         {
           col: 10,
-          message: MESSAGE,
+          fix: (FIX_DESC, "let foo = new RegExp('\\\\d {2}')"),
         }
       ],
       "let foo = RegExp('\\u0041   ')": [
         {
           col: 10,
-          message: MESSAGE,
+          fix: (FIX_DESC, "let foo = RegExp('\\u0041 {3}')"),
         }
       ],
       "let foo = new RegExp('\\\\[  \\\\]');": [
         {
           col: 10,
-          message: MESSAGE,
+          fix: (FIX_DESC, "let foo = new RegExp('\\\\[ {2}\\\\]');"),
         }
-      ]
+      ],
+      "let foo = RegExp('bar\\x20  baz');": [
+        {
+          col: 10,
+          fix: (FIX_DESC, "let foo = RegExp('bar\\x20{3}baz');"),
+        }
+      ],
+      "let foo = RegExp('\\0  ');": [
+        {
+          col: 10,
+          fix: (FIX_DESC, "let foo = RegExp('\\0 {2}');"),
+        }
+      ],
     };
   }
 }
@@ -0,0 +1,562 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::handler::Handler;
+use crate::js_regex::ast::{
+  Alternative, Atom, CharacterClass, ClassEscape, ClassEscapeKind, ClassItem,
+  Span, Term, Visit,
+};
+use crate::js_regex::RegexSource;
+use crate::syntax_features::SyntaxFeatures;
+
+use deno_ast::view::{CallExpr, Callee, NewExpr, NodeKind, Regex};
+use deno_ast::SourceRanged;
+use derive_more::Display;
+
+#[derive(Debug)]
+pub struct NoRegexVulnerabilities;
+
+const CODE: &str = "no-regex-vulnerabilities";
+
+#[derive(Display, Clone, Copy)]
+enum NoRegexVulnerabilitiesMessage {
+  #[display(
+    fmt = "A repeated group contains a quantifier over the same characters, so matching can take exponential time"
+  )]
+  NestedQuantifier,
+  #[display(
+    fmt = "A repeated group has alternatives that match the same characters, so matching can take exponential time"
+  )]
+  OverlappingAlternatives,
+}
+
+#[derive(Display)]
+enum NoRegexVulnerabilitiesHint {
+  #[display(
+    fmt = "Remove the inner quantifier or make the parts of the group match different characters, e.g. write `(a+)+` as `a+`"
+  )]
+  NestedQuantifier,
+  #[display(
+    fmt = "Make the alternatives match different characters, e.g. write `(\\w|\\d)+` as `\\w+`"
+  )]
+  OverlappingAlternatives,
+}
+
+impl LintRule for NoRegexVulnerabilities {
+  fn tags(&self) -> &'static [&'static str] {
+    &["security"]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn required_syntax(&self) -> SyntaxFeatures {
+    SyntaxFeatures::REGEX
+  }
+
+  fn aliases(&self) -> &'static [&'static str] {
+    &["regexp/no-super-linear-backtracking"]
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoRegexVulnerabilitiesHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::Regex, NodeKind::NewExpr, NodeKind::CallExpr])
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_regex_vulnerabilities.md")
+  }
+}
+
+const MAX_CODE_POINT: u32 = 0x10ffff;
+
+/// A set of code points, stored as sorted, non-overlapping inclusive ranges.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct CharSet(Vec<(u32, u32)>);
+
+impl CharSet {
+  fn new(mut ranges: Vec<(u32, u32)>) -> Self {
+    ranges.sort_unstable();
+    let mut merged: Vec<(u32, u32)> = Vec::with_capacity(ranges.len());
+    for (from, to) in ranges {
+      match merged.last_mut() {
+        Some(last) if from <= last.1.saturating_add(1) => {
+          last.1 = last.1.max(to);
+        }
+        _ => merged.push((from, to)),
+      }
+    }
+    Self(merged)
+  }
+
+  fn all() -> Self {
+    Self(vec![(0, MAX_CODE_POINT)])
+  }
+
+  fn single(c: u32) -> Self {
+    Self(vec![(c, c)])
+  }
+
+  fn is_empty(&self) -> bool {
+    self.0.is_empty()
+  }
+
+  fn union(&self, other: &Self) -> Self {
+    Self::new(self.0.iter().chain(&other.0).copied().collect())
+  }
+
+  fn intersection(&self, other: &Self) -> Self {
+    let mut ranges = Vec::new();
+    for &(a_from, a_to) in &self.0 {
+      for &(b_from, b_to) in &other.0 {
+        let (from, to) = (a_from.max(b_from), a_to.min(b_to));
+        if from <= to {
+          ranges.push((from, to));
+        }
+      }
+    }
+    Self::new(ranges)
+  }
+
+  fn overlaps(&self, other: &Self) -> bool {
+    !self.intersection(other).is_empty()
+  }
+
+  fn complement(&self) -> Self {
+    let mut ranges = Vec::new();
+    let mut next = 0;
+    for &(from, to) in &self.0 {
+      if from > next {
+        ranges.push((next, from - 1));
+      }
+      next = to + 1;
+    }
+    if next <= MAX_CODE_POINT {
+      ranges.push((next, MAX_CODE_POINT));
+    }
+    Self(ranges)
+  }
+
+  /// Adds the other case of the ASCII letters in the set, which is enough to
+  /// tell whether two sets overlap with the `i` flag in practice.
+  fn ignore_case(&self) -> Self {
+    let mut ranges = self.0.clone();
+    for &(from, to) in &self.0 {
+      for (letters, other_case) in [
+        (('a' as u32, 'z' as u32), 'A' as u32),
+        (('A' as u32, 'Z' as u32), 'a' as u32),
+      ] {
+        let (from, to) = (from.max(letters.0), to.min(letters.1));
+        if from <= to {
+          ranges
+            .push((from - letters.0 + other_case, to - letters.0 + other_case));
+        }
+      }
+    }
+    Self::new(ranges)
+  }
+}
+
+static DIGIT: &[(u32, u32)] = &[(0x30, 0x39)];
+static WORD: &[(u32, u32)] =
+  &[(0x30, 0x39), (0x41, 0x5a), (0x5f, 0x5f), (0x61, 0x7a)];
+static SPACE: &[(u32, u32)] = &[
+  (0x09, 0x0d),
+  (0x20, 0x20),
+  (0xa0, 0xa0),
+  (0x1680, 0x1680),
+  (0x2000, 0x200a),
+  (0x2028, 0x2029),
+  (0x202f, 0x202f),
+  (0x205f, 0x205f),
+  (0x3000, 0x3000),
+  (0xfeff, 0xfeff),
+];
+static LINE_TERMINATORS: &[(u32, u32)] =
+  &[(0x0a, 0x0a), (0x0d, 0x0d), (0x2028, 0x2029)];
+
+/// Looks for repeated groups that can match the same input in many different
+/// ways, which makes a backtracking engine like V8's try all of them before
+/// failing. This is a heuristic: it finds the common cases like `(a+)+`,
+/// `(\w+\s?)*` and `(a|a)*`, not every pattern with exponential backtracking.
+struct Analyzer {
+  ignore_case: bool,
+  dot_all: bool,
+  found: Option<(NoRegexVulnerabilitiesMessage, Span)>,
+}
+
+impl Analyzer {
+  fn class_escape_chars(&self, escape: &ClassEscape) -> CharSet {
+    let set = match escape.kind {
+      ClassEscapeKind::Digit => CharSet(DIGIT.to_vec()),
+      ClassEscapeKind::Word => CharSet(WORD.to_vec()),
+      ClassEscapeKind::Space => CharSet(SPACE.to_vec()),
+      // Unicode properties aren't worth the tables, so assume they can match
+      // anything.
+      ClassEscapeKind::Property => return CharSet::all(),
+    };
+    if escape.negated {
+      set.complement()
+    } else {
+      set
+    }
+  }
+
+  fn class_chars(&self, class: &CharacterClass) -> CharSet {
+    let set = class.items.iter().fold(CharSet::default(), |set, item| {
+      set.union(&match item {
+        ClassItem::Char(c) => CharSet::single(c.value),
+        ClassItem::Range(from, to) => CharSet(vec![(from.value, to.value)]),
+        ClassItem::Escape(escape) => self.class_escape_chars(escape),
+      })
+    });
+    let set = if self.ignore_case {
+      set.ignore_case()
+    } else {
+      set
+    };
+    if class.negated {
+      set.complement()
+    } else {
+      set
+    }
+  }
+
+  /// The characters the atom can match when it stands for a single character,
+  /// or `None` if it's a group, assertion or backreference.
+  fn single_char_chars(&self, atom: &Atom) -> Option<CharSet> {
+    let set = match atom {
+      Atom::Char(c) if self.ignore_case => {
+        CharSet::single(c.value).ignore_case()
+      }
+      Atom::Char(c) => CharSet::single(c.value),
+      Atom::Dot if self.dot_all => CharSet::all(),
+      Atom::Dot => CharSet(LINE_TERMINATORS.to_vec()).complement(),
+      Atom::ClassEscape(escape) => self.class_escape_chars(escape),
+      Atom::Class(class) => self.class_chars(class),
+      Atom::Assertion(_) | Atom::Group(_) | Atom::Backreference(_) => {
+        return None
+      }
+    };
+    Some(set)
+  }
+
+  /// All the characters the atom can consume.
+  fn atom_chars(&self, atom: &Atom) -> CharSet {
+    if let Some(set) = self.single_char_chars(atom) {
+      return set;
+    }
+    match atom {
+      Atom::Group(group) => group
+        .alternatives
+        .iter()
+        .flat_map(|alternative| &alternative.terms)
+        .fold(CharSet::default(), |set, term| {
+          set.union(&self.atom_chars(&term.atom))
+        }),
+      Atom::Backreference(_) => CharSet::all(),
+      _ => CharSet::default(),
+    }
+  }
+
+  /// The characters a match of the alternatives can start with.
+  fn first_chars(&self, alternatives: &[Alternative]) -> CharSet {
+    let mut set = CharSet::default();
+    for alternative in alternatives {
+      for term in &alternative.terms {
+        set = set.union(&match &term.atom {
+          Atom::Group(group) => self.first_chars(&group.alternatives),
+          atom => self.atom_chars(atom),
+        });
+        if !is_nullable(term) {
+          break;
+        }
+      }
+    }
+    set
+  }
+
+  /// The characters that every term of a alternative made only of single
+  /// characters can match, like `a` for `a|aa`. Two such alternatives that
+  /// share a character can both match a run of it.
+  fn shared_chars(&self, alternative: &Alternative) -> Option<CharSet> {
+    let mut terms = alternative.terms.iter();
+    let first = self.single_char_chars(&terms.next()?.atom)?;
+    terms.try_fold(first, |set, term| {
+      Some(set.intersection(&self.single_char_chars(&term.atom)?))
+    })
+  }
+
+  fn check(&self, term: &Term) -> Option<NoRegexVulnerabilitiesMessage> {
+    let (Atom::Group(group), Some(quantifier)) = (&term.atom, &term.quantifier)
+    else {
+      return None;
+    };
+    if !quantifier.is_unbounded() {
+      return None;
+    }
+
+    // `(a+)+`: the inner quantifier ends the group, so each run of `a` can be
+    // split between the inner and the outer quantifier in many ways.
+    let first = self.first_chars(&group.alternatives);
+    for alternative in &group.alternatives {
+      for (i, inner) in alternative.terms.iter().enumerate() {
+        if inner.quantifier.is_some_and(|q| q.is_unbounded())
+          && alternative.terms[i + 1..].iter().all(is_nullable)
+          && self.atom_chars(&inner.atom).overlaps(&first)
+        {
+          return Some(NoRegexVulnerabilitiesMessage::NestedQuantifier);
+        }
+      }
+    }
+
+    // `(a|a)*`: every repetition can pick either alternative.
+    let shared = group
+      .alternatives
+      .iter()
+      .filter_map(|alternative| self.shared_chars(alternative))
+      .collect::<Vec<_>>();
+    for (i, a) in shared.iter().enumerate() {
+      if shared[i + 1..].iter().any(|b| a.overlaps(b)) {
+        return Some(NoRegexVulnerabilitiesMessage::OverlappingAlternatives);
+      }
+    }
+    None
+  }
+}
+
+impl Visit for Analyzer {
+  fn visit_term(&mut self, term: &Term) {
+    if self.found.is_some() {
+      return;
+    }
+    self.found = self.check(term).map(|message| (message, term.span));
+    self.visit_atom(&term.atom);
+  }
+}
+
+/// Whether the term can match the empty string.
+fn is_nullable(term: &Term) -> bool {
+  term.quantifier.is_some_and(|q| q.min == 0) || is_atom_nullable(&term.atom)
+}
+
+fn is_atom_nullable(atom: &Atom) -> bool {
+  match atom {
+    Atom::Assertion(_) | Atom::Backreference(_) => true,
+    Atom::Group(group) => group
+      .alternatives
+      .iter()
+      .any(|alternative| alternative.terms.iter().all(is_nullable)),
+    _ => false,
+  }
+}
+
+fn check_regex(regex: &RegexSource, ctx: &mut Context) {
+  let Some(flags) = &regex.flags else {
+    return;
+  };
+  let Some(pattern) = regex.parse() else {
+    return;
+  };
+  let mut analyzer = Analyzer {
+    ignore_case: flags.contains('i'),
+    dot_all: flags.contains('s'),
+    found: None,
+  };
+  pattern.visit_with(&mut analyzer);
+  let Some((message, span)) = analyzer.found else {
+    return;
+  };
+  let hint = match message {
+    NoRegexVulnerabilitiesMessage::NestedQuantifier => {
+      NoRegexVulnerabilitiesHint::NestedQuantifier
+    }
+    NoRegexVulnerabilitiesMessage::OverlappingAlternatives => {
+      NoRegexVulnerabilitiesHint::OverlappingAlternatives
+    }
+  };
+  ctx.add_diagnostic_with_hint(regex.range_of(span), CODE, message, hint);
+}
+
+struct NoRegexVulnerabilitiesHandler;
+
+impl Handler for NoRegexVulnerabilitiesHandler {
+  fn regex(&mut self, regex: &Regex, ctx: &mut Context) {
+    check_regex(&RegexSource::from_regex(regex), ctx);
+  }
+
+  fn new_expr(&mut self, new_expr: &NewExpr, ctx: &mut Context) {
+    if let Some(regex) = RegexSource::from_constructor(
+      &new_expr.callee,
+      new_expr.args.unwrap_or_default(),
+      new_expr.range(),
+      ctx,
+    ) {
+      check_regex(&regex, ctx);
+    }
+  }
+
+  fn call_expr(&mut self, call_expr: &CallExpr, ctx: &mut Context) {
+    if let Callee::Expr(callee) = &call_expr.callee {
+      if let Some(regex) = RegexSource::from_constructor(
+        callee,
+        call_expr.args,
+        call_expr.range(),
+        ctx,
+      ) {
+        check_regex(&regex, ctx);
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_regex_vulnerabilities_valid() {
+    assert_lint_ok! {
+      NoRegexVulnerabilities,
+      r"/a+/",
+      r"/(ab)+/",
+      r"/(a+b)+/",
+      r"/(\d+,)*\d+/",
+      r"/^(a|b)*$/",
+      r"/(ab|ac)*/",
+      r"/(a|ab)*/",
+      r"/(?:\s*\w+)?/",
+      r"/(a+){1,3}/",
+      r"/(\d+)?px/",
+      r"/^[\w.+-]+@[\w-]+\.[\w.-]+$/",
+      r"/(?:[a-z]+\d)+/",
+      r#"/"(?:[^"\\]|\\.)*"/"#,
+      r"/(a|A)*/",
+      r"new RegExp('(a|b)+')",
+      r"new RegExp(pattern)",
+      r"new RegExp('(a+)+', flags)",
+      r"const RegExp = String; new RegExp('(a+)+');",
+      r"new RegExp('[')",
+      r"/[[a]--[b]]+/v",
+    };
+  }
+
+  #[test]
+  fn no_regex_vulnerabilities_invalid() {
+    assert_lint_err! {
+      NoRegexVulnerabilities,
+      r"/(a+)+$/": [
+        {
+          col: 1,
+          message: NoRegexVulnerabilitiesMessage::NestedQuantifier,
+          hint: NoRegexVulnerabilitiesHint::NestedQuantifier,
+        }
+      ],
+      r"/^(\w+\s?)*$/": [
+        {
+          col: 2,
+          message: NoRegexVulnerabilitiesMessage::NestedQuantifier,
+          hint: NoRegexVulnerabilitiesHint::NestedQuantifier,
+        }
+      ],
+      r"/(?:x*)*y/": [
+        {
+          col: 1,
+          message: NoRegexVulnerabilitiesMessage::NestedQuantifier,
+          hint: NoRegexVulnerabilitiesHint::NestedQuantifier,
+        }
+      ],
+      r#"/"([^"\\]+|\\.)*"/"#: [
+        {
+          col: 2,
+          message: NoRegexVulnerabilitiesMessage::NestedQuantifier,
+          hint: NoRegexVulnerabilitiesHint::NestedQuantifier,
+        }
+      ],
+      r"/(\s*\w+)+$/": [
+        {
+          col: 1,
+          message: NoRegexVulnerabilitiesMessage::NestedQuantifier,
+          hint: NoRegexVulnerabilitiesHint::NestedQuantifier,
+        }
+      ],
+      r"/(a|a)*b/": [
+        {
+          col: 1,
+          message: NoRegexVulnerabilitiesMessage::OverlappingAlternatives,
+          hint: NoRegexVulnerabilitiesHint::OverlappingAlternatives,
+        }
+      ],
+      r"/(\w|\d)+$/": [
+        {
+          col: 1,
+          message: NoRegexVulnerabilitiesMessage::OverlappingAlternatives,
+          hint: NoRegexVulnerabilitiesHint::OverlappingAlternatives,
+        }
+      ],
+      r"/(.|\s)*;/": [
+        {
+          col: 1,
+          message: NoRegexVulnerabilitiesMessage::OverlappingAlternatives,
+          hint: NoRegexVulnerabilitiesHint::OverlappingAlternatives,
+        }
+      ],
+      r"/(a|aa){2,}$/": [
+        {
+          col: 1,
+          message: NoRegexVulnerabilitiesMessage::OverlappingAlternatives,
+          hint: NoRegexVulnerabilitiesHint::OverlappingAlternatives,
+        }
+      ],
+      r"/(a|A)*$/i": [
+        {
+          col: 1,
+          message: NoRegexVulnerabilitiesMessage::OverlappingAlternatives,
+          hint: NoRegexVulnerabilitiesHint::OverlappingAlternatives,
+        }
+      ],
+      r"const re = new RegExp('^(\\d+)*$');": [
+        {
+          col: 24,
+          message: NoRegexVulnerabilitiesMessage::NestedQuantifier,
+          hint: NoRegexVulnerabilitiesHint::NestedQuantifier,
+        }
+      ],
+      r"RegExp('(x+x+)+y', 'g');": [
+        {
+          col: 8,
+          message: NoRegexVulnerabilitiesMessage::NestedQuantifier,
+          hint: NoRegexVulnerabilitiesHint::NestedQuantifier,
+        }
+      ],
+      r"new RegExp(/(a+)+/);": [
+        {
+          col: 12,
+          message: NoRegexVulnerabilitiesMessage::NestedQuantifier,
+          hint: NoRegexVulnerabilitiesHint::NestedQuantifier,
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn char_set() {
+    let set = CharSet::new(vec![(5, 10), (0, 2), (3, 4), (20, 30)]);
+    assert_eq!(set, CharSet(vec![(0, 10), (20, 30)]));
+    assert_eq!(
+      set.complement(),
+      CharSet(vec![(11, 19), (31, MAX_CODE_POINT)])
+    );
+    assert!(set.overlaps(&CharSet::single(25)));
+    assert!(!set.overlaps(&CharSet::single(15)));
+    assert_eq!(
+      CharSet::single('a' as u32).ignore_case(),
+      CharSet(vec![('A' as u32, 'A' as u32), ('a' as u32, 'a' as u32)])
+    );
+  }
+}
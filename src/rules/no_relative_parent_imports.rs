@@ -0,0 +1,421 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::Program;
+
+use deno_ast::{ModuleSpecifier, SourceRange};
+use derive_more::Display;
+use serde::Deserialize;
+
+#[derive(Debug, Default)]
+pub struct NoRelativeParentImports {
+  options: NoRelativeParentImportsOptions,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct NoRelativeParentImportsOptions {
+  /// How many directories a relative import may go up, or `None` for no
+  /// limit.
+  pub max_depth: Option<usize>,
+  /// The directories of packages, as absolute paths or URLs. Relative imports
+  /// must not leave the package of the importing file.
+  pub package_roots: Vec<String>,
+  /// Directories that files in other directories must not import from. They
+  /// apply to resolved specifiers, so also to those mapped by an import map.
+  pub zones: Vec<ImportZone>,
+}
+
+impl Default for NoRelativeParentImportsOptions {
+  fn default() -> Self {
+    Self {
+      max_depth: Some(0),
+      package_roots: Vec::new(),
+      zones: Vec::new(),
+    }
+  }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportZone {
+  /// The directory of the files that the restriction applies to.
+  pub target: String,
+  /// The directory that those files must not import from.
+  pub from: String,
+  /// Explains the restriction, like which module to use instead.
+  pub message: Option<String>,
+}
+
+impl NoRelativeParentImports {
+  pub fn new(options: NoRelativeParentImportsOptions) -> Self {
+    Self { options }
+  }
+}
+
+const CODE: &str = "no-relative-parent-imports";
+
+#[derive(Display)]
+enum NoRelativeParentImportsMessage {
+  #[display(
+    fmt = "`{}` goes up {} directories, more than the {} allowed",
+    _0,
+    _1,
+    _2
+  )]
+  TooDeep(String, usize, usize),
+  #[display(fmt = "`{}` is outside of the package at `{}`", _0, _1)]
+  OutsidePackage(String, String),
+  #[display(fmt = "Importing `{}` from here is restricted", _0)]
+  Restricted(String),
+}
+
+#[derive(Display)]
+enum NoRelativeParentImportsHint {
+  #[display(
+    fmt = "Move the module closer, or import it through an import map entry"
+  )]
+  TooDeep,
+  #[display(
+    fmt = "Import the other package by its name, so it's used through its public exports"
+  )]
+  OutsidePackage,
+  #[display(fmt = "Import it from a module that is allowed here")]
+  Restricted,
+}
+
+impl LintRule for NoRelativeParentImports {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "maxDepth": { "type": ["integer", "null"], "minimum": 0 },
+        "packageRoots": { "type": "array", "items": { "type": "string" } },
+        "zones": {
+          "type": "array",
+          "items": {
+            "type": "object",
+            "properties": {
+              "target": { "type": "string" },
+              "from": { "type": "string" },
+              "message": { "type": "string" }
+            },
+            "required": ["target", "from"]
+          }
+        }
+      }
+    }))
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    _program: Program,
+  ) {
+    let package_roots = self
+      .options
+      .package_roots
+      .iter()
+      .filter_map(|root| directory_url(root))
+      .collect::<Vec<_>>();
+    let zones = self
+      .options
+      .zones
+      .iter()
+      .filter_map(|zone| {
+        Some((
+          directory_url(&zone.target)?,
+          directory_url(&zone.from)?,
+          zone,
+        ))
+      })
+      .collect::<Vec<_>>();
+    let importer = context.specifier().clone();
+    // The longest root is the package of nested packages.
+    let package = package_roots
+      .iter()
+      .filter(|root| importer.as_str().starts_with(root.as_str()))
+      .max_by_key(|root| root.as_str().len());
+
+    for (specifier, range) in context.module_graph().import_specifiers() {
+      let is_relative =
+        specifier.starts_with("./") || specifier.starts_with("../");
+      if is_relative {
+        let depth = parent_depth(specifier);
+        if let Some(max_depth) = self.options.max_depth {
+          if depth > max_depth {
+            report(
+              context,
+              range,
+              NoRelativeParentImportsMessage::TooDeep(
+                specifier.to_string(),
+                depth,
+                max_depth,
+              ),
+              NoRelativeParentImportsHint::TooDeep.to_string(),
+            );
+            continue;
+          }
+        }
+      }
+      let Some(resolved) = context.resolve(specifier) else {
+        continue;
+      };
+      if let Some(package) = package {
+        if is_relative && !resolved.as_str().starts_with(package.as_str()) {
+          report(
+            context,
+            range,
+            NoRelativeParentImportsMessage::OutsidePackage(
+              specifier.to_string(),
+              display_path(package),
+            ),
+            NoRelativeParentImportsHint::OutsidePackage.to_string(),
+          );
+          continue;
+        }
+      }
+      let restriction = zones.iter().find(|(target, from, _)| {
+        importer.as_str().starts_with(target.as_str())
+          && resolved.as_str().starts_with(from.as_str())
+      });
+      if let Some((_, _, zone)) = restriction {
+        report(
+          context,
+          range,
+          NoRelativeParentImportsMessage::Restricted(specifier.to_string()),
+          zone.message.clone().unwrap_or_else(|| {
+            NoRelativeParentImportsHint::Restricted.to_string()
+          }),
+        );
+      }
+    }
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_relative_parent_imports.md")
+  }
+}
+
+fn report(
+  ctx: &mut Context,
+  range: SourceRange,
+  message: NoRelativeParentImportsMessage,
+  hint: String,
+) {
+  ctx.add_diagnostic_with_hint(range, CODE, message, hint);
+}
+
+/// How many directories above the importing file's directory the path goes,
+/// so `../a/../b` is 1 and `./a/../b` is 0.
+fn parent_depth(specifier: &str) -> usize {
+  let path = specifier.split(['?', '#']).next().unwrap_or_default();
+  let mut level: isize = 0;
+  let mut deepest: isize = 0;
+  for segment in path.split('/') {
+    match segment {
+      ".." => {
+        level -= 1;
+        deepest = deepest.min(level);
+      }
+      "." | "" => {}
+      _ => level += 1,
+    }
+  }
+  deepest.unsigned_abs()
+}
+
+/// Converts a directory, as a URL or an absolute path, to a URL that ends
+/// with `/`, so its files are the URLs that start with it.
+fn directory_url(directory: &str) -> Option<ModuleSpecifier> {
+  // Windows paths like `C:\repo` parse as URLs with a single letter scheme.
+  let url = match ModuleSpecifier::parse(directory) {
+    Ok(url) if url.scheme().len() > 1 => url,
+    _ => ModuleSpecifier::from_directory_path(directory).ok()?,
+  };
+  if url.path().ends_with('/') {
+    Some(url)
+  } else {
+    ModuleSpecifier::parse(&format!("{url}/")).ok()
+  }
+}
+
+fn display_path(url: &ModuleSpecifier) -> String {
+  match url.to_file_path() {
+    Ok(path) => path.display().to_string(),
+    Err(()) => url.to_string(),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn options(
+    max_depth: Option<usize>,
+    package_roots: &[&str],
+    zones: &[(&str, &str, Option<&str>)],
+  ) -> NoRelativeParentImportsOptions {
+    NoRelativeParentImportsOptions {
+      max_depth,
+      package_roots: package_roots.iter().map(|s| s.to_string()).collect(),
+      zones: zones
+        .iter()
+        .map(|(target, from, message)| ImportZone {
+          target: target.to_string(),
+          from: from.to_string(),
+          message: message.map(String::from),
+        })
+        .collect(),
+    }
+  }
+
+  #[test]
+  fn parent_depth_of_specifiers() {
+    assert_eq!(parent_depth("./a.ts"), 0);
+    assert_eq!(parent_depth("../a.ts"), 1);
+    assert_eq!(parent_depth("../../a/b.ts"), 2);
+    assert_eq!(parent_depth("../a/../b.ts"), 1);
+    assert_eq!(parent_depth("./a/../b.ts"), 0);
+    assert_eq!(parent_depth("./a/../../b.ts"), 1);
+    assert_eq!(parent_depth("./a.ts?x=../../"), 0);
+  }
+
+  #[test]
+  fn no_relative_parent_imports_valid() {
+    assert_lint_ok! {
+      NoRelativeParentImports::default(),
+      r#"import { a } from "./a.ts";"#,
+      r#"import { a } from "./lib/a.ts";"#,
+      r#"import { join } from "jsr:@std/path@1";"#,
+      r#"import { a } from "https://example.com/a/../b.ts";"#,
+    };
+
+    assert_lint_ok! {
+      NoRelativeParentImports::new(options(Some(1), &[], &[])),
+      r#"import { a } from "../a.ts";"#,
+      r#"export * from "../lib/../a.ts";"#,
+    };
+
+    assert_lint_ok! {
+      NoRelativeParentImports::new(options(
+        None,
+        &["file:///repo/packages/a", "file:///repo/packages/a/nested"],
+        &[],
+      )),
+      filename: "file:///repo/packages/a/src/utils/mod.ts",
+      r#"import { b } from "../../b.ts";"#,
+      r#"import { c } from "../../nested/c.ts";"#,
+      r#"import { d } from "npm:d@1";"#,
+    };
+
+    assert_lint_ok! {
+      NoRelativeParentImports::new(options(
+        None,
+        &[],
+        &[("file:///repo/src/ui/", "file:///repo/src/db/", None)],
+      )),
+      filename: "file:///repo/src/api/mod.ts",
+      r#"import { query } from "../db/query.ts";"#,
+    };
+  }
+
+  #[test]
+  fn no_relative_parent_imports_invalid() {
+    assert_lint_err! {
+      NoRelativeParentImports::default(),
+      r#"import { a } from "../a.ts";"#: [
+        {
+          col: 18,
+          message: NoRelativeParentImportsMessage::TooDeep(
+            "../a.ts".to_string(),
+            1,
+            0,
+          ),
+          hint: NoRelativeParentImportsHint::TooDeep,
+        }
+      ],
+      r#"const a = await import("./lib/../../a.ts");"#: [
+        {
+          col: 23,
+          message: NoRelativeParentImportsMessage::TooDeep(
+            "./lib/../../a.ts".to_string(),
+            1,
+            0,
+          ),
+          hint: NoRelativeParentImportsHint::TooDeep,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoRelativeParentImports::new(options(Some(1), &[], &[])),
+      r#"export { a } from "../../a.ts";"#: [
+        {
+          col: 18,
+          message: NoRelativeParentImportsMessage::TooDeep(
+            "../../a.ts".to_string(),
+            2,
+            1,
+          ),
+          hint: NoRelativeParentImportsHint::TooDeep,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoRelativeParentImports::new(options(
+        None,
+        &["/repo/packages/a", "/repo/packages/b"],
+        &[],
+      )),
+      filename: "file:///repo/packages/a/src/mod.ts",
+      r#"import { b } from "../../b/mod.ts";"#: [
+        {
+          col: 18,
+          message: NoRelativeParentImportsMessage::OutsidePackage(
+            "../../b/mod.ts".to_string(),
+            "/repo/packages/a/".to_string(),
+          ),
+          hint: NoRelativeParentImportsHint::OutsidePackage,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoRelativeParentImports::new(options(
+        None,
+        &[],
+        &[(
+          "file:///repo/src/ui",
+          "file:///repo/src/db",
+          Some("Use the API client in `src/api` instead"),
+        )],
+      )),
+      filename: "file:///repo/src/ui/components/list.tsx",
+      r#"import { query } from "../../db/query.ts";"#: [
+        {
+          col: 22,
+          message: NoRelativeParentImportsMessage::Restricted(
+            "../../db/query.ts".to_string(),
+          ),
+          hint: "Use the API client in `src/api` instead",
+        }
+      ],
+      r#"import type { Row } from "file:///repo/src/db/types.ts";"#: [
+        {
+          col: 25,
+          message: NoRelativeParentImportsMessage::Restricted(
+            "file:///repo/src/db/types.ts".to_string(),
+          ),
+          hint: "Use the API client in `src/api` instead",
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,259 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::Handler;
+use crate::syntax_features::SyntaxFeatures;
+use deno_ast::view::{
+  AwaitExpr, Decl, ForHead, Node, NodeKind, NodeTrait, Stmt,
+};
+use deno_ast::{SourceRange, SourceRanged};
+
+#[derive(Debug)]
+pub struct NoReturnAwait;
+
+const CODE: &str = "no-return-await";
+const MESSAGE: &str = "Redundant use of `await` on a return value";
+const HINT: &str =
+  "Remove the `await`, since the returned promise is awaited by the caller";
+const FIX_DESC: &str = "Remove `await`";
+
+impl LintRule for NoReturnAwait {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn required_syntax(&self) -> SyntaxFeatures {
+    SyntaxFeatures::ASYNC
+  }
+
+  fn aliases(&self) -> &'static [&'static str] {
+    &["@typescript-eslint/return-await"]
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoReturnAwaitHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::AwaitExpr])
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_return_await.md")
+  }
+}
+
+/// Whether the value of the parent expression is the value of the child, like
+/// the value of a conditional expression is the value of one of its branches.
+fn passes_on_value<'a>(child: Node<'a>, parent: Node<'a>) -> bool {
+  match parent {
+    Node::ParenExpr(_) => true,
+    Node::CondExpr(cond) => cond.test.range() != child.range(),
+    Node::SeqExpr(seq) => seq
+      .exprs
+      .last()
+      .is_some_and(|last| last.range() == child.range()),
+    _ => false,
+  }
+}
+
+/// Whether the await is the value returned by its function, either through
+/// `return await x` or `async () => await x`, including as a branch of a
+/// conditional or as the last expression of a sequence.
+fn is_returned(await_expr: &AwaitExpr) -> bool {
+  let mut node = await_expr.as_node();
+  let mut parent = node.parent();
+  while let Some(expr) = parent.filter(|parent| passes_on_value(node, *parent))
+  {
+    node = expr;
+    parent = expr.parent();
+  }
+  match parent {
+    Some(Node::ReturnStmt(_)) => true,
+    Some(Node::ArrowExpr(arrow)) => arrow.body.range() == node.range(),
+    _ => false,
+  }
+}
+
+fn declares_using(stmts: &[Stmt]) -> bool {
+  stmts
+    .iter()
+    .any(|stmt| matches!(stmt, Stmt::Decl(Decl::Using(_))))
+}
+
+/// Whether awaiting before returning changes when errors are caught or when
+/// resources are disposed, because the await is inside of a `try` block, a
+/// `catch` block followed by `finally`, a `finally` block, or a scope with
+/// `using` declarations.
+fn changes_semantics(await_expr: &AwaitExpr) -> bool {
+  let mut child: Node = await_expr.as_node();
+  for ancestor in await_expr.ancestors() {
+    match ancestor {
+      Node::Function(_) | Node::ArrowExpr(_) => return false,
+      Node::TryStmt(try_stmt) => {
+        if try_stmt.block.range() == child.range() {
+          return true;
+        }
+        let in_handler = try_stmt
+          .handler
+          .is_some_and(|handler| handler.range() == child.range());
+        if in_handler && try_stmt.finalizer.is_some() {
+          return true;
+        }
+        let in_finalizer = try_stmt
+          .finalizer
+          .is_some_and(|finalizer| finalizer.range() == child.range());
+        if in_finalizer {
+          return true;
+        }
+      }
+      Node::BlockStmt(block) if declares_using(block.stmts) => return true,
+      Node::SwitchCase(case) if declares_using(case.cons) => return true,
+      Node::ForOfStmt(for_of)
+        if matches!(for_of.left, ForHead::UsingDecl(_)) =>
+      {
+        return true
+      }
+      _ => {}
+    }
+    child = ancestor;
+  }
+  false
+}
+
+struct NoReturnAwaitHandler;
+
+impl Handler for NoReturnAwaitHandler {
+  fn await_expr(&mut self, await_expr: &AwaitExpr, ctx: &mut Context) {
+    if !is_returned(await_expr) || changes_semantics(await_expr) {
+      return;
+    }
+    let keyword = SourceRange::new(await_expr.start(), await_expr.arg.start());
+    ctx.add_diagnostic_with_fixes(
+      keyword,
+      CODE,
+      MESSAGE,
+      Some(HINT.to_string()),
+      vec![LintFix {
+        description: FIX_DESC.into(),
+        changes: vec![LintFixChange {
+          new_text: "".into(),
+          range: keyword,
+        }],
+      }],
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_return_await_valid() {
+    assert_lint_ok! {
+      NoReturnAwait,
+      "async function f() { return fetch(url); }",
+      "async function f() { const res = await fetch(url); return res; }",
+      "async function f() { return (await fetch(url)).json(); }",
+      "async function f() { return await a ?? b; }",
+      "async () => (await load()).value;",
+      "async function f() { try { return await load(); } catch { return null; } }",
+      "async function f() { try { return await load(); } finally { done(); } }",
+      "async function f() { try { run(); } catch { return await fallback(); } finally { done(); } }",
+      "async function f() { try { if (x) { return await load(); } } catch {} }",
+      "async function f() { using res = open(); return await res.read(); }",
+      "async function f() { await using res = open(); { return await res.read(); } }",
+      "async function f() { for (using res of open()) { return await res.read(); } }",
+      "async function f() { switch (x) { case 1: using res = open(); return await res.read(); } }",
+      "async function f() { try { run(); } finally { return await cleanup(); } }",
+      "async function f() { return (await a) ? b : c; }",
+      "async function f() { return (await a, b); }",
+    };
+  }
+
+  #[test]
+  fn no_return_await_invalid() {
+    assert_lint_err! {
+      NoReturnAwait,
+      "async function f() { return await fetch(url); }": [
+        {
+          col: 28,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "async function f() { return fetch(url); }"),
+        }
+      ],
+      "const f = async () => await load();": [
+        {
+          col: 22,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "const f = async () => load();"),
+        }
+      ],
+      "async function f() { return (await load()); }": [
+        {
+          col: 29,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "async function f() { return (load()); }"),
+        }
+      ],
+      "async function f() { try { run(); } catch { return await fallback(); } }": [
+        {
+          col: 51,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "async function f() { try { run(); } catch { return fallback(); } }"),
+        }
+      ],
+      "async function f() { return c ? await a : b; }": [
+        {
+          col: 32,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "async function f() { return c ? a : b; }"),
+        }
+      ],
+      "async function f() { return c ? b : (await a); }": [
+        {
+          col: 37,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "async function f() { return c ? b : (a); }"),
+        }
+      ],
+      "async function f() { return (x, await a); }": [
+        {
+          col: 32,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "async function f() { return (x, a); }"),
+        }
+      ],
+      "const f = async () => c ? await a : b;": [
+        {
+          col: 26,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "const f = async () => c ? a : b;"),
+        }
+      ],
+      "async function f() { try { return g(async () => { return await load(); }); } catch {} }": [
+        {
+          col: 57,
+          message: MESSAGE,
+          hint: HINT,
+          fix: (FIX_DESC, "async function f() { try { return g(async () => { return load(); }); } catch {} }"),
+        }
+      ],
+    };
+  }
+}
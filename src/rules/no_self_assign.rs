@@ -1,11 +1,9 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::handler::Handler;
 use crate::swc_util::StringRepr;
-use crate::Program;
 
-use deno_ast::view::AssignExpr;
 use deno_ast::view::AssignOp;
 use deno_ast::view::AssignTarget;
 use deno_ast::view::Expr;
@@ -17,6 +15,7 @@ use deno_ast::view::ObjectPatProp;
 use deno_ast::view::Pat;
 use deno_ast::view::Prop;
 use deno_ast::view::PropOrSpread;
+use deno_ast::view::{AssignExpr, NodeKind};
 use deno_ast::{SourceRange, SourceRanged};
 use derive_more::Display;
 
@@ -48,12 +47,12 @@ impl LintRule for NoSelfAssign {
     CODE
   }
 
-  fn lint_program_with_ast_view<'view>(
-    &self,
-    context: &mut Context<'view>,
-    program: Program<'view>,
-  ) {
-    NoSelfAssignVisitor.traverse(program, context);
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoSelfAssignVisitor))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::AssignExpr])
   }
 
   #[cfg(feature = "docs")]
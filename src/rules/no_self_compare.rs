@@ -1,5 +1,7 @@
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
+use crate::handler::Handler;
+use crate::swc_util::ast_eq;
+use deno_ast::view::NodeKind;
 use if_chain::if_chain;
 
 use deno_ast::{
@@ -25,12 +27,12 @@ impl LintRule for NoSelfCompare {
     CODE
   }
 
-  fn lint_program_with_ast_view<'view>(
-    &self,
-    context: &mut Context<'view>,
-    program: deno_ast::view::Program<'view>,
-  ) {
-    NoSelfCompareHandler.traverse(program, context);
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoSelfCompareHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::BinExpr])
   }
 
   #[cfg(feature = "docs")]
@@ -60,7 +62,10 @@ impl Handler for NoSelfCompareHandler {
           | BinaryOp::LtEq
       );
 
-      if binary_expression.left.text() == binary_expression.right.text();
+      if ast_eq(
+        &binary_expression.inner.left,
+        &binary_expression.inner.right,
+      );
 
       then {
         ctx.add_diagnostic_with_hint(
@@ -88,6 +93,9 @@ mod tests {
         "y=x*x",
         "foo.bar.baz === foo.bar.qux",
         "if ('x' === 'y') { }",
+        "foo['a-b'] === foo.a",
+        r#"if ("Ā" != "\u0100") { }"#,
+        r#"if ('a' === "a") { }"#,
     };
   }
 
@@ -191,7 +199,22 @@ mod tests {
               hint: HINT,
           }
         ],
-
+        "if (foo.bar === (foo).bar) { }": [
+          {
+            line: 1,
+            col: 4,
+            message: variant!(NoSelfCompareMessage, Invalid, "foo.bar"),
+            hint: HINT,
+          }
+        ],
+        "a['b'] < a.b": [
+          {
+            line: 1,
+            col: 0,
+            message: variant!(NoSelfCompareMessage, Invalid, "a['b']"),
+            hint: HINT,
+          }
+        ],
     };
   }
 }
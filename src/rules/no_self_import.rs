@@ -0,0 +1,138 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::Program;
+
+use deno_ast::ModuleSpecifier;
+use derive_more::Display;
+
+#[derive(Debug)]
+pub struct NoSelfImport;
+
+const CODE: &str = "no-self-import";
+
+#[derive(Display)]
+enum NoSelfImportMessage {
+  #[display(fmt = "Module imports itself")]
+  Unexpected,
+}
+
+#[derive(Display)]
+enum NoSelfImportHint {
+  #[display(
+    fmt = "Remove the import, the module's own bindings are already in scope"
+  )]
+  Remove,
+}
+
+impl LintRule for NoSelfImport {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    _program: Program,
+  ) {
+    let module = without_fragment(context.specifier().clone());
+    for (specifier, range) in context.module_graph().import_specifiers() {
+      let Some(resolved) = context.resolve(specifier) else {
+        continue;
+      };
+      if without_fragment(resolved) == module {
+        context.add_diagnostic_with_hint(
+          range,
+          CODE,
+          NoSelfImportMessage::Unexpected,
+          NoSelfImportHint::Remove,
+        );
+      }
+    }
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_self_import.md")
+  }
+}
+
+fn without_fragment(mut specifier: ModuleSpecifier) -> ModuleSpecifier {
+  specifier.set_fragment(None);
+  specifier
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_self_import_valid() {
+    assert_lint_ok! {
+      NoSelfImport,
+      filename: "file:///repo/src/mod.ts",
+      r#"import { a } from "./a.ts";"#,
+      r#"import { a } from "../mod.ts";"#,
+      r#"import { a } from "./mod.tsx";"#,
+      r#"import { a } from "./mod.ts?worker";"#,
+      r#"import { a } from "mod.ts";"#,
+      r#"import { a } from "https://example.com/src/mod.ts";"#,
+      r#"const mod = await import(`./mod.ts`);"#,
+    };
+  }
+
+  #[test]
+  fn no_self_import_invalid() {
+    assert_lint_err! {
+      NoSelfImport,
+      filename: "file:///repo/src/mod.ts",
+      r#"import { a } from "./mod.ts";"#: [
+        {
+          col: 18,
+          message: NoSelfImportMessage::Unexpected,
+          hint: NoSelfImportHint::Remove,
+        }
+      ],
+      r#"export * from "../src/mod.ts";"#: [
+        {
+          col: 14,
+          message: NoSelfImportMessage::Unexpected,
+          hint: NoSelfImportHint::Remove,
+        }
+      ],
+      r#"import type { A } from "/repo/src/mod.ts";"#: [
+        {
+          col: 23,
+          message: NoSelfImportMessage::Unexpected,
+          hint: NoSelfImportHint::Remove,
+        }
+      ],
+      r#"import "file:///repo/src/mod.ts#main";"#: [
+        {
+          col: 7,
+          message: NoSelfImportMessage::Unexpected,
+          hint: NoSelfImportHint::Remove,
+        }
+      ],
+      r#"const mod = await import("./mod.ts");"#: [
+        {
+          col: 25,
+          message: NoSelfImportMessage::Unexpected,
+          hint: NoSelfImportHint::Remove,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoSelfImport,
+      filename: "https://example.com/lib/mod.ts",
+      r#"import { a } from "https://example.com/lib/mod.ts";"#: [
+        {
+          col: 18,
+          message: NoSelfImportMessage::Unexpected,
+          hint: NoSelfImportHint::Remove,
+        }
+      ],
+    };
+  }
+}
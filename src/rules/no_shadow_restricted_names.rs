@@ -1,11 +1,10 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
-use crate::Program;
+use crate::handler::Handler;
 use deno_ast::view::{
   ArrowExpr, AssignExpr, AssignTarget, CatchClause, Expr, FnDecl, FnExpr,
-  Ident, ObjectPatProp, Pat, SimpleAssignTarget, VarDecl,
+  Ident, NodeKind, ObjectPatProp, Pat, SimpleAssignTarget, VarDecl,
 };
 use deno_ast::SourceRanged;
 use derive_more::Display;
@@ -30,12 +29,19 @@ impl LintRule for NoShadowRestrictedNames {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    NoShadowRestrictedNamesHandler.traverse(program, context);
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoShadowRestrictedNamesHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[
+      NodeKind::VarDecl,
+      NodeKind::FnDecl,
+      NodeKind::FnExpr,
+      NodeKind::ArrowExpr,
+      NodeKind::CatchClause,
+      NodeKind::AssignExpr,
+    ])
   }
 
   #[cfg(feature = "docs")]
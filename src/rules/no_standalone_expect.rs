@@ -0,0 +1,188 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::swc_util::enclosing_function;
+use crate::testing::{
+  callback_test, is_test_file, AssertionImports, TestCallKind,
+};
+use crate::Program;
+use deno_ast::view::{CallExpr, Callee, Expr, Module, NodeTrait};
+use deno_ast::SourceRanged;
+use derive_more::Display;
+
+#[derive(Debug)]
+pub struct NoStandaloneExpect;
+
+const CODE: &str = "no-standalone-expect";
+
+#[derive(Display)]
+enum NoStandaloneExpectMessage {
+  #[display(fmt = "Assertion outside of a test or test step")]
+  TopLevel,
+  #[display(fmt = "Assertion directly inside of `describe`")]
+  Describe,
+}
+
+#[derive(Display)]
+enum NoStandaloneExpectHint {
+  #[display(
+    fmt = "Move it into the body of `Deno.test`, `t.step` or `it`, since it doesn't run as part of a test here"
+  )]
+  MoveIntoTest,
+}
+
+impl LintRule for NoStandaloneExpect {
+  fn tags(&self) -> &'static [&'static str] {
+    &["test"]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn aliases(&self) -> &'static [&'static str] {
+    &["jest/no-standalone-expect", "no-assertions-outside-test"]
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    if is_test_file(context.specifier()) {
+      NoStandaloneExpectHandler::default().traverse(program, context);
+    }
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_standalone_expect.md")
+  }
+}
+
+#[derive(Default)]
+struct NoStandaloneExpectHandler {
+  imports: AssertionImports,
+}
+
+impl NoStandaloneExpectHandler {
+  fn is_assertion(&self, callee: &Expr, ctx: &Context) -> bool {
+    match callee {
+      // Test runners like Jest and Vitest can provide `expect` as a global.
+      Expr::Ident(ident)
+        if ident.sym() == "expect"
+          && ctx.scope().is_global(&ident.inner.to_id()) =>
+      {
+        true
+      }
+      _ => self.imports.is_assertion(callee),
+    }
+  }
+}
+
+impl Handler for NoStandaloneExpectHandler {
+  fn module(&mut self, module: &Module, _ctx: &mut Context) {
+    self.imports = AssertionImports::from_module(module);
+  }
+
+  fn call_expr(&mut self, call_expr: &CallExpr, ctx: &mut Context) {
+    let Callee::Expr(callee) = &call_expr.callee else {
+      return;
+    };
+    if !self.is_assertion(callee, ctx) {
+      return;
+    }
+    // Assertions in other functions are fine, since they're usually helpers
+    // or hooks that run as part of a test.
+    let message = match enclosing_function(call_expr.as_node()) {
+      None => NoStandaloneExpectMessage::TopLevel,
+      Some(function) => match callback_test(function, ctx) {
+        Some(test) if test.kind == TestCallKind::Describe => {
+          NoStandaloneExpectMessage::Describe
+        }
+        _ => return,
+      },
+    };
+    ctx.add_diagnostic_with_hint(
+      call_expr.range(),
+      CODE,
+      message,
+      NoStandaloneExpectHint::MoveIntoTest,
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_standalone_expect_valid() {
+    assert_lint_ok! {
+      NoStandaloneExpect,
+      r#"import { expect } from "jsr:@std/expect"; it("adds", () => { expect(1 + 1).toBe(2); });"#,
+      r#"import { expect } from "jsr:@std/expect"; describe("math", () => { it("adds", () => { expect(1 + 1).toBe(2); }); });"#,
+      r#"import { expect } from "jsr:@std/expect"; describe("math", () => { beforeEach(() => { expect(db).toBeDefined(); }); });"#,
+      r#"import { assertEquals } from "@std/assert"; Deno.test("math", async (t) => { await t.step("adds", () => { assertEquals(1 + 1, 2); }); });"#,
+      r#"import { assertEquals } from "@std/assert"; function check(value) { assertEquals(value, 2); }"#,
+      r#"function expect() {} expect();"#,
+    };
+    // Assertions in other files are runtime invariants.
+    assert_lint_ok! {
+      NoStandaloneExpect,
+      filename: "file:///math.ts",
+      r#"import { assert } from "@std/assert"; assert(Deno.args.length > 0);"#,
+      r#"import assert from "node:assert"; assert.equal(typeof Deno, "object");"#,
+    };
+  }
+
+  #[test]
+  fn no_standalone_expect_invalid() {
+    assert_lint_err! {
+      NoStandaloneExpect,
+      r#"import { expect } from "jsr:@std/expect"; describe("math", () => { expect(1 + 1).toBe(2); });"#: [
+        {
+          col: 67,
+          message: NoStandaloneExpectMessage::Describe,
+          hint: NoStandaloneExpectHint::MoveIntoTest,
+        }
+      ],
+      r#"expect(add(1, 2)).toBe(3);"#: [
+        {
+          col: 0,
+          message: NoStandaloneExpectMessage::TopLevel,
+          hint: NoStandaloneExpectHint::MoveIntoTest,
+        }
+      ],
+      r#"import { assert } from "chai"; describe("math", function () { assert.equal(1 + 1, 2); });"#: [
+        {
+          col: 62,
+          message: NoStandaloneExpectMessage::Describe,
+          hint: NoStandaloneExpectHint::MoveIntoTest,
+        }
+      ],
+      r#"import { assert as check } from "https://deno.land/std@0.224.0/assert/mod.ts"; if (Deno.args.length) { check(false); }"#: [
+        {
+          col: 103,
+          message: NoStandaloneExpectMessage::TopLevel,
+          hint: NoStandaloneExpectHint::MoveIntoTest,
+        }
+      ],
+      r#"import * as asserts from "@std/assert"; asserts.assertEquals(1 + 1, 2);"#: [
+        {
+          col: 40,
+          message: NoStandaloneExpectMessage::TopLevel,
+          hint: NoStandaloneExpectHint::MoveIntoTest,
+        }
+      ],
+      r#"import assert from "node:assert"; assert.equal(1 + 1, 2);"#: [
+        {
+          col: 34,
+          message: NoStandaloneExpectMessage::TopLevel,
+          hint: NoStandaloneExpectHint::MoveIntoTest,
+        }
+      ],
+    };
+  }
+}
@@ -1,9 +1,8 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
-use crate::Program;
-use deno_ast::view::{Expr, Pat, VarDecl};
+use crate::handler::Handler;
+use deno_ast::view::{Expr, NodeKind, Pat, VarDecl};
 use deno_ast::SourceRanged;
 use if_chain::if_chain;
 
@@ -22,12 +21,12 @@ impl LintRule for NoThisAlias {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    NoThisAliasHandler.traverse(program, context);
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoThisAliasHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::VarDecl])
   }
 
   #[cfg(feature = "docs")]
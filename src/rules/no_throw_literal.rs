@@ -1,9 +1,8 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
-use crate::Program;
-use deno_ast::view::{Expr, ThrowStmt};
+use crate::handler::Handler;
+use deno_ast::view::{Expr, NodeKind, ThrowStmt};
 use deno_ast::SourceRanged;
 use derive_more::Display;
 
@@ -26,12 +25,16 @@ impl LintRule for NoThrowLiteral {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    NoThrowLiteralHandler.traverse(program, context);
+  fn aliases(&self) -> &'static [&'static str] {
+    &["@typescript-eslint/only-throw-error"]
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoThrowLiteralHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::ThrowStmt])
   }
 
   #[cfg(feature = "docs")]
@@ -1,10 +1,10 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
-use crate::Program;
+use crate::handler::Handler;
+use crate::syntax_features::SyntaxFeatures;
 use deno_ast::view::NodeTrait;
-use deno_ast::view::{self as ast_view};
+use deno_ast::view::{self as ast_view, NodeKind};
 use deno_ast::SourceRanged;
 use if_chain::if_chain;
 
@@ -19,12 +19,16 @@ impl LintRule for NoTopLevelAwait {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program<'_>,
-  ) {
-    NoTopLevelAwaitHandler.traverse(program, context);
+  fn required_syntax(&self) -> SyntaxFeatures {
+    SyntaxFeatures::ASYNC
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoTopLevelAwaitHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::AwaitExpr, NodeKind::ForOfStmt])
   }
 
   #[cfg(feature = "docs")]
@@ -0,0 +1,375 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::handler::Handler;
+use crate::swc_util::is_side_effect_free;
+
+use deno_ast::swc::ast::{
+  ArrowExpr as SwcArrowExpr, AwaitExpr as SwcAwaitExpr, Class as SwcClass,
+  Function as SwcFunction,
+};
+use deno_ast::swc::visit::{noop_visit_type, Visit, VisitWith};
+use deno_ast::view::{
+  Callee, Decl, ExportDecl, Expr, Lit, Module, ModuleDecl, ModuleItem,
+  NodeKind, NodeTrait, Script, Stmt, VarDecl,
+};
+use deno_ast::{SourceRange, SourceRanged, SourceRangedForSpanned};
+use derive_more::Display;
+use serde::Deserialize;
+
+#[derive(Debug, Default)]
+pub struct NoTopLevelSideEffectInLibrary {
+  options: NoTopLevelSideEffectInLibraryOptions,
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct NoTopLevelSideEffectInLibraryOptions {
+  /// Functions that may be called at the top level, e.g.
+  /// `"customElements.define"`.
+  pub allow: Vec<String>,
+}
+
+impl NoTopLevelSideEffectInLibrary {
+  pub fn new(options: NoTopLevelSideEffectInLibraryOptions) -> Self {
+    Self { options }
+  }
+}
+
+const CODE: &str = "no-top-level-side-effect-in-library";
+
+#[derive(Display)]
+enum NoTopLevelSideEffectInLibraryMessage {
+  #[display(
+    fmt = "Top-level statements shouldn't have side effects when the module is imported"
+  )]
+  SideEffect,
+  #[display(
+    fmt = "Top-level `await` blocks importing the module until it settles"
+  )]
+  Await,
+}
+
+#[derive(Display)]
+enum NoTopLevelSideEffectInLibraryHint {
+  #[display(
+    fmt = "Move it into a function that users of the library call explicitly"
+  )]
+  MoveIntoFunction,
+  #[display(
+    fmt = "Move it into an async function that users of the library call explicitly"
+  )]
+  MoveIntoAsyncFunction,
+}
+
+impl LintRule for NoTopLevelSideEffectInLibrary {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "allow": { "type": "array", "items": { "type": "string" } }
+      }
+    }))
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoTopLevelSideEffectInLibraryHandler {
+      options: &self.options,
+    }))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::Script, NodeKind::Module])
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_top_level_side_effect_in_library.md")
+  }
+}
+
+/// Finds the first `await` outside of nested functions and classes.
+#[derive(Default)]
+struct AwaitFinder {
+  range: Option<SourceRange>,
+}
+
+impl Visit for AwaitFinder {
+  noop_visit_type!();
+
+  fn visit_await_expr(&mut self, await_expr: &SwcAwaitExpr) {
+    if self.range.is_none() {
+      self.range = Some(await_expr.range());
+    }
+  }
+
+  fn visit_function(&mut self, _function: &SwcFunction) {}
+
+  fn visit_arrow_expr(&mut self, _arrow_expr: &SwcArrowExpr) {}
+
+  fn visit_class(&mut self, _class: &SwcClass) {}
+}
+
+struct NoTopLevelSideEffectInLibraryHandler<'a> {
+  options: &'a NoTopLevelSideEffectInLibraryOptions,
+}
+
+impl<'a> NoTopLevelSideEffectInLibraryHandler<'a> {
+  fn is_allowed_call(&self, expr: &Expr) -> bool {
+    let callee = match expr {
+      Expr::Call(call) => match &call.callee {
+        Callee::Expr(callee) => callee,
+        _ => return false,
+      },
+      Expr::New(new_expr) => &new_expr.callee,
+      Expr::Paren(paren) => return self.is_allowed_call(&paren.expr),
+      _ => return false,
+    };
+    let args_pure = match expr {
+      Expr::Call(call) => call
+        .args
+        .iter()
+        .all(|arg| is_side_effect_free(&arg.inner.expr, true)),
+      Expr::New(new_expr) => new_expr.args.map_or(true, |args| {
+        args
+          .iter()
+          .all(|arg| is_side_effect_free(&arg.inner.expr, true))
+      }),
+      _ => false,
+    };
+    let callee_text = callee
+      .text()
+      .chars()
+      .filter(|c| !c.is_whitespace())
+      .collect::<String>();
+    args_pure
+      && self
+        .options
+        .allow
+        .iter()
+        .any(|allowed| *allowed == callee_text)
+  }
+
+  fn check_var_decl(&self, var_decl: &VarDecl, ctx: &mut Context) {
+    for declarator in var_decl.decls {
+      let Some(init) = &declarator.inner.init else {
+        continue;
+      };
+      let mut finder = AwaitFinder::default();
+      init.visit_with(&mut finder);
+      if let Some(range) = finder.range {
+        ctx.add_diagnostic_with_hint(
+          range,
+          CODE,
+          NoTopLevelSideEffectInLibraryMessage::Await,
+          NoTopLevelSideEffectInLibraryHint::MoveIntoAsyncFunction,
+        );
+      }
+    }
+  }
+
+  fn check_stmt(&self, stmt: &Stmt, ctx: &mut Context) {
+    match stmt {
+      Stmt::Decl(Decl::Var(var_decl)) => self.check_var_decl(var_decl, ctx),
+      Stmt::Decl(_) | Stmt::Empty(_) => {}
+      Stmt::Expr(expr_stmt) => {
+        let expr = &expr_stmt.expr;
+        // Directives like "use strict". Property reads are assumed not to run
+        // getters.
+        if matches!(expr, Expr::Lit(Lit::Str(_)))
+          || is_side_effect_free(&expr_stmt.inner.expr, true)
+        {
+          return;
+        }
+        if self.is_allowed_call(expr) {
+          return;
+        }
+        let (message, hint) = match expr {
+          Expr::Await(_) => (
+            NoTopLevelSideEffectInLibraryMessage::Await,
+            NoTopLevelSideEffectInLibraryHint::MoveIntoAsyncFunction,
+          ),
+          _ => (
+            NoTopLevelSideEffectInLibraryMessage::SideEffect,
+            NoTopLevelSideEffectInLibraryHint::MoveIntoFunction,
+          ),
+        };
+        ctx.add_diagnostic_with_hint(expr_stmt.range(), CODE, message, hint);
+      }
+      // Control flow at the top level runs code on import.
+      _ => {
+        ctx.add_diagnostic_with_hint(
+          stmt.range(),
+          CODE,
+          NoTopLevelSideEffectInLibraryMessage::SideEffect,
+          NoTopLevelSideEffectInLibraryHint::MoveIntoFunction,
+        );
+      }
+    }
+  }
+}
+
+impl<'a> Handler for NoTopLevelSideEffectInLibraryHandler<'a> {
+  // Files without imports or exports are parsed as scripts, but are still
+  // loaded as modules by Deno.
+  fn script(&mut self, script: &Script, ctx: &mut Context) {
+    for stmt in script.body {
+      self.check_stmt(stmt, ctx);
+    }
+  }
+
+  fn module(&mut self, module: &Module, ctx: &mut Context) {
+    for item in module.body {
+      match item {
+        ModuleItem::Stmt(stmt) => self.check_stmt(stmt, ctx),
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+          decl: Decl::Var(var_decl),
+          ..
+        })) => self.check_var_decl(var_decl, ctx),
+        ModuleItem::ModuleDecl(_) => {}
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_top_level_side_effect_in_library_valid() {
+    assert_lint_ok! {
+      NoTopLevelSideEffectInLibrary::default(),
+      "export function foo() { console.log('foo'); }",
+      "const x = 1; export { x };",
+      "export const x = compute();",
+      "const registry = new Map();",
+      r#""use strict";"#,
+      "class Foo { static { init(); } }",
+      "export default function () { fetch('https://example.com'); }",
+      "export const load = async () => await fetch('https://example.com');",
+      "import './polyfill.ts';",
+      "export * from './mod.ts';",
+      ";",
+      "foo;",
+      "foo.bar[baz];",
+      "(class { static x = 1; });",
+    };
+
+    assert_lint_ok! {
+      NoTopLevelSideEffectInLibrary::new(NoTopLevelSideEffectInLibraryOptions {
+        allow: vec!["customElements.define".to_string()],
+      }),
+      "customElements.define('my-element', MyElement);",
+    };
+  }
+
+  #[test]
+  fn no_top_level_side_effect_in_library_invalid() {
+    assert_lint_err! {
+      NoTopLevelSideEffectInLibrary::default(),
+      "console.log('loaded');": [
+        {
+          col: 0,
+          message: NoTopLevelSideEffectInLibraryMessage::SideEffect,
+          hint: NoTopLevelSideEffectInLibraryHint::MoveIntoFunction,
+        }
+      ],
+      "globalThis.foo = 1;": [
+        {
+          col: 0,
+          message: NoTopLevelSideEffectInLibraryMessage::SideEffect,
+          hint: NoTopLevelSideEffectInLibraryHint::MoveIntoFunction,
+        }
+      ],
+      "cache[key()];": [
+        {
+          col: 0,
+          message: NoTopLevelSideEffectInLibraryMessage::SideEffect,
+          hint: NoTopLevelSideEffectInLibraryHint::MoveIntoFunction,
+        }
+      ],
+      "(class { static { init(); } });": [
+        {
+          col: 0,
+          message: NoTopLevelSideEffectInLibraryMessage::SideEffect,
+          hint: NoTopLevelSideEffectInLibraryHint::MoveIntoFunction,
+        }
+      ],
+      "let count = 0; count++;": [
+        {
+          col: 15,
+          message: NoTopLevelSideEffectInLibraryMessage::SideEffect,
+          hint: NoTopLevelSideEffectInLibraryHint::MoveIntoFunction,
+        }
+      ],
+      "if (Deno.env.get('DEBUG')) { enableDebug(); }": [
+        {
+          col: 0,
+          message: NoTopLevelSideEffectInLibraryMessage::SideEffect,
+          hint: NoTopLevelSideEffectInLibraryHint::MoveIntoFunction,
+        }
+      ],
+      "for (const x of xs) {}": [
+        {
+          col: 0,
+          message: NoTopLevelSideEffectInLibraryMessage::SideEffect,
+          hint: NoTopLevelSideEffectInLibraryHint::MoveIntoFunction,
+        }
+      ],
+      "await fetch('https://example.com');": [
+        {
+          col: 0,
+          message: NoTopLevelSideEffectInLibraryMessage::Await,
+          hint: NoTopLevelSideEffectInLibraryHint::MoveIntoAsyncFunction,
+        }
+      ],
+      "export const data = await (await fetch('https://example.com')).json();": [
+        {
+          col: 20,
+          message: NoTopLevelSideEffectInLibraryMessage::Await,
+          hint: NoTopLevelSideEffectInLibraryHint::MoveIntoAsyncFunction,
+        }
+      ],
+      "const config = { value: await load() };": [
+        {
+          col: 24,
+          message: NoTopLevelSideEffectInLibraryMessage::Await,
+          hint: NoTopLevelSideEffectInLibraryHint::MoveIntoAsyncFunction,
+        }
+      ],
+      "customElements.define('my-element', MyElement);": [
+        {
+          col: 0,
+          message: NoTopLevelSideEffectInLibraryMessage::SideEffect,
+          hint: NoTopLevelSideEffectInLibraryHint::MoveIntoFunction,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoTopLevelSideEffectInLibrary::new(NoTopLevelSideEffectInLibraryOptions {
+        allow: vec!["customElements.define".to_string()],
+      }),
+      "customElements.define('my-element', create());": [
+        {
+          col: 0,
+          message: NoTopLevelSideEffectInLibraryMessage::SideEffect,
+          hint: NoTopLevelSideEffectInLibraryHint::MoveIntoFunction,
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn no_top_level_side_effect_in_library_options() {
+    let options: NoTopLevelSideEffectInLibraryOptions =
+      serde_json::from_str(r#"{ "allow": ["customElements.define"] }"#)
+        .unwrap();
+    assert_eq!(options.allow, vec!["customElements.define".to_string()]);
+  }
+}
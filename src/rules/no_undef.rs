@@ -2,7 +2,7 @@
 
 use super::program_ref;
 use super::{Context, LintRule};
-use crate::globals::GLOBALS;
+use crate::globals::{Environment, Globals};
 use crate::Program;
 use crate::ProgramRef;
 use deno_ast::swc::{
@@ -10,22 +10,74 @@ use deno_ast::swc::{
   visit::{noop_visit_type, Visit, VisitWith},
 };
 use deno_ast::SourceRangedForSpanned;
+use serde::Deserialize;
 
-#[derive(Debug)]
-pub struct NoUndef;
+#[derive(Debug, Default)]
+pub struct NoUndef {
+  globals: Globals,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct NoUndefOptions {
+  /// The environments whose globals are defined, which is only Deno by
+  /// default.
+  pub env: Vec<Environment>,
+  /// Names of other globals that are defined, e.g. ones that a script tag
+  /// adds.
+  pub globals: Vec<String>,
+}
+
+impl Default for NoUndefOptions {
+  fn default() -> Self {
+    Self {
+      env: vec![Environment::Deno],
+      globals: vec![],
+    }
+  }
+}
+
+impl NoUndef {
+  pub fn new(options: NoUndefOptions) -> Self {
+    let mut globals = Globals::new(options.env);
+    for name in options.globals {
+      globals.add(name, false);
+    }
+    Self::with_globals(globals)
+  }
+
+  /// Uses the globals as they are, for embedders with globals that no
+  /// environment has.
+  pub fn with_globals(globals: Globals) -> Self {
+    Self { globals }
+  }
+}
 
 impl LintRule for NoUndef {
   fn code(&self) -> &'static str {
     "no-undef"
   }
 
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "env": {
+          "type": "array",
+          "items": { "enum": ["deno", "browser", "worker", "node"] }
+        },
+        "globals": { "type": "array", "items": { "type": "string" } }
+      }
+    }))
+  }
+
   fn lint_program_with_ast_view<'view>(
     &self,
     context: &mut Context<'view>,
     program: Program<'view>,
   ) {
     let program = program_ref(program);
-    let mut visitor = NoUndefVisitor::new(context);
+    let mut visitor = NoUndefVisitor::new(context, &self.globals);
     match program {
       ProgramRef::Module(m) => m.visit_with(&mut visitor),
       ProgramRef::Script(s) => s.visit_with(&mut visitor),
@@ -40,11 +92,12 @@ impl LintRule for NoUndef {
 
 struct NoUndefVisitor<'c, 'view> {
   context: &'c mut Context<'view>,
+  globals: &'c Globals,
 }
 
 impl<'c, 'view> NoUndefVisitor<'c, 'view> {
-  fn new(context: &'c mut Context<'view>) -> Self {
-    Self { context }
+  fn new(context: &'c mut Context<'view>, globals: &'c Globals) -> Self {
+    Self { context, globals }
   }
 
   fn check(&mut self, ident: &Ident) {
@@ -67,7 +120,7 @@ impl<'c, 'view> NoUndefVisitor<'c, 'view> {
     }
 
     // Globals
-    if GLOBALS.iter().any(|(name, _)| name == &&*ident.sym) {
+    if self.globals.is_global(&ident.sym) {
       return;
     }
 
@@ -106,6 +159,8 @@ impl<'c, 'view> Visit for NoUndefVisitor<'c, 'view> {
   }
 
   fn visit_class_prop(&mut self, p: &ClassProp) {
+    p.decorators.visit_with(self);
+    p.key.visit_with(self);
     p.value.visit_with(self)
   }
 
@@ -154,7 +209,7 @@ mod tests {
   #[test]
   fn no_undef_valid() {
     assert_lint_ok! {
-      NoUndef,
+      NoUndef::default(),
       "var a = 1, b = 2; a;",
       "function a(){}  a();",
       "function f(b) { b; }",
@@ -310,7 +365,7 @@ mod tests {
   #[test]
   fn no_undef_invalid() {
     assert_lint_err! {
-      NoUndef,
+      NoUndef::default(),
       "a = 1;": [
         {
           col: 0,
@@ -395,4 +450,84 @@ mod tests {
       ],
     };
   }
+
+  #[test]
+  fn no_undef_env() {
+    assert_lint_ok! {
+      NoUndef::new(NoUndefOptions {
+        env: vec![Environment::Browser],
+        globals: vec!["jQuery".to_string()],
+      }),
+      "document.body; fetch(location.href);",
+      "jQuery(document);",
+    };
+
+    assert_lint_err! {
+      NoUndef::new(NoUndefOptions {
+        env: vec![Environment::Worker],
+        globals: vec![],
+      }),
+      "importScripts(); window.foo;": [
+        {
+          col: 17,
+          message: "window is not defined",
+        },
+      ],
+    };
+
+    assert_lint_err! {
+      NoUndef::default(),
+      "document.body;": [
+        {
+          col: 0,
+          message: "document is not defined",
+        },
+      ],
+    };
+
+    assert_lint_err! {
+      NoUndef::default(),
+      "class A { @dec foo = 1; }": [
+        {
+          col: 11,
+          message: "dec is not defined",
+        },
+      ],
+      "class A { [key] = 1; }": [
+        {
+          col: 11,
+          message: "key is not defined",
+        },
+      ],
+      "class A { constructor(@Inject(TOKEN) a) {} }": [
+        {
+          col: 23,
+          message: "Inject is not defined",
+        },
+        {
+          col: 30,
+          message: "TOKEN is not defined",
+        },
+      ],
+    };
+  }
+
+  #[test]
+  fn no_undef_custom_globals() {
+    assert_lint_ok! {
+      NoUndef::with_globals(
+        Globals::new([Environment::Node]).with_global("Bun", false),
+      ),
+      "Bun.serve(); process.exit(0);",
+    };
+  }
+
+  #[test]
+  fn no_undef_options() {
+    let options: NoUndefOptions =
+      serde_json::from_str(r#"{ "env": ["deno", "browser"] }"#).unwrap();
+    assert_eq!(options.env, vec![Environment::Deno, Environment::Browser]);
+    assert!(options.globals.is_empty());
+    assert_eq!(NoUndefOptions::default().env, vec![Environment::Deno]);
+  }
 }
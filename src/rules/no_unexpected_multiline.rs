@@ -0,0 +1,239 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::handler::Handler;
+
+use deno_ast::swc::ast::{BinaryOp, Callee, Expr, MemberProp};
+use deno_ast::view::{BinExpr, CallExpr, MemberExpr, NodeKind, TaggedTpl};
+use deno_ast::{SourcePos, SourceRangedForSpanned};
+use derive_more::Display;
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+#[derive(Debug)]
+pub struct NoUnexpectedMultiline;
+
+const CODE: &str = "no-unexpected-multiline";
+
+#[derive(Display)]
+enum NoUnexpectedMultilineMessage {
+  #[display(fmt = "Unexpected line break between a function and its call")]
+  Call,
+  #[display(
+    fmt = "Unexpected line break between an object and its property access"
+  )]
+  Member,
+  #[display(fmt = "Unexpected line break between a tag and its template")]
+  TaggedTemplate,
+  #[display(
+    fmt = "Unexpected line break between a value and the division operator"
+  )]
+  Division,
+}
+
+#[derive(Display)]
+enum NoUnexpectedMultilineHint {
+  #[display(
+    fmt = "Add a semicolon at the end of the previous line if it's meant to be a separate statement, or move the `{}` to the end of the previous line",
+    _0
+  )]
+  SemicolonOrJoin(&'static str),
+}
+
+impl LintRule for NoUnexpectedMultiline {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoUnexpectedMultilineHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[
+      NodeKind::CallExpr,
+      NodeKind::MemberExpr,
+      NodeKind::TaggedTpl,
+      NodeKind::BinExpr,
+    ])
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_unexpected_multiline.md")
+  }
+}
+
+struct NoUnexpectedMultilineHandler;
+
+impl Handler for NoUnexpectedMultilineHandler {
+  fn call_expr(&mut self, call_expr: &CallExpr, ctx: &mut Context) {
+    let Callee::Expr(callee) = &call_expr.inner.callee else {
+      return;
+    };
+    check_break_before(
+      callee.end(),
+      "(",
+      NoUnexpectedMultilineMessage::Call,
+      ctx,
+    );
+  }
+
+  fn member_expr(&mut self, member_expr: &MemberExpr, ctx: &mut Context) {
+    if !matches!(member_expr.inner.prop, MemberProp::Computed(_)) {
+      return;
+    }
+    check_break_before(
+      member_expr.inner.obj.end(),
+      "[",
+      NoUnexpectedMultilineMessage::Member,
+      ctx,
+    );
+  }
+
+  fn tagged_tpl(&mut self, tagged_tpl: &TaggedTpl, ctx: &mut Context) {
+    check_break_before(
+      tagged_tpl.inner.tag.end(),
+      "`",
+      NoUnexpectedMultilineMessage::TaggedTemplate,
+      ctx,
+    );
+  }
+
+  fn bin_expr(&mut self, bin_expr: &BinExpr, ctx: &mut Context) {
+    static REGEX_FLAGS: Lazy<Regex> =
+      Lazy::new(|| Regex::new(r"^[dgimsuvy]+$").unwrap());
+
+    // `a\n/b/g.test(c)` is parsed as `a / b / g.test(c)`, so look for a
+    // division of a division that's followed by what could be regex flags.
+    if bin_expr.inner.op != BinaryOp::Div {
+      return;
+    }
+    let Expr::Bin(left) = &*bin_expr.inner.left else {
+      return;
+    };
+    if left.op != BinaryOp::Div {
+      return;
+    }
+    let Some(second_slash) = ctx.token_after(left.end()) else {
+      return;
+    };
+    let Some(flags) = ctx.token_after(second_slash.end()) else {
+      return;
+    };
+    if flags.start() == second_slash.end()
+      && REGEX_FLAGS.is_match(ctx.text_of(flags.range()))
+    {
+      check_break_before(
+        left.left.end(),
+        "/",
+        NoUnexpectedMultilineMessage::Division,
+        ctx,
+      );
+    }
+  }
+}
+
+/// Reports the token after the position if it's the expected one and on a
+/// later line, which the author likely meant to start a new statement.
+fn check_break_before(
+  pos: SourcePos,
+  expected: &'static str,
+  message: NoUnexpectedMultilineMessage,
+  ctx: &mut Context,
+) {
+  let Some(token) = ctx.token_after(pos) else {
+    return;
+  };
+  if ctx.text_of(token.range()) != expected
+    || ctx.line_index(pos) == ctx.line_index(token.start())
+  {
+    return;
+  }
+  ctx.add_diagnostic_with_hint(
+    token.range(),
+    CODE,
+    message,
+    NoUnexpectedMultilineHint::SemicolonOrJoin(expected),
+  );
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_unexpected_multiline_valid() {
+    assert_lint_ok! {
+      NoUnexpectedMultiline,
+      "const a = b;\n(c || d).e();",
+      "const a = b\n;(c || d).e();",
+      "foo(\n  a,\n);",
+      "foo(a)\n  .bar();",
+      "const a = b;\n[1, 2].forEach(f);",
+      "const a = b[\n  c\n];",
+      "const a = b\n  ?.[c];",
+      "const a = tag`hello`;",
+      "const a = b;\n`hello`;",
+      "const a = b\n  / 2 / g;",
+      "const a = b / c\n  / g;",
+      "const a = b\n/ c / 2;",
+      "const a = () => {}\nfunction foo() {}",
+    };
+  }
+
+  #[test]
+  fn no_unexpected_multiline_invalid() {
+    assert_lint_err! {
+      NoUnexpectedMultiline,
+      "const a = b\n(c || d).e();": [
+        {
+          line: 2,
+          col: 0,
+          message: NoUnexpectedMultilineMessage::Call,
+          hint: NoUnexpectedMultilineHint::SemicolonOrJoin("("),
+        }
+      ],
+      "const a = b\n[1, 2].forEach(f);": [
+        {
+          line: 2,
+          col: 0,
+          message: NoUnexpectedMultilineMessage::Member,
+          hint: NoUnexpectedMultilineHint::SemicolonOrJoin("["),
+        }
+      ],
+      "const a = b\n`hello`;": [
+        {
+          line: 2,
+          col: 0,
+          message: NoUnexpectedMultilineMessage::TaggedTemplate,
+          hint: NoUnexpectedMultilineHint::SemicolonOrJoin("`"),
+        }
+      ],
+      "const a = b\n/foo/g.test(c);": [
+        {
+          line: 2,
+          col: 0,
+          message: NoUnexpectedMultilineMessage::Division,
+          hint: NoUnexpectedMultilineHint::SemicolonOrJoin("/"),
+        }
+      ],
+      "const a = (b)\n(c);": [
+        {
+          line: 2,
+          col: 0,
+          message: NoUnexpectedMultilineMessage::Call,
+          hint: NoUnexpectedMultilineHint::SemicolonOrJoin("("),
+        }
+      ],
+      "const a = foo()\n  (bar);": [
+        {
+          line: 2,
+          col: 2,
+          message: NoUnexpectedMultilineMessage::Call,
+          hint: NoUnexpectedMultilineHint::SemicolonOrJoin("("),
+        }
+      ],
+    };
+  }
+}
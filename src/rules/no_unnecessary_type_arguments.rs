@@ -0,0 +1,279 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::{Handler, Traverse};
+use crate::Program;
+
+use deno_ast::view::{
+  CallExpr, Callee, Class, ClassDecl, Expr, FnDecl, NewExpr, NodeTrait,
+  TsEntityName, TsExprWithTypeArgs, TsInterfaceDecl, TsTypeAliasDecl,
+  TsTypeParamDecl, TsTypeParamInstantiation, TsTypeRef,
+};
+use deno_ast::{SourceRange, SourceRanged};
+use derive_more::Display;
+use std::collections::HashMap;
+
+#[derive(Debug)]
+pub struct NoUnnecessaryTypeArguments;
+
+const CODE: &str = "no-unnecessary-type-arguments";
+
+#[derive(Display)]
+enum NoUnnecessaryTypeArgumentsMessage {
+  #[display(
+    fmt = "This is the default value of this type parameter, so it can be omitted"
+  )]
+  Unnecessary,
+}
+
+#[derive(Display)]
+enum NoUnnecessaryTypeArgumentsHint {
+  #[display(fmt = "Remove the type argument")]
+  Remove,
+}
+
+const FIX_DESC: &str = "Remove the type argument";
+
+impl LintRule for NoUnnecessaryTypeArguments {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    let mut collector = DefaultsCollector::default();
+    collector.traverse(program, context);
+    NoUnnecessaryTypeArgumentsHandler {
+      defaults: collector.defaults,
+    }
+    .traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_unnecessary_type_arguments.md")
+  }
+}
+
+/// The text of a type, ignoring whitespace, so that `Map<K,V>` and
+/// `Map<K, V>` compare equal.
+fn normalize(text: &str) -> String {
+  text.chars().filter(|c| !c.is_whitespace()).collect()
+}
+
+/// The defaults of the type parameters of each generic declared in the file.
+/// Without type information only defaults that are written out can be
+/// compared, and names declared more than once with different type
+/// parameters are ignored.
+#[derive(Default)]
+struct DefaultsCollector {
+  defaults: HashMap<String, Option<Vec<Option<String>>>>,
+}
+
+impl DefaultsCollector {
+  fn add(&mut self, name: &str, type_params: Option<&TsTypeParamDecl>) {
+    let Some(type_params) = type_params else {
+      return;
+    };
+    let defaults = type_params
+      .params
+      .iter()
+      .map(|param| param.default.map(|default| normalize(default.text())))
+      .collect::<Vec<_>>();
+    self
+      .defaults
+      .entry(name.to_string())
+      .and_modify(|existing| {
+        if existing.as_ref() != Some(&defaults) {
+          *existing = None;
+        }
+      })
+      .or_insert_with(|| Some(defaults.clone()));
+  }
+}
+
+impl Handler for DefaultsCollector {
+  fn fn_decl(&mut self, fn_decl: &FnDecl, _ctx: &mut Context) {
+    self.add(fn_decl.ident.sym(), fn_decl.function.type_params);
+  }
+
+  fn class_decl(&mut self, class_decl: &ClassDecl, _ctx: &mut Context) {
+    self.add(class_decl.ident.sym(), class_decl.class.type_params);
+  }
+
+  fn ts_type_alias_decl(
+    &mut self,
+    alias: &TsTypeAliasDecl,
+    _ctx: &mut Context,
+  ) {
+    self.add(alias.id.sym(), alias.type_params);
+  }
+
+  fn ts_interface_decl(
+    &mut self,
+    interface: &TsInterfaceDecl,
+    _ctx: &mut Context,
+  ) {
+    self.add(interface.id.sym(), interface.type_params);
+  }
+}
+
+struct NoUnnecessaryTypeArgumentsHandler {
+  defaults: HashMap<String, Option<Vec<Option<String>>>>,
+}
+
+impl NoUnnecessaryTypeArgumentsHandler {
+  fn check(
+    &self,
+    name: &str,
+    type_args: Option<&TsTypeParamInstantiation>,
+    ctx: &mut Context,
+  ) {
+    let Some(type_args) = type_args else {
+      return;
+    };
+    let Some(Some(defaults)) = self.defaults.get(name) else {
+      return;
+    };
+    // Only the last type argument can be omitted.
+    let Some(index) = type_args.params.len().checked_sub(1) else {
+      return;
+    };
+    let arg = type_args.params[index];
+    let Some(Some(default)) = defaults.get(index) else {
+      return;
+    };
+    if normalize(arg.text()) != *default {
+      return;
+    }
+
+    let range = if index == 0 {
+      type_args.range()
+    } else {
+      SourceRange::new(type_args.params[index - 1].end(), arg.end())
+    };
+    ctx.add_diagnostic_with_fixes(
+      arg.range(),
+      CODE,
+      NoUnnecessaryTypeArgumentsMessage::Unnecessary,
+      Some(NoUnnecessaryTypeArgumentsHint::Remove.to_string()),
+      vec![LintFix {
+        description: FIX_DESC.into(),
+        changes: vec![LintFixChange {
+          new_text: "".into(),
+          range,
+        }],
+      }],
+    );
+  }
+}
+
+impl Handler for NoUnnecessaryTypeArgumentsHandler {
+  fn call_expr(&mut self, call_expr: &CallExpr, ctx: &mut Context) {
+    if let Callee::Expr(Expr::Ident(ident)) = &call_expr.callee {
+      self.check(ident.sym(), call_expr.type_args, ctx);
+    }
+  }
+
+  fn new_expr(&mut self, new_expr: &NewExpr, ctx: &mut Context) {
+    if let Expr::Ident(ident) = &new_expr.callee {
+      self.check(ident.sym(), new_expr.type_args, ctx);
+    }
+  }
+
+  fn ts_type_ref(&mut self, type_ref: &TsTypeRef, ctx: &mut Context) {
+    if let TsEntityName::Ident(ident) = &type_ref.type_name {
+      self.check(ident.sym(), type_ref.type_params, ctx);
+    }
+  }
+
+  fn ts_expr_with_type_args(
+    &mut self,
+    expr: &TsExprWithTypeArgs,
+    ctx: &mut Context,
+  ) {
+    if let Expr::Ident(ident) = &expr.expr {
+      self.check(ident.sym(), expr.type_args, ctx);
+    }
+  }
+
+  fn class(&mut self, class: &Class, ctx: &mut Context) {
+    if let Some(Expr::Ident(ident)) = &class.super_class {
+      self.check(ident.sym(), class.super_type_params, ctx);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_unnecessary_type_arguments_valid() {
+    assert_lint_ok! {
+      NoUnnecessaryTypeArguments,
+      "function f<T = number>() {} f();",
+      "function f<T = number>() {} f<string>();",
+      "function f<T>() {} f<number>();",
+      "function f<T = number, U = string>() {} f<string, number>();",
+      "function f<T = number, U = string>() {} f<number, number>();",
+      "class C<T = number> {} new C<string>();",
+      "interface I<T = number> {} type A = I<string>;",
+      "type A = Map<string, number>;",
+      "type T<U = number> = U; type T<U = string> = U; type A = T<number>;",
+    };
+  }
+
+  #[test]
+  fn no_unnecessary_type_arguments_invalid() {
+    assert_lint_err! {
+      NoUnnecessaryTypeArguments,
+      NoUnnecessaryTypeArgumentsMessage::Unnecessary,
+      NoUnnecessaryTypeArgumentsHint::Remove,
+      "function f<T = number>() {} f<number>();": [
+        {
+          col: 30,
+          fix: (FIX_DESC, "function f<T = number>() {} f();"),
+        }
+      ],
+      "function f<T, U = string>() {} f<number, string>();": [
+        {
+          col: 41,
+          fix: (FIX_DESC, "function f<T, U = string>() {} f<number>();"),
+        }
+      ],
+      "class C<T = Map<K,V>> {} new C<Map<K, V>>();": [
+        {
+          col: 31,
+          fix: (FIX_DESC, "class C<T = Map<K,V>> {} new C();"),
+        }
+      ],
+      "interface I<T = number> {} type A = I<number>;": [
+        {
+          col: 38,
+          fix: (FIX_DESC, "interface I<T = number> {} type A = I;"),
+        }
+      ],
+      "class A<T = number> {} class B extends A<number> {}": [
+        {
+          col: 41,
+          fix: (FIX_DESC, "class A<T = number> {} class B extends A {}"),
+        }
+      ],
+      "interface I<T = number> {} class C implements I<number> {}": [
+        {
+          col: 48,
+          fix: (FIX_DESC, "interface I<T = number> {} class C implements I {}"),
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,481 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::Handler;
+
+use deno_ast::view::{
+  BinaryOp, Expr, Lit, Node, NodeKind, NodeTrait, TsAsExpr, TsConstAssertion,
+  TsLit, TsNonNullExpr, TsType, TsTypeAssertion, UnaryOp, VarDeclKind,
+};
+use deno_ast::{SourceRange, SourceRanged};
+use derive_more::Display;
+
+#[derive(Debug)]
+pub struct NoUnnecessaryTypeAssertion;
+
+const CODE: &str = "no-unnecessary-type-assertion";
+
+#[derive(Display)]
+enum NoUnnecessaryTypeAssertionMessage {
+  #[display(
+    fmt = "This assertion is unnecessary since it does not change the type of the expression"
+  )]
+  TypeAssertion,
+  #[display(
+    fmt = "This non-null assertion is unnecessary since the expression can never be null or undefined"
+  )]
+  NonNullAssertion,
+}
+
+#[derive(Display)]
+enum NoUnnecessaryTypeAssertionHint {
+  #[display(fmt = "Remove the type assertion")]
+  RemoveTypeAssertion,
+  #[display(fmt = "Remove the non-null assertion operator (`!`)")]
+  RemoveNonNullAssertion,
+}
+
+const FIX_DESC: &str = "Remove the unnecessary assertion";
+
+impl LintRule for NoUnnecessaryTypeAssertion {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoUnnecessaryTypeAssertionHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[
+      NodeKind::TsAsExpr,
+      NodeKind::TsTypeAssertion,
+      NodeKind::TsConstAssertion,
+      NodeKind::TsNonNullExpr,
+    ])
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_unnecessary_type_assertion.md")
+  }
+}
+
+struct NoUnnecessaryTypeAssertionHandler;
+
+fn add_diagnostic(
+  ctx: &mut Context,
+  range: SourceRange,
+  expr: &Expr,
+  message: NoUnnecessaryTypeAssertionMessage,
+  hint: NoUnnecessaryTypeAssertionHint,
+) {
+  ctx.add_diagnostic_with_fixes(
+    range,
+    CODE,
+    message,
+    Some(hint.to_string()),
+    vec![LintFix {
+      description: FIX_DESC.into(),
+      changes: vec![LintFixChange {
+        new_text: expr.text().to_string().into(),
+        range,
+      }],
+    }],
+  );
+}
+
+/// Whether the assertion is the initializer of a `const` declaration, where
+/// literal types are never widened.
+fn is_const_initializer(node: Node) -> bool {
+  node
+    .parent()
+    .and_then(|parent| parent.to::<deno_ast::view::VarDeclarator>())
+    .and_then(|declarator| declarator.parent().to::<deno_ast::view::VarDecl>())
+    .map_or(false, |decl| decl.decl_kind() == VarDeclKind::Const)
+}
+
+fn is_same_literal(expr: &Expr, ty: &TsType) -> bool {
+  let (Expr::Lit(lit), TsType::TsLitType(lit_type)) = (expr, ty) else {
+    return false;
+  };
+  match (lit, &lit_type.lit) {
+    (Lit::Str(value), TsLit::Str(ty)) => value.value() == ty.value(),
+    (Lit::Num(value), TsLit::Number(ty)) => value.value() == ty.value(),
+    (Lit::Bool(value), TsLit::Bool(ty)) => value.value() == ty.value(),
+    (Lit::BigInt(value), TsLit::BigInt(ty)) => value.value() == ty.value(),
+    _ => false,
+  }
+}
+
+fn is_primitive_literal(expr: &Expr) -> bool {
+  matches!(
+    expr,
+    Expr::Lit(Lit::Str(_) | Lit::Num(_) | Lit::Bool(_) | Lit::BigInt(_))
+  )
+}
+
+/// Whether the expression already is an assertion to the same type, e.g. the
+/// inner assertion of `(foo as Foo) as Foo`.
+fn is_same_assertion(expr: &Expr, ty: &TsType) -> bool {
+  let inner_ty = match expr {
+    Expr::Paren(paren) => return is_same_assertion(&paren.expr, ty),
+    Expr::TsAs(as_expr) => &as_expr.type_ann,
+    Expr::TsTypeAssertion(assertion) => &assertion.type_ann,
+    _ => return false,
+  };
+  inner_ty.text() == ty.text()
+}
+
+/// Whether the expression can syntactically never evaluate to `null` or
+/// `undefined`.
+fn is_never_nullish(expr: &Expr) -> bool {
+  match expr {
+    Expr::Lit(lit) => !matches!(lit, Lit::Null(_)),
+    Expr::Tpl(_)
+    | Expr::TaggedTpl(_)
+    | Expr::Array(_)
+    | Expr::Object(_)
+    | Expr::Fn(_)
+    | Expr::Arrow(_)
+    | Expr::Class(_)
+    | Expr::New(_)
+    | Expr::Update(_) => true,
+    Expr::Unary(unary) => unary.op() != UnaryOp::Void,
+    Expr::Bin(bin) => !matches!(
+      bin.op(),
+      BinaryOp::LogicalOr | BinaryOp::LogicalAnd | BinaryOp::NullishCoalescing
+    ),
+    Expr::Paren(paren) => is_never_nullish(&paren.expr),
+    _ => false,
+  }
+}
+
+/// Whether the assertion doesn't change the type of the expression. The types
+/// decide if the linter has them, and the syntax decides otherwise.
+fn keeps_type(
+  ctx: &Context,
+  assertion: SourceRange,
+  expr: &Expr,
+  by_syntax: impl FnOnce() -> bool,
+) -> bool {
+  ctx
+    .is_same_type(expr.range(), assertion)
+    .unwrap_or_else(by_syntax)
+}
+
+impl Handler for NoUnnecessaryTypeAssertionHandler {
+  fn ts_as_expr(&mut self, as_expr: &TsAsExpr, ctx: &mut Context) {
+    if keeps_type(ctx, as_expr.range(), &as_expr.expr, || {
+      is_same_assertion(&as_expr.expr, &as_expr.type_ann)
+        || (is_const_initializer(as_expr.as_node())
+          && is_same_literal(&as_expr.expr, &as_expr.type_ann))
+    }) {
+      add_diagnostic(
+        ctx,
+        as_expr.range(),
+        &as_expr.expr,
+        NoUnnecessaryTypeAssertionMessage::TypeAssertion,
+        NoUnnecessaryTypeAssertionHint::RemoveTypeAssertion,
+      );
+    }
+  }
+
+  fn ts_type_assertion(
+    &mut self,
+    type_assertion: &TsTypeAssertion,
+    ctx: &mut Context,
+  ) {
+    if keeps_type(ctx, type_assertion.range(), &type_assertion.expr, || {
+      is_same_assertion(&type_assertion.expr, &type_assertion.type_ann)
+        || (is_const_initializer(type_assertion.as_node())
+          && is_same_literal(&type_assertion.expr, &type_assertion.type_ann))
+    }) {
+      add_diagnostic(
+        ctx,
+        type_assertion.range(),
+        &type_assertion.expr,
+        NoUnnecessaryTypeAssertionMessage::TypeAssertion,
+        NoUnnecessaryTypeAssertionHint::RemoveTypeAssertion,
+      );
+    }
+  }
+
+  fn ts_const_assertion(
+    &mut self,
+    const_assertion: &TsConstAssertion,
+    ctx: &mut Context,
+  ) {
+    // `const foo = "bar" as const` has the same type as `const foo = "bar"`.
+    if keeps_type(ctx, const_assertion.range(), &const_assertion.expr, || {
+      is_const_initializer(const_assertion.as_node())
+        && is_primitive_literal(&const_assertion.expr)
+    }) {
+      add_diagnostic(
+        ctx,
+        const_assertion.range(),
+        &const_assertion.expr,
+        NoUnnecessaryTypeAssertionMessage::TypeAssertion,
+        NoUnnecessaryTypeAssertionHint::RemoveTypeAssertion,
+      );
+    }
+  }
+
+  fn ts_non_null_expr(
+    &mut self,
+    non_null_expr: &TsNonNullExpr,
+    ctx: &mut Context,
+  ) {
+    let never_nullish = ctx
+      .is_nullable_type(non_null_expr.expr.range())
+      .map(|nullable| !nullable)
+      .unwrap_or_else(|| is_never_nullish(&non_null_expr.expr));
+    if never_nullish {
+      add_diagnostic(
+        ctx,
+        non_null_expr.range(),
+        &non_null_expr.expr,
+        NoUnnecessaryTypeAssertionMessage::NonNullAssertion,
+        NoUnnecessaryTypeAssertionHint::RemoveNonNullAssertion,
+      );
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::{ExpectedDiagnostic, LintCase, RuleTester};
+  use crate::type_info::TypeInfoProvider;
+  use deno_ast::ModuleSpecifier;
+  use std::ops::Range;
+  use std::sync::Arc;
+
+  /// Types of the expressions with the given texts. The types of all other
+  /// expressions are unknown.
+  #[derive(Debug)]
+  struct Types {
+    source: &'static str,
+    types: &'static [(&'static str, &'static str)],
+  }
+
+  impl Types {
+    fn type_of(&self, range: Range<usize>) -> Option<&'static str> {
+      let text = &self.source[range];
+      self
+        .types
+        .iter()
+        .find(|(t, _)| *t == text)
+        .map(|(_, ty)| *ty)
+    }
+  }
+
+  impl TypeInfoProvider for Types {
+    fn is_any(
+      &self,
+      _specifier: &ModuleSpecifier,
+      _range: Range<usize>,
+    ) -> Option<bool> {
+      None
+    }
+
+    fn is_same_type(
+      &self,
+      _specifier: &ModuleSpecifier,
+      a: Range<usize>,
+      b: Range<usize>,
+    ) -> Option<bool> {
+      Some(self.type_of(a)? == self.type_of(b)?)
+    }
+
+    fn is_nullable(
+      &self,
+      _specifier: &ModuleSpecifier,
+      range: Range<usize>,
+    ) -> Option<bool> {
+      Some(self.type_of(range)?.ends_with("| null"))
+    }
+  }
+
+  fn tester(
+    source: &'static str,
+    types: &'static [(&'static str, &'static str)],
+  ) -> RuleTester {
+    RuleTester::with_type_info(
+      Box::new(NoUnnecessaryTypeAssertion),
+      Arc::new(Types { source, types }),
+    )
+  }
+
+  #[test]
+  fn no_unnecessary_type_assertion_with_types() {
+    const TYPES: &[(&str, &str)] = &[
+      ("foo", "Foo"),
+      ("bar", "Bar"),
+      ("maybe", "Foo | null"),
+      ("foo as Foo", "Foo"),
+      ("bar as Foo", "Foo"),
+      ("<Foo>foo", "Foo"),
+      ("<Foo>bar", "Foo"),
+      // Types that disagree with the syntax.
+      (r#""a""#, "string"),
+      (r#""a" as "a""#, r#""a""#),
+    ];
+
+    for (source, fixed) in [("foo as Foo;", "foo;"), ("<Foo>foo;", "foo;")] {
+      tester(source, TYPES).run(
+        LintCase::new(source).error(
+          ExpectedDiagnostic::new(
+            1,
+            0,
+            NoUnnecessaryTypeAssertionMessage::TypeAssertion,
+          )
+          .hint(NoUnnecessaryTypeAssertionHint::RemoveTypeAssertion)
+          .fix(FIX_DESC, fixed),
+        ),
+      );
+    }
+    tester("foo!;", TYPES).run(
+      LintCase::new("foo!;").error(
+        ExpectedDiagnostic::new(
+          1,
+          0,
+          NoUnnecessaryTypeAssertionMessage::NonNullAssertion,
+        )
+        .hint(NoUnnecessaryTypeAssertionHint::RemoveNonNullAssertion)
+        .fix(FIX_DESC, "foo;"),
+      ),
+    );
+
+    for source in [
+      "bar as Foo;",
+      "<Foo>bar;",
+      "maybe!;",
+      r#"const a = "a" as "a";"#,
+    ] {
+      tester(source, TYPES).run(source);
+    }
+
+    // Without a known type, the syntax decides.
+    let source = r#""foo"!.length;"#;
+    tester(source, TYPES).run(
+      LintCase::new(source).error(
+        ExpectedDiagnostic::new(
+          1,
+          0,
+          NoUnnecessaryTypeAssertionMessage::NonNullAssertion,
+        )
+        .hint(NoUnnecessaryTypeAssertionHint::RemoveNonNullAssertion)
+        .fix(FIX_DESC, r#""foo".length;"#),
+      ),
+    );
+    tester("const foo = bar as Foo;", &[]).run("const foo = bar as Foo;");
+  }
+
+  #[test]
+  fn no_unnecessary_type_assertion_valid() {
+    assert_lint_ok! {
+      NoUnnecessaryTypeAssertion,
+      "const foo = bar as Foo;",
+      "const foo = <Foo>bar;",
+      "const foo = (bar as Foo) as Baz;",
+      // literal types of `let` declarations are widened without the assertion
+      r#"let foo = "foo" as "foo";"#,
+      r#"let foo = "foo" as const;"#,
+      r#"const foo = "foo" as string;"#,
+      r#"const foo = "foo" as "bar";"#,
+      "const foo = [1, 2] as const;",
+      "const foo = { a: 1 } as const;",
+      "foo!.bar;",
+      "foo()!;",
+      "(a || b)!;",
+      "(a ?? b)!;",
+      "(void 0)!;",
+      "null!;",
+    };
+  }
+
+  #[test]
+  fn no_unnecessary_type_assertion_invalid() {
+    assert_lint_err! {
+      NoUnnecessaryTypeAssertion,
+      r#"const foo = "foo" as "foo";"#: [
+        {
+          col: 12,
+          message: NoUnnecessaryTypeAssertionMessage::TypeAssertion,
+          hint: NoUnnecessaryTypeAssertionHint::RemoveTypeAssertion,
+          fix: (FIX_DESC, r#"const foo = "foo";"#),
+        }
+      ],
+      "const foo = <1>1;": [
+        {
+          col: 12,
+          message: NoUnnecessaryTypeAssertionMessage::TypeAssertion,
+          hint: NoUnnecessaryTypeAssertionHint::RemoveTypeAssertion,
+          fix: (FIX_DESC, "const foo = 1;"),
+        }
+      ],
+      "const foo = true as const;": [
+        {
+          col: 12,
+          message: NoUnnecessaryTypeAssertionMessage::TypeAssertion,
+          hint: NoUnnecessaryTypeAssertionHint::RemoveTypeAssertion,
+          fix: (FIX_DESC, "const foo = true;"),
+        }
+      ],
+      "const foo = (bar as Foo) as Foo;": [
+        {
+          col: 12,
+          message: NoUnnecessaryTypeAssertionMessage::TypeAssertion,
+          hint: NoUnnecessaryTypeAssertionHint::RemoveTypeAssertion,
+          fix: (FIX_DESC, "const foo = (bar as Foo);"),
+        }
+      ],
+      "const foo = <Foo>(<Foo>bar);": [
+        {
+          col: 12,
+          message: NoUnnecessaryTypeAssertionMessage::TypeAssertion,
+          hint: NoUnnecessaryTypeAssertionHint::RemoveTypeAssertion,
+          fix: (FIX_DESC, "const foo = (<Foo>bar);"),
+        }
+      ],
+      r#""foo"!.length;"#: [
+        {
+          col: 0,
+          message: NoUnnecessaryTypeAssertionMessage::NonNullAssertion,
+          hint: NoUnnecessaryTypeAssertionHint::RemoveNonNullAssertion,
+          fix: (FIX_DESC, r#""foo".length;"#),
+        }
+      ],
+      "[1, 2]!.map(f);": [
+        {
+          col: 0,
+          message: NoUnnecessaryTypeAssertionMessage::NonNullAssertion,
+          hint: NoUnnecessaryTypeAssertionHint::RemoveNonNullAssertion,
+          fix: (FIX_DESC, "[1, 2].map(f);"),
+        }
+      ],
+      "new Foo()!.bar();": [
+        {
+          col: 0,
+          message: NoUnnecessaryTypeAssertionMessage::NonNullAssertion,
+          hint: NoUnnecessaryTypeAssertionHint::RemoveNonNullAssertion,
+          fix: (FIX_DESC, "new Foo().bar();"),
+        }
+      ],
+      "const sum = (a + b)!;": [
+        {
+          col: 12,
+          message: NoUnnecessaryTypeAssertionMessage::NonNullAssertion,
+          hint: NoUnnecessaryTypeAssertionHint::RemoveNonNullAssertion,
+          fix: (FIX_DESC, "const sum = (a + b);"),
+        }
+      ],
+    };
+  }
+}
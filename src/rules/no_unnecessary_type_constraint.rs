@@ -0,0 +1,206 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::Handler;
+
+use deno_ast::view::{
+  ArrowExpr, NodeKind, NodeTrait, TsKeywordTypeKind, TsType, TsTypeParam,
+  TsTypeParamDecl,
+};
+use deno_ast::{MediaType, SourceRange, SourceRanged};
+use derive_more::Display;
+
+#[derive(Debug)]
+pub struct NoUnnecessaryTypeConstraint;
+
+const CODE: &str = "no-unnecessary-type-constraint";
+
+#[derive(Display)]
+enum NoUnnecessaryTypeConstraintMessage {
+  #[display(
+    fmt = "Constraining the generic type `{}` to `{}` does nothing and is unnecessary",
+    _0,
+    _1
+  )]
+  Unnecessary(String, &'static str),
+}
+
+#[derive(Display)]
+enum NoUnnecessaryTypeConstraintHint {
+  #[display(fmt = "Remove the constraint")]
+  Remove,
+}
+
+#[derive(Display)]
+enum NoUnnecessaryTypeConstraintFix {
+  #[display(fmt = "Remove `extends {}`", _0)]
+  Remove(&'static str),
+}
+
+impl LintRule for NoUnnecessaryTypeConstraint {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoUnnecessaryTypeConstraintHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::TsTypeParam])
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_unnecessary_type_constraint.md")
+  }
+}
+
+/// In a `.tsx` file, `<T>() => {}` is parsed as a JSX element, so the only
+/// type parameter of an arrow function needs a trailing comma once its
+/// constraint is removed.
+fn needs_trailing_comma(param: &TsTypeParam, ctx: &Context) -> bool {
+  if ctx.media_type() != MediaType::Tsx {
+    return false;
+  }
+  let Some(decl) = param.parent().to::<TsTypeParamDecl>() else {
+    return false;
+  };
+  decl.params.len() == 1
+    && decl.parent().is::<ArrowExpr>()
+    && !decl.text().trim_end_matches('>').trim_end().ends_with(',')
+}
+
+struct NoUnnecessaryTypeConstraintHandler;
+
+impl Handler for NoUnnecessaryTypeConstraintHandler {
+  fn ts_type_param(&mut self, param: &TsTypeParam, ctx: &mut Context) {
+    let Some(TsType::TsKeywordType(keyword)) = param.constraint else {
+      return;
+    };
+    let constraint = match keyword.keyword_kind() {
+      TsKeywordTypeKind::TsAnyKeyword => "any",
+      TsKeywordTypeKind::TsUnknownKeyword => "unknown",
+      _ => return,
+    };
+
+    let new_text = if needs_trailing_comma(param, ctx) {
+      ","
+    } else {
+      ""
+    };
+    ctx.add_diagnostic_with_fixes(
+      param.range(),
+      CODE,
+      NoUnnecessaryTypeConstraintMessage::Unnecessary(
+        param.name.sym().to_string(),
+        constraint,
+      ),
+      Some(NoUnnecessaryTypeConstraintHint::Remove.to_string()),
+      vec![LintFix {
+        description: NoUnnecessaryTypeConstraintFix::Remove(constraint)
+          .to_string()
+          .into(),
+        changes: vec![LintFixChange {
+          new_text: new_text.into(),
+          range: SourceRange::new(param.name.end(), keyword.end()),
+        }],
+      }],
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_unnecessary_type_constraint_valid() {
+    assert_lint_ok! {
+      NoUnnecessaryTypeConstraint,
+      "function foo<T>() {}",
+      "function foo<T extends string>() {}",
+      "function foo<T extends object = any>() {}",
+      "class Foo<T extends unknown[]> {}",
+      "type Foo<T extends Record<string, unknown>> = T;",
+    };
+  }
+
+  #[test]
+  fn no_unnecessary_type_constraint_invalid() {
+    assert_lint_err! {
+      NoUnnecessaryTypeConstraint,
+      "function foo<T extends any>() {}": [
+        {
+          col: 13,
+          message: NoUnnecessaryTypeConstraintMessage::Unnecessary(
+            "T".to_string(),
+            "any"
+          ),
+          hint: NoUnnecessaryTypeConstraintHint::Remove,
+          fix: ("Remove `extends any`", "function foo<T>() {}"),
+        }
+      ],
+      "interface Foo<T, U extends unknown = string> {}": [
+        {
+          col: 17,
+          message: NoUnnecessaryTypeConstraintMessage::Unnecessary(
+            "U".to_string(),
+            "unknown"
+          ),
+          hint: NoUnnecessaryTypeConstraintHint::Remove,
+          fix: (
+            "Remove `extends unknown`",
+            "interface Foo<T, U = string> {}"
+          ),
+        }
+      ],
+      "const foo = <T extends unknown>() => {};": [
+        {
+          col: 13,
+          message: NoUnnecessaryTypeConstraintMessage::Unnecessary(
+            "T".to_string(),
+            "unknown"
+          ),
+          hint: NoUnnecessaryTypeConstraintHint::Remove,
+          fix: ("Remove `extends unknown`", "const foo = <T>() => {};"),
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn no_unnecessary_type_constraint_tsx() {
+    assert_lint_err! {
+      NoUnnecessaryTypeConstraint,
+      filename: "file:///foo.tsx",
+      "const foo = <T extends unknown>() => {};": [
+        {
+          col: 13,
+          message: NoUnnecessaryTypeConstraintMessage::Unnecessary(
+            "T".to_string(),
+            "unknown"
+          ),
+          hint: NoUnnecessaryTypeConstraintHint::Remove,
+          fix: ("Remove `extends unknown`", "const foo = <T,>() => {};"),
+        }
+      ],
+      "function foo<T extends any>() {}": [
+        {
+          col: 13,
+          message: NoUnnecessaryTypeConstraintMessage::Unnecessary(
+            "T".to_string(),
+            "any"
+          ),
+          hint: NoUnnecessaryTypeConstraintHint::Remove,
+          fix: ("Remove `extends any`", "function foo<T>() {}"),
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,236 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::explicit_any::{checks_file, is_any_or_unknown_type, ExplicitAny};
+use crate::handler::{Handler, Traverse};
+use crate::Program;
+
+use deno_ast::view::{
+  AssignExpr, AssignOp, AssignTarget, ClassProp, Pat, PrivateProp,
+  SimpleAssignTarget, TsTypeAnn, VarDeclarator,
+};
+use deno_ast::{SourceRange, SourceRanged};
+use derive_more::Display;
+
+#[derive(Debug)]
+pub struct NoUnsafeAssignment;
+
+const CODE: &str = "no-unsafe-assignment";
+
+#[derive(Display)]
+enum NoUnsafeAssignmentMessage {
+  #[display(fmt = "Unsafe assignment of an `any` value")]
+  Assignment,
+  #[display(fmt = "Unsafe destructuring of an `any` value")]
+  Destructuring,
+}
+
+#[derive(Display)]
+enum NoUnsafeAssignmentHint {
+  #[display(
+    fmt = "Give the value a more specific type, or annotate the target as `unknown` and narrow it before use"
+  )]
+  NarrowType,
+}
+
+impl LintRule for NoUnsafeAssignment {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    if !checks_file(context) {
+      return;
+    }
+    let explicit_any = ExplicitAny::collect(program, context);
+    NoUnsafeAssignmentHandler { explicit_any }.traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_unsafe_assignment.md")
+  }
+}
+
+struct NoUnsafeAssignmentHandler {
+  explicit_any: ExplicitAny,
+}
+
+fn accepts_any(type_ann: Option<&TsTypeAnn>) -> bool {
+  type_ann.map_or(false, |type_ann| is_any_or_unknown_type(&type_ann.type_ann))
+}
+
+fn add_diagnostic(
+  ctx: &mut Context,
+  range: SourceRange,
+  message: NoUnsafeAssignmentMessage,
+) {
+  ctx.add_diagnostic_with_hint(
+    range,
+    CODE,
+    message,
+    NoUnsafeAssignmentHint::NarrowType,
+  );
+}
+
+impl Handler for NoUnsafeAssignmentHandler {
+  fn var_declarator(&mut self, declarator: &VarDeclarator, ctx: &mut Context) {
+    let Some(init) = &declarator.init else {
+      return;
+    };
+    if !self.explicit_any.is_any(init, ctx) {
+      return;
+    }
+    let message = match &declarator.name {
+      Pat::Ident(binding) if accepts_any(binding.type_ann) => return,
+      Pat::Ident(_) => NoUnsafeAssignmentMessage::Assignment,
+      _ => NoUnsafeAssignmentMessage::Destructuring,
+    };
+    add_diagnostic(ctx, declarator.range(), message);
+  }
+
+  fn assign_expr(&mut self, assign_expr: &AssignExpr, ctx: &mut Context) {
+    if assign_expr.op() != AssignOp::Assign
+      || !self.explicit_any.is_any(&assign_expr.right, ctx)
+    {
+      return;
+    }
+    let message = match &assign_expr.left {
+      AssignTarget::Simple(SimpleAssignTarget::Ident(binding))
+        if self.explicit_any.accepts_any(&binding.id.inner.to_id()) =>
+      {
+        return
+      }
+      AssignTarget::Simple(_) => NoUnsafeAssignmentMessage::Assignment,
+      AssignTarget::Pat(_) => NoUnsafeAssignmentMessage::Destructuring,
+    };
+    add_diagnostic(ctx, assign_expr.range(), message);
+  }
+
+  fn class_prop(&mut self, class_prop: &ClassProp, ctx: &mut Context) {
+    if let Some(value) = &class_prop.value {
+      if self.explicit_any.is_any(value, ctx)
+        && !accepts_any(class_prop.type_ann)
+      {
+        add_diagnostic(
+          ctx,
+          class_prop.range(),
+          NoUnsafeAssignmentMessage::Assignment,
+        );
+      }
+    }
+  }
+
+  fn private_prop(&mut self, private_prop: &PrivateProp, ctx: &mut Context) {
+    if let Some(value) = &private_prop.value {
+      if self.explicit_any.is_any(value, ctx)
+        && !accepts_any(private_prop.type_ann)
+      {
+        add_diagnostic(
+          ctx,
+          private_prop.range(),
+          NoUnsafeAssignmentMessage::Assignment,
+        );
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::{
+    ExpectedDiagnostic, LintCase, RuleTester, UnknownTypes,
+  };
+  use std::sync::Arc;
+
+  fn tester() -> RuleTester {
+    RuleTester::with_type_info(
+      Box::new(NoUnsafeAssignment),
+      Arc::new(UnknownTypes),
+    )
+  }
+
+  #[test]
+  fn no_unsafe_assignment_valid() {
+    let tester = tester();
+    for source in [
+      "const foo = 1;",
+      "const foo: any = bar as any;",
+      "const foo: unknown = JSON.parse(text);",
+      "const foo = bar as any as Foo;",
+      "let foo: unknown; foo = bar as any;",
+      "let foo: any; foo = bar as any;",
+      "let foo = 1; foo += bar as any;",
+      "class Foo { bar: unknown = baz as any; }",
+      "class Foo { #bar: any = baz as any; }",
+      "function f(foo: Foo) { const bar = foo.bar; }",
+    ] {
+      tester.run(source);
+    }
+  }
+
+  #[test]
+  fn no_unsafe_assignment_invalid() {
+    let tester = tester();
+    tester.run(
+      LintCase::new("const foo = bar as any;").error(
+        ExpectedDiagnostic::new(1, 6, NoUnsafeAssignmentMessage::Assignment)
+          .hint(NoUnsafeAssignmentHint::NarrowType),
+      ),
+    );
+    tester.run(
+      LintCase::new("const foo: Foo = JSON.parse(text);").error(
+        ExpectedDiagnostic::new(1, 6, NoUnsafeAssignmentMessage::Assignment)
+          .hint(NoUnsafeAssignmentHint::NarrowType),
+      ),
+    );
+    tester.run(
+      LintCase::new("function f(foo: any) { const bar = foo.bar; }").error(
+        ExpectedDiagnostic::new(1, 29, NoUnsafeAssignmentMessage::Assignment)
+          .hint(NoUnsafeAssignmentHint::NarrowType),
+      ),
+    );
+    tester.run(
+      LintCase::new("const { a, b } = <any>foo;").error(
+        ExpectedDiagnostic::new(1, 6, NoUnsafeAssignmentMessage::Destructuring)
+          .hint(NoUnsafeAssignmentHint::NarrowType),
+      ),
+    );
+    tester.run(
+      LintCase::new("let foo: string; foo = bar as any;").error(
+        ExpectedDiagnostic::new(1, 17, NoUnsafeAssignmentMessage::Assignment)
+          .hint(NoUnsafeAssignmentHint::NarrowType),
+      ),
+    );
+    tester.run(
+      LintCase::new("[a, b] = bar as any;").error(
+        ExpectedDiagnostic::new(1, 0, NoUnsafeAssignmentMessage::Destructuring)
+          .hint(NoUnsafeAssignmentHint::NarrowType),
+      ),
+    );
+    tester.run(
+      LintCase::new("this.foo = bar as any;").error(
+        ExpectedDiagnostic::new(1, 0, NoUnsafeAssignmentMessage::Assignment)
+          .hint(NoUnsafeAssignmentHint::NarrowType),
+      ),
+    );
+    tester.run(
+      LintCase::new(
+        "class Foo { bar = baz as any; #qux: string = baz as any; }",
+      )
+      .error(
+        ExpectedDiagnostic::new(1, 12, NoUnsafeAssignmentMessage::Assignment)
+          .hint(NoUnsafeAssignmentHint::NarrowType),
+      )
+      .error(
+        ExpectedDiagnostic::new(1, 30, NoUnsafeAssignmentMessage::Assignment)
+          .hint(NoUnsafeAssignmentHint::NarrowType),
+      ),
+    );
+  }
+}
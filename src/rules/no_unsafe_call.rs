@@ -0,0 +1,217 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::explicit_any::{checks_file, ExplicitAny};
+use crate::handler::{Handler, Traverse};
+use crate::Program;
+
+use deno_ast::view::{CallExpr, Callee, NewExpr, TaggedTpl};
+use deno_ast::SourceRanged;
+use derive_more::Display;
+
+#[derive(Debug)]
+pub struct NoUnsafeCall;
+
+const CODE: &str = "no-unsafe-call";
+
+#[derive(Display)]
+enum NoUnsafeCallMessage {
+  #[display(fmt = "Unsafe call of an `any` value")]
+  Call,
+  #[display(fmt = "Unsafe construction of an `any` value")]
+  New,
+  #[display(fmt = "Unsafe use of an `any` value as a template tag")]
+  TemplateTag,
+}
+
+#[derive(Display)]
+enum NoUnsafeCallHint {
+  #[display(
+    fmt = "Give the value a function type, or use `unknown` and narrow it before calling it"
+  )]
+  NarrowType,
+}
+
+impl LintRule for NoUnsafeCall {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    if !checks_file(context) {
+      return;
+    }
+    let explicit_any = ExplicitAny::collect(program, context);
+    NoUnsafeCallHandler { explicit_any }.traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_unsafe_call.md")
+  }
+}
+
+struct NoUnsafeCallHandler {
+  explicit_any: ExplicitAny,
+}
+
+impl Handler for NoUnsafeCallHandler {
+  fn call_expr(&mut self, call_expr: &CallExpr, ctx: &mut Context) {
+    let Callee::Expr(callee) = &call_expr.callee else {
+      return;
+    };
+    if self.explicit_any.is_any(callee, ctx) {
+      ctx.add_diagnostic_with_hint(
+        callee.range(),
+        CODE,
+        NoUnsafeCallMessage::Call,
+        NoUnsafeCallHint::NarrowType,
+      );
+    }
+  }
+
+  fn new_expr(&mut self, new_expr: &NewExpr, ctx: &mut Context) {
+    if self.explicit_any.is_any(&new_expr.callee, ctx) {
+      ctx.add_diagnostic_with_hint(
+        new_expr.callee.range(),
+        CODE,
+        NoUnsafeCallMessage::New,
+        NoUnsafeCallHint::NarrowType,
+      );
+    }
+  }
+
+  fn tagged_tpl(&mut self, tagged_tpl: &TaggedTpl, ctx: &mut Context) {
+    if self.explicit_any.is_any(&tagged_tpl.tag, ctx) {
+      ctx.add_diagnostic_with_hint(
+        tagged_tpl.tag.range(),
+        CODE,
+        NoUnsafeCallMessage::TemplateTag,
+        NoUnsafeCallHint::NarrowType,
+      );
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::{
+    ExpectedDiagnostic, LintCase, RuleTester, UnknownTypes,
+  };
+  use crate::type_info::TypeInfoProvider;
+  use deno_ast::ModuleSpecifier;
+  use std::ops::Range;
+  use std::sync::Arc;
+
+  fn tester() -> RuleTester {
+    RuleTester::with_type_info(Box::new(NoUnsafeCall), Arc::new(UnknownTypes))
+  }
+
+  #[test]
+  fn no_unsafe_call_valid() {
+    let tester = tester();
+    for source in [
+      "function f(foo: () => void) { foo(); }",
+      "function f(foo: unknown) { if (typeof foo === 'function') foo(); }",
+      "(foo as any as () => void)();",
+      "function load(): any {} load();",
+      "JSON.parse(text);",
+      "new Foo();",
+      "tag`foo`;",
+    ] {
+      tester.run(source);
+    }
+  }
+
+  #[test]
+  fn no_unsafe_call_needs_type_info() {
+    assert_lint_ok! {
+      NoUnsafeCall,
+      "(foo as any)();",
+      "JSON.parse(text).map(f);",
+    };
+    tester().run(
+      LintCase::new("JSON.parse(text).map(f);").filename("file:///foo.js"),
+    );
+  }
+
+  /// Types where only the expression at one byte range is `any`.
+  #[derive(Debug)]
+  struct AnyAt(Range<usize>);
+
+  impl TypeInfoProvider for AnyAt {
+    fn is_any(
+      &self,
+      _specifier: &ModuleSpecifier,
+      range: Range<usize>,
+    ) -> Option<bool> {
+      Some(range == self.0)
+    }
+  }
+
+  #[test]
+  fn no_unsafe_call_with_types() {
+    let tester =
+      RuleTester::with_type_info(Box::new(NoUnsafeCall), Arc::new(AnyAt(0..3)));
+    tester.run(
+      LintCase::new("foo();").error(
+        ExpectedDiagnostic::new(1, 0, NoUnsafeCallMessage::Call)
+          .hint(NoUnsafeCallHint::NarrowType),
+      ),
+    );
+    // The types know better than the syntax.
+    tester.run("(baz as any)();");
+  }
+
+  #[test]
+  fn no_unsafe_call_invalid() {
+    let tester = tester();
+    tester.run(
+      LintCase::new("function f(foo: any) { foo(); }").error(
+        ExpectedDiagnostic::new(1, 23, NoUnsafeCallMessage::Call)
+          .hint(NoUnsafeCallHint::NarrowType),
+      ),
+    );
+    tester.run(
+      LintCase::new("function f(foo: any) { foo.bar(); }").error(
+        ExpectedDiagnostic::new(1, 23, NoUnsafeCallMessage::Call)
+          .hint(NoUnsafeCallHint::NarrowType),
+      ),
+    );
+    tester.run(
+      LintCase::new("(foo as any)();").error(
+        ExpectedDiagnostic::new(1, 0, NoUnsafeCallMessage::Call)
+          .hint(NoUnsafeCallHint::NarrowType),
+      ),
+    );
+    tester.run(
+      LintCase::new("function load(): any {} load()();").error(
+        ExpectedDiagnostic::new(1, 24, NoUnsafeCallMessage::Call)
+          .hint(NoUnsafeCallHint::NarrowType),
+      ),
+    );
+    tester.run(
+      LintCase::new("JSON.parse(text).map(f);").error(
+        ExpectedDiagnostic::new(1, 0, NoUnsafeCallMessage::Call)
+          .hint(NoUnsafeCallHint::NarrowType),
+      ),
+    );
+    tester.run(
+      LintCase::new("const Foo: any = get(); new Foo();").error(
+        ExpectedDiagnostic::new(1, 28, NoUnsafeCallMessage::New)
+          .hint(NoUnsafeCallHint::NarrowType),
+      ),
+    );
+    tester.run(
+      LintCase::new("const tag = <any>get(); tag`foo`;").error(
+        ExpectedDiagnostic::new(1, 24, NoUnsafeCallMessage::TemplateTag)
+          .hint(NoUnsafeCallHint::NarrowType),
+      ),
+    );
+  }
+}
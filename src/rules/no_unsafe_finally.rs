@@ -1,9 +1,8 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
-use crate::Program;
-use deno_ast::view::NodeTrait;
+use crate::handler::Handler;
+use deno_ast::view::{NodeKind, NodeTrait};
 use deno_ast::{view as ast_view, SourceRange, SourceRanged};
 use derive_more::Display;
 
@@ -46,12 +45,17 @@ impl LintRule for NoUnsafeFinally {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program<'_>,
-  ) {
-    NoUnsafeFinallyHandler.traverse(program, context);
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoUnsafeFinallyHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[
+      NodeKind::BreakStmt,
+      NodeKind::ContinueStmt,
+      NodeKind::ReturnStmt,
+      NodeKind::ThrowStmt,
+    ])
   }
 
   #[cfg(feature = "docs")]
@@ -0,0 +1,166 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::explicit_any::{checks_file, ExplicitAny};
+use crate::handler::{Handler, Traverse};
+use crate::Program;
+
+use deno_ast::view::{MemberExpr, MemberProp, NodeTrait};
+use deno_ast::SourceRanged;
+use derive_more::Display;
+
+#[derive(Debug)]
+pub struct NoUnsafeMemberAccess;
+
+const CODE: &str = "no-unsafe-member-access";
+
+#[derive(Display)]
+enum NoUnsafeMemberAccessMessage {
+  #[display(fmt = "Unsafe member access {} on an `any` value", _0)]
+  Unsafe(String),
+}
+
+#[derive(Display)]
+enum NoUnsafeMemberAccessHint {
+  #[display(
+    fmt = "Give the value a more specific type, or use `unknown` and narrow it before accessing its members"
+  )]
+  NarrowType,
+}
+
+impl LintRule for NoUnsafeMemberAccess {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    if !checks_file(context) {
+      return;
+    }
+    let explicit_any = ExplicitAny::collect(program, context);
+    NoUnsafeMemberAccessHandler { explicit_any }.traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_unsafe_member_access.md")
+  }
+}
+
+struct NoUnsafeMemberAccessHandler {
+  explicit_any: ExplicitAny,
+}
+
+impl Handler for NoUnsafeMemberAccessHandler {
+  fn member_expr(&mut self, member_expr: &MemberExpr, ctx: &mut Context) {
+    // Only the first access of a chain like `foo.bar.baz` is reported.
+    if !self.explicit_any.is_any(&member_expr.obj, ctx)
+      || self.explicit_any.is_derived_any(&member_expr.obj, ctx)
+    {
+      return;
+    }
+    let access = match &member_expr.prop {
+      MemberProp::Ident(ident) => format!(".{}", ident.sym()),
+      MemberProp::PrivateName(private_name) => {
+        format!(".#{}", private_name.name())
+      }
+      MemberProp::Computed(computed) => computed.text().to_string(),
+    };
+    ctx.add_diagnostic_with_hint(
+      member_expr.prop.range(),
+      CODE,
+      NoUnsafeMemberAccessMessage::Unsafe(access),
+      NoUnsafeMemberAccessHint::NarrowType,
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::{
+    ExpectedDiagnostic, LintCase, RuleTester, UnknownTypes,
+  };
+  use std::sync::Arc;
+
+  fn tester() -> RuleTester {
+    RuleTester::with_type_info(
+      Box::new(NoUnsafeMemberAccess),
+      Arc::new(UnknownTypes),
+    )
+  }
+
+  #[test]
+  fn no_unsafe_member_access_valid() {
+    let tester = tester();
+    for source in [
+      "const foo = { bar: 1 }; foo.bar;",
+      "function f(foo: unknown) { return foo; }",
+      "function f(foo: Foo) { return foo.bar; }",
+      "(foo as any as Foo).bar;",
+      "const JSON = { parse: () => ({}) }; JSON.parse().foo;",
+      "function f(foo: any) { return foo; }",
+    ] {
+      tester.run(source);
+    }
+  }
+
+  #[test]
+  fn no_unsafe_member_access_invalid() {
+    let tester = tester();
+    tester.run(
+      LintCase::new("function f(foo: any) { return foo.bar; }").error(
+        ExpectedDiagnostic::new(
+          1,
+          34,
+          NoUnsafeMemberAccessMessage::Unsafe(".bar".to_string()),
+        )
+        .hint(NoUnsafeMemberAccessHint::NarrowType),
+      ),
+    );
+    tester.run(
+      LintCase::new("(foo as any).bar.baz;").error(
+        ExpectedDiagnostic::new(
+          1,
+          13,
+          NoUnsafeMemberAccessMessage::Unsafe(".bar".to_string()),
+        )
+        .hint(NoUnsafeMemberAccessHint::NarrowType),
+      ),
+    );
+    tester.run(
+      LintCase::new("let foo: any; foo[key] = 1;").error(
+        ExpectedDiagnostic::new(
+          1,
+          17,
+          NoUnsafeMemberAccessMessage::Unsafe("[key]".to_string()),
+        )
+        .hint(NoUnsafeMemberAccessHint::NarrowType),
+      ),
+    );
+    tester.run(
+      LintCase::new("JSON.parse(text).items;").error(
+        ExpectedDiagnostic::new(
+          1,
+          17,
+          NoUnsafeMemberAccessMessage::Unsafe(".items".to_string()),
+        )
+        .hint(NoUnsafeMemberAccessHint::NarrowType),
+      ),
+    );
+    tester.run(
+      LintCase::new("function load(): any {} load().data;").error(
+        ExpectedDiagnostic::new(
+          1,
+          31,
+          NoUnsafeMemberAccessMessage::Unsafe(".data".to_string()),
+        )
+        .hint(NoUnsafeMemberAccessHint::NarrowType),
+      ),
+    );
+  }
+}
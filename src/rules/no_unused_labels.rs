@@ -2,6 +2,7 @@
 
 use super::{Context, LintRule};
 use crate::handler::{Handler, Traverse};
+use crate::syntax_features::SyntaxFeatures;
 use crate::Program;
 use deno_ast::{view as ast_view, SourceRanged};
 use derive_more::Display;
@@ -27,6 +28,10 @@ impl LintRule for NoUnusedLabels {
     CODE
   }
 
+  fn required_syntax(&self) -> SyntaxFeatures {
+    SyntaxFeatures::LABEL
+  }
+
   fn lint_program_with_ast_view(
     &self,
     context: &mut Context,
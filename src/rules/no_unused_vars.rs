@@ -2,6 +2,7 @@
 
 use super::program_ref;
 use super::{Context, LintRule};
+use crate::diagnostic::{LintFix, LintFixChange};
 use crate::Program;
 use crate::ProgramRef;
 use deno_ast::swc::ast::{
@@ -10,8 +11,8 @@ use deno_ast::swc::ast::{
   ExportDecl, ExportDefaultDecl, ExportNamedSpecifier, Expr, FnDecl, FnExpr,
   Function, Ident, ImportDefaultSpecifier, ImportNamedSpecifier,
   ImportStarAsSpecifier, JSXElementName, JSXFragment, JSXObject, MemberExpr,
-  MemberProp, MethodKind, ModuleExportName, NamedExport, Param, Pat,
-  PrivateMethod, Prop, PropName, SetterProp, TsEntityName, TsEnumDecl,
+  MemberProp, MethodKind, ModuleExportName, NamedExport, ObjectPatProp, Param,
+  Pat, PrivateMethod, Prop, PropName, SetterProp, TsEntityName, TsEnumDecl,
   TsExprWithTypeArgs, TsImportEqualsDecl, TsInterfaceDecl, TsModuleDecl,
   TsModuleRef, TsNamespaceDecl, TsPropertySignature, TsTypeAliasDecl,
   TsTypeQueryExpr, TsTypeRef, VarDecl, VarDeclarator,
@@ -21,15 +22,60 @@ use deno_ast::swc::atoms::js_word;
 use deno_ast::swc::utils::find_pat_ids;
 use deno_ast::swc::visit::{Visit, VisitWith};
 use deno_ast::view::AssignOp;
-use deno_ast::SourceRangedForSpanned;
+use deno_ast::{SourceRange, SourceRangedForSpanned};
 use derive_more::Display;
 use if_chain::if_chain;
-use std::collections::HashSet;
+use regex::Regex;
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
 use std::iter;
 use std::sync::Arc;
 
-#[derive(Debug)]
-pub struct NoUnusedVars;
+#[derive(Debug, Default)]
+pub struct NoUnusedVars {
+  args_ignore_pattern: Option<Regex>,
+  vars_ignore_pattern: Option<Regex>,
+  caught_errors: CaughtErrors,
+  ignore_rest_siblings: bool,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct NoUnusedVarsOptions {
+  /// A regular expression for the names of parameters that aren't reported,
+  /// e.g. `"^unused"`.
+  pub args_ignore_pattern: Option<String>,
+  /// A regular expression for the names of other variables that aren't
+  /// reported, including imports.
+  pub vars_ignore_pattern: Option<String>,
+  pub caught_errors: CaughtErrors,
+  /// Whether the siblings of a rest property are ignored, like `a` in
+  /// `const { a, ...rest } = obj;`, which only leaves `a` out of `rest`.
+  pub ignore_rest_siblings: bool,
+}
+
+/// Whether the bindings of `catch` clauses are reported.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CaughtErrors {
+  #[default]
+  All,
+  None,
+}
+
+impl NoUnusedVars {
+  /// Returns an error if one of the patterns isn't a valid regular
+  /// expression.
+  pub fn new(options: NoUnusedVarsOptions) -> Result<Self, regex::Error> {
+    let pattern = |pattern: Option<String>| pattern.as_deref().map(Regex::new);
+    Ok(Self {
+      args_ignore_pattern: pattern(options.args_ignore_pattern).transpose()?,
+      vars_ignore_pattern: pattern(options.vars_ignore_pattern).transpose()?,
+      caught_errors: options.caught_errors,
+      ignore_rest_siblings: options.ignore_rest_siblings,
+    })
+  }
+}
 
 const CODE: &str = "no-unused-vars";
 
@@ -54,6 +100,10 @@ enum NoUnusedVarsHint {
   Alias(String),
 }
 
+const FIX_PREFIX_DESC: &str = "Prefix with an underscore";
+const FIX_ALIAS_DESC: &str = "Alias with an underscore";
+const FIX_REMOVE_DESC: &str = "Remove the unused binding";
+
 impl LintRule for NoUnusedVars {
   fn tags(&self) -> &'static [&'static str] {
     &["recommended"]
@@ -63,6 +113,22 @@ impl LintRule for NoUnusedVars {
     CODE
   }
 
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "argsIgnorePattern": { "type": "string" },
+        "varsIgnorePattern": { "type": "string" },
+        "caughtErrors": { "enum": ["all", "none"] },
+        "ignoreRestSiblings": { "type": "boolean" }
+      }
+    }))
+  }
+
   fn lint_program_with_ast_view<'view>(
     &self,
     context: &mut Context<'view>,
@@ -82,10 +148,18 @@ impl LintRule for NoUnusedVars {
       ProgramRef::Script(s) => s.visit_with(&mut collector),
     }
 
+    let mut counter = ReferenceCounter::default();
+    match program {
+      ProgramRef::Module(m) => m.visit_with(&mut counter),
+      ProgramRef::Script(s) => s.visit_with(&mut counter),
+    }
+
     let mut visitor = NoUnusedVarVisitor::new(
       context,
+      self,
       collector.used_vars,
       collector.used_types,
+      counter.0,
     );
     match program {
       ProgramRef::Module(m) => m.visit_with(&mut visitor),
@@ -464,6 +538,67 @@ impl Visit for Collector {
   }
 }
 
+/// Counts the occurrences of each identifier, including where it's declared
+/// and assigned to, so a binding can only be renamed if this is its only
+/// occurrence.
+#[derive(Default)]
+struct ReferenceCounter(HashMap<Id, usize>);
+
+impl Visit for ReferenceCounter {
+  fn visit_ident(&mut self, ident: &Ident) {
+    *self.0.entry(ident.to_id()).or_default() += 1;
+  }
+}
+
+/// The bindings that are siblings of a rest property in an object pattern,
+/// like `a` in `const { a, ...rest } = obj;`.
+fn rest_siblings(pat: &Pat, siblings: &mut HashSet<Id>) {
+  match pat {
+    Pat::Object(object) => {
+      let has_rest = object
+        .props
+        .iter()
+        .any(|prop| matches!(prop, ObjectPatProp::Rest(_)));
+      for prop in &object.props {
+        match prop {
+          ObjectPatProp::KeyValue(prop) => match &*prop.value {
+            Pat::Ident(ident) if has_rest => {
+              siblings.insert(ident.id.to_id());
+            }
+            value => rest_siblings(value, siblings),
+          },
+          ObjectPatProp::Assign(prop) if has_rest => {
+            siblings.insert(prop.key.to_id());
+          }
+          _ => {}
+        }
+      }
+    }
+    Pat::Array(array) => {
+      for elem in array.elems.iter().flatten() {
+        rest_siblings(elem, siblings);
+      }
+    }
+    Pat::Assign(assign) => rest_siblings(&assign.left, siblings),
+    _ => {}
+  }
+}
+
+/// The bindings of a pattern that are written as shorthand properties, like
+/// `a` in `const { a } = obj;`, which can't be renamed without adding a key.
+fn shorthand_ids(pat: &Pat) -> HashSet<Id> {
+  struct Finder(HashSet<Id>);
+  impl Visit for Finder {
+    fn visit_assign_pat_prop(&mut self, prop: &AssignPatProp) {
+      self.0.insert(prop.key.to_id());
+      prop.value.visit_with(self);
+    }
+  }
+  let mut finder = Finder(HashSet::new());
+  pat.visit_with(&mut finder);
+  finder.0
+}
+
 fn get_id(r: &TsEntityName) -> Id {
   match r {
     TsEntityName::TsQualifiedName(q) => get_id(&q.left),
@@ -473,38 +608,56 @@ fn get_id(r: &TsEntityName) -> Id {
 
 struct NoUnusedVarVisitor<'c, 'view> {
   context: &'c mut Context<'view>,
+  rule: &'c NoUnusedVars,
   used_vars: HashSet<Id>,
   used_types: HashSet<Id>,
+  references: HashMap<Id, usize>,
+  /// Bindings that are written as shorthand properties of the pattern that
+  /// is being visited.
+  shorthands: HashSet<Id>,
 }
 
 impl<'c, 'view> NoUnusedVarVisitor<'c, 'view> {
   fn new(
     context: &'c mut Context<'view>,
+    rule: &'c NoUnusedVars,
     used_vars: HashSet<Id>,
     used_types: HashSet<Id>,
+    references: HashMap<Id, usize>,
   ) -> Self {
     Self {
       context,
+      rule,
       used_vars,
       used_types,
+      references,
+      shorthands: HashSet::new(),
     }
   }
 }
 
 #[derive(Debug, Clone, Copy)]
 enum IdentKind<'a> {
-  NamedImport(&'a Ident),
+  /// An imported binding, which is aliased if it's renamed like
+  /// `import { a as b }`.
+  NamedImport(&'a Ident, bool),
   DefaultImport(&'a Ident),
   StarAsImport(&'a Ident),
+  Param(&'a Ident),
+  /// The binding of a `catch` clause, which is the whole parameter if it's
+  /// not destructured.
+  CaughtError(&'a Ident, Option<&'a CatchClause>),
   Other(&'a Ident),
 }
 
 impl<'a> IdentKind<'a> {
   fn inner(&self) -> &Ident {
     match *self {
-      IdentKind::NamedImport(ident) => ident,
+      IdentKind::NamedImport(ident, _) => ident,
       IdentKind::DefaultImport(ident) => ident,
       IdentKind::StarAsImport(ident) => ident,
+      IdentKind::Param(ident) => ident,
+      IdentKind::CaughtError(ident, _) => ident,
       IdentKind::Other(ident) => ident,
     }
   }
@@ -517,9 +670,11 @@ impl<'a> IdentKind<'a> {
   fn to_hint(self) -> NoUnusedVarsHint {
     let symbol = self.inner().sym.to_string();
     match self {
-      IdentKind::NamedImport(_) => NoUnusedVarsHint::Alias(symbol),
+      IdentKind::NamedImport(..) => NoUnusedVarsHint::Alias(symbol),
       IdentKind::DefaultImport(_)
       | IdentKind::StarAsImport(_)
+      | IdentKind::Param(_)
+      | IdentKind::CaughtError(..)
       | IdentKind::Other(_) => NoUnusedVarsHint::AddPrefix(symbol),
     }
   }
@@ -528,28 +683,102 @@ impl<'a> IdentKind<'a> {
 impl<'c, 'view> NoUnusedVarVisitor<'c, 'view> {
   fn handle_id(&mut self, ident: IdentKind) {
     let inner = ident.inner();
-    if inner.sym.starts_with('_') {
+    if inner.sym.starts_with('_') || self.is_ignored(ident) {
       return;
     }
 
     if !self.used_vars.contains(&inner.to_id()) {
       // The variable is not used.
-      self.context.add_diagnostic_with_hint(
+      let fixes = self.fixes(ident).into_iter().collect();
+      self.context.add_diagnostic_with_fixes(
         inner.range(),
         CODE,
         ident.to_message(),
-        ident.to_hint(),
+        Some(ident.to_hint().to_string()),
+        fixes,
       );
     }
   }
+
+  fn is_ignored(&self, ident: IdentKind) -> bool {
+    let name = &*ident.inner().sym;
+    let matches = |pattern: &Option<Regex>| {
+      pattern
+        .as_ref()
+        .map_or(false, |pattern| pattern.is_match(name))
+    };
+    match ident {
+      IdentKind::Param(_) => matches(&self.rule.args_ignore_pattern),
+      IdentKind::CaughtError(..) => {
+        self.rule.caught_errors == CaughtErrors::None
+      }
+      _ => matches(&self.rule.vars_ignore_pattern),
+    }
+  }
+
+  fn fixes(&self, ident: IdentKind) -> Option<LintFix> {
+    let inner = ident.inner();
+    let name = &*inner.sym;
+    if let IdentKind::CaughtError(_, Some(clause)) = ident {
+      // `catch (e) {}` becomes `catch {}`.
+      let start = clause.range().start + "catch".len();
+      return Some(LintFix {
+        description: FIX_REMOVE_DESC.into(),
+        changes: vec![LintFixChange {
+          new_text: " ".into(),
+          range: SourceRange::new(start, clause.body.range().start),
+        }],
+      });
+    }
+    // Renaming would break the other occurrences, like assignments.
+    if self.references.get(&inner.to_id()) != Some(&1) {
+      return None;
+    }
+    let (description, new_text) = match ident {
+      IdentKind::NamedImport(_, false) => {
+        (FIX_ALIAS_DESC, format!("{} as _{}", name, name))
+      }
+      IdentKind::NamedImport(_, true) => (FIX_ALIAS_DESC, format!("_{}", name)),
+      _ if self.shorthands.contains(&inner.to_id()) => {
+        (FIX_PREFIX_DESC, format!("{}: _{}", name, name))
+      }
+      _ => (FIX_PREFIX_DESC, format!("_{}", name)),
+    };
+    Some(LintFix {
+      description: description.into(),
+      changes: vec![LintFixChange {
+        new_text: new_text.into(),
+        range: inner.range(),
+      }],
+    })
+  }
+
+  fn handle_pat_ids(
+    &mut self,
+    pat: &Pat,
+    kind: fn(&Ident) -> IdentKind,
+    ignored: &HashSet<Id>,
+  ) {
+    let declared_idents: Vec<Ident> = find_pat_ids(pat);
+    let shorthands =
+      std::mem::replace(&mut self.shorthands, shorthand_ids(pat));
+    for ident in &declared_idents {
+      if !ignored.contains(&ident.to_id()) {
+        self.handle_id(kind(ident));
+      }
+    }
+    self.shorthands = shorthands;
+  }
 }
 
 impl<'c, 'view> Visit for NoUnusedVarVisitor<'c, 'view> {
   fn visit_arrow_expr(&mut self, expr: &ArrowExpr) {
-    let declared_idents: Vec<Ident> = find_pat_ids(&expr.params);
-
-    for ident in declared_idents {
-      self.handle_id(IdentKind::Other(&ident));
+    for param in &expr.params {
+      self.handle_pat_ids(
+        param,
+        |ident| IdentKind::Param(ident),
+        &HashSet::new(),
+      );
     }
     expr.body.visit_with(self)
   }
@@ -576,11 +805,15 @@ impl<'c, 'view> Visit for NoUnusedVarVisitor<'c, 'view> {
   }
 
   fn visit_var_declarator(&mut self, declarator: &VarDeclarator) {
-    let declared_idents: Vec<Ident> = find_pat_ids(&declarator.name);
-
-    for ident in declared_idents {
-      self.handle_id(IdentKind::Other(&ident));
+    let mut ignored = HashSet::new();
+    if self.rule.ignore_rest_siblings {
+      rest_siblings(&declarator.name, &mut ignored);
     }
+    self.handle_pat_ids(
+      &declarator.name,
+      |ident| IdentKind::Other(ident),
+      &ignored,
+    );
     declarator.name.visit_with(self);
     declarator.init.visit_with(self);
   }
@@ -595,10 +828,16 @@ impl<'c, 'view> Visit for NoUnusedVarVisitor<'c, 'view> {
   }
 
   fn visit_catch_clause(&mut self, clause: &CatchClause) {
-    let declared_idents: Vec<Ident> = find_pat_ids(&clause.param);
-
-    for ident in declared_idents {
-      self.handle_id(IdentKind::Other(&ident));
+    match &clause.param {
+      Some(Pat::Ident(ident)) => {
+        self.handle_id(IdentKind::CaughtError(&ident.id, Some(clause)))
+      }
+      Some(param) => self.handle_pat_ids(
+        param,
+        |ident| IdentKind::CaughtError(ident, None),
+        &HashSet::new(),
+      ),
+      None => {}
     }
 
     clause.body.visit_with(self);
@@ -644,11 +883,11 @@ impl<'c, 'view> Visit for NoUnusedVarVisitor<'c, 'view> {
   }
 
   fn visit_param(&mut self, param: &Param) {
-    let declared_idents: Vec<Ident> = find_pat_ids(&param.pat);
-
-    for ident in declared_idents {
-      self.handle_id(IdentKind::Other(&ident));
-    }
+    self.handle_pat_ids(
+      &param.pat,
+      |ident| IdentKind::Param(ident),
+      &HashSet::new(),
+    );
     param.visit_children_with(self);
   }
 
@@ -656,7 +895,10 @@ impl<'c, 'view> Visit for NoUnusedVarVisitor<'c, 'view> {
     if self.used_types.contains(&import.local.to_id()) {
       return;
     }
-    self.handle_id(IdentKind::NamedImport(&import.local));
+    self.handle_id(IdentKind::NamedImport(
+      &import.local,
+      import.imported.is_some(),
+    ));
   }
 
   fn visit_import_default_specifier(
@@ -770,7 +1012,7 @@ mod tests {
   #[test]
   fn no_unused_vars_valid() {
     assert_lint_ok! {
-      NoUnusedVars,
+      NoUnusedVars::default(),
       "var a = 1; console.log(a)",
       "var a = 1; const arrow = () => a; console.log(arrow)",
       "var a = 1; console.log?.(a)",
@@ -1471,7 +1713,7 @@ console.log(MyDeps);
 
     // JSX or TSX
     assert_lint_ok! {
-      NoUnusedVars,
+      NoUnusedVars::default(),
       filename: "file:///foo.tsx",
       r#"
 import { TypeA } from './interface';
@@ -1530,12 +1772,13 @@ export default <></>;
   #[test]
   fn no_unused_vars_invalid() {
     assert_lint_err! {
-      NoUnusedVars,
+      NoUnusedVars::default(),
       "var a = 0": [
         {
           col: 4,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "a"),
           hint: variant!(NoUnusedVarsHint, AddPrefix, "a"),
+          fix: (FIX_PREFIX_DESC, "var _a = 0"),
         }
       ],
       // variable shadowing
@@ -1544,6 +1787,7 @@ export default <></>;
           col: 4,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "a"),
           hint: variant!(NoUnusedVarsHint, AddPrefix, "a"),
+          fix: (FIX_PREFIX_DESC, "var _a = 1; function foo() { var a = 2; console.log(a); }; use(foo);"),
         }
       ],
       "function foox() { return foox(); }": [
@@ -1558,6 +1802,7 @@ export default <></>;
           col: 6,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "Foo"),
           hint: variant!(NoUnusedVarsHint, AddPrefix, "Foo"),
+          fix: (FIX_PREFIX_DESC, "class _Foo {}"),
         }
       ],
       "(function() { function foox() { if (true) { return foox(); } } }())": [
@@ -1594,11 +1839,13 @@ export default <></>;
           col: 9,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "foo"),
           hint: variant!(NoUnusedVarsHint, AddPrefix, "foo"),
+          fix: (FIX_PREFIX_DESC, "function _foo(first, second) {\ndoStuff(function(){\nconsole.log(second);});};"),
         },
         {
           col: 13,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "first"),
           hint: variant!(NoUnusedVarsHint, AddPrefix, "first"),
+          fix: (FIX_PREFIX_DESC, "function foo(_first, second) {\ndoStuff(function(){\nconsole.log(second);});};"),
         }
       ],
       "var a=10; a=20;": [
@@ -1613,6 +1860,7 @@ export default <></>;
           col: 4,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "a"),
           hint: variant!(NoUnusedVarsHint, AddPrefix, "a"),
+          fix: (FIX_PREFIX_DESC, "var _a=10; (function() { var a = 1; alert(a); })();"),
         }
       ],
       "var a=10, b=0, c=null; alert(a+b)": [
@@ -1620,6 +1868,7 @@ export default <></>;
           col: 15,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "c"),
           hint: variant!(NoUnusedVarsHint, AddPrefix, "c"),
+          fix: (FIX_PREFIX_DESC, "var a=10, b=0, _c=null; alert(a+b)"),
         }
       ],
       "var a=10, b=0, c=null; setTimeout(function() { var b=2; alert(a+b+c); }, 0);": [
@@ -1627,6 +1876,7 @@ export default <></>;
           col: 10,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "b"),
           hint: variant!(NoUnusedVarsHint, AddPrefix, "b"),
+          fix: (FIX_PREFIX_DESC, "var a=10, _b=0, c=null; setTimeout(function() { var b=2; alert(a+b+c); }, 0);"),
         }
       ],
       "var a=10, b=0, c=null; setTimeout(function() \
@@ -1635,11 +1885,13 @@ export default <></>;
           col: 10,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "b"),
           hint: variant!(NoUnusedVarsHint, AddPrefix, "b"),
+          fix: (FIX_PREFIX_DESC, "var a=10, _b=0, c=null; setTimeout(function() { var b=2; var c=2; alert(a+b+c); }, 0);"),
         },
         {
           col: 15,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "c"),
           hint: variant!(NoUnusedVarsHint, AddPrefix, "c"),
+          fix: (FIX_PREFIX_DESC, "var a=10, b=0, _c=null; setTimeout(function() { var b=2; var c=2; alert(a+b+c); }, 0);"),
         }
       ],
       "function f(){var a=[];return a.map(function(){});}": [
@@ -1647,6 +1899,7 @@ export default <></>;
           col: 9,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "f"),
           hint: variant!(NoUnusedVarsHint, AddPrefix, "f"),
+          fix: (FIX_PREFIX_DESC, "function _f(){var a=[];return a.map(function(){});}"),
         }
       ],
       "function f(){var a=[];return a.map(function g(){});}": [
@@ -1654,6 +1907,7 @@ export default <></>;
           col: 9,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "f"),
           hint: variant!(NoUnusedVarsHint, AddPrefix, "f"),
+          fix: (FIX_PREFIX_DESC, "function _f(){var a=[];return a.map(function g(){});}"),
         }
       ],
       "function f(){var x;function a(){x=42;}function b(){alert(x);}}": [
@@ -1661,16 +1915,19 @@ export default <></>;
           col: 9,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "f"),
           hint: variant!(NoUnusedVarsHint, AddPrefix, "f"),
+          fix: (FIX_PREFIX_DESC, "function _f(){var x;function a(){x=42;}function b(){alert(x);}}"),
         },
         {
           col: 28,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "a"),
           hint: variant!(NoUnusedVarsHint, AddPrefix, "a"),
+          fix: (FIX_PREFIX_DESC, "function f(){var x;function _a(){x=42;}function b(){alert(x);}}"),
         },
         {
           col: 47,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "b"),
           hint: variant!(NoUnusedVarsHint, AddPrefix, "b"),
+          fix: (FIX_PREFIX_DESC, "function f(){var x;function a(){x=42;}function _b(){alert(x);}}"),
         }
       ],
       "function f(a) {}; f();": [
@@ -1678,6 +1935,7 @@ export default <></>;
           col: 11,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "a"),
           hint: variant!(NoUnusedVarsHint, AddPrefix, "a"),
+          fix: (FIX_PREFIX_DESC, "function f(_a) {}; f();"),
         }
       ],
       "function a(x, y, z){ return y; }; a();": [
@@ -1685,11 +1943,13 @@ export default <></>;
           col: 11,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "x"),
           hint: variant!(NoUnusedVarsHint, AddPrefix, "x"),
+          fix: (FIX_PREFIX_DESC, "function a(_x, y, z){ return y; }; a();"),
         },
         {
           col: 17,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "z"),
           hint: variant!(NoUnusedVarsHint, AddPrefix, "z"),
+          fix: (FIX_PREFIX_DESC, "function a(x, y, _z){ return y; }; a();"),
         }
       ],
       "var min = Math.min": [
@@ -1697,6 +1957,7 @@ export default <></>;
           col: 4,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "min"),
           hint: variant!(NoUnusedVarsHint, AddPrefix, "min"),
+          fix: (FIX_PREFIX_DESC, "var _min = Math.min"),
         }
       ],
       "Foo.bar = function(baz) { return 1; };": [
@@ -1704,6 +1965,7 @@ export default <></>;
           col: 19,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "baz"),
           hint: variant!(NoUnusedVarsHint, AddPrefix, "baz"),
+          fix: (FIX_PREFIX_DESC, "Foo.bar = function(_baz) { return 1; };"),
         }
       ],
       "var min = {min: 1}": [
@@ -1711,6 +1973,7 @@ export default <></>;
           col: 4,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "min"),
           hint: variant!(NoUnusedVarsHint, AddPrefix, "min"),
+          fix: (FIX_PREFIX_DESC, "var _min = {min: 1}"),
         }
       ],
       "function gg(baz, bar) { return baz; }; gg();": [
@@ -1718,6 +1981,7 @@ export default <></>;
           col: 17,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "bar"),
           hint: variant!(NoUnusedVarsHint, AddPrefix, "bar"),
+          fix: (FIX_PREFIX_DESC, "function gg(baz, _bar) { return baz; }; gg();"),
         }
       ],
       "(function(foo, baz, bar) { return baz; })();": [
@@ -1725,11 +1989,13 @@ export default <></>;
           col: 10,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "foo"),
           hint: variant!(NoUnusedVarsHint, AddPrefix, "foo"),
+          fix: (FIX_PREFIX_DESC, "(function(_foo, baz, bar) { return baz; })();"),
         },
         {
           col: 20,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "bar"),
           hint: variant!(NoUnusedVarsHint, AddPrefix, "bar"),
+          fix: (FIX_PREFIX_DESC, "(function(foo, baz, _bar) { return baz; })();"),
         }
       ],
       "(function z(foo) { var bar = 33; })();": [
@@ -1737,11 +2003,13 @@ export default <></>;
           col: 12,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "foo"),
           hint: variant!(NoUnusedVarsHint, AddPrefix, "foo"),
+          fix: (FIX_PREFIX_DESC, "(function z(_foo) { var bar = 33; })();"),
         },
         {
           col: 23,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "bar"),
           hint: variant!(NoUnusedVarsHint, AddPrefix, "bar"),
+          fix: (FIX_PREFIX_DESC, "(function z(foo) { var _bar = 33; })();"),
         }
       ],
       "(function z(foo) { z(); })();": [
@@ -1749,6 +2017,7 @@ export default <></>;
           col: 12,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "foo"),
           hint: variant!(NoUnusedVarsHint, AddPrefix, "foo"),
+          fix: (FIX_PREFIX_DESC, "(function z(_foo) { z(); })();"),
         }
       ],
       "function f() { var a = 1; return function(){ f(a = 2); }; }": [
@@ -1768,6 +2037,7 @@ export default <></>;
           col: 7,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "x"),
           hint: variant!(NoUnusedVarsHint, AddPrefix, "x"),
+          fix: (FIX_PREFIX_DESC, "import _x from \"y\";"),
         }
       ],
       "export function fn2({ x, y }) {\n console.log(x); \n};": [
@@ -1775,6 +2045,7 @@ export default <></>;
           col: 25,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "y"),
           hint: variant!(NoUnusedVarsHint, AddPrefix, "y"),
+          fix: (FIX_PREFIX_DESC, "export function fn2({ x, y: _y }) {\n console.log(x); \n};"),
         }
       ],
       "export function fn2( x, y ) {\n console.log(x); \n};": [
@@ -1782,6 +2053,7 @@ export default <></>;
           col: 24,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "y"),
           hint: variant!(NoUnusedVarsHint, AddPrefix, "y"),
+          fix: (FIX_PREFIX_DESC, "export function fn2( x, _y ) {\n console.log(x); \n};"),
         }
       ],
       "var _a; var b;": [
@@ -1789,6 +2061,7 @@ export default <></>;
           col: 12,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "b"),
           hint: variant!(NoUnusedVarsHint, AddPrefix, "b"),
+          fix: (FIX_PREFIX_DESC, "var _a; var _b;"),
         }
       ],
       "function foo(a, _b) { } foo()": [
@@ -1796,6 +2069,7 @@ export default <></>;
           col: 13,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "a"),
           hint: variant!(NoUnusedVarsHint, AddPrefix, "a"),
+          fix: (FIX_PREFIX_DESC, "function foo(_a, _b) { } foo()"),
         }
       ],
       "function foo(a, _b, c) { return a; } foo();": [
@@ -1803,6 +2077,7 @@ export default <></>;
           col: 20,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "c"),
           hint: variant!(NoUnusedVarsHint, AddPrefix, "c"),
+          fix: (FIX_PREFIX_DESC, "function foo(a, _b, _c) { return a; } foo();"),
         }
       ],
       "const data = { type: 'coords', x: 1, y: 2 };\
@@ -1811,6 +2086,7 @@ export default <></>;
           col: 52,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "type"),
           hint: variant!(NoUnusedVarsHint, AddPrefix, "type"),
+          fix: (FIX_PREFIX_DESC, "const data = { type: 'coords', x: 1, y: 2 };const { type: _type, ...coords } = data;\n console.log(coords);"),
         }
       ],
       "const data = { type: 'coords', x: 3, y: 2 };\
@@ -1819,6 +2095,7 @@ export default <></>;
           col: 61,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "coords"),
           hint: variant!(NoUnusedVarsHint, AddPrefix, "coords"),
+          fix: (FIX_PREFIX_DESC, "const data = { type: 'coords', x: 3, y: 2 };const { type, ..._coords } = data;\n console.log(type)"),
         }
       ],
       "const data = { vars: \
@@ -1828,6 +2105,7 @@ export default <></>;
           col: 61,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "x"),
           hint: variant!(NoUnusedVarsHint, AddPrefix, "x"),
+          fix: (FIX_PREFIX_DESC, "const data = { vars: ['x','y'], x: 1, y: 2 }; const { vars: [_x], ...coords } = data;\nconsole.log(coords)"),
         }
       ],
       "const data = { defaults: { x: 0 }, x: 1, y: 2 }; const { defaults: { x }, ...coords } = data;\n console.log(coords)": [
@@ -1835,6 +2113,7 @@ export default <></>;
           col: 69,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "x"),
           hint: variant!(NoUnusedVarsHint, AddPrefix, "x"),
+          fix: (FIX_PREFIX_DESC, "const data = { defaults: { x: 0 }, x: 1, y: 2 }; const { defaults: { x: _x }, ...coords } = data;\n console.log(coords)"),
         }
       ],
       "export default function(a) {}": [
@@ -1842,6 +2121,7 @@ export default <></>;
           col: 24,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "a"),
           hint: variant!(NoUnusedVarsHint, AddPrefix, "a"),
+          fix: (FIX_PREFIX_DESC, "export default function(_a) {}"),
         }
       ],
       "export default function(a, b) { console.log(a); }": [
@@ -1849,6 +2129,7 @@ export default <></>;
           col: 27,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "b"),
           hint: variant!(NoUnusedVarsHint, AddPrefix, "b"),
+          fix: (FIX_PREFIX_DESC, "export default function(a, _b) { console.log(a); }"),
         }
       ],
       "export default (function(a) {});": [
@@ -1856,6 +2137,7 @@ export default <></>;
           col: 25,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "a"),
           hint: variant!(NoUnusedVarsHint, AddPrefix, "a"),
+          fix: (FIX_PREFIX_DESC, "export default (function(_a) {});"),
         }
       ],
       "export default (function(a, b) { console.log(a); });": [
@@ -1863,6 +2145,7 @@ export default <></>;
           col: 28,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "b"),
           hint: variant!(NoUnusedVarsHint, AddPrefix, "b"),
+          fix: (FIX_PREFIX_DESC, "export default (function(a, _b) { console.log(a); });"),
         }
       ],
       "export default (a) => {};": [
@@ -1870,6 +2153,7 @@ export default <></>;
           col: 16,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "a"),
           hint: variant!(NoUnusedVarsHint, AddPrefix, "a"),
+          fix: (FIX_PREFIX_DESC, "export default (_a) => {};"),
         }
       ],
       "export default (a, b) => { console.log(a); };": [
@@ -1877,6 +2161,7 @@ export default <></>;
           col: 19,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "b"),
           hint: variant!(NoUnusedVarsHint, AddPrefix, "b"),
+          fix: (FIX_PREFIX_DESC, "export default (a, _b) => { console.log(a); };"),
         }
       ],
       "try{}catch(err){};": [
@@ -1884,6 +2169,7 @@ export default <></>;
           col: 11,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "err"),
           hint: variant!(NoUnusedVarsHint, AddPrefix, "err"),
+          fix: (FIX_REMOVE_DESC, "try{}catch {};"),
         }
       ],
       "(function ({ a }, b ) { return b; })();": [
@@ -1891,6 +2177,7 @@ export default <></>;
           col: 13,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "a"),
           hint: variant!(NoUnusedVarsHint, AddPrefix, "a"),
+          fix: (FIX_PREFIX_DESC, "(function ({ a: _a }, b ) { return b; })();"),
         }
       ],
       "(function ({ a }, { b, c } ) { return b; })();": [
@@ -1898,11 +2185,13 @@ export default <></>;
           col: 13,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "a"),
           hint: variant!(NoUnusedVarsHint, AddPrefix, "a"),
+          fix: (FIX_PREFIX_DESC, "(function ({ a: _a }, { b, c } ) { return b; })();"),
         },
         {
           col: 23,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "c"),
           hint: variant!(NoUnusedVarsHint, AddPrefix, "c"),
+          fix: (FIX_PREFIX_DESC, "(function ({ a }, { b, c: _c } ) { return b; })();"),
         }
       ],
       "var a = function() { a(); };": [
@@ -1952,6 +2241,7 @@ export default <></>;
           col: 13,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "foo"),
           hint: variant!(NoUnusedVarsHint, AddPrefix, "foo"),
+          fix: (FIX_PREFIX_DESC, "let a = 'a';\n    a = 10;\n    function _foo(){\n        a = 11;\n        a = () => {\n            a = 13\n        }\n    }"),
         }
       ],
       "let c = 'c'
@@ -1979,6 +2269,7 @@ export class Foo {}
           col: 9,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "ClassDecoratorFactory"),
           hint: variant!(NoUnusedVarsHint, Alias, "ClassDecoratorFactory"),
+          fix: (FIX_ALIAS_DESC, "\nimport { ClassDecoratorFactory as _ClassDecoratorFactory } from 'decorators';\nexport class Foo {}\n      "),
         }
       ],
       "
@@ -1991,6 +2282,7 @@ baz<Bar>();
           col: 9,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "Foo"),
           hint: variant!(NoUnusedVarsHint, Alias, "Foo"),
+          fix: (FIX_ALIAS_DESC, "\nimport { Foo as _Foo, Bar } from 'foo';\nfunction baz<Foo>() {}\nbaz<Bar>();\n      "),
         }
       ],
       "
@@ -2003,6 +2295,7 @@ console.log(a);
           col: 9,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "Nullable"),
           hint: variant!(NoUnusedVarsHint, Alias, "Nullable"),
+          fix: (FIX_ALIAS_DESC, "\nimport { Nullable as _Nullable } from 'nullable';\nconst a: string = 'hello';\nconsole.log(a);\n      "),
         }
       ],
       "
@@ -2016,6 +2309,7 @@ console.log(a);
           col: 9,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "SomeOther"),
           hint: variant!(NoUnusedVarsHint, Alias, "SomeOther"),
+          fix: (FIX_ALIAS_DESC, "\nimport { Nullable } from 'nullable';\nimport { SomeOther as _SomeOther } from 'other';\nconst a: Nullable<string> = 'hello';\nconsole.log(a);\n      "),
         }
       ],
       "
@@ -2033,6 +2327,7 @@ new A();
           col: 9,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "Another"),
           hint: variant!(NoUnusedVarsHint, Alias, "Another"),
+          fix: (FIX_ALIAS_DESC, "\nimport { Nullable } from 'nullable';\nimport { Another as _Another } from 'some';\nclass A {\n  do = (a: Nullable) => {\n    console.log(a);\n  };\n}\nnew A();\n      "),
         }
       ],
       "
@@ -2050,6 +2345,7 @@ new A();
           col: 9,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "Another"),
           hint: variant!(NoUnusedVarsHint, Alias, "Another"),
+          fix: (FIX_ALIAS_DESC, "\nimport { Nullable } from 'nullable';\nimport { Another as _Another } from 'some';\nclass A {\n  do(a: Nullable) {\n    console.log(a);\n  }\n}\nnew A();\n        "),
         }
       ],
       "
@@ -2067,6 +2363,7 @@ new A();
           col: 9,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "Another"),
           hint: variant!(NoUnusedVarsHint, Alias, "Another"),
+          fix: (FIX_ALIAS_DESC, "\nimport { Nullable } from 'nullable';\nimport { Another as _Another } from 'some';\nclass A {\n  do(): Nullable {\n    return null;\n  }\n}\nnew A();\n      "),
         }
       ],
       "
@@ -2081,6 +2378,7 @@ interface A {
           col: 9,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "Another"),
           hint: variant!(NoUnusedVarsHint, Alias, "Another"),
+          fix: (FIX_ALIAS_DESC, "\nimport { Nullable } from 'nullable';\nimport { Another as _Another } from 'some';\ninterface A {\n  do(a: Nullable);\n}\n      "),
         }
       ],
       "
@@ -2095,6 +2393,7 @@ interface A {
           col: 9,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "Another"),
           hint: variant!(NoUnusedVarsHint, Alias, "Another"),
+          fix: (FIX_ALIAS_DESC, "\nimport { Nullable } from 'nullable';\nimport { Another as _Another } from 'some';\ninterface A {\n  other: Nullable;\n}\n        "),
         }
       ],
       "
@@ -2109,6 +2408,7 @@ foo();
           col: 9,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "Nullable"),
           hint: variant!(NoUnusedVarsHint, Alias, "Nullable"),
+          fix: (FIX_ALIAS_DESC, "\nimport { Nullable as _Nullable } from 'nullable';\nfunction foo(a: string) {\n  console.log(a);\n}\nfoo();\n        "),
         }
       ],
       "
@@ -2123,6 +2423,7 @@ foo();
           col: 9,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "Nullable"),
           hint: variant!(NoUnusedVarsHint, Alias, "Nullable"),
+          fix: (FIX_ALIAS_DESC, "\nimport { Nullable as _Nullable } from 'nullable';\nfunction foo(): string | null {\n  return null;\n}\nfoo();\n        "),
         }
       ],
       "
@@ -2139,6 +2440,7 @@ new A();
           col: 9,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "SomeOther"),
           hint: variant!(NoUnusedVarsHint, Alias, "SomeOther"),
+          fix: (FIX_ALIAS_DESC, "\nimport { Nullable } from 'nullable';\nimport { SomeOther as _SomeOther } from 'some';\nimport { Another } from 'some';\nclass A extends Nullable {\n  other: Nullable<Another>;\n}\nnew A();\n        "),
         }
       ],
       "
@@ -2155,6 +2457,7 @@ new A();
           col: 9,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "SomeOther"),
           hint: variant!(NoUnusedVarsHint, Alias, "SomeOther"),
+          fix: (FIX_ALIAS_DESC, "\nimport { Nullable } from 'nullable';\nimport { SomeOther as _SomeOther } from 'some';\nimport { Another } from 'some';\nabstract class A extends Nullable {\n  other: Nullable<Another>;\n}\nnew A();\n        "),
         }
       ],
       "
@@ -2168,6 +2471,7 @@ enum FormFieldIds {
           col: 5,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "FormFieldIds"),
           hint: variant!(NoUnusedVarsHint, AddPrefix, "FormFieldIds"),
+          fix: (FIX_PREFIX_DESC, "\nenum _FormFieldIds {\n  PHONE = 'phone',\n  EMAIL = 'email',\n}\n        "),
         }
       ],
       "
@@ -2180,6 +2484,7 @@ export interface Bar extends baz.test {}
           col: 7,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "test"),
           hint: variant!(NoUnusedVarsHint, AddPrefix, "test"),
+          fix: (FIX_PREFIX_DESC, "\nimport _test from 'test';\nimport baz from 'baz';\nexport interface Bar extends baz.test {}\n        "),
         }
       ],
       "
@@ -2192,6 +2497,7 @@ foo(a);
           col: 7,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "React"),
           hint: variant!(NoUnusedVarsHint, AddPrefix, "React"),
+          fix: (FIX_PREFIX_DESC, "\nimport _React from './dummy.ts';\nconst a = 42;\nfoo(a);\n      "),
         }
       ],
 
@@ -2202,6 +2508,7 @@ foo(a);
           col: 12,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "foo"),
           hint: variant!(NoUnusedVarsHint, AddPrefix, "foo"),
+          fix: (FIX_PREFIX_DESC, r#"import * as _foo from "./foo.ts";"#),
         }
       ],
 
@@ -2211,6 +2518,7 @@ foo(a);
           col: 6,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "fn"),
           hint: variant!(NoUnusedVarsHint, AddPrefix, "fn"),
+          fix: (FIX_PREFIX_DESC, "const _fn = function foo() { foo(); };"),
         }
       ],
 
@@ -2220,6 +2528,7 @@ foo(a);
           col: 11,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "x"),
           hint: variant!(NoUnusedVarsHint, AddPrefix, "x"),
+          fix: (FIX_PREFIX_DESC, "for (const _x of xs) {}"),
         }
       ],
       "let x; for (x of xs) {}": [
@@ -2234,6 +2543,7 @@ foo(a);
           col: 17,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "x"),
           hint: variant!(NoUnusedVarsHint, AddPrefix, "x"),
+          fix: (FIX_PREFIX_DESC, "for await (const _x of xs) {}"),
         }
       ],
       "let x; for await (x of xs) {}": [
@@ -2248,6 +2558,7 @@ foo(a);
           col: 11,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "x"),
           hint: variant!(NoUnusedVarsHint, AddPrefix, "x"),
+          fix: (FIX_PREFIX_DESC, "for (const _x in xs) {}"),
         }
       ],
       "let x; for (x in xs) {}": [
@@ -2262,11 +2573,13 @@ foo(a);
           col: 12,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "x1"),
           hint: variant!(NoUnusedVarsHint, AddPrefix, "x1"),
+          fix: (FIX_PREFIX_DESC, "for (const [_x1, x2] of xs) {}"),
         },
         {
           col: 16,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "x2"),
           hint: variant!(NoUnusedVarsHint, AddPrefix, "x2"),
+          fix: (FIX_PREFIX_DESC, "for (const [x1, _x2] of xs) {}"),
         }
       ],
       "let x1, x2; for ([x1, x2] of xs) { console.log(x1); }": [
@@ -2281,11 +2594,13 @@ foo(a);
           col: 13,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "x1"),
           hint: variant!(NoUnusedVarsHint, AddPrefix, "x1"),
+          fix: (FIX_PREFIX_DESC, "for (const { x1: _x1, x2 } of xs) {}"),
         },
         {
           col: 17,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "x2"),
           hint: variant!(NoUnusedVarsHint, AddPrefix, "x2"),
+          fix: (FIX_PREFIX_DESC, "for (const { x1, x2: _x2 } of xs) {}"),
         }
       ],
       "let x1, x2; for ({ x1, x2 } of xs) { console.log(x2); }": [
@@ -2310,6 +2625,15 @@ export class Foo {
             line:5,
             message: variant!(NoUnusedVarsMessage, NeverUsed, "value"),
             hint: variant!(NoUnusedVarsHint, AddPrefix, "value"),
+            fix: (FIX_PREFIX_DESC, r#"
+export class Foo {
+  #myFunction(value: string): string;
+  #myFunction(value: number): number;
+  #myFunction(_value: string | number) {
+    return 42;
+  }
+}
+        "#),
           },
         ],
         "import * as deps from './test.js';\nimport Test = deps.test;": [
@@ -2318,13 +2642,14 @@ export class Foo {
             line: 2,
             message: variant!(NoUnusedVarsMessage, NeverUsed, "Test"),
             hint: variant!(NoUnusedVarsHint, AddPrefix, "Test"),
+            fix: (FIX_PREFIX_DESC, "import * as deps from './test.js';\nimport _Test = deps.test;"),
           },
         ],
     };
 
     // jsx/tsx
     assert_lint_err! {
-      NoUnusedVars,
+      NoUnusedVars::default(),
       filename: "file:///foo.tsx",
       r#"
 import React from 'react';
@@ -2336,6 +2661,11 @@ export const Foo = () => {
           col: 7,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "React"),
           hint: variant!(NoUnusedVarsHint, AddPrefix, "React"),
+          fix: (FIX_PREFIX_DESC, r#"
+import _React from 'react';
+export const Foo = () => {
+  return "string";
+}"#),
         }
       ],
       r#"
@@ -2349,6 +2679,12 @@ export const Foo = () => {
           col: 9,
           message: variant!(NoUnusedVarsMessage, NeverUsed, "h"),
           hint: variant!(NoUnusedVarsHint, Alias, "h"),
+          fix: (FIX_ALIAS_DESC, r#"
+/** @jsx h */ /** @jsxFrag Fragment */
+import { h as _h, Fragment } from "preact";
+export const Foo = () => {
+  return <></>;
+}"#),
         }
       ]
     }
@@ -2359,7 +2695,7 @@ export const Foo = () => {
   #[ignore = "control flow analysis is not implemented yet"]
   fn no_unused_vars_err_for_loop_control_flow() {
     assert_lint_err! {
-      NoUnusedVars,
+      NoUnusedVars::default(),
       "(function(obj) { var name; for ( name in obj ) { i(); return; } })({});": [
         {
           col: 21,
@@ -2389,7 +2725,7 @@ export const Foo = () => {
   #[ignore = "control flow analysis is not implemented yet"]
   fn no_unused_vars_err_assign_expr() {
     assert_lint_err! {
-      NoUnusedVars,
+      NoUnusedVars::default(),
       "var a = 0; a = a + 1;": [
         {
           col: 4,
@@ -2468,7 +2804,7 @@ export const Foo = () => {
   #[ignore = "control flow analysis is not implemented yet"]
   fn no_unused_vars_err_assign_to_self() {
     assert_lint_err! {
-      NoUnusedVars,
+      NoUnusedVars::default(),
       "function foo(cb) { cb = function(a) { cb(1 + a); }; bar(not_cb); } foo();": [
         {
           col: 13,
@@ -2504,7 +2840,7 @@ export const Foo = () => {
   #[ignore = "pure method analysis is not implemented yet"]
   fn no_unused_vars_err_array_methods() {
     assert_lint_err! {
-      NoUnusedVars,
+      NoUnusedVars::default(),
       "let myArray = [1,2,3,4].filter((x) => x == 0); myArray = myArray.filter((x) => x == 1);": [
         {
           col: 4,
@@ -2519,7 +2855,7 @@ export const Foo = () => {
   #[ignore = "swc cannot parse this at the moment"]
   fn no_unused_vars_ts_err_06() {
     assert_lint_err! {
-      NoUnusedVars,
+      NoUnusedVars::default(),
       "
 import test from 'test';
 import baz from 'baz';
@@ -2563,7 +2899,7 @@ export class Bar implements baz().test {}
   #[ignore = "typescript property analysis is not implemented yet"]
   fn no_unused_vars_ts_ok_12() {
     assert_lint_ok! {
-      NoUnusedVars,
+      NoUnusedVars::default(),
       "
 export class App {
   constructor(private logger: Logger) {
@@ -2605,4 +2941,99 @@ export class App {
       ",
     };
   }
+
+  #[test]
+  fn no_unused_vars_options() {
+    let options: NoUnusedVarsOptions = serde_json::from_str(
+      r#"{
+        "argsIgnorePattern": "^unused",
+        "varsIgnorePattern": "^ignored",
+        "caughtErrors": "none",
+        "ignoreRestSiblings": true
+      }"#,
+    )
+    .unwrap();
+    assert_eq!(options.caught_errors, CaughtErrors::None);
+
+    assert_lint_ok! {
+      NoUnusedVars::new(options.clone()).unwrap(),
+      "function foo(unusedArg) {} foo();",
+      "const ignoredVar = 1;",
+      r#"import { ignoredImport } from "./a.ts";"#,
+      "try {} catch (err) {}",
+      "const { a, ...rest } = obj; console.log(rest);",
+      "const { a: b, c = 1, ...rest } = obj; console.log(rest);",
+    };
+
+    assert_lint_err! {
+      NoUnusedVars::new(options.clone()).unwrap(),
+      "function foo(ignoredArg, unusedArg) {} foo();": [
+        {
+          col: 13,
+          message: variant!(NoUnusedVarsMessage, NeverUsed, "ignoredArg"),
+          hint: variant!(NoUnusedVarsHint, AddPrefix, "ignoredArg"),
+          fix: (FIX_PREFIX_DESC, "function foo(_ignoredArg, unusedArg) {} foo();"),
+        }
+      ],
+      "const { a, b: { c } } = obj;": [
+        {
+          col: 8,
+          message: variant!(NoUnusedVarsMessage, NeverUsed, "a"),
+          hint: variant!(NoUnusedVarsHint, AddPrefix, "a"),
+          fix: (FIX_PREFIX_DESC, "const { a: _a, b: { c } } = obj;"),
+        },
+        {
+          col: 16,
+          message: variant!(NoUnusedVarsMessage, NeverUsed, "c"),
+          hint: variant!(NoUnusedVarsHint, AddPrefix, "c"),
+          fix: (FIX_PREFIX_DESC, "const { a, b: { c: _c } } = obj;"),
+        }
+      ],
+    };
+
+    assert!(NoUnusedVars::new(NoUnusedVarsOptions {
+      vars_ignore_pattern: Some("(".to_string()),
+      ..Default::default()
+    })
+    .is_err());
+  }
+
+  #[test]
+  fn no_unused_vars_fixes() {
+    assert_lint_err! {
+      NoUnusedVars::default(),
+      r#"import { a as b } from "./a.ts";"#: [
+        {
+          col: 14,
+          message: variant!(NoUnusedVarsMessage, NeverUsed, "b"),
+          hint: variant!(NoUnusedVarsHint, Alias, "b"),
+          fix: (FIX_ALIAS_DESC, r#"import { a as _b } from "./a.ts";"#),
+        }
+      ],
+      "try { foo(); } catch ({ message }) {}": [
+        {
+          col: 24,
+          message: variant!(NoUnusedVarsMessage, NeverUsed, "message"),
+          hint: variant!(NoUnusedVarsHint, AddPrefix, "message"),
+          fix: (FIX_PREFIX_DESC, "try { foo(); } catch ({ message: _message }) {}"),
+        }
+      ],
+      "try { foo(); } catch (err) { err = 1; }": [
+        {
+          col: 22,
+          message: variant!(NoUnusedVarsMessage, NeverUsed, "err"),
+          hint: variant!(NoUnusedVarsHint, AddPrefix, "err"),
+          fix: (FIX_REMOVE_DESC, "try { foo(); } catch { err = 1; }"),
+        }
+      ],
+      // Renaming `a` would leave the assignment to the old name.
+      "function foo(a) { a = 1; } foo();": [
+        {
+          col: 13,
+          message: variant!(NoUnusedVarsMessage, NeverUsed, "a"),
+          hint: variant!(NoUnusedVarsHint, AddPrefix, "a"),
+        }
+      ],
+    };
+  }
 }
@@ -0,0 +1,291 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::Program;
+
+use deno_ast::SourceRange;
+use derive_more::Display;
+use serde::Deserialize;
+
+#[derive(Debug, Default)]
+pub struct NoUnversionedRemoteImports {
+  options: NoUnversionedRemoteImportsOptions,
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct NoUnversionedRemoteImportsOptions {
+  /// Specifier prefixes that are allowed to be unversioned, e.g. `"npm:"` to
+  /// ignore all npm packages or `"https://deno.land/x/my_lib"` for a single
+  /// module.
+  pub ignore: Vec<String>,
+}
+
+impl NoUnversionedRemoteImports {
+  pub fn new(options: NoUnversionedRemoteImportsOptions) -> Self {
+    Self { options }
+  }
+}
+
+const CODE: &str = "no-unversioned-remote-imports";
+
+#[derive(Display)]
+enum NoUnversionedRemoteImportsMessage {
+  #[display(fmt = "The specifier `{}` doesn't specify a version", _0)]
+  Unversioned(String),
+}
+
+#[derive(Display)]
+enum NoUnversionedRemoteImportsHint {
+  #[display(
+    fmt = "Add a version or semver range so that the same code is resolved every time, e.g. `{}`",
+    _0
+  )]
+  AddVersion(String),
+}
+
+impl LintRule for NoUnversionedRemoteImports {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "ignore": { "type": "array", "items": { "type": "string" } }
+      }
+    }))
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    _program: Program,
+  ) {
+    for (specifier, range) in context.module_graph().import_specifiers() {
+      self.check_specifier(specifier, range, context);
+    }
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_unversioned_remote_imports.md")
+  }
+}
+
+/// Finds where a version has to be added to the package name at the start of
+/// `rest`, along with the text to insert there. Returns `None` if the package
+/// already has a version. `scoped` is whether package names can be scoped,
+/// like `@std/path`.
+fn missing_version(rest: &str, scoped: bool) -> Option<(usize, &'static str)> {
+  let mut segments = rest.split('/');
+  let first = segments.next()?;
+  let name_end = if scoped && first.starts_with('@') {
+    first.len() + 1 + segments.next()?.len()
+  } else {
+    first.len()
+  };
+  // The `@` of a scope is not a version separator.
+  let name_start = if rest.starts_with('@') { 1 } else { 0 };
+  let name = &rest[name_start..name_end];
+  match name.split_once('@') {
+    Some((_, "")) => Some((name_end, "<version>")),
+    Some(_) => None,
+    None if name.is_empty() => None,
+    None => Some((name_end, "@<version>")),
+  }
+}
+
+/// Returns the specifier with a `<version>` placeholder added if it refers to
+/// a known registry and has no version.
+fn unversioned_suggestion(specifier: &str) -> Option<String> {
+  let (prefix_len, scoped) =
+    if let Some(rest) = specifier.strip_prefix("https://deno.land/x/") {
+      (specifier.len() - rest.len(), false)
+    } else if specifier == "https://deno.land/std"
+      || specifier.starts_with("https://deno.land/std/")
+      || specifier.starts_with("https://deno.land/std@")
+    {
+      ("https://deno.land/".len(), false)
+    } else if let Some(rest) = specifier
+      .strip_prefix("jsr:")
+      .or_else(|| specifier.strip_prefix("npm:"))
+    {
+      (specifier.len() - rest.trim_start_matches('/').len(), true)
+    } else {
+      return None;
+    };
+
+  let (index, placeholder) = missing_version(&specifier[prefix_len..], scoped)?;
+  let mut suggestion = specifier.to_string();
+  suggestion.insert_str(prefix_len + index, placeholder);
+  Some(suggestion)
+}
+
+impl NoUnversionedRemoteImports {
+  fn check_specifier(
+    &self,
+    specifier: &str,
+    range: SourceRange,
+    ctx: &mut Context,
+  ) {
+    if self
+      .options
+      .ignore
+      .iter()
+      .any(|prefix| specifier.starts_with(prefix.as_str()))
+    {
+      return;
+    }
+    if let Some(suggestion) = unversioned_suggestion(specifier) {
+      ctx.add_diagnostic_with_hint(
+        range,
+        CODE,
+        NoUnversionedRemoteImportsMessage::Unversioned(specifier.to_string()),
+        NoUnversionedRemoteImportsHint::AddVersion(suggestion),
+      );
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_unversioned_remote_imports_valid() {
+    assert_lint_ok! {
+      NoUnversionedRemoteImports::default(),
+      r#"import { serve } from "https://deno.land/x/oak@v12.6.1/mod.ts";"#,
+      r#"import { join } from "https://deno.land/std@0.224.0/path/mod.ts";"#,
+      r#"import { join } from "jsr:@std/path@^1.0.0";"#,
+      r#"import { join } from "jsr:@std/path@1/join";"#,
+      r#"import chalk from "npm:chalk@5";"#,
+      r#"import express from "npm:@types/express@^4.17";"#,
+      r#"import foo from "./foo.ts";"#,
+      r#"import foo from "https://example.com/foo.ts";"#,
+      r#"import fs from "node:fs";"#,
+      r#"const mod = await import("jsr:@std/path@1");"#,
+    };
+
+    assert_lint_ok! {
+      NoUnversionedRemoteImports::new(NoUnversionedRemoteImportsOptions {
+        ignore: vec!["npm:".to_string(), "jsr:@std/".to_string()],
+      }),
+      r#"import chalk from "npm:chalk";"#,
+      r#"import { join } from "jsr:@std/path";"#,
+    };
+  }
+
+  #[test]
+  fn no_unversioned_remote_imports_invalid() {
+    assert_lint_err! {
+      NoUnversionedRemoteImports::default(),
+      r#"import { serve } from "https://deno.land/x/oak/mod.ts";"#: [
+        {
+          col: 22,
+          message: NoUnversionedRemoteImportsMessage::Unversioned(
+            "https://deno.land/x/oak/mod.ts".to_string()
+          ),
+          hint: NoUnversionedRemoteImportsHint::AddVersion(
+            "https://deno.land/x/oak@<version>/mod.ts".to_string()
+          ),
+        }
+      ],
+      r#"import { join } from "https://deno.land/std/path/mod.ts";"#: [
+        {
+          col: 21,
+          message: NoUnversionedRemoteImportsMessage::Unversioned(
+            "https://deno.land/std/path/mod.ts".to_string()
+          ),
+          hint: NoUnversionedRemoteImportsHint::AddVersion(
+            "https://deno.land/std@<version>/path/mod.ts".to_string()
+          ),
+        }
+      ],
+      r#"import { join } from "jsr:@std/path";"#: [
+        {
+          col: 21,
+          message: NoUnversionedRemoteImportsMessage::Unversioned(
+            "jsr:@std/path".to_string()
+          ),
+          hint: NoUnversionedRemoteImportsHint::AddVersion(
+            "jsr:@std/path@<version>".to_string()
+          ),
+        }
+      ],
+      r#"import { join } from "jsr:@std/path/join";"#: [
+        {
+          col: 21,
+          message: NoUnversionedRemoteImportsMessage::Unversioned(
+            "jsr:@std/path/join".to_string()
+          ),
+          hint: NoUnversionedRemoteImportsHint::AddVersion(
+            "jsr:@std/path@<version>/join".to_string()
+          ),
+        }
+      ],
+      r#"export * from "npm:chalk";"#: [
+        {
+          col: 14,
+          message: NoUnversionedRemoteImportsMessage::Unversioned(
+            "npm:chalk".to_string()
+          ),
+          hint: NoUnversionedRemoteImportsHint::AddVersion(
+            "npm:chalk@<version>".to_string()
+          ),
+        }
+      ],
+      r#"import express from "npm:@types/express@";"#: [
+        {
+          col: 20,
+          message: NoUnversionedRemoteImportsMessage::Unversioned(
+            "npm:@types/express@".to_string()
+          ),
+          hint: NoUnversionedRemoteImportsHint::AddVersion(
+            "npm:@types/express@<version>".to_string()
+          ),
+        }
+      ],
+      r#"const mod = await import("npm:lodash/fp");"#: [
+        {
+          col: 25,
+          message: NoUnversionedRemoteImportsMessage::Unversioned(
+            "npm:lodash/fp".to_string()
+          ),
+          hint: NoUnversionedRemoteImportsHint::AddVersion(
+            "npm:lodash@<version>/fp".to_string()
+          ),
+        }
+      ],      r#"import fs = require("npm:fs-extra");"#: [
+        {
+          col: 20,
+          message: NoUnversionedRemoteImportsMessage::Unversioned(
+            "npm:fs-extra".to_string()
+          ),
+          hint: NoUnversionedRemoteImportsHint::AddVersion(
+            "npm:fs-extra@<version>".to_string()
+          ),
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoUnversionedRemoteImports::new(NoUnversionedRemoteImportsOptions {
+        ignore: vec!["npm:".to_string()],
+      }),
+      r#"import { join } from "jsr:@std/path";"#: [
+        {
+          col: 21,
+          message: NoUnversionedRemoteImportsMessage::Unversioned(
+            "jsr:@std/path".to_string()
+          ),
+          hint: NoUnversionedRemoteImportsHint::AddVersion(
+            "jsr:@std/path@<version>".to_string()
+          ),
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,256 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::Handler;
+use crate::syntax_features::SyntaxFeatures;
+
+use deno_ast::swc::ast::{
+  Accessibility, Callee, ClassMember, Constructor, Expr, ExprOrSpread,
+  ParamOrTsParamProp, Pat, Stmt,
+};
+use deno_ast::view::{Class, NodeKind};
+use deno_ast::SourceRangedForSpanned;
+use derive_more::Display;
+
+#[derive(Debug)]
+pub struct NoUselessConstructor;
+
+const CODE: &str = "no-useless-constructor";
+
+#[derive(Display)]
+enum NoUselessConstructorMessage {
+  #[display(fmt = "Useless constructor")]
+  Useless,
+}
+
+#[derive(Display)]
+enum NoUselessConstructorHint {
+  #[display(
+    fmt = "Remove the constructor, since the default constructor does the same"
+  )]
+  Remove,
+}
+
+const FIX_DESC: &str = "Remove the constructor";
+
+impl LintRule for NoUselessConstructor {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn required_syntax(&self) -> SyntaxFeatures {
+    SyntaxFeatures::CLASS
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoUselessConstructorHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::Class])
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_useless_constructor.md")
+  }
+}
+
+struct NoUselessConstructorHandler;
+
+impl Handler for NoUselessConstructorHandler {
+  fn class(&mut self, class: &Class, ctx: &mut Context) {
+    let is_derived = class.inner.super_class.is_some();
+    for member in &class.inner.body {
+      let ClassMember::Constructor(constructor) = member else {
+        continue;
+      };
+      if !is_useless(constructor, is_derived) {
+        continue;
+      }
+      ctx.add_diagnostic_with_fixes(
+        constructor.range(),
+        CODE,
+        NoUselessConstructorMessage::Useless,
+        Some(NoUselessConstructorHint::Remove.to_string()),
+        vec![LintFix {
+          description: FIX_DESC.into(),
+          changes: vec![LintFixChange {
+            new_text: "".into(),
+            range: constructor.range(),
+          }],
+        }],
+      );
+    }
+  }
+}
+
+/// Whether the constructor does the same as the default constructor, which
+/// is empty, or only calls `super` with all its arguments in derived classes.
+fn is_useless(constructor: &Constructor, is_derived: bool) -> bool {
+  let Some(body) = &constructor.body else {
+    return false;
+  };
+  // Private and protected constructors prevent instantiating the class from
+  // outside of it.
+  if matches!(
+    constructor.accessibility,
+    Some(Accessibility::Private | Accessibility::Protected)
+  ) {
+    return false;
+  }
+  // Parameter properties and decorated parameters do something.
+  let has_side_effects = constructor.params.iter().any(|param| match param {
+    ParamOrTsParamProp::TsParamProp(_) => true,
+    ParamOrTsParamProp::Param(param) => !param.decorators.is_empty(),
+  });
+  if has_side_effects {
+    return false;
+  }
+
+  if !is_derived {
+    return body.stmts.is_empty();
+  }
+  let [Stmt::Expr(expr_stmt)] = body.stmts.as_slice() else {
+    return false;
+  };
+  let Expr::Call(call) = &*expr_stmt.expr else {
+    return false;
+  };
+  if !matches!(call.callee, Callee::Super(_)) {
+    return false;
+  }
+  passes_arguments_through(&constructor.params, &call.args)
+}
+
+/// Whether the arguments are the parameters in the same order, like
+/// `super(a, ...rest)` for `(a, ...rest)`, or `super(...arguments)`.
+fn passes_arguments_through(
+  params: &[ParamOrTsParamProp],
+  args: &[ExprOrSpread],
+) -> bool {
+  if let [ExprOrSpread {
+    spread: Some(_),
+    expr,
+  }] = args
+  {
+    if matches!(&**expr, Expr::Ident(ident) if ident.sym == *"arguments") {
+      return true;
+    }
+  }
+
+  params.len() == args.len()
+    && params.iter().zip(args).all(|(param, arg)| {
+      let ParamOrTsParamProp::Param(param) = param else {
+        return false;
+      };
+      let (ident, is_rest) = match &param.pat {
+        Pat::Ident(binding) => (&binding.id, false),
+        Pat::Rest(rest) => match &*rest.arg {
+          Pat::Ident(binding) => (&binding.id, true),
+          _ => return false,
+        },
+        _ => return false,
+      };
+      arg.spread.is_some() == is_rest
+        && matches!(&*arg.expr, Expr::Ident(arg) if arg.sym == ident.sym)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_useless_constructor_valid() {
+    assert_lint_ok! {
+      NoUselessConstructor,
+      "class A {}",
+      "class A { constructor() { doSomething(); } }",
+      "class A extends B { constructor() {} }",
+      "class A extends B { constructor() { super('foo'); } }",
+      "class A extends B { constructor(a, b) { super(b, a); } }",
+      "class A extends B { constructor(a, b) { super(a); } }",
+      "class A extends B { constructor(a) { super(...a); } }",
+      "class A extends B { constructor(...args) { super(args); } }",
+      "class A extends B { constructor(a = 1) { super(a); } }",
+      "class A extends B { constructor(a) { super(a); doSomething(); } }",
+      "class A extends B { constructor() { foo(); } }",
+      "class A { private constructor() {} }",
+      "class A extends B { protected constructor() { super(); } }",
+      "class A { constructor(private a: string) {} }",
+      "class A extends B { constructor(readonly a: string) { super(a); } }",
+      "class A { constructor(@Inject(TOKEN) a) {} }",
+      "class A { constructor(); constructor(a?) { this.a = a; } }",
+    };
+  }
+
+  #[test]
+  fn no_useless_constructor_invalid() {
+    assert_lint_err! {
+      NoUselessConstructor,
+      "class A { constructor() {} }": [
+        {
+          col: 10,
+          message: NoUselessConstructorMessage::Useless,
+          hint: NoUselessConstructorHint::Remove,
+          fix: (FIX_DESC, "class A {  }"),
+        }
+      ],
+      "class A { public constructor() {} }": [
+        {
+          col: 10,
+          message: NoUselessConstructorMessage::Useless,
+          hint: NoUselessConstructorHint::Remove,
+          fix: (FIX_DESC, "class A {  }"),
+        }
+      ],
+      "class A extends B { constructor() { super(); } }": [
+        {
+          col: 20,
+          message: NoUselessConstructorMessage::Useless,
+          hint: NoUselessConstructorHint::Remove,
+          fix: (FIX_DESC, "class A extends B {  }"),
+        }
+      ],
+      "class A extends B { constructor(a: string, b: number) { super(a, b); } }": [
+        {
+          col: 20,
+          message: NoUselessConstructorMessage::Useless,
+          hint: NoUselessConstructorHint::Remove,
+          fix: (FIX_DESC, "class A extends B {  }"),
+        }
+      ],
+      "class A extends B { constructor(a, ...rest) { super(a, ...rest); } }": [
+        {
+          col: 20,
+          message: NoUselessConstructorMessage::Useless,
+          hint: NoUselessConstructorHint::Remove,
+          fix: (FIX_DESC, "class A extends B {  }"),
+        }
+      ],
+      "class A extends B { constructor(a) { super(...arguments); } }": [
+        {
+          col: 20,
+          message: NoUselessConstructorMessage::Useless,
+          hint: NoUselessConstructorHint::Remove,
+          fix: (FIX_DESC, "class A extends B {  }"),
+        }
+      ],
+      "const A = class extends B {\n  foo() {}\n  constructor() {\n    super();\n  }\n};": [
+        {
+          line: 3,
+          col: 2,
+          message: NoUselessConstructorMessage::Useless,
+          hint: NoUselessConstructorHint::Remove,
+          fix: (FIX_DESC, "const A = class extends B {\n  foo() {}\n  \n};"),
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,154 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::Handler;
+
+use deno_ast::view::{Module, ModuleDecl, ModuleItem, NamedExport, NodeKind};
+use deno_ast::SourceRanged;
+use derive_more::Display;
+
+#[derive(Debug)]
+pub struct NoUselessEmptyExport;
+
+const CODE: &str = "no-useless-empty-export";
+
+#[derive(Display)]
+enum NoUselessEmptyExportMessage {
+  #[display(
+    fmt = "Empty export has no effect since the file already is a module"
+  )]
+  Useless,
+}
+
+#[derive(Display)]
+enum NoUselessEmptyExportHint {
+  #[display(fmt = "Remove the empty export")]
+  Remove,
+}
+
+const FIX_DESC: &str = "Remove the empty export";
+
+impl LintRule for NoUselessEmptyExport {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoUselessEmptyExportHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::Module])
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_useless_empty_export.md")
+  }
+}
+
+/// Whether the item is `export {}`.
+fn as_empty_export<'a>(item: &ModuleItem<'a>) -> Option<&'a NamedExport<'a>> {
+  match item {
+    ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(named_export))
+      if named_export.specifiers.is_empty() && named_export.src.is_none() =>
+    {
+      Some(named_export)
+    }
+    _ => None,
+  }
+}
+
+struct NoUselessEmptyExportHandler;
+
+impl Handler for NoUselessEmptyExportHandler {
+  fn module(&mut self, module: &Module, ctx: &mut Context) {
+    // `export {}` turns a script into a module, which is only needed if there
+    // are no other imports or exports.
+    let has_other_module_decl = module.body.iter().any(|item| {
+      matches!(item, ModuleItem::ModuleDecl(_))
+        && as_empty_export(item).is_none()
+    });
+    if !has_other_module_decl {
+      return;
+    }
+
+    for named_export in module.body.iter().filter_map(as_empty_export) {
+      ctx.add_diagnostic_with_fixes(
+        named_export.range(),
+        CODE,
+        NoUselessEmptyExportMessage::Useless,
+        Some(NoUselessEmptyExportHint::Remove.to_string()),
+        vec![LintFix {
+          description: FIX_DESC.into(),
+          changes: vec![LintFixChange {
+            new_text: "".into(),
+            range: named_export.range(),
+          }],
+        }],
+      );
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_useless_empty_export_valid() {
+    assert_lint_ok! {
+      NoUselessEmptyExport,
+      "export {};",
+      "const foo = 1; export {};",
+      "export { foo };",
+      "export {} from 'foo'; export const bar = 1;",
+      "declare module 'foo' {}",
+    };
+  }
+
+  #[test]
+  fn no_useless_empty_export_invalid() {
+    assert_lint_err! {
+      NoUselessEmptyExport,
+      "export const foo = 1; export {};": [
+        {
+          col: 22,
+          message: NoUselessEmptyExportMessage::Useless,
+          hint: NoUselessEmptyExportHint::Remove,
+          fix: (FIX_DESC, "export const foo = 1; "),
+        }
+      ],
+      "import foo from 'foo';\nexport {};\nfoo();": [
+        {
+          line: 2,
+          col: 0,
+          message: NoUselessEmptyExportMessage::Useless,
+          hint: NoUselessEmptyExportHint::Remove,
+          fix: (FIX_DESC, "import foo from 'foo';\n\nfoo();"),
+        }
+      ],
+      "export {};\nexport default function foo() {}": [
+        {
+          col: 0,
+          message: NoUselessEmptyExportMessage::Useless,
+          hint: NoUselessEmptyExportHint::Remove,
+          fix: (FIX_DESC, "\nexport default function foo() {}"),
+        }
+      ],
+      "import type { Foo } from './foo.ts'; export {};": [
+        {
+          col: 37,
+          message: NoUselessEmptyExportMessage::Useless,
+          hint: NoUselessEmptyExportHint::Remove,
+          fix: (FIX_DESC, "import type { Foo } from './foo.ts'; "),
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,138 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::handler::Handler;
+use crate::syntax_features::SyntaxFeatures;
+use deno_ast::swc::ast::{Expr, Function as SwcFunction, Stmt, YieldExpr};
+use deno_ast::swc::visit::{noop_visit_type, Visit, VisitWith};
+use deno_ast::view::{Function, NodeKind};
+use deno_ast::SourceRanged;
+
+#[derive(Debug)]
+pub struct NoUselessGenerators;
+
+const CODE: &str = "no-useless-generators";
+const MESSAGE: &str =
+  "Async generator function only yields a single awaited value at its end";
+const HINT: &str =
+  "Use an async function that returns the value instead of yielding it";
+
+impl LintRule for NoUselessGenerators {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn required_syntax(&self) -> SyntaxFeatures {
+    SyntaxFeatures::GENERATOR
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoUselessGeneratorsHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::Function])
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_useless_generators.md")
+  }
+}
+
+/// Counts the `yield`s of a function, without the ones of nested functions.
+#[derive(Default)]
+struct YieldCounter {
+  count: usize,
+}
+
+impl Visit for YieldCounter {
+  noop_visit_type!();
+
+  fn visit_yield_expr(&mut self, yield_expr: &YieldExpr) {
+    self.count += 1;
+    yield_expr.visit_children_with(self);
+  }
+
+  fn visit_function(&mut self, _function: &SwcFunction) {}
+}
+
+/// Whether the statement is `yield await value;`.
+fn is_yield_of_await(stmt: &Stmt) -> bool {
+  let Stmt::Expr(expr_stmt) = stmt else {
+    return false;
+  };
+  let Expr::Yield(yield_expr) = &*expr_stmt.expr else {
+    return false;
+  };
+  if yield_expr.delegate {
+    return false;
+  }
+  let mut arg = match &yield_expr.arg {
+    Some(arg) => &**arg,
+    None => return false,
+  };
+  while let Expr::Paren(paren) = arg {
+    arg = &paren.expr;
+  }
+  matches!(arg, Expr::Await(_))
+}
+
+struct NoUselessGeneratorsHandler;
+
+impl Handler for NoUselessGeneratorsHandler {
+  fn function(&mut self, function: &Function, ctx: &mut Context) {
+    // Generators without any `yield` are reported by `require-yield`.
+    if !function.is_async() || !function.is_generator() {
+      return;
+    }
+    let Some(body) = &function.inner.body else {
+      return;
+    };
+    if !body.stmts.last().is_some_and(is_yield_of_await) {
+      return;
+    }
+    let mut counter = YieldCounter::default();
+    body.visit_children_with(&mut counter);
+    if counter.count == 1 {
+      ctx.add_diagnostic_with_hint(function.range(), CODE, MESSAGE, HINT);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_useless_generators_valid() {
+    assert_lint_ok! {
+      NoUselessGenerators,
+      "async function* f() { for (const url of urls) yield await fetch(url); }",
+      "async function* f() { yield await a(); yield await b(); }",
+      "async function* f() { if (x) yield 1; yield await load(); }",
+      "async function* f() { yield await load(); cleanup(); }",
+      "async function* f() { yield* await load(); }",
+      "async function* f() { yield load(); }",
+      "function* f() { yield load(); }",
+      "async function f() { return await load(); }",
+      "async function* f() {}",
+    };
+  }
+
+  #[test]
+  fn no_useless_generators_invalid() {
+    assert_lint_err! {
+      NoUselessGenerators,
+      "async function* f() { const res = await fetch(url); yield await res.json(); }": [
+        { col: 0, message: MESSAGE, hint: HINT }
+      ],
+      "const f = async function* () { yield (await load()); };": [
+        { col: 10, message: MESSAGE, hint: HINT }
+      ],
+      "class A { async *load() { function* g() { yield 1; } yield await g; } }": [
+        { col: 10, message: MESSAGE, hint: HINT }
+      ],
+    };
+  }
+}
@@ -0,0 +1,296 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::Program;
+
+use deno_ast::ModuleSpecifier;
+use derive_more::Display;
+use serde::Deserialize;
+
+#[derive(Debug, Default)]
+pub struct NoUselessPathSegments {
+  options: NoUselessPathSegmentsOptions,
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct NoUselessPathSegmentsOptions {
+  /// Also report imports of `index` files, like `./lib/index.ts`, that can
+  /// import the directory instead. Only Node-style resolution supports this,
+  /// Deno doesn't.
+  pub no_useless_index: bool,
+}
+
+impl NoUselessPathSegments {
+  pub fn new(options: NoUselessPathSegmentsOptions) -> Self {
+    Self { options }
+  }
+}
+
+const CODE: &str = "no-useless-path-segments";
+const FIX_DESC: &str = "Remove the unnecessary path segments";
+
+/// Extensions of `index` files that Node-style resolution tries.
+const INDEX_EXTENSIONS: &[&str] =
+  &["ts", "tsx", "mts", "cts", "js", "jsx", "mjs", "cjs"];
+
+#[derive(Display)]
+enum NoUselessPathSegmentsMessage {
+  #[display(fmt = "`{}` has unnecessary path segments", _0)]
+  Useless(String),
+}
+
+#[derive(Display)]
+enum NoUselessPathSegmentsHint {
+  #[display(fmt = "Import `{}` instead", _0)]
+  UseNormalized(String),
+}
+
+impl LintRule for NoUselessPathSegments {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "noUselessIndex": { "type": "boolean" }
+      }
+    }))
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    _program: Program,
+  ) {
+    for (specifier, range) in context.module_graph().import_specifiers() {
+      let Some(normalized) = normalize(
+        specifier,
+        context.specifier(),
+        self.options.no_useless_index,
+      ) else {
+        continue;
+      };
+      let quote = &context.text_of(range)[..1];
+      let new_text = format!("{quote}{normalized}{quote}");
+      context.add_diagnostic_with_fixes(
+        range,
+        CODE,
+        NoUselessPathSegmentsMessage::Useless(specifier.to_string()),
+        Some(NoUselessPathSegmentsHint::UseNormalized(normalized).to_string()),
+        vec![LintFix {
+          description: FIX_DESC.into(),
+          changes: vec![LintFixChange {
+            new_text: new_text.into(),
+            range,
+          }],
+        }],
+      );
+    }
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_useless_path_segments.md")
+  }
+}
+
+/// Returns the shortest way to write a relative specifier, if it differs from
+/// the specifier.
+fn normalize(
+  specifier: &str,
+  referrer: &ModuleSpecifier,
+  no_useless_index: bool,
+) -> Option<String> {
+  if !specifier.starts_with("./") && !specifier.starts_with("../") {
+    return None;
+  }
+  let suffix_start = specifier.find(['?', '#']).unwrap_or(specifier.len());
+  let (path, suffix) = specifier.split_at(suffix_start);
+
+  let mut ups = 0;
+  let mut segments = Vec::new();
+  for segment in path.split('/') {
+    match segment {
+      "" | "." => {}
+      ".." => {
+        if segments.pop().is_none() {
+          ups += 1;
+        }
+      }
+      _ => segments.push(segment),
+    }
+  }
+  let mut is_directory =
+    path.ends_with('/') || path.ends_with("/.") || path.ends_with("/..");
+  if no_useless_index && !is_directory && segments.last().is_some_and(is_index)
+  {
+    segments.pop();
+    is_directory = true;
+  }
+
+  // `../src/a.ts` in `src/mod.ts` goes back into the same directory.
+  let resolve = |ups: usize, segments: &[&str]| {
+    let mut path = format_path(ups, segments);
+    if is_directory {
+      path.push('/');
+    }
+    referrer.join(&path).ok()
+  };
+  let min_segments = if is_directory { 0 } else { 1 };
+  while ups > 0 && segments.len() > min_segments {
+    let resolved = resolve(ups, &segments);
+    if resolved.is_none() || resolved != resolve(ups - 1, &segments[1..]) {
+      break;
+    }
+    ups -= 1;
+    segments.remove(0);
+  }
+
+  let mut normalized = format_path(ups, &segments);
+  if path.ends_with('/') {
+    normalized.push('/');
+  }
+  normalized.push_str(suffix);
+  (normalized != specifier).then_some(normalized)
+}
+
+fn format_path(ups: usize, segments: &[&str]) -> String {
+  let parents = vec![".."; ups].join("/");
+  match (ups, segments.is_empty()) {
+    (0, true) => ".".to_string(),
+    (_, true) => parents,
+    (0, false) => format!("./{}", segments.join("/")),
+    (_, false) => format!("{}/{}", parents, segments.join("/")),
+  }
+}
+
+fn is_index(segment: &&str) -> bool {
+  match segment.strip_prefix("index") {
+    Some("") => true,
+    Some(extension) => extension
+      .strip_prefix('.')
+      .is_some_and(|extension| INDEX_EXTENSIONS.contains(&extension)),
+    None => false,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn normalize_specifiers() {
+    let referrer = ModuleSpecifier::parse("file:///repo/src/mod.ts").unwrap();
+    let normalize = |specifier| normalize(specifier, &referrer, false);
+    assert_eq!(normalize("./a.ts"), None);
+    assert_eq!(normalize("../../a.ts"), None);
+    assert_eq!(normalize("./lib/"), None);
+    assert_eq!(normalize("./"), None);
+    assert_eq!(normalize("../"), None);
+    assert_eq!(normalize("./../a.ts").as_deref(), Some("../a.ts"));
+    assert_eq!(normalize("././a.ts").as_deref(), Some("./a.ts"));
+    assert_eq!(normalize("./a//b.ts").as_deref(), Some("./a/b.ts"));
+    assert_eq!(normalize("./lib/../a.ts").as_deref(), Some("./a.ts"));
+    assert_eq!(normalize("../lib/../a.ts").as_deref(), Some("../a.ts"));
+    assert_eq!(normalize("../src/a.ts").as_deref(), Some("./a.ts"));
+    assert_eq!(normalize("../../repo/src/a.ts").as_deref(), Some("./a.ts"));
+    assert_eq!(normalize("../srcs/a.ts"), None);
+    assert_eq!(normalize("./lib/./").as_deref(), Some("./lib/"));
+    assert_eq!(
+      normalize("./lib/../a.ts?raw").as_deref(),
+      Some("./a.ts?raw")
+    );
+    assert_eq!(normalize("./a.ts#../b"), None);
+    assert_eq!(normalize("./index.ts"), None);
+  }
+
+  #[test]
+  fn normalize_index() {
+    let referrer = ModuleSpecifier::parse("file:///repo/src/mod.ts").unwrap();
+    let normalize = |specifier| normalize(specifier, &referrer, true);
+    assert_eq!(normalize("./lib/index.ts").as_deref(), Some("./lib"));
+    assert_eq!(normalize("./lib/index").as_deref(), Some("./lib"));
+    assert_eq!(normalize("./index.js").as_deref(), Some("."));
+    assert_eq!(normalize("../index.mjs").as_deref(), Some(".."));
+    assert_eq!(normalize("../src/index.ts").as_deref(), Some("."));
+    assert_eq!(normalize("./lib/index.json"), None);
+    assert_eq!(normalize("./lib/indexes.ts"), None);
+    assert_eq!(normalize("./lib/index.ts/"), None);
+  }
+
+  #[test]
+  fn no_useless_path_segments_valid() {
+    assert_lint_ok! {
+      NoUselessPathSegments::default(),
+      filename: "file:///repo/src/mod.ts",
+      r#"import { a } from "./a.ts";"#,
+      r#"import { a } from "../lib/a.ts";"#,
+      r#"import { a } from "./lib/index.ts";"#,
+      r#"import { a } from "https://example.com/a/../b.ts";"#,
+      r#"import { a } from "jsr:@scope/a@1/./b";"#,
+    };
+  }
+
+  #[test]
+  fn no_useless_path_segments_invalid() {
+    assert_lint_err! {
+      NoUselessPathSegments::default(),
+      filename: "file:///repo/src/mod.ts",
+      r#"import { a } from "./../a.ts";"#: [
+        {
+          col: 18,
+          message: NoUselessPathSegmentsMessage::Useless("./../a.ts".to_string()),
+          hint: NoUselessPathSegmentsHint::UseNormalized("../a.ts".to_string()),
+          fix: (FIX_DESC, r#"import { a } from "../a.ts";"#),
+        }
+      ],
+      r#"export * from '../src/lib/a.ts';"#: [
+        {
+          col: 14,
+          message: NoUselessPathSegmentsMessage::Useless(
+            "../src/lib/a.ts".to_string(),
+          ),
+          hint: NoUselessPathSegmentsHint::UseNormalized(
+            "./lib/a.ts".to_string(),
+          ),
+          fix: (FIX_DESC, "export * from './lib/a.ts';"),
+        }
+      ],
+      r#"const a = await import("./lib/../a.ts");"#: [
+        {
+          col: 23,
+          message: NoUselessPathSegmentsMessage::Useless(
+            "./lib/../a.ts".to_string(),
+          ),
+          hint: NoUselessPathSegmentsHint::UseNormalized("./a.ts".to_string()),
+          fix: (FIX_DESC, r#"const a = await import("./a.ts");"#),
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      NoUselessPathSegments::new(NoUselessPathSegmentsOptions {
+        no_useless_index: true,
+      }),
+      filename: "file:///repo/src/mod.ts",
+      r#"import { a } from "./lib/index.ts";"#: [
+        {
+          col: 18,
+          message: NoUselessPathSegmentsMessage::Useless(
+            "./lib/index.ts".to_string(),
+          ),
+          hint: NoUselessPathSegmentsHint::UseNormalized("./lib".to_string()),
+          fix: (FIX_DESC, r#"import { a } from "./lib";"#),
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,233 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::Handler;
+use crate::js_regex::ast::{
+  walk_class, Char, CharKind, CharacterClass, Span, Visit,
+};
+use crate::js_regex::RegexSource;
+use crate::syntax_features::SyntaxFeatures;
+use deno_ast::view::{CallExpr, Callee, NewExpr, NodeKind, Regex};
+use deno_ast::SourceRanged;
+use derive_more::Display;
+
+#[derive(Debug)]
+pub struct NoUselessRegexEscape;
+
+const CODE: &str = "no-useless-regex-escape";
+const FIX_DESC: &str = "Remove the backslash";
+
+#[derive(Display)]
+enum NoUselessRegexEscapeMessage {
+  #[display(fmt = "Unnecessary escape of `{}` in regular expression", _0)]
+  Unnecessary(char),
+}
+
+#[derive(Display)]
+enum NoUselessRegexEscapeHint {
+  #[display(fmt = "Remove the backslash, since the character means the same")]
+  Remove,
+}
+
+impl LintRule for NoUselessRegexEscape {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn required_syntax(&self) -> SyntaxFeatures {
+    SyntaxFeatures::REGEX
+  }
+
+  fn aliases(&self) -> &'static [&'static str] {
+    &["regexp/no-useless-escape"]
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoUselessRegexEscapeHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::Regex, NodeKind::NewExpr, NodeKind::CallExpr])
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_useless_regex_escape.md")
+  }
+}
+
+/// Characters that have to be escaped outside of a class to match themselves.
+const SYNTAX_CHARS: &str = r"^$\.*+?()[]{}|/";
+/// Characters that have to be, or commonly are, escaped inside of a class.
+const CLASS_SYNTAX_CHARS: &str = r"\]-^[/";
+/// Letters that start other escapes, so escaping one on its own, like a `\x`
+/// without hex digits, is more likely an incomplete escape than a useless one.
+const INCOMPLETE_ESCAPES: &str = "cuxkpP";
+
+#[derive(Default)]
+struct UselessEscapeFinder {
+  in_class: bool,
+  /// The spans of the escapes along with the escaped characters.
+  found: Vec<(Span, char)>,
+}
+
+impl Visit for UselessEscapeFinder {
+  fn visit_class(&mut self, class: &CharacterClass) {
+    self.in_class = true;
+    walk_class(self, class);
+    self.in_class = false;
+  }
+
+  fn visit_char(&mut self, c: &Char) {
+    if c.kind != CharKind::Identity {
+      return;
+    }
+    let Some(escaped) = char::from_u32(c.value) else {
+      return;
+    };
+    let needed = if self.in_class {
+      CLASS_SYNTAX_CHARS
+    } else {
+      SYNTAX_CHARS
+    };
+    if !needed.contains(escaped) && !INCOMPLETE_ESCAPES.contains(escaped) {
+      self.found.push((c.span, escaped));
+    }
+  }
+}
+
+fn check_regex(regex: &RegexSource, ctx: &mut Context) {
+  let Some(pattern) = regex.parse() else {
+    return;
+  };
+  let mut finder = UselessEscapeFinder::default();
+  pattern.visit_with(&mut finder);
+  for (span, escaped) in finder.found {
+    let range = regex.range_of(span);
+    let message = NoUselessRegexEscapeMessage::Unnecessary(escaped);
+    match regex.exact_range_of(Span::new(span.start, span.start + 1)) {
+      Some(backslash) => ctx.add_diagnostic_with_fixes(
+        range,
+        CODE,
+        message,
+        Some(NoUselessRegexEscapeHint::Remove.to_string()),
+        vec![LintFix {
+          description: FIX_DESC.into(),
+          changes: vec![LintFixChange {
+            new_text: "".into(),
+            range: backslash,
+          }],
+        }],
+      ),
+      None => ctx.add_diagnostic_with_hint(
+        range,
+        CODE,
+        message,
+        NoUselessRegexEscapeHint::Remove,
+      ),
+    }
+  }
+}
+
+struct NoUselessRegexEscapeHandler;
+
+impl Handler for NoUselessRegexEscapeHandler {
+  fn regex(&mut self, regex: &Regex, ctx: &mut Context) {
+    check_regex(&RegexSource::from_regex(regex), ctx);
+  }
+
+  fn new_expr(&mut self, new_expr: &NewExpr, ctx: &mut Context) {
+    if let Some(regex) = RegexSource::from_constructor(
+      &new_expr.callee,
+      new_expr.args.unwrap_or_default(),
+      new_expr.range(),
+      ctx,
+    ) {
+      check_regex(&regex, ctx);
+    }
+  }
+
+  fn call_expr(&mut self, call_expr: &CallExpr, ctx: &mut Context) {
+    if let Callee::Expr(callee) = &call_expr.callee {
+      if let Some(regex) = RegexSource::from_constructor(
+        callee,
+        call_expr.args,
+        call_expr.range(),
+        ctx,
+      ) {
+        check_regex(&regex, ctx);
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_useless_regex_escape_valid() {
+    assert_lint_ok! {
+      NoUselessRegexEscape,
+      r"/\./",
+      r"/\/\*\+\?\(\)\[\]\{\}\|\^\$\\/",
+      r"/[\]\-\^\\]/",
+      r"/\d\w\s\b\n\t\x41A\0/",
+      r"/\x/",
+      r"/\k/",
+      r"/\1(a)/",
+      r"new RegExp('\\.')",
+      r"new RegExp('\\a', 'v')",
+    };
+  }
+
+  #[test]
+  fn no_useless_regex_escape_invalid() {
+    assert_lint_err! {
+      NoUselessRegexEscape,
+      r"/\a/": [
+        {
+          col: 1,
+          message: NoUselessRegexEscapeMessage::Unnecessary('a'),
+          hint: NoUselessRegexEscapeHint::Remove,
+          fix: (FIX_DESC, r"/a/"),
+        }
+      ],
+      r"/\-/": [
+        {
+          col: 1,
+          message: NoUselessRegexEscapeMessage::Unnecessary('-'),
+          hint: NoUselessRegexEscapeHint::Remove,
+          fix: (FIX_DESC, r"/-/"),
+        }
+      ],
+      r"const re = /[\.\*]/;": [
+        {
+          col: 13,
+          message: NoUselessRegexEscapeMessage::Unnecessary('.'),
+          hint: NoUselessRegexEscapeHint::Remove,
+          fix: (FIX_DESC, r"const re = /[.\*]/;"),
+        },
+        {
+          col: 15,
+          message: NoUselessRegexEscapeMessage::Unnecessary('*'),
+          hint: NoUselessRegexEscapeHint::Remove,
+          fix: (FIX_DESC, r"const re = /[\.*]/;"),
+        }
+      ],
+      r"new RegExp('\\#foo');": [
+        {
+          col: 12,
+          message: NoUselessRegexEscapeMessage::Unnecessary('#'),
+          hint: NoUselessRegexEscapeHint::Remove,
+          fix: (FIX_DESC, r"new RegExp('#foo');"),
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,364 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::Handler;
+use crate::js_regex::ast::{
+  walk_atom, Assertion, Atom, Char, ClassEscapeKind, ClassItem, Visit,
+};
+use crate::js_regex::RegexSource;
+use crate::syntax_features::SyntaxFeatures;
+use deno_ast::view::{
+  CallExpr, Callee, Expr, ExprOrSpread, MemberExpr, MemberProp, NewExpr, Node,
+  NodeKind, NodeTrait, Regex,
+};
+use deno_ast::SourceRanged;
+use derive_more::Display;
+
+#[derive(Debug)]
+pub struct NoUselessRegexFlags;
+
+const CODE: &str = "no-useless-regex-flags";
+const FIX_DESC: &str = "Remove the flag";
+
+#[derive(Display)]
+enum NoUselessRegexFlagsMessage {
+  #[display(fmt = "Duplicate `{}` flag in regular expression", _0)]
+  Duplicate(char),
+  #[display(
+    fmt = "The `i` flag has no effect, since the pattern has no letters"
+  )]
+  IgnoreCase,
+  #[display(
+    fmt = "The `m` flag has no effect, since the pattern has no `^` or `$`"
+  )]
+  Multiline,
+  #[display(fmt = "The `s` flag has no effect, since the pattern has no `.`")]
+  DotAll,
+  #[display(fmt = "The `g` flag has no effect on `{}`", _0)]
+  Global(&'static str),
+}
+
+#[derive(Display)]
+enum NoUselessRegexFlagsHint {
+  #[display(fmt = "Remove the flag")]
+  Remove,
+}
+
+impl LintRule for NoUselessRegexFlags {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn required_syntax(&self) -> SyntaxFeatures {
+    SyntaxFeatures::REGEX
+  }
+
+  fn aliases(&self) -> &'static [&'static str] {
+    &["regexp/no-useless-flag"]
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoUselessRegexFlagsHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::Regex, NodeKind::NewExpr, NodeKind::CallExpr])
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/no_useless_regex_flags.md")
+  }
+}
+
+/// What the parts of the pattern that flags can affect are.
+#[derive(Default)]
+struct FlagUsage {
+  unicode: bool,
+  /// Whether anything in the pattern matches differently with the `i` flag.
+  cased: bool,
+  anchors: bool,
+  dot: bool,
+}
+
+/// Ranges bigger than this are assumed to contain a letter without checking.
+const MAX_CHECKED_RANGE: u32 = 0x1000;
+
+fn is_cased(value: u32) -> bool {
+  char::from_u32(value).is_some_and(|c| c.to_lowercase().ne(c.to_uppercase()))
+}
+
+impl Visit for FlagUsage {
+  fn visit_atom(&mut self, atom: &Atom) {
+    match atom {
+      Atom::Dot => self.dot = true,
+      Atom::Assertion(Assertion::Start | Assertion::End) => self.anchors = true,
+      // What a backreference matches depends on the case of the group.
+      Atom::Backreference(_) => self.cased = true,
+      Atom::ClassEscape(escape) => self.visit_class_escape(escape.kind),
+      Atom::Class(class) => {
+        for item in &class.items {
+          match item {
+            ClassItem::Char(c) => self.visit_char(c),
+            ClassItem::Range(from, to) => {
+              self.cased |= to.value - from.value > MAX_CHECKED_RANGE
+                || (from.value..=to.value).any(is_cased);
+            }
+            ClassItem::Escape(escape) => self.visit_class_escape(escape.kind),
+          }
+        }
+      }
+      _ => walk_atom(self, atom),
+    }
+  }
+
+  fn visit_char(&mut self, c: &Char) {
+    self.cased |= is_cased(c.value);
+  }
+}
+
+impl FlagUsage {
+  fn visit_class_escape(&mut self, kind: ClassEscapeKind) {
+    match kind {
+      // With the `u` and `i` flags, `\w` also matches `ſ` and the Kelvin sign.
+      ClassEscapeKind::Word => self.cased |= self.unicode,
+      ClassEscapeKind::Property => self.cased = true,
+      ClassEscapeKind::Digit | ClassEscapeKind::Space => {}
+    }
+  }
+}
+
+/// The method that ignores the `g` flag of the regex at `node`, if it's
+/// `regex.test()`, `str.search(regex)` or `str.split(regex)`.
+fn method_ignoring_global(node: Node) -> Option<&'static str> {
+  let parent = node.parent()?;
+  if let Some(member_expr) = parent.to::<MemberExpr>() {
+    if member_expr.obj.range() != node.range()
+      || !member_expr.parent().is::<CallExpr>()
+    {
+      return None;
+    }
+    return (prop_name(member_expr)? == "test").then_some("test");
+  }
+
+  let arg = parent.to::<ExprOrSpread>()?;
+  let call_expr = arg.parent().to::<CallExpr>()?;
+  if call_expr.args.first()?.range() != arg.range() {
+    return None;
+  }
+  let Callee::Expr(Expr::Member(callee)) = &call_expr.callee else {
+    return None;
+  };
+  match prop_name(callee)? {
+    "search" => Some("search"),
+    "split" => Some("split"),
+    _ => None,
+  }
+}
+
+fn prop_name<'a>(member_expr: &MemberExpr<'a>) -> Option<&'a str> {
+  match &member_expr.prop {
+    MemberProp::Ident(ident) => Some(ident.sym()),
+    _ => None,
+  }
+}
+
+fn check_regex(regex: &RegexSource, node: Node, ctx: &mut Context) {
+  let Some(flags) = &regex.flags else {
+    return;
+  };
+  let mut useless = Vec::new();
+  for (i, flag) in flags.char_indices() {
+    if flags[..i].contains(flag) {
+      useless.push((i, NoUselessRegexFlagsMessage::Duplicate(flag)));
+    }
+  }
+
+  if let Some(pattern) = regex.parse() {
+    let mut usage = FlagUsage {
+      unicode: regex.has_flag('u'),
+      ..Default::default()
+    };
+    pattern.visit_with(&mut usage);
+    for (flag, used, message) in [
+      ('i', usage.cased, NoUselessRegexFlagsMessage::IgnoreCase),
+      ('m', usage.anchors, NoUselessRegexFlagsMessage::Multiline),
+      ('s', usage.dot, NoUselessRegexFlagsMessage::DotAll),
+    ] {
+      if let (Some(i), false) = (flags.find(flag), used) {
+        useless.push((i, message));
+      }
+    }
+  }
+
+  if let (Some(i), Some(method)) =
+    (flags.find('g'), method_ignoring_global(node))
+  {
+    useless.push((i, NoUselessRegexFlagsMessage::Global(method)));
+  }
+
+  useless.sort_by_key(|(i, _)| *i);
+  for (i, message) in useless {
+    match regex.flag_range(i) {
+      Some(flag_range) => ctx.add_diagnostic_with_fixes(
+        flag_range,
+        CODE,
+        message,
+        Some(NoUselessRegexFlagsHint::Remove.to_string()),
+        vec![LintFix {
+          description: FIX_DESC.into(),
+          changes: vec![LintFixChange {
+            new_text: "".into(),
+            range: flag_range,
+          }],
+        }],
+      ),
+      None => ctx.add_diagnostic_with_hint(
+        regex.range,
+        CODE,
+        message,
+        NoUselessRegexFlagsHint::Remove,
+      ),
+    }
+  }
+}
+
+struct NoUselessRegexFlagsHandler;
+
+impl Handler for NoUselessRegexFlagsHandler {
+  fn regex(&mut self, regex: &Regex, ctx: &mut Context) {
+    check_regex(&RegexSource::from_regex(regex), regex.as_node(), ctx);
+  }
+
+  fn new_expr(&mut self, new_expr: &NewExpr, ctx: &mut Context) {
+    if let Some(regex) = RegexSource::from_constructor(
+      &new_expr.callee,
+      new_expr.args.unwrap_or_default(),
+      new_expr.range(),
+      ctx,
+    ) {
+      check_regex(&regex, new_expr.as_node(), ctx);
+    }
+  }
+
+  fn call_expr(&mut self, call_expr: &CallExpr, ctx: &mut Context) {
+    if let Callee::Expr(callee) = &call_expr.callee {
+      if let Some(regex) = RegexSource::from_constructor(
+        callee,
+        call_expr.args,
+        call_expr.range(),
+        ctx,
+      ) {
+        check_regex(&regex, call_expr.as_node(), ctx);
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_useless_regex_flags_valid() {
+    assert_lint_ok! {
+      NoUselessRegexFlags,
+      r"/foo/i",
+      r"/[a-z]/i",
+      r"/\w/iu",
+      r"/\p{L}/iu",
+      r"/(a)\1/i",
+      r"/^foo$/m",
+      r"/a.b/s",
+      r"/\d+/g",
+      r"str.replace(/\d+/g, '')",
+      r"const re = /\d+/g; re.test(str);",
+      r"str.split(',', /\d/g)",
+      r"new RegExp('^a', 'm')",
+      r"new RegExp('a', flags)",
+      r"new RegExp('\\d', 'v')",
+    };
+  }
+
+  #[test]
+  fn no_useless_regex_flags_invalid() {
+    assert_lint_err! {
+      NoUselessRegexFlags,
+      r"/\d+/i": [
+        {
+          col: 5,
+          message: NoUselessRegexFlagsMessage::IgnoreCase,
+          hint: NoUselessRegexFlagsHint::Remove,
+          fix: (FIX_DESC, r"/\d+/"),
+        }
+      ],
+      r"/foo/m": [
+        {
+          col: 5,
+          message: NoUselessRegexFlagsMessage::Multiline,
+          hint: NoUselessRegexFlagsHint::Remove,
+          fix: (FIX_DESC, r"/foo/"),
+        }
+      ],
+      r"/foo/gs": [
+        {
+          col: 6,
+          message: NoUselessRegexFlagsMessage::DotAll,
+          hint: NoUselessRegexFlagsHint::Remove,
+          fix: (FIX_DESC, r"/foo/g"),
+        }
+      ],
+      r"/foo/gig": [
+        {
+          col: 7,
+          message: NoUselessRegexFlagsMessage::Duplicate('g'),
+          hint: NoUselessRegexFlagsHint::Remove,
+          fix: (FIX_DESC, r"/foo/gi"),
+        }
+      ],
+      r"if (/foo/g.test(str)) {}": [
+        {
+          col: 9,
+          message: NoUselessRegexFlagsMessage::Global("test"),
+          hint: NoUselessRegexFlagsHint::Remove,
+          fix: (FIX_DESC, r"if (/foo/.test(str)) {}"),
+        }
+      ],
+      r"str.split(/,\s*/g)": [
+        {
+          col: 16,
+          message: NoUselessRegexFlagsMessage::Global("split"),
+          hint: NoUselessRegexFlagsHint::Remove,
+          fix: (FIX_DESC, r"str.split(/,\s*/)"),
+        }
+      ],
+      r"str.search(/a/gi)": [
+        {
+          col: 14,
+          message: NoUselessRegexFlagsMessage::Global("search"),
+          hint: NoUselessRegexFlagsHint::Remove,
+          fix: (FIX_DESC, r"str.search(/a/i)"),
+        }
+      ],
+      r"new RegExp('[0-9]', 'gi').test(str)": [
+        {
+          col: 21,
+          message: NoUselessRegexFlagsMessage::Global("test"),
+          hint: NoUselessRegexFlagsHint::Remove,
+          fix: (FIX_DESC, r"new RegExp('[0-9]', 'i').test(str)"),
+        },
+        {
+          col: 22,
+          message: NoUselessRegexFlagsMessage::IgnoreCase,
+          hint: NoUselessRegexFlagsHint::Remove,
+          fix: (FIX_DESC, r"new RegExp('[0-9]', 'g').test(str)"),
+        }
+      ],
+    };
+  }
+}
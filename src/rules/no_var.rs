@@ -1,9 +1,8 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
-use crate::Program;
-use deno_ast::view::{NodeTrait, VarDecl, VarDeclKind};
+use crate::handler::Handler;
+use deno_ast::view::{NodeKind, NodeTrait, VarDecl, VarDeclKind};
 use deno_ast::SourceRangedForSpanned;
 
 #[derive(Debug)]
@@ -21,12 +20,12 @@ impl LintRule for NoVar {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    NoVarHandler.traverse(program, context);
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoVarHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::VarDecl])
   }
 
   #[cfg(feature = "docs")]
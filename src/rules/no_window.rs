@@ -4,20 +4,17 @@ use super::LintRule;
 use crate::diagnostic::LintFix;
 use crate::diagnostic::LintFixChange;
 use crate::handler::Handler;
-use crate::handler::Traverse;
-use crate::Program;
+use deno_ast::view::NodeKind;
 
 use deno_ast::view as ast_view;
 use deno_ast::SourceRange;
 use deno_ast::SourceRanged;
-use if_chain::if_chain;
 
 #[derive(Debug)]
 pub struct NoWindow;
 
 const CODE: &str = "no-window";
-const MESSAGE: &str =
-  "window is deprecated and scheduled for removal in Deno 2.0";
+const MESSAGE: &str = "window was removed in Deno 2.0";
 const HINT: &str = "Instead, use `globalThis`";
 const FIX_DESC: &str = "Rename window to globalThis";
 
@@ -30,12 +27,16 @@ impl LintRule for NoWindow {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program<'_>,
-  ) {
-    NoWindowGlobalHandler.traverse(program, context);
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoWindowGlobalHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::Ident])
   }
 
   #[cfg(feature = "docs")]
@@ -47,7 +48,12 @@ impl LintRule for NoWindow {
 struct NoWindowGlobalHandler;
 
 impl NoWindowGlobalHandler {
-  fn add_diagnostic(&self, ctx: &mut Context, range: SourceRange) {
+  fn add_diagnostic(
+    &self,
+    ctx: &mut Context,
+    range: SourceRange,
+    new_text: &str,
+  ) {
     ctx.add_diagnostic_with_fixes(
       range,
       CODE,
@@ -56,7 +62,7 @@ impl NoWindowGlobalHandler {
       vec![LintFix {
         description: FIX_DESC.into(),
         changes: vec![LintFixChange {
-          new_text: "globalThis".into(),
+          new_text: new_text.to_string().into(),
           range,
         }],
       }],
@@ -64,33 +70,48 @@ impl NoWindowGlobalHandler {
   }
 }
 
+/// Whether an identifier with this parent refers to a value, as opposed to
+/// e.g. a binding, a label or a type.
+fn is_value_reference(parent: ast_view::Node) -> bool {
+  if let Some(unary) = parent.to::<ast_view::UnaryExpr>() {
+    // `typeof window` is how code tells if it runs in a browser.
+    return unary.op() != ast_view::UnaryOp::TypeOf;
+  }
+  !(parent.is::<ast_view::BindingIdent>()
+    || parent.is::<ast_view::LabeledStmt>()
+    || parent.is::<ast_view::BreakStmt>()
+    || parent.is::<ast_view::ContinueStmt>()
+    || parent.is::<ast_view::ExportNamedSpecifier>()
+    || parent.is::<ast_view::ImportNamedSpecifier>()
+    || parent.is::<ast_view::JSXOpeningElement>()
+    || parent.is::<ast_view::JSXClosingElement>()
+    || parent.is::<ast_view::JSXMemberExpr>()
+    || parent.is::<ast_view::TsTypeRef>()
+    || parent.is::<ast_view::TsTypeQuery>()
+    || parent.is::<ast_view::TsQualifiedName>()
+    || parent.is::<ast_view::TsPropertySignature>()
+    || parent.is::<ast_view::TsMethodSignature>()
+    || parent.is::<ast_view::TsGetterSignature>()
+    || parent.is::<ast_view::TsSetterSignature>()
+    || parent.is::<ast_view::TsEnumMember>())
+}
+
 impl Handler for NoWindowGlobalHandler {
-  fn member_expr(&mut self, expr: &ast_view::MemberExpr, ctx: &mut Context) {
-    if expr.parent().is::<ast_view::MemberExpr>() {
+  fn ident(&mut self, ident: &ast_view::Ident, ctx: &mut Context) {
+    if ident.sym() != "window" || !ctx.scope().is_global(&ident.inner.to_id()) {
       return;
     }
-
-    use deno_ast::view::Expr;
-    if_chain! {
-      if let Expr::Ident(ident) = &expr.obj;
-      if ident.sym() == "window";
-      if ctx.scope().is_global(&ident.inner.to_id());
-      then {
-        self.add_diagnostic(ctx, ident.range());
-      }
-    }
-  }
-
-  fn expr_stmt(&mut self, expr: &ast_view::ExprStmt, ctx: &mut Context) {
-    use deno_ast::view::Expr;
-    if_chain! {
-      if let Expr::Ident(ident) = &expr.expr;
-      if ident.sym() == "window";
-      if ctx.scope().is_global(&ident.inner.to_id());
-      then {
-        self.add_diagnostic(ctx, ident.range());
-      }
+    let parent = ident.parent();
+    if !is_value_reference(parent) {
+      return;
     }
+    // `{ window }` keeps its key when fixed.
+    let new_text = if parent.is::<ast_view::ObjectLit>() {
+      "window: globalThis"
+    } else {
+      "globalThis"
+    };
+    self.add_diagnostic(ctx, ident.range(), new_text);
   }
 }
 
@@ -116,7 +137,15 @@ mod tests {
 
       // https://github.com/denoland/deno_lint/issues/1232
       "const params: { window: number } = { window: 23 };",
-      "x.window"
+      "x.window",
+      "if (typeof window !== 'undefined') {}",
+      "window: for (;;) { break window; }",
+      "type Foo = { window: Window };",
+      "interface Foo { window(): void }",
+      "enum Foo { window }",
+      r#"import { window as w } from "./mod.ts";"#,
+      r#"export { window } from "./mod.ts";"#,
+      r#"export { window as w } from "./mod.ts";"#,
     };
   }
 
@@ -144,6 +173,30 @@ mod tests {
           fix: (FIX_DESC, "globalThis[`fetch`]()"),
         }
       ],
+      "window.location.href": [
+        {
+          col: 0,
+          fix: (FIX_DESC, "globalThis.location.href"),
+        }
+      ],
+      "console.log(window);": [
+        {
+          col: 12,
+          fix: (FIX_DESC, "console.log(globalThis);"),
+        }
+      ],
+      "const w = window;": [
+        {
+          col: 10,
+          fix: (FIX_DESC, "const w = globalThis;"),
+        }
+      ],
+      "const globals = { window };": [
+        {
+          col: 18,
+          fix: (FIX_DESC, "const globals = { window: globalThis };"),
+        }
+      ],
       r#"
 function foo() {
   const window = 42;
@@ -5,8 +5,7 @@ use super::LintRule;
 use crate::diagnostic::LintFix;
 use crate::diagnostic::LintFixChange;
 use crate::handler::Handler;
-use crate::handler::Traverse;
-use crate::Program;
+use deno_ast::view::NodeKind;
 
 use deno_ast::view as ast_view;
 use deno_ast::SourceRanged;
@@ -32,12 +31,16 @@ impl LintRule for NoWindowPrefix {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program<'_>,
-  ) {
-    NoWindowPrefixHandler.traverse(program, context);
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(NoWindowPrefixHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::MemberExpr])
   }
 
   #[cfg(feature = "docs")]
@@ -0,0 +1,423 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::Handler;
+
+use deno_ast::swc::ast::{Expr, FnExpr, Prop, PropName, PropOrSpread};
+use deno_ast::view::{NodeKind, ObjectLit};
+use deno_ast::{SourceRange, SourceRangedForSpanned};
+use derive_more::Display;
+use serde::Deserialize;
+
+#[derive(Debug, Default)]
+pub struct ObjectShorthand {
+  options: ObjectShorthandOptions,
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ObjectShorthandOptions {
+  pub style: ShorthandStyle,
+}
+
+/// How properties and methods of object literals should be written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ShorthandStyle {
+  /// `{ x, f() {} }`
+  #[default]
+  Always,
+  /// `{ x: x, f: function () {} }`
+  Never,
+  /// Either style, as long as all the properties of an object use the same.
+  Consistent,
+}
+
+impl ObjectShorthand {
+  pub fn new(options: ObjectShorthandOptions) -> Self {
+    Self { options }
+  }
+}
+
+const CODE: &str = "object-shorthand";
+
+#[derive(Display)]
+enum ObjectShorthandMessage {
+  #[display(fmt = "Expected property shorthand")]
+  PropertyShorthand,
+  #[display(fmt = "Expected method shorthand")]
+  MethodShorthand,
+  #[display(fmt = "Expected longform property")]
+  PropertyLongform,
+  #[display(fmt = "Expected longform method")]
+  MethodLongform,
+  #[display(fmt = "Unexpected mix of shorthand and longform properties")]
+  Mixed,
+}
+
+#[derive(Display)]
+enum ObjectShorthandHint {
+  #[display(fmt = "Use the shorthand, e.g. `{{ x }}` or `{{ f() {{}} }}`")]
+  UseShorthand,
+  #[display(
+    fmt = "Use the longform, e.g. `{{ x: x }}` or `{{ f: function () {{}} }}`"
+  )]
+  UseLongform,
+  #[display(
+    fmt = "Write all the properties of the object either as shorthands or in the longform"
+  )]
+  Consistent,
+}
+
+const SHORTHAND_FIX_DESC: &str = "Convert to the shorthand";
+const LONGFORM_FIX_DESC: &str = "Convert to the longform";
+
+impl LintRule for ObjectShorthand {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "style": {
+          "enum": ["always", "never", "consistent"],
+          "default": "always"
+        }
+      }
+    }))
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(ObjectShorthandHandler {
+      style: self.options.style,
+    }))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::ObjectLit])
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/object_shorthand.md")
+  }
+}
+
+struct ObjectShorthandHandler {
+  style: ShorthandStyle,
+}
+
+impl Handler for ObjectShorthandHandler {
+  fn object_lit(&mut self, object_lit: &ObjectLit, ctx: &mut Context) {
+    let props = object_lit.inner.props.iter().filter_map(|prop| match prop {
+      PropOrSpread::Prop(prop) => Some(&**prop),
+      PropOrSpread::Spread(_) => None,
+    });
+    match self.style {
+      ShorthandStyle::Always => {
+        for prop in props {
+          check_shorthand(prop, ctx);
+        }
+      }
+      ShorthandStyle::Never => {
+        for prop in props {
+          check_longform(prop, ctx);
+        }
+      }
+      ShorthandStyle::Consistent => {
+        let (mut shorthands, mut longforms) = (0, 0);
+        for prop in props {
+          match prop {
+            Prop::Shorthand(_) | Prop::Method(_) => shorthands += 1,
+            Prop::KeyValue(_) => longforms += 1,
+            // Accessors and `a = 1` in patterns have no other form.
+            Prop::Getter(_) | Prop::Setter(_) | Prop::Assign(_) => {}
+          }
+        }
+        if shorthands > 0 && longforms > 0 {
+          ctx.add_diagnostic_with_hint(
+            object_lit.inner.range(),
+            CODE,
+            ObjectShorthandMessage::Mixed,
+            ObjectShorthandHint::Consistent,
+          );
+        }
+      }
+    }
+  }
+}
+
+/// Reports properties like `x: x` and `f: function () {}`.
+fn check_shorthand(prop: &Prop, ctx: &mut Context) {
+  let Prop::KeyValue(key_value) = prop else {
+    return;
+  };
+  let (message, new_text) = match &*key_value.value {
+    Expr::Ident(value) => match &key_value.key {
+      PropName::Ident(key) if key.sym == value.sym => (
+        ObjectShorthandMessage::PropertyShorthand,
+        value.sym.to_string(),
+      ),
+      _ => return,
+    },
+    // Named functions are left alone, since they may refer to their name.
+    Expr::Fn(FnExpr {
+      ident: None,
+      function,
+    }) => {
+      let function_text = key_value.value.text_fast(ctx.text_info());
+      let rest = function_text
+        .strip_prefix("async")
+        .unwrap_or(function_text)
+        .trim_start();
+      let Some(rest) = rest.strip_prefix("function") else {
+        return;
+      };
+      let rest = rest.trim_start();
+      let rest = rest.strip_prefix('*').unwrap_or(rest).trim_start();
+      let new_text = format!(
+        "{}{}{}{}",
+        if function.is_async { "async " } else { "" },
+        if function.is_generator { "*" } else { "" },
+        key_value.key.text_fast(ctx.text_info()),
+        rest
+      );
+      (ObjectShorthandMessage::MethodShorthand, new_text)
+    }
+    _ => return,
+  };
+  add_diagnostic(
+    ctx,
+    prop.range(),
+    message,
+    ObjectShorthandHint::UseShorthand,
+    SHORTHAND_FIX_DESC,
+    new_text,
+  );
+}
+
+/// Reports properties like `x` and `f() {}`.
+fn check_longform(prop: &Prop, ctx: &mut Context) {
+  let (message, new_text) = match prop {
+    Prop::Shorthand(ident) => (
+      ObjectShorthandMessage::PropertyLongform,
+      format!("{0}: {0}", ident.sym),
+    ),
+    Prop::Method(method) => {
+      let rest = ctx.text_of(SourceRange::new(method.key.end(), prop.end()));
+      let new_text = format!(
+        "{}: {}function{}{}",
+        method.key.text_fast(ctx.text_info()),
+        if method.function.is_async {
+          "async "
+        } else {
+          ""
+        },
+        if method.function.is_generator {
+          "*"
+        } else {
+          ""
+        },
+        rest
+      );
+      (ObjectShorthandMessage::MethodLongform, new_text)
+    }
+    _ => return,
+  };
+  add_diagnostic(
+    ctx,
+    prop.range(),
+    message,
+    ObjectShorthandHint::UseLongform,
+    LONGFORM_FIX_DESC,
+    new_text,
+  );
+}
+
+fn add_diagnostic(
+  ctx: &mut Context,
+  range: SourceRange,
+  message: ObjectShorthandMessage,
+  hint: ObjectShorthandHint,
+  fix_desc: &'static str,
+  new_text: String,
+) {
+  // Comments in the property would be lost.
+  let fixes = if ctx.comments_within(range).next().is_some() {
+    vec![]
+  } else {
+    vec![LintFix {
+      description: fix_desc.into(),
+      changes: vec![LintFixChange {
+        new_text: new_text.into(),
+        range,
+      }],
+    }]
+  };
+  ctx.add_diagnostic_with_fixes(
+    range,
+    CODE,
+    message,
+    Some(hint.to_string()),
+    fixes,
+  );
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn object_shorthand_valid() {
+    assert_lint_ok! {
+      ObjectShorthand::default(),
+      "const obj = { x, y: 1, ...rest };",
+      "const obj = { x: y };",
+      "const obj = { 'x': x };",
+      "const obj = { [x]: x };",
+      "const obj = { f() {}, async *g() {} };",
+      "const obj = { f: function f() { return f; } };",
+      "const obj = { f: () => {} };",
+      "const obj = { get x() { return 1; }, set x(value) {} };",
+      "const { x = 1 } = obj;",
+    };
+
+    assert_lint_ok! {
+      ObjectShorthand::new(ObjectShorthandOptions {
+        style: ShorthandStyle::Never,
+      }),
+      "const obj = { x: x, f: function () {} };",
+      "const obj = { get x() { return 1; } };",
+      "const { x } = obj;",
+    };
+
+    assert_lint_ok! {
+      ObjectShorthand::new(ObjectShorthandOptions {
+        style: ShorthandStyle::Consistent,
+      }),
+      "const obj = { x, f() {}, ...rest };",
+      "const obj = { x: x, f: function () {} };",
+      "const obj = { x, get y() { return 1; } };",
+      "const obj = {};",
+    };
+  }
+
+  #[test]
+  fn object_shorthand_invalid() {
+    assert_lint_err! {
+      ObjectShorthand::default(),
+      "const obj = { x: x, y };": [
+        {
+          col: 14,
+          message: ObjectShorthandMessage::PropertyShorthand,
+          hint: ObjectShorthandHint::UseShorthand,
+          fix: (SHORTHAND_FIX_DESC, "const obj = { x, y };"),
+        }
+      ],
+      "const obj = { f: function (a) { return a; } };": [
+        {
+          col: 14,
+          message: ObjectShorthandMessage::MethodShorthand,
+          hint: ObjectShorthandHint::UseShorthand,
+          fix: (SHORTHAND_FIX_DESC, "const obj = { f(a) { return a; } };"),
+        }
+      ],
+      "const obj = { 'a-b': async function* <T>(a: T): AsyncGenerator<T> {} };": [
+        {
+          col: 14,
+          message: ObjectShorthandMessage::MethodShorthand,
+          hint: ObjectShorthandHint::UseShorthand,
+          fix: (
+            SHORTHAND_FIX_DESC,
+            "const obj = { async *'a-b'<T>(a: T): AsyncGenerator<T> {} };"
+          ),
+        }
+      ],
+      "const obj = { [key]: function() {} };": [
+        {
+          col: 14,
+          message: ObjectShorthandMessage::MethodShorthand,
+          hint: ObjectShorthandHint::UseShorthand,
+          fix: (SHORTHAND_FIX_DESC, "const obj = { [key]() {} };"),
+        }
+      ],
+      "const obj = { x: /* x */ x };": [
+        {
+          col: 14,
+          message: ObjectShorthandMessage::PropertyShorthand,
+          hint: ObjectShorthandHint::UseShorthand,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      ObjectShorthand::new(ObjectShorthandOptions {
+        style: ShorthandStyle::Never,
+      }),
+      "const obj = { x, y: 1 };": [
+        {
+          col: 14,
+          message: ObjectShorthandMessage::PropertyLongform,
+          hint: ObjectShorthandHint::UseLongform,
+          fix: (LONGFORM_FIX_DESC, "const obj = { x: x, y: 1 };"),
+        }
+      ],
+      "const obj = { f(a) { return a; } };": [
+        {
+          col: 14,
+          message: ObjectShorthandMessage::MethodLongform,
+          hint: ObjectShorthandHint::UseLongform,
+          fix: (
+            LONGFORM_FIX_DESC,
+            "const obj = { f: function(a) { return a; } };"
+          ),
+        }
+      ],
+      "const obj = { async *[key]<T>(a: T) {} };": [
+        {
+          col: 14,
+          message: ObjectShorthandMessage::MethodLongform,
+          hint: ObjectShorthandHint::UseLongform,
+          fix: (
+            LONGFORM_FIX_DESC,
+            "const obj = { [key]: async function*<T>(a: T) {} };"
+          ),
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      ObjectShorthand::new(ObjectShorthandOptions {
+        style: ShorthandStyle::Consistent,
+      }),
+      "const obj = { x, y: y };": [
+        {
+          col: 12,
+          message: ObjectShorthandMessage::Mixed,
+          hint: ObjectShorthandHint::Consistent,
+        }
+      ],
+      "const obj = { f() {}, g: function () {} };": [
+        {
+          col: 12,
+          message: ObjectShorthandMessage::Mixed,
+          hint: ObjectShorthandHint::Consistent,
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn object_shorthand_options() {
+    let options: ObjectShorthandOptions =
+      serde_json::from_str(r#"{ "style": "consistent" }"#).unwrap();
+    assert_eq!(options.style, ShorthandStyle::Consistent);
+  }
+}
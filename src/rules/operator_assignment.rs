@@ -0,0 +1,389 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::Handler;
+use crate::swc_util::{is_same_reference, is_simple_reference};
+
+use deno_ast::swc::ast::{AssignOp, AssignTarget, BinaryOp, Expr};
+use deno_ast::view::{AssignExpr, NodeKind};
+use deno_ast::{SourceRange, SourceRangedForSpanned};
+use derive_more::Display;
+use serde::Deserialize;
+
+#[derive(Debug, Default)]
+pub struct OperatorAssignment {
+  options: OperatorAssignmentOptions,
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct OperatorAssignmentOptions {
+  pub style: OperatorAssignmentStyle,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum OperatorAssignmentStyle {
+  /// Operator assignments wherever they can be used, like `a += b`.
+  #[default]
+  Always,
+  /// No operator assignments, like `a = a + b`.
+  Never,
+}
+
+impl OperatorAssignment {
+  pub fn new(options: OperatorAssignmentOptions) -> Self {
+    Self { options }
+  }
+}
+
+const CODE: &str = "operator-assignment";
+
+#[derive(Display)]
+enum OperatorAssignmentMessage {
+  #[display(
+    fmt = "Assignment (`=`) can be replaced with operator assignment (`{}`)",
+    _0
+  )]
+  Assignment(AssignOp),
+  #[display(fmt = "Unexpected operator assignment (`{}`) shorthand", _0)]
+  Unexpected(AssignOp),
+}
+
+#[derive(Display)]
+enum OperatorAssignmentHint {
+  #[display(fmt = "Use the `{}` operator instead", _0)]
+  UseOperator(AssignOp),
+  #[display(fmt = "Write out the assignment instead, e.g. `a = a + b`")]
+  WriteOut,
+}
+
+const FIX_DESC: &str = "Use operator assignment";
+const WRITE_OUT_FIX_DESC: &str = "Write out the assignment";
+
+impl LintRule for OperatorAssignment {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "style": { "enum": ["always", "never"], "default": "always" }
+      }
+    }))
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(OperatorAssignmentHandler {
+      options: &self.options,
+    }))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::AssignExpr])
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/operator_assignment.md")
+  }
+}
+
+struct OperatorAssignmentHandler<'a> {
+  options: &'a OperatorAssignmentOptions,
+}
+
+impl Handler for OperatorAssignmentHandler<'_> {
+  fn assign_expr(&mut self, assign_expr: &AssignExpr, ctx: &mut Context) {
+    let assign_expr = assign_expr.inner;
+    let AssignTarget::Simple(target) = &assign_expr.left else {
+      return;
+    };
+
+    match self.options.style {
+      OperatorAssignmentStyle::Always => {
+        if assign_expr.op != AssignOp::Assign {
+          return;
+        }
+        let Expr::Bin(bin) = &*assign_expr.right else {
+          return;
+        };
+        let Some(operator) = operator_assignment(bin.op) else {
+          return;
+        };
+        let fixes = if is_same_reference(target, &bin.left) {
+          let new_text = format!(
+            "{} {} {}",
+            target.text_fast(ctx.text_info()),
+            operator,
+            bin.right.text_fast(ctx.text_info())
+          );
+          fix(ctx, FIX_DESC, assign_expr.range(), new_text)
+        } else if is_commutative(bin.op)
+          && is_same_reference(target, &bin.right)
+        {
+          // `a = b * a` isn't fixed, since `b` would be evaluated after `a`.
+          vec![]
+        } else {
+          return;
+        };
+        ctx.add_diagnostic_with_fixes(
+          assign_expr.range(),
+          CODE,
+          OperatorAssignmentMessage::Assignment(operator),
+          Some(OperatorAssignmentHint::UseOperator(operator).to_string()),
+          fixes,
+        );
+      }
+      OperatorAssignmentStyle::Never => {
+        let Some(op) = assign_expr.op.to_update() else {
+          return;
+        };
+        if op.may_short_circuit() {
+          return;
+        }
+        // The target is read twice in the written out assignment, so it's
+        // only fixed when reading it again has no side effects.
+        let fixes = if is_simple_reference(target) {
+          let target = target.text_fast(ctx.text_info());
+          let right = assign_expr.right.text_fast(ctx.text_info());
+          let new_text = if needs_parens(&assign_expr.right, op) {
+            format!("{} = {} {} ({})", target, target, op, right)
+          } else {
+            format!("{} = {} {} {}", target, target, op, right)
+          };
+          fix(ctx, WRITE_OUT_FIX_DESC, assign_expr.range(), new_text)
+        } else {
+          vec![]
+        };
+        ctx.add_diagnostic_with_fixes(
+          assign_expr.range(),
+          CODE,
+          OperatorAssignmentMessage::Unexpected(assign_expr.op),
+          Some(OperatorAssignmentHint::WriteOut.to_string()),
+          fixes,
+        );
+      }
+    }
+  }
+}
+
+/// The operator assignment for the binary operator, except for the logical
+/// operators, which are checked by `logical-assignment-operators`.
+fn operator_assignment(op: BinaryOp) -> Option<AssignOp> {
+  let op = match op {
+    BinaryOp::Add => AssignOp::AddAssign,
+    BinaryOp::Sub => AssignOp::SubAssign,
+    BinaryOp::Mul => AssignOp::MulAssign,
+    BinaryOp::Div => AssignOp::DivAssign,
+    BinaryOp::Mod => AssignOp::ModAssign,
+    BinaryOp::Exp => AssignOp::ExpAssign,
+    BinaryOp::LShift => AssignOp::LShiftAssign,
+    BinaryOp::RShift => AssignOp::RShiftAssign,
+    BinaryOp::ZeroFillRShift => AssignOp::ZeroFillRShiftAssign,
+    BinaryOp::BitAnd => AssignOp::BitAndAssign,
+    BinaryOp::BitOr => AssignOp::BitOrAssign,
+    BinaryOp::BitXor => AssignOp::BitXorAssign,
+    _ => return None,
+  };
+  Some(op)
+}
+
+/// Whether `a = b op a` is the same as `a = a op b`. `+` isn't, since it
+/// concatenates strings.
+fn is_commutative(op: BinaryOp) -> bool {
+  matches!(
+    op,
+    BinaryOp::Mul | BinaryOp::BitAnd | BinaryOp::BitOr | BinaryOp::BitXor
+  )
+}
+
+/// Whether the right side of the operator assignment has to be wrapped in
+/// parentheses to stay the right operand, like `b + c` in `a *= b + c`.
+fn needs_parens(expr: &Expr, op: BinaryOp) -> bool {
+  match expr {
+    Expr::Bin(bin) => bin.op.precedence() <= op.precedence(),
+    Expr::Assign(_)
+    | Expr::Arrow(_)
+    | Expr::Cond(_)
+    | Expr::Seq(_)
+    | Expr::Yield(_)
+    | Expr::TsAs(_)
+    | Expr::TsSatisfies(_) => true,
+    _ => false,
+  }
+}
+
+/// Comments in the range would be lost, so it isn't fixed if there are any.
+fn fix(
+  ctx: &Context,
+  description: &'static str,
+  range: SourceRange,
+  new_text: String,
+) -> Vec<LintFix> {
+  if ctx.comments_within(range).next().is_some() {
+    return vec![];
+  }
+  vec![LintFix {
+    description: description.into(),
+    changes: vec![LintFixChange {
+      new_text: new_text.into(),
+      range,
+    }],
+  }]
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn operator_assignment_valid() {
+    assert_lint_ok! {
+      OperatorAssignment::default(),
+      "a += b;",
+      "a = b + c;",
+      "a = b + a;",
+      "a = a + b + c;",
+      "a = a === b;",
+      "a = a || b;",
+      "a.b = a.c * 2;",
+      "a[b()] = a[b()] + 1;",
+      "foo().a = foo().a * 2;",
+      "a = -a;",
+    };
+
+    assert_lint_ok! {
+      OperatorAssignment::new(OperatorAssignmentOptions {
+        style: OperatorAssignmentStyle::Never,
+      }),
+      "a = a + b;",
+      "a ||= b;",
+      "a = b;",
+    };
+  }
+
+  #[test]
+  fn operator_assignment_invalid() {
+    assert_lint_err! {
+      OperatorAssignment::default(),
+      "a = a + b;": [
+        {
+          col: 0,
+          message: OperatorAssignmentMessage::Assignment(AssignOp::AddAssign),
+          hint: OperatorAssignmentHint::UseOperator(AssignOp::AddAssign),
+          fix: (FIX_DESC, "a += b;"),
+        }
+      ],
+      "a.b = a.b - c * d;": [
+        {
+          col: 0,
+          message: OperatorAssignmentMessage::Assignment(AssignOp::SubAssign),
+          hint: OperatorAssignmentHint::UseOperator(AssignOp::SubAssign),
+          fix: (FIX_DESC, "a.b -= c * d;"),
+        }
+      ],
+      "this.a['b'] = this.a['b'] ** 2 ** 3;": [
+        {
+          col: 0,
+          message: OperatorAssignmentMessage::Assignment(AssignOp::ExpAssign),
+          hint: OperatorAssignmentHint::UseOperator(AssignOp::ExpAssign),
+          fix: (FIX_DESC, "this.a['b'] **= 2 ** 3;"),
+        }
+      ],
+      "a[i] = a[i] >>> 1;": [
+        {
+          col: 0,
+          message: OperatorAssignmentMessage::Assignment(
+            AssignOp::ZeroFillRShiftAssign
+          ),
+          hint: OperatorAssignmentHint::UseOperator(
+            AssignOp::ZeroFillRShiftAssign
+          ),
+          fix: (FIX_DESC, "a[i] >>>= 1;"),
+        }
+      ],
+      "a = b * a;": [
+        {
+          col: 0,
+          message: OperatorAssignmentMessage::Assignment(AssignOp::MulAssign),
+          hint: OperatorAssignmentHint::UseOperator(AssignOp::MulAssign),
+        }
+      ],
+      "a = a /* one */ + 1;": [
+        {
+          col: 0,
+          message: OperatorAssignmentMessage::Assignment(AssignOp::AddAssign),
+          hint: OperatorAssignmentHint::UseOperator(AssignOp::AddAssign),
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      OperatorAssignment::new(OperatorAssignmentOptions {
+        style: OperatorAssignmentStyle::Never,
+      }),
+      "a += b;": [
+        {
+          col: 0,
+          message: OperatorAssignmentMessage::Unexpected(AssignOp::AddAssign),
+          hint: OperatorAssignmentHint::WriteOut,
+          fix: (WRITE_OUT_FIX_DESC, "a = a + b;"),
+        }
+      ],
+      "a.b *= c + d;": [
+        {
+          col: 0,
+          message: OperatorAssignmentMessage::Unexpected(AssignOp::MulAssign),
+          hint: OperatorAssignmentHint::WriteOut,
+          fix: (WRITE_OUT_FIX_DESC, "a.b = a.b * (c + d);"),
+        }
+      ],
+      "a -= b - c;": [
+        {
+          col: 0,
+          message: OperatorAssignmentMessage::Unexpected(AssignOp::SubAssign),
+          hint: OperatorAssignmentHint::WriteOut,
+          fix: (WRITE_OUT_FIX_DESC, "a = a - (b - c);"),
+        }
+      ],
+      "a += b * c;": [
+        {
+          col: 0,
+          message: OperatorAssignmentMessage::Unexpected(AssignOp::AddAssign),
+          hint: OperatorAssignmentHint::WriteOut,
+          fix: (WRITE_OUT_FIX_DESC, "a = a + b * c;"),
+        }
+      ],
+      "a |= b ? c : d;": [
+        {
+          col: 0,
+          message: OperatorAssignmentMessage::Unexpected(AssignOp::BitOrAssign),
+          hint: OperatorAssignmentHint::WriteOut,
+          fix: (WRITE_OUT_FIX_DESC, "a = a | (b ? c : d);"),
+        }
+      ],
+      "foo().a += 1;": [
+        {
+          col: 0,
+          message: OperatorAssignmentMessage::Unexpected(AssignOp::AddAssign),
+          hint: OperatorAssignmentHint::WriteOut,
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn operator_assignment_options() {
+    let options: OperatorAssignmentOptions =
+      serde_json::from_str(r#"{ "style": "never" }"#).unwrap();
+    assert_eq!(options.style, OperatorAssignmentStyle::Never);
+  }
+}
@@ -0,0 +1,381 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::handler::Handler;
+use crate::syntax_features::SyntaxFeatures;
+
+use deno_ast::view::{
+  Accessibility, AssignTarget, Class, ClassMember, ClassProp, Expr, MemberProp,
+  NodeKind, NodeTrait, ParamOrTsParamProp, Pat, PropName, SimpleAssignTarget,
+  Stmt, TsParamProp, TsParamPropParam,
+};
+use deno_ast::SourceRanged;
+use derive_more::Display;
+use serde::Deserialize;
+
+#[derive(Debug, Default)]
+pub struct ParameterProperties {
+  options: ParameterPropertiesOptions,
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ParameterPropertiesOptions {
+  pub prefer: PreferredProperty,
+  /// Modifier combinations that are allowed regardless of `prefer`, e.g.
+  /// `"readonly"` or `"private readonly"`.
+  pub allow: Vec<String>,
+}
+
+/// How properties that are initialized from constructor parameters should be
+/// declared.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum PreferredProperty {
+  /// A class property that is assigned in the constructor.
+  #[default]
+  ClassProperty,
+  /// `constructor(private foo: string)`
+  ParameterProperty,
+}
+
+impl ParameterProperties {
+  pub fn new(options: ParameterPropertiesOptions) -> Self {
+    Self { options }
+  }
+}
+
+const CODE: &str = "parameter-properties";
+
+#[derive(Display)]
+enum ParameterPropertiesMessage {
+  #[display(fmt = "Property `{}` should be declared as a class property", _0)]
+  PreferClassProperty(String),
+  #[display(
+    fmt = "Property `{}` should be declared as a parameter property",
+    _0
+  )]
+  PreferParameterProperty(String),
+}
+
+#[derive(Display)]
+enum ParameterPropertiesHint {
+  #[display(
+    fmt = "Declare the property in the class body and assign it in the constructor"
+  )]
+  PreferClassProperty,
+  #[display(
+    fmt = "Remove the property and the assignment, and add its modifiers to the constructor parameter instead"
+  )]
+  PreferParameterProperty,
+}
+
+impl LintRule for ParameterProperties {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn required_syntax(&self) -> SyntaxFeatures {
+    SyntaxFeatures::CLASS
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "prefer": {
+          "enum": ["class-property", "parameter-property"],
+          "default": "class-property"
+        },
+        "allow": { "type": "array", "items": { "type": "string" } }
+      }
+    }))
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(ParameterPropertiesHandler {
+      options: &self.options,
+    }))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::TsParamProp, NodeKind::Class])
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/parameter_properties.md")
+  }
+}
+
+/// The modifiers of a property as they would be written in `allow`, e.g.
+/// `"private readonly"`.
+fn modifiers(accessibility: Option<Accessibility>, readonly: bool) -> String {
+  let accessibility = match accessibility {
+    Some(Accessibility::Public) => Some("public"),
+    Some(Accessibility::Protected) => Some("protected"),
+    Some(Accessibility::Private) => Some("private"),
+    None => None,
+  };
+  let readonly = if readonly { Some("readonly") } else { None };
+  accessibility
+    .into_iter()
+    .chain(readonly)
+    .collect::<Vec<_>>()
+    .join(" ")
+}
+
+fn param_prop_name(param_prop: &TsParamProp) -> Option<String> {
+  match &param_prop.param {
+    TsParamPropParam::Ident(ident) => Some(ident.id.sym().to_string()),
+    TsParamPropParam::Assign(assign) => match &assign.left {
+      Pat::Ident(ident) => Some(ident.id.sym().to_string()),
+      _ => None,
+    },
+  }
+}
+
+/// Whether the statement is `this.name = name;`.
+fn is_this_assignment(stmt: &Stmt, name: &str) -> bool {
+  let Stmt::Expr(expr_stmt) = stmt else {
+    return false;
+  };
+  let Expr::Assign(assign) = &expr_stmt.expr else {
+    return false;
+  };
+  let AssignTarget::Simple(SimpleAssignTarget::Member(member)) = &assign.left
+  else {
+    return false;
+  };
+  matches!(member.obj, Expr::This(_))
+    && matches!(&member.prop, MemberProp::Ident(prop) if prop.sym() == name)
+    && matches!(&assign.right, Expr::Ident(right) if right.sym() == name)
+}
+
+struct ParameterPropertiesHandler<'a> {
+  options: &'a ParameterPropertiesOptions,
+}
+
+impl<'a> ParameterPropertiesHandler<'a> {
+  fn is_allowed(&self, modifiers: &str) -> bool {
+    self.options.allow.iter().any(|allowed| {
+      allowed.split_whitespace().collect::<Vec<_>>()
+        == modifiers.split_whitespace().collect::<Vec<_>>()
+    })
+  }
+
+  /// Whether the property could be written as a parameter property: it is
+  /// assigned from a constructor parameter of the same name, with the same
+  /// type annotation, at the top level of the constructor.
+  fn is_parameter_property_candidate(
+    &self,
+    prop: &ClassProp,
+    name: &str,
+    class: &Class,
+  ) -> bool {
+    let Some(constructor) = class.body.iter().find_map(|member| match member {
+      ClassMember::Constructor(constructor) => Some(constructor),
+      _ => None,
+    }) else {
+      return false;
+    };
+    let Some(body) = constructor.body else {
+      return false;
+    };
+
+    let param = constructor.params.iter().find_map(|param| match param {
+      ParamOrTsParamProp::Param(param) => match &param.pat {
+        Pat::Ident(ident) if ident.id.sym() == name => Some(ident),
+        _ => None,
+      },
+      ParamOrTsParamProp::TsParamProp(_) => None,
+    });
+    let Some(param) = param else {
+      return false;
+    };
+    let same_type = match (prop.type_ann, param.type_ann) {
+      (Some(prop_type), Some(param_type)) => {
+        prop_type.type_ann.text() == param_type.type_ann.text()
+      }
+      (Some(_), None) => false,
+      (None, _) => true,
+    };
+
+    same_type && body.stmts.iter().any(|stmt| is_this_assignment(stmt, name))
+  }
+}
+
+impl<'a> Handler for ParameterPropertiesHandler<'a> {
+  fn ts_param_prop(&mut self, param_prop: &TsParamProp, ctx: &mut Context) {
+    if self.options.prefer != PreferredProperty::ClassProperty {
+      return;
+    }
+    let modifiers =
+      modifiers(param_prop.accessibility(), param_prop.readonly());
+    if self.is_allowed(&modifiers) {
+      return;
+    }
+    let Some(name) = param_prop_name(param_prop) else {
+      return;
+    };
+    ctx.add_diagnostic_with_hint(
+      param_prop.range(),
+      CODE,
+      ParameterPropertiesMessage::PreferClassProperty(name),
+      ParameterPropertiesHint::PreferClassProperty,
+    );
+  }
+
+  fn class(&mut self, class: &Class, ctx: &mut Context) {
+    if self.options.prefer != PreferredProperty::ParameterProperty {
+      return;
+    }
+    for member in class.body {
+      let ClassMember::ClassProp(prop) = member else {
+        continue;
+      };
+      if prop.is_static() || prop.value.is_some() || prop.declare() {
+        continue;
+      }
+      let PropName::Ident(key) = &prop.key else {
+        continue;
+      };
+      // A parameter property needs at least one modifier.
+      let modifiers = modifiers(prop.accessibility(), prop.readonly());
+      if modifiers.is_empty() || self.is_allowed(&modifiers) {
+        continue;
+      }
+      let name = key.sym().as_ref();
+      if self.is_parameter_property_candidate(prop, name, class) {
+        ctx.add_diagnostic_with_hint(
+          prop.range(),
+          CODE,
+          ParameterPropertiesMessage::PreferParameterProperty(name.to_string()),
+          ParameterPropertiesHint::PreferParameterProperty,
+        );
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn options(prefer: PreferredProperty, allow: &[&str]) -> ParameterProperties {
+    ParameterProperties::new(ParameterPropertiesOptions {
+      prefer,
+      allow: allow.iter().map(|allow| allow.to_string()).collect(),
+    })
+  }
+
+  #[test]
+  fn parameter_properties_valid() {
+    assert_lint_ok! {
+      ParameterProperties::default(),
+      "class Foo { constructor(name: string) {} }",
+      "class Foo { private name: string; constructor(name: string) { this.name = name; } }",
+      "class Foo { constructor({ name }: { name: string }) {} }",
+    };
+
+    assert_lint_ok! {
+      options(PreferredProperty::ClassProperty, &["readonly", "private  readonly"]),
+      "class Foo { constructor(readonly name: string) {} }",
+      "class Foo { constructor(private readonly name: string) {} }",
+    };
+
+    assert_lint_ok! {
+      options(PreferredProperty::ParameterProperty, &[]),
+      "class Foo { constructor(private name: string) {} }",
+      "class Foo { name: string; constructor(name: string) { this.name = name; } }",
+      "class Foo { private name: string; constructor(name: number) { this.name = String(name); } }",
+      "class Foo { private name: string; constructor(name: number) { this.name = name; } }",
+      "class Foo { private name = 'a'; constructor(name: string) { this.name = name; } }",
+      "class Foo { private name: string; constructor(name: string) { if (x) { this.name = name; } } }",
+      "class Foo { private name: string; constructor(other: string) { this.name = other; } }",
+      "class Foo { private static name: string; constructor(name: string) { this.name = name; } }",
+    };
+
+    assert_lint_ok! {
+      options(PreferredProperty::ParameterProperty, &["private"]),
+      "class Foo { private name: string; constructor(name: string) { this.name = name; } }",
+    };
+  }
+
+  #[test]
+  fn parameter_properties_invalid() {
+    assert_lint_err! {
+      ParameterProperties::default(),
+      "class Foo { constructor(private name: string) {} }": [
+        {
+          col: 24,
+          message: ParameterPropertiesMessage::PreferClassProperty(
+            "name".to_string()
+          ),
+          hint: ParameterPropertiesHint::PreferClassProperty,
+        }
+      ],
+      "class Foo { constructor(readonly a: string, public b = 1) {} }": [
+        {
+          col: 24,
+          message: ParameterPropertiesMessage::PreferClassProperty(
+            "a".to_string()
+          ),
+          hint: ParameterPropertiesHint::PreferClassProperty,
+        },
+        {
+          col: 44,
+          message: ParameterPropertiesMessage::PreferClassProperty(
+            "b".to_string()
+          ),
+          hint: ParameterPropertiesHint::PreferClassProperty,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      options(PreferredProperty::ClassProperty, &["readonly"]),
+      "class Foo { constructor(private readonly name: string) {} }": [
+        {
+          col: 24,
+          message: ParameterPropertiesMessage::PreferClassProperty(
+            "name".to_string()
+          ),
+          hint: ParameterPropertiesHint::PreferClassProperty,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      options(PreferredProperty::ParameterProperty, &["readonly"]),
+      "class Foo { private name: string; constructor(name: string) { this.name = name; } }": [
+        {
+          col: 12,
+          message: ParameterPropertiesMessage::PreferParameterProperty(
+            "name".to_string()
+          ),
+          hint: ParameterPropertiesHint::PreferParameterProperty,
+        }
+      ],
+      "class Foo { protected readonly name; constructor(name: string) { this.name = name; } }": [
+        {
+          col: 12,
+          message: ParameterPropertiesMessage::PreferParameterProperty(
+            "name".to_string()
+          ),
+          hint: ParameterPropertiesHint::PreferParameterProperty,
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn parameter_properties_options() {
+    let options: ParameterPropertiesOptions = serde_json::from_str(
+      r#"{ "prefer": "parameter-property", "allow": ["readonly"] }"#,
+    )
+    .unwrap();
+    assert_eq!(options.prefer, PreferredProperty::ParameterProperty);
+    assert_eq!(options.allow, vec!["readonly".to_string()]);
+  }
+}
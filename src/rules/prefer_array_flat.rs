@@ -0,0 +1,260 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::Handler;
+use crate::swc_util::is_member_object;
+use deno_ast::view::{
+  ArrowExpr, BlockStmtOrExpr, CallExpr, Callee, Expr, ExprOrSpread, MemberProp,
+  NodeKind, Pat,
+};
+use deno_ast::SourceRanged;
+use derive_more::Display;
+
+#[derive(Debug)]
+pub struct PreferArrayFlat;
+
+const CODE: &str = "prefer-array-flat";
+const FIX_DESC: &str = "Replace with `flat()`";
+
+#[derive(Display)]
+enum PreferArrayFlatMessage {
+  #[display(fmt = "Use `flat()` to flatten an array")]
+  UseFlat,
+}
+
+#[derive(Display)]
+enum PreferArrayFlatHint {
+  #[display(fmt = "`{}.flat()` flattens the array by one level", _0)]
+  Flatten(String),
+}
+
+impl LintRule for PreferArrayFlat {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(PreferArrayFlatHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::CallExpr])
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/prefer_array_flat.md")
+  }
+}
+
+fn is_empty_array(expr: &Expr) -> bool {
+  matches!(expr, Expr::Array(array) if array.elems.is_empty())
+}
+
+fn param_name<'a>(pat: &'a Pat) -> Option<&'a str> {
+  match pat {
+    Pat::Ident(binding) => Some(binding.id.sym().as_ref()),
+    _ => None,
+  }
+}
+
+fn is_ident(expr: &Expr, name: &str) -> bool {
+  matches!(expr, Expr::Ident(ident) if ident.sym() == name)
+}
+
+/// Whether the callback is `(a, b) => a.concat(b)` or `(a, b) => [...a, ...b]`.
+fn is_concat_callback(arrow: &ArrowExpr) -> bool {
+  let [acc, item] = arrow.params else {
+    return false;
+  };
+  let (Some(acc), Some(item)) = (param_name(acc), param_name(item)) else {
+    return false;
+  };
+  let BlockStmtOrExpr::Expr(body) = arrow.body else {
+    return false;
+  };
+  match body {
+    Expr::Call(call) => {
+      let Callee::Expr(Expr::Member(callee)) = &call.callee else {
+        return false;
+      };
+      let [arg] = call.args else {
+        return false;
+      };
+      is_ident(&callee.obj, acc)
+        && matches!(&callee.prop, MemberProp::Ident(prop) if prop.sym() == "concat")
+        && arg.spread().is_none()
+        && is_ident(&arg.expr, item)
+    }
+    Expr::Array(array) => match array.elems {
+      [Some(first), Some(second)] => {
+        first.spread().is_some()
+          && is_ident(&first.expr, acc)
+          && second.spread().is_some()
+          && is_ident(&second.expr, item)
+      }
+      _ => false,
+    },
+    _ => false,
+  }
+}
+
+/// The array that's flattened by `[].concat(...arr)`.
+fn concat_spread<'a>(
+  obj: &Expr<'a>,
+  args: &[&'a ExprOrSpread<'a>],
+) -> Option<Expr<'a>> {
+  let [arg] = args else {
+    return None;
+  };
+  (is_empty_array(obj) && arg.spread().is_some()).then_some(arg.expr)
+}
+
+/// The array that's flattened by `arr.reduce((a, b) => a.concat(b), [])`.
+fn reduce_concat<'a>(
+  obj: &Expr<'a>,
+  args: &[&'a ExprOrSpread<'a>],
+) -> Option<Expr<'a>> {
+  let (callback, initial) = match args {
+    [callback] => (callback, None),
+    [callback, initial] => (callback, Some(initial)),
+    _ => return None,
+  };
+  let Expr::Arrow(arrow) = callback.expr else {
+    return None;
+  };
+  if callback.spread().is_some() || !is_concat_callback(arrow) {
+    return None;
+  }
+  if let Some(initial) = initial {
+    if initial.spread().is_some() || !is_empty_array(&initial.expr) {
+      return None;
+    }
+  }
+  Some(*obj)
+}
+
+struct PreferArrayFlatHandler;
+
+impl Handler for PreferArrayFlatHandler {
+  fn call_expr(&mut self, call_expr: &CallExpr, ctx: &mut Context) {
+    let Callee::Expr(Expr::Member(callee)) = &call_expr.callee else {
+      return;
+    };
+    let MemberProp::Ident(prop) = &callee.prop else {
+      return;
+    };
+    let array = match prop.sym().as_ref() {
+      "concat" => concat_spread(&callee.obj, call_expr.args),
+      "reduce" => reduce_concat(&callee.obj, call_expr.args),
+      _ => None,
+    };
+    let Some(array) = array else {
+      return;
+    };
+    let text = array.text_fast(ctx.text_info());
+    let array = if is_member_object(&array) {
+      text.to_string()
+    } else {
+      format!("({text})")
+    };
+    ctx.add_diagnostic_with_fixes(
+      call_expr.range(),
+      CODE,
+      PreferArrayFlatMessage::UseFlat,
+      Some(PreferArrayFlatHint::Flatten(array.clone()).to_string()),
+      vec![LintFix {
+        description: FIX_DESC.into(),
+        changes: vec![LintFixChange {
+          new_text: format!("{array}.flat()").into(),
+          range: call_expr.range(),
+        }],
+      }],
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn prefer_array_flat_valid() {
+    assert_lint_ok! {
+      PreferArrayFlat,
+      "arr.flat()",
+      "[].concat(arr)",
+      "[].concat(...a, ...b)",
+      "[1].concat(...arr)",
+      "foo.concat(...arr)",
+      "arr.reduce((a, b) => a.concat(b), [1])",
+      "arr.reduce((a, b) => b.concat(a), [])",
+      "arr.reduce((a, b) => a.concat(b, c), [])",
+      "arr.reduce((a, b) => a.concat(...b), [])",
+      "arr.reduce((a, b) => [...a, b], [])",
+      "arr.reduce((a, b) => { return a.concat(b); }, [])",
+      "arr.reduce(concat, [])",
+      "arr.reduceRight((a, b) => a.concat(b), [])",
+    };
+  }
+
+  #[test]
+  fn prefer_array_flat_invalid() {
+    assert_lint_err! {
+      PreferArrayFlat,
+      "[].concat(...arr)": [
+        {
+          col: 0,
+          message: PreferArrayFlatMessage::UseFlat,
+          hint: PreferArrayFlatHint::Flatten("arr".to_string()),
+          fix: (FIX_DESC, "arr.flat()"),
+        }
+      ],
+      "const all = [].concat(...(await getLists()));": [
+        {
+          col: 12,
+          message: PreferArrayFlatMessage::UseFlat,
+          hint: PreferArrayFlatHint::Flatten("(await getLists())".to_string()),
+          fix: (FIX_DESC, "const all = (await getLists()).flat();"),
+        }
+      ],
+      "[].concat(...a ?? b)": [
+        {
+          col: 0,
+          message: PreferArrayFlatMessage::UseFlat,
+          hint: PreferArrayFlatHint::Flatten("(a ?? b)".to_string()),
+          fix: (FIX_DESC, "(a ?? b).flat()"),
+        }
+      ],
+      "arr.reduce((a, b) => a.concat(b), [])": [
+        {
+          col: 0,
+          message: PreferArrayFlatMessage::UseFlat,
+          hint: PreferArrayFlatHint::Flatten("arr".to_string()),
+          fix: (FIX_DESC, "arr.flat()"),
+        }
+      ],
+      "lists.reduce((acc, list) => acc.concat(list))": [
+        {
+          col: 0,
+          message: PreferArrayFlatMessage::UseFlat,
+          hint: PreferArrayFlatHint::Flatten("lists".to_string()),
+          fix: (FIX_DESC, "lists.flat()"),
+        }
+      ],
+      "foo.bar.reduce((a, b) => [...a, ...b], [])": [
+        {
+          col: 0,
+          message: PreferArrayFlatMessage::UseFlat,
+          hint: PreferArrayFlatHint::Flatten("foo.bar".to_string()),
+          fix: (FIX_DESC, "foo.bar.flat()"),
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,160 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::Handler;
+use deno_ast::view::{CallExpr, Callee, Expr, Lit, MemberProp, NodeKind};
+use deno_ast::{SourceRange, SourceRanged};
+use derive_more::Display;
+
+#[derive(Debug)]
+pub struct PreferArrayFlatMap;
+
+const CODE: &str = "prefer-array-flat-map";
+const FIX_DESC: &str = "Replace with `flatMap()`";
+
+#[derive(Display)]
+enum PreferArrayFlatMapMessage {
+  #[display(fmt = "Use `flatMap()` instead of `map().flat()`")]
+  UseFlatMap,
+}
+
+#[derive(Display)]
+enum PreferArrayFlatMapHint {
+  #[display(
+    fmt = "`flatMap()` maps and flattens the result by one level in a single pass"
+  )]
+  SinglePass,
+}
+
+impl LintRule for PreferArrayFlatMap {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(PreferArrayFlatMapHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::CallExpr])
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/prefer_array_flat_map.md")
+  }
+}
+
+fn is_prop(prop: &MemberProp, name: &str) -> bool {
+  matches!(prop, MemberProp::Ident(ident) if ident.sym() == name)
+}
+
+struct PreferArrayFlatMapHandler;
+
+impl Handler for PreferArrayFlatMapHandler {
+  fn call_expr(&mut self, call_expr: &CallExpr, ctx: &mut Context) {
+    let Callee::Expr(Expr::Member(flat)) = &call_expr.callee else {
+      return;
+    };
+    if !is_prop(&flat.prop, "flat") {
+      return;
+    }
+    // `flatMap()` only flattens one level, so `flat(2)` can't be replaced.
+    let flattens_one_level = match call_expr.args {
+      [] => true,
+      [depth] => {
+        depth.spread().is_none()
+          && matches!(depth.expr, Expr::Lit(Lit::Num(num)) if num.value() == 1.0)
+      }
+      _ => false,
+    };
+    if !flattens_one_level {
+      return;
+    }
+    let Expr::Call(map_call) = &flat.obj else {
+      return;
+    };
+    let Callee::Expr(Expr::Member(map)) = &map_call.callee else {
+      return;
+    };
+    if !is_prop(&map.prop, "map") {
+      return;
+    }
+    ctx.add_diagnostic_with_fixes(
+      call_expr.range(),
+      CODE,
+      PreferArrayFlatMapMessage::UseFlatMap,
+      Some(PreferArrayFlatMapHint::SinglePass.to_string()),
+      vec![LintFix {
+        description: FIX_DESC.into(),
+        changes: vec![
+          LintFixChange {
+            new_text: "flatMap".into(),
+            range: map.prop.range(),
+          },
+          LintFixChange {
+            new_text: "".into(),
+            range: SourceRange::new(map_call.end(), call_expr.end()),
+          },
+        ],
+      }],
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn prefer_array_flat_map_valid() {
+    assert_lint_ok! {
+      PreferArrayFlatMap,
+      "arr.flatMap(f)",
+      "arr.map(f)",
+      "arr.flat()",
+      "arr.map(f).flat(2)",
+      "arr.map(f).flat(depth)",
+      "arr.map(f).flat(Infinity)",
+      "arr.filter(f).flat()",
+      "map(f).flat()",
+      "arr.map(f).foo.flat()",
+    };
+  }
+
+  #[test]
+  fn prefer_array_flat_map_invalid() {
+    assert_lint_err! {
+      PreferArrayFlatMap,
+      "arr.map(f).flat()": [
+        {
+          col: 0,
+          message: PreferArrayFlatMapMessage::UseFlatMap,
+          hint: PreferArrayFlatMapHint::SinglePass,
+          fix: (FIX_DESC, "arr.flatMap(f)"),
+        }
+      ],
+      "const words = lines.map((line) => line.split(' ')).flat(1);": [
+        {
+          col: 14,
+          message: PreferArrayFlatMapMessage::UseFlatMap,
+          hint: PreferArrayFlatMapHint::SinglePass,
+          fix: (FIX_DESC, "const words = lines.flatMap((line) => line.split(' '));"),
+        }
+      ],
+      "arr\n  .map(f)\n  .flat()": [
+        {
+          col: 0,
+          message: PreferArrayFlatMapMessage::UseFlatMap,
+          hint: PreferArrayFlatMapHint::SinglePass,
+          fix: (FIX_DESC, "arr\n  .flatMap(f)"),
+        }
+      ],
+    };
+  }
+}
@@ -1,11 +1,10 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
-use crate::Program;
+use crate::handler::Handler;
 use deno_ast::view::{
-  ArrayPat, BindingIdent, Expr, Lit, ObjectPat, Pat, TsAsExpr, TsLit, TsType,
-  TsTypeAnn, TsTypeAssertion, VarDecl,
+  ArrayPat, BindingIdent, Expr, Lit, NodeKind, ObjectPat, Pat, TsAsExpr, TsLit,
+  TsType, TsTypeAnn, TsTypeAssertion, VarDecl,
 };
 use deno_ast::{SourceRange, SourceRanged};
 use derive_more::Display;
@@ -38,12 +37,16 @@ impl LintRule for PreferAsConst {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    PreferAsConstHandler.traverse(program, context);
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(PreferAsConstHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[
+      NodeKind::TsAsExpr,
+      NodeKind::TsTypeAssertion,
+      NodeKind::VarDecl,
+    ])
   }
 
   #[cfg(feature = "docs")]
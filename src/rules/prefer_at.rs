@@ -0,0 +1,259 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::Handler;
+use crate::swc_util::{is_same_expr_reference, is_side_effect_free};
+use deno_ast::swc::ast;
+use deno_ast::view::{
+  BinaryOp, CallExpr, Callee, Expr, Lit, MemberExpr, MemberProp, Node,
+  NodeKind, UnaryOp,
+};
+use deno_ast::{SourceRanged, SourceRangedForSpanned};
+use derive_more::Display;
+
+#[derive(Debug)]
+pub struct PreferAt;
+
+const CODE: &str = "prefer-at";
+const FIX_DESC: &str = "Replace with `at()`";
+
+#[derive(Display)]
+enum PreferAtMessage {
+  #[display(fmt = "Use `at(-{})` to read from the end", _0)]
+  UseAt(u64),
+}
+
+#[derive(Display)]
+enum PreferAtHint {
+  #[display(fmt = "`at()` counts negative indexes from the end")]
+  NegativeIndex,
+  #[display(
+    fmt = "`at()` counts negative indexes from the end, but returns `undefined` instead of `\"\"` when the string is too short"
+  )]
+  CharAt,
+}
+
+impl LintRule for PreferAt {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(PreferAtHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::MemberExpr, NodeKind::CallExpr])
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/prefer_at.md")
+  }
+}
+
+/// Whether both expressions read the same array or string without side
+/// effects, like `a.b` or `a[i + 1]`, so reading it once instead of twice
+/// doesn't change what the code does. Property reads are assumed not to run
+/// getters.
+fn is_same_receiver(a: &ast::Expr, b: &ast::Expr, ctx: &Context) -> bool {
+  is_same_expr_reference(a, b)
+    || (ctx.text_of(a.range()) == ctx.text_of(b.range())
+      && is_side_effect_free(a, true))
+}
+
+/// `n` if the expression is `obj.length - n`, where `obj` is the same
+/// receiver as the given object and `n` is a positive integer.
+fn offset_from_end(expr: &Expr, obj: &ast::Expr, ctx: &Context) -> Option<u64> {
+  let Expr::Bin(bin) = expr else {
+    return None;
+  };
+  if bin.op() != BinaryOp::Sub {
+    return None;
+  }
+  let Expr::Member(length) = bin.left else {
+    return None;
+  };
+  let is_length =
+    matches!(&length.prop, MemberProp::Ident(prop) if prop.sym() == "length");
+  if !is_length || !is_same_receiver(&length.inner.obj, obj, ctx) {
+    return None;
+  }
+  let Expr::Lit(Lit::Num(num)) = bin.right else {
+    return None;
+  };
+  let value = num.value();
+  (value >= 1.0 && value.fract() == 0.0).then_some(value as u64)
+}
+
+/// Whether the member expression is written to, so it can't be replaced with
+/// a call.
+fn is_write_target(member: &MemberExpr) -> bool {
+  match member.parent() {
+    Node::AssignExpr(assign) => assign.left.range() == member.range(),
+    Node::UnaryExpr(unary) => unary.op() == UnaryOp::Delete,
+    Node::UpdateExpr(_)
+    | Node::ArrayPat(_)
+    | Node::ObjectPat(_)
+    | Node::KeyValuePatProp(_)
+    | Node::AssignPat(_)
+    | Node::RestPat(_)
+    | Node::ForInStmt(_)
+    | Node::ForOfStmt(_) => true,
+    _ => false,
+  }
+}
+
+struct PreferAtHandler;
+
+impl Handler for PreferAtHandler {
+  fn member_expr(&mut self, member: &MemberExpr, ctx: &mut Context) {
+    let MemberProp::Computed(computed) = &member.prop else {
+      return;
+    };
+    let Some(offset) = offset_from_end(&computed.expr, &member.inner.obj, ctx)
+    else {
+      return;
+    };
+    // `arr?.[arr.length - 1]` would need to keep the optional chaining.
+    if matches!(member.parent(), Node::OptChainExpr(_))
+      || is_write_target(member)
+    {
+      return;
+    }
+    let obj = member.obj.text_fast(ctx.text_info());
+    ctx.add_diagnostic_with_fixes(
+      member.range(),
+      CODE,
+      PreferAtMessage::UseAt(offset),
+      Some(PreferAtHint::NegativeIndex.to_string()),
+      vec![LintFix {
+        description: FIX_DESC.into(),
+        changes: vec![LintFixChange {
+          new_text: format!("{obj}.at(-{offset})").into(),
+          range: member.range(),
+        }],
+      }],
+    );
+  }
+
+  fn call_expr(&mut self, call_expr: &CallExpr, ctx: &mut Context) {
+    let Callee::Expr(Expr::Member(callee)) = &call_expr.callee else {
+      return;
+    };
+    if !matches!(&callee.prop, MemberProp::Ident(prop) if prop.sym() == "charAt")
+    {
+      return;
+    }
+    let [index] = call_expr.args else {
+      return;
+    };
+    if index.spread().is_some() {
+      return;
+    }
+    let Some(offset) = offset_from_end(&index.expr, &callee.inner.obj, ctx)
+    else {
+      return;
+    };
+    // `charAt()` returns `""` for an index out of range, so there's no fix.
+    ctx.add_diagnostic_with_hint(
+      call_expr.range(),
+      CODE,
+      PreferAtMessage::UseAt(offset),
+      PreferAtHint::CharAt,
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn prefer_at_valid() {
+    assert_lint_ok! {
+      PreferAt,
+      "arr.at(-1)",
+      "arr[0]",
+      "arr[arr.length]",
+      "arr[arr.length + 1]",
+      "arr[arr.length - i]",
+      "arr[arr.length - 0]",
+      "arr[arr.length - 1.5]",
+      "arr[other.length - 1]",
+      "foo()[foo().length - 1]",
+      "a[i++][a[i++].length - 1]",
+      "a[f(x)][a[f(x)].length - 1]",
+      "a.b[a.c.length - 1]",
+      "arr?.[arr.length - 1]",
+      "arr[arr.length - 1] = 1",
+      "arr[arr.length - 1] += 1",
+      "arr[arr.length - 1]++",
+      "delete arr[arr.length - 1]",
+      "[arr[arr.length - 1]] = [1]",
+      "({ a: arr[arr.length - 1] } = obj)",
+      "for (arr[arr.length - 1] of items) {}",
+      "str.charAt(0)",
+      "str.charAt(other.length - 1)",
+    };
+  }
+
+  #[test]
+  fn prefer_at_invalid() {
+    assert_lint_err! {
+      PreferAt,
+      "arr[arr.length - 1]": [
+        {
+          col: 0,
+          message: PreferAtMessage::UseAt(1),
+          hint: PreferAtHint::NegativeIndex,
+          fix: (FIX_DESC, "arr.at(-1)"),
+        }
+      ],
+      "const last = this.items[this.items.length - 2];": [
+        {
+          col: 13,
+          message: PreferAtMessage::UseAt(2),
+          hint: PreferAtHint::NegativeIndex,
+          fix: (FIX_DESC, "const last = this.items.at(-2);"),
+        }
+      ],
+      "a.b['c'][a.b['c'].length - 1]": [
+        {
+          col: 0,
+          message: PreferAtMessage::UseAt(1),
+          hint: PreferAtHint::NegativeIndex,
+          fix: (FIX_DESC, "a.b['c'].at(-1)"),
+        }
+      ],
+      "rows[i + 1][rows[i + 1].length - 1]": [
+        {
+          col: 0,
+          message: PreferAtMessage::UseAt(1),
+          hint: PreferAtHint::NegativeIndex,
+          fix: (FIX_DESC, "rows[i + 1].at(-1)"),
+        }
+      ],
+      "foo(arr[arr.length - 1])": [
+        {
+          col: 4,
+          message: PreferAtMessage::UseAt(1),
+          hint: PreferAtHint::NegativeIndex,
+          fix: (FIX_DESC, "foo(arr.at(-1))"),
+        }
+      ],
+      "str.charAt(str.length - 1)": [
+        {
+          col: 0,
+          message: PreferAtMessage::UseAt(1),
+          hint: PreferAtHint::CharAt,
+        }
+      ],
+    };
+  }
+}
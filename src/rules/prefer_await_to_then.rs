@@ -0,0 +1,103 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::handler::Handler;
+use crate::swc_util::enclosing_function;
+use deno_ast::view::{
+  CallExpr, Callee, Expr, MemberProp, Node, NodeKind, NodeTrait,
+};
+use deno_ast::SourceRanged;
+
+#[derive(Debug)]
+pub struct PreferAwaitToThen;
+
+const CODE: &str = "prefer-await-to-then";
+const MESSAGE: &str = "Prefer `await` to `.then()` in async functions";
+const HINT: &str =
+  "Await the promise instead, e.g. `const value = await promise;`";
+
+impl LintRule for PreferAwaitToThen {
+  fn tags(&self) -> &'static [&'static str] {
+    &["promise"]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn aliases(&self) -> &'static [&'static str] {
+    &["promise/prefer-await-to-then"]
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(PreferAwaitToThenHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::CallExpr])
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/prefer_await_to_then.md")
+  }
+}
+
+struct PreferAwaitToThenHandler;
+
+impl Handler for PreferAwaitToThenHandler {
+  fn call_expr(&mut self, call_expr: &CallExpr, ctx: &mut Context) {
+    let Callee::Expr(Expr::Member(callee)) = &call_expr.callee else {
+      return;
+    };
+    let MemberProp::Ident(prop) = &callee.prop else {
+      return;
+    };
+    if prop.sym() != "then" {
+      return;
+    }
+    let is_async = match enclosing_function(call_expr.as_node()) {
+      Some(Node::Function(function)) => function.is_async(),
+      Some(Node::ArrowExpr(arrow)) => arrow.is_async(),
+      _ => false,
+    };
+    if is_async {
+      ctx.add_diagnostic_with_hint(prop.range(), CODE, MESSAGE, HINT);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn prefer_await_to_then_valid() {
+    assert_lint_ok! {
+      PreferAwaitToThen,
+      "async function f() { const value = await fetch(url); }",
+      "function f() { return fetch(url).then((res) => res.json()); }",
+      "fetch(url).then((res) => res.json());",
+      "async function f() { items.forEach(() => p.then(log)); }",
+      "async function f() { return obj.then; }",
+      "async function f() { await p.catch(handle); }",
+    };
+  }
+
+  #[test]
+  fn prefer_await_to_then_invalid() {
+    assert_lint_err! {
+      PreferAwaitToThen,
+      "async function f() { return fetch(url).then((res) => res.json()); }": [
+        { col: 39, message: MESSAGE, hint: HINT }
+      ],
+      "const f = async () => { p.then(a).then(b); };": [
+        { col: 26, message: MESSAGE, hint: HINT },
+        { col: 34, message: MESSAGE, hint: HINT }
+      ],
+      "class A { async m() { await this.load().then(done); } }": [
+        { col: 40, message: MESSAGE, hint: HINT }
+      ],
+    };
+  }
+}
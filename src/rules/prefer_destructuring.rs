@@ -0,0 +1,392 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::Handler;
+
+use deno_ast::swc::ast::{
+  AssignOp, AssignTarget, Expr, Ident, Lit, MemberExpr, MemberProp, Pat,
+  SimpleAssignTarget,
+};
+use deno_ast::view::{AssignExpr, NodeKind, VarDeclarator};
+use deno_ast::{SourceRange, SourceRangedForSpanned};
+use derive_more::Display;
+use serde::Deserialize;
+
+#[derive(Debug, Default)]
+pub struct PreferDestructuring {
+  options: PreferDestructuringOptions,
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct PreferDestructuringOptions {
+  /// Which kinds of destructuring to enforce in variable declarations, like
+  /// `const a = obj.a;`.
+  pub declaration: DestructuringKinds,
+  /// Which kinds of destructuring to enforce in assignments, like
+  /// `a = obj.a;`.
+  pub assignment: DestructuringKinds,
+  /// Whether to also report properties assigned to variables with another
+  /// name, like `const b = obj.a;`, and computed properties, like
+  /// `const a = obj[key];`.
+  pub enforce_for_renamed_properties: bool,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct DestructuringKinds {
+  /// Report elements read by index, like `arr[0]`.
+  pub array: bool,
+  /// Report properties, like `obj.a`.
+  pub object: bool,
+}
+
+impl Default for DestructuringKinds {
+  fn default() -> Self {
+    Self {
+      array: true,
+      object: true,
+    }
+  }
+}
+
+impl PreferDestructuring {
+  pub fn new(options: PreferDestructuringOptions) -> Self {
+    Self { options }
+  }
+}
+
+const CODE: &str = "prefer-destructuring";
+
+#[derive(Display)]
+enum PreferDestructuringMessage {
+  #[display(fmt = "Use object destructuring")]
+  Object,
+  #[display(fmt = "Use array destructuring")]
+  Array,
+}
+
+#[derive(Display)]
+enum PreferDestructuringHint {
+  #[display(fmt = "Destructure the property from the object")]
+  Object,
+  #[display(fmt = "Destructure the element from the array")]
+  Array,
+}
+
+const FIX_DESC: &str = "Use object destructuring";
+
+impl LintRule for PreferDestructuring {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    let kinds = serde_json::json!({
+      "type": "object",
+      "properties": {
+        "array": { "type": "boolean" },
+        "object": { "type": "boolean" }
+      }
+    });
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "declaration": kinds,
+        "assignment": kinds,
+        "enforceForRenamedProperties": { "type": "boolean" }
+      }
+    }))
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(PreferDestructuringHandler {
+      options: &self.options,
+    }))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::VarDeclarator, NodeKind::AssignExpr])
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/prefer_destructuring.md")
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Destructuring {
+  Array,
+  Object,
+  /// An object property that's assigned to a variable with the same name,
+  /// which can be fixed to a shorthand pattern.
+  SameName,
+}
+
+struct PreferDestructuringHandler<'a> {
+  options: &'a PreferDestructuringOptions,
+}
+
+impl PreferDestructuringHandler<'_> {
+  /// How the member that's assigned to the variable could be destructured,
+  /// if the options enforce it.
+  fn destructuring(
+    &self,
+    ident: &Ident,
+    member: &MemberExpr,
+    kinds: DestructuringKinds,
+  ) -> Option<Destructuring> {
+    let destructuring = match &member.prop {
+      MemberProp::Ident(prop) if prop.sym == ident.sym => {
+        Destructuring::SameName
+      }
+      MemberProp::Computed(computed) if is_index(&computed.expr) => {
+        Destructuring::Array
+      }
+      MemberProp::PrivateName(_) => return None,
+      _ if self.options.enforce_for_renamed_properties => Destructuring::Object,
+      _ => return None,
+    };
+    let enforced = match destructuring {
+      Destructuring::Array => kinds.array,
+      Destructuring::Object | Destructuring::SameName => kinds.object,
+    };
+    enforced.then_some(destructuring)
+  }
+}
+
+/// Whether the expression is an index of an array, like `0`.
+fn is_index(expr: &Expr) -> bool {
+  match expr {
+    Expr::Lit(Lit::Num(num)) => num.value >= 0.0 && num.value.fract() == 0.0,
+    _ => false,
+  }
+}
+
+fn add_diagnostic(
+  ctx: &mut Context,
+  range: SourceRange,
+  destructuring: Destructuring,
+  fixes: Vec<LintFix>,
+) {
+  let (message, hint) = match destructuring {
+    Destructuring::Array => (
+      PreferDestructuringMessage::Array,
+      PreferDestructuringHint::Array,
+    ),
+    Destructuring::Object | Destructuring::SameName => (
+      PreferDestructuringMessage::Object,
+      PreferDestructuringHint::Object,
+    ),
+  };
+  ctx.add_diagnostic_with_fixes(
+    range,
+    CODE,
+    message,
+    Some(hint.to_string()),
+    fixes,
+  );
+}
+
+impl Handler for PreferDestructuringHandler<'_> {
+  fn var_declarator(&mut self, declarator: &VarDeclarator, ctx: &mut Context) {
+    let declarator = declarator.inner;
+    // Destructuring would lose the type annotation.
+    let Pat::Ident(binding) = &declarator.name else {
+      return;
+    };
+    if binding.type_ann.is_some() {
+      return;
+    }
+    let Some(Expr::Member(member)) = declarator.init.as_deref() else {
+      return;
+    };
+    let Some(destructuring) =
+      self.destructuring(&binding.id, member, self.options.declaration)
+    else {
+      return;
+    };
+
+    // Only properties with the same name are fixed, since reading an
+    // element by index and destructuring an iterable differ.
+    let mut fixes = vec![];
+    if destructuring == Destructuring::SameName
+      && ctx.comments_within(declarator.range()).next().is_none()
+    {
+      fixes.push(LintFix {
+        description: FIX_DESC.into(),
+        changes: vec![LintFixChange {
+          new_text: format!(
+            "{{ {} }} = {}",
+            binding.id.sym,
+            member.obj.text_fast(ctx.text_info())
+          )
+          .into(),
+          range: declarator.range(),
+        }],
+      });
+    }
+    add_diagnostic(ctx, declarator.range(), destructuring, fixes);
+  }
+
+  fn assign_expr(&mut self, assign_expr: &AssignExpr, ctx: &mut Context) {
+    let assign_expr = assign_expr.inner;
+    if assign_expr.op != AssignOp::Assign {
+      return;
+    }
+    let AssignTarget::Simple(SimpleAssignTarget::Ident(binding)) =
+      &assign_expr.left
+    else {
+      return;
+    };
+    let Expr::Member(member) = &*assign_expr.right else {
+      return;
+    };
+    if let Some(destructuring) =
+      self.destructuring(&binding.id, member, self.options.assignment)
+    {
+      add_diagnostic(ctx, assign_expr.range(), destructuring, vec![]);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn prefer_destructuring_valid() {
+    assert_lint_ok! {
+      PreferDestructuring::default(),
+      "const { a } = obj;",
+      "const [a] = arr;",
+      "const b = obj.a;",
+      "const a = obj[key];",
+      "const a = obj['a'];",
+      "const a = arr[-1];",
+      "const a = arr[1.5];",
+      "const a: number = obj.a;",
+      "const a = obj?.a;",
+      "const a = obj.a.b;",
+      "class A extends B { foo() { const foo = super.foo; } }",
+      "class A { #a = 1; foo() { const a = this.#a; } }",
+      "a += obj.a;",
+      "obj.b = obj.a;",
+      "let a; ({ a } = obj);",
+    };
+
+    assert_lint_ok! {
+      PreferDestructuring::new(PreferDestructuringOptions {
+        declaration: DestructuringKinds {
+          array: false,
+          object: true,
+        },
+        assignment: DestructuringKinds {
+          array: false,
+          object: false,
+        },
+        enforce_for_renamed_properties: false,
+      }),
+      "const a = arr[0];",
+      "a = obj.a;",
+      "a = arr[0];",
+    };
+  }
+
+  #[test]
+  fn prefer_destructuring_invalid() {
+    assert_lint_err! {
+      PreferDestructuring::default(),
+      "const a = obj.a;": [
+        {
+          col: 6,
+          message: PreferDestructuringMessage::Object,
+          hint: PreferDestructuringHint::Object,
+          fix: (FIX_DESC, "const { a } = obj;"),
+        }
+      ],
+      "let foo = this.props.foo;": [
+        {
+          col: 4,
+          message: PreferDestructuringMessage::Object,
+          hint: PreferDestructuringHint::Object,
+          fix: (FIX_DESC, "let { foo } = this.props;"),
+        }
+      ],
+      "var a = getObj().a, b = 1;": [
+        {
+          col: 4,
+          message: PreferDestructuringMessage::Object,
+          hint: PreferDestructuringHint::Object,
+          fix: (FIX_DESC, "var { a } = getObj(), b = 1;"),
+        }
+      ],
+      "const a = obj /* comment */ .a;": [
+        {
+          col: 6,
+          message: PreferDestructuringMessage::Object,
+          hint: PreferDestructuringHint::Object,
+        }
+      ],
+      "const x = arr[0];": [
+        {
+          col: 6,
+          message: PreferDestructuringMessage::Array,
+          hint: PreferDestructuringHint::Array,
+        }
+      ],
+      "let a; a = obj.a;": [
+        {
+          col: 7,
+          message: PreferDestructuringMessage::Object,
+          hint: PreferDestructuringHint::Object,
+        }
+      ],
+      "let x; x = arr[1];": [
+        {
+          col: 7,
+          message: PreferDestructuringMessage::Array,
+          hint: PreferDestructuringHint::Array,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      PreferDestructuring::new(PreferDestructuringOptions {
+        enforce_for_renamed_properties: true,
+        ..Default::default()
+      }),
+      "const b = obj.a;": [
+        {
+          col: 6,
+          message: PreferDestructuringMessage::Object,
+          hint: PreferDestructuringHint::Object,
+        }
+      ],
+      "const a = obj[key];": [
+        {
+          col: 6,
+          message: PreferDestructuringMessage::Object,
+          hint: PreferDestructuringHint::Object,
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn prefer_destructuring_options() {
+    let options: PreferDestructuringOptions = serde_json::from_str(
+      r#"{ "assignment": { "array": false }, "enforceForRenamedProperties": true }"#,
+    )
+    .unwrap();
+    assert!(options.declaration.array && options.declaration.object);
+    assert!(!options.assignment.array && options.assignment.object);
+    assert!(options.enforce_for_renamed_properties);
+  }
+}
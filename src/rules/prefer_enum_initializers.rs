@@ -0,0 +1,197 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::Handler;
+use crate::syntax_features::SyntaxFeatures;
+
+use deno_ast::view::{Expr, Lit, NodeKind, TsEnumDecl, TsEnumMemberId};
+use deno_ast::{SourceRange, SourceRanged};
+use derive_more::Display;
+
+#[derive(Debug)]
+pub struct PreferEnumInitializers;
+
+const CODE: &str = "prefer-enum-initializers";
+
+#[derive(Display)]
+enum PreferEnumInitializersMessage {
+  #[display(
+    fmt = "The value of the enum member `{}` should be explicitly defined",
+    _0
+  )]
+  MissingInitializer(String),
+}
+
+#[derive(Display)]
+enum PreferEnumInitializersHint {
+  #[display(
+    fmt = "Add an initializer, so that adding or reordering members doesn't change its value"
+  )]
+  AddInitializer,
+}
+
+impl LintRule for PreferEnumInitializers {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn required_syntax(&self) -> SyntaxFeatures {
+    SyntaxFeatures::ENUM
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(PreferEnumInitializersHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::TsEnumDecl])
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/prefer_enum_initializers.md")
+  }
+}
+
+fn member_name(id: &TsEnumMemberId) -> String {
+  match id {
+    TsEnumMemberId::Ident(ident) => ident.sym().to_string(),
+    TsEnumMemberId::Str(str) => str.value().to_string(),
+  }
+}
+
+fn insert_fix(
+  description: String,
+  range: SourceRange,
+  text: String,
+) -> LintFix {
+  LintFix {
+    description: description.into(),
+    changes: vec![LintFixChange {
+      new_text: text.into(),
+      range: SourceRange::new(range.end, range.end),
+    }],
+  }
+}
+
+struct PreferEnumInitializersHandler;
+
+impl Handler for PreferEnumInitializersHandler {
+  fn ts_enum_decl(&mut self, enum_decl: &TsEnumDecl, ctx: &mut Context) {
+    // The value TypeScript implicitly gives to the current member, if it is
+    // known.
+    let mut implicit_value = Some(0.0);
+    for member in enum_decl.members {
+      if let Some(init) = &member.init {
+        implicit_value = match init {
+          Expr::Lit(Lit::Num(num)) => Some(num.value() + 1.0),
+          _ => None,
+        };
+        continue;
+      }
+      let name = member_name(&member.id);
+      let id_range = member.id.range();
+      let mut fixes = Vec::new();
+      if let Some(value) = implicit_value {
+        fixes.push(insert_fix(
+          format!("Initialize to `{}`", value),
+          id_range,
+          format!(" = {}", value),
+        ));
+        implicit_value = Some(value + 1.0);
+      }
+      fixes.push(insert_fix(
+        format!("Initialize to `\"{}\"`", name),
+        id_range,
+        format!(" = {:?}", name),
+      ));
+      ctx.add_diagnostic_with_fixes(
+        id_range,
+        CODE,
+        PreferEnumInitializersMessage::MissingInitializer(name),
+        Some(PreferEnumInitializersHint::AddInitializer.to_string()),
+        fixes,
+      );
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn prefer_enum_initializers_valid() {
+    assert_lint_ok! {
+      PreferEnumInitializers,
+      "enum Direction {}",
+      "enum Direction { Up = 1 }",
+      "enum Direction { Up = 'Up', Down = 'Down' }",
+      "enum Direction { Up = 1, Down = Up + 1 }",
+    };
+  }
+
+  #[test]
+  fn prefer_enum_initializers_invalid() {
+    assert_lint_err! {
+      PreferEnumInitializers,
+      "enum Direction { Up }": [
+        {
+          col: 17,
+          message: PreferEnumInitializersMessage::MissingInitializer(
+            "Up".to_string()
+          ),
+          hint: PreferEnumInitializersHint::AddInitializer,
+          fix: ("Initialize to `0`", "enum Direction { Up = 0 }"),
+          fix: (r#"Initialize to `"Up"`"#, r#"enum Direction { Up = "Up" }"#),
+        }
+      ],
+      "enum Direction { Up = 1, Down }": [
+        {
+          col: 25,
+          message: PreferEnumInitializersMessage::MissingInitializer(
+            "Down".to_string()
+          ),
+          hint: PreferEnumInitializersHint::AddInitializer,
+          fix: ("Initialize to `2`", "enum Direction { Up = 1, Down = 2 }"),
+          fix: (
+            r#"Initialize to `"Down"`"#,
+            r#"enum Direction { Up = 1, Down = "Down" }"#
+          ),
+        }
+      ],
+      "enum Direction { Up = 'Up', Down }": [
+        {
+          col: 28,
+          message: PreferEnumInitializersMessage::MissingInitializer(
+            "Down".to_string()
+          ),
+          hint: PreferEnumInitializersHint::AddInitializer,
+          fix: (
+            r#"Initialize to `"Down"`"#,
+            r#"enum Direction { Up = 'Up', Down = "Down" }"#
+          ),
+        }
+      ],
+      "enum Direction { 'Up Left' }": [
+        {
+          col: 17,
+          message: PreferEnumInitializersMessage::MissingInitializer(
+            "Up Left".to_string()
+          ),
+          hint: PreferEnumInitializersHint::AddInitializer,
+          fix: ("Initialize to `0`", "enum Direction { 'Up Left' = 0 }"),
+          fix: (
+            r#"Initialize to `"Up Left"`"#,
+            r#"enum Direction { 'Up Left' = "Up Left" }"#
+          ),
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,237 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::Handler;
+use deno_ast::view::{
+  BinExpr, BinaryOp, CallExpr, Callee, Expr, Lit, MemberProp, NodeKind, UnaryOp,
+};
+use deno_ast::{SourceRange, SourceRanged};
+use derive_more::Display;
+
+#[derive(Debug)]
+pub struct PreferIncludes;
+
+const CODE: &str = "prefer-includes";
+const FIX_DESC: &str = "Replace with `includes()`";
+
+#[derive(Display)]
+enum PreferIncludesMessage {
+  #[display(fmt = "Use `includes()` instead of comparing `indexOf()`")]
+  IndexOf,
+}
+
+#[derive(Display)]
+enum PreferIncludesHint {
+  #[display(fmt = "`includes()` says that only the presence matters")]
+  Presence,
+}
+
+impl LintRule for PreferIncludes {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(PreferIncludesHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::BinExpr])
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/prefer_includes.md")
+  }
+}
+
+/// The call if the expression is `x.indexOf(...)`, along with the range of
+/// `indexOf`.
+fn index_of_call<'a>(
+  expr: &Expr<'a>,
+) -> Option<(&'a CallExpr<'a>, SourceRange)> {
+  let Expr::Call(call) = expr else {
+    return None;
+  };
+  let Callee::Expr(Expr::Member(callee)) = &call.callee else {
+    return None;
+  };
+  let MemberProp::Ident(prop) = &callee.prop else {
+    return None;
+  };
+  let has_search = matches!(call.args, [_] | [_, _])
+    && call.args.iter().all(|arg| arg.spread().is_none());
+  (prop.sym() == "indexOf" && has_search).then(|| (*call, prop.range()))
+}
+
+/// The value of `0` or `-1`.
+fn small_int(expr: &Expr) -> Option<i8> {
+  match expr {
+    Expr::Lit(Lit::Num(num)) if num.value() == 0.0 => Some(0),
+    Expr::Unary(unary) if unary.op() == UnaryOp::Minus => match unary.arg {
+      Expr::Lit(Lit::Num(num)) if num.value() == 1.0 => Some(-1),
+      _ => None,
+    },
+    _ => None,
+  }
+}
+
+/// Whether `index <op> value` checks that the item is found, or `None` if it
+/// isn't a check for whether the item is found.
+fn checks_found(op: BinaryOp, value: i8) -> Option<bool> {
+  match (op, value) {
+    (BinaryOp::NotEqEq | BinaryOp::NotEq | BinaryOp::Gt, -1)
+    | (BinaryOp::GtEq, 0) => Some(true),
+    (BinaryOp::EqEqEq | BinaryOp::EqEq | BinaryOp::LtEq, -1)
+    | (BinaryOp::Lt, 0) => Some(false),
+    _ => None,
+  }
+}
+
+/// The operator with its operands swapped, so `a < b` becomes `b > a`.
+fn flip(op: BinaryOp) -> BinaryOp {
+  match op {
+    BinaryOp::Lt => BinaryOp::Gt,
+    BinaryOp::LtEq => BinaryOp::GtEq,
+    BinaryOp::Gt => BinaryOp::Lt,
+    BinaryOp::GtEq => BinaryOp::LtEq,
+    op => op,
+  }
+}
+
+struct PreferIncludesHandler;
+
+impl Handler for PreferIncludesHandler {
+  fn bin_expr(&mut self, bin_expr: &BinExpr, ctx: &mut Context) {
+    let (call, prop, op, value) =
+      if let Some((call, prop)) = index_of_call(&bin_expr.left) {
+        (call, prop, bin_expr.op(), &bin_expr.right)
+      } else if let Some((call, prop)) = index_of_call(&bin_expr.right) {
+        (call, prop, flip(bin_expr.op()), &bin_expr.left)
+      } else {
+        return;
+      };
+    let Some(found) =
+      small_int(value).and_then(|value| checks_found(op, value))
+    else {
+      return;
+    };
+    let new_text = format!(
+      "{}{}includes{}",
+      if found { "" } else { "!" },
+      ctx.text_of(SourceRange::new(call.start(), prop.start)),
+      ctx.text_of(SourceRange::new(prop.end, call.end())),
+    );
+    ctx.add_diagnostic_with_fixes(
+      bin_expr.range(),
+      CODE,
+      PreferIncludesMessage::IndexOf,
+      Some(PreferIncludesHint::Presence.to_string()),
+      vec![LintFix {
+        description: FIX_DESC.into(),
+        changes: vec![LintFixChange {
+          new_text: new_text.into(),
+          range: bin_expr.range(),
+        }],
+      }],
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn prefer_includes_valid() {
+    assert_lint_ok! {
+      PreferIncludes,
+      "str.includes(x)",
+      "str.indexOf(x) === 0",
+      "str.indexOf(x) !== 1",
+      "str.indexOf(x) >= -1",
+      "str.indexOf(x) < -1",
+      "str.indexOf(x) > 0",
+      "str.indexOf() !== -1",
+      "str.indexOf(...args) !== -1",
+      "str.lastIndexOf(x) !== -1",
+      "const i = str.indexOf(x);",
+      "/^abc/.test(str)",
+      "/abc$/.test(str)",
+      "/abc/i.test(str)",
+      "/a.c/.test(str)",
+      "re.test(str)",
+      // left to `prefer-string-method`
+      "/foo/.test(str)",
+      "/foo/.test('a foo')",
+    };
+  }
+
+  #[test]
+  fn prefer_includes_invalid() {
+    assert_lint_err! {
+      PreferIncludes,
+      "str.indexOf(x) !== -1": [
+        {
+          col: 0,
+          message: PreferIncludesMessage::IndexOf,
+          hint: PreferIncludesHint::Presence,
+          fix: (FIX_DESC, "str.includes(x)"),
+        }
+      ],
+      "if (arr.indexOf(item) === -1) {}": [
+        {
+          col: 4,
+          message: PreferIncludesMessage::IndexOf,
+          hint: PreferIncludesHint::Presence,
+          fix: (FIX_DESC, "if (!arr.includes(item)) {}"),
+        }
+      ],
+      "a.b.indexOf(x, 1) >= 0": [
+        {
+          col: 0,
+          message: PreferIncludesMessage::IndexOf,
+          hint: PreferIncludesHint::Presence,
+          fix: (FIX_DESC, "a.b.includes(x, 1)"),
+        }
+      ],
+      "str.indexOf(x) < 0": [
+        {
+          col: 0,
+          message: PreferIncludesMessage::IndexOf,
+          hint: PreferIncludesHint::Presence,
+          fix: (FIX_DESC, "!str.includes(x)"),
+        }
+      ],
+      "str.indexOf(x) > -1": [
+        {
+          col: 0,
+          message: PreferIncludesMessage::IndexOf,
+          hint: PreferIncludesHint::Presence,
+          fix: (FIX_DESC, "str.includes(x)"),
+        }
+      ],
+      "-1 != str.indexOf(x)": [
+        {
+          col: 0,
+          message: PreferIncludesMessage::IndexOf,
+          hint: PreferIncludesHint::Presence,
+          fix: (FIX_DESC, "str.includes(x)"),
+        }
+      ],
+      "0 > str.indexOf(x)": [
+        {
+          col: 0,
+          message: PreferIncludesMessage::IndexOf,
+          hint: PreferIncludesHint::Presence,
+          fix: (FIX_DESC, "!str.includes(x)"),
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,306 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::Program;
+
+use deno_ast::SourceRange;
+use derive_more::Display;
+
+#[derive(Debug)]
+pub struct PreferJsrOverDenoLandX;
+
+const CODE: &str = "prefer-jsr-over-deno-land-x";
+
+#[derive(Display)]
+enum PreferJsrOverDenoLandXMessage {
+  #[display(fmt = "Prefer importing the standard library from JSR")]
+  Std,
+  #[display(fmt = "Prefer importing `{}` from JSR", _0)]
+  ThirdParty(String),
+}
+
+#[derive(Display)]
+enum PreferJsrOverDenoLandXHint {
+  #[display(fmt = "Import it from `{}` instead", _0)]
+  UseJsr(String),
+  #[display(
+    fmt = "Check whether the module is published on JSR (https://jsr.io) and import it from there"
+  )]
+  CheckJsr,
+}
+
+const FIX_DESC: &str = "Replace with the JSR specifier";
+
+/// Modules on `deno.land/x` that are known to be published on JSR under a
+/// different name, with the same versions.
+static KNOWN_PACKAGES: phf::Map<&'static str, &'static str> = phf::phf_map! {
+  "oak" => "@oak/oak",
+  "hono" => "@hono/hono",
+  "deno_dom" => "@b-fuze/deno-dom",
+};
+
+/// The top-level modules of the standard library that are published on JSR
+/// as `@std/<name>`, with underscores replaced by dashes.
+const STD_MODULES: &[&str] = &[
+  "assert",
+  "async",
+  "bytes",
+  "cli",
+  "collections",
+  "crypto",
+  "csv",
+  "data_structures",
+  "datetime",
+  "dotenv",
+  "encoding",
+  "expect",
+  "fmt",
+  "front_matter",
+  "fs",
+  "html",
+  "http",
+  "ini",
+  "io",
+  "json",
+  "jsonc",
+  "log",
+  "media_types",
+  "msgpack",
+  "net",
+  "path",
+  "regexp",
+  "semver",
+  "streams",
+  "testing",
+  "text",
+  "toml",
+  "ulid",
+  "url",
+  "uuid",
+  "webgpu",
+  "yaml",
+];
+
+impl LintRule for PreferJsrOverDenoLandX {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    _program: Program,
+  ) {
+    for (specifier, range) in context.module_graph().import_specifiers() {
+      check_specifier(specifier, range, context);
+    }
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/prefer_jsr_over_deno_land_x.md")
+  }
+}
+
+/// Converts a path within a module, like `fs/empty_dir.ts` or `mod.ts`, to
+/// the JSR export it corresponds to, like `/empty-dir` or the empty string.
+fn jsr_subpath(path: &str) -> Option<String> {
+  if path.is_empty() {
+    return Some(String::new());
+  }
+  let path = path.strip_suffix(".ts")?;
+  let path = if path == "mod" {
+    ""
+  } else {
+    path.strip_suffix("/mod").unwrap_or(path)
+  };
+  if path.is_empty() {
+    Some(String::new())
+  } else {
+    Some(format!("/{}", path.replace('_', "-")))
+  }
+}
+
+/// The JSR specifier for a standard library import, e.g.
+/// `https://deno.land/std@0.224.0/fs/empty_dir.ts` becomes
+/// `jsr:@std/fs/empty-dir`. The version is dropped, since the versions of the
+/// standard library on JSR are numbered differently.
+fn std_to_jsr(rest: &str) -> Option<String> {
+  let (_, rest) = rest.split_once('/')?;
+  let (module, path) = rest.split_once('/').unwrap_or((rest, ""));
+  if !STD_MODULES.contains(&module) {
+    return None;
+  }
+  Some(format!(
+    "jsr:@std/{}{}",
+    module.replace('_', "-"),
+    jsr_subpath(path)?
+  ))
+}
+
+/// The JSR specifier for an import of a known module from `deno.land/x`,
+/// keeping its version, e.g. `https://deno.land/x/oak@v12.6.1/mod.ts` becomes
+/// `jsr:@oak/oak@12.6.1`.
+fn third_party_to_jsr(
+  name: &str,
+  version: Option<&str>,
+  path: &str,
+) -> Option<String> {
+  let package = KNOWN_PACKAGES.get(name)?;
+  let version = version
+    .map(|version| format!("@{}", version.trim_start_matches('v')))
+    .unwrap_or_default();
+  Some(format!("jsr:{}{}{}", package, version, jsr_subpath(path)?))
+}
+
+fn check_specifier(specifier: &str, range: SourceRange, ctx: &mut Context) {
+  let (message, replacement) = if let Some(rest) =
+    specifier.strip_prefix("https://deno.land/").filter(|rest| {
+      *rest == "std" || rest.starts_with("std/") || rest.starts_with("std@")
+    }) {
+    (PreferJsrOverDenoLandXMessage::Std, std_to_jsr(rest))
+  } else if let Some(rest) = specifier.strip_prefix("https://deno.land/x/") {
+    let (module, path) = rest.split_once('/').unwrap_or((rest, ""));
+    let (name, version) = match module.split_once('@') {
+      Some((name, version)) => (name, Some(version)),
+      None => (module, None),
+    };
+    (
+      PreferJsrOverDenoLandXMessage::ThirdParty(name.to_string()),
+      third_party_to_jsr(name, version, path),
+    )
+  } else {
+    return;
+  };
+
+  match replacement {
+    Some(replacement) => {
+      let quote = &ctx.text_of(range)[..1];
+      ctx.add_diagnostic_with_fixes(
+        range,
+        CODE,
+        message,
+        Some(
+          PreferJsrOverDenoLandXHint::UseJsr(replacement.clone()).to_string(),
+        ),
+        vec![LintFix {
+          description: FIX_DESC.into(),
+          changes: vec![LintFixChange {
+            new_text: format!("{quote}{replacement}{quote}").into(),
+            range,
+          }],
+        }],
+      );
+    }
+    None => {
+      ctx.add_diagnostic_with_hint(
+        range,
+        CODE,
+        message,
+        PreferJsrOverDenoLandXHint::CheckJsr,
+      );
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn prefer_jsr_over_deno_land_x_valid() {
+    assert_lint_ok! {
+      PreferJsrOverDenoLandX,
+      r#"import { join } from "jsr:@std/path";"#,
+      r#"import { Application } from "jsr:@oak/oak@12";"#,
+      r#"import foo from "https://deno.land/foo.ts";"#,
+      r#"import foo from "https://example.com/x/foo/mod.ts";"#,
+      r#"import foo from "./std/mod.ts";"#,
+      r#"import foo from "https://deno.land/standard/mod.ts";"#,
+    };
+  }
+
+  #[test]
+  fn prefer_jsr_over_deno_land_x_invalid() {
+    assert_lint_err! {
+      PreferJsrOverDenoLandX,
+      r#"import { join } from "https://deno.land/std@0.224.0/path/mod.ts";"#: [
+        {
+          col: 21,
+          message: PreferJsrOverDenoLandXMessage::Std,
+          hint: PreferJsrOverDenoLandXHint::UseJsr("jsr:@std/path".to_string()),
+          fix: (FIX_DESC, r#"import { join } from "jsr:@std/path";"#),
+        }
+      ],
+      "import { emptyDir } from 'https://deno.land/std/fs/empty_dir.ts';": [
+        {
+          col: 25,
+          message: PreferJsrOverDenoLandXMessage::Std,
+          hint: PreferJsrOverDenoLandXHint::UseJsr(
+            "jsr:@std/fs/empty-dir".to_string()
+          ),
+          fix: (FIX_DESC, "import { emptyDir } from 'jsr:@std/fs/empty-dir';"),
+        }
+      ],
+      r#"import { typeByExtension } from "https://deno.land/std@0.200.0/media_types/mod.ts";"#: [
+        {
+          col: 32,
+          message: PreferJsrOverDenoLandXMessage::Std,
+          hint: PreferJsrOverDenoLandXHint::UseJsr(
+            "jsr:@std/media-types".to_string()
+          ),
+          fix: (
+            FIX_DESC,
+            r#"import { typeByExtension } from "jsr:@std/media-types";"#
+          ),
+        }
+      ],
+      r#"import { join } from "https://deno.land/std@0.100.0/removed/mod.ts";"#: [
+        {
+          col: 21,
+          message: PreferJsrOverDenoLandXMessage::Std,
+          hint: PreferJsrOverDenoLandXHint::CheckJsr,
+        }
+      ],
+      r#"import { Application } from "https://deno.land/x/oak@v12.6.1/mod.ts";"#: [
+        {
+          col: 28,
+          message: PreferJsrOverDenoLandXMessage::ThirdParty("oak".to_string()),
+          hint: PreferJsrOverDenoLandXHint::UseJsr(
+            "jsr:@oak/oak@12.6.1".to_string()
+          ),
+          fix: (FIX_DESC, r#"import { Application } from "jsr:@oak/oak@12.6.1";"#),
+        }
+      ],
+      r#"export * from "https://deno.land/x/hono/middleware.ts";"#: [
+        {
+          col: 14,
+          message: PreferJsrOverDenoLandXMessage::ThirdParty("hono".to_string()),
+          hint: PreferJsrOverDenoLandXHint::UseJsr(
+            "jsr:@hono/hono/middleware".to_string()
+          ),
+          fix: (FIX_DESC, r#"export * from "jsr:@hono/hono/middleware";"#),
+        }
+      ],
+      r#"const mod = await import("https://deno.land/x/foo@1.0.0/mod.ts");"#: [
+        {
+          col: 25,
+          message: PreferJsrOverDenoLandXMessage::ThirdParty("foo".to_string()),
+          hint: PreferJsrOverDenoLandXHint::CheckJsr,
+        }
+      ],
+      r#"import foo from "https://deno.land/x/oak@v12.6.1/README.md";"#: [
+        {
+          col: 16,
+          message: PreferJsrOverDenoLandXMessage::ThirdParty("oak".to_string()),
+          hint: PreferJsrOverDenoLandXHint::CheckJsr,
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,244 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::handler::Handler;
+use crate::syntax_features::SyntaxFeatures;
+
+use deno_ast::view::{
+  BinaryOp, Expr, Lit, MemberProp, NodeKind, TsEnumDecl, TsEnumMemberId,
+  UnaryOp,
+};
+use deno_ast::SourceRanged;
+use derive_more::Display;
+use serde::Deserialize;
+use std::collections::HashSet;
+
+#[derive(Debug, Default)]
+pub struct PreferLiteralEnumMember {
+  options: PreferLiteralEnumMemberOptions,
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct PreferLiteralEnumMemberOptions {
+  /// Allow bitwise expressions of literals and other members of the same
+  /// enum, e.g. `ReadWrite = Read | Write`.
+  pub allow_bitwise_expressions: bool,
+}
+
+impl PreferLiteralEnumMember {
+  pub fn new(options: PreferLiteralEnumMemberOptions) -> Self {
+    Self { options }
+  }
+}
+
+const CODE: &str = "prefer-literal-enum-member";
+
+#[derive(Display)]
+enum PreferLiteralEnumMemberMessage {
+  #[display(
+    fmt = "The value of an enum member must be a string or number literal"
+  )]
+  NotLiteral,
+  #[display(
+    fmt = "The value of an enum member must be a string or number literal, or a bitwise expression of them"
+  )]
+  NotLiteralOrBitwise,
+}
+
+#[derive(Display)]
+enum PreferLiteralEnumMemberHint {
+  #[display(fmt = "Replace the value with a literal")]
+  UseLiteral,
+}
+
+impl LintRule for PreferLiteralEnumMember {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn required_syntax(&self) -> SyntaxFeatures {
+    SyntaxFeatures::ENUM
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "allowBitwiseExpressions": { "type": "boolean", "default": false }
+      }
+    }))
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(PreferLiteralEnumMemberHandler {
+      options: &self.options,
+    }))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::TsEnumDecl])
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/prefer_literal_enum_member.md")
+  }
+}
+
+fn is_literal(expr: &Expr) -> bool {
+  match expr {
+    Expr::Lit(Lit::Str(_) | Lit::Num(_)) => true,
+    Expr::Tpl(tpl) => tpl.exprs.is_empty(),
+    Expr::Unary(unary) => {
+      matches!(unary.op(), UnaryOp::Minus | UnaryOp::Plus)
+        && matches!(unary.arg, Expr::Lit(Lit::Num(_)))
+    }
+    Expr::Paren(paren) => is_literal(&paren.expr),
+    _ => false,
+  }
+}
+
+struct PreferLiteralEnumMemberHandler<'a> {
+  options: &'a PreferLiteralEnumMemberOptions,
+}
+
+impl<'a> PreferLiteralEnumMemberHandler<'a> {
+  /// Whether the expression is a bitwise expression whose operands are
+  /// literals or members of the enum.
+  fn is_bitwise_expr(
+    &self,
+    expr: &Expr,
+    enum_name: &str,
+    members: &HashSet<String>,
+  ) -> bool {
+    match expr {
+      Expr::Bin(bin) => {
+        matches!(
+          bin.op(),
+          BinaryOp::BitOr
+            | BinaryOp::BitAnd
+            | BinaryOp::BitXor
+            | BinaryOp::LShift
+            | BinaryOp::RShift
+            | BinaryOp::ZeroFillRShift
+        ) && self.is_bitwise_operand(&bin.left, enum_name, members)
+          && self.is_bitwise_operand(&bin.right, enum_name, members)
+      }
+      Expr::Unary(unary) if unary.op() == UnaryOp::Tilde => {
+        self.is_bitwise_operand(&unary.arg, enum_name, members)
+      }
+      Expr::Paren(paren) => {
+        self.is_bitwise_expr(&paren.expr, enum_name, members)
+      }
+      _ => false,
+    }
+  }
+
+  fn is_bitwise_operand(
+    &self,
+    expr: &Expr,
+    enum_name: &str,
+    members: &HashSet<String>,
+  ) -> bool {
+    match expr {
+      Expr::Ident(ident) => members.contains(ident.sym().as_ref()),
+      Expr::Member(member) => matches!(
+        (&member.obj, &member.prop),
+        (Expr::Ident(obj), MemberProp::Ident(prop))
+          if obj.sym() == enum_name && members.contains(prop.sym().as_ref())
+      ),
+      Expr::Paren(paren) => {
+        self.is_bitwise_operand(&paren.expr, enum_name, members)
+      }
+      expr => {
+        matches!(expr, Expr::Lit(Lit::Num(_)))
+          || self.is_bitwise_expr(expr, enum_name, members)
+      }
+    }
+  }
+}
+
+impl<'a> Handler for PreferLiteralEnumMemberHandler<'a> {
+  fn ts_enum_decl(&mut self, enum_decl: &TsEnumDecl, ctx: &mut Context) {
+    let enum_name = enum_decl.id.sym().as_ref();
+    // Only members declared before the current one can be referenced.
+    let mut members = HashSet::new();
+    for member in enum_decl.members {
+      if let Some(init) = &member.init {
+        if !is_literal(init) {
+          let message = if !self.options.allow_bitwise_expressions {
+            Some(PreferLiteralEnumMemberMessage::NotLiteral)
+          } else if !self.is_bitwise_expr(init, enum_name, &members) {
+            Some(PreferLiteralEnumMemberMessage::NotLiteralOrBitwise)
+          } else {
+            None
+          };
+          if let Some(message) = message {
+            ctx.add_diagnostic_with_hint(
+              init.range(),
+              CODE,
+              message,
+              PreferLiteralEnumMemberHint::UseLiteral,
+            );
+          }
+        }
+      }
+      if let TsEnumMemberId::Ident(ident) = &member.id {
+        members.insert(ident.sym().to_string());
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn prefer_literal_enum_member_valid() {
+    assert_lint_ok! {
+      PreferLiteralEnumMember::default(),
+      "enum Foo { A }",
+      "enum Foo { A = 1, B = -1, C = +2 }",
+      "enum Foo { A = 'a', B = `b` }",
+      "enum Foo { A = (1) }",
+    };
+
+    assert_lint_ok! {
+      PreferLiteralEnumMember::new(PreferLiteralEnumMemberOptions {
+        allow_bitwise_expressions: true,
+      }),
+      "enum Foo { A = 1 << 0, B = 1 << 1, C = A | B, D = Foo.A | Foo.B }",
+      "enum Foo { A = 1, B = ~A, C = (A | B) & 4 }",
+    };
+  }
+
+  #[test]
+  fn prefer_literal_enum_member_invalid() {
+    assert_lint_err! {
+      PreferLiteralEnumMember::default(),
+      PreferLiteralEnumMemberMessage::NotLiteral,
+      PreferLiteralEnumMemberHint::UseLiteral,
+      "const x = 1; enum Foo { A = x }": [{ col: 28 }],
+      "enum Foo { A = 1, B = A }": [{ col: 22 }],
+      "enum Foo { A = 1 << 0 }": [{ col: 15 }],
+      "enum Foo { A = 'a'.length }": [{ col: 15 }],
+      "enum Foo { A = `${x}` }": [{ col: 15 }],
+      "enum Foo { A = foo() }": [{ col: 15 }],
+      "enum Foo { A = -x }": [{ col: 15 }],
+    };
+
+    assert_lint_err! {
+      PreferLiteralEnumMember::new(PreferLiteralEnumMemberOptions {
+        allow_bitwise_expressions: true,
+      }),
+      PreferLiteralEnumMemberMessage::NotLiteralOrBitwise,
+      PreferLiteralEnumMemberHint::UseLiteral,
+      "const x = 1; enum Foo { A = x | 1 }": [{ col: 28 }],
+      "enum Foo { A = B | 1, B = 2 }": [{ col: 15 }],
+      "enum Foo { A = 1 + 1 }": [{ col: 15 }],
+      "enum Foo { A = 1, B = A }": [{ col: 22 }],
+    };
+  }
+}
@@ -1,9 +1,9 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
-use crate::Program;
-use deno_ast::view::{TsModuleDecl, TsModuleName};
+use crate::handler::Handler;
+use crate::syntax_features::SyntaxFeatures;
+use deno_ast::view::{NodeKind, TsModuleDecl, TsModuleName};
 use deno_ast::SourceRanged;
 use once_cell::sync::Lazy;
 use regex::Regex;
@@ -23,12 +23,16 @@ impl LintRule for PreferNamespaceKeyword {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    PreferNamespaceKeywordHandler.traverse(program, context);
+  fn required_syntax(&self) -> SyntaxFeatures {
+    SyntaxFeatures::NAMESPACE
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(PreferNamespaceKeywordHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::TsModuleDecl])
   }
 
   #[cfg(feature = "docs")]
@@ -0,0 +1,194 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::Handler;
+use deno_ast::view::{CallExpr, Callee, Expr, Lit, MemberProp, NodeKind};
+use deno_ast::SourceRanged;
+use derive_more::Display;
+
+#[derive(Debug)]
+pub struct PreferNumericLiterals;
+
+const CODE: &str = "prefer-numeric-literals";
+const FIX_DESC: &str = "Replace with a numeric literal";
+
+#[derive(Display)]
+enum PreferNumericLiteralsMessage {
+  #[display(fmt = "Use {} literals instead of `{}()`", _0, _1)]
+  UseLiteral(&'static str, &'static str),
+}
+
+#[derive(Display)]
+enum PreferNumericLiteralsHint {
+  #[display(fmt = "Write the number as `{}`", _0)]
+  WriteAs(String),
+}
+
+impl LintRule for PreferNumericLiterals {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(PreferNumericLiteralsHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::CallExpr])
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/prefer_numeric_literals.md")
+  }
+}
+
+fn is_global_ident(expr: &Expr, name: &str, ctx: &Context) -> bool {
+  matches!(expr, Expr::Ident(ident)
+    if ident.sym() == name && ctx.scope().is_global(&ident.inner.to_id()))
+}
+
+/// The name of the function that is called, for `parseInt()` and
+/// `Number.parseInt()`.
+fn parse_int_name(callee: &Expr, ctx: &Context) -> Option<&'static str> {
+  if is_global_ident(callee, "parseInt", ctx) {
+    return Some("parseInt");
+  }
+  let Expr::Member(member) = callee else {
+    return None;
+  };
+  let is_number_parse_int = is_global_ident(&member.obj, "Number", ctx)
+    && matches!(&member.prop, MemberProp::Ident(prop) if prop.sym() == "parseInt");
+  is_number_parse_int.then_some("Number.parseInt")
+}
+
+fn string_value(expr: &Expr) -> Option<String> {
+  match expr {
+    Expr::Lit(Lit::Str(str)) => Some(str.value().to_string()),
+    Expr::Tpl(tpl) if tpl.exprs.is_empty() => {
+      let cooked = tpl.quasis.first()?.cooked().as_ref()?;
+      Some(cooked.to_string())
+    }
+    _ => None,
+  }
+}
+
+struct PreferNumericLiteralsHandler;
+
+impl Handler for PreferNumericLiteralsHandler {
+  fn call_expr(&mut self, call_expr: &CallExpr, ctx: &mut Context) {
+    let Callee::Expr(callee) = &call_expr.callee else {
+      return;
+    };
+    let Some(name) = parse_int_name(callee, ctx) else {
+      return;
+    };
+    let [digits, radix] = call_expr.args else {
+      return;
+    };
+    if digits.spread().is_some() || radix.spread().is_some() {
+      return;
+    }
+    let Expr::Lit(Lit::Num(radix)) = radix.expr else {
+      return;
+    };
+    let (kind, prefix, radix) = match radix.value() {
+      2.0 => ("binary", "0b", 2),
+      8.0 => ("octal", "0o", 8),
+      16.0 => ("hexadecimal", "0x", 16),
+      _ => return,
+    };
+    let Some(digits) = string_value(&digits.expr) else {
+      return;
+    };
+    // `parseInt()` ignores anything after the first invalid digit, so the
+    // result only matches the literal when every character is a digit.
+    if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix)) {
+      return;
+    }
+    let literal = format!("{prefix}{digits}");
+    ctx.add_diagnostic_with_fixes(
+      call_expr.range(),
+      CODE,
+      PreferNumericLiteralsMessage::UseLiteral(kind, name),
+      Some(PreferNumericLiteralsHint::WriteAs(literal.clone()).to_string()),
+      vec![LintFix {
+        description: FIX_DESC.into(),
+        changes: vec![LintFixChange {
+          new_text: literal.into(),
+          range: call_expr.range(),
+        }],
+      }],
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn prefer_numeric_literals_valid() {
+    assert_lint_ok! {
+      PreferNumericLiterals,
+      r#"parseInt("111", 10)"#,
+      r#"parseInt("111", 3)"#,
+      r#"parseInt("111")"#,
+      r#"parseInt("112", 2)"#,
+      r#"parseInt("0x1f", 16)"#,
+      r#"parseInt("", 2)"#,
+      r#"parseInt(str, 2)"#,
+      r#"parseInt(`1${a}`, 2)"#,
+      r#"parseInt(...args)"#,
+      r#"parseInt("111", ...radix)"#,
+      r#"foo.parseInt("111", 2)"#,
+      r#"Number.parseFloat("111", 2)"#,
+      r#"function f(parseInt) { parseInt("111", 2); }"#,
+      r#"const Number = {}; Number.parseInt("111", 2);"#,
+    };
+  }
+
+  #[test]
+  fn prefer_numeric_literals_invalid() {
+    assert_lint_err! {
+      PreferNumericLiterals,
+      r#"parseInt("111", 2)"#: [
+        {
+          col: 0,
+          message: PreferNumericLiteralsMessage::UseLiteral("binary", "parseInt"),
+          hint: PreferNumericLiteralsHint::WriteAs("0b111".to_string()),
+          fix: (FIX_DESC, "0b111"),
+        }
+      ],
+      r#"const mode = parseInt('755', 8);"#: [
+        {
+          col: 13,
+          message: PreferNumericLiteralsMessage::UseLiteral("octal", "parseInt"),
+          hint: PreferNumericLiteralsHint::WriteAs("0o755".to_string()),
+          fix: (FIX_DESC, "const mode = 0o755;"),
+        }
+      ],
+      r#"Number.parseInt("1F7", 16)"#: [
+        {
+          col: 0,
+          message: PreferNumericLiteralsMessage::UseLiteral("hexadecimal", "Number.parseInt"),
+          hint: PreferNumericLiteralsHint::WriteAs("0x1F7".to_string()),
+          fix: (FIX_DESC, "0x1F7"),
+        }
+      ],
+      r#"parseInt(`11`, 2)"#: [
+        {
+          col: 0,
+          message: PreferNumericLiteralsMessage::UseLiteral("binary", "parseInt"),
+          hint: PreferNumericLiteralsHint::WriteAs("0b11".to_string()),
+          fix: (FIX_DESC, "0b11"),
+        }
+      ],
+    };
+  }
+}
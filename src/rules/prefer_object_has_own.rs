@@ -0,0 +1,159 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::Handler;
+use deno_ast::view::{CallExpr, Callee, Expr, MemberProp, NodeKind};
+use deno_ast::SourceRanged;
+use derive_more::Display;
+
+#[derive(Debug)]
+pub struct PreferObjectHasOwn;
+
+const CODE: &str = "prefer-object-has-own";
+const FIX_DESC: &str = "Replace with `Object.hasOwn`";
+
+#[derive(Display)]
+enum PreferObjectHasOwnMessage {
+  #[display(
+    fmt = "Use `Object.hasOwn()` instead of `Object.prototype.hasOwnProperty.call()`"
+  )]
+  UseHasOwn,
+}
+
+#[derive(Display)]
+enum PreferObjectHasOwnHint {
+  #[display(fmt = "`Object.hasOwn(obj, key)` does the same check")]
+  SameCheck,
+}
+
+impl LintRule for PreferObjectHasOwn {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(PreferObjectHasOwnHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::CallExpr])
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/prefer_object_has_own.md")
+  }
+}
+
+fn is_global_ident(expr: &Expr, name: &str, ctx: &Context) -> bool {
+  matches!(expr, Expr::Ident(ident)
+    if ident.sym() == name && ctx.scope().is_global(&ident.inner.to_id()))
+}
+
+fn is_prop(prop: &MemberProp, name: &str) -> bool {
+  matches!(prop, MemberProp::Ident(ident) if ident.sym() == name)
+}
+
+/// Whether the expression is `Object.prototype` or `{}`, either of which
+/// `hasOwnProperty` can be read from.
+fn is_object_prototype(expr: &Expr, ctx: &Context) -> bool {
+  match expr {
+    Expr::Member(member) => {
+      is_global_ident(&member.obj, "Object", ctx)
+        && is_prop(&member.prop, "prototype")
+    }
+    Expr::Object(object) => object.props.is_empty(),
+    Expr::Paren(paren) => is_object_prototype(&paren.expr, ctx),
+    _ => false,
+  }
+}
+
+struct PreferObjectHasOwnHandler;
+
+impl Handler for PreferObjectHasOwnHandler {
+  fn call_expr(&mut self, call_expr: &CallExpr, ctx: &mut Context) {
+    let Callee::Expr(Expr::Member(call)) = &call_expr.callee else {
+      return;
+    };
+    if !is_prop(&call.prop, "call") {
+      return;
+    }
+    let Expr::Member(has_own_property) = &call.obj else {
+      return;
+    };
+    if !is_prop(&has_own_property.prop, "hasOwnProperty")
+      || !is_object_prototype(&has_own_property.obj, ctx)
+    {
+      return;
+    }
+    ctx.add_diagnostic_with_fixes(
+      call_expr.range(),
+      CODE,
+      PreferObjectHasOwnMessage::UseHasOwn,
+      Some(PreferObjectHasOwnHint::SameCheck.to_string()),
+      vec![LintFix {
+        description: FIX_DESC.into(),
+        changes: vec![LintFixChange {
+          new_text: "Object.hasOwn".into(),
+          range: call.range(),
+        }],
+      }],
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn prefer_object_has_own_valid() {
+    assert_lint_ok! {
+      PreferObjectHasOwn,
+      r#"Object.hasOwn(obj, "key")"#,
+      r#"obj.hasOwnProperty("key")"#,
+      r#"Object.prototype.hasOwnProperty("key")"#,
+      r#"Object.prototype.hasOwnProperty.apply(obj, ["key"])"#,
+      r#"Object.prototype.isPrototypeOf.call(obj, proto)"#,
+      r#"({ a: 1 }).hasOwnProperty.call(obj, "key")"#,
+      r#"Foo.prototype.hasOwnProperty.call(obj, "key")"#,
+      r#"const Object = {}; Object.prototype.hasOwnProperty.call(obj, "key");"#,
+    };
+  }
+
+  #[test]
+  fn prefer_object_has_own_invalid() {
+    assert_lint_err! {
+      PreferObjectHasOwn,
+      r#"Object.prototype.hasOwnProperty.call(obj, "key")"#: [
+        {
+          col: 0,
+          message: PreferObjectHasOwnMessage::UseHasOwn,
+          hint: PreferObjectHasOwnHint::SameCheck,
+          fix: (FIX_DESC, r#"Object.hasOwn(obj, "key")"#),
+        }
+      ],
+      r#"if ({}.hasOwnProperty.call(obj, key)) {}"#: [
+        {
+          col: 4,
+          message: PreferObjectHasOwnMessage::UseHasOwn,
+          hint: PreferObjectHasOwnHint::SameCheck,
+          fix: (FIX_DESC, "if (Object.hasOwn(obj, key)) {}"),
+        }
+      ],
+      r#"const has = ({}).hasOwnProperty.call(obj, key);"#: [
+        {
+          col: 12,
+          message: PreferObjectHasOwnMessage::UseHasOwn,
+          hint: PreferObjectHasOwnHint::SameCheck,
+          fix: (FIX_DESC, "const has = Object.hasOwn(obj, key);"),
+        }
+      ],
+    };
+  }
+}
@@ -1,9 +1,8 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
-use crate::Program;
-use deno_ast::view::NodeTrait;
+use crate::handler::Handler;
+use deno_ast::view::{NodeKind, NodeTrait};
 use deno_ast::Scope;
 use deno_ast::{view as ast_view, SourceRanged};
 use derive_more::Display;
@@ -64,12 +63,21 @@ impl LintRule for PreferPrimordials {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program<'_>,
-  ) {
-    PreferPrimordialsHandler.traverse(program, context);
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(PreferPrimordialsHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[
+      NodeKind::Ident,
+      NodeKind::MemberExpr,
+      NodeKind::ExprOrSpread,
+      NodeKind::ForOfStmt,
+      NodeKind::YieldExpr,
+      NodeKind::ArrayPat,
+      NodeKind::Regex,
+      NodeKind::BinExpr,
+    ])
   }
 
   #[cfg(feature = "docs")]
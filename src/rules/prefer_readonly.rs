@@ -0,0 +1,343 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::class_analysis::{ClassAnalysis, MemberKey, MemberKind};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::Handler;
+use crate::syntax_features::SyntaxFeatures;
+
+use deno_ast::swc::ast::{
+  ArrowExpr as SwcArrowExpr, Class as SwcClass, Constructor as SwcConstructor,
+  Expr as SwcExpr, Function as SwcFunction, MemberExpr as SwcMemberExpr,
+  MemberProp as SwcMemberProp, Pat as SwcPat, PropName as SwcPropName,
+  SimpleAssignTarget, UpdateExpr as SwcUpdateExpr,
+};
+use deno_ast::swc::visit::{noop_visit_type, Visit, VisitWith};
+use deno_ast::view::{Accessibility, Class, NodeKind};
+use deno_ast::{MediaType, SourcePos, SourceRange, SourceRangedForSpanned};
+use derive_more::Display;
+use std::collections::HashSet;
+
+#[derive(Debug)]
+pub struct PreferReadonly;
+
+const CODE: &str = "prefer-readonly";
+
+#[derive(Display)]
+enum PreferReadonlyMessage {
+  #[display(fmt = "Private member `{}` is never reassigned", _0)]
+  NeverReassigned(String),
+}
+
+#[derive(Display)]
+enum PreferReadonlyHint {
+  #[display(fmt = "Mark it as `readonly`")]
+  MarkReadonly,
+}
+
+const FIX_DESC: &str = "Add the `readonly` modifier";
+
+impl LintRule for PreferReadonly {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn required_syntax(&self) -> SyntaxFeatures {
+    SyntaxFeatures::CLASS
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(PreferReadonlyHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::Class])
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/prefer_readonly.md")
+  }
+}
+
+/// A private member that could be marked as `readonly`.
+struct Candidate {
+  /// The name of the member, including the `#` of private names.
+  name: String,
+  range: SourceRange,
+  /// Where to insert the `readonly` modifier.
+  insert_pos: SourcePos,
+}
+
+/// Collects the names of all members that are assigned to outside of the
+/// constructor. Assignments to `this` in the constructor itself (but not in
+/// functions nested in it) are ignored.
+#[derive(Default)]
+struct AssignmentCollector {
+  in_constructor: bool,
+  assigned: HashSet<String>,
+}
+
+impl AssignmentCollector {
+  fn record(&mut self, member: &SwcMemberExpr) {
+    if self.in_constructor && matches!(&*member.obj, SwcExpr::This(_)) {
+      return;
+    }
+    match &member.prop {
+      SwcMemberProp::Ident(ident) => {
+        self.assigned.insert(ident.sym.to_string());
+      }
+      SwcMemberProp::PrivateName(private_name) => {
+        self.assigned.insert(format!("#{}", private_name.name));
+      }
+      SwcMemberProp::Computed(_) => {}
+    }
+  }
+
+  fn with_constructor_flag(
+    &mut self,
+    in_constructor: bool,
+    visit: impl FnOnce(&mut Self),
+  ) {
+    let prev = self.in_constructor;
+    self.in_constructor = in_constructor;
+    visit(self);
+    self.in_constructor = prev;
+  }
+}
+
+impl Visit for AssignmentCollector {
+  noop_visit_type!();
+
+  fn visit_simple_assign_target(&mut self, target: &SimpleAssignTarget) {
+    if let SimpleAssignTarget::Member(member) = target {
+      self.record(member);
+    }
+    target.visit_children_with(self);
+  }
+
+  fn visit_pat(&mut self, pat: &SwcPat) {
+    // Members in destructuring assignments, e.g. `[this.foo] = bar`.
+    if let SwcPat::Expr(expr) = pat {
+      if let SwcExpr::Member(member) = &**expr {
+        self.record(member);
+      }
+    }
+    pat.visit_children_with(self);
+  }
+
+  fn visit_update_expr(&mut self, update_expr: &SwcUpdateExpr) {
+    if let SwcExpr::Member(member) = &*update_expr.arg {
+      self.record(member);
+    }
+    update_expr.visit_children_with(self);
+  }
+
+  fn visit_constructor(&mut self, constructor: &SwcConstructor) {
+    self.with_constructor_flag(true, |v| constructor.visit_children_with(v));
+  }
+
+  fn visit_function(&mut self, function: &SwcFunction) {
+    self.with_constructor_flag(false, |v| function.visit_children_with(v));
+  }
+
+  fn visit_arrow_expr(&mut self, arrow_expr: &SwcArrowExpr) {
+    // Arrow functions created in the constructor may run at any later time.
+    self.with_constructor_flag(false, |v| arrow_expr.visit_children_with(v));
+  }
+
+  // `this` refers to another class in nested classes.
+  fn visit_class(&mut self, _class: &SwcClass) {}
+}
+
+fn collect_candidates(class: &SwcClass) -> Vec<Candidate> {
+  let analysis = ClassAnalysis::new(class);
+  let mut candidates = Vec::new();
+  for member in &analysis.members {
+    if member.kind != MemberKind::Property || member.readonly || member.declare
+    {
+      continue;
+    }
+    let (Some(key), Some(name)) = (member.key, &member.name) else {
+      continue;
+    };
+    let is_candidate = match key {
+      MemberKey::Public(key) => {
+        matches!(key, SwcPropName::Ident(_))
+          && member.accessibility == Some(Accessibility::Private)
+      }
+      MemberKey::Private(_) => true,
+    };
+    if is_candidate {
+      candidates.push(Candidate {
+        name: name.clone(),
+        range: key.range(),
+        insert_pos: key.range().start,
+      });
+    }
+  }
+  for param_prop in &analysis.param_props {
+    if param_prop.accessibility != Some(Accessibility::Private)
+      || param_prop.readonly
+    {
+      continue;
+    }
+    candidates.push(Candidate {
+      name: param_prop.name.clone(),
+      range: param_prop.range,
+      insert_pos: param_prop.param.param.start(),
+    });
+  }
+  candidates
+}
+
+struct PreferReadonlyHandler;
+
+impl Handler for PreferReadonlyHandler {
+  fn class(&mut self, class: &Class, ctx: &mut Context) {
+    // `readonly` is TypeScript only.
+    if !matches!(
+      ctx.media_type(),
+      MediaType::TypeScript | MediaType::Tsx | MediaType::Mts | MediaType::Cts
+    ) {
+      return;
+    }
+
+    let candidates = collect_candidates(class.inner);
+    if candidates.is_empty() {
+      return;
+    }
+
+    let mut collector = AssignmentCollector::default();
+    for member in &class.inner.body {
+      member.visit_with(&mut collector);
+    }
+
+    for candidate in candidates {
+      if collector.assigned.contains(&candidate.name) {
+        continue;
+      }
+      ctx.add_diagnostic_with_fixes(
+        candidate.range,
+        CODE,
+        PreferReadonlyMessage::NeverReassigned(candidate.name),
+        Some(PreferReadonlyHint::MarkReadonly.to_string()),
+        vec![LintFix {
+          description: FIX_DESC.into(),
+          changes: vec![LintFixChange {
+            new_text: "readonly ".into(),
+            range: SourceRange::new(candidate.insert_pos, candidate.insert_pos),
+          }],
+        }],
+      );
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn prefer_readonly_valid() {
+    assert_lint_ok! {
+      PreferReadonly,
+      "class Foo { private readonly bar = 1; }",
+      "class Foo { readonly #bar = 1; }",
+      "class Foo { bar = 1; }",
+      "class Foo { protected bar = 1; }",
+      "class Foo { public bar = 1; }",
+      "class Foo { private declare bar: number; }",
+      "class Foo { private bar = 1; reset() { this.bar = 0; } }",
+      "class Foo { #bar = 1; reset() { this.#bar = 0; } }",
+      "class Foo { private bar = 1; inc() { this.bar++; } }",
+      "class Foo { private bar = 1; add() { this.bar += 1; } }",
+      "class Foo { private bar = 1; swap() { [this.bar] = [2]; } }",
+      "class Foo { private bar = 1; set() { ({ x: this.bar } = obj); } }",
+      "class Foo { private bar = 1; static reset(foo: Foo) { foo.bar = 0; } }",
+      "class Foo { private bar = 1; constructor() { setTimeout(() => { this.bar = 2; }); } }",
+      "class Foo { private bar = 1; constructor(other: Foo) { other.bar = 2; } }",
+      "class Foo { constructor(private readonly bar: number) {} }",
+      "class Foo { constructor(public bar: number) {} }",
+      "class Foo { constructor(private bar: number) {} reset() { this.bar = 0; } }",
+      "class Foo { private bar() {} }",
+    };
+
+    assert_lint_ok! {
+      PreferReadonly,
+      filename: "file:///foo.js",
+      "class Foo { #bar = 1; }",
+    };
+  }
+
+  #[test]
+  fn prefer_readonly_invalid() {
+    assert_lint_err! {
+      PreferReadonly,
+      "class Foo { private bar = 1; }": [
+        {
+          col: 20,
+          message: PreferReadonlyMessage::NeverReassigned("bar".to_string()),
+          hint: PreferReadonlyHint::MarkReadonly,
+          fix: (FIX_DESC, "class Foo { private readonly bar = 1; }"),
+        }
+      ],
+      "class Foo { private static bar = 1; }": [
+        {
+          col: 27,
+          message: PreferReadonlyMessage::NeverReassigned("bar".to_string()),
+          hint: PreferReadonlyHint::MarkReadonly,
+          fix: (FIX_DESC, "class Foo { private static readonly bar = 1; }"),
+        }
+      ],
+      "class Foo { #bar = 1; get() { return this.#bar; } }": [
+        {
+          col: 12,
+          message: PreferReadonlyMessage::NeverReassigned("#bar".to_string()),
+          hint: PreferReadonlyHint::MarkReadonly,
+          fix: (
+            FIX_DESC,
+            "class Foo { readonly #bar = 1; get() { return this.#bar; } }"
+          ),
+        }
+      ],
+      "class Foo { private bar: number; constructor() { this.bar = 1; } }": [
+        {
+          col: 20,
+          message: PreferReadonlyMessage::NeverReassigned("bar".to_string()),
+          hint: PreferReadonlyHint::MarkReadonly,
+          fix: (
+            FIX_DESC,
+            "class Foo { private readonly bar: number; constructor() { this.bar = 1; } }"
+          ),
+        }
+      ],
+      "class Foo { constructor(private bar: number) {} }": [
+        {
+          col: 32,
+          message: PreferReadonlyMessage::NeverReassigned("bar".to_string()),
+          hint: PreferReadonlyHint::MarkReadonly,
+          fix: (
+            FIX_DESC,
+            "class Foo { constructor(private readonly bar: number) {} }"
+          ),
+        }
+      ],
+      "class Foo { private bar = 1; reset() { class Bar { bar = 1; reset() { this.bar = 0; } } } }": [
+        {
+          col: 20,
+          message: PreferReadonlyMessage::NeverReassigned("bar".to_string()),
+          hint: PreferReadonlyHint::MarkReadonly,
+          fix: (
+            FIX_DESC,
+            "class Foo { private readonly bar = 1; reset() { class Bar { bar = 1; reset() { this.bar = 0; } } } }"
+          ),
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,252 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::Handler;
+use crate::syntax_features::SyntaxFeatures;
+
+use deno_ast::swc::ast::{
+  ArrowExpr as SwcArrowExpr, Class as SwcClass, Expr as SwcExpr,
+  Function as SwcFunction, ReturnStmt as SwcReturnStmt,
+};
+use deno_ast::swc::visit::{noop_visit_type, Visit, VisitWith};
+use deno_ast::view::{
+  ClassDecl, ClassExpr, ClassMethod, MethodKind, NodeKind, NodeTrait,
+  TsEntityName, TsType, TsTypeRef, TsUnionOrIntersectionType,
+};
+use deno_ast::SourceRanged;
+use derive_more::Display;
+
+/// Reports methods annotated to return their class that always return `this`.
+///
+/// This is a syntactic approximation: only `return this` counts as returning
+/// `this`, so a method that returns anything else, even a value that is `this`
+/// at runtime, is never reported or fixed.
+#[derive(Debug)]
+pub struct PreferReturnThisType;
+
+const CODE: &str = "prefer-return-this-type";
+
+#[derive(Display)]
+enum PreferReturnThisTypeMessage {
+  #[display(fmt = "Use `this` type instead of `{}`", _0)]
+  UseThisType(String),
+}
+
+#[derive(Display)]
+enum PreferReturnThisTypeHint {
+  #[display(
+    fmt = "This method always returns `this`, so the return type `this` keeps method chains on subclasses typed as the subclass"
+  )]
+  UseThisType,
+}
+
+const FIX_DESC: &str = "Change the return type to `this`";
+
+impl LintRule for PreferReturnThisType {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn required_syntax(&self) -> SyntaxFeatures {
+    SyntaxFeatures::CLASS
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(PreferReturnThisTypeHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::ClassMethod])
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/prefer_return_this_type.md")
+  }
+}
+
+/// Checks whether every `return` statement of a function returns `this`,
+/// skipping nested functions and classes.
+#[derive(Default)]
+struct ThisReturnFinder {
+  has_return: bool,
+  returns_other: bool,
+}
+
+impl Visit for ThisReturnFinder {
+  noop_visit_type!();
+
+  fn visit_return_stmt(&mut self, return_stmt: &SwcReturnStmt) {
+    self.has_return = true;
+    let mut arg = return_stmt.arg.as_deref();
+    while let Some(SwcExpr::Paren(paren)) = arg {
+      arg = Some(&paren.expr);
+    }
+    if !matches!(arg, Some(SwcExpr::This(_))) {
+      self.returns_other = true;
+    }
+  }
+
+  fn visit_function(&mut self, _function: &SwcFunction) {}
+
+  fn visit_arrow_expr(&mut self, _arrow_expr: &SwcArrowExpr) {}
+
+  fn visit_class(&mut self, _class: &SwcClass) {}
+}
+
+/// Finds the reference to the class in the return type, either the whole type
+/// or a member of a union like `Foo | undefined`.
+fn find_class_ref<'a>(
+  ty: &'a TsType<'a>,
+  class_name: &str,
+) -> Option<&'a TsTypeRef<'a>> {
+  match ty {
+    TsType::TsTypeRef(type_ref) => match &type_ref.type_name {
+      TsEntityName::Ident(ident) if ident.sym() == class_name => Some(type_ref),
+      _ => None,
+    },
+    TsType::TsUnionOrIntersectionType(
+      TsUnionOrIntersectionType::TsUnionType(union),
+    ) => union
+      .types
+      .iter()
+      .find_map(|ty| find_class_ref(ty, class_name)),
+    TsType::TsParenthesizedType(paren) => {
+      find_class_ref(&paren.type_ann, class_name)
+    }
+    _ => None,
+  }
+}
+
+struct PreferReturnThisTypeHandler;
+
+impl Handler for PreferReturnThisTypeHandler {
+  fn class_method(&mut self, method: &ClassMethod, ctx: &mut Context) {
+    if method.is_static() || method.inner.kind != MethodKind::Method {
+      return;
+    }
+    let class = method.parent();
+    let class_name = if let Some(class_decl) = class.parent().to::<ClassDecl>()
+    {
+      class_decl.ident.sym()
+    } else if let Some(ident) = class
+      .parent()
+      .to::<ClassExpr>()
+      .and_then(|class_expr| class_expr.ident)
+    {
+      ident.sym()
+    } else {
+      return;
+    };
+
+    let (Some(return_type), Some(body)) =
+      (method.function.return_type, method.function.body)
+    else {
+      return;
+    };
+    let Some(class_ref) = find_class_ref(&return_type.type_ann, class_name)
+    else {
+      return;
+    };
+
+    let mut finder = ThisReturnFinder::default();
+    body.inner.visit_with(&mut finder);
+    if !finder.has_return || finder.returns_other {
+      return;
+    }
+
+    ctx.add_diagnostic_with_fixes(
+      class_ref.range(),
+      CODE,
+      PreferReturnThisTypeMessage::UseThisType(class_ref.text().to_string()),
+      Some(PreferReturnThisTypeHint::UseThisType.to_string()),
+      vec![LintFix {
+        description: FIX_DESC.into(),
+        changes: vec![LintFixChange {
+          new_text: "this".into(),
+          range: class_ref.range(),
+        }],
+      }],
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn prefer_return_this_type_valid() {
+    assert_lint_ok! {
+      PreferReturnThisType,
+      "class Foo { f(): this { return this; } }",
+      "class Foo { f() { return this; } }",
+      "class Foo { f(): Foo { return new Foo(); } }",
+      "class Foo { f(): Foo | undefined { return new Foo(); } }",
+      "class Foo { f(): Foo { const g = () => this; return new Foo(); } }",
+      "class Foo { f(): Foo { const self = this; return self; } }",
+      "class Foo { f(): Foo { if (x) { return this; } return new Foo(); } }",
+      "class Foo { f(): Foo { throw new Error(); } }",
+      "class Foo { static f(): Foo { return this; } }",
+      "class Foo { f(): Bar { return this; } }",
+      "class Foo { f(): Foo { return; } }",
+      "export default class { f(): Foo { return this; } }",
+      "class Foo { f(): Foo { const g = function () { return this; }; return g(); } }",
+    };
+  }
+
+  #[test]
+  fn prefer_return_this_type_invalid() {
+    assert_lint_err! {
+      PreferReturnThisType,
+      "class Foo { f(): Foo { return this; } }": [
+        {
+          col: 17,
+          message: PreferReturnThisTypeMessage::UseThisType("Foo".to_string()),
+          hint: PreferReturnThisTypeHint::UseThisType,
+          fix: (FIX_DESC, "class Foo { f(): this { return this; } }"),
+        }
+      ],
+      "class Foo<T> { f(): Foo<T> { if (x) { return (this); } return this; } }": [
+        {
+          col: 20,
+          message: PreferReturnThisTypeMessage::UseThisType(
+            "Foo<T>".to_string()
+          ),
+          hint: PreferReturnThisTypeHint::UseThisType,
+          fix: (
+            FIX_DESC,
+            "class Foo<T> { f(): this { if (x) { return (this); } return this; } }"
+          ),
+        }
+      ],
+      "const Foo = class Bar { f(): Bar | undefined { return this; } };": [
+        {
+          col: 29,
+          message: PreferReturnThisTypeMessage::UseThisType("Bar".to_string()),
+          hint: PreferReturnThisTypeHint::UseThisType,
+          fix: (
+            FIX_DESC,
+            "const Foo = class Bar { f(): this | undefined { return this; } };"
+          ),
+        }
+      ],
+      "class Foo { f(): Foo { const g = () => { return 1; }; return this; } }": [
+        {
+          col: 17,
+          message: PreferReturnThisTypeMessage::UseThisType("Foo".to_string()),
+          hint: PreferReturnThisTypeHint::UseThisType,
+          fix: (
+            FIX_DESC,
+            "class Foo { f(): this { const g = () => { return 1; }; return this; } }"
+          ),
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,255 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::Handler;
+use crate::swc_util::{is_string_literal, literal_regex};
+use deno_ast::view::{
+  CallExpr, Callee, Expr, Lit, MemberExpr, MemberProp, NodeKind,
+};
+use deno_ast::SourceRanged;
+use derive_more::Display;
+
+#[derive(Debug)]
+pub struct PreferStringMethod;
+
+const CODE: &str = "prefer-string-method";
+const FIX_DESC: &str = "Use the string method";
+
+#[derive(Display)]
+enum PreferStringMethodMessage {
+  #[display(
+    fmt = "Use `String.prototype.{}` instead of a regular expression",
+    _0
+  )]
+  UseMethod(&'static str),
+}
+
+#[derive(Display)]
+enum PreferStringMethodHint {
+  #[display(
+    fmt = "The regular expression only matches literal text, which the string method can find on its own"
+  )]
+  LiteralOnly,
+}
+
+impl LintRule for PreferStringMethod {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(PreferStringMethodHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::CallExpr])
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/prefer_string_method.md")
+  }
+}
+
+struct PreferStringMethodHandler;
+
+impl PreferStringMethodHandler {
+  /// `/^abc/.test(str)`, which is `str.startsWith("abc")`.
+  fn check_test(
+    &self,
+    call_expr: &CallExpr,
+    regex: &Expr,
+    ctx: &mut Context,
+  ) -> Option<()> {
+    let literal = literal_regex(regex, "u")?;
+    let method = match (literal.start, literal.end) {
+      (true, false) => "startsWith",
+      (false, true) => "endsWith",
+      (false, false) => "includes",
+      // `str === "abc"` isn't a method call, and it doesn't convert `str` to
+      // a string like `test` does.
+      (true, true) => return None,
+    };
+    let [arg] = call_expr.args else {
+      return None;
+    };
+    if arg.spread().is_some() {
+      return None;
+    }
+    // `test()` converts anything to a string, while the string methods throw
+    // on `undefined` and `includes` means something else on arrays, so only
+    // strings are fixed.
+    let fixes = if is_string_literal(&arg.expr) {
+      let subject = arg.expr.text_fast(ctx.text_info());
+      vec![LintFix {
+        description: FIX_DESC.into(),
+        changes: vec![LintFixChange {
+          new_text: format!("{subject}.{method}({:?})", literal.text).into(),
+          range: call_expr.range(),
+        }],
+      }]
+    } else {
+      vec![]
+    };
+    ctx.add_diagnostic_with_fixes(
+      call_expr.range(),
+      CODE,
+      PreferStringMethodMessage::UseMethod(method),
+      Some(PreferStringMethodHint::LiteralOnly.to_string()),
+      fixes,
+    );
+    Some(())
+  }
+
+  /// `str.replace(/abc/g, "")`, which is `str.replaceAll("abc", "")`.
+  fn check_replace(
+    &self,
+    call_expr: &CallExpr,
+    callee: &MemberExpr,
+    ctx: &mut Context,
+  ) -> Option<()> {
+    let [pattern, _] = call_expr.args else {
+      return None;
+    };
+    let Expr::Lit(Lit::Regex(regex)) = pattern.expr else {
+      return None;
+    };
+    if !regex.inner.flags.contains('g') {
+      return None;
+    }
+    let literal = literal_regex(&pattern.expr, "gu")?;
+    if literal.start || literal.end {
+      return None;
+    }
+    // Other objects can have a `replace` method without `replaceAll`.
+    let fixes = if is_string_literal(&callee.obj) {
+      vec![LintFix {
+        description: FIX_DESC.into(),
+        changes: vec![
+          LintFixChange {
+            new_text: "replaceAll".into(),
+            range: callee.prop.range(),
+          },
+          LintFixChange {
+            new_text: format!("{:?}", literal.text).into(),
+            range: pattern.range(),
+          },
+        ],
+      }]
+    } else {
+      vec![]
+    };
+    ctx.add_diagnostic_with_fixes(
+      call_expr.range(),
+      CODE,
+      PreferStringMethodMessage::UseMethod("replaceAll"),
+      Some(PreferStringMethodHint::LiteralOnly.to_string()),
+      fixes,
+    );
+    Some(())
+  }
+}
+
+impl Handler for PreferStringMethodHandler {
+  fn call_expr(&mut self, call_expr: &CallExpr, ctx: &mut Context) {
+    let Callee::Expr(Expr::Member(callee)) = &call_expr.callee else {
+      return;
+    };
+    let MemberProp::Ident(prop) = &callee.prop else {
+      return;
+    };
+    match prop.sym().as_ref() {
+      "test" => self.check_test(call_expr, &callee.obj, ctx),
+      "replace" => self.check_replace(call_expr, callee, ctx),
+      _ => None,
+    };
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn prefer_string_method_valid() {
+    assert_lint_ok! {
+      PreferStringMethod,
+      r"/^a+/.test(str)",
+      r"/^abc/i.test(str)",
+      r"/^abc/m.test(str)",
+      r"/^abc$/.test(str)",
+      r"/a|b/.test(str)",
+      r"/[abc]/.test(str)",
+      r"/a.c/.test(str)",
+      r"re.test(str)",
+      r"/abc/.test(...args)",
+      r"str.replace(/abc/, '')",
+      r"str.replace(/^abc/g, '')",
+      r"str.replace(/a\d/g, '')",
+      r"str.replaceAll(/abc/g, '')",
+    };
+  }
+
+  #[test]
+  fn prefer_string_method_invalid() {
+    assert_lint_err! {
+      PreferStringMethod,
+      r"/^abc/.test(str)": [
+        {
+          col: 0,
+          message: PreferStringMethodMessage::UseMethod("startsWith"),
+          hint: PreferStringMethodHint::LiteralOnly,
+        }
+      ],
+      r"if (/\.ts$/.test(path)) {}": [
+        {
+          col: 4,
+          message: PreferStringMethodMessage::UseMethod("endsWith"),
+          hint: PreferStringMethodHint::LiteralOnly,
+        }
+      ],
+      r"/foo/.test(a + b)": [
+        {
+          col: 0,
+          message: PreferStringMethodMessage::UseMethod("includes"),
+          hint: PreferStringMethodHint::LiteralOnly,
+        }
+      ],
+      r"/\x41\n/u.test(str)": [
+        {
+          col: 0,
+          message: PreferStringMethodMessage::UseMethod("includes"),
+          hint: PreferStringMethodHint::LiteralOnly,
+        }
+      ],
+      r"str.replace(/\//g, '\\')": [
+        {
+          col: 0,
+          message: PreferStringMethodMessage::UseMethod("replaceAll"),
+          hint: PreferStringMethodHint::LiteralOnly,
+        }
+      ],
+      r"/\x41\n/u.test('ABC\n')": [
+        {
+          col: 0,
+          message: PreferStringMethodMessage::UseMethod("includes"),
+          hint: PreferStringMethodHint::LiteralOnly,
+          fix: (FIX_DESC, r#"'ABC\n'.includes("A\n")"#),
+        }
+      ],
+      r"`${a}/${b}`.replace(/\//g, '\\')": [
+        {
+          col: 0,
+          message: PreferStringMethodMessage::UseMethod("replaceAll"),
+          hint: PreferStringMethodHint::LiteralOnly,
+          fix: (FIX_DESC, r#"`${a}/${b}`.replaceAll("/", '\\')"#),
+        }
+      ],
+    };
+  }
+}
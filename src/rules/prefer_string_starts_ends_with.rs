@@ -0,0 +1,169 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::Handler;
+use deno_ast::view::{
+  BinExpr, BinaryOp, Callee, Expr, Lit, MemberProp, NodeKind,
+};
+use deno_ast::{SourceRange, SourceRanged};
+use derive_more::Display;
+
+#[derive(Debug)]
+pub struct PreferStringStartsEndsWith;
+
+const CODE: &str = "prefer-string-starts-ends-with";
+
+#[derive(Display)]
+enum PreferStringStartsEndsWithMessage {
+  #[display(fmt = "Use `startsWith()` instead of comparing `charAt(0)`")]
+  CharAt,
+}
+
+#[derive(Display)]
+enum PreferStringStartsEndsWithHint {
+  #[display(fmt = "`startsWith()` checks the first character directly")]
+  FirstChar,
+}
+
+impl LintRule for PreferStringStartsEndsWith {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(PreferStringStartsEndsWithHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::BinExpr])
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/prefer_string_starts_ends_with.md")
+  }
+}
+
+/// The range of `charAt` if the expression is `str.charAt(0)`.
+fn char_at_zero(expr: &Expr) -> Option<SourceRange> {
+  let Expr::Call(call) = expr else {
+    return None;
+  };
+  let Callee::Expr(Expr::Member(callee)) = &call.callee else {
+    return None;
+  };
+  let MemberProp::Ident(prop) = &callee.prop else {
+    return None;
+  };
+  let [index] = call.args else {
+    return None;
+  };
+  let is_zero = index.spread().is_none()
+    && matches!(index.expr, Expr::Lit(Lit::Num(num)) if num.value() == 0.0);
+  (prop.sym() == "charAt" && is_zero).then(|| prop.range())
+}
+
+/// A string literal of a single UTF-16 code unit, which is what `charAt()`
+/// returns.
+fn is_single_char(expr: &Expr) -> bool {
+  matches!(expr, Expr::Lit(Lit::Str(str))
+    if str.value().encode_utf16().count() == 1)
+}
+
+struct PreferStringStartsEndsWithHandler;
+
+impl Handler for PreferStringStartsEndsWithHandler {
+  fn bin_expr(&mut self, bin_expr: &BinExpr, ctx: &mut Context) {
+    let negated = match bin_expr.op() {
+      BinaryOp::EqEqEq | BinaryOp::EqEq => false,
+      BinaryOp::NotEqEq | BinaryOp::NotEq => true,
+      _ => return,
+    };
+    let (call, prop, expected) =
+      if let Some(prop) = char_at_zero(&bin_expr.left) {
+        (&bin_expr.left, prop, &bin_expr.right)
+      } else if let Some(prop) = char_at_zero(&bin_expr.right) {
+        (&bin_expr.right, prop, &bin_expr.left)
+      } else {
+        return;
+      };
+    if !is_single_char(expected) {
+      return;
+    }
+    let new_text = format!(
+      "{}{}startsWith({})",
+      if negated { "!" } else { "" },
+      ctx.text_of(SourceRange::new(call.start(), prop.start)),
+      expected.text_fast(ctx.text_info()),
+    );
+    ctx.add_diagnostic_with_fixes(
+      bin_expr.range(),
+      CODE,
+      PreferStringStartsEndsWithMessage::CharAt,
+      Some(PreferStringStartsEndsWithHint::FirstChar.to_string()),
+      vec![LintFix {
+        description: "Replace with `startsWith()`".into(),
+        changes: vec![LintFixChange {
+          new_text: new_text.into(),
+          range: bin_expr.range(),
+        }],
+      }],
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn prefer_string_starts_ends_with_valid() {
+    assert_lint_ok! {
+      PreferStringStartsEndsWith,
+      "str.startsWith('a')",
+      "/abc/.test(str)",
+      "/^abc$/.test(str)",
+      "/^abc/i.test(str)",
+      "/^abc/m.test(str)",
+      "/^a+/.test(str)",
+      "/[abc]$/.test(str)",
+      "/^abc/.test(...args)",
+      // left to `prefer-string-method`
+      "/^abc/.test(str)",
+      r"/\.ts$/.test(`${name}.ts`)",
+      "str.charAt(0) === 'ab'",
+      "str.charAt(0) === ''",
+      "str.charAt(1) === 'a'",
+      "str.charAt(0) === char",
+      "str.charAt(0) < 'a'",
+    };
+  }
+
+  #[test]
+  fn prefer_string_starts_ends_with_invalid() {
+    assert_lint_err! {
+      PreferStringStartsEndsWith,
+      "str.charAt(0) === '#'": [
+        {
+          col: 0,
+          message: PreferStringStartsEndsWithMessage::CharAt,
+          hint: PreferStringStartsEndsWithHint::FirstChar,
+          fix: ("Replace with `startsWith()`", "str.startsWith('#')"),
+        }
+      ],
+      "if ('/' != path.charAt(0)) {}": [
+        {
+          col: 4,
+          message: PreferStringStartsEndsWithMessage::CharAt,
+          hint: PreferStringStartsEndsWithHint::FirstChar,
+          fix: ("Replace with `startsWith()`", "if (!path.startsWith('/')) {}"),
+        }
+      ],
+    };
+  }
+}
@@ -3,9 +3,10 @@
 use super::{Context, LintRule};
 use crate::handler::{Handler, Traverse};
 use crate::swc_util::StringRepr;
+use crate::syntax_features::SyntaxFeatures;
 
 use deno_ast::swc::parser::token::{Token, Word};
-use deno_ast::view::{NodeTrait, Program};
+use deno_ast::view::{NodeKind, NodeTrait};
 use deno_ast::SourceRange;
 use deno_ast::SourceRanged;
 use deno_ast::SourceRangedForSpanned;
@@ -59,12 +60,23 @@ impl LintRule for RequireAwait {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    RequireAwaitHandler.traverse(program, context);
+  fn required_syntax(&self) -> SyntaxFeatures {
+    SyntaxFeatures::ASYNC
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(RequireAwaitHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[
+      NodeKind::FnDecl,
+      NodeKind::FnExpr,
+      NodeKind::ArrowExpr,
+      NodeKind::MethodProp,
+      NodeKind::ClassMethod,
+      NodeKind::PrivateMethod,
+    ])
   }
 
   #[cfg(feature = "docs")]
@@ -120,8 +132,8 @@ fn find_async_token_range(
   node: deno_ast::view::Node,
   ctx: &Context,
 ) -> SourceRange {
-  node
-    .tokens_fast(ctx.program())
+  ctx
+    .tokens_within(node.range())
     .iter()
     .find(|t| t.token == Token::Word(Word::Ident("async".into())))
     .expect("there must be a async span")
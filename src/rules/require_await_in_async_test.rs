@@ -0,0 +1,159 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::handler::Handler;
+use crate::testing::{enclosing_test, TestCall, TestCallKind};
+use deno_ast::view::{
+  CallExpr, Callee, Expr, ExprStmt, MemberProp, NodeKind, NodeTrait,
+};
+use deno_ast::SourceRanged;
+use derive_more::Display;
+
+#[derive(Debug)]
+pub struct RequireAwaitInAsyncTest;
+
+const CODE: &str = "require-await-in-async-test";
+
+#[derive(Display)]
+enum RequireAwaitInAsyncTestMessage {
+  #[display(fmt = "Test step is not awaited")]
+  Step,
+  #[display(fmt = "`{}` returns a promise that is not awaited", _0)]
+  Assertion(&'static str),
+}
+
+#[derive(Display)]
+enum RequireAwaitInAsyncTestHint {
+  #[display(
+    fmt = "Add `await`, otherwise the test can finish before it does and its failures are missed"
+  )]
+  AddAwait,
+}
+
+impl LintRule for RequireAwaitInAsyncTest {
+  fn tags(&self) -> &'static [&'static str] {
+    &["test"]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(RequireAwaitInAsyncTestHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::ExprStmt])
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/require_await_in_async_test.md")
+  }
+}
+
+/// Assertions from `@std/assert` that return a promise.
+const ASYNC_ASSERTIONS: &[&str] = &["assertRejects"];
+
+fn async_assertion(call_expr: &CallExpr) -> Option<&'static str> {
+  let Callee::Expr(callee) = &call_expr.callee else {
+    return None;
+  };
+  let name = match callee {
+    Expr::Ident(ident) => ident.sym(),
+    Expr::Member(member) => match &member.prop {
+      MemberProp::Ident(ident) => ident.sym(),
+      _ => return None,
+    },
+    _ => return None,
+  };
+  ASYNC_ASSERTIONS
+    .iter()
+    .find(|assertion| name == **assertion)
+    .copied()
+}
+
+struct RequireAwaitInAsyncTestHandler;
+
+impl Handler for RequireAwaitInAsyncTestHandler {
+  fn expr_stmt(&mut self, expr_stmt: &ExprStmt, ctx: &mut Context) {
+    // Only promises whose result is thrown away are reported, since ones that
+    // are stored or passed on may be awaited later.
+    let Expr::Call(call_expr) = &expr_stmt.expr else {
+      return;
+    };
+    let message = match TestCall::from_call(call_expr, ctx) {
+      Some(step) if step.kind == TestCallKind::Step => {
+        RequireAwaitInAsyncTestMessage::Step
+      }
+      Some(_) => return,
+      None => match async_assertion(call_expr) {
+        Some(name) => RequireAwaitInAsyncTestMessage::Assertion(name),
+        None => return,
+      },
+    };
+    if enclosing_test(expr_stmt.as_node(), ctx).is_some() {
+      ctx.add_diagnostic_with_hint(
+        call_expr.range(),
+        CODE,
+        message,
+        RequireAwaitInAsyncTestHint::AddAwait,
+      );
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn require_await_in_async_test_valid() {
+    assert_lint_ok! {
+      RequireAwaitInAsyncTest,
+      r#"Deno.test("steps", async (t) => { await t.step("a", () => {}); });"#,
+      r#"Deno.test("rejects", async () => { await assertRejects(() => load()); });"#,
+      r#"Deno.test("rejects", () => assertRejects(() => load()));"#,
+      r#"Deno.test("rejects", () => { return assertRejects(() => load()); });"#,
+      r#"it("rejects", async () => { const done = assertRejects(load); await done; });"#,
+      r#"function helper(t) { t.step("a", () => {}); }"#,
+      r#"assertRejects(() => load());"#,
+    };
+  }
+
+  #[test]
+  fn require_await_in_async_test_invalid() {
+    assert_lint_err! {
+      RequireAwaitInAsyncTest,
+      r#"Deno.test("steps", async (t) => { t.step("a", () => {}); });"#: [
+        {
+          col: 34,
+          message: RequireAwaitInAsyncTestMessage::Step,
+          hint: RequireAwaitInAsyncTestHint::AddAwait,
+        }
+      ],
+      r#"Deno.test({ name: "steps", async fn(t) { await t.step("a", async (t) => { t.step("b", () => {}); }); } });"#: [
+        {
+          col: 74,
+          message: RequireAwaitInAsyncTestMessage::Step,
+          hint: RequireAwaitInAsyncTestHint::AddAwait,
+        }
+      ],
+      r#"it("rejects", async () => { assertRejects(() => load()); });"#: [
+        {
+          col: 28,
+          message: RequireAwaitInAsyncTestMessage::Assertion("assertRejects"),
+          hint: RequireAwaitInAsyncTestHint::AddAwait,
+        }
+      ],
+      r#"Deno.test("rejects", () => { if (x) { asserts.assertRejects(load); } });"#: [
+        {
+          col: 38,
+          message: RequireAwaitInAsyncTestMessage::Assertion("assertRejects"),
+          hint: RequireAwaitInAsyncTestHint::AddAwait,
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,216 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::Program;
+use deno_ast::view::{
+  Decl, DefaultDecl, Expr, ModuleDecl, ModuleItem, Pat, VarDecl,
+};
+use deno_ast::{SourceRange, SourceRanged};
+use derive_more::Display;
+
+#[derive(Debug)]
+pub struct RequireJsdoc;
+
+const CODE: &str = "require-jsdoc";
+const HINT: &str = "Add a `/** ... */` comment that describes it";
+
+#[derive(Display)]
+enum RequireJsdocMessage {
+  #[display(fmt = "Exported function `{}` is missing a JSDoc comment", _0)]
+  Function(String),
+  #[display(fmt = "Exported class `{}` is missing a JSDoc comment", _0)]
+  Class(String),
+}
+
+impl LintRule for RequireJsdoc {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn aliases(&self) -> &'static [&'static str] {
+    &["jsdoc/require-jsdoc"]
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    let Program::Module(module) = program else {
+      return;
+    };
+    let mut previous_overload = None;
+    for item in module.body {
+      let overload = check_item(item, previous_overload, context);
+      previous_overload = overload;
+    }
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/require_jsdoc.md")
+  }
+}
+
+/// Checks an exported declaration, and returns the name of the function if
+/// it's an overload signature.
+fn check_item<'a>(
+  item: &ModuleItem<'a>,
+  previous_overload: Option<&str>,
+  ctx: &mut Context,
+) -> Option<&'a str> {
+  let ModuleItem::ModuleDecl(module_decl) = item else {
+    return None;
+  };
+  let (range, message) = match module_decl {
+    ModuleDecl::ExportDecl(export) => match &export.decl {
+      Decl::Fn(fn_decl) => {
+        let name = fn_decl.ident.sym().as_ref();
+        if fn_decl.function.body.is_none() {
+          report_undocumented(
+            export.range(),
+            fn_decl.ident.range(),
+            RequireJsdocMessage::Function(name.to_string()),
+            ctx,
+          );
+          return Some(name);
+        }
+        // The implementation of overloads isn't part of the public API.
+        if previous_overload == Some(name) {
+          return None;
+        }
+        (
+          fn_decl.ident.range(),
+          RequireJsdocMessage::Function(name.to_string()),
+        )
+      }
+      Decl::Class(class_decl) => (
+        class_decl.ident.range(),
+        RequireJsdocMessage::Class(class_decl.ident.sym().to_string()),
+      ),
+      Decl::Var(var_decl) => {
+        let (range, name) = exported_function(var_decl)?;
+        (range, RequireJsdocMessage::Function(name.to_string()))
+      }
+      _ => return None,
+    },
+    ModuleDecl::ExportDefaultDecl(export) => match &export.decl {
+      DefaultDecl::Fn(fn_expr) => (
+        fn_expr.ident.map_or(export.range(), |ident| ident.range()),
+        RequireJsdocMessage::Function(
+          fn_expr
+            .ident
+            .map_or("default".to_string(), |ident| ident.sym().to_string()),
+        ),
+      ),
+      DefaultDecl::Class(class_expr) => (
+        class_expr
+          .ident
+          .map_or(export.range(), |ident| ident.range()),
+        RequireJsdocMessage::Class(
+          class_expr
+            .ident
+            .map_or("default".to_string(), |ident| ident.sym().to_string()),
+        ),
+      ),
+      DefaultDecl::TsInterfaceDecl(_) => return None,
+    },
+    _ => return None,
+  };
+  report_undocumented(module_decl.range(), range, message, ctx);
+  None
+}
+
+/// The name of a function that is exported with `export const`.
+fn exported_function<'a>(
+  var_decl: &VarDecl<'a>,
+) -> Option<(SourceRange, &'a str)> {
+  let [declarator] = var_decl.decls else {
+    return None;
+  };
+  let Pat::Ident(ident) = &declarator.name else {
+    return None;
+  };
+  match declarator.init? {
+    Expr::Arrow(_) | Expr::Fn(_) => {
+      Some((ident.range(), ident.id.sym().as_ref()))
+    }
+    _ => None,
+  }
+}
+
+fn report_undocumented(
+  declaration: SourceRange,
+  range: SourceRange,
+  message: RequireJsdocMessage,
+  ctx: &mut Context,
+) {
+  if ctx.jsdoc_at(declaration.start).is_none() {
+    ctx.add_diagnostic_with_hint(range, CODE, message, HINT);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn require_jsdoc_valid() {
+    assert_lint_ok! {
+      RequireJsdoc,
+      "/** Adds two numbers. */ export function add(a, b) { return a + b; }",
+      "/** A point. */ export class Point {}",
+      "/** Says hello. */ export const greet = () => console.log('hello');",
+      "/** The default handler. */ export default function () {}",
+      "/** The default class. */ export default class {}",
+      "function notExported() {}",
+      "class NotExported {}",
+      "export const VERSION = '1.0.0';",
+      "export interface Options {}",
+      "/** Parses a number. */ export function parse(x: string): number; /** Parses numbers. */ export function parse(x: string[]): number[]; export function parse(x) { return x; }",
+    };
+  }
+
+  #[test]
+  fn require_jsdoc_invalid() {
+    assert_lint_err! {
+      RequireJsdoc,
+      "export function add(a, b) { return a + b; }": [
+        {
+          col: 16,
+          message: RequireJsdocMessage::Function("add".to_string()),
+          hint: HINT,
+        }
+      ],
+      "// A point.\nexport class Point {}": [
+        {
+          line: 2,
+          col: 13,
+          message: RequireJsdocMessage::Class("Point".to_string()),
+          hint: HINT,
+        }
+      ],
+      "/* Says hello. */ export const greet = function () {};": [
+        {
+          col: 31,
+          message: RequireJsdocMessage::Function("greet".to_string()),
+          hint: HINT,
+        }
+      ],
+      "export default function () {}": [
+        {
+          col: 0,
+          message: RequireJsdocMessage::Function("default".to_string()),
+          hint: HINT,
+        }
+      ],
+      "/** Parses a number. */ export function parse(x: string): number; export function parse(x: string[]): number[]; export function parse(x) { return x; }": [
+        {
+          col: 82,
+          message: RequireJsdocMessage::Function("parse".to_string()),
+          hint: HINT,
+        }
+      ],
+    };
+  }
+}
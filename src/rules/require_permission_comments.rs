@@ -0,0 +1,329 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::handler::{Handler, Traverse};
+use crate::Program;
+
+use deno_ast::view::{Expr, MemberExpr, MemberProp};
+use deno_ast::{SourceRanged, SourceRangedForSpanned};
+use derive_more::Display;
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug)]
+pub struct RequirePermissionComments;
+
+const CODE: &str = "require-permission-comments";
+
+/// Documents the permissions needed by the code on the same or the next line.
+const LINE_DIRECTIVE: &str = "deno-permissions:";
+/// Documents the permissions needed by the whole file.
+const FILE_DIRECTIVE: &str = "deno-permissions-file:";
+
+#[derive(Display)]
+enum RequirePermissionCommentsMessage {
+  #[display(
+    fmt = "`Deno.{}` requires the `{}` permission, which isn't documented",
+    _0,
+    _1
+  )]
+  Undocumented(String, &'static str),
+}
+
+#[derive(Display)]
+enum RequirePermissionCommentsHint {
+  #[display(
+    fmt = "Add a `// deno-permissions: {}` comment on the line above, or a `// deno-permissions-file: {}` comment to the file",
+    _0,
+    _0
+  )]
+  AddComment(&'static str),
+}
+
+/// The permissions required by the APIs in the `Deno` namespace.
+static PERMISSIONS: phf::Map<&'static str, &'static [&'static str]> = phf::phf_map! {
+  "chdir" => &["read"],
+  "cwd" => &["read"],
+  "execPath" => &["read"],
+  "lstat" => &["read"],
+  "lstatSync" => &["read"],
+  "open" => &["read"],
+  "openSync" => &["read"],
+  "readDir" => &["read"],
+  "readDirSync" => &["read"],
+  "readFile" => &["read"],
+  "readFileSync" => &["read"],
+  "readLink" => &["read"],
+  "readLinkSync" => &["read"],
+  "readTextFile" => &["read"],
+  "readTextFileSync" => &["read"],
+  "realPath" => &["read"],
+  "realPathSync" => &["read"],
+  "stat" => &["read"],
+  "statSync" => &["read"],
+  "watchFs" => &["read"],
+  "chmod" => &["write"],
+  "chmodSync" => &["write"],
+  "chown" => &["write"],
+  "chownSync" => &["write"],
+  "create" => &["write"],
+  "createSync" => &["write"],
+  "makeTempDir" => &["write"],
+  "makeTempDirSync" => &["write"],
+  "makeTempFile" => &["write"],
+  "makeTempFileSync" => &["write"],
+  "mkdir" => &["write"],
+  "mkdirSync" => &["write"],
+  "remove" => &["write"],
+  "removeSync" => &["write"],
+  "truncate" => &["write"],
+  "truncateSync" => &["write"],
+  "utime" => &["write"],
+  "utimeSync" => &["write"],
+  "writeFile" => &["write"],
+  "writeFileSync" => &["write"],
+  "writeTextFile" => &["write"],
+  "writeTextFileSync" => &["write"],
+  "copyFile" => &["read", "write"],
+  "copyFileSync" => &["read", "write"],
+  "link" => &["read", "write"],
+  "linkSync" => &["read", "write"],
+  "rename" => &["read", "write"],
+  "renameSync" => &["read", "write"],
+  "symlink" => &["read", "write"],
+  "symlinkSync" => &["read", "write"],
+  "connect" => &["net"],
+  "connectTls" => &["net"],
+  "listen" => &["net"],
+  "listenDatagram" => &["net"],
+  "listenTls" => &["net"],
+  "resolveDns" => &["net"],
+  "serve" => &["net"],
+  "startTls" => &["net"],
+  "env" => &["env"],
+  "Command" => &["run"],
+  "kill" => &["run"],
+  "run" => &["run"],
+  "gid" => &["sys"],
+  "hostname" => &["sys"],
+  "loadavg" => &["sys"],
+  "networkInterfaces" => &["sys"],
+  "osRelease" => &["sys"],
+  "osUptime" => &["sys"],
+  "systemMemoryInfo" => &["sys"],
+  "uid" => &["sys"],
+  "dlopen" => &["ffi"],
+};
+
+impl LintRule for RequirePermissionComments {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    let mut handler = RequirePermissionCommentsHandler::default();
+    for comment in context.all_comments() {
+      let text = comment.text.trim();
+      if let Some(permissions) = text.strip_prefix(FILE_DIRECTIVE) {
+        handler
+          .file_permissions
+          .extend(parse_permissions(permissions));
+      } else if let Some(permissions) = text.strip_prefix(LINE_DIRECTIVE) {
+        let line = context.line_index(comment.end());
+        handler
+          .line_permissions
+          .entry(line)
+          .or_default()
+          .extend(parse_permissions(permissions));
+      }
+    }
+    handler.traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/require_permission_comments.md")
+  }
+}
+
+/// Parses a list of permissions like `read, net` or `--allow-read --allow-net`.
+fn parse_permissions(text: &str) -> impl Iterator<Item = String> + '_ {
+  text
+    .split(|c: char| c == ',' || c.is_whitespace())
+    .filter(|permission| !permission.is_empty())
+    .map(|permission| permission.trim_start_matches("--allow-").to_string())
+}
+
+#[derive(Default)]
+struct RequirePermissionCommentsHandler {
+  file_permissions: HashSet<String>,
+  /// Permissions documented by line comments, keyed by the line they end on.
+  line_permissions: HashMap<usize, HashSet<String>>,
+}
+
+impl RequirePermissionCommentsHandler {
+  fn is_documented(&self, permission: &str, line: usize) -> bool {
+    let documented_at = |line: usize| {
+      self
+        .line_permissions
+        .get(&line)
+        .is_some_and(|permissions| permissions.contains(permission))
+    };
+    self.file_permissions.contains(permission)
+      || documented_at(line)
+      || (line > 0 && documented_at(line - 1))
+  }
+}
+
+impl Handler for RequirePermissionCommentsHandler {
+  fn member_expr(&mut self, member_expr: &MemberExpr, ctx: &mut Context) {
+    let Expr::Ident(obj) = &member_expr.obj else {
+      return;
+    };
+    if obj.sym() != "Deno" || !ctx.scope().is_global(&obj.inner.to_id()) {
+      return;
+    }
+    let MemberProp::Ident(prop) = &member_expr.prop else {
+      return;
+    };
+    let name = prop.sym().as_ref();
+    let Some(permissions) = PERMISSIONS.get(name) else {
+      return;
+    };
+
+    let line = ctx.line_index(member_expr.start());
+    for permission in permissions.iter() {
+      if !self.is_documented(permission, line) {
+        ctx.add_diagnostic_with_hint(
+          member_expr.range(),
+          CODE,
+          RequirePermissionCommentsMessage::Undocumented(
+            name.to_string(),
+            permission,
+          ),
+          RequirePermissionCommentsHint::AddComment(permission),
+        );
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn require_permission_comments_valid() {
+    assert_lint_ok! {
+      RequirePermissionComments,
+      r#"
+// deno-permissions: read
+const text = await Deno.readTextFile("./config.json");
+      "#,
+      r#"const text = await Deno.readTextFile("./config.json"); // deno-permissions: read"#,
+      r#"
+// deno-permissions: --allow-read --allow-write
+await Deno.copyFile("a.txt", "b.txt");
+      "#,
+      r#"
+// deno-permissions-file: env, net
+const port = Deno.env.get("PORT");
+
+export function connect() {
+  return Deno.connect({ port: Number(port) });
+}
+      "#,
+      r#"
+/* deno-permissions: run */
+const command = new Deno.Command("git", { args: ["status"] });
+      "#,
+      "const args = Deno.args;",
+      "const pid = Deno.pid;",
+      "const Deno = { readTextFile() {} }; Deno.readTextFile();",
+      "foo.readTextFile();",
+    };
+  }
+
+  #[test]
+  fn require_permission_comments_invalid() {
+    assert_lint_err! {
+      RequirePermissionComments,
+      r#"const text = await Deno.readTextFile("./config.json");"#: [
+        {
+          col: 19,
+          message: RequirePermissionCommentsMessage::Undocumented(
+            "readTextFile".to_string(),
+            "read"
+          ),
+          hint: RequirePermissionCommentsHint::AddComment("read"),
+        }
+      ],
+      r#"const port = Deno.env.get("PORT");"#: [
+        {
+          col: 13,
+          message: RequirePermissionCommentsMessage::Undocumented(
+            "env".to_string(),
+            "env"
+          ),
+          hint: RequirePermissionCommentsHint::AddComment("env"),
+        }
+      ],
+      r#"
+// deno-permissions: read
+await Deno.copyFile("a.txt", "b.txt");
+      "#: [
+        {
+          line: 3,
+          col: 6,
+          message: RequirePermissionCommentsMessage::Undocumented(
+            "copyFile".to_string(),
+            "write"
+          ),
+          hint: RequirePermissionCommentsHint::AddComment("write"),
+        }
+      ],
+      r#"
+// deno-permissions: net
+
+const conn = await Deno.connect({ port: 80 });
+      "#: [
+        {
+          line: 4,
+          col: 19,
+          message: RequirePermissionCommentsMessage::Undocumented(
+            "connect".to_string(),
+            "net"
+          ),
+          hint: RequirePermissionCommentsHint::AddComment("net"),
+        }
+      ],
+      r#"
+// deno-permissions-file: read
+const name = Deno.hostname();
+      "#: [
+        {
+          line: 3,
+          col: 13,
+          message: RequirePermissionCommentsMessage::Undocumented(
+            "hostname".to_string(),
+            "sys"
+          ),
+          hint: RequirePermissionCommentsHint::AddComment("sys"),
+        }
+      ],
+      "const lib = Deno.dlopen('./lib.so', {});": [
+        {
+          col: 12,
+          message: RequirePermissionCommentsMessage::Undocumented(
+            "dlopen".to_string(),
+            "ffi"
+          ),
+          hint: RequirePermissionCommentsHint::AddComment("ffi"),
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,112 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::handler::Handler;
+use crate::js_regex::RegexSource;
+use crate::syntax_features::SyntaxFeatures;
+use deno_ast::view::{CallExpr, Callee, NewExpr, NodeKind, Regex};
+use deno_ast::SourceRanged;
+
+#[derive(Debug)]
+pub struct RequireUnicodeRegexp;
+
+const CODE: &str = "require-unicode-regexp";
+const MESSAGE: &str = "Regular expression is missing the `u` or `v` flag";
+const HINT: &str =
+  "Add the `u` flag, or the `v` flag to also use set notation in classes";
+
+impl LintRule for RequireUnicodeRegexp {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn required_syntax(&self) -> SyntaxFeatures {
+    SyntaxFeatures::REGEX
+  }
+
+  fn aliases(&self) -> &'static [&'static str] {
+    &["regexp/require-unicode-regexp"]
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(RequireUnicodeRegexpHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::Regex, NodeKind::NewExpr, NodeKind::CallExpr])
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/require_unicode_regexp.md")
+  }
+}
+
+fn check_regex(regex: &RegexSource, ctx: &mut Context) {
+  // Flags that aren't a string literal may well include `u` or `v`.
+  if regex.flags.is_some() && !regex.has_flag('u') && !regex.has_flag('v') {
+    ctx.add_diagnostic_with_hint(regex.range, CODE, MESSAGE, HINT);
+  }
+}
+
+struct RequireUnicodeRegexpHandler;
+
+impl Handler for RequireUnicodeRegexpHandler {
+  fn regex(&mut self, regex: &Regex, ctx: &mut Context) {
+    check_regex(&RegexSource::from_regex(regex), ctx);
+  }
+
+  fn new_expr(&mut self, new_expr: &NewExpr, ctx: &mut Context) {
+    if let Some(regex) = RegexSource::from_constructor(
+      &new_expr.callee,
+      new_expr.args.unwrap_or_default(),
+      new_expr.range(),
+      ctx,
+    ) {
+      check_regex(&regex, ctx);
+    }
+  }
+
+  fn call_expr(&mut self, call_expr: &CallExpr, ctx: &mut Context) {
+    if let Callee::Expr(callee) = &call_expr.callee {
+      if let Some(regex) = RegexSource::from_constructor(
+        callee,
+        call_expr.args,
+        call_expr.range(),
+        ctx,
+      ) {
+        check_regex(&regex, ctx);
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn require_unicode_regexp_valid() {
+    assert_lint_ok! {
+      RequireUnicodeRegexp,
+      r"/foo/u",
+      r"/foo/gv",
+      r"new RegExp('foo', 'u')",
+      r"RegExp('foo', 'gu')",
+      r"new RegExp('foo', flags)",
+      r"new RegExp(pattern)",
+      r"const RegExp = Foo; new RegExp('foo');",
+    };
+  }
+
+  #[test]
+  fn require_unicode_regexp_invalid() {
+    assert_lint_err! {
+      RequireUnicodeRegexp,
+      r"/foo/": [{ col: 0, message: MESSAGE, hint: HINT }],
+      r"const re = /foo/gi;": [{ col: 11, message: MESSAGE, hint: HINT }],
+      r"new RegExp('foo')": [{ col: 0, message: MESSAGE, hint: HINT }],
+      r"RegExp('foo', 'g')": [{ col: 0, message: MESSAGE, hint: HINT }],
+    };
+  }
+}
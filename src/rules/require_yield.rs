@@ -2,6 +2,7 @@
 
 use super::program_ref;
 use super::{Context, LintRule};
+use crate::syntax_features::SyntaxFeatures;
 use crate::Program;
 use crate::ProgramRef;
 use deno_ast::swc::ast::ClassMethod;
@@ -30,6 +31,10 @@ impl LintRule for RequireYield {
     CODE
   }
 
+  fn required_syntax(&self) -> SyntaxFeatures {
+    SyntaxFeatures::GENERATOR
+  }
+
   fn lint_program_with_ast_view<'view>(
     &self,
     context: &mut Context<'view>,
@@ -0,0 +1,505 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::handler::Handler;
+
+use deno_ast::swc::ast::{
+  ArrowExpr as SwcArrowExpr, Class as SwcClass, Function as SwcFunction,
+  ReturnStmt as SwcReturnStmt,
+};
+use deno_ast::swc::visit::{noop_visit_type, Visit, VisitWith};
+use deno_ast::view::{
+  ArrowExpr, AssignExpr, AssignTarget, BinExpr, BinaryOp, BlockStmt,
+  BlockStmtOrExpr, CallExpr, Callee, ClassProp, CondExpr, DoWhileStmt,
+  ExportDefaultDecl, ExportDefaultExpr, Expr, ExprOrSpread, FnDecl, FnExpr,
+  ForInStmt, ForOfStmt, ForStmt, Function, GetterProp, IfStmt, KeyValueProp,
+  MemberProp, MethodProp, Node, NodeKind, NodeTrait, ParenExpr, Pat, PropName,
+  SetterProp, SimpleAssignTarget, SwitchCase, VarDeclarator, WhileStmt,
+};
+use deno_ast::SourceRanged;
+use derive_more::Display;
+
+#[derive(Debug)]
+pub struct RulesOfHooks;
+
+const CODE: &str = "rules-of-hooks";
+
+#[derive(Display)]
+enum RulesOfHooksMessage {
+  #[display(fmt = "React Hook `{}` is called conditionally", _0)]
+  Conditional(String),
+  #[display(
+    fmt = "React Hook `{}` may be called more than once because it is called in a loop",
+    _0
+  )]
+  InLoop(String),
+  #[display(
+    fmt = "React Hook `{}` is called after a possible early return",
+    _0
+  )]
+  AfterEarlyReturn(String),
+  #[display(fmt = "React Hook `{}` is called inside a callback", _0)]
+  InCallback(String),
+  #[display(
+    fmt = "React Hook `{}` is called in function `{}`, which is neither a component nor a custom hook",
+    _0,
+    _1
+  )]
+  InRegularFunction(String, String),
+  #[display(fmt = "React Hook `{}` is called in a class", _0)]
+  InClass(String),
+  #[display(fmt = "React Hook `{}` is called at the top level", _0)]
+  TopLevel(String),
+}
+
+#[derive(Display)]
+enum RulesOfHooksHint {
+  #[display(
+    fmt = "Hooks must be called in the same order on every render, so call them unconditionally at the top level of a component or custom hook"
+  )]
+  CallAtTopLevel,
+  #[display(
+    fmt = "Component names must start with an uppercase letter, and custom hook names with `use`"
+  )]
+  Rename,
+}
+
+impl LintRule for RulesOfHooks {
+  fn tags(&self) -> &'static [&'static str] {
+    &["react", "fresh"]
+  }
+
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn aliases(&self) -> &'static [&'static str] {
+    &["react-hooks/rules-of-hooks"]
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(RulesOfHooksHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::CallExpr])
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/rules_of_hooks.md")
+  }
+}
+
+/// `useState` and `use1` are hooks, `use` and `user` are not.
+fn is_hook_name(name: &str) -> bool {
+  name
+    .strip_prefix("use")
+    .and_then(|rest| rest.chars().next())
+    .is_some_and(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+}
+
+fn is_component_name(name: &str) -> bool {
+  name.chars().next().is_some_and(|c| c.is_ascii_uppercase())
+}
+
+/// The name of the hook called by `call_expr`, for calls like `useState()`
+/// and `React.useState()`.
+fn hook_name(call_expr: &CallExpr) -> Option<String> {
+  let name = match &call_expr.callee {
+    Callee::Expr(Expr::Ident(ident)) => ident.sym(),
+    Callee::Expr(Expr::Member(member)) => match (&member.obj, &member.prop) {
+      (Expr::Ident(obj), MemberProp::Ident(prop))
+        if is_component_name(obj.sym()) =>
+      {
+        prop.sym()
+      }
+      _ => return None,
+    },
+    _ => return None,
+  };
+  is_hook_name(name).then(|| name.to_string())
+}
+
+/// What a function that calls a hook is, as far as can be told from the
+/// syntax.
+enum FunctionKind {
+  Named(String),
+  /// An anonymous component, like `memo(() => ...)` or an anonymous default
+  /// export.
+  Component,
+  Callback,
+  ClassMember,
+}
+
+impl FunctionKind {
+  fn from_prop_name(key: &PropName) -> Self {
+    match key {
+      PropName::Ident(ident) => FunctionKind::Named(ident.sym().to_string()),
+      _ => FunctionKind::Callback,
+    }
+  }
+}
+
+/// Wrappers whose argument is a component.
+fn is_component_wrapper(callee: &Callee) -> bool {
+  let name = match callee {
+    Callee::Expr(Expr::Ident(ident)) => ident.sym(),
+    Callee::Expr(Expr::Member(member)) => match &member.prop {
+      MemberProp::Ident(prop) => prop.sym(),
+      _ => return false,
+    },
+    _ => return false,
+  };
+  name == "memo" || name == "forwardRef"
+}
+
+/// Classifies an anonymous function by where it's used, e.g. the name of the
+/// variable it's assigned to.
+fn kind_from_context(node: Node) -> FunctionKind {
+  let Some(parent) = node.parent() else {
+    return FunctionKind::Callback;
+  };
+  if parent.is::<ParenExpr>() || parent.is::<ExprOrSpread>() {
+    return kind_from_context(parent);
+  }
+  if let Some(declarator) = parent.to::<VarDeclarator>() {
+    if let Pat::Ident(ident) = &declarator.name {
+      return FunctionKind::Named(ident.id.sym().to_string());
+    }
+  }
+  if let Some(assign) = parent.to::<AssignExpr>() {
+    if let AssignTarget::Simple(SimpleAssignTarget::Ident(ident)) = &assign.left
+    {
+      return FunctionKind::Named(ident.id.sym().to_string());
+    }
+  }
+  if let Some(call) = parent.to::<CallExpr>() {
+    if is_component_wrapper(&call.callee) {
+      return FunctionKind::Component;
+    }
+  }
+  if let Some(prop) = parent.to::<KeyValueProp>() {
+    return FunctionKind::from_prop_name(&prop.key);
+  }
+  if parent.is::<ClassProp>() {
+    return FunctionKind::ClassMember;
+  }
+  if parent.is::<ExportDefaultExpr>() || parent.is::<ExportDefaultDecl>() {
+    return FunctionKind::Component;
+  }
+  FunctionKind::Callback
+}
+
+fn function_kind(function: &Function) -> FunctionKind {
+  let parent = function.parent();
+  if let Some(fn_decl) = parent.to::<FnDecl>() {
+    return FunctionKind::Named(fn_decl.ident.sym().to_string());
+  }
+  if let Some(fn_expr) = parent.to::<FnExpr>() {
+    return match fn_expr.ident {
+      Some(ident) => FunctionKind::Named(ident.sym().to_string()),
+      None => kind_from_context(fn_expr.as_node()),
+    };
+  }
+  if let Some(method) = parent.to::<MethodProp>() {
+    return FunctionKind::from_prop_name(&method.key);
+  }
+  if let Some(getter) = parent.to::<GetterProp>() {
+    return FunctionKind::from_prop_name(&getter.key);
+  }
+  if let Some(setter) = parent.to::<SetterProp>() {
+    return FunctionKind::from_prop_name(&setter.key);
+  }
+  // Class methods, private methods and constructors.
+  FunctionKind::ClassMember
+}
+
+/// Finds `return` statements outside of nested functions.
+#[derive(Default)]
+struct ReturnFinder {
+  found: bool,
+}
+
+impl Visit for ReturnFinder {
+  noop_visit_type!();
+
+  fn visit_return_stmt(&mut self, _return_stmt: &SwcReturnStmt) {
+    self.found = true;
+  }
+
+  fn visit_function(&mut self, _function: &SwcFunction) {}
+
+  fn visit_arrow_expr(&mut self, _arrow_expr: &SwcArrowExpr) {}
+
+  fn visit_class(&mut self, _class: &SwcClass) {}
+}
+
+/// Whether the function may return before reaching the statement `stmt` of
+/// its body. Statements that always return make the rest of the function
+/// unreachable, which `no-unreachable` reports instead.
+fn may_return_before(body: &BlockStmt, stmt: Node, ctx: &Context) -> bool {
+  body
+    .stmts
+    .iter()
+    .zip(body.inner.stmts.iter())
+    .take_while(|(preceding, _)| preceding.start() != stmt.start())
+    .any(|(preceding, inner)| {
+      let mut finder = ReturnFinder::default();
+      inner.visit_with(&mut finder);
+      finder.found
+        && !ctx
+          .control_flow()
+          .meta(preceding.start())
+          .is_some_and(|meta| meta.stops_execution())
+    })
+}
+
+/// How a hook call is nested inside the function that calls it.
+#[derive(Default)]
+struct Nesting {
+  in_condition: bool,
+  in_loop: bool,
+}
+
+impl Nesting {
+  /// Records how `child` is nested in `parent`.
+  fn add(&mut self, parent: Node, child: Node) {
+    let is = |expr_range| child.range() == expr_range;
+    if let Some(if_stmt) = parent.to::<IfStmt>() {
+      self.in_condition |= !is(if_stmt.test.range());
+    } else if let Some(cond) = parent.to::<CondExpr>() {
+      self.in_condition |= !is(cond.test.range());
+    } else if let Some(bin) = parent.to::<BinExpr>() {
+      self.in_condition |= matches!(
+        bin.op(),
+        BinaryOp::LogicalAnd
+          | BinaryOp::LogicalOr
+          | BinaryOp::NullishCoalescing
+      ) && is(bin.right.range());
+    } else if let Some(case) = parent.to::<SwitchCase>() {
+      self.in_condition |= case.test.map_or(true, |test| !is(test.range()));
+    } else if let Some(for_stmt) = parent.to::<ForStmt>() {
+      self.in_loop |= for_stmt.init.map_or(true, |init| !is(init.range()));
+    } else if let Some(for_in) = parent.to::<ForInStmt>() {
+      self.in_loop |= !is(for_in.right.range());
+    } else if let Some(for_of) = parent.to::<ForOfStmt>() {
+      self.in_loop |= !is(for_of.right.range());
+    } else if parent.is::<WhileStmt>() || parent.is::<DoWhileStmt>() {
+      self.in_loop = true;
+    }
+  }
+}
+
+struct RulesOfHooksHandler;
+
+impl Handler for RulesOfHooksHandler {
+  fn call_expr(&mut self, call_expr: &CallExpr, ctx: &mut Context) {
+    let Some(name) = hook_name(call_expr) else {
+      return;
+    };
+
+    let mut nesting = Nesting::default();
+    let mut child = call_expr.as_node();
+    let mut grandchild = child;
+    let mut function = None;
+    for ancestor in call_expr.ancestors() {
+      if let Some(func) = ancestor.to::<Function>() {
+        function = Some((function_kind(func), func.body));
+        break;
+      }
+      if let Some(arrow) = ancestor.to::<ArrowExpr>() {
+        let body = match arrow.body {
+          BlockStmtOrExpr::BlockStmt(body) => Some(body),
+          BlockStmtOrExpr::Expr(_) => None,
+        };
+        function = Some((kind_from_context(arrow.as_node()), body));
+        break;
+      }
+      nesting.add(ancestor, child);
+      grandchild = child;
+      child = ancestor;
+    }
+
+    let message = match function {
+      None => RulesOfHooksMessage::TopLevel(name),
+      Some((FunctionKind::ClassMember, _)) => {
+        RulesOfHooksMessage::InClass(name)
+      }
+      Some((FunctionKind::Callback, _)) => {
+        RulesOfHooksMessage::InCallback(name)
+      }
+      Some((FunctionKind::Named(function_name), _))
+        if !is_component_name(&function_name)
+          && !is_hook_name(&function_name) =>
+      {
+        ctx.add_diagnostic_with_hint(
+          call_expr.range(),
+          CODE,
+          RulesOfHooksMessage::InRegularFunction(name, function_name),
+          RulesOfHooksHint::Rename,
+        );
+        return;
+      }
+      Some((_, body)) => {
+        if nesting.in_loop {
+          RulesOfHooksMessage::InLoop(name)
+        } else if nesting.in_condition {
+          RulesOfHooksMessage::Conditional(name)
+        } else if body
+          .is_some_and(|body| may_return_before(body, grandchild, ctx))
+        {
+          RulesOfHooksMessage::AfterEarlyReturn(name)
+        } else {
+          return;
+        }
+      }
+    };
+    ctx.add_diagnostic_with_hint(
+      call_expr.range(),
+      CODE,
+      message,
+      RulesOfHooksHint::CallAtTopLevel,
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn rules_of_hooks_valid() {
+    assert_lint_ok! {
+      RulesOfHooks,
+      "function App() { const [count, setCount] = useState(0); useEffect(() => {}); }",
+      "function useCounter() { return useState(0); }",
+      "const App = () => { useState(0); };",
+      "const App = function () { useState(0); };",
+      "const App = memo(() => { useState(0); });",
+      "const App = React.forwardRef(function (props, ref) { useImperativeHandle(ref, () => ({})); });",
+      "export default function () { useState(0); }",
+      "export default () => { useState(0); };",
+      "function App() { if (useIsMobile()) {} }",
+      "function App() { for (const item of useItems()) {} }",
+      "function App() { const theme = React.useContext(Theme); }",
+      "function App() { const data = use(promise); if (x) { use(other); } }",
+      "function App() { useEffect(() => { if (x) { return; } }); useState(0); }",
+      "function helper() { user(); usefulThing(); }",
+      "function App() { if (cond) { foo(); } useState(0); }",
+      "const useThing = () => { useState(0); };",
+      "const hooks = { useThing() { useState(0); } };",
+    };
+  }
+
+  #[test]
+  fn rules_of_hooks_invalid() {
+    assert_lint_err! {
+      RulesOfHooks,
+      "function App() { if (cond) { useState(0); } }": [
+        {
+          col: 29,
+          message: RulesOfHooksMessage::Conditional("useState".to_string()),
+          hint: RulesOfHooksHint::CallAtTopLevel,
+        }
+      ],
+      "function App() { const value = cond ? useMemo(f) : null; }": [
+        {
+          col: 38,
+          message: RulesOfHooksMessage::Conditional("useMemo".to_string()),
+          hint: RulesOfHooksHint::CallAtTopLevel,
+        }
+      ],
+      "function App() { cond && useEffect(f); }": [
+        {
+          col: 25,
+          message: RulesOfHooksMessage::Conditional("useEffect".to_string()),
+          hint: RulesOfHooksHint::CallAtTopLevel,
+        }
+      ],
+      "function App() { const value = useContext(Context) ?? useState(0); }": [
+        {
+          col: 54,
+          message: RulesOfHooksMessage::Conditional("useState".to_string()),
+          hint: RulesOfHooksHint::CallAtTopLevel,
+        }
+      ],
+      "function App() { switch (x) { case 1: useState(0); } }": [
+        {
+          col: 38,
+          message: RulesOfHooksMessage::Conditional("useState".to_string()),
+          hint: RulesOfHooksHint::CallAtTopLevel,
+        }
+      ],
+      "function App() { for (const item of items) { useState(item); } }": [
+        {
+          col: 45,
+          message: RulesOfHooksMessage::InLoop("useState".to_string()),
+          hint: RulesOfHooksHint::CallAtTopLevel,
+        }
+      ],
+      "function useItems() { while (more()) { if (x) { useRef(); } } }": [
+        {
+          col: 48,
+          message: RulesOfHooksMessage::InLoop("useRef".to_string()),
+          hint: RulesOfHooksHint::CallAtTopLevel,
+        }
+      ],
+      "function App() { if (loading) { return null; } const [a] = useState(0); }": [
+        {
+          col: 59,
+          message: RulesOfHooksMessage::AfterEarlyReturn("useState".to_string()),
+          hint: RulesOfHooksHint::CallAtTopLevel,
+        }
+      ],
+      "function App() { useEffect(() => { useState(0); }); }": [
+        {
+          col: 35,
+          message: RulesOfHooksMessage::InCallback("useState".to_string()),
+          hint: RulesOfHooksHint::CallAtTopLevel,
+        }
+      ],
+      "function App() { items.map((item) => useMemo(() => item)); }": [
+        {
+          col: 37,
+          message: RulesOfHooksMessage::InCallback("useMemo".to_string()),
+          hint: RulesOfHooksHint::CallAtTopLevel,
+        }
+      ],
+      "function helper() { useState(0); }": [
+        {
+          col: 20,
+          message: RulesOfHooksMessage::InRegularFunction(
+            "useState".to_string(),
+            "helper".to_string()
+          ),
+          hint: RulesOfHooksHint::Rename,
+        }
+      ],
+      "const helper = () => React.useEffect(f);": [
+        {
+          col: 21,
+          message: RulesOfHooksMessage::InRegularFunction(
+            "useEffect".to_string(),
+            "helper".to_string()
+          ),
+          hint: RulesOfHooksHint::Rename,
+        }
+      ],
+      "class App extends Component { render() { useState(0); } }": [
+        {
+          col: 41,
+          message: RulesOfHooksMessage::InClass("useState".to_string()),
+          hint: RulesOfHooksHint::CallAtTopLevel,
+        }
+      ],
+      "const [state] = useState(0);": [
+        {
+          col: 16,
+          message: RulesOfHooksMessage::TopLevel("useState".to_string()),
+          hint: RulesOfHooksHint::CallAtTopLevel,
+        }
+      ],
+    };
+  }
+}
@@ -0,0 +1,845 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::handler::{Handler, Traverse};
+use crate::syntax_features::SyntaxFeatures;
+use crate::Program;
+
+use deno_ast::swc::ast::Id;
+use deno_ast::view::{
+  BindingIdent, Expr, Ident, Lit, MemberProp, Node, NodeTrait, SwitchStmt,
+  TsEntityName, TsEnumDecl, TsEnumMemberId, TsKeywordTypeKind, TsLit, TsType,
+  TsTypeAliasDecl, TsUnionOrIntersectionType, VarDecl, VarDeclKind,
+};
+use deno_ast::{SourcePos, SourceRange, SourceRanged};
+use derive_more::Display;
+use std::collections::{HashMap, HashSet};
+
+/// Requires `switch` statements over union types to handle every member of
+/// the union, or to have a `default` case.
+///
+/// With type information, the members of the union are taken from the type of
+/// the discriminant. Without it, or if that type isn't known, only parameters and `const` bindings annotated
+/// with a union of literal types, or with a type alias or enum declared in the
+/// same file that resolves to one, are checked, and only if they aren't used
+/// before the switch, since that use could narrow their type.
+#[derive(Debug)]
+pub struct SwitchExhaustivenessCheck;
+
+const CODE: &str = "switch-exhaustiveness-check";
+
+#[derive(Display)]
+enum SwitchExhaustivenessCheckMessage {
+  #[display(fmt = "Switch is not exhaustive. Cases not matched: {}", _0)]
+  NotExhaustive(String),
+}
+
+#[derive(Display)]
+enum SwitchExhaustivenessCheckHint {
+  #[display(fmt = "Add the missing cases, or add a `default` case")]
+  AddCases,
+}
+
+const FIX_DESC: &str = "Add the missing cases";
+
+/// Aliases are resolved up to this depth to guard against recursive types.
+const MAX_RESOLVE_DEPTH: usize = 16;
+
+impl LintRule for SwitchExhaustivenessCheck {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn required_syntax(&self) -> SyntaxFeatures {
+    SyntaxFeatures::SWITCH
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    program: Program,
+  ) {
+    // Types can be used before they are declared, so all of them are
+    // collected before any switch statement is checked.
+    let mut collector = TypeCollector::default();
+    collector.traverse(program, context);
+    SwitchExhaustivenessCheckHandler { types: collector }
+      .traverse(program, context);
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/switch_exhaustiveness_check.md")
+  }
+}
+
+/// A single value a union type can hold.
+#[derive(Clone)]
+struct UnionMember {
+  /// Identifies the value regardless of how it is written.
+  key: String,
+  /// The expression to use in a `case` for this value.
+  text: String,
+}
+
+impl UnionMember {
+  /// A member of a union as the type information writes it.
+  fn from_text(text: String) -> Self {
+    let key = match text.as_str() {
+      "null" | "undefined" => text.clone(),
+      "true" | "false" => format!("boolean:{}", text),
+      _ if text.len() >= 2
+        && (text.starts_with('"') && text.ends_with('"')
+          || text.starts_with('\'') && text.ends_with('\'')) =>
+      {
+        format!("string:{}", &text[1..text.len() - 1])
+      }
+      _ => match text.parse::<f64>() {
+        Ok(num) => format!("number:{}", num),
+        Err(_) => format!("enum:{}", text),
+      },
+    };
+    Self { key, text }
+  }
+}
+
+enum UnionPart {
+  Member(UnionMember),
+  /// A reference to a type alias or an enum.
+  Ref(String),
+}
+
+fn parse_union(ty: &TsType, parts: &mut Vec<UnionPart>) -> Option<()> {
+  match ty {
+    TsType::TsUnionOrIntersectionType(
+      TsUnionOrIntersectionType::TsUnionType(union),
+    ) => {
+      for ty in union.types {
+        parse_union(ty, parts)?;
+      }
+    }
+    TsType::TsParenthesizedType(paren) => parse_union(&paren.type_ann, parts)?,
+    TsType::TsLitType(lit_type) => {
+      let key = match &lit_type.lit {
+        TsLit::Str(str) => format!("string:{}", str.value()),
+        TsLit::Number(num) => format!("number:{}", num.value()),
+        TsLit::Bool(bool) => format!("boolean:{}", bool.value()),
+        _ => return None,
+      };
+      parts.push(UnionPart::Member(UnionMember {
+        key,
+        text: lit_type.text().to_string(),
+      }));
+    }
+    TsType::TsKeywordType(keyword) => match keyword.keyword_kind() {
+      TsKeywordTypeKind::TsNullKeyword => {
+        parts.push(UnionPart::Member(UnionMember {
+          key: "null".to_string(),
+          text: "null".to_string(),
+        }));
+      }
+      TsKeywordTypeKind::TsUndefinedKeyword => {
+        parts.push(UnionPart::Member(UnionMember {
+          key: "undefined".to_string(),
+          text: "undefined".to_string(),
+        }));
+      }
+      TsKeywordTypeKind::TsBooleanKeyword => {
+        for value in ["true", "false"] {
+          parts.push(UnionPart::Member(UnionMember {
+            key: format!("boolean:{}", value),
+            text: value.to_string(),
+          }));
+        }
+      }
+      _ => return None,
+    },
+    TsType::TsTypeRef(type_ref) if type_ref.type_params.is_none() => {
+      let TsEntityName::Ident(ident) = &type_ref.type_name else {
+        return None;
+      };
+      parts.push(UnionPart::Ref(ident.sym().to_string()));
+    }
+    _ => return None,
+  }
+  Some(())
+}
+
+/// A parameter or a `const` binding whose type is a union.
+struct UnionBinding {
+  parts: Vec<UnionPart>,
+  /// The start of the identifier that declares the binding.
+  start: SourcePos,
+}
+
+/// Collects the types of annotated bindings, the type aliases and the enums
+/// of a file, and where each identifier is first used.
+#[derive(Default)]
+struct TypeCollector {
+  bindings: HashMap<Id, UnionBinding>,
+  /// The starts of all the identifiers that refer to each binding, including
+  /// the one that declares it.
+  references: HashMap<Id, Vec<SourcePos>>,
+  aliases: HashMap<String, Vec<UnionPart>>,
+  enums: HashMap<String, Vec<UnionMember>>,
+}
+
+impl TypeCollector {
+  fn resolve(&self, parts: &[UnionPart]) -> Option<Vec<UnionMember>> {
+    let mut members = Vec::new();
+    self.resolve_into(parts, &mut members, 0)?;
+    let mut seen = HashSet::new();
+    members.retain(|member| seen.insert(member.key.clone()));
+    Some(members)
+  }
+
+  fn resolve_into(
+    &self,
+    parts: &[UnionPart],
+    members: &mut Vec<UnionMember>,
+    depth: usize,
+  ) -> Option<()> {
+    if depth > MAX_RESOLVE_DEPTH {
+      return None;
+    }
+    for part in parts {
+      match part {
+        UnionPart::Member(member) => members.push(member.clone()),
+        UnionPart::Ref(name) => {
+          if let Some(alias) = self.aliases.get(name) {
+            self.resolve_into(alias, members, depth + 1)?;
+          } else if let Some(enum_members) = self.enums.get(name) {
+            members.extend(enum_members.iter().cloned());
+          } else {
+            return None;
+          }
+        }
+      }
+    }
+    Some(())
+  }
+}
+
+/// Whether the binding is a parameter or a `const` binding with an initializer
+/// that doesn't narrow its type, like a literal does. Other variables can be
+/// assigned values that narrow their type.
+fn keeps_declared_type(binding: &BindingIdent) -> bool {
+  match binding.parent() {
+    Node::Param(_) | Node::ArrowExpr(_) => true,
+    Node::VarDeclarator(declarator) => {
+      let is_const = declarator
+        .parent()
+        .to::<VarDecl>()
+        .is_some_and(|decl| decl.decl_kind() == VarDeclKind::Const);
+      let narrows = matches!(
+        declarator.init,
+        Some(Expr::Lit(_) | Expr::Tpl(_) | Expr::Unary(_))
+      );
+      is_const && !narrows
+    }
+    _ => false,
+  }
+}
+
+impl Handler for TypeCollector {
+  fn binding_ident(&mut self, binding: &BindingIdent, _ctx: &mut Context) {
+    let Some(type_ann) = binding.type_ann else {
+      return;
+    };
+    if !keeps_declared_type(binding) {
+      return;
+    }
+    let mut parts = Vec::new();
+    if parse_union(&type_ann.type_ann, &mut parts).is_some() {
+      self.bindings.insert(
+        binding.id.inner.to_id(),
+        UnionBinding {
+          parts,
+          start: binding.start(),
+        },
+      );
+    }
+  }
+
+  fn ident(&mut self, ident: &Ident, _ctx: &mut Context) {
+    self
+      .references
+      .entry(ident.inner.to_id())
+      .or_default()
+      .push(ident.start());
+  }
+
+  fn ts_type_alias_decl(
+    &mut self,
+    alias: &TsTypeAliasDecl,
+    _ctx: &mut Context,
+  ) {
+    if alias.type_params.is_some() {
+      return;
+    }
+    let mut parts = Vec::new();
+    if parse_union(&alias.type_ann, &mut parts).is_some() {
+      self.aliases.insert(alias.id.sym().to_string(), parts);
+    }
+  }
+
+  fn ts_enum_decl(&mut self, enum_decl: &TsEnumDecl, _ctx: &mut Context) {
+    let enum_name = enum_decl.id.sym().to_string();
+    let mut members = Vec::new();
+    for member in enum_decl.members {
+      // Members with quoted names are rare enough to not be worth supporting.
+      let TsEnumMemberId::Ident(ident) = &member.id else {
+        return;
+      };
+      let path = format!("{}.{}", enum_name, ident.sym());
+      members.push(UnionMember {
+        key: format!("enum:{}", path),
+        text: path,
+      });
+    }
+    self.enums.insert(enum_name, members);
+  }
+}
+
+/// Computes the key of a `case` test, matching the keys of [`UnionMember`].
+fn case_key(test: &Expr) -> Option<String> {
+  match test {
+    Expr::Lit(Lit::Str(str)) => Some(format!("string:{}", str.value())),
+    Expr::Lit(Lit::Num(num)) => Some(format!("number:{}", num.value())),
+    Expr::Lit(Lit::Bool(bool)) => Some(format!("boolean:{}", bool.value())),
+    Expr::Lit(Lit::Null(_)) => Some("null".to_string()),
+    Expr::Ident(ident) if ident.sym() == "undefined" => {
+      Some("undefined".to_string())
+    }
+    Expr::Member(member) => {
+      let (Expr::Ident(obj), MemberProp::Ident(prop)) =
+        (&member.obj, &member.prop)
+      else {
+        return None;
+      };
+      Some(format!("enum:{}.{}", obj.sym(), prop.sym()))
+    }
+    Expr::Paren(paren) => case_key(&paren.expr),
+    _ => None,
+  }
+}
+
+struct SwitchExhaustivenessCheckHandler {
+  types: TypeCollector,
+}
+
+impl SwitchExhaustivenessCheckHandler {
+  /// The members of the declared type of the discriminant, if it's a binding
+  /// whose type can't have been narrowed before the switch.
+  fn declared_members(&self, discriminant: &Expr) -> Option<Vec<UnionMember>> {
+    let Expr::Ident(discriminant) = discriminant else {
+      return None;
+    };
+    let id = discriminant.inner.to_id();
+    let binding = self.types.bindings.get(&id)?;
+    // A use before the switch, like `if (x === "a") return;`, can narrow the
+    // type, which would make the missing cases dead code.
+    let used_before = self.types.references.get(&id).is_some_and(|starts| {
+      starts
+        .iter()
+        .any(|&start| start != binding.start && start < discriminant.start())
+    });
+    if used_before {
+      return None;
+    }
+    self.types.resolve(&binding.parts)
+  }
+}
+
+impl Handler for SwitchExhaustivenessCheckHandler {
+  fn switch_stmt(&mut self, switch_stmt: &SwitchStmt, ctx: &mut Context) {
+    if switch_stmt.cases.iter().any(|case| case.test.is_none()) {
+      return;
+    }
+    let members = match ctx.union_members(switch_stmt.discriminant.range()) {
+      Some(members) => {
+        members.into_iter().map(UnionMember::from_text).collect()
+      }
+      None => match self.declared_members(&switch_stmt.discriminant) {
+        Some(members) => members,
+        None => return,
+      },
+    };
+
+    let mut covered = HashSet::new();
+    for case in switch_stmt.cases {
+      let Some(key) = case.test.as_ref().and_then(case_key) else {
+        // The case may cover any value, so the switch can't be checked.
+        return;
+      };
+      covered.insert(key);
+    }
+
+    let missing = members
+      .iter()
+      .filter(|member| !covered.contains(&member.key))
+      .collect::<Vec<_>>();
+    if missing.is_empty() {
+      return;
+    }
+
+    let missing_text = missing
+      .iter()
+      .map(|member| member.text.as_str())
+      .collect::<Vec<_>>()
+      .join(" | ");
+
+    // The stubs are inserted after the last case, so a fix is only offered
+    // when there is one to align them with.
+    let fixes = switch_stmt.cases.last().map_or(vec![], |last_case| {
+      let indent = ctx.leading_whitespace(last_case.range()).to_string();
+      let mut stubs = String::new();
+      for member in &missing {
+        stubs.push_str(&format!("\n{}case {}: {{", indent, member.text));
+        stubs.push_str(&format!(
+          "\n{}  throw new Error(\"Not implemented yet\");",
+          indent
+        ));
+        stubs.push_str(&format!("\n{}}}", indent));
+      }
+      vec![LintFix {
+        description: FIX_DESC.into(),
+        changes: vec![LintFixChange {
+          new_text: stubs.into(),
+          range: SourceRange::new(last_case.end(), last_case.end()),
+        }],
+      }]
+    });
+
+    ctx.add_diagnostic_with_fixes(
+      switch_stmt.range(),
+      CODE,
+      SwitchExhaustivenessCheckMessage::NotExhaustive(missing_text),
+      Some(SwitchExhaustivenessCheckHint::AddCases.to_string()),
+      fixes,
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::{ExpectedDiagnostic, LintCase, RuleTester};
+  use crate::type_info::TypeInfoProvider;
+  use deno_ast::ModuleSpecifier;
+  use std::ops::Range;
+  use std::sync::Arc;
+
+  /// Union types of the expressions with the given texts. The types of all
+  /// other expressions are unknown.
+  #[derive(Debug)]
+  struct Unions {
+    source: &'static str,
+    unions: &'static [(&'static str, &'static [&'static str])],
+  }
+
+  impl TypeInfoProvider for Unions {
+    fn is_any(
+      &self,
+      _specifier: &ModuleSpecifier,
+      _range: Range<usize>,
+    ) -> Option<bool> {
+      None
+    }
+
+    fn union_members(
+      &self,
+      _specifier: &ModuleSpecifier,
+      range: Range<usize>,
+    ) -> Option<Vec<String>> {
+      let text = &self.source[range];
+      let (_, members) = self.unions.iter().find(|(t, _)| *t == text)?;
+      Some(members.iter().map(|member| member.to_string()).collect())
+    }
+  }
+
+  fn tester(
+    source: &'static str,
+    unions: &'static [(&'static str, &'static [&'static str])],
+  ) -> RuleTester {
+    RuleTester::with_type_info(
+      Box::new(SwitchExhaustivenessCheck),
+      Arc::new(Unions { source, unions }),
+    )
+  }
+
+  #[test]
+  fn switch_exhaustiveness_check_with_types() {
+    let source = r#"
+import { Day } from "./day.ts";
+function f(day: Day) {
+  switch (day) {
+    case "mon":
+      break;
+  }
+}
+"#;
+    tester(source, &[("day", &[r#""mon""#, r#""tue""#])]).run(
+      LintCase::new(source).error(
+        ExpectedDiagnostic::new(
+          4,
+          2,
+          SwitchExhaustivenessCheckMessage::NotExhaustive(
+            r#""tue""#.to_string(),
+          ),
+        )
+        .hint(SwitchExhaustivenessCheckHint::AddCases)
+        .fix(
+          FIX_DESC,
+          r#"
+import { Day } from "./day.ts";
+function f(day: Day) {
+  switch (day) {
+    case "mon":
+      break;
+    case "tue": {
+      throw new Error("Not implemented yet");
+    }
+  }
+}
+"#,
+        ),
+      ),
+    );
+
+    let source = r#"
+function f(shape: Shape) {
+  switch (shape.kind) {
+    case Kind.Circle:
+    case 1:
+    case true:
+      break;
+  }
+}
+"#;
+    tester(
+      source,
+      &[(
+        "shape.kind",
+        &["Kind.Circle", "Kind.Square", "1", "2", "true", "false"],
+      )],
+    )
+    .run(
+      LintCase::new(source).error(
+        ExpectedDiagnostic::new(
+          3,
+          2,
+          SwitchExhaustivenessCheckMessage::NotExhaustive(
+            "Kind.Square | 2 | false".to_string(),
+          ),
+        )
+        .hint(SwitchExhaustivenessCheckHint::AddCases)
+        .fix(
+          FIX_DESC,
+          r#"
+function f(shape: Shape) {
+  switch (shape.kind) {
+    case Kind.Circle:
+    case 1:
+    case true:
+      break;
+    case Kind.Square: {
+      throw new Error("Not implemented yet");
+    }
+    case 2: {
+      throw new Error("Not implemented yet");
+    }
+    case false: {
+      throw new Error("Not implemented yet");
+    }
+  }
+}
+"#,
+        ),
+      ),
+    );
+
+    // The types know better than the declaration.
+    let source = r#"
+function f(x: "a" | "b") {
+  switch (x) {
+    case "b":
+      break;
+  }
+}
+"#;
+    tester(source, &[("x", &[r#""b""#])]).run(source);
+
+    // Without a known type, the declaration decides.
+    tester(source, &[]).run(
+      LintCase::new(source).error(
+        ExpectedDiagnostic::new(
+          3,
+          2,
+          SwitchExhaustivenessCheckMessage::NotExhaustive(r#""a""#.to_string()),
+        )
+        .hint(SwitchExhaustivenessCheckHint::AddCases)
+        .fix(
+          FIX_DESC,
+          r#"
+function f(x: "a" | "b") {
+  switch (x) {
+    case "b":
+      break;
+    case "a": {
+      throw new Error("Not implemented yet");
+    }
+  }
+}
+"#,
+        ),
+      ),
+    );
+  }
+
+  #[test]
+  fn switch_exhaustiveness_check_valid() {
+    assert_lint_ok! {
+      SwitchExhaustivenessCheck,
+      r#"
+type Day = "mon" | "tue";
+function f(day: Day) {
+  switch (day) {
+    case "mon":
+      break;
+    case "tue":
+      break;
+  }
+}
+"#,
+      r#"
+function f(day: "mon" | "tue") {
+  switch (day) {
+    case "mon":
+      break;
+    default:
+      break;
+  }
+}
+"#,
+      r#"
+enum Direction { Up, Down }
+function f(dir: Direction) {
+  switch (dir) {
+    case Direction.Up:
+    case Direction.Down:
+      break;
+  }
+}
+"#,
+      // not a union of literals
+      r#"
+function f(day: string) {
+  switch (day) {
+    case "mon":
+      break;
+  }
+}
+"#,
+      // unknown types can't be resolved
+      r#"
+import { Day } from "./day.ts";
+function f(day: Day) {
+  switch (day) {
+    case "mon":
+      break;
+  }
+}
+"#,
+      // a case that isn't a literal could match anything
+      r#"
+const MON = "mon";
+function f(day: "mon" | "tue") {
+  switch (day) {
+    case MON:
+      break;
+  }
+}
+"#,
+      r#"
+function f(flag: boolean | null) {
+  switch (flag) {
+    case true:
+    case false:
+    case null:
+      break;
+  }
+}
+"#,
+      r#"
+type Rec = Rec | "a";
+function f(value: Rec) {
+  switch (value) {
+    case "b":
+      break;
+  }
+}
+"#,
+      // the type is narrowed before the switch
+      r#"
+let x: "a" | "b" = f();
+if (x === "a") throw 1;
+switch (x) {
+  case "b":
+}
+"#,
+      r#"
+function f(x: "a" | "b") {
+  if (x === "a") return;
+  switch (x) {
+    case "b":
+      break;
+  }
+}
+"#,
+      r#"
+const x: "a" | "b" = "b";
+switch (x) {
+  case "b":
+}
+"#,
+      // `let` bindings can be narrowed by assignments
+      r#"
+let x: "a" | "b" = f();
+switch (x) {
+  case "b":
+}
+"#,
+    };
+  }
+
+  #[test]
+  fn switch_exhaustiveness_check_invalid() {
+    assert_lint_err! {
+      SwitchExhaustivenessCheck,
+      r#"
+type Day = "mon" | "tue" | "wed";
+function f(day: Day) {
+  switch (day) {
+    case "mon":
+      break;
+  }
+}
+"#: [
+        {
+          line: 4,
+          col: 2,
+          message: SwitchExhaustivenessCheckMessage::NotExhaustive(
+            r#""tue" | "wed""#.to_string()
+          ),
+          hint: SwitchExhaustivenessCheckHint::AddCases,
+          fix: (
+            FIX_DESC,
+            r#"
+type Day = "mon" | "tue" | "wed";
+function f(day: Day) {
+  switch (day) {
+    case "mon":
+      break;
+    case "tue": {
+      throw new Error("Not implemented yet");
+    }
+    case "wed": {
+      throw new Error("Not implemented yet");
+    }
+  }
+}
+"#
+          ),
+        }
+      ],
+      r#"
+type Base = 1 | 2;
+type Extended = Base | 3;
+const value: Extended = get();
+switch (value) {
+  case 1:
+  case 3:
+    break;
+}
+"#: [
+        {
+          line: 5,
+          col: 0,
+          message: SwitchExhaustivenessCheckMessage::NotExhaustive(
+            "2".to_string()
+          ),
+          hint: SwitchExhaustivenessCheckHint::AddCases,
+          fix: (
+            FIX_DESC,
+            r#"
+type Base = 1 | 2;
+type Extended = Base | 3;
+const value: Extended = get();
+switch (value) {
+  case 1:
+  case 3:
+    break;
+  case 2: {
+    throw new Error("Not implemented yet");
+  }
+}
+"#
+          ),
+        }
+      ],
+      r#"
+function f(dir: Direction) {
+  switch (dir) {
+    case Direction.Up:
+      return 1;
+  }
+}
+enum Direction { Up, Down }
+"#: [
+        {
+          line: 3,
+          col: 2,
+          message: SwitchExhaustivenessCheckMessage::NotExhaustive(
+            "Direction.Down".to_string()
+          ),
+          hint: SwitchExhaustivenessCheckHint::AddCases,
+          fix: (
+            FIX_DESC,
+            r#"
+function f(dir: Direction) {
+  switch (dir) {
+    case Direction.Up:
+      return 1;
+    case Direction.Down: {
+      throw new Error("Not implemented yet");
+    }
+  }
+}
+enum Direction { Up, Down }
+"#
+          ),
+        }
+      ],
+      r#"
+function f(flag: boolean) {
+  switch (flag) {}
+}
+"#: [
+        {
+          line: 3,
+          col: 2,
+          message: SwitchExhaustivenessCheckMessage::NotExhaustive(
+            "true | false".to_string()
+          ),
+          hint: SwitchExhaustivenessCheckHint::AddCases,
+        }
+      ],
+    };
+  }
+}
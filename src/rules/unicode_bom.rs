@@ -0,0 +1,186 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::diagnostic::{LintFix, LintFixChange};
+use crate::Program;
+
+use deno_ast::SourceRange;
+use derive_more::Display;
+use serde::Deserialize;
+
+#[derive(Debug, Default)]
+pub struct UnicodeBom {
+  options: UnicodeBomOptions,
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct UnicodeBomOptions {
+  pub style: UnicodeBomStyle,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum UnicodeBomStyle {
+  /// Files must start with a BOM.
+  Always,
+  /// Files must not start with a BOM.
+  #[default]
+  Never,
+}
+
+impl UnicodeBom {
+  pub fn new(options: UnicodeBomOptions) -> Self {
+    Self { options }
+  }
+}
+
+const CODE: &str = "unicode-bom";
+const ADD_FIX_DESC: &str = "Add a BOM";
+
+#[derive(Display)]
+enum UnicodeBomMessage {
+  #[display(fmt = "Unexpected Unicode BOM (Byte Order Mark)")]
+  Unexpected,
+  #[display(fmt = "Expected Unicode BOM (Byte Order Mark)")]
+  Expected,
+}
+
+#[derive(Display)]
+enum UnicodeBomHint {
+  #[display(fmt = "Remove the BOM from the start of the file")]
+  Remove,
+  #[display(fmt = "Add a BOM to the start of the file")]
+  Add,
+}
+
+impl LintRule for UnicodeBom {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn fixable(&self) -> bool {
+    true
+  }
+
+  fn options_schema(&self) -> Option<serde_json::Value> {
+    Some(serde_json::json!({
+      "type": "object",
+      "properties": {
+        "style": { "enum": ["always", "never"], "default": "never" }
+      }
+    }))
+  }
+
+  fn lint_program_with_ast_view(
+    &self,
+    context: &mut Context,
+    _program: Program,
+  ) {
+    let start = context.text_info().range().start.as_source_pos();
+    let range = SourceRange::new(start, start);
+    let (message, hint, fixes) = match (self.options.style, context.has_bom()) {
+      // The BOM is stripped before parsing, so the changes of a fix can't
+      // cover it and there's no fix to remove it.
+      (UnicodeBomStyle::Never, true) => (
+        UnicodeBomMessage::Unexpected,
+        UnicodeBomHint::Remove,
+        vec![],
+      ),
+      (UnicodeBomStyle::Always, false) => (
+        UnicodeBomMessage::Expected,
+        UnicodeBomHint::Add,
+        vec![LintFix {
+          description: ADD_FIX_DESC.into(),
+          changes: vec![LintFixChange {
+            new_text: "\u{FEFF}".into(),
+            range,
+          }],
+        }],
+      ),
+      _ => return,
+    };
+    context.add_diagnostic_with_fixes(
+      range,
+      CODE,
+      message,
+      Some(hint.to_string()),
+      fixes,
+    );
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/unicode_bom.md")
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_util::{LintCase, RuleTester};
+
+  #[test]
+  fn unicode_bom_valid() {
+    assert_lint_ok! {
+      UnicodeBom::default(),
+      "const a = 1;",
+      "",
+      "const a = '\u{FEFF}';",
+    };
+
+    assert_lint_ok! {
+      UnicodeBom::new(UnicodeBomOptions {
+        style: UnicodeBomStyle::Always,
+      }),
+      "\u{FEFF}const a = 1;",
+      "\u{FEFF}",
+    };
+  }
+
+  #[test]
+  fn unicode_bom_invalid() {
+    assert_lint_err! {
+      UnicodeBom::default(),
+      "\u{FEFF}const a = 1;": [
+        {
+          col: 0,
+          message: UnicodeBomMessage::Unexpected,
+          hint: UnicodeBomHint::Remove,
+        }
+      ],
+    };
+
+    assert_lint_err! {
+      UnicodeBom::new(UnicodeBomOptions {
+        style: UnicodeBomStyle::Always,
+      }),
+      "const a = 1;": [
+        {
+          col: 0,
+          message: UnicodeBomMessage::Expected,
+          hint: UnicodeBomHint::Add,
+          fix: (ADD_FIX_DESC, "\u{FEFF}const a = 1;"),
+        }
+      ],
+    };
+  }
+
+  #[test]
+  fn unicode_bom_fixes_leave_the_bom() {
+    let source = "\u{FEFF}const a = 1;";
+    let tester = RuleTester::new(Box::new(UnicodeBom::default()));
+    let (_, diagnostics) = tester.lint(&LintCase::new(source));
+    assert_eq!(diagnostics.len(), 1);
+    let fixed = crate::fixes::apply_all(source, &diagnostics);
+    assert_eq!(fixed.applied, 0);
+    assert_eq!(fixed.text, source);
+  }
+
+  #[test]
+  fn unicode_bom_options() {
+    let options: UnicodeBomOptions =
+      serde_json::from_str(r#"{ "style": "always" }"#).unwrap();
+    assert_eq!(options.style, UnicodeBomStyle::Always);
+  }
+}
@@ -1,9 +1,8 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use super::{Context, LintRule};
-use crate::handler::{Handler, Traverse};
-use crate::Program;
-use deno_ast::view::{BinExpr, BinaryOp, Expr, Ident, SwitchStmt};
+use crate::handler::Handler;
+use deno_ast::view::{BinExpr, BinaryOp, Expr, Ident, NodeKind, SwitchStmt};
 use deno_ast::SourceRanged;
 use derive_more::Display;
 
@@ -37,12 +36,12 @@ impl LintRule for UseIsNaN {
     CODE
   }
 
-  fn lint_program_with_ast_view(
-    &self,
-    context: &mut Context,
-    program: Program,
-  ) {
-    UseIsNaNHandler.traverse(program, context);
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(UseIsNaNHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[NodeKind::BinExpr, NodeKind::SwitchStmt])
   }
 
   #[cfg(feature = "docs")]
@@ -0,0 +1,335 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use super::{Context, LintRule};
+use crate::handler::Handler;
+use deno_ast::swc::ast::{
+  Function as SwcFunction, ParamOrTsParamProp, Pat as SwcPat, TsParamPropParam,
+};
+use deno_ast::view::{
+  ClassMethod, Constructor, DefaultDecl, ExportDefaultDecl, Expr, FnDecl,
+  MethodProp, Node, NodeKind, NodeTrait, Pat, VarDecl,
+};
+use deno_ast::{SourcePos, SourceRange, SourceRanged, SourceRangedForSpanned};
+use derive_more::Display;
+
+#[derive(Debug)]
+pub struct ValidJsdoc;
+
+const CODE: &str = "valid-jsdoc";
+
+#[derive(Display)]
+enum ValidJsdocMessage {
+  #[display(fmt = "Expected JSDoc for `{}` but found `{}`", _0, _1)]
+  Mismatch(String, String),
+  #[display(fmt = "JSDoc documents `{}`, which is not a parameter", _0)]
+  Unknown(String),
+  #[display(fmt = "Missing JSDoc for parameter `{}`", _0)]
+  Missing(String),
+}
+
+#[derive(Display)]
+enum ValidJsdocHint {
+  #[display(
+    fmt = "Rename the `@param` tag, or reorder the tags to match the parameters"
+  )]
+  Mismatch,
+  #[display(fmt = "Remove the `@param` tag, or rename it to a parameter")]
+  Unknown,
+  #[display(fmt = "Add a `@param` tag for the parameter")]
+  Missing,
+}
+
+impl LintRule for ValidJsdoc {
+  fn code(&self) -> &'static str {
+    CODE
+  }
+
+  fn aliases(&self) -> &'static [&'static str] {
+    &["jsdoc/check-param-names"]
+  }
+
+  fn handler(&self) -> Option<Box<dyn Handler + '_>> {
+    Some(Box::new(ValidJsdocHandler))
+  }
+
+  fn node_kinds(&self) -> Option<&'static [NodeKind]> {
+    Some(&[
+      NodeKind::FnDecl,
+      NodeKind::ExportDefaultDecl,
+      NodeKind::VarDecl,
+      NodeKind::ClassMethod,
+      NodeKind::Constructor,
+      NodeKind::MethodProp,
+    ])
+  }
+
+  #[cfg(feature = "docs")]
+  fn docs(&self) -> &'static str {
+    include_str!("../../docs/rules/valid_jsdoc.md")
+  }
+}
+
+/// A parameter, with its name unless it's destructured, since the name of
+/// the `@param` tag of a destructured parameter can be anything.
+struct Param<'a> {
+  name: Option<&'a str>,
+  range: SourceRange,
+}
+
+fn param_name(pat: &SwcPat) -> Option<&str> {
+  match pat {
+    SwcPat::Ident(ident) => Some(&ident.id.sym),
+    SwcPat::Assign(assign) => param_name(&assign.left),
+    SwcPat::Rest(rest) => param_name(&rest.arg),
+    _ => None,
+  }
+}
+
+fn param(pat: &SwcPat) -> Param {
+  Param {
+    name: param_name(pat),
+    range: pat.range(),
+  }
+}
+
+fn function_params(function: &SwcFunction) -> Vec<Param> {
+  function
+    .params
+    .iter()
+    .map(|param| self::param(&param.pat))
+    .collect()
+}
+
+/// Where the JSDoc comment of the declaration is, which is before `export`
+/// if the declaration is exported.
+fn declaration_start(node: Node) -> SourcePos {
+  match node.parent() {
+    Some(parent @ Node::ExportDecl(_)) => parent.start(),
+    _ => node.start(),
+  }
+}
+
+/// Checks that the `@param` tags of the JSDoc comment at `doc_start` match
+/// the parameters in order. Functions without `@param` tags aren't checked,
+/// since their comment may only describe what they do.
+fn check_params(
+  doc_start: SourcePos,
+  name_range: SourceRange,
+  params: Vec<Param>,
+  ctx: &mut Context,
+) {
+  let Some(doc) = ctx.jsdoc_at(doc_start) else {
+    return;
+  };
+  let tags: Vec<&str> = doc
+    .params()
+    .filter_map(|tag| tag.name.as_deref())
+    .map(|name| name.strip_prefix("...").unwrap_or(name))
+    // Tags like `@param options.name` document a property of a parameter.
+    .filter(|name| !name.contains('.') && !name.contains('['))
+    .collect();
+  if tags.is_empty() {
+    return;
+  }
+  // The `this` parameter of TypeScript is not a real parameter.
+  let params: Vec<Param> = params
+    .into_iter()
+    .filter(|param| param.name != Some("this"))
+    .collect();
+
+  for (i, tag) in tags.iter().enumerate() {
+    match params.get(i) {
+      Some(Param {
+        name: Some(name),
+        range,
+      }) if name != tag => ctx.add_diagnostic_with_hint(
+        *range,
+        CODE,
+        ValidJsdocMessage::Mismatch(name.to_string(), tag.to_string()),
+        ValidJsdocHint::Mismatch,
+      ),
+      Some(_) => {}
+      None => ctx.add_diagnostic_with_hint(
+        name_range,
+        CODE,
+        ValidJsdocMessage::Unknown(tag.to_string()),
+        ValidJsdocHint::Unknown,
+      ),
+    }
+  }
+  for param in params.iter().skip(tags.len()) {
+    if let Some(name) = param.name {
+      ctx.add_diagnostic_with_hint(
+        param.range,
+        CODE,
+        ValidJsdocMessage::Missing(name.to_string()),
+        ValidJsdocHint::Missing,
+      );
+    }
+  }
+}
+
+struct ValidJsdocHandler;
+
+impl Handler for ValidJsdocHandler {
+  fn fn_decl(&mut self, fn_decl: &FnDecl, ctx: &mut Context) {
+    check_params(
+      declaration_start(fn_decl.as_node()),
+      fn_decl.ident.range(),
+      function_params(fn_decl.function.inner),
+      ctx,
+    );
+  }
+
+  fn export_default_decl(
+    &mut self,
+    export_default_decl: &ExportDefaultDecl,
+    ctx: &mut Context,
+  ) {
+    if let DefaultDecl::Fn(fn_expr) = &export_default_decl.decl {
+      check_params(
+        export_default_decl.start(),
+        fn_expr
+          .ident
+          .map_or(export_default_decl.range(), |ident| ident.range()),
+        function_params(fn_expr.function.inner),
+        ctx,
+      );
+    }
+  }
+
+  fn var_decl(&mut self, var_decl: &VarDecl, ctx: &mut Context) {
+    let [declarator] = var_decl.decls else {
+      return;
+    };
+    let Pat::Ident(ident) = &declarator.name else {
+      return;
+    };
+    let params = match declarator.init {
+      Some(Expr::Arrow(arrow)) => {
+        arrow.inner.params.iter().map(param).collect()
+      }
+      Some(Expr::Fn(fn_expr)) => function_params(fn_expr.function.inner),
+      _ => return,
+    };
+    check_params(
+      declaration_start(var_decl.as_node()),
+      ident.range(),
+      params,
+      ctx,
+    );
+  }
+
+  fn class_method(&mut self, class_method: &ClassMethod, ctx: &mut Context) {
+    check_params(
+      class_method.start(),
+      class_method.key.range(),
+      function_params(class_method.function.inner),
+      ctx,
+    );
+  }
+
+  fn constructor(&mut self, constructor: &Constructor, ctx: &mut Context) {
+    let params = constructor
+      .inner
+      .params
+      .iter()
+      .map(|param| match param {
+        ParamOrTsParamProp::Param(param) => self::param(&param.pat),
+        ParamOrTsParamProp::TsParamProp(prop) => Param {
+          name: match &prop.param {
+            TsParamPropParam::Ident(ident) => Some(&ident.id.sym),
+            TsParamPropParam::Assign(assign) => param_name(&assign.left),
+          },
+          range: prop.range(),
+        },
+      })
+      .collect();
+    check_params(constructor.start(), constructor.key.range(), params, ctx);
+  }
+
+  fn method_prop(&mut self, method_prop: &MethodProp, ctx: &mut Context) {
+    check_params(
+      method_prop.start(),
+      method_prop.key.range(),
+      function_params(method_prop.function.inner),
+      ctx,
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn valid_jsdoc_valid() {
+    assert_lint_ok! {
+      ValidJsdoc,
+      "/** @param a The first. */ function first(a) {}",
+      "/**\n * @param a The first\n * @param b The second\n */\nexport function add(a, b) {}",
+      "/** Adds numbers. */ function add(a, b) {}",
+      "function add(a, b) {}",
+      "/** @param options The options\n * @param options.name The name */ function f({ name }) {}",
+      "/** @param {...number} numbers */ function sum(...numbers) {}",
+      "/** @param ...numbers */ function sum(...numbers) {}",
+      "/** @param [x=1] */ function f(x = 1) {}",
+      "/** @param a */ function f(this: Foo, a: string) {}",
+      "/** @param a */ const f = (a) => a;",
+      "/** @arg a */ export const f = function (a) {};",
+      "class A { /** @param x */ constructor(private x: number) {} }",
+      "class A { /** @param x */ method(x) {} }",
+      "const o = { /** @param x */ method(x) {} };",
+    };
+  }
+
+  #[test]
+  fn valid_jsdoc_invalid() {
+    assert_lint_err! {
+      ValidJsdoc,
+      "/** @param b\n * @param a */ function f(a, b) {}": [
+        {
+          line: 2,
+          col: 26,
+          message: ValidJsdocMessage::Mismatch("a".to_string(), "b".to_string()),
+          hint: ValidJsdocHint::Mismatch,
+        },
+        {
+          line: 2,
+          col: 29,
+          message: ValidJsdocMessage::Mismatch("b".to_string(), "a".to_string()),
+          hint: ValidJsdocHint::Mismatch,
+        }
+      ],
+      "/** @param a\n * @param b */ export function f(a) {}": [
+        {
+          line: 2,
+          col: 31,
+          message: ValidJsdocMessage::Unknown("b".to_string()),
+          hint: ValidJsdocHint::Unknown,
+        }
+      ],
+      "/** @param a */ function f(a, b = 1) {}": [
+        {
+          col: 30,
+          message: ValidJsdocMessage::Missing("b".to_string()),
+          hint: ValidJsdocHint::Missing,
+        }
+      ],
+      "/** @param value */ const f = (x) => x;": [
+        {
+          col: 31,
+          message: ValidJsdocMessage::Mismatch("x".to_string(), "value".to_string()),
+          hint: ValidJsdocHint::Mismatch,
+        }
+      ],
+      "class A { /** @param y */ constructor(private x: number) {} }": [
+        {
+          col: 38,
+          message: ValidJsdocMessage::Mismatch("x".to_string(), "y".to_string()),
+          hint: ValidJsdocHint::Mismatch,
+        }
+      ],
+    };
+  }
+}
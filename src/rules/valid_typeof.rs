@@ -2,16 +2,16 @@
 
 use super::program_ref;
 use super::{Context, LintRule};
-use crate::swc_util::StringRepr;
+use crate::const_eval::{ConstEvaluator, ConstValue};
 use crate::Program;
 use crate::ProgramRef;
 use deno_ast::swc::ast::BinExpr;
 use deno_ast::swc::ast::BinaryOp::{EqEq, EqEqEq, NotEq, NotEqEq};
-use deno_ast::swc::ast::Expr::{Lit, Tpl, Unary};
-use deno_ast::swc::ast::Lit::Str;
+use deno_ast::swc::ast::Expr::{Tpl, Unary};
 use deno_ast::swc::ast::UnaryOp::TypeOf;
 use deno_ast::swc::visit::{noop_visit_type, Visit};
 use deno_ast::SourceRangedForSpanned;
+use std::rc::Rc;
 
 #[derive(Debug)]
 pub struct ValidTypeof;
@@ -28,10 +28,10 @@ impl LintRule for ValidTypeof {
     CODE
   }
 
-  fn lint_program_with_ast_view(
+  fn lint_program_with_ast_view<'view>(
     &self,
-    context: &mut Context,
-    program: Program,
+    context: &mut Context<'view>,
+    program: Program<'view>,
   ) {
     let program = program_ref(program);
     let mut visitor = ValidTypeofVisitor::new(context);
@@ -49,11 +49,13 @@ impl LintRule for ValidTypeof {
 
 struct ValidTypeofVisitor<'c, 'view> {
   context: &'c mut Context<'view>,
+  evaluator: Rc<ConstEvaluator<'view>>,
 }
 
 impl<'c, 'view> ValidTypeofVisitor<'c, 'view> {
   fn new(context: &'c mut Context<'view>) -> Self {
-    Self { context }
+    let evaluator = context.const_evaluator();
+    Self { context, evaluator }
   }
 }
 
@@ -69,24 +71,19 @@ impl<'c, 'view> Visit for ValidTypeofVisitor<'c, 'view> {
       (Unary(unary), operand) | (operand, Unary(unary))
         if unary.op == TypeOf =>
       {
-        match operand {
-          Unary(unary) if unary.op == TypeOf => {}
-          Lit(Str(str)) => {
-            if !is_valid_typeof_string(&str.value) {
-              self.context.add_diagnostic(str.range(), CODE, MESSAGE);
-            }
-          }
-          Tpl(tpl) => {
-            if tpl
-              .string_repr()
-              .map_or(false, |s| !is_valid_typeof_string(&s))
-            {
-              self.context.add_diagnostic(tpl.range(), CODE, MESSAGE);
-            }
-          }
-          _ => {
-            self.context.add_diagnostic(operand.range(), CODE, MESSAGE);
+        let is_valid = match (operand, self.evaluator.eval(operand)) {
+          (Unary(unary), _) if unary.op == TypeOf => true,
+          (_, Some(ConstValue::String(value))) => {
+            is_valid_typeof_string(&value)
           }
+          (_, Some(_)) => false,
+          // Template literals with unknown parts, like `${prefix}ing`, may
+          // still be valid.
+          (Tpl(_), None) => true,
+          (_, None) => false,
+        };
+        if !is_valid {
+          self.context.add_diagnostic(operand.range(), CODE, MESSAGE);
         }
       }
       _ => {}
@@ -155,6 +152,9 @@ mod tests {
       r#"typeof foo !== `bigint`"#,
 
       r#"typeof bar != typeof qux"#,
+      r#"typeof foo === `${prefix}ing`"#,
+      r#"typeof foo === "str" + "ing""#,
+      r#"const STRING = "string"; typeof foo === STRING"#,
     };
   }
 
@@ -190,6 +190,18 @@ mod tests {
         col: 15,
         message: MESSAGE
       }],
+      r#"const STRING = "strnig"; typeof foo === STRING"#: [{
+        col: 40,
+        message: MESSAGE
+      }],
+      r#"typeof foo === `${"str"}nig`"#: [{
+        col: 15,
+        message: MESSAGE
+      }],
+      r#"typeof foo === null"#: [{
+        col: 15,
+        message: MESSAGE
+      }],
     }
   }
 }
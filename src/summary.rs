@@ -0,0 +1,185 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+//! Counts diagnostics and decides whether a lint run fails, so tools that
+//! embed the linter share the same semantics for exit codes.
+
+use crate::diagnostic::LintDiagnostic;
+use std::collections::{BTreeMap, HashSet};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LintSeverity {
+  Error,
+  Warning,
+}
+
+/// When a lint run fails. By default, it only fails on diagnostics with the
+/// error severity.
+#[derive(Debug, Clone, Default)]
+pub struct LintPolicy {
+  /// The codes of the rules whose diagnostics are warnings instead of
+  /// errors.
+  pub warning_rules: HashSet<String>,
+  /// Fails if there are more warnings than this, like `--max-warnings`.
+  pub max_warnings: Option<usize>,
+  /// Fails if there are any warnings.
+  pub deny_warnings: bool,
+}
+
+impl LintPolicy {
+  pub fn severity(&self, code: &str) -> LintSeverity {
+    if self.warning_rules.contains(code) {
+      LintSeverity::Warning
+    } else {
+      LintSeverity::Error
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LintCounts {
+  pub errors: usize,
+  pub warnings: usize,
+  /// The diagnostics that come with a fix.
+  pub fixable: usize,
+}
+
+impl LintCounts {
+  pub fn problems(&self) -> usize {
+    self.errors + self.warnings
+  }
+}
+
+/// The counts of the diagnostics of a lint run, in total and per rule.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LintSummary {
+  pub total: LintCounts,
+  pub rules: BTreeMap<String, LintCounts>,
+}
+
+impl LintSummary {
+  pub fn new<'a>(
+    diagnostics: impl IntoIterator<Item = &'a LintDiagnostic>,
+    policy: &LintPolicy,
+  ) -> Self {
+    let mut summary = Self::default();
+    for diagnostic in diagnostics {
+      summary.add(diagnostic, policy);
+    }
+    summary
+  }
+
+  pub fn add(&mut self, diagnostic: &LintDiagnostic, policy: &LintPolicy) {
+    let code = &diagnostic.details.code;
+    let severity = policy.severity(code);
+    let fixable = !diagnostic.details.fixes.is_empty();
+    let rule = self.rules.entry(code.clone()).or_default();
+    for counts in [&mut self.total, rule] {
+      match severity {
+        LintSeverity::Error => counts.errors += 1,
+        LintSeverity::Warning => counts.warnings += 1,
+      }
+      if fixable {
+        counts.fixable += 1;
+      }
+    }
+  }
+
+  /// Whether the run fails under the policy.
+  pub fn fails(&self, policy: &LintPolicy) -> bool {
+    self.total.errors > 0
+      || (policy.deny_warnings && self.total.warnings > 0)
+      || policy
+        .max_warnings
+        .map_or(false, |max| self.total.warnings > max)
+  }
+
+  /// The exit code of a command line tool for the run, which is 1 if it
+  /// fails and 0 otherwise.
+  pub fn exit_code(&self, policy: &LintPolicy) -> i32 {
+    if self.fails(policy) {
+      1
+    } else {
+      0
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::rules::no_var::NoVar;
+  use crate::rules::no_window::NoWindow;
+  use crate::rules::LintRule;
+  use crate::test_util;
+
+  fn diagnostics() -> Vec<LintDiagnostic> {
+    let source = "var a = window.foo;\nvar b = window.bar;";
+    let rules: [Box<dyn LintRule>; 2] = [Box::new(NoVar), Box::new(NoWindow)];
+    rules
+      .into_iter()
+      .flat_map(|rule| test_util::lint(rule, source, "file:///a.ts").1)
+      .collect()
+  }
+
+  #[test]
+  fn summary_counts() {
+    let policy = LintPolicy {
+      warning_rules: HashSet::from(["no-var".to_string()]),
+      ..Default::default()
+    };
+    let summary = LintSummary::new(&diagnostics(), &policy);
+    assert_eq!(
+      summary.total,
+      LintCounts {
+        errors: 2,
+        warnings: 2,
+        fixable: 2,
+      }
+    );
+    assert_eq!(
+      summary.rules["no-var"],
+      LintCounts {
+        errors: 0,
+        warnings: 2,
+        fixable: 0,
+      }
+    );
+    assert_eq!(summary.rules["no-window"].fixable, 2);
+    assert_eq!(summary.total.problems(), 4);
+  }
+
+  #[test]
+  fn summary_policy() {
+    let warnings = LintPolicy {
+      warning_rules: HashSet::from([
+        "no-var".to_string(),
+        "no-window".to_string(),
+      ]),
+      ..Default::default()
+    };
+    let summary = LintSummary::new(&diagnostics(), &warnings);
+    assert!(!summary.fails(&warnings));
+    assert_eq!(summary.exit_code(&warnings), 0);
+
+    let max_warnings = LintPolicy {
+      max_warnings: Some(3),
+      ..warnings.clone()
+    };
+    assert!(summary.fails(&max_warnings));
+    let max_warnings = LintPolicy {
+      max_warnings: Some(4),
+      ..warnings.clone()
+    };
+    assert!(!summary.fails(&max_warnings));
+
+    let deny_warnings = LintPolicy {
+      deny_warnings: true,
+      ..warnings
+    };
+    assert_eq!(summary.exit_code(&deny_warnings), 1);
+
+    let errors = LintPolicy::default();
+    assert!(LintSummary::new(&diagnostics(), &errors).fails(&errors));
+    assert!(!LintSummary::default().fails(&errors));
+  }
+}
@@ -1,45 +1,18 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
+use crate::js_regex::{LiteralPattern, RegexSource};
 use deno_ast::swc::ast::{
   ArrowExpr, BigInt, BindingIdent, BlockStmt, Bool, CallExpr, Class,
-  ComputedPropName, Constructor, Expr, Function, Ident, IdentName, JSXText,
-  Lit, MemberExpr, MemberProp, Null, Number, PrivateName, Prop, PropName,
-  PropOrSpread, Regex, Str, Tpl,
+  ClassMember, ComputedPropName, Constructor, Expr, Function, Ident, IdentName,
+  JSXText, Lit, MemberExpr, MemberProp, NewExpr, Null, Number, OptCall,
+  OptChainBase, PrivateName, PrivateProp, Prop, PropName, PropOrSpread, Regex,
+  SimpleAssignTarget, Str, TaggedTpl, Tpl, UnaryOp, VarDecl,
 };
+use deno_ast::swc::common::util::take::Take;
 use deno_ast::swc::common::DUMMY_SP;
 use deno_ast::swc::utils::{find_pat_ids, ident::IdentLike};
 use deno_ast::swc::visit::{VisitMut, VisitMutWith};
-use deno_ast::view::{self as ast_view};
-use deno_ast::Scope;
-
-/// Extracts regex string from an expression, using ScopeManager.
-/// If the passed expression is not regular expression, this will return `None`.
-pub(crate) fn extract_regex(
-  scope: &Scope,
-  expr_ident: &ast_view::Ident,
-  expr_args: &[&ast_view::ExprOrSpread],
-) -> Option<String> {
-  if expr_ident.inner.sym != *"RegExp" {
-    return None;
-  }
-
-  if scope.var(&expr_ident.inner.to_id()).is_some() {
-    return None;
-  }
-
-  match expr_args.first() {
-    Some(first_arg) => match first_arg.expr {
-      ast_view::Expr::Lit(ast_view::Lit::Str(literal)) => {
-        Some(literal.inner.value.to_string())
-      }
-      ast_view::Expr::Lit(ast_view::Lit::Regex(regex)) => {
-        Some(regex.inner.exp.to_string())
-      }
-      _ => None,
-    },
-    None => None,
-  }
-}
+use deno_ast::view::{self as ast_view, NodeTrait};
 
 pub(crate) trait StringRepr {
   fn string_repr(&self) -> Option<String>;
@@ -316,6 +289,403 @@ where
   }
 }
 
+/// The closest function or arrow function that contains the node, which is
+/// either an `ast_view::Node::Function` or an `ast_view::Node::ArrowExpr`.
+pub(crate) fn enclosing_function(
+  node: ast_view::Node,
+) -> Option<ast_view::Node> {
+  node.ancestors().find(|ancestor| {
+    matches!(
+      ancestor,
+      ast_view::Node::Function(_) | ast_view::Node::ArrowExpr(_)
+    )
+  })
+}
+
+/// Reads a regex literal whose flags are all in `allowed`, and that only
+/// matches literal text.
+pub(crate) fn literal_regex(
+  expr: &ast_view::Expr,
+  allowed: &str,
+) -> Option<LiteralPattern> {
+  let ast_view::Expr::Lit(ast_view::Lit::Regex(regex)) = expr else {
+    return None;
+  };
+  RegexSource::from_regex(regex).literal_pattern(allowed)
+}
+
+/// Whether the expression can be the object of a member expression without
+/// adding parentheses.
+pub(crate) fn is_member_object(expr: &ast_view::Expr) -> bool {
+  matches!(
+    expr,
+    ast_view::Expr::Ident(_)
+      | ast_view::Expr::Member(_)
+      | ast_view::Expr::Call(_)
+      | ast_view::Expr::Paren(_)
+      | ast_view::Expr::This(_)
+      | ast_view::Expr::Lit(ast_view::Lit::Str(_))
+      | ast_view::Expr::Tpl(_)
+      | ast_view::Expr::Array(_)
+  )
+}
+
+/// Whether the expression is a string literal or a template literal, the
+/// only expressions known to be strings without type information.
+pub(crate) fn is_string_literal(expr: &ast_view::Expr) -> bool {
+  matches!(
+    expr,
+    ast_view::Expr::Lit(ast_view::Lit::Str(_)) | ast_view::Expr::Tpl(_)
+  )
+}
+
+/// Whether the assignment target and the expression refer to the same
+/// variable or property, like `a.b` in `a.b = a.b + 1`. Only references that
+/// can be read without calling functions are compared, so one of them can be
+/// dropped, like in `a.b += 1`.
+pub(crate) fn is_same_reference(
+  target: &SimpleAssignTarget,
+  expr: &Expr,
+) -> bool {
+  match (target, expr) {
+    (_, Expr::Paren(paren)) => is_same_reference(target, &paren.expr),
+    (SimpleAssignTarget::Paren(paren), _) => {
+      is_same_expr_reference(&paren.expr, expr)
+    }
+    (SimpleAssignTarget::Ident(a), Expr::Ident(b)) => a.to_id() == b.to_id(),
+    (SimpleAssignTarget::Member(a), Expr::Member(b)) => {
+      is_same_member_reference(a, b)
+    }
+    _ => false,
+  }
+}
+
+/// Whether the assignment target can be read without calling functions, like
+/// `a` or `this.a.b`, so it can be read again.
+pub(crate) fn is_simple_reference(target: &SimpleAssignTarget) -> bool {
+  match target {
+    SimpleAssignTarget::Ident(_) => true,
+    SimpleAssignTarget::Member(member) => {
+      is_same_member_reference(member, member)
+    }
+    SimpleAssignTarget::Paren(paren) => {
+      is_same_expr_reference(&paren.expr, &paren.expr)
+    }
+    _ => false,
+  }
+}
+
+/// Whether the expressions refer to the same variable or property, like
+/// `a.b` and `(a).b`, where both can be read without calling functions.
+pub(crate) fn is_same_expr_reference(a: &Expr, b: &Expr) -> bool {
+  match (a, b) {
+    (Expr::Paren(a), _) => is_same_expr_reference(&a.expr, b),
+    (_, Expr::Paren(b)) => is_same_expr_reference(a, &b.expr),
+    (Expr::Ident(a), Expr::Ident(b)) => a.to_id() == b.to_id(),
+    (Expr::This(_), Expr::This(_)) => true,
+    (Expr::Member(a), Expr::Member(b)) => is_same_member_reference(a, b),
+    _ => false,
+  }
+}
+
+fn is_same_member_reference(a: &MemberExpr, b: &MemberExpr) -> bool {
+  if !is_same_expr_reference(&a.obj, &b.obj) {
+    return false;
+  }
+  match (&a.prop, &b.prop) {
+    (MemberProp::Ident(a), MemberProp::Ident(b)) => a.sym == b.sym,
+    (MemberProp::PrivateName(a), MemberProp::PrivateName(b)) => {
+      a.name == b.name
+    }
+    (MemberProp::Computed(a), MemberProp::Computed(b)) => {
+      match (&*a.expr, &*b.expr) {
+        (Expr::Lit(Lit::Str(a)), Expr::Lit(Lit::Str(b))) => a.value == b.value,
+        (Expr::Lit(Lit::Num(a)), Expr::Lit(Lit::Num(b))) => a.value == b.value,
+        (a, b) => is_same_expr_reference(a, b),
+      }
+    }
+    _ => false,
+  }
+}
+
+/// Whether evaluating the expression can't have an observable effect, other
+/// than throwing. Calls, assignments and anything else that can run code are
+/// side effects, except that property reads are only side-effect free when
+/// `assume_no_getters` is set, since a getter could run any code.
+pub(crate) fn is_side_effect_free(
+  expr: &Expr,
+  assume_no_getters: bool,
+) -> bool {
+  let is_free = |expr: &Expr| is_side_effect_free(expr, assume_no_getters);
+  match expr {
+    Expr::Ident(_)
+    | Expr::Lit(_)
+    | Expr::This(_)
+    | Expr::Arrow(_)
+    | Expr::Fn(_)
+    | Expr::MetaProp(_) => true,
+    Expr::Class(class) => is_class_side_effect_free(&class.class, is_free),
+    Expr::Member(member) => {
+      assume_no_getters && is_member_side_effect_free(member, is_free)
+    }
+    Expr::OptChain(opt_chain) => match &*opt_chain.base {
+      OptChainBase::Member(member) => {
+        assume_no_getters && is_member_side_effect_free(member, is_free)
+      }
+      OptChainBase::Call(_) => false,
+    },
+    Expr::Paren(paren) => is_free(&paren.expr),
+    Expr::Unary(unary) => unary.op != UnaryOp::Delete && is_free(&unary.arg),
+    Expr::Bin(bin) => is_free(&bin.left) && is_free(&bin.right),
+    Expr::Cond(cond) => {
+      is_free(&cond.test) && is_free(&cond.cons) && is_free(&cond.alt)
+    }
+    Expr::Seq(seq) => seq.exprs.iter().all(|expr| is_free(expr)),
+    Expr::Tpl(tpl) => tpl.exprs.iter().all(|expr| is_free(expr)),
+    // Spreading runs the iterator of the spread value.
+    Expr::Array(array) => array.elems.iter().all(|elem| {
+      elem
+        .as_ref()
+        .map_or(true, |elem| elem.spread.is_none() && is_free(&elem.expr))
+    }),
+    Expr::Object(object) => object.props.iter().all(|prop| match prop {
+      PropOrSpread::Prop(prop) => match &**prop {
+        Prop::Shorthand(_) => true,
+        Prop::KeyValue(key_value) => {
+          is_prop_name_side_effect_free(&key_value.key, is_free)
+            && is_free(&key_value.value)
+        }
+        Prop::Method(method) => {
+          is_prop_name_side_effect_free(&method.key, is_free)
+        }
+        Prop::Getter(getter) => {
+          is_prop_name_side_effect_free(&getter.key, is_free)
+        }
+        Prop::Setter(setter) => {
+          is_prop_name_side_effect_free(&setter.key, is_free)
+        }
+        Prop::Assign(_) => false,
+      },
+      PropOrSpread::Spread(_) => false,
+    }),
+    Expr::TsAs(as_expr) => is_free(&as_expr.expr),
+    Expr::TsNonNull(non_null) => is_free(&non_null.expr),
+    Expr::TsSatisfies(satisfies) => is_free(&satisfies.expr),
+    Expr::TsConstAssertion(assertion) => is_free(&assertion.expr),
+    Expr::TsTypeAssertion(assertion) => is_free(&assertion.expr),
+    Expr::TsInstantiation(instantiation) => is_free(&instantiation.expr),
+    _ => false,
+  }
+}
+
+fn is_member_side_effect_free(
+  member: &MemberExpr,
+  is_free: impl Fn(&Expr) -> bool,
+) -> bool {
+  let is_prop_free = match &member.prop {
+    MemberProp::Computed(computed) => is_free(&computed.expr),
+    MemberProp::Ident(_) | MemberProp::PrivateName(_) => true,
+  };
+  is_prop_free && is_free(&member.obj)
+}
+
+fn is_prop_name_side_effect_free(
+  name: &PropName,
+  is_free: impl Fn(&Expr) -> bool,
+) -> bool {
+  match name {
+    PropName::Computed(computed) => is_free(&computed.expr),
+    _ => true,
+  }
+}
+
+/// Defining a class runs its decorators, computed keys, static initializers
+/// and static blocks.
+fn is_class_side_effect_free(
+  class: &Class,
+  is_free: impl Fn(&Expr) -> bool + Copy,
+) -> bool {
+  if !class.decorators.is_empty()
+    || !class
+      .super_class
+      .as_ref()
+      .map_or(true, |expr| is_free(expr))
+  {
+    return false;
+  }
+  class.body.iter().all(|member| match member {
+    ClassMember::Constructor(_) | ClassMember::Empty(_) => true,
+    ClassMember::Method(method) => {
+      method.function.decorators.is_empty()
+        && is_prop_name_side_effect_free(&method.key, is_free)
+    }
+    ClassMember::PrivateMethod(method) => method.function.decorators.is_empty(),
+    ClassMember::ClassProp(prop) => {
+      prop.decorators.is_empty()
+        && is_prop_name_side_effect_free(&prop.key, is_free)
+        && (!prop.is_static || prop.value.as_ref().map_or(true, |v| is_free(v)))
+    }
+    ClassMember::PrivateProp(prop) => {
+      prop.decorators.is_empty()
+        && (!prop.is_static || prop.value.as_ref().map_or(true, |v| is_free(v)))
+    }
+    ClassMember::AutoAccessor(accessor) => {
+      accessor.decorators.is_empty()
+        && (!accessor.is_static
+          || accessor.value.as_ref().map_or(true, |v| is_free(v)))
+    }
+    ClassMember::TsIndexSignature(_) => true,
+    ClassMember::StaticBlock(_) => false,
+  })
+}
+
+/// Whether the expressions have the same structure, regardless of how they're
+/// written. Parentheses, spans and the spelling of numbers are ignored, so
+/// `(a)["b"]` equals `a.b` and `0x10` equals `16`. String and template
+/// literals are compared as written, since code like `"Ā" != "\u0100"`
+/// deliberately compares two spellings. Identifiers are compared by name, so
+/// both expressions should be in the same scope.
+pub(crate) fn ast_eq(a: &Expr, b: &Expr) -> bool {
+  let normalize = |expr: &Expr| {
+    let mut expr = expr.clone();
+    expr.visit_mut_with(&mut Normalize { keep_strings: true });
+    expr
+  };
+  normalize(a) == normalize(b)
+}
+
+/// The expression with the details that `ast_eq` ignores removed, which can be
+/// hashed to find structurally equal expressions. Unlike `ast_eq`, strings
+/// are compared by value, so `"Ā"` equals `"\u0100"` and `` `a` `` equals
+/// `"a"`.
+pub(crate) fn normalize_expr(expr: &Expr) -> Expr {
+  let mut expr = expr.clone();
+  expr.visit_mut_with(&mut Normalize {
+    keep_strings: false,
+  });
+  expr
+}
+
+struct Normalize {
+  /// Whether string and template literals keep how they're written.
+  keep_strings: bool,
+}
+
+impl VisitMut for Normalize {
+  #[allow(clippy::disallowed_types)]
+  fn visit_mut_span(&mut self, span: &mut deno_ast::swc::common::Span) {
+    *span = DUMMY_SP;
+  }
+
+  fn visit_mut_expr(&mut self, expr: &mut Expr) {
+    expr.visit_mut_children_with(self);
+    match expr {
+      Expr::Paren(paren) => *expr = *paren.expr.take(),
+      Expr::Tpl(tpl) if tpl.exprs.is_empty() && !self.keep_strings => {
+        if let Some(cooked) = &tpl.quasis[0].cooked {
+          *expr = Expr::Lit(Lit::Str(Str {
+            span: DUMMY_SP,
+            value: cooked.clone(),
+            raw: None,
+          }));
+        }
+      }
+      _ => {}
+    }
+  }
+
+  fn visit_mut_member_prop(&mut self, prop: &mut MemberProp) {
+    prop.visit_mut_children_with(self);
+    if let MemberProp::Computed(computed) = prop {
+      if let Expr::Lit(Lit::Str(str)) = &*computed.expr {
+        if Ident::verify_symbol(&str.value).is_ok() {
+          *prop =
+            MemberProp::Ident(IdentName::new(str.value.clone(), DUMMY_SP));
+        }
+      }
+    }
+  }
+
+  fn visit_mut_str(&mut self, node: &mut Str) {
+    node.span = DUMMY_SP;
+    if !self.keep_strings {
+      node.raw = None;
+    }
+  }
+
+  fn visit_mut_number(&mut self, node: &mut Number) {
+    node.span = DUMMY_SP;
+    node.raw = None;
+  }
+
+  fn visit_mut_big_int(&mut self, node: &mut BigInt) {
+    node.span = DUMMY_SP;
+    node.raw = None;
+  }
+
+  // Contexts are unique to each scope, so the same parameter of two equal
+  // functions would have different contexts.
+  fn visit_mut_ident(&mut self, node: &mut Ident) {
+    node.ctxt = Default::default();
+    node.visit_mut_children_with(self);
+  }
+
+  fn visit_mut_arrow_expr(&mut self, node: &mut ArrowExpr) {
+    node.ctxt = Default::default();
+    node.visit_mut_children_with(self);
+  }
+
+  fn visit_mut_block_stmt(&mut self, node: &mut BlockStmt) {
+    node.ctxt = Default::default();
+    node.visit_mut_children_with(self);
+  }
+
+  fn visit_mut_call_expr(&mut self, node: &mut CallExpr) {
+    node.ctxt = Default::default();
+    node.visit_mut_children_with(self);
+  }
+
+  fn visit_mut_class(&mut self, node: &mut Class) {
+    node.ctxt = Default::default();
+    node.visit_mut_children_with(self);
+  }
+
+  fn visit_mut_constructor(&mut self, node: &mut Constructor) {
+    node.ctxt = Default::default();
+    node.visit_mut_children_with(self);
+  }
+
+  fn visit_mut_function(&mut self, node: &mut Function) {
+    node.ctxt = Default::default();
+    node.visit_mut_children_with(self);
+  }
+
+  fn visit_mut_new_expr(&mut self, node: &mut NewExpr) {
+    node.ctxt = Default::default();
+    node.visit_mut_children_with(self);
+  }
+
+  fn visit_mut_opt_call(&mut self, node: &mut OptCall) {
+    node.ctxt = Default::default();
+    node.visit_mut_children_with(self);
+  }
+
+  fn visit_mut_private_prop(&mut self, node: &mut PrivateProp) {
+    node.ctxt = Default::default();
+    node.visit_mut_children_with(self);
+  }
+
+  fn visit_mut_tagged_tpl(&mut self, node: &mut TaggedTpl) {
+    node.ctxt = Default::default();
+    node.visit_mut_children_with(self);
+  }
+
+  fn visit_mut_var_decl(&mut self, node: &mut VarDecl) {
+    node.ctxt = Default::default();
+    node.visit_mut_children_with(self);
+  }
+}
+
 pub fn span_and_ctx_drop<T>(mut t: T) -> T
 where
   T: VisitMutWith<DropSpanAndCtx>,
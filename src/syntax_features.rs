@@ -0,0 +1,234 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+//! Finds out which syntax a file uses before the rules run, so that rules
+//! that only check some syntax, like JSX or enums, can be skipped on files
+//! that don't use it.
+
+use crate::ProgramRef;
+use deno_ast::swc::ast::{
+  ArrowExpr, AwaitExpr, Class, Decorator, ForOfStmt, Function, Ident,
+  JSXElement, JSXFragment, LabeledStmt, Regex, SwitchStmt, TsEnumDecl,
+  TsModuleDecl, WithStmt, YieldExpr,
+};
+use deno_ast::swc::visit::{noop_visit_type, Visit, VisitWith};
+use std::ops::{BitOr, BitOrAssign};
+
+/// A set of syntax features.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SyntaxFeatures(u16);
+
+impl SyntaxFeatures {
+  pub const NONE: Self = Self(0);
+  /// Class declarations and expressions.
+  pub const CLASS: Self = Self(1 << 0);
+  /// JSX elements and fragments.
+  pub const JSX: Self = Self(1 << 1);
+  /// Async functions, `await` and `for await`.
+  pub const ASYNC: Self = Self(1 << 2);
+  /// Generator functions and `yield`.
+  pub const GENERATOR: Self = Self(1 << 3);
+  pub const WITH: Self = Self(1 << 4);
+  /// Labeled statements.
+  pub const LABEL: Self = Self(1 << 5);
+  pub const SWITCH: Self = Self(1 << 6);
+  /// TypeScript enums.
+  pub const ENUM: Self = Self(1 << 7);
+  /// TypeScript namespaces and modules, including `declare global`.
+  pub const NAMESPACE: Self = Self(1 << 8);
+  pub const DECORATOR: Self = Self(1 << 9);
+  /// Regex literals and anything named `RegExp`.
+  pub const REGEX: Self = Self(1 << 10);
+
+  /// Scans the program for the features it uses.
+  pub fn scan(program: ProgramRef) -> Self {
+    let mut scanner = Scanner(Self::NONE);
+    match program {
+      ProgramRef::Module(module) => module.visit_with(&mut scanner),
+      ProgramRef::Script(script) => script.visit_with(&mut scanner),
+    }
+    scanner.0
+  }
+
+  pub fn is_empty(self) -> bool {
+    self.0 == 0
+  }
+
+  /// Whether all the features of `other` are in this set.
+  pub fn contains(self, other: Self) -> bool {
+    self.0 & other.0 == other.0
+  }
+
+  /// Whether any of the features of `other` is in this set.
+  pub fn intersects(self, other: Self) -> bool {
+    self.0 & other.0 != 0
+  }
+}
+
+impl BitOr for SyntaxFeatures {
+  type Output = Self;
+
+  fn bitor(self, other: Self) -> Self {
+    Self(self.0 | other.0)
+  }
+}
+
+impl BitOrAssign for SyntaxFeatures {
+  fn bitor_assign(&mut self, other: Self) {
+    self.0 |= other.0;
+  }
+}
+
+struct Scanner(SyntaxFeatures);
+
+impl Visit for Scanner {
+  noop_visit_type!();
+
+  fn visit_class(&mut self, class: &Class) {
+    self.0 |= SyntaxFeatures::CLASS;
+    class.visit_children_with(self);
+  }
+
+  fn visit_jsx_element(&mut self, element: &JSXElement) {
+    self.0 |= SyntaxFeatures::JSX;
+    element.visit_children_with(self);
+  }
+
+  fn visit_jsx_fragment(&mut self, fragment: &JSXFragment) {
+    self.0 |= SyntaxFeatures::JSX;
+    fragment.visit_children_with(self);
+  }
+
+  fn visit_function(&mut self, function: &Function) {
+    if function.is_async {
+      self.0 |= SyntaxFeatures::ASYNC;
+    }
+    if function.is_generator {
+      self.0 |= SyntaxFeatures::GENERATOR;
+    }
+    function.visit_children_with(self);
+  }
+
+  fn visit_arrow_expr(&mut self, arrow: &ArrowExpr) {
+    if arrow.is_async {
+      self.0 |= SyntaxFeatures::ASYNC;
+    }
+    arrow.visit_children_with(self);
+  }
+
+  fn visit_await_expr(&mut self, await_expr: &AwaitExpr) {
+    self.0 |= SyntaxFeatures::ASYNC;
+    await_expr.visit_children_with(self);
+  }
+
+  fn visit_for_of_stmt(&mut self, for_of: &ForOfStmt) {
+    if for_of.is_await {
+      self.0 |= SyntaxFeatures::ASYNC;
+    }
+    for_of.visit_children_with(self);
+  }
+
+  fn visit_yield_expr(&mut self, yield_expr: &YieldExpr) {
+    self.0 |= SyntaxFeatures::GENERATOR;
+    yield_expr.visit_children_with(self);
+  }
+
+  fn visit_with_stmt(&mut self, with: &WithStmt) {
+    self.0 |= SyntaxFeatures::WITH;
+    with.visit_children_with(self);
+  }
+
+  fn visit_labeled_stmt(&mut self, labeled: &LabeledStmt) {
+    self.0 |= SyntaxFeatures::LABEL;
+    labeled.visit_children_with(self);
+  }
+
+  fn visit_switch_stmt(&mut self, switch: &SwitchStmt) {
+    self.0 |= SyntaxFeatures::SWITCH;
+    switch.visit_children_with(self);
+  }
+
+  fn visit_ts_enum_decl(&mut self, enum_decl: &TsEnumDecl) {
+    self.0 |= SyntaxFeatures::ENUM;
+    enum_decl.visit_children_with(self);
+  }
+
+  fn visit_ts_module_decl(&mut self, module_decl: &TsModuleDecl) {
+    self.0 |= SyntaxFeatures::NAMESPACE;
+    module_decl.visit_children_with(self);
+  }
+
+  fn visit_decorator(&mut self, decorator: &Decorator) {
+    self.0 |= SyntaxFeatures::DECORATOR;
+    decorator.visit_children_with(self);
+  }
+
+  fn visit_regex(&mut self, _: &Regex) {
+    self.0 |= SyntaxFeatures::REGEX;
+  }
+
+  fn visit_ident(&mut self, ident: &Ident) {
+    if ident.sym == *"RegExp" {
+      self.0 |= SyntaxFeatures::REGEX;
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::ast_parser::parse_program;
+  use deno_ast::{MediaType, ModuleSpecifier};
+
+  fn scan(source: &str) -> SyntaxFeatures {
+    let parsed_source = parse_program(
+      ModuleSpecifier::parse("file:///test.tsx").unwrap(),
+      MediaType::Tsx,
+      source.to_string(),
+    )
+    .unwrap();
+    parsed_source.with_view(|program| {
+      SyntaxFeatures::scan(crate::rules::program_ref(program))
+    })
+  }
+
+  #[test]
+  fn scans_features() {
+    assert_eq!(scan("const a = 1; foo(a);"), SyntaxFeatures::NONE);
+    assert_eq!(scan("class A {}"), SyntaxFeatures::CLASS);
+    assert_eq!(scan("<div><>a</></div>;"), SyntaxFeatures::JSX);
+    assert_eq!(
+      scan("const f = async () => { for await (const x of y) {} };"),
+      SyntaxFeatures::ASYNC
+    );
+    assert_eq!(scan("await x;"), SyntaxFeatures::ASYNC);
+    assert_eq!(
+      scan("function* f() { yield 1; }"),
+      SyntaxFeatures::GENERATOR
+    );
+    assert_eq!(scan("a: while (true) break a;"), SyntaxFeatures::LABEL);
+    assert_eq!(scan("switch (a) {}"), SyntaxFeatures::SWITCH);
+    assert_eq!(
+      scan("enum A {} namespace B {}"),
+      SyntaxFeatures::ENUM | SyntaxFeatures::NAMESPACE
+    );
+    assert_eq!(
+      scan("@dec class A {}"),
+      SyntaxFeatures::CLASS | SyntaxFeatures::DECORATOR
+    );
+    assert_eq!(scan("/a/.test(b);"), SyntaxFeatures::REGEX);
+    assert_eq!(scan("new RegExp(a);"), SyntaxFeatures::REGEX);
+    // Types aren't scanned.
+    assert_eq!(scan("let a: Promise<RegExp>;"), SyntaxFeatures::NONE);
+  }
+
+  #[test]
+  fn contains_and_intersects() {
+    let features = SyntaxFeatures::CLASS | SyntaxFeatures::JSX;
+    assert!(features.contains(SyntaxFeatures::CLASS));
+    assert!(!features.contains(SyntaxFeatures::CLASS | SyntaxFeatures::ENUM));
+    assert!(features.intersects(SyntaxFeatures::CLASS | SyntaxFeatures::ENUM));
+    assert!(!features.intersects(SyntaxFeatures::ENUM));
+    assert!(features.contains(SyntaxFeatures::NONE));
+    assert!(SyntaxFeatures::NONE.is_empty());
+  }
+}
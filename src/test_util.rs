@@ -1,21 +1,462 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
+//! Testing of lint rules, the same way the rules of this crate are tested.
+//!
+//! ```
+//! use deno_lint::rules::no_extra_semi::NoExtraSemi;
+//! use deno_lint::test_util::{ExpectedDiagnostic, LintCase, RuleTester};
+//!
+//! let tester = RuleTester::new(Box::new(NoExtraSemi));
+//! tester.run("const a = 1;");
+//! tester.run(
+//!   LintCase::new("const a = 1;;")
+//!     .filename("file:///mod.js")
+//!     .error(
+//!       ExpectedDiagnostic::new(1, 12, "Unnecessary semicolon")
+//!         .hint("Remove the extra semicolon")
+//!         .fix("Remove the extra semicolon", "const a = 1;"),
+//!     ),
+//! );
+//! ```
+
+#[cfg(test)]
 use crate::ast_parser;
 use crate::diagnostic::LintDiagnostic;
+use crate::fixes::apply_all_until_stable;
 use crate::linter::LintConfig;
 use crate::linter::LintFileOptions;
 use crate::linter::Linter;
 use crate::linter::LinterOptions;
 use crate::rules::get_all_rules;
 use crate::rules::LintRule;
+use crate::type_info::TypeInfoProvider;
 use deno_ast::diagnostics::Diagnostic;
+#[cfg(test)]
 use deno_ast::view as ast_view;
 use deno_ast::MediaType;
 use deno_ast::ModuleSpecifier;
 use deno_ast::ParsedSource;
 use deno_ast::SourceTextInfo;
 use deno_ast::TextChange;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::path::Path;
+use std::sync::Arc;
+
+/// Lints cases with a single rule and checks the diagnostics it reports.
+pub struct RuleTester {
+  linter: Linter,
+  code: &'static str,
+}
+
+impl RuleTester {
+  pub fn new(rule: Box<dyn LintRule>) -> Self {
+    Self::with_options(rule, None)
+  }
+
+  /// A tester whose linter has types from a type checker, for rules that
+  /// need them.
+  pub fn with_type_info(
+    rule: Box<dyn LintRule>,
+    type_info: Arc<dyn TypeInfoProvider>,
+  ) -> Self {
+    Self::with_options(rule, Some(type_info))
+  }
+
+  fn with_options(
+    rule: Box<dyn LintRule>,
+    type_info: Option<Arc<dyn TypeInfoProvider>>,
+  ) -> Self {
+    let code = rule.code();
+    let linter = Linter::new(LinterOptions {
+      rules: vec![rule],
+      all_rule_codes: get_all_rules()
+        .into_iter()
+        .map(|rule| rule.code())
+        .collect(),
+      custom_ignore_diagnostic_directive: None,
+      custom_ignore_file_directive: None,
+      resolver: None,
+      type_info,
+    });
+    Self { linter, code }
+  }
+
+  /// Lints the case and panics if the diagnostics aren't the expected ones,
+  /// in the same order. A case without expected diagnostics has to be valid.
+  #[track_caller]
+  pub fn run(&self, case: impl Into<LintCase>) {
+    let case = case.into();
+    let (parsed_source, diagnostics) = self.lint(&case);
+    let source = &case.source;
+    if case.errors.is_empty() {
+      assert!(
+        diagnostics.is_empty(),
+        "Unexpected diagnostics found:\n{:#?}\n\nfilename: {}\nsource:\n{}\n",
+        diagnostics
+          .iter()
+          .map(|d| d.details.message.as_str())
+          .collect::<Vec<_>>(),
+        case.specifier,
+        source
+      );
+      return;
+    }
+    if case.errors.len() != diagnostics.len() {
+      eprintln!(
+        "Actual diagnostics:\n{:#?}",
+        diagnostics
+          .iter()
+          .map(|d| d.details.message.to_string())
+          .collect::<Vec<_>>()
+      );
+      assert_eq!(
+        case.errors.len(),
+        diagnostics.len(),
+        "{} diagnostics expected, but got {}.\n\nsource:\n{}\n",
+        case.errors.len(),
+        diagnostics.len(),
+        source,
+      );
+    }
+
+    for (error, diagnostic) in case.errors.iter().zip(&diagnostics) {
+      assert_diagnostic_2(
+        diagnostic,
+        self.code,
+        error,
+        source,
+        parsed_source.text_info_lazy(),
+      );
+    }
+  }
+
+  /// Lints every file in the directory, like `tests/fixtures/no-var`, and
+  /// compares the rendered diagnostics and the source with all fixes applied
+  /// to the snapshot next to the file, `<file>.snap`. Setting the
+  /// `UPDATE_SNAPSHOTS` environment variable writes the snapshots instead.
+  #[track_caller]
+  pub fn assert_fixtures(&self, dir: impl AsRef<Path>) {
+    let dir = dir.as_ref();
+    let update = std::env::var_os("UPDATE_SNAPSHOTS").is_some();
+    let mut paths = std::fs::read_dir(dir)
+      .unwrap_or_else(|e| panic!("Can't read {}: {}", dir.display(), e))
+      .map(|entry| entry.unwrap().path())
+      .filter(|path| path.is_file())
+      .filter(|path| path.extension().is_some_and(|ext| ext != "snap"))
+      .collect::<Vec<_>>();
+    paths.sort();
+
+    for path in paths {
+      let file_name = path.file_name().unwrap().to_string_lossy();
+      let source = std::fs::read_to_string(&path).unwrap();
+      let case =
+        LintCase::new(source).filename(&format!("file:///{file_name}"));
+      let actual = self.snapshot(&case);
+      let snapshot_path = path.with_file_name(format!("{file_name}.snap"));
+      if update {
+        std::fs::write(&snapshot_path, &actual).unwrap();
+        continue;
+      }
+      let expected = std::fs::read_to_string(&snapshot_path)
+        .unwrap_or_else(|_| {
+          panic!(
+            "{} has no snapshot, run the test with UPDATE_SNAPSHOTS=1 to create it",
+            path.display()
+          )
+        })
+        .replace("\r\n", "\n");
+      assert!(
+        actual == expected,
+        "The snapshot of {} doesn't match, run the test with UPDATE_SNAPSHOTS=1 to update it.\n\nexpected:\n{}\nactual:\n{}",
+        path.display(),
+        expected,
+        actual,
+      );
+    }
+  }
+
+  /// The rendered diagnostics of the case, followed by its source after
+  /// applying all fixes.
+  fn snapshot(&self, case: &LintCase) -> String {
+    static ANSI_RE: Lazy<Regex> =
+      Lazy::new(|| Regex::new(r"\x1b\[[0-9;]*m").unwrap());
+    let (_, diagnostics) = self.lint(case);
+    let mut snapshot = String::from("# Diagnostics\n\n");
+    if diagnostics.is_empty() {
+      snapshot.push_str("(none)\n\n");
+    }
+    for diagnostic in &diagnostics {
+      let rendered = diagnostic.display().to_string();
+      for line in ANSI_RE.replace_all(rendered.trim_end(), "").lines() {
+        snapshot.push_str(line.trim_end());
+        snapshot.push('\n');
+      }
+      snapshot.push('\n');
+    }
+
+    snapshot.push_str("# Fixed\n\n");
+    let fixed = apply_all_until_stable(
+      &self.linter,
+      LintFileOptions {
+        specifier: case.specifier.clone(),
+        source_code: case.source.clone(),
+        media_type: case.resolved_media_type(),
+        config: case.config.clone(),
+      },
+      10,
+    );
+    match fixed {
+      Ok(fixed) if fixed.applied > 0 => {
+        snapshot.push_str(fixed.parsed_source.text());
+      }
+      Ok(_) => snapshot.push_str("(no fixes)\n"),
+      Err(e) => {
+        snapshot.push_str(&format!("(the fixes broke the file: {e})\n"))
+      }
+    }
+    snapshot
+  }
+
+  /// Lints the case and returns the diagnostics, panicking if it can't be
+  /// parsed.
+  #[track_caller]
+  pub fn lint(&self, case: &LintCase) -> (ParsedSource, Vec<LintDiagnostic>) {
+    let lint_result = self.linter.lint_file(LintFileOptions {
+      specifier: case.specifier.clone(),
+      source_code: case.source.clone(),
+      media_type: case.resolved_media_type(),
+      config: case.config.clone(),
+    });
+    match lint_result {
+      Ok((source, diagnostics)) => (source, diagnostics),
+      Err(e) => panic!(
+        "Failed to lint.\n[cause]\n{}\n\n[source code]\n{}",
+        e, case.source
+      ),
+    }
+  }
+}
+
+/// Source code to lint along with the diagnostics it should have.
+#[derive(Debug, Clone)]
+pub struct LintCase {
+  source: String,
+  specifier: ModuleSpecifier,
+  media_type: Option<MediaType>,
+  config: LintConfig,
+  errors: Vec<ExpectedDiagnostic>,
+}
+
+impl LintCase {
+  /// A case linted as `file:///deno_lint_test.ts`, with `React.createElement`
+  /// and `React.Fragment` as the JSX factories.
+  pub fn new(source: impl ToString) -> Self {
+    Self {
+      source: source.to_string(),
+      specifier: ModuleSpecifier::parse("file:///deno_lint_test.ts").unwrap(),
+      media_type: None,
+      config: LintConfig {
+        default_jsx_factory: Some("React.createElement".to_owned()),
+        default_jsx_fragment_factory: Some("React.Fragment".to_owned()),
+      },
+      errors: Vec::new(),
+    }
+  }
+
+  /// The specifier of the file, whose extension decides the media type unless
+  /// it's set with `media_type`. Panics if it isn't a valid URL.
+  pub fn filename(mut self, specifier: &str) -> Self {
+    self.specifier = ModuleSpecifier::parse(specifier).unwrap();
+    self
+  }
+
+  pub fn media_type(mut self, media_type: MediaType) -> Self {
+    self.media_type = Some(media_type);
+    self
+  }
+
+  pub fn config(mut self, config: LintConfig) -> Self {
+    self.config = config;
+    self
+  }
+
+  /// Adds a diagnostic that the rule has to report, in the order they're
+  /// reported.
+  pub fn error(mut self, error: ExpectedDiagnostic) -> Self {
+    self.errors.push(error);
+    self
+  }
+
+  fn resolved_media_type(&self) -> MediaType {
+    self
+      .media_type
+      .unwrap_or_else(|| MediaType::from_specifier(&self.specifier))
+  }
+}
+
+impl From<&str> for LintCase {
+  fn from(source: &str) -> Self {
+    Self::new(source)
+  }
+}
+
+/// A diagnostic that a case has to have.
+#[derive(Debug, Clone, Default)]
+pub struct ExpectedDiagnostic {
+  line: usize,
+  col: usize,
+  message: String,
+  hint: Option<String>,
+  fixes: Vec<ExpectedFix>,
+  related: Option<Vec<(usize, usize)>>,
+}
 
+impl ExpectedDiagnostic {
+  /// A diagnostic that starts at the 1-based line and 0-based column.
+  pub fn new(line: usize, col: usize, message: impl ToString) -> Self {
+    // Line is 1-based in deno_lint
+    assert!(line >= 1);
+    Self {
+      line,
+      col,
+      message: message.to_string(),
+      ..Default::default()
+    }
+  }
+
+  /// The hint the diagnostic has, which has to be given if it has one.
+  pub fn hint(mut self, hint: impl ToString) -> Self {
+    self.hint = Some(hint.to_string());
+    self
+  }
+
+  /// A fix the diagnostic has, as its description and the whole source after
+  /// it's applied. All the fixes have to be given.
+  pub fn fix(
+    mut self,
+    description: impl ToString,
+    fixed_code: impl ToString,
+  ) -> Self {
+    self.fixes.push(ExpectedFix {
+      description: description.to_string(),
+      fixed_code: fixed_code.to_string(),
+    });
+    self
+  }
+
+  /// The 1-based line and 0-based column of a related range. They're only
+  /// checked when at least one is given.
+  pub fn related(mut self, line: usize, col: usize) -> Self {
+    self.related.get_or_insert_with(Vec::new).push((line, col));
+    self
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ExpectedFix {
+  description: String,
+  fixed_code: String,
+}
+
+/// Lints the source as TypeScript and as TSX with every rule on its own, and
+/// returns a description of the first problem: a rule that panics, or a
+/// diagnostic, related range or fix whose range isn't within the source or
+/// doesn't start and end on character boundaries. Sources that don't parse,
+/// or that the parser panics on, have nothing to check.
+pub fn lint_no_panic(source: &str) -> Result<(), String> {
+  for media_type in [MediaType::TypeScript, MediaType::Tsx] {
+    let specifier = ModuleSpecifier::parse(&format!(
+      "file:///lint_no_panic{}",
+      media_type.as_ts_extension()
+    ))
+    .unwrap();
+    // The parser panics on some invalid code, which isn't up to the rules.
+    let parsed = std::panic::catch_unwind(|| {
+      crate::ast_parser::parse_program(
+        specifier,
+        media_type,
+        deno_ast::strip_bom(source.to_string()),
+      )
+    });
+    let Ok(Ok(parsed_source)) = parsed else {
+      continue;
+    };
+    for rule in get_all_rules() {
+      let code = rule.code();
+      let linter = Linter::new(LinterOptions {
+        rules: vec![rule],
+        all_rule_codes: Default::default(),
+        custom_ignore_diagnostic_directive: None,
+        custom_ignore_file_directive: None,
+        resolver: None,
+        type_info: None,
+      });
+      let config = LintConfig {
+        default_jsx_factory: Some("React.createElement".to_owned()),
+        default_jsx_fragment_factory: Some("React.Fragment".to_owned()),
+      };
+      let result =
+        std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+          linter.lint_with_ast(&parsed_source, config)
+        }));
+      let diagnostics = result.map_err(|payload| {
+        let message = payload
+          .downcast_ref::<&str>()
+          .map(|message| message.to_string())
+          .or_else(|| payload.downcast_ref::<String>().cloned())
+          .unwrap_or_default();
+        format!("{code} panicked on {media_type}: {message}")
+      })?;
+      for diagnostic in &diagnostics {
+        check_diagnostic_ranges(diagnostic, &parsed_source)
+          .map_err(|problem| format!("{code} on {media_type}: {problem}"))?;
+      }
+    }
+  }
+  Ok(())
+}
+
+fn check_diagnostic_ranges(
+  diagnostic: &LintDiagnostic,
+  parsed_source: &ParsedSource,
+) -> Result<(), String> {
+  let text_info = parsed_source.text_info_lazy();
+  let text = text_info.text_str();
+  let start = text_info.range().start;
+  let check = |kind: &str, range: &deno_ast::SourceRange| {
+    let valid = range.start >= start
+      && range.start <= range.end
+      && range.end - start <= text.len()
+      && text.is_char_boundary(range.start - start)
+      && text.is_char_boundary(range.end - start);
+    if valid {
+      Ok(())
+    } else {
+      Err(format!(
+        "the {} of \"{}\" has the invalid range {:?} in a source of {} bytes",
+        kind,
+        diagnostic.details.message,
+        range.as_byte_range(start),
+        text.len()
+      ))
+    }
+  };
+  if let Some(range) = &diagnostic.range {
+    check("range", &range.range)?;
+  }
+  for related in &diagnostic.related {
+    check("related range", &related.range)?;
+  }
+  for fix in &diagnostic.details.fixes {
+    for change in &fix.changes {
+      check("fix", &change.range)?;
+    }
+  }
+  Ok(())
+}
+
+#[cfg(test)]
 #[macro_export]
 macro_rules! assert_lint_ok {
   (
@@ -37,6 +478,7 @@ macro_rules! assert_lint_ok {
   };
 }
 
+#[cfg(test)]
 #[macro_export]
 macro_rules! assert_lint_err {
   (
@@ -104,6 +546,7 @@ macro_rules! assert_lint_err {
   };
 }
 
+#[cfg(test)]
 #[macro_export]
 macro_rules! variant {
   ($enum:ident, $variant:ident) => {{
@@ -118,6 +561,7 @@ macro_rules! variant {
   }};
 }
 
+#[cfg(test)]
 macro_rules! parse_err_test {
   (
     [
@@ -178,18 +622,20 @@ macro_rules! parse_err_test {
   }};
 }
 
+#[cfg(test)]
 pub struct LintErrTester {
   src: &'static str,
-  errors: Vec<LintErr>,
+  errors: Vec<ExpectedDiagnostic>,
   filename: &'static str,
   rule: Box<dyn LintRule>,
 }
 
+#[cfg(test)]
 impl LintErrTester {
   pub fn new(
     rule: Box<dyn LintRule>,
     src: &'static str,
-    errors: Vec<LintErr>,
+    errors: Vec<ExpectedDiagnostic>,
     filename: &'static str,
   ) -> Self {
     Self {
@@ -202,81 +648,30 @@ impl LintErrTester {
 
   #[track_caller]
   pub fn run(self) {
-    let rule_code = self.rule.code();
-    let (parsed_source, diagnostics) = lint(self.rule, self.src, self.filename);
-    if self.errors.len() != diagnostics.len() {
-      eprintln!(
-        "Actual diagnostics:\n{:#?}",
-        diagnostics
-          .iter()
-          .map(|d| d.details.message.to_string())
-          .collect::<Vec<_>>()
-      );
-      assert_eq!(
-        self.errors.len(),
-        diagnostics.len(),
-        "{} diagnostics expected, but got {}.\n\nsource:\n{}\n",
-        self.errors.len(),
-        diagnostics.len(),
-        self.src,
-      );
-    }
-
-    for (error, diagnostic) in self.errors.iter().zip(&diagnostics) {
-      let LintErr {
-        line,
-        col,
-        message,
-        hint,
-        fixes,
-      } = error;
-      assert_diagnostic_2(
-        diagnostic,
-        rule_code,
-        *line,
-        *col,
-        self.src,
-        message,
-        hint.as_deref(),
-        fixes,
-        parsed_source.text_info_lazy(),
-      );
-    }
+    let mut case = LintCase::new(self.src).filename(self.filename);
+    case.errors = self.errors;
+    RuleTester::new(self.rule).run(case);
   }
 }
 
-#[derive(Debug, PartialEq, Eq)]
-pub struct LintErrFix {
-  pub description: String,
-  pub fixed_code: String,
-}
-
-#[derive(Default)]
-pub struct LintErr {
-  pub line: usize,
-  pub col: usize,
-  pub message: String,
-  pub hint: Option<String>,
-  pub fixes: Vec<LintErrFix>,
-}
-
+#[cfg(test)]
 #[derive(Default)]
 pub struct LintErrBuilder {
   line: Option<usize>,
   col: Option<usize>,
   message: Option<String>,
   hint: Option<String>,
-  fixes: Vec<LintErrFix>,
+  fixes: Vec<(&'static str, &'static str)>,
+  related: Vec<(usize, usize)>,
 }
 
+#[cfg(test)]
 impl LintErrBuilder {
   pub fn new() -> Self {
     Self::default()
   }
 
   pub fn line(&mut self, line: usize) -> &mut Self {
-    // Line is 1-based in deno_lint
-    assert!(line >= 1);
     self.line = Some(line);
     self
   }
@@ -297,60 +692,44 @@ impl LintErrBuilder {
   }
 
   pub fn fix(&mut self, value: (&'static str, &'static str)) -> &mut Self {
-    self.fixes.push(LintErrFix {
-      description: value.0.to_string(),
-      fixed_code: value.1.to_string(),
-    });
+    self.fixes.push(value);
     self
   }
 
-  pub fn build(self) -> LintErr {
-    LintErr {
-      line: self.line.unwrap_or(1),
-      col: self.col.unwrap_or(0),
-      message: self.message.unwrap_or_default(),
-      hint: self.hint,
-      fixes: self.fixes,
+  /// The 1-based line and 0-based column of a related range.
+  pub fn related(&mut self, value: (usize, usize)) -> &mut Self {
+    self.related.push(value);
+    self
+  }
+
+  pub fn build(self) -> ExpectedDiagnostic {
+    let mut error = ExpectedDiagnostic::new(
+      self.line.unwrap_or(1),
+      self.col.unwrap_or(0),
+      self.message.unwrap_or_default(),
+    );
+    error.hint = self.hint;
+    for (description, fixed_code) in self.fixes {
+      error = error.fix(description, fixed_code);
     }
+    for (line, col) in self.related {
+      error = error.related(line, col);
+    }
+    error
   }
 }
 
+#[cfg(test)]
 #[track_caller]
-fn lint(
+pub fn lint(
   rule: Box<dyn LintRule>,
   source: &str,
   specifier: &str,
 ) -> (ParsedSource, Vec<LintDiagnostic>) {
-  let linter = Linter::new(LinterOptions {
-    rules: vec![rule],
-    all_rule_codes: get_all_rules()
-      .into_iter()
-      .map(|rule| rule.code())
-      .collect(),
-    custom_ignore_diagnostic_directive: None,
-    custom_ignore_file_directive: None,
-  });
-
-  let specifier = ModuleSpecifier::parse(specifier).unwrap();
-  let media_type = MediaType::from_specifier(&specifier);
-  let lint_result = linter.lint_file(LintFileOptions {
-    specifier,
-    source_code: source.to_string(),
-    media_type,
-    config: LintConfig {
-      default_jsx_factory: Some("React.createElement".to_owned()),
-      default_jsx_fragment_factory: Some("React.Fragment".to_owned()),
-    },
-  });
-  match lint_result {
-    Ok((source, diagnostics)) => (source, diagnostics),
-    Err(e) => panic!(
-      "Failed to lint.\n[cause]\n{}\n\n[source code]\n{}",
-      e, source
-    ),
-  }
+  RuleTester::new(rule).lint(&LintCase::new(source).filename(specifier))
 }
 
+#[cfg(test)]
 pub fn assert_diagnostic(
   diagnostic: &LintDiagnostic,
   code: &str,
@@ -381,19 +760,22 @@ pub fn assert_diagnostic(
   );
 }
 
-#[allow(clippy::too_many_arguments)]
 #[track_caller]
 fn assert_diagnostic_2(
   diagnostic: &LintDiagnostic,
   code: &str,
-  line: usize,
-  col: usize,
+  expected: &ExpectedDiagnostic,
   source: &str,
-  message: &str,
-  hint: Option<&str>,
-  fixes: &[LintErrFix],
   text_info: &SourceTextInfo,
 ) {
+  let ExpectedDiagnostic {
+    line,
+    col,
+    message,
+    hint,
+    fixes,
+    related,
+  } = expected;
   let diagnostic_range = diagnostic.range.as_ref().unwrap();
   let line_and_column = diagnostic_range
     .text_info
@@ -404,7 +786,7 @@ fn assert_diagnostic_2(
     code, diagnostic.details.code, source
   );
   assert_eq!(
-    line,
+    *line,
     line_and_column.line_index + 1,
     "Line is expected to be \"{}\", but got \"{}\"\n\nsource:\n{}\n",
     line,
@@ -412,7 +794,7 @@ fn assert_diagnostic_2(
     source
   );
   assert_eq!(
-    col, line_and_column.column_index,
+    *col, line_and_column.column_index,
     "Column is expected to be \"{}\", but got \"{}\"\n\nsource:\n{}\n",
     col, line_and_column.column_index, source
   );
@@ -422,7 +804,7 @@ fn assert_diagnostic_2(
     message, &diagnostic.details.message, source
   );
   assert_eq!(
-    hint,
+    hint.as_deref(),
     diagnostic.details.hint.as_deref(),
     "Diagnostic hint is expected to be \"{:?}\", but got \"{:?}\"\n\nsource:\n{}\n",
     hint,
@@ -433,7 +815,7 @@ fn assert_diagnostic_2(
     .details
     .fixes
     .iter()
-    .map(|fix| LintErrFix {
+    .map(|fix| ExpectedFix {
       description: fix.description.to_string(),
       fixed_code: deno_ast::apply_text_changes(
         text_info.text_str(),
@@ -448,33 +830,62 @@ fn assert_diagnostic_2(
       ),
     })
     .collect::<Vec<_>>();
-  assert_eq!(actual_fixes, fixes, "Quick fixes did not match.");
+  assert_eq!(&actual_fixes, fixes, "Quick fixes did not match.");
+  if let Some(related) = related {
+    let actual_related = diagnostic
+      .related
+      .iter()
+      .map(|related| {
+        let line_and_column =
+          text_info.line_and_column_index(related.range.start);
+        (line_and_column.line_index + 1, line_and_column.column_index)
+      })
+      .collect::<Vec<_>>();
+    assert_eq!(
+      &actual_related, related,
+      "Related ranges did not match.\n\nsource:\n{}\n",
+      source
+    );
+  }
 }
 
+#[cfg(test)]
 #[track_caller]
 pub fn assert_lint_ok(
   rule: Box<dyn LintRule>,
   source: &str,
   specifier: &'static str,
 ) {
-  let (_parsed_source, diagnostics) = lint(rule, source, specifier);
-  if !diagnostics.is_empty() {
-    eprintln!("filename {:?}", specifier);
-    panic!(
-      "Unexpected diagnostics found:\n{:#?}\n\nsource:\n{}\n",
-      diagnostics.iter().map(|d| d.message()).collect::<Vec<_>>(),
-      source
-    );
-  }
+  RuleTester::new(rule).run(LintCase::new(source).filename(specifier));
 }
 
 /// Just run the specified lint on the source code to make sure it doesn't panic.
+#[cfg(test)]
 pub fn assert_lint_not_panic(rule: Box<dyn LintRule>, source: &str) {
   let _result = lint(rule, source, TEST_FILE_NAME);
 }
 
+/// Types that don't know the type of any expression, so rules that need
+/// types run but only go by the syntax.
+#[cfg(test)]
+#[derive(Debug)]
+pub(crate) struct UnknownTypes;
+
+#[cfg(test)]
+impl TypeInfoProvider for UnknownTypes {
+  fn is_any(
+    &self,
+    _specifier: &ModuleSpecifier,
+    _range: std::ops::Range<usize>,
+  ) -> Option<bool> {
+    None
+  }
+}
+
+#[cfg(test)]
 const TEST_FILE_NAME: &str = "file:///lint_test.ts";
 
+#[cfg(test)]
 pub fn parse(source_code: &str) -> ParsedSource {
   ast_parser::parse_program(
     ModuleSpecifier::parse(TEST_FILE_NAME).unwrap(),
@@ -484,6 +895,7 @@ pub fn parse(source_code: &str) -> ParsedSource {
   .unwrap()
 }
 
+#[cfg(test)]
 pub fn parse_and_then(source_code: &str, test: impl Fn(ast_view::Program)) {
   let parsed_source = parse(source_code);
   parsed_source.with_view(|pg| {
@@ -0,0 +1,245 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+//! Recognizes the calls that register tests, for rules about test code.
+
+use crate::context::Context;
+use deno_ast::swc::ast::Id;
+use deno_ast::view::{
+  CallExpr, Callee, Expr, ExprOrSpread, ImportSpecifier, KeyValueProp,
+  MemberProp, Module, ModuleDecl, ModuleItem, Node, NodeTrait, ObjectLit, Prop,
+  PropName, PropOrSpread,
+};
+use deno_ast::ModuleSpecifier;
+use std::collections::HashSet;
+
+/// Whether the file is picked up by `deno test` by default, like
+/// `math_test.ts`, `math.test.ts` or `test.ts`.
+pub(crate) fn is_test_file(specifier: &ModuleSpecifier) -> bool {
+  let Some(file_name) = specifier.path().rsplit('/').next() else {
+    return false;
+  };
+  let Some((stem, extension)) = file_name.rsplit_once('.') else {
+    return false;
+  };
+  matches!(extension, "ts" | "tsx" | "mts" | "js" | "jsx" | "mjs")
+    && (stem == "test" || stem.ends_with("_test") || stem.ends_with(".test"))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TestCallKind {
+  /// `Deno.test(...)`
+  DenoTest,
+  /// `t.step(...)` inside of a test.
+  Step,
+  /// `describe(...)` from `@std/testing/bdd` and similar libraries.
+  Describe,
+  /// `it(...)` or `test(...)` from `@std/testing/bdd` and similar libraries.
+  It,
+}
+
+/// A call that registers a test, a test step or a group of tests.
+pub(crate) struct TestCall<'a> {
+  pub kind: TestCallKind,
+  /// The property after the test function, like `only` in `Deno.test.only`.
+  pub modifier: Option<&'a MemberProp<'a>>,
+  pub args: &'a [&'a ExprOrSpread<'a>],
+}
+
+impl<'a> TestCall<'a> {
+  pub fn from_call(call_expr: &CallExpr<'a>, ctx: &Context) -> Option<Self> {
+    let Callee::Expr(callee) = call_expr.callee else {
+      return None;
+    };
+    let (kind, modifier) = match callee {
+      Expr::Member(member) => match (kind_of(&member.obj, ctx), &member.prop) {
+        (Some(kind), prop @ MemberProp::Ident(ident))
+          if kind != TestCallKind::Step
+            && matches!(ident.sym().as_ref(), "only" | "ignore" | "skip") =>
+        {
+          (kind, Some(prop))
+        }
+        _ => (kind_of(&callee, ctx)?, None),
+      },
+      _ => (kind_of(&callee, ctx)?, None),
+    };
+    Some(Self {
+      kind,
+      modifier,
+      args: call_expr.args,
+    })
+  }
+
+  /// The name of the modifier, like `only` in `Deno.test.only`.
+  pub fn modifier_name(&self) -> Option<&'a str> {
+    match self.modifier? {
+      MemberProp::Ident(ident) => Some(ident.sym()),
+      _ => None,
+    }
+  }
+
+  /// The options object, as in `Deno.test({ name, fn })` or
+  /// `Deno.test("name", { ignore: true }, fn)`.
+  pub fn options(&self) -> Option<&'a ObjectLit<'a>> {
+    self.args.iter().find_map(|arg| match arg.expr {
+      Expr::Object(object) => Some(object),
+      _ => None,
+    })
+  }
+
+  /// The value of the option with the given name.
+  pub fn option(&self, name: &str) -> Option<&'a KeyValueProp<'a>> {
+    self.options()?.props.iter().find_map(|prop| match prop {
+      PropOrSpread::Prop(Prop::KeyValue(prop)) if is_key(&prop.key, name) => {
+        Some(*prop)
+      }
+      _ => None,
+    })
+  }
+}
+
+fn kind_of(expr: &Expr, ctx: &Context) -> Option<TestCallKind> {
+  match expr {
+    Expr::Ident(ident) => match ident.sym().as_ref() {
+      "describe" => Some(TestCallKind::Describe),
+      "it" | "test" => Some(TestCallKind::It),
+      _ => None,
+    },
+    Expr::Member(member) => {
+      let MemberProp::Ident(prop) = &member.prop else {
+        return None;
+      };
+      match (&member.obj, prop.sym().as_ref()) {
+        (Expr::Ident(obj), "test")
+          if obj.sym() == "Deno"
+            && ctx.scope().is_global(&obj.inner.to_id()) =>
+        {
+          Some(TestCallKind::DenoTest)
+        }
+        (Expr::Ident(_), "step") => Some(TestCallKind::Step),
+        _ => None,
+      }
+    }
+    _ => None,
+  }
+}
+
+fn is_key(key: &PropName, name: &str) -> bool {
+  match key {
+    PropName::Ident(ident) => ident.sym() == name,
+    PropName::Str(str) => str.value() == name,
+    _ => false,
+  }
+}
+
+/// The closest function around the node that is the body of a test, along
+/// with the test call, even if the test is nested in other functions.
+pub(crate) fn enclosing_test<'a>(
+  node: Node<'a>,
+  ctx: &Context,
+) -> Option<(Node<'a>, TestCall<'a>)> {
+  node
+    .ancestors()
+    .find_map(|ancestor| Some((ancestor, callback_test(ancestor, ctx)?)))
+}
+
+/// The test call that the function or arrow function is the body of, as an
+/// argument or as the `fn` option.
+pub(crate) fn callback_test<'a>(
+  function: Node<'a>,
+  ctx: &Context,
+) -> Option<TestCall<'a>> {
+  let value = match function {
+    Node::ArrowExpr(_) => function,
+    // The function is wrapped in a `FnExpr` or a `MethodProp`.
+    Node::Function(_) => function.parent()?,
+    _ => return None,
+  };
+  let call_expr = match value {
+    Node::MethodProp(method) if is_key(&method.key, "fn") => {
+      object_call(method.parent())?
+    }
+    _ => match value.parent()? {
+      Node::ExprOrSpread(arg) => arg.parent().to::<CallExpr>()?,
+      Node::KeyValueProp(prop) if is_key(&prop.key, "fn") => {
+        object_call(prop.parent())?
+      }
+      _ => return None,
+    },
+  };
+  TestCall::from_call(call_expr, ctx)
+}
+
+/// The call that the object literal is an argument of.
+fn object_call<'a>(object: &ObjectLit<'a>) -> Option<&'a CallExpr<'a>> {
+  object
+    .parent()
+    .to::<ExprOrSpread>()?
+    .parent()
+    .to::<CallExpr>()
+}
+
+/// Whether the module is an assertion library, like `@std/assert`.
+fn is_assertion_module(specifier: &str) -> bool {
+  specifier.contains("std/assert")
+    || specifier.contains("std/expect")
+    || specifier.contains("std/testing/asserts")
+    || (specifier.contains("/std@") && specifier.contains("/assert/"))
+    || matches!(
+      specifier,
+      "node:assert" | "node:assert/strict" | "chai" | "npm:chai"
+    )
+}
+
+/// The assertion functions that a module imports.
+#[derive(Default)]
+pub(crate) struct AssertionImports {
+  assertions: HashSet<Id>,
+  /// The namespaces that assertion modules are imported as, like `asserts`
+  /// in `import * as asserts from "@std/assert"`.
+  namespaces: HashSet<Id>,
+}
+
+impl AssertionImports {
+  pub fn from_module(module: &Module) -> Self {
+    let mut imports = Self::default();
+    for item in module.body {
+      let ModuleItem::ModuleDecl(ModuleDecl::Import(import)) = item else {
+        continue;
+      };
+      if !is_assertion_module(import.src.value()) {
+        continue;
+      }
+      for specifier in import.specifiers {
+        match specifier {
+          ImportSpecifier::Named(named) => {
+            imports.assertions.insert(named.local.inner.to_id());
+          }
+          // `node:assert` exports `assert` as its default export.
+          ImportSpecifier::Default(default) => {
+            imports.assertions.insert(default.local.inner.to_id());
+          }
+          ImportSpecifier::Namespace(namespace) => {
+            imports.namespaces.insert(namespace.local.inner.to_id());
+          }
+        }
+      }
+    }
+    imports
+  }
+
+  /// Whether the callee is an imported assertion, like `assertEquals`,
+  /// `asserts.assertEquals` or `assert.equal`.
+  pub fn is_assertion(&self, callee: &Expr) -> bool {
+    match callee {
+      Expr::Ident(ident) => self.assertions.contains(&ident.inner.to_id()),
+      Expr::Member(member) => match &member.obj {
+        Expr::Ident(obj) => {
+          let id = obj.inner.to_id();
+          self.namespaces.contains(&id) || self.assertions.contains(&id)
+        }
+        _ => false,
+      },
+      _ => false,
+    }
+  }
+}
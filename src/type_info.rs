@@ -0,0 +1,55 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+//! Types of expressions from a type checker, for rules that can't tell the
+//! type of a value from the syntax alone.
+
+use deno_ast::ModuleSpecifier;
+use std::ops::Range;
+
+/// Answers questions about the types of the expressions of a file, which
+/// embedders implement with a type checker. Rules that need type information,
+/// like `no-unsafe-call`, are skipped when the linter doesn't have one.
+pub trait TypeInfoProvider: std::fmt::Debug + Send + Sync {
+  /// Whether the expression at the byte range of the source of the module at
+  /// `specifier` has the type `any`. Returns `None` if the type isn't known,
+  /// in which case the rules only go by what the syntax says, like an `as any`
+  /// assertion.
+  fn is_any(
+    &self,
+    specifier: &ModuleSpecifier,
+    range: Range<usize>,
+  ) -> Option<bool>;
+
+  /// Whether the expressions at the two byte ranges have the same type.
+  /// Returns `None` if either type isn't known.
+  fn is_same_type(
+    &self,
+    _specifier: &ModuleSpecifier,
+    _a: Range<usize>,
+    _b: Range<usize>,
+  ) -> Option<bool> {
+    None
+  }
+
+  /// Whether the type of the expression at the byte range includes `null` or
+  /// `undefined`. Returns `None` if the type isn't known.
+  fn is_nullable(
+    &self,
+    _specifier: &ModuleSpecifier,
+    _range: Range<usize>,
+  ) -> Option<bool> {
+    None
+  }
+
+  /// The members of the union type of the expression at the byte range, each
+  /// written as it would be in a `case` of a `switch` over the expression,
+  /// like `"a"`, `1`, `true`, `null` or `Color.Red`. Returns `None` if the
+  /// type isn't known, or isn't a union of such values.
+  fn union_members(
+    &self,
+    _specifier: &ModuleSpecifier,
+    _range: Range<usize>,
+  ) -> Option<Vec<String>> {
+    None
+  }
+}
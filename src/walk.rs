@@ -0,0 +1,268 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+//! Finds the files to lint in a directory, so tools don't each have to walk
+//! the file system and match globs on their own.
+
+use anyhow::Error as AnyError;
+use deno_ast::MediaType;
+use ignore::WalkBuilder;
+use std::path::{Path, PathBuf};
+
+/// Directories that are never linted.
+pub const DEFAULT_EXCLUDES: &[&str] = &["node_modules", ".git"];
+
+#[derive(Debug, Clone, Default)]
+pub struct WalkOptions {
+  /// Globs of the files to lint relative to the root, like `src/**/*.ts`, or
+  /// directories to lint all the files in, like `src/`. All the files are
+  /// linted if it's empty.
+  pub include: Vec<String>,
+  /// Globs of the files or directories to leave out, which take precedence
+  /// over `include`.
+  pub exclude: Vec<String>,
+  /// Whether to leave out the files that `.gitignore` files ignore.
+  pub gitignore: bool,
+}
+
+/// Matches paths against the `include` and `exclude` globs of
+/// `WalkOptions`, which also works for files that aren't on disk, like code
+/// from stdin.
+#[derive(Debug, Clone)]
+pub struct FileMatcher {
+  root: PathBuf,
+  include: Option<globset::GlobSet>,
+  exclude: globset::GlobSet,
+}
+
+impl FileMatcher {
+  pub fn new(root: &Path, options: &WalkOptions) -> Result<Self, AnyError> {
+    let include = if options.include.is_empty() {
+      None
+    } else {
+      Some(glob_set(&options.include)?)
+    };
+    Ok(Self {
+      root: root.to_path_buf(),
+      include,
+      exclude: glob_set(&options.exclude)?,
+    })
+  }
+
+  /// Whether the file should be linted, because it has an extension that
+  /// can be linted and the globs include it.
+  pub fn matches(&self, path: &Path) -> bool {
+    is_lintable(path) && self.includes_dir(path) && self.matches_include(path)
+  }
+
+  /// Whether the files in the directory can be linted, which is false for
+  /// excluded directories so they can be skipped entirely.
+  pub fn includes_dir(&self, path: &Path) -> bool {
+    let path = self.relative(path);
+    !path.components().any(|component| {
+      DEFAULT_EXCLUDES
+        .contains(&component.as_os_str().to_string_lossy().as_ref())
+    }) && !matches_any_ancestor(&self.exclude, path)
+  }
+
+  fn matches_include(&self, path: &Path) -> bool {
+    match &self.include {
+      Some(include) => matches_any_ancestor(include, self.relative(path)),
+      None => true,
+    }
+  }
+
+  fn relative<'a>(&self, path: &'a Path) -> &'a Path {
+    path.strip_prefix(&self.root).unwrap_or(path)
+  }
+}
+
+fn glob_set(patterns: &[String]) -> Result<globset::GlobSet, AnyError> {
+  let mut builder = globset::GlobSetBuilder::new();
+  for pattern in patterns {
+    // Exclude globs can be written as negated globs, like `!dist`.
+    let pattern = pattern.strip_prefix('!').unwrap_or(pattern);
+    let pattern = pattern.strip_prefix("./").unwrap_or(pattern);
+    builder.add(globset::Glob::new(pattern.trim_end_matches('/'))?);
+  }
+  Ok(builder.build()?)
+}
+
+/// Whether the globs match the path or a directory it's in.
+fn matches_any_ancestor(set: &globset::GlobSet, path: &Path) -> bool {
+  path
+    .ancestors()
+    .any(|path| !path.as_os_str().is_empty() && set.is_match(path))
+}
+
+/// Whether the file is JavaScript or TypeScript.
+pub fn is_lintable(path: &Path) -> bool {
+  matches!(
+    MediaType::from_path(path),
+    MediaType::JavaScript
+      | MediaType::Jsx
+      | MediaType::Mjs
+      | MediaType::Cjs
+      | MediaType::TypeScript
+      | MediaType::Mts
+      | MediaType::Cts
+      | MediaType::Dts
+      | MediaType::Dmts
+      | MediaType::Dcts
+      | MediaType::Tsx
+  )
+}
+
+/// Finds the files to lint in the root directory, sorted by their path.
+///
+/// Absolute paths of files in `include` are linted as they are, even if they
+/// are outside of the root.
+pub fn walk(
+  root: &Path,
+  options: &WalkOptions,
+) -> Result<Vec<PathBuf>, AnyError> {
+  let matcher = FileMatcher::new(root, options)?;
+  let mut files: Vec<PathBuf> = options
+    .include
+    .iter()
+    .map(PathBuf::from)
+    .filter(|path| path.is_absolute() && path.is_file())
+    .collect();
+
+  let filter = matcher.clone();
+  let walker = WalkBuilder::new(root)
+    .hidden(false)
+    .ignore(false)
+    .parents(options.gitignore)
+    .git_ignore(options.gitignore)
+    .git_exclude(options.gitignore)
+    .git_global(false)
+    .require_git(false)
+    .follow_links(false)
+    .filter_entry(move |entry| {
+      !entry.file_type().map_or(false, |t| t.is_dir())
+        || filter.includes_dir(entry.path())
+    })
+    .build();
+  for entry in walker {
+    let entry = entry?;
+    if entry.file_type().map_or(false, |t| t.is_file())
+      && matcher.matches(entry.path())
+    {
+      files.push(entry.into_path());
+    }
+  }
+  files.sort();
+  files.dedup();
+  Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  /// Creates the files in a new directory, which is removed when the test is
+  /// done.
+  struct TempDir(PathBuf);
+
+  impl TempDir {
+    fn new(name: &str, files: &[&str]) -> Self {
+      let dir = std::env::temp_dir().join(format!(
+        "deno_lint_walk_{}_{}",
+        name,
+        std::process::id()
+      ));
+      let _ = std::fs::remove_dir_all(&dir);
+      for file in files {
+        let (path, content) = file.split_once(':').unwrap_or((file, ""));
+        let path = dir.join(path);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content).unwrap();
+      }
+      Self(dir)
+    }
+
+    fn walk(&self, options: &WalkOptions) -> Vec<String> {
+      walk(&self.0, options)
+        .unwrap()
+        .iter()
+        .map(|path| {
+          let path = path.strip_prefix(&self.0).unwrap();
+          path.to_string_lossy().replace('\\', "/")
+        })
+        .collect()
+    }
+  }
+
+  impl Drop for TempDir {
+    fn drop(&mut self) {
+      let _ = std::fs::remove_dir_all(&self.0);
+    }
+  }
+
+  #[test]
+  fn walk_default() {
+    let dir = TempDir::new(
+      "default",
+      &[
+        "main.ts",
+        "README.md",
+        "src/app.tsx",
+        "src/.hidden.js",
+        "node_modules/dep/index.js",
+        ".git/hooks/hook.js",
+      ],
+    );
+    assert_eq!(
+      dir.walk(&WalkOptions::default()),
+      vec!["main.ts", "src/.hidden.js", "src/app.tsx"]
+    );
+  }
+
+  #[test]
+  fn walk_include_and_exclude() {
+    let dir = TempDir::new(
+      "globs",
+      &[
+        "main.ts",
+        "src/app.ts",
+        "src/vendor/lib.js",
+        "src/types.generated.ts",
+        "tests/app_test.ts",
+      ],
+    );
+    let options = WalkOptions {
+      include: vec!["src/".to_string(), "./main.ts".to_string()],
+      exclude: vec!["src/vendor".to_string(), "**/*.generated.ts".to_string()],
+      gitignore: false,
+    };
+    assert_eq!(dir.walk(&options), vec!["main.ts", "src/app.ts"]);
+
+    let matcher = FileMatcher::new(&dir.0, &options).unwrap();
+    assert!(matcher.matches(&dir.0.join("src/not_on_disk.ts")));
+    assert!(!matcher.matches(&dir.0.join("src/vendor/not_on_disk.ts")));
+    assert!(!matcher.matches(&dir.0.join("tests/not_on_disk.ts")));
+    assert!(!matcher.matches(&dir.0.join("src/data.json")));
+  }
+
+  #[test]
+  fn walk_gitignore() {
+    let dir = TempDir::new(
+      "gitignore",
+      &[
+        ".gitignore:dist/\n*.tmp.ts\n",
+        "a.ts",
+        "b.tmp.ts",
+        "dist/out.js",
+      ],
+    );
+    let options = WalkOptions {
+      gitignore: true,
+      ..Default::default()
+    };
+    assert_eq!(dir.walk(&options), vec!["a.ts"]);
+    assert_eq!(
+      dir.walk(&WalkOptions::default()),
+      vec!["a.ts", "b.tmp.ts", "dist/out.js"]
+    );
+  }
+}
@@ -0,0 +1,24 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+//! Lints the files in `tests/fixtures/<rule>/` with the rule and compares the
+//! results with their snapshots. Run with `UPDATE_SNAPSHOTS=1` to update them.
+
+use deno_lint::rules::get_all_rules;
+use deno_lint::test_util::RuleTester;
+use std::path::Path;
+
+#[test]
+fn fixtures() {
+  let fixtures_dir =
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+  let mut rules = get_all_rules();
+  for entry in std::fs::read_dir(fixtures_dir).unwrap() {
+    let dir = entry.unwrap().path();
+    let code = dir.file_name().unwrap().to_string_lossy().to_string();
+    let index = rules
+      .iter()
+      .position(|rule| rule.code() == code)
+      .unwrap_or_else(|| panic!("No rule named {code} for {}", dir.display()));
+    RuleTester::new(rules.remove(index)).assert_fixtures(&dir);
+  }
+}
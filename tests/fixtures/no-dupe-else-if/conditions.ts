@@ -0,0 +1,12 @@
+export function describe(value: number | string): string {
+  if (typeof value === "number" && value > 0) {
+    return "positive";
+  } else if (typeof value === "string") {
+    return "text";
+  } else if (typeof value === "number" && value > 0) {
+    return "unreachable";
+  } else if (value === 0 || typeof value === "string") {
+    return "zero";
+  }
+  return "negative";
+}
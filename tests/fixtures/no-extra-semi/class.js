@@ -0,0 +1,13 @@
+class Queue {
+  #items = [];;
+
+  push(item) {
+    this.#items.push(item);
+  };
+
+  shift() {
+    return this.#items.shift();
+  }
+};
+
+export default Queue;
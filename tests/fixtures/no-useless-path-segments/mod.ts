@@ -0,0 +1,8 @@
+import { join } from "./../path/join.ts";
+import { parse } from "./lib/../parse.ts";
+import type { Options } from "./options.ts";
+export * from "././types.ts";
+
+export function resolve(options: Options, ...paths: string[]) {
+  return parse(join(options.root, ...paths));
+}
@@ -0,0 +1,155 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+//! Lints unusual syntax, and mutations of it, with every rule to check that
+//! no rule panics or reports invalid ranges. Set `LINT_NO_PANIC_MUTATIONS`
+//! to change how many mutations of each seed are linted, and
+//! `LINT_NO_PANIC_SEED` to get different mutations.
+//!
+//! The mutations only run without `--release`, since on some invalid code
+//! the parser reaches states that are only checked by debug assertions.
+
+use deno_lint::test_util::lint_no_panic;
+
+const SEEDS: &[&str] = &[
+  "",
+  "\u{FEFF}const a = 1;",
+  "// 🦕 comment\nconst 変数 = \"😀\"; /* ünïcödé */ let ß = `${変数}🦕`;",
+  "const re = /[\\u{1F600}-\\u{1F64F}]+/gu, re2 = /(?<year>\\d{4})-\\k<year>/v;",
+  "label: for (const a of b) { continue label; } outer: { break outer; }",
+  "with (obj) { a = b; }",
+  "class A<T> extends B<T> implements C { #x = 1; static { this.#x; } accessor y; get #z() { return #x in this; } }",
+  "@dec() @other class D { @prop() p?: string; constructor(@inject() private readonly s: S) { super(); } }",
+  "enum E { A = 1, B = A << 1, 'C' = 3 } declare const enum F {} namespace N.M { export import X = Y.Z; }",
+  "function f(this: Window, a?: number, ...rest: string[]): asserts a is number {}\nfunction f(): void;",
+  "type T<in out U> = U extends [infer H extends string, ...infer R] ? `${H}${T<R>}` : never;",
+  "let v = <const T,>(x: T) => x satisfies unknown as any!;",
+  "async function* g() { for await (const x of y) yield* x; await using res = getResource(); }",
+  "const o = { __proto__: null, [`k${1}`]: 1, get a() { return 1 }, set a(v) {}, async *[Symbol.iterator]() {} };",
+  "a ??= b ||= c &&= d ** -e; x?.y?.[z]?.(w); new.target; import.meta.url; 0b1_0n;",
+  "const el = <div {...props} key=\"1\" on:click={() => {}}><>{/* c */}</><A.B x={<y />} />&nbsp;</div>;",
+  "import def, * as ns from \"./a.ts\" with { type: \"json\" }; export { default as x, \"string name\" } from './b.ts'; export * as y from \"c\";",
+  "if (a) b; else if (c) d; else { e }; switch (x) { case 1: case 1: default: }",
+  "try { } catch { } finally { } do ; while (0) for (;;) {} for (var i in o) ;",
+  "// deno-lint-ignore-file\n// deno-lint-ignore no-var\nvar a;\n// @ts-ignore\n// TODO",
+  "`${`${`${a}`}`}`; String.raw`\\u`; tag<T>`x`;",
+  "export default function () {} export = foo; declare module \"m\" { export const x: number; }",
+  "let [a = 1, [b], ...c] = d, { e: { f } = {}, ...g } = h; ({ a } = b); [a, b] = [b, a];",
+  "0 ? 1 : 2 ? 3 : 4; !!(a, b); void 0; typeof typeof x; delete a[b]; a++ + ++b; -(-x);",
+  "'use strict'; \"use asm\"; function h() { 'use strict'; return arguments.callee; }",
+];
+
+/// Tokens that are inserted into the seeds, chosen to create syntax at odd
+/// places rather than to be valid.
+const TOKENS: &[&str] = &[
+  "(",
+  ")",
+  "{",
+  "}",
+  "[",
+  "]",
+  ";",
+  ",",
+  "=>",
+  "async ",
+  "await ",
+  "?.",
+  "#x",
+  "\"😀\"",
+  "`",
+  "${",
+  "/* 🦕 */",
+  "//",
+  "\n",
+  "<div>",
+  "</div>",
+  "@dec ",
+  "yield ",
+  "this",
+  "super",
+  "...",
+  "!",
+  "?",
+  ":",
+  "as any",
+  "=",
+  "1n",
+];
+
+/// xorshift64*, to get the same mutations on every run.
+struct Rng(u64);
+
+impl Rng {
+  fn next(&mut self) -> u64 {
+    self.0 ^= self.0 >> 12;
+    self.0 ^= self.0 << 25;
+    self.0 ^= self.0 >> 27;
+    self.0.wrapping_mul(0x2545_F491_4F6C_DD1D)
+  }
+
+  fn below(&mut self, n: usize) -> usize {
+    (self.next() % n.max(1) as u64) as usize
+  }
+
+  /// A character boundary of the text.
+  fn boundary(&mut self, text: &str) -> usize {
+    let boundaries = text
+      .char_indices()
+      .map(|(i, _)| i)
+      .chain([text.len()])
+      .collect::<Vec<_>>();
+    boundaries[self.below(boundaries.len())]
+  }
+}
+
+fn mutate(source: &str, rng: &mut Rng) -> String {
+  let mut source = source.to_string();
+  for _ in 0..=rng.below(3) {
+    let a = rng.boundary(&source);
+    let b = rng.boundary(&source);
+    let (start, end) = (a.min(b), a.max(b));
+    match rng.below(4) {
+      0 => source.replace_range(start..end, ""),
+      1 => source.insert_str(start, TOKENS[rng.below(TOKENS.len())]),
+      2 => {
+        let copy = source[start..end].to_string();
+        source.insert_str(end, &copy);
+      }
+      _ => source.truncate(end),
+    }
+  }
+  source
+}
+
+fn env_or(name: &str, default: u64) -> u64 {
+  std::env::var(name)
+    .ok()
+    .and_then(|value| value.parse().ok())
+    .unwrap_or(default)
+}
+
+#[test]
+fn seeds() {
+  for seed in SEEDS {
+    if let Err(problem) = lint_no_panic(seed) {
+      panic!("{problem}\n\nsource:\n{seed}");
+    }
+  }
+}
+
+#[test]
+#[cfg_attr(
+  not(debug_assertions),
+  ignore = "the parser can crash on invalid code in release builds"
+)]
+fn mutations() {
+  let mutations = env_or("LINT_NO_PANIC_MUTATIONS", 10);
+  let mut rng = Rng(env_or("LINT_NO_PANIC_SEED", 0x9E37_79B9_7F4A_7C15).max(1));
+  for seed in SEEDS {
+    for _ in 0..mutations {
+      let source = mutate(seed, &mut rng);
+      if let Err(problem) = lint_no_panic(&source) {
+        panic!("{problem}\n\nsource:\n{source}");
+      }
+    }
+  }
+}
@@ -31,6 +31,14 @@ export function Rule(props: RuleProps) {
             <Badge color="blue">Recommended</Badge>}
           {rule.tags.includes("fresh") &&
             <Badge color="green">Fresh</Badge>}
+          {rule.tags.includes("react") &&
+            <Badge color="purple">React</Badge>}
+          {rule.tags.includes("security") &&
+            <Badge color="red">Security</Badge>}
+          {rule.tags.includes("promise") &&
+            <Badge color="yellow">Promise</Badge>}
+          {rule.tags.includes("test") &&
+            <Badge color="gray">Test</Badge>}
         </div>
       </div>
       <div class="relative bg-gray-50 dark:bg-[#192029] dark:text-white p-3">